@@ -0,0 +1,31 @@
+#!/usr/bin/env bash
+
+# Compares the performance of the hot paths against the saved baseline
+#
+#   ./bench.sh save [name]     records the baseline, usually before the change
+#   ./bench.sh compare [name]  compares with the baseline, failing on regressions
+#
+# Baselines are stored in `target/criterion`, the name defaults to `main`
+
+set -eo pipefail
+
+NAME=${2:-main}
+BENCHES=(-p db_vanilla -p smart_output --bench '*')
+
+case "$1" in
+save)
+    cargo bench "${BENCHES[@]}" -- --save-baseline "$NAME"
+    ;;
+compare)
+    LOG=$(mktemp)
+    cargo bench "${BENCHES[@]}" -- --baseline "$NAME" | tee "$LOG"
+    if grep -q "Performance has regressed" "$LOG"; then
+        echo "Performance has regressed compared to the \`$NAME\` baseline"
+        exit 1
+    fi
+    ;;
+*)
+    echo "Usage: $0 save|compare [name]"
+    exit 1
+    ;;
+esac
@@ -18,7 +18,7 @@ pub fn load_from_dir(dir: impl AsRef<Path>) -> miette::Result<Vec<(PathBuf, Sche
         if !entry
             .path()
             .extension()
-            .is_some_and(|ext| ext.to_ascii_lowercase() == "xml")
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("xml"))
         {
             continue;
         }
@@ -0,0 +1,218 @@
+use eh_mod_dev::schema::expression::Expression;
+use eh_mod_dev::schema::schema::{
+    AmmunitionId, BulletController, BulletControllerParametric, BulletTrigger,
+    BulletTriggerCondition, BulletTriggerSpawnBullet, ColorMode,
+};
+
+/// Set of bullet spawn triggers sharing the same settings, differing only
+/// in the spawn position and rotation
+#[derive(Debug, Clone)]
+pub struct SpawnPattern {
+    template: BulletTriggerSpawnBullet,
+    points: Vec<SpawnPoint>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SpawnPoint {
+    x: f32,
+    y: f32,
+    rotation: f32,
+}
+
+impl SpawnPattern {
+    /// Creates a pattern of a single bullet spawned at the parent position
+    /// when the parent bullet is created
+    pub fn new(ammo: AmmunitionId) -> Self {
+        Self {
+            template: BulletTrigger::spawn_bullet()
+                .with_condition(BulletTriggerCondition::Created)
+                .with_ammunition(ammo)
+                .with_quantity(1),
+            points: vec![SpawnPoint {
+                x: 0.0,
+                y: 0.0,
+                rotation: 0.0,
+            }],
+        }
+    }
+
+    /// Runs a range of actions on the trigger shared by all spawn points
+    ///
+    /// Offset and rotation set here are overwritten by the pattern
+    pub fn edit(mut self, actions: impl FnOnce(&mut BulletTriggerSpawnBullet)) -> Self {
+        actions(&mut self.template);
+        self
+    }
+
+    pub fn with_condition(mut self, condition: BulletTriggerCondition) -> Self {
+        self.template.condition = condition;
+        self
+    }
+
+    /// Sets the size and color of spawned bullets, overriding the ammunition
+    /// color
+    pub fn with_look(mut self, size: f32, color: impl Into<String>) -> Self {
+        self.template.size = size;
+        self.template.color = color.into();
+        self.template.color_mode = ColorMode::UseMyOwn;
+        self
+    }
+
+    /// Rotates the whole pattern around the parent bullet
+    pub fn rotated(mut self, degrees: f32) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        for point in &mut self.points {
+            (point.x, point.y) = (point.x * cos - point.y * sin, point.x * sin + point.y * cos);
+            point.rotation += degrees;
+        }
+        self
+    }
+
+    /// Keeps only the spawn points matching the predicate
+    ///
+    /// Predicate receives the index of the point and its offset
+    pub fn filter(mut self, mut predicate: impl FnMut(usize, f32, f32) -> bool) -> Self {
+        let mut i = 0;
+        self.points.retain(|p| {
+            i += 1;
+            predicate(i - 1, p.x, p.y)
+        });
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn into_triggers(self) -> Vec<BulletTrigger> {
+        self.into()
+    }
+}
+
+impl From<SpawnPattern> for Vec<BulletTrigger> {
+    fn from(value: SpawnPattern) -> Self {
+        value
+            .points
+            .into_iter()
+            .map(|p| {
+                value
+                    .template
+                    .clone()
+                    .with_offset_x(format_number(p.x))
+                    .with_offset_y(format_number(p.y))
+                    .with_rotation(format_number(p.rotation))
+                    .wrap()
+            })
+            .collect()
+    }
+}
+
+impl IntoIterator for SpawnPattern {
+    type Item = BulletTrigger;
+    type IntoIter = std::vec::IntoIter<BulletTrigger>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Vec::from(self).into_iter()
+    }
+}
+
+/// Spawns `n` bullets evenly rotated around the parent bullet, each facing
+/// outwards
+pub fn spawn_ring(n: usize, ammo: AmmunitionId) -> SpawnPattern {
+    spawn_ring_with_radius(n, 0.0, ammo)
+}
+
+/// Same as [spawn_ring], but bullets are spawned on the circle of the
+/// given radius instead of the parent bullet position
+pub fn spawn_ring_with_radius(n: usize, radius: f32, ammo: AmmunitionId) -> SpawnPattern {
+    let mut pattern = SpawnPattern::new(ammo);
+    pattern.points = (0..n)
+        .map(|i| {
+            let rotation = 360.0 * i as f32 / n as f32;
+            let (sin, cos) = rotation.to_radians().sin_cos();
+            SpawnPoint {
+                x: radius * cos,
+                y: radius * sin,
+                rotation,
+            }
+        })
+        .collect();
+    pattern
+}
+
+/// Spawns a `w` by `h` grid of bullets with a unit spacing, centered on
+/// the parent bullet
+pub fn spawn_grid(w: usize, h: usize, ammo: AmmunitionId) -> SpawnPattern {
+    spawn_grid_with_spacing(w, h, 1.0, ammo)
+}
+
+/// Same as [spawn_grid], with a custom distance between bullets
+pub fn spawn_grid_with_spacing(
+    w: usize,
+    h: usize,
+    spacing: f32,
+    ammo: AmmunitionId,
+) -> SpawnPattern {
+    let mut pattern = SpawnPattern::new(ammo);
+    let cx = (w as f32 - 1.0) / 2.0;
+    let cy = (h as f32 - 1.0) / 2.0;
+    pattern.points = (0..h)
+        .flat_map(|y| (0..w).map(move |x| (x, y)))
+        .map(|(x, y)| SpawnPoint {
+            x: (x as f32 - cx) * spacing,
+            y: (y as f32 - cy) * spacing,
+            rotation: 0.0,
+        })
+        .collect();
+    pattern
+}
+
+/// Same as [spawn_grid_with_spacing], but only the outer border of the grid
+/// is spawned
+pub fn spawn_grid_outline(w: usize, h: usize, spacing: f32, ammo: AmmunitionId) -> SpawnPattern {
+    spawn_grid_with_spacing(w, h, spacing, ammo)
+        .filter(|i, _, _| i % w == 0 || i % w == w - 1 || i / w == 0 || i / w == h - 1)
+}
+
+/// Parametric controller moving the bullet along the sine wave
+///
+/// `period` is the time in seconds of a half wave, and `magnitude` is the
+/// maximum sideways offset
+pub fn sine_path(period: f32, magnitude: f32) -> BulletControllerParametric {
+    let frequency = std::f32::consts::PI / period;
+    BulletController::parametric()
+        .with_y(format!("SIN(t * {frequency}) * {magnitude}"))
+        .with_rotation(format!(
+            "COS(t * {frequency}) * {}",
+            180.0 / std::f32::consts::PI
+        ))
+}
+
+/// Mirrors the parametric controller path across the direction of movement
+pub fn mirrored(controller: &BulletControllerParametric) -> BulletControllerParametric {
+    controller
+        .clone()
+        .with_y(negate(&controller.y))
+        .with_rotation(negate(&controller.rotation))
+}
+
+fn negate(expr: &Expression) -> Expression {
+    match expr.as_str().trim() {
+        "" | "0" => expr.clone(),
+        expr => format!("-({expr})").into(),
+    }
+}
+
+fn format_number(value: f32) -> String {
+    // Avoid float noise like `1.0000001` from the trigonometry
+    let rounded = (value * 1000.0).round() / 1000.0;
+    if rounded == 0.0 {
+        "0".to_string()
+    } else {
+        rounded.to_string()
+    }
+}
@@ -0,0 +1,148 @@
+use image::{DynamicImage, Rgba, RgbaImage};
+
+use eh_mod_dev::database::Database;
+
+/// Outline of the placeholder icon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconShape {
+    Circle,
+    Square,
+    Diamond,
+    Hexagon,
+}
+
+/// Mark drawn over the icon shape
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconGlyph {
+    None,
+    /// Row of dots, usually denoting the component size
+    Pips(u32),
+    /// Stack of horizontal bars
+    Bars(u32),
+    Cross,
+    Ring,
+}
+
+/// Look of the generated placeholder icons
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IconStyle {
+    pub shape: IconShape,
+    pub color: Rgba<u8>,
+    /// Color of the glyph
+    pub glyph_color: Rgba<u8>,
+    /// Width and height of the icon, in pixels
+    pub size: u32,
+}
+
+impl IconStyle {
+    pub fn new(shape: IconShape, color: impl Into<Rgba<u8>>) -> Self {
+        Self {
+            shape,
+            color: color.into(),
+            glyph_color: Rgba([255, 255, 255, 230]),
+            size: 128,
+        }
+    }
+
+    pub fn with_glyph_color(mut self, color: impl Into<Rgba<u8>>) -> Self {
+        self.glyph_color = color.into();
+        self
+    }
+
+    pub fn with_size(mut self, size: u32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Draws the icon with the given glyph
+    pub fn render(&self, glyph: IconGlyph) -> RgbaImage {
+        const SAMPLES: [(f32, f32); 4] = [(0.25, 0.25), (0.75, 0.25), (0.25, 0.75), (0.75, 0.75)];
+        let size = self.size.max(1);
+        let outline = shade(self.color, 0.5);
+        RgbaImage::from_fn(size, size, |x, y| {
+            let mut acc = [0.0f32; 4];
+            for (dx, dy) in SAMPLES {
+                // Pixel position in the `-1..1` range, with `y` pointing down
+                let u = (x as f32 + dx) / size as f32 * 2.0 - 1.0;
+                let v = (y as f32 + dy) / size as f32 * 2.0 - 1.0;
+                let color = if glyph_contains(glyph, u, v) {
+                    Some(self.glyph_color)
+                } else {
+                    match shape_distance(self.shape, u, v) {
+                        d if d <= 0.82 => Some(shade(self.color, 1.1 - v * 0.2)),
+                        d if d <= 0.9 => Some(outline),
+                        _ => None,
+                    }
+                };
+                if let Some(Rgba([r, g, b, a])) = color {
+                    let a = a as f32 / 255.0;
+                    acc[0] += r as f32 * a;
+                    acc[1] += g as f32 * a;
+                    acc[2] += b as f32 * a;
+                    acc[3] += a;
+                }
+            }
+            if acc[3] == 0.0 {
+                return Rgba([0, 0, 0, 0]);
+            }
+            let samples = SAMPLES.len() as f32;
+            Rgba([
+                (acc[0] / acc[3]).round() as u8,
+                (acc[1] / acc[3]).round() as u8,
+                (acc[2] / acc[3]).round() as u8,
+                (acc[3] / samples * 255.0).round() as u8,
+            ])
+        })
+    }
+
+    /// Draws the icon and inserts it into the database under the given name
+    pub fn register(&self, db: &Database, name: impl Into<String>, glyph: IconGlyph) {
+        db.insert_image(name.into(), DynamicImage::ImageRgba8(self.render(glyph)));
+    }
+}
+
+/// Distance from the center in the metric of the shape, so the shape
+/// outline is at the same distance for all shapes
+fn shape_distance(shape: IconShape, u: f32, v: f32) -> f32 {
+    let (u, v) = (u.abs(), v.abs());
+    match shape {
+        IconShape::Circle => (u * u + v * v).sqrt(),
+        IconShape::Square => u.max(v),
+        IconShape::Diamond => u + v,
+        IconShape::Hexagon => (u * 0.866 + v * 0.5).max(v),
+    }
+}
+
+fn glyph_contains(glyph: IconGlyph, u: f32, v: f32) -> bool {
+    match glyph {
+        IconGlyph::None => false,
+        IconGlyph::Pips(count) => {
+            let radius = 0.11;
+            let step = 0.3;
+            let start = -(count.saturating_sub(1) as f32) * step / 2.0;
+            (0..count).any(|i| {
+                let cx = start + i as f32 * step;
+                let (du, dv) = (u - cx, v - 0.35);
+                du * du + dv * dv <= radius * radius
+            })
+        }
+        IconGlyph::Bars(count) => {
+            let step = 0.2;
+            let start = -(count.saturating_sub(1) as f32) * step / 2.0;
+            u.abs() <= 0.4
+                && (0..count).any(|i| (v - (start + i as f32 * step)).abs() <= step * 0.3)
+        }
+        IconGlyph::Cross => {
+            (u.abs() <= 0.1 && v.abs() <= 0.5) || (v.abs() <= 0.1 && u.abs() <= 0.5)
+        }
+        IconGlyph::Ring => {
+            let d = (u * u + v * v).sqrt();
+            (0.3..=0.45).contains(&d)
+        }
+    }
+}
+
+fn shade(Rgba([r, g, b, a]): Rgba<u8>, factor: f32) -> Rgba<u8> {
+    let scale = |c: u8| (c as f32 * factor).clamp(0.0, 255.0) as u8;
+    Rgba([scale(r), scale(g), scale(b), a])
+}
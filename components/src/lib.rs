@@ -1,3 +1,6 @@
+pub mod bullets;
+pub mod icons;
+pub mod loadout;
 pub mod sized;
 
 pub mod modifier;
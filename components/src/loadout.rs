@@ -0,0 +1,211 @@
+use std::ops::{Deref, DerefMut};
+
+use eh_mod_dev::database::{Database, DatabaseIdLike, DbItem};
+use eh_mod_dev::layout::Layout;
+use eh_mod_dev::schema::schema::{
+    Barrel, ComponentId, ComponentModId, InstalledComponent, ModificationQuality, Satellite,
+    SatelliteBuild, SatelliteBuildId, SatelliteId, Ship, ShipBuild, ShipBuildId, ShipId,
+};
+
+/// List of components installed on a ship, drone or satellite build
+///
+/// Slot and weapon class compatibility of the installed components is
+/// checked when the database is saved
+#[derive(Debug, Clone, Default)]
+pub struct Loadout {
+    components: Vec<InstalledComponent>,
+}
+
+impl Loadout {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Installs a component with the top left corner of its layout placed
+    /// at the given cell of the hull layout
+    pub fn install(mut self, component: ComponentId, x: i32, y: i32) -> Self {
+        self.components
+            .push(InstalledComponent::new(component).with_x(x).with_y(y));
+        self
+    }
+
+    /// Installs a weapon component and attaches it to the given barrel
+    pub fn install_weapon(mut self, component: ComponentId, x: i32, y: i32, barrel: i32) -> Self {
+        self.components.push(
+            InstalledComponent::new(component)
+                .with_x(x)
+                .with_y(y)
+                .with_barrel_id(barrel),
+        );
+        self
+    }
+
+    /// Installs a component with the given modification
+    pub fn install_modified(
+        mut self,
+        component: ComponentId,
+        x: i32,
+        y: i32,
+        modification: ComponentModId,
+        quality: ModificationQuality,
+    ) -> Self {
+        self.components.push(
+            InstalledComponent::new(component)
+                .with_x(x)
+                .with_y(y)
+                .with_modification(modification)
+                .with_quality(quality),
+        );
+        self
+    }
+
+    /// Adds a fully configured installed component
+    pub fn with(mut self, component: InstalledComponent) -> Self {
+        self.components.push(component);
+        self
+    }
+
+    /// Edits the last installed component
+    ///
+    /// # Panics
+    /// Will panic if no components were installed yet
+    pub fn edit_last(mut self, actions: impl FnOnce(&mut InstalledComponent)) -> Self {
+        actions(
+            self.components
+                .last_mut()
+                .expect("Should have at least one component installed"),
+        );
+        self
+    }
+
+    pub fn into_components(self) -> Vec<InstalledComponent> {
+        self.components
+    }
+
+    /// Appends all components of this loadout to the ship build
+    pub fn apply_to_ship(self, build: &mut ShipBuild) {
+        build.components.extend(self.components)
+    }
+
+    /// Appends all components of this loadout to the satellite build
+    pub fn apply_to_satellite(self, build: &mut SatelliteBuild) {
+        build.components.extend(self.components)
+    }
+}
+
+impl From<Loadout> for Vec<InstalledComponent> {
+    fn from(value: Loadout) -> Self {
+        value.components
+    }
+}
+
+/// Builder for a satellite together with its build, sharing the same string ID
+///
+/// Both items are written into the database when the builder is dropped
+#[derive(Debug)]
+pub struct SatelliteBuilder {
+    satellite: DbItem<Satellite>,
+    build: DbItem<SatelliteBuild>,
+}
+
+impl SatelliteBuilder {
+    pub fn new(db: &Database, id: impl Into<String>) -> Self {
+        let id = id.into();
+        let satellite = db.new_satellite(db.new_id(id.as_str()));
+        let build = db.new_satellite_build(db.new_id(id.as_str()), satellite.id);
+        Self { satellite, build }
+    }
+
+    pub fn satellite_id(&self) -> SatelliteId {
+        self.satellite.id
+    }
+
+    pub fn build_id(&self) -> SatelliteBuildId {
+        self.build.id
+    }
+
+    /// Sets the hull layout of the satellite
+    pub fn layout(mut self, layout: impl Into<Layout>) -> Self {
+        self.satellite.layout = layout.into();
+        self
+    }
+
+    /// Adds a barrel to the satellite, returning the builder alongside the
+    /// barrel index for use in [Loadout::install_weapon]
+    pub fn barrel(mut self, barrel: Barrel) -> (Self, i32) {
+        self.satellite.barrels.push(barrel);
+        let idx = self.satellite.barrels.len() as i32 - 1;
+        (self, idx)
+    }
+
+    /// Installs components into the satellite build
+    pub fn loadout(mut self, loadout: Loadout) -> Self {
+        loadout.apply_to_satellite(&mut self.build);
+        self
+    }
+
+    /// Runs a range of actions on the satellite
+    pub fn edit_satellite(mut self, actions: impl FnOnce(&mut Satellite)) -> Self {
+        actions(&mut self.satellite);
+        self
+    }
+
+    /// Runs a range of actions on the satellite build
+    pub fn edit_build(mut self, actions: impl FnOnce(&mut SatelliteBuild)) -> Self {
+        actions(&mut self.build);
+        self
+    }
+
+    /// Saves both items to the database
+    pub fn save(self) {}
+}
+
+/// Builder for the drone ship build, mirroring [SatelliteBuilder] for ship
+/// builds that are launched from drone bays
+#[derive(Debug)]
+pub struct DroneBuilder {
+    build: DbItem<ShipBuild>,
+}
+
+impl DroneBuilder {
+    /// Creates a new drone build for an existing ship hull
+    ///
+    /// Created build is not available for the player or enemy fleets, since
+    /// drones are only spawned by drone bays
+    pub fn new(db: &Database, id: impl Into<String>, ship: impl DatabaseIdLike<Ship>) -> Self {
+        let id = id.into();
+        let ship: ShipId = db.id(ship);
+        let build = db.new_ship_build(db.new_id(id.as_str()), ship).with(|b| {
+            b.with_available_for_player(false)
+                .with_available_for_enemy(false)
+        });
+        Self { build }
+    }
+
+    pub fn build_id(&self) -> ShipBuildId {
+        self.build.id
+    }
+
+    /// Installs components into the drone build
+    pub fn loadout(mut self, loadout: Loadout) -> Self {
+        loadout.apply_to_ship(&mut self.build);
+        self
+    }
+
+    /// Saves the build to the database
+    pub fn save(self) {}
+}
+
+impl Deref for DroneBuilder {
+    type Target = ShipBuild;
+
+    fn deref(&self) -> &Self::Target {
+        &self.build
+    }
+}
+
+impl DerefMut for DroneBuilder {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.build
+    }
+}
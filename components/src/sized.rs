@@ -7,11 +7,14 @@ use eh_mod_dev::database::{Database, DbItem};
 use eh_mod_dev::layout::Layout;
 use eh_mod_dev::schema::schema::{Component, ComponentStats, MinMax};
 
+use crate::icons::{IconGlyph, IconStyle};
 use crate::modifier::StatsModifier;
 
 #[derive(Debug)]
 pub struct SizedComponent {
+    #[allow(dead_code)]
     id: String,
+    #[allow(dead_code)]
     ids: Vec<String>,
     stats: Vec<DbItem<ComponentStats>>,
     components: Vec<DbItem<Component>>,
@@ -61,7 +64,17 @@ impl SizedComponent {
     pub fn layouts_square(&mut self) -> &mut Self {
         let min = self.sizes.0 + 1;
         for (size, comp) in self.components.iter_mut().enumerate() {
-            comp.layout = Layout::new_square(min + size, '1').to_string();
+            comp.layout = Layout::new_square(min + size, '1');
+        }
+        self
+    }
+
+    /// Generates placeholder icons for all components, marking each size
+    /// with a matching amount of pips
+    pub fn placeholder_icons(&mut self, db: &Database, style: &IconStyle) -> &mut Self {
+        for (size, comp) in self.components.iter().enumerate() {
+            let pips = (self.sizes.0 + size + 1) as u32;
+            style.register(db, comp.icon.clone(), IconGlyph::Pips(pips));
         }
         self
     }
@@ -0,0 +1,79 @@
+//! Hot paths of the mod build, measured on the bundled vanilla database
+//!
+//! See `bench.sh` in the repository root for comparing the runs
+
+use std::path::PathBuf;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+use db_vanilla::load_vanilla;
+use eh_mod_dev::database::{database, Database, DatabaseHolder};
+use eh_mod_dev::diagnostic::context::DiagnosticContext;
+use eh_mod_dev::schema::schema::{DatabaseItem, Weapon};
+use smart_output::testing::test_dir;
+
+fn vanilla() -> Database {
+    let db = DatabaseHolder::ephemeral();
+    load_vanilla(&db, Default::default());
+    db
+}
+
+fn load(c: &mut Criterion) {
+    c.bench_function("vanilla_load", |b| b.iter(vanilla));
+}
+
+fn id_allocation(c: &mut Criterion) {
+    c.bench_function("id_allocation_1000", |b| {
+        b.iter_batched(
+            DatabaseHolder::ephemeral,
+            |db| {
+                for i in 0..1000 {
+                    db.new_id::<Weapon>(format!("bench:weapon_{i}"));
+                }
+                db
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn validation(c: &mut Criterion) {
+    let db = vanilla();
+    c.bench_function("vanilla_validation", |b| {
+        b.iter(|| {
+            let mut ctx = DiagnosticContext::default();
+            db.iter_all(|ty, _, item| item.validate(ctx.enter(ty)));
+            ctx
+        })
+    });
+}
+
+fn save(c: &mut Criterion) {
+    let db = vanilla();
+    c.bench_function("vanilla_serialization", |b| {
+        b.iter(|| {
+            let mut size = 0;
+            db.iter_all(|_, _, item| size += serde_json::to_string_pretty(item).unwrap().len());
+            size
+        })
+    });
+    drop(db);
+
+    // All iterations but the first find the output unchanged, which is the
+    // common case of the incremental builds
+    let (_dir, dir) = test_dir("db_vanilla_bench");
+    c.bench_function("vanilla_save_unchanged", |b| {
+        b.iter_batched(
+            || {
+                let db = database(&dir, None::<PathBuf>);
+                load_vanilla(&db, Default::default());
+                db
+            },
+            |db| db.save(),
+            BatchSize::PerIteration,
+        )
+    });
+}
+
+criterion_group!(benches, load, id_allocation, validation, save);
+criterion_main!(benches);
@@ -0,0 +1,45 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use eh_schema::schema::{AssetKind, Item, VisitAssets};
+
+fn main() {
+    let vanilla = Path::new(env!("CARGO_MANIFEST_DIR")).join("vanilla");
+    println!("cargo:rerun-if-changed={}", vanilla.display());
+
+    let mut images = BTreeSet::new();
+    let mut audio_clips = BTreeSet::new();
+    let mut prefabs = BTreeSet::new();
+    for entry in walkdir::WalkDir::new(&vanilla) {
+        let entry = entry.expect("Should be able to read vanilla directory");
+        let path = entry.path();
+        if !entry.file_type().is_file() || path.extension().is_none_or(|ext| ext != "json") {
+            continue;
+        }
+        let data = std::fs::read(path).expect("Should be able to read vanilla file");
+        let item: Item = serde_json5::from_slice(&data)
+            .unwrap_or_else(|err| panic!("Vanilla file {} is invalid: {err}", path.display()));
+        item.visit_assets(&mut |kind, name| {
+            let names = match kind {
+                AssetKind::Image => &mut images,
+                AssetKind::AudioClip => &mut audio_clips,
+                AssetKind::Prefab => &mut prefabs,
+            };
+            names.insert(name.to_string());
+        });
+    }
+
+    let mut code = String::new();
+    for (name, values) in [
+        ("IMAGES", images),
+        ("AUDIO_CLIPS", audio_clips),
+        ("PREFABS", prefabs),
+    ] {
+        let values: Vec<_> = values.into_iter().collect();
+        writeln!(code, "const {name}: &[&str] = &{values:?};").unwrap();
+    }
+
+    let out = Path::new(&std::env::var("OUT_DIR").unwrap()).join("asset_index.rs");
+    std::fs::write(out, code).expect("Should be able to write asset index");
+}
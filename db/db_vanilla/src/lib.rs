@@ -1,9 +1,82 @@
-use eh_mod_dev::database::Database;
-use eh_mod_dev::vanilla_mappings::add_vanilla_mappings;
+use eh_mod_dev::database::{Database, LoadOptions};
+use eh_mod_dev::schema::schema::{AssetKind, DatabaseSettings};
+use eh_mod_dev::validators::KnownAssets;
+use eh_mod_dev::vanilla_mappings::{add_vanilla_mappings, GameVersion};
 
-pub fn load_vanilla(db: &Database) {
+include!(concat!(env!("OUT_DIR"), "/asset_index.rs"));
+
+/// Loads the bundled vanilla database, naming its items with the mappings
+/// of the given game version
+///
+/// # Panics
+/// Will panic if the bundled database is of a different version
+pub fn load_vanilla(db: &Database, version: GameVersion) {
+    load_vanilla_with(db, version, &LoadOptions::default())
+}
+
+/// Same as [load_vanilla], but only loads the items accepted by the options
+///
+/// [DatabaseSettings] must not be filtered out, since it's used to check
+/// the version of the bundled database
+pub fn load_vanilla_with(db: &Database, version: GameVersion, options: &LoadOptions) {
     static DB: include_dir::Dir = include_dir::include_dir!("$CARGO_MANIFEST_DIR/vanilla");
-    db.load_from_included_dir(&DB);
+    db.load_from_included_dir_with(&DB, options);
+
+    let bundled = db
+        .get_singleton::<DatabaseSettings>()
+        .and_then(|settings| GameVersion::of_settings(&settings.read()));
+    if bundled != Some(version) {
+        panic!("Bundled vanilla database is not of version {version}, its mappings would mismatch the items")
+    }
+
+    add_vanilla_mappings(db, version);
+
+    let index = asset_index();
+    db.extra_or_init::<KnownAssets>().edit(|known| {
+        for kind in [AssetKind::Image, AssetKind::AudioClip, AssetKind::Prefab] {
+            known.extend(kind, index.names(kind).iter().copied());
+        }
+    });
+}
+
+/// Names of the game assets referenced by the vanilla database
+#[derive(Debug, Clone, Copy)]
+pub struct AssetIndex {
+    images: &'static [&'static str],
+    audio_clips: &'static [&'static str],
+    prefabs: &'static [&'static str],
+}
+
+impl AssetIndex {
+    /// Returns all names of the given kind, in sorted order
+    pub fn names(&self, kind: AssetKind) -> &'static [&'static str] {
+        match kind {
+            AssetKind::Image => self.images,
+            AssetKind::AudioClip => self.audio_clips,
+            AssetKind::Prefab => self.prefabs,
+        }
+    }
+
+    pub fn contains(&self, kind: AssetKind, name: &str) -> bool {
+        self.names(kind).binary_search(&name).is_ok()
+    }
+
+    /// Returns names of the given kind starting with the prefix
+    pub fn complete(&self, kind: AssetKind, prefix: &str) -> &'static [&'static str] {
+        let names = self.names(kind);
+        let start = names.partition_point(|name| *name < prefix);
+        let end = start + names[start..].partition_point(|name| name.starts_with(prefix));
+        &names[start..end]
+    }
+}
 
-    add_vanilla_mappings(db);
+/// Returns the index of all asset names that are referenced by the vanilla
+/// database, collected when the crate is built
+pub fn asset_index() -> &'static AssetIndex {
+    static INDEX: AssetIndex = AssetIndex {
+        images: IMAGES,
+        audio_clips: AUDIO_CLIPS,
+        prefabs: PREFABS,
+    };
+    &INDEX
 }
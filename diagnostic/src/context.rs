@@ -7,6 +7,8 @@ use std::fmt::Display;
 #[derive(Debug)]
 pub struct DiagnosticContext {
     pub diagnostics: BTreeMap<String, Vec<Diagnostic>>,
+    /// Human-readable origins of the diagnostic entries
+    pub origins: BTreeMap<String, String>,
     path: DiagnosticPath,
 }
 
@@ -14,6 +16,7 @@ impl Default for DiagnosticContext {
     fn default() -> Self {
         DiagnosticContext {
             diagnostics: Default::default(),
+            origins: Default::default(),
             path: DiagnosticPath::empty(),
         }
     }
@@ -29,6 +32,11 @@ impl DiagnosticContext {
         }
     }
 
+    /// Records where the entity behind the entry came from
+    pub fn set_origin(&mut self, ident: impl Display, origin: impl Display) {
+        self.origins.insert(ident.to_string(), origin.to_string());
+    }
+
     pub fn enter_new(&mut self, ident: impl Display) -> DiagnosticContextRef<'_> {
         if self.diagnostics.contains_key(&ident.to_string()) {
             panic!("Diagnostic context already exists for {}", ident);
@@ -48,6 +56,7 @@ impl<'a> DiagnosticContextRef<'a> {
     pub fn emit(&mut self, diagnostic: DiagnosticKind) {
         self.diagnostics.push(Diagnostic {
             path: self.path.clone(),
+            error: diagnostic.is_error(),
             kind: diagnostic,
         })
     }
@@ -1,6 +1,22 @@
 use crate::path::DiagnosticPath;
 use thiserror::Error;
 
+/// Group of the checks that produce the diagnostic, used to toggle the
+/// checks together
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckGroup {
+    /// Schema ranges of the field values
+    Range,
+    /// References to the items, quests and images
+    Reference,
+    /// Suspicious, but valid data
+    Lint,
+    /// Limits of the game engine
+    EngineLimit,
+    /// Data that is certainly broken
+    Core,
+}
+
 #[derive(Debug, Clone, Error)]
 pub enum DiagnosticKind {
     #[error("Obsolete field usage detected")]
@@ -9,8 +25,125 @@ pub enum DiagnosticKind {
     ValueTooSmall { min: f64, value: f64 },
     #[error("Value {} is too large, expected at most {}", .value, .max)]
     ValueTooLarge { max: f64, value: f64 },
-    #[error("Expected a square layout, but got a layout with length {}", .length)]
-    LayoutNotSquare { length: usize },
+    #[error(
+        "Component layout can't fit into any of the {} hull layouts it is installed on",
+        .hulls
+    )]
+    ComponentNeverFits { hulls: usize },
+    #[error("Component can't be placed at ({}, {}) of the hull layout", .x, .y)]
+    InvalidComponentPlacement { x: i32, y: i32 },
+    #[error("Barrel {} does not exist, hull only has {} barrels", .barrel, .barrels)]
+    BarrelOutOfRange { barrel: i32, barrels: usize },
+    #[error(
+        "Weapon of slot type `{}` can't be installed into barrel of class `{}`",
+        .slot,
+        .class
+    )]
+    WeaponClassMismatch { slot: String, class: String },
+    #[error(
+        "Relation towards {} is {}, but the reverse relation is {}",
+        .other,
+        .relation,
+        .reverse
+    )]
+    AsymmetricRelation {
+        other: String,
+        relation: String,
+        reverse: String,
+    },
+    #[error("Failed to parse expression: {}", .error)]
+    InvalidExpression { error: String },
+    #[error("Unknown variable `{}` in expression", .name)]
+    UnknownExpressionVariable { name: String },
+    #[error("Unknown function `{}` in expression", .name)]
+    UnknownExpressionFunction { name: String },
+    #[error("Function `{}` can't be called with {} arguments", .name, .count)]
+    InvalidArgumentCount { name: String, count: usize },
+    #[error("Division by `{}`, which can evaluate to zero", .divisor)]
+    UnsafeDivision { divisor: String },
+    #[error(
+        "Item ID is used by both the item from {} and the item from {}, the latter was kept",
+        .existing,
+        .new
+    )]
+    ItemCollision { existing: String, new: String },
+    #[error(
+        "Item ID collision, kept the item from {} and discarded the item from {}",
+        .kept,
+        .discarded
+    )]
+    ItemCollisionResolved { kept: String, discarded: String },
+    #[error("Image is {}x{}, which is larger than {} pixels", .width, .height, .max)]
+    ImageTooLarge { width: u32, height: u32, max: u32 },
+    #[error("Expected a square image, but got {}x{}", .width, .height)]
+    ImageNotSquare { width: u32, height: u32 },
+    #[error("Image color format `{}` is not supported, expected 8 bits per channel", .color)]
+    UnsupportedImageColor { color: String },
+    #[error("Color `{}` is malformed, expected `#RRGGBB` or `#AARRGGBB`", .color)]
+    InvalidColor { color: String },
+    #[error(
+        "Image `{}` is not present in the game or the mod{}",
+        .name,
+        .suggestion.as_ref().map(|s| format!(", did you mean `{s}`?")).unwrap_or_default()
+    )]
+    UnknownImage {
+        name: String,
+        suggestion: Option<String>,
+    },
+    #[error("Requirement can never be met")]
+    UnsatisfiableRequirement,
+    #[error("{} is {}, which exceeds the engine limit of {}", .limit, .value, .max)]
+    EngineLimitExceeded {
+        limit: String,
+        value: usize,
+        max: usize,
+    },
+    #[error(
+        "{} was {} in the frozen build, but is now {}, which breaks existing savegames",
+        .what,
+        .frozen,
+        .current.as_deref().unwrap_or("removed")
+    )]
+    SavegameIncompatible {
+        what: String,
+        frozen: String,
+        current: Option<String>,
+        strict: bool,
+    },
+    #[error("ID `{}` was allocated, but no item was added with it", .id)]
+    UnusedId { id: String },
+    #[error(
+        "Referenced {} with ID {} does not exist{}",
+        .ty,
+        .id,
+        if .other_types.is_empty() {
+            String::new()
+        } else {
+            format!(", but this ID is used by {}", .other_types.join(", "))
+        }
+    )]
+    UnresolvedReference {
+        ty: String,
+        id: i32,
+        /// Types that have an item with this ID
+        other_types: Vec<String>,
+    },
+    #[error("Referenced quest `{}` was never built", .quest)]
+    UnknownQuest { quest: String },
+    #[error("Referenced quest `{}` does not start with the node `{}`", .quest, .node)]
+    UnknownQuestNode { quest: String, node: String },
+    #[error("Mod ID `{}` is already used by the mod `{}`", .guid, .other)]
+    ModIdCollision { guid: String, other: String },
+    #[error(
+        "Mod version {} was not bumped since the last published version {}",
+        .version,
+        .published
+    )]
+    ModVersionNotBumped { version: i32, published: i32 },
+    /// Problem found by the checks of the mod itself, see
+    /// `DatabaseHolder::add_save_check`
+    #[error("{}", .message)]
+    Custom { message: String },
 }
 
 impl DiagnosticKind {
@@ -32,9 +165,178 @@ impl DiagnosticKind {
         DiagnosticKind::ObsoleteField
     }
 
-    pub fn layout_not_square(length: impl Into<usize>) -> Self {
-        DiagnosticKind::LayoutNotSquare {
-            length: length.into(),
+    pub fn component_never_fits(hulls: impl Into<usize>) -> Self {
+        DiagnosticKind::ComponentNeverFits {
+            hulls: hulls.into(),
+        }
+    }
+
+    pub fn invalid_component_placement(x: impl Into<i32>, y: impl Into<i32>) -> Self {
+        DiagnosticKind::InvalidComponentPlacement {
+            x: x.into(),
+            y: y.into(),
+        }
+    }
+
+    pub fn barrel_out_of_range(barrel: impl Into<i32>, barrels: impl Into<usize>) -> Self {
+        DiagnosticKind::BarrelOutOfRange {
+            barrel: barrel.into(),
+            barrels: barrels.into(),
+        }
+    }
+
+    pub fn weapon_class_mismatch(slot: impl Into<String>, class: impl Into<String>) -> Self {
+        DiagnosticKind::WeaponClassMismatch {
+            slot: slot.into(),
+            class: class.into(),
+        }
+    }
+
+    pub fn asymmetric_relation(
+        other: impl Into<String>,
+        relation: impl Into<String>,
+        reverse: impl Into<String>,
+    ) -> Self {
+        DiagnosticKind::AsymmetricRelation {
+            other: other.into(),
+            relation: relation.into(),
+            reverse: reverse.into(),
+        }
+    }
+
+    pub fn invalid_expression(error: impl ToString) -> Self {
+        DiagnosticKind::InvalidExpression {
+            error: error.to_string(),
+        }
+    }
+
+    pub fn unknown_expression_variable(name: impl Into<String>) -> Self {
+        DiagnosticKind::UnknownExpressionVariable { name: name.into() }
+    }
+
+    pub fn unknown_expression_function(name: impl Into<String>) -> Self {
+        DiagnosticKind::UnknownExpressionFunction { name: name.into() }
+    }
+
+    pub fn invalid_argument_count(name: impl Into<String>, count: impl Into<usize>) -> Self {
+        DiagnosticKind::InvalidArgumentCount {
+            name: name.into(),
+            count: count.into(),
+        }
+    }
+
+    pub fn unsafe_division(divisor: impl ToString) -> Self {
+        DiagnosticKind::UnsafeDivision {
+            divisor: divisor.to_string(),
+        }
+    }
+
+    pub fn item_collision(existing: impl Into<String>, new: impl Into<String>) -> Self {
+        DiagnosticKind::ItemCollision {
+            existing: existing.into(),
+            new: new.into(),
+        }
+    }
+
+    pub fn item_collision_resolved(kept: impl Into<String>, discarded: impl Into<String>) -> Self {
+        DiagnosticKind::ItemCollisionResolved {
+            kept: kept.into(),
+            discarded: discarded.into(),
+        }
+    }
+
+    pub fn image_too_large(width: u32, height: u32, max: u32) -> Self {
+        DiagnosticKind::ImageTooLarge { width, height, max }
+    }
+
+    pub fn image_not_square(width: u32, height: u32) -> Self {
+        DiagnosticKind::ImageNotSquare { width, height }
+    }
+
+    pub fn unsupported_image_color(color: impl ToString) -> Self {
+        DiagnosticKind::UnsupportedImageColor {
+            color: color.to_string(),
+        }
+    }
+
+    pub fn invalid_color(color: impl Into<String>) -> Self {
+        DiagnosticKind::InvalidColor {
+            color: color.into(),
+        }
+    }
+
+    pub fn unknown_image(name: impl Into<String>, suggestion: Option<String>) -> Self {
+        DiagnosticKind::UnknownImage {
+            name: name.into(),
+            suggestion,
+        }
+    }
+
+    pub fn unsatisfiable_requirement() -> Self {
+        DiagnosticKind::UnsatisfiableRequirement
+    }
+
+    pub fn engine_limit_exceeded(limit: impl Into<String>, value: usize, max: usize) -> Self {
+        DiagnosticKind::EngineLimitExceeded {
+            limit: limit.into(),
+            value,
+            max,
+        }
+    }
+
+    pub fn savegame_incompatible(
+        what: impl Into<String>,
+        frozen: impl Into<String>,
+        current: Option<String>,
+        strict: bool,
+    ) -> Self {
+        DiagnosticKind::SavegameIncompatible {
+            what: what.into(),
+            frozen: frozen.into(),
+            current,
+            strict,
+        }
+    }
+
+    pub fn unused_id(id: impl Into<String>) -> Self {
+        DiagnosticKind::UnusedId { id: id.into() }
+    }
+
+    pub fn unresolved_reference(ty: impl Into<String>, id: i32, other_types: Vec<String>) -> Self {
+        DiagnosticKind::UnresolvedReference {
+            ty: ty.into(),
+            id,
+            other_types,
+        }
+    }
+
+    pub fn unknown_quest(quest: impl Into<String>) -> Self {
+        DiagnosticKind::UnknownQuest {
+            quest: quest.into(),
+        }
+    }
+
+    pub fn unknown_quest_node(quest: impl Into<String>, node: impl Into<String>) -> Self {
+        DiagnosticKind::UnknownQuestNode {
+            quest: quest.into(),
+            node: node.into(),
+        }
+    }
+
+    pub fn mod_id_collision(guid: impl Into<String>, other: impl Into<String>) -> Self {
+        DiagnosticKind::ModIdCollision {
+            guid: guid.into(),
+            other: other.into(),
+        }
+    }
+
+    pub fn mod_version_not_bumped(version: i32, published: i32) -> Self {
+        DiagnosticKind::ModVersionNotBumped { version, published }
+    }
+
+    pub fn custom(message: impl ToString) -> Self {
+        DiagnosticKind::Custom {
+            message: message.to_string(),
         }
     }
 
@@ -43,7 +345,68 @@ impl DiagnosticKind {
             DiagnosticKind::ObsoleteField => false,
             DiagnosticKind::ValueTooSmall { .. } => false,
             DiagnosticKind::ValueTooLarge { .. } => false,
-            DiagnosticKind::LayoutNotSquare { .. } => true,
+            DiagnosticKind::ComponentNeverFits { .. } => true,
+            DiagnosticKind::InvalidComponentPlacement { .. } => true,
+            DiagnosticKind::BarrelOutOfRange { .. } => true,
+            DiagnosticKind::WeaponClassMismatch { .. } => true,
+            DiagnosticKind::AsymmetricRelation { .. } => false,
+            DiagnosticKind::InvalidExpression { .. } => true,
+            DiagnosticKind::UnknownExpressionVariable { .. } => true,
+            DiagnosticKind::UnknownExpressionFunction { .. } => true,
+            DiagnosticKind::InvalidArgumentCount { .. } => true,
+            DiagnosticKind::UnsafeDivision { .. } => false,
+            DiagnosticKind::ItemCollision { .. } => true,
+            DiagnosticKind::ItemCollisionResolved { .. } => false,
+            DiagnosticKind::ImageTooLarge { .. } => false,
+            DiagnosticKind::ImageNotSquare { .. } => false,
+            DiagnosticKind::UnsupportedImageColor { .. } => true,
+            DiagnosticKind::InvalidColor { .. } => true,
+            DiagnosticKind::UnknownImage { .. } => false,
+            DiagnosticKind::UnsatisfiableRequirement => false,
+            DiagnosticKind::EngineLimitExceeded { .. } => true,
+            DiagnosticKind::SavegameIncompatible { strict, .. } => *strict,
+            DiagnosticKind::UnusedId { .. } => false,
+            DiagnosticKind::UnresolvedReference { .. } => true,
+            DiagnosticKind::UnknownQuest { .. } => true,
+            DiagnosticKind::UnknownQuestNode { .. } => true,
+            DiagnosticKind::ModIdCollision { .. } => false,
+            DiagnosticKind::ModVersionNotBumped { .. } => false,
+            DiagnosticKind::Custom { .. } => false,
+        }
+    }
+
+    pub fn group(&self) -> CheckGroup {
+        match self {
+            DiagnosticKind::ObsoleteField => CheckGroup::Lint,
+            DiagnosticKind::ValueTooSmall { .. } => CheckGroup::Range,
+            DiagnosticKind::ValueTooLarge { .. } => CheckGroup::Range,
+            DiagnosticKind::ComponentNeverFits { .. } => CheckGroup::Core,
+            DiagnosticKind::InvalidComponentPlacement { .. } => CheckGroup::Core,
+            DiagnosticKind::BarrelOutOfRange { .. } => CheckGroup::Core,
+            DiagnosticKind::WeaponClassMismatch { .. } => CheckGroup::Core,
+            DiagnosticKind::AsymmetricRelation { .. } => CheckGroup::Lint,
+            DiagnosticKind::InvalidExpression { .. } => CheckGroup::Core,
+            DiagnosticKind::UnknownExpressionVariable { .. } => CheckGroup::Core,
+            DiagnosticKind::UnknownExpressionFunction { .. } => CheckGroup::Core,
+            DiagnosticKind::InvalidArgumentCount { .. } => CheckGroup::Core,
+            DiagnosticKind::UnsafeDivision { .. } => CheckGroup::Lint,
+            DiagnosticKind::ItemCollision { .. } => CheckGroup::Core,
+            DiagnosticKind::ItemCollisionResolved { .. } => CheckGroup::Lint,
+            DiagnosticKind::ImageTooLarge { .. } => CheckGroup::EngineLimit,
+            DiagnosticKind::ImageNotSquare { .. } => CheckGroup::Lint,
+            DiagnosticKind::UnsupportedImageColor { .. } => CheckGroup::Core,
+            DiagnosticKind::InvalidColor { .. } => CheckGroup::Core,
+            DiagnosticKind::UnknownImage { .. } => CheckGroup::Reference,
+            DiagnosticKind::UnsatisfiableRequirement => CheckGroup::Lint,
+            DiagnosticKind::EngineLimitExceeded { .. } => CheckGroup::EngineLimit,
+            DiagnosticKind::SavegameIncompatible { .. } => CheckGroup::Core,
+            DiagnosticKind::UnusedId { .. } => CheckGroup::Lint,
+            DiagnosticKind::UnresolvedReference { .. } => CheckGroup::Reference,
+            DiagnosticKind::UnknownQuest { .. } => CheckGroup::Reference,
+            DiagnosticKind::UnknownQuestNode { .. } => CheckGroup::Reference,
+            DiagnosticKind::ModIdCollision { .. } => CheckGroup::Lint,
+            DiagnosticKind::ModVersionNotBumped { .. } => CheckGroup::Lint,
+            DiagnosticKind::Custom { .. } => CheckGroup::Lint,
         }
     }
 }
@@ -52,4 +415,7 @@ impl DiagnosticKind {
 pub struct Diagnostic {
     pub path: DiagnosticPath,
     pub kind: DiagnosticKind,
+    /// Whether the diagnostic fails the build, defaults to
+    /// [DiagnosticKind::is_error]
+    pub error: bool,
 }
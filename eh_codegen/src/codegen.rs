@@ -9,12 +9,13 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use thiserror::Error;
 
-use codegen_schema::schema::{SchemaDataType, SchemaItem};
+use codegen_schema::schema::{SchemaDataType, SchemaExpressionParam, SchemaItem};
 
 use crate::codegen::structs::{Field, StructData};
 use crate::codegen::switch::Variant;
 
 mod enums;
+mod expressions;
 mod objects;
 mod structs;
 mod switch;
@@ -26,6 +27,9 @@ pub struct CodegenState {
     pub enums: HashMap<String, Vec<String>>,
     pub objects: HashMap<String, StructData>,
     pub extra_functions: BTreeMap<String, TokenStream>,
+    pub expressions: BTreeMap<String, Vec<SchemaExpressionParam>>,
+    /// See [CodegenOptions::preserve_unknown](crate::CodegenOptions::preserve_unknown)
+    pub preserve_unknown: bool,
 }
 
 impl CodegenState {
@@ -73,7 +77,13 @@ impl CodegenState {
                             data.item.ok_or_else(|| miette!("Got enum without items"))?,
                         )
                         .context("Failed to generate enum data")?,
-                    SchemaDataType::Expression => return Ok(None),
+                    SchemaDataType::Expression => {
+                        // Expression types are emitted together in the
+                        // `expressions` module once all enums are known
+                        self.expressions
+                            .insert(data.name, data.param.unwrap_or_default());
+                        return Ok(None);
+                    }
                 }
             }
         };
@@ -160,13 +170,23 @@ impl CodegenState {
                     Some(quote!(#ident_lower(#args) -> #ident))
                 });
 
-        let all_items_macro = lower_idents
-            .iter()
-            .map(|(lower_ident, Variant { ident, .. })| {
-                quote! {
-                    #lower_ident: #ident
-                }
-            });
+        // Metadata passed along with each type, so receiving macros can
+        // generate APIs specific to the collections or settings
+        let type_metadata = |Variant { ident, data }: &Variant| {
+            let has_id = data.id_access.is_some();
+            let is_setting = !has_id;
+            let type_name = ident.to_string();
+            quote! {
+                { has_id: #has_id, is_setting: #is_setting, type_name: #type_name }
+            }
+        };
+        let all_items_macro = lower_idents.iter().map(|(lower_ident, variant)| {
+            let ident = &variant.ident;
+            let metadata = type_metadata(variant);
+            quote! {
+                #lower_ident: #ident #metadata
+            }
+        });
         let all_settings_macro =
             lower_idents
                 .iter()
@@ -178,15 +198,14 @@ impl CodegenState {
                         #lower_ident: #ident
                     })
                 });
-        let all_collections_macro =
-            lower_idents
-                .iter()
-                .filter_map(|(lower_ident, Variant { ident, data })| {
-                    data.id_access.as_ref()?;
-                    Some(quote! {
-                        #lower_ident: #ident
-                    })
-                });
+        let all_collections_macro = lower_idents.iter().filter_map(|(lower_ident, variant)| {
+            variant.data.id_access.as_ref()?;
+            let ident = &variant.ident;
+            let metadata = type_metadata(variant);
+            Some(quote! {
+                #lower_ident: #ident #metadata
+            })
+        });
 
         let ident = format_ident!("Item");
         let code = self.codegen_custom_switch(
@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use itertools::Itertools;
 use miette::bail;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Literal};
 use quote::{format_ident, quote};
 
 use codegen_schema::schema::SchemaEnumItem;
@@ -111,6 +111,21 @@ impl CodegenState {
 
         let repr = if is_char { quote!(u32) } else { quote!(i32) };
 
+        let idents: Vec<_> = items.iter().map(|i| format_ident!("{}", i.name)).collect();
+        let names = items.iter().map(|i| &i.name).collect_vec();
+        let count = Literal::usize_unsuffixed(items.len());
+
+        // Char enums already display as their serialized character
+        let display_impl = (!is_char).then(|| {
+            quote! {
+                impl std::fmt::Display for #name {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(f, "{}", self.name())
+                    }
+                }
+            }
+        });
+
         let name_str = name.to_string();
 
         let derive_reprs = derive_reprs
@@ -133,6 +148,44 @@ impl CodegenState {
                 }
             }
 
+            impl #name {
+                /// All variants, in the schema order
+                pub const ALL_VARIANTS: [Self; #count] = [#(Self::#idents,)*];
+
+                pub const fn variant_count() -> usize {
+                    #count
+                }
+
+                /// Name of the variant, as defined in the schema
+                pub fn name(&self) -> &'static str {
+                    match self {
+                        #(Self::#idents => #names,)*
+                    }
+                }
+            }
+
+            impl std::str::FromStr for #name {
+                type Err = UnknownEnumVariant;
+
+                /// Parses the variant from its schema name
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        #(#names => Ok(Self::#idents),)*
+                        _ => Err(UnknownEnumVariant::new(#name_str, s)),
+                    }
+                }
+            }
+
+            impl std::ops::BitOr for #name {
+                type Output = Flags<Self>;
+
+                fn bitor(self, rhs: Self) -> Self::Output {
+                    Flags::from([self, rhs])
+                }
+            }
+
+            #display_impl
+
             #impls
         })
     }
@@ -0,0 +1,94 @@
+use convert_case::{Case, Casing};
+use itertools::Itertools;
+use miette::bail;
+use proc_macro2::Ident;
+use quote::{format_ident, quote};
+
+use codegen_schema::schema::{SchemaExpressionParam, SchemaExpressionParamType};
+
+use crate::codegen::{CodegenState, TokensResult};
+
+impl CodegenState {
+    pub fn codegen_expressions(&mut self) -> TokensResult {
+        let mut consts = vec![];
+        let mut idents = vec![];
+        for (name, params) in &self.expressions {
+            let ident = const_ident(name);
+            let params: Vec<_> = params
+                .iter()
+                .map(|param| self.expression_param(name, param))
+                .try_collect()?;
+            consts.push(quote! {
+                pub const #ident: ExpressionType = ExpressionType {
+                    name: #name,
+                    params: &[#(#params),*],
+                };
+            });
+            idents.push(ident);
+        }
+
+        Ok(quote! {
+            pub mod expressions {
+                use super::*;
+
+                #(#consts)*
+
+                pub const ALL: &[ExpressionType] = &[#(#idents),*];
+
+                /// Looks up the expression type by its schema name
+                pub fn get(name: &str) -> Option<&'static ExpressionType> {
+                    ALL.iter().find(|ty| ty.name == name)
+                }
+            }
+        })
+    }
+
+    fn expression_param(&self, expression: &str, param: &SchemaExpressionParam) -> TokensResult {
+        let SchemaExpressionParam {
+            name,
+            ty,
+            typeid,
+            description,
+        } = param;
+        let ty = match ty {
+            SchemaExpressionParamType::Float => quote!(ExpressionParamType::Float),
+            SchemaExpressionParamType::Int => quote!(ExpressionParamType::Int),
+            SchemaExpressionParamType::Enum => {
+                let Some(typeid) = typeid else {
+                    bail!(
+                        "Enum parameter `{}` of expression `{}` is missing a typeid",
+                        name,
+                        expression
+                    )
+                };
+                let Some(variants) = self.enums.get(typeid) else {
+                    bail!(
+                        "Parameter `{}` of expression `{}` points at the unknown enum `{}`",
+                        name,
+                        expression,
+                        typeid
+                    )
+                };
+                quote!(ExpressionParamType::Enum {
+                    name: #typeid,
+                    variants: &[#(#variants),*],
+                })
+            }
+        };
+        let description = match description {
+            None => quote!(None),
+            Some(desc) => quote!(Some(#desc)),
+        };
+        Ok(quote! {
+            ExpressionParam {
+                name: #name,
+                ty: #ty,
+                description: #description,
+            }
+        })
+    }
+}
+
+fn const_ident(name: &str) -> Ident {
+    format_ident!("{}", name.from_case(Case::Pascal).to_case(Case::UpperSnake))
+}
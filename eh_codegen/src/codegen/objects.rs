@@ -45,6 +45,12 @@ impl CodegenState {
             quote!(x.id)
         };
 
+        let id_field_mut = if is_switch {
+            quote!(x.id_mut())
+        } else {
+            quote!(&mut x.id)
+        };
+
         let code = data.code;
 
         data.id_access = Some(id_field_getter.clone());
@@ -58,6 +64,10 @@ impl CodegenState {
                     let x = self;
                     #id_field_getter
                 }
+                fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+                    let x = self;
+                    #id_field_mut
+                }
             }
         };
 
@@ -92,6 +92,9 @@ impl Field {
         } = self;
 
         let desc = field.description.as_ref().map(|s| quote!(#[doc = #s]));
+        let metadata = self.metadata_docs();
+        let separator = (desc.is_some() && !metadata.is_empty()).then(|| quote!(#[doc = ""]));
+        let metadata = metadata.iter().map(|line| quote!(#[doc = #line]));
         let serde_default = serde_default.as_ref().map(|s| s.to_string()).map(|s| {
             if s == "default_default" {
                 quote! {#[serde(default)]}
@@ -121,15 +124,80 @@ impl Field {
             SchemaStructMemberType::Vector => quote!(#[serde(with = "crate::helpers::glam_ser")]),
             _ => quote!(),
         };
+        // Keep accepting data written under the old field names
+        let aliases = self.aliases().map(|alias| quote!(#[serde(alias = #alias)]));
         quote! {
             #desc
+            #separator
+            #(#metadata)*
             #serde_default
             #skip_serializing_if
             #serde_with
+            #(#aliases)*
             pub #ident: #ty,
         }
     }
 
+    /// Old names of the field, as written in the data files
+    fn aliases(&self) -> impl Iterator<Item = &str> {
+        self.field
+            .alias
+            .iter()
+            .flat_map(|alias| alias.split(','))
+            .map(|alias| alias.trim())
+            .filter(|alias| !alias.is_empty())
+    }
+
+    /// Pattern matching the snake case name of the field in the
+    /// `DynamicFields` methods, along with its old names
+    fn name_pattern(&self) -> TokenStream {
+        let name = self.ident.to_string();
+        let name = name.trim_start_matches("r#");
+        let aliases = self
+            .aliases()
+            .map(|alias| alias.from_case(Case::Pascal).to_case(Case::Snake));
+        quote!(#name #(| #aliases)*)
+    }
+
+    /// Doc lines listing the range, default value and options of the
+    /// field, so they show up alongside the description
+    fn metadata_docs(&self) -> Vec<String> {
+        let field = &self.field;
+        let mut lines = vec![];
+        if let Some(min) = field.minvalue {
+            lines.push(format!(" - Minimum: `{min}`"));
+        }
+        if let Some(max) = field.maxvalue {
+            lines.push(format!(" - Maximum: `{max}`"));
+        }
+        if let Some(default) = self.default_doc() {
+            lines.push(format!(" - Default: `{default}`"));
+        }
+        if let Some(options) = &field.options {
+            let options = options.split(',').map(|e| e.trim()).join(", ");
+            lines.push(format!(" - Options: `{options}`"));
+        }
+        lines
+    }
+
+    /// Default value of the field as it's written in the data, or `None` if
+    /// the field defaults to the empty value of its type
+    fn default_doc(&self) -> Option<String> {
+        self.default_value.as_ref()?;
+        let default = self.field.default.as_deref();
+        Some(match self.field.ty {
+            SchemaStructMemberType::Int => i32::from_str(default.unwrap_or("0")).ok()?.to_string(),
+            SchemaStructMemberType::Bool => {
+                bool::from_str(default.unwrap_or("false")).ok()?.to_string()
+            }
+            SchemaStructMemberType::Float => {
+                f32::from_str(default.unwrap_or("0")).ok()?.to_string()
+            }
+            SchemaStructMemberType::Color => format!("\"{}\"", default.unwrap_or("#00000000")),
+            _ => format!("\"{}\"", default?),
+        })
+    }
+
     pub fn builder_fn(&self) -> TokenStream {
         let Self { ident, ty, .. } = self;
 
@@ -137,6 +205,18 @@ impl Field {
         let builder_fn_ident = format_ident!("with_{}", i);
         let setter_fn_ident = format_ident!("set_{}", i);
 
+        // Builders of optional fields accept bare values via `Into<Option<T>>`,
+        // so only clearing needs a dedicated function
+        let clear_fn = ty.to_string().starts_with("Option").then(|| {
+            let clear_fn_ident = format_ident!("clear_{}", i);
+            quote! {
+                pub fn #clear_fn_ident(&mut self) -> &mut Self {
+                    self.#ident = None;
+                    self
+                }
+            }
+        });
+
         quote! {
             pub fn #builder_fn_ident(mut self, #ident: impl Into<#ty>) -> Self {
                 self.#ident = #ident.into();
@@ -146,6 +226,7 @@ impl Field {
                 self.#ident = #ident.into();
                 self
             }
+            #clear_fn
         }
     }
 
@@ -292,17 +373,18 @@ impl Field {
             SchemaStructMemberType::Vector => {}
             SchemaStructMemberType::Float => {}
             SchemaStructMemberType::Int => {}
-            SchemaStructMemberType::Color => {}
+            SchemaStructMemberType::Color => validation.push(quote! {
+                if !crate::color::Color::is_valid(&self.#ident) {
+                    ctx.emit(DiagnosticKind::invalid_color(&self.#ident));
+                }
+            }),
             SchemaStructMemberType::Bool => {}
             SchemaStructMemberType::String => {}
             SchemaStructMemberType::Image => {}
             SchemaStructMemberType::AudioClip => {}
             SchemaStructMemberType::Prefab => {}
-            SchemaStructMemberType::Layout => validation.push(quote! {
-                if (self.#ident.len() as f32).sqrt().floor().powi(2) != (self.#ident.len() as f32) {
-                    ctx.emit(DiagnosticKind::layout_not_square(self.#ident.len()));
-                }
-            }),
+            // Squareness is guaranteed by the `Layout` type
+            SchemaStructMemberType::Layout => {}
         };
 
         if !validation.is_empty() {
@@ -317,6 +399,39 @@ impl Field {
         }
     }
 
+    /// Code visiting IDs referenced by the field, if any
+    fn visit_ids(&self) -> Option<TokenStream> {
+        let ident = &self.ident;
+        match self.field.ty {
+            // Own ID of the object is not a reference
+            SchemaStructMemberType::Object if self.field.name == "Id" => None,
+            SchemaStructMemberType::Struct
+            | SchemaStructMemberType::StructList
+            | SchemaStructMemberType::Object
+            | SchemaStructMemberType::ObjectList => Some(quote!(self.#ident.visit_ids(visitor);)),
+            _ => None,
+        }
+    }
+
+    /// Code visiting asset names used by the field, if any
+    fn visit_assets(&self) -> Option<TokenStream> {
+        let ident = &self.ident;
+        let kind = match self.field.ty {
+            SchemaStructMemberType::Struct | SchemaStructMemberType::StructList => {
+                return Some(quote!(self.#ident.visit_assets(visitor);))
+            }
+            SchemaStructMemberType::Image => quote!(AssetKind::Image),
+            SchemaStructMemberType::AudioClip => quote!(AssetKind::AudioClip),
+            SchemaStructMemberType::Prefab => quote!(AssetKind::Prefab),
+            _ => return None,
+        };
+        Some(quote! {
+            if !self.#ident.is_empty() {
+                visitor(#kind, &self.#ident);
+            }
+        })
+    }
+
     pub fn add_extra_functions(&self, funcs: &mut BTreeMap<String, TokenStream>) {
         let ty = &self.ty;
         let Some(default) = &self.default_value else {
@@ -404,11 +519,35 @@ impl CodegenState {
             .partition::<Vec<_>, _>(|f| f.default_value.is_some());
 
         let field_construction = fields.iter().map(|f| f.constructor_entry());
+
+        let (unknown_field, unknown_construction, unknown_eq, unknown_hash) =
+            if self.preserve_unknown {
+                (
+                    quote! {
+                        /// Fields missing from the schema
+                        #[serde(flatten)]
+                        pub unknown_fields: UnknownFields,
+                    },
+                    quote!(unknown_fields: Default::default(),),
+                    quote!(&&self.unknown_fields == other.unknown_fields),
+                    quote!(self.unknown_fields.hash(state);),
+                )
+            } else {
+                Default::default()
+            };
         let constructor_arguments = contructed
             .iter()
             .map(|Field { ident, ty, .. }| quote!(#ident: #ty,));
 
         let validations: Vec<_> = fields.iter().map(|f| f.validation()).try_collect()?;
+        let visit_ids = fields.iter().filter_map(|f| f.visit_ids());
+        let visit_assets = fields.iter().filter_map(|f| f.visit_assets());
+        let field_names: Vec<_> = fields
+            .iter()
+            .map(|f| f.ident.to_string().trim_start_matches("r#").to_string())
+            .collect();
+        let field_patterns: Vec<_> = fields.iter().map(|f| f.name_pattern()).collect();
+        let field_idents: Vec<_> = fields.iter().map(|f| &f.ident).collect();
 
         let default_impl = contructed.is_empty().then(|| {
             quote! {
@@ -441,12 +580,14 @@ impl CodegenState {
                     fn eq(&self, other: &Self) -> bool {
                         #(#eq_impl)*
                         #custom_eq_code
+                        #unknown_eq
                     }
                 }
 
                 impl std::hash::Hash for #name {
                     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
                         #(#hash_impl)*
+                        #unknown_hash
                     }
                 }
             }
@@ -466,12 +607,14 @@ impl CodegenState {
             #[serde(rename_all = "PascalCase")]
             pub struct #name {
                 #(#struct_fields)*
+                #unknown_field
             }
 
             impl #name {
                 pub fn new(#(#constructor_arguments)*) -> Self {
                     Self {
                         #(#field_construction)*
+                        #unknown_construction
                     }
                 }
 
@@ -488,6 +631,38 @@ impl CodegenState {
                 }
             }
 
+            impl VisitIds for #name {
+                fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+                    #(#visit_ids)*
+                }
+            }
+
+            impl VisitAssets for #name {
+                fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+                    #(#visit_assets)*
+                }
+            }
+
+            impl DynamicFields for #name {
+                fn field_names(&self) -> &'static [&'static str] {
+                    &[#(#field_names),*]
+                }
+
+                fn get_field(&self, name: &str) -> Option<FieldValue> {
+                    match name {
+                        #(#field_patterns => Some(FieldValue::of(&self.#field_idents)),)*
+                        _ => None,
+                    }
+                }
+
+                fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+                    match name {
+                        #(#field_patterns => value.write_to(&mut self.#field_idents, #field_names),)*
+                        _ => Err(FieldError::UnknownField(name.to_string())),
+                    }
+                }
+            }
+
             #custom_eq_hash_impls
 
             #default_impl
@@ -601,11 +776,10 @@ fn rust_type(field: &SchemaStructMember, struct_name: &Ident) -> Result<(TokenSt
             }
             SchemaStructMemberType::EnumFlags => {
                 let id = type_id()?;
-                quote!(std::collections::BTreeSet::<#id>)
+                quote!(Flags<#id>)
             }
             SchemaStructMemberType::Expression => {
-                // MAYBE?: something smarter for expressions?
-                quote!(String)
+                quote!(crate::expression::Expression)
             }
             SchemaStructMemberType::Vector => {
                 quote!(glam::f32::Vec2)
@@ -635,9 +809,88 @@ fn rust_type(field: &SchemaStructMember, struct_name: &Ident) -> Result<(TokenSt
                 quote!(String)
             }
             SchemaStructMemberType::Layout => {
-                quote!(String)
+                quote!(crate::layout::Layout)
             }
         },
         false,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use codegen_schema::schema::{
+        SchemaData, SchemaDataType, SchemaItem, SchemaStructMember, SchemaStructMemberType,
+    };
+
+    use crate::codegen::CodegenState;
+
+    fn member(name: &str, alias: Option<&str>) -> SchemaStructMember {
+        SchemaStructMember {
+            name: name.to_string(),
+            ty: SchemaStructMemberType::Int,
+            minvalue: None,
+            maxvalue: None,
+            typeid: None,
+            options: None,
+            case: None,
+            alias: alias.map(str::to_string),
+            default: None,
+            arguments: None,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn aliases() {
+        let item = SchemaItem::Data(SchemaData {
+            ty: SchemaDataType::Struct,
+            name: "Sample".to_string(),
+            switch: None,
+            typeid: None,
+            member: Some(vec![
+                member("Renamed", Some("OldName, OlderName")),
+                member("Plain", None),
+            ]),
+            param: None,
+            item: None,
+        });
+        let code = CodegenState::default()
+            .codegen(item)
+            .and_then(CodegenState::format_tokens)
+            .unwrap()
+            .unwrap();
+
+        // Formatting of the generated code is not important here
+        let compact: String = code.split_whitespace().collect();
+        let contains = |snippet: &str| {
+            let snippet: String = snippet.split_whitespace().collect();
+            compact.contains(&snippet)
+        };
+
+        assert!(
+            contains(
+                r#"#[serde(alias = "OldName")]
+                #[serde(alias = "OlderName")]
+                pub r#renamed: i32,"#
+            ),
+            "{code}"
+        );
+        assert_eq!(code.matches("serde(alias").count(), 2, "{code}");
+        assert!(
+            contains(
+                r#""renamed" | "old_name" | "older_name" => { Some(FieldValue::of(&self.r#renamed)) }"#
+            ),
+            "{code}"
+        );
+        assert!(
+            contains(
+                r#""renamed" | "old_name" | "older_name" => { value.write_to(&mut self.r#renamed, "renamed") }"#
+            ),
+            "{code}"
+        );
+        assert!(
+            contains(r#""plain" => Some(FieldValue::of(&self.r#plain))"#),
+            "{code}"
+        );
+    }
+}
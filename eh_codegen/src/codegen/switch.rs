@@ -248,6 +248,20 @@ impl CodegenState {
             }
         });
 
+        // Tag is not a field of the variant, so it must not end up among the
+        // unknown fields of the variant
+        let take_tag = if self.preserve_unknown {
+            quote! {
+                let mut data = serde_json::Value::deserialize(deserializer)?;
+                let tag = data.as_object_mut().and_then(|data| data.remove(#tag_field));
+            }
+        } else {
+            quote! {
+                let data = serde_json::Value::deserialize(deserializer)?;
+                let tag = data.get(#tag_field);
+            }
+        };
+
         let serde_impl = quote! {
             impl serde::Serialize for #switch_struct_ident {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -271,8 +285,8 @@ impl CodegenState {
 
             impl<'de> serde::Deserialize<'de> for #switch_struct_ident {
                 fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::de::Deserializer<'de> {
-                    let data = serde_json::Value::deserialize(deserializer)?;
-                    let variant_ty: #enum_ident = if let Some(variant) = data.get(#tag_field) {
+                    #take_tag
+                    let variant_ty: #enum_ident = if let Some(variant) = tag {
                         serde_json::from_value(variant.clone()).map_err(serde::de::Error::custom)?
                         // return Err(serde::de::Error::missing_field(#tag_field));
                     } else {
@@ -399,6 +413,53 @@ impl CodegenState {
             }
         });
 
+        let visit_matches = variants.iter().map(|v| {
+            let name = &v.ident;
+            quote!(Self::#name(x) => x.visit_ids(visitor),)
+        });
+        let visit_assets_matches = variants.iter().map(|v| {
+            let name = &v.ident;
+            quote!(Self::#name(x) => x.visit_assets(visitor),)
+        });
+        let variant_idents: Vec<_> = variants.iter().map(|v| &v.ident).collect();
+        blocks.push(quote! {
+            impl VisitIds for #switch_struct_ident {
+                fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+                    match self {
+                        #(#visit_matches)*
+                    }
+                }
+            }
+
+            impl VisitAssets for #switch_struct_ident {
+                fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+                    match self {
+                        #(#visit_assets_matches)*
+                    }
+                }
+            }
+
+            impl DynamicFields for #switch_struct_ident {
+                fn field_names(&self) -> &'static [&'static str] {
+                    match self {
+                        #(Self::#variant_idents(x) => x.field_names(),)*
+                    }
+                }
+
+                fn get_field(&self, name: &str) -> Option<FieldValue> {
+                    match self {
+                        #(Self::#variant_idents(x) => x.get_field(name),)*
+                    }
+                }
+
+                fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+                    match self {
+                        #(Self::#variant_idents(x) => x.set_field(name, value),)*
+                    }
+                }
+            }
+        });
+
         let type_names = variants.iter().map(|Variant { ident, data }| {
             let ty = &data.ident;
             quote!(Self::#ident(_) => #ty::type_name(),)
@@ -413,6 +474,30 @@ impl CodegenState {
             }
         });
 
+        let macro_name = format_ident!(
+            "match_{}",
+            ident_str.from_case(Case::Pascal).to_case(Case::Snake)
+        );
+        let macro_matches = variants.iter().map(|v| {
+            let name = &v.ident;
+            quote!($crate::schema::#switch_struct_ident::#name($inner) => $body,)
+        });
+        let macro_doc = format!(
+            " Runs the same code for every variant of [{ident_str}], binding the inner item \
+            to the given pattern"
+        );
+        blocks.push(quote! {
+            #[doc = #macro_doc]
+            #[macro_export]
+            macro_rules! #macro_name {
+                ($value:expr, |$inner:pat_param| $body:expr) => {
+                    match $value {
+                        #(#macro_matches)*
+                    }
+                };
+            }
+        });
+
         Ok(quote! {
             #(#blocks)*
         })
@@ -0,0 +1,193 @@
+use std::path::{Path, PathBuf};
+
+use miette::{Context, IntoDiagnostic, Result};
+
+use codegen_schema::schema::SchemaItem;
+
+use crate::codegen::CodegenState;
+
+mod codegen;
+
+/// Attributes prepended to the generated Rust code by default
+pub const DEFAULT_RUST_HEADER: &str = "\
+    #![allow(clippy::large_enum_variant)]\n\
+    #![allow(clippy::op_ref)]\n\
+    #![allow(dead_code)]\n\
+    #![allow(unused_variables)]\n\
+    #![allow(unreachable_patterns)]\n\
+    #![allow(clippy::match_single_binding)]\n\n";
+
+#[derive(Debug, Clone)]
+pub struct CodegenOptions {
+    /// Code prepended to the generated Rust file
+    pub rust_header: String,
+    /// Adds an `unknown_fields` map to the generated structs, keeping the
+    /// fields missing from the schema through a load and save
+    pub preserve_unknown: bool,
+}
+
+impl Default for CodegenOptions {
+    fn default() -> Self {
+        Self {
+            rust_header: DEFAULT_RUST_HEADER.to_string(),
+            preserve_unknown: false,
+        }
+    }
+}
+
+/// Code generator for the Event Horizon schema
+#[derive(Debug, Clone, Default)]
+pub struct Codegen {
+    options: CodegenOptions,
+}
+
+impl Codegen {
+    pub fn new(options: CodegenOptions) -> Self {
+        Self { options }
+    }
+
+    /// Generates code for the schema items
+    ///
+    /// Paths are only used for the comments and error messages, and are
+    /// expected to be relative to the schema directory
+    pub fn generate(
+        &self,
+        schema_items: impl IntoIterator<Item = (PathBuf, SchemaItem)>,
+    ) -> Result<GeneratedArtifacts> {
+        let mut state = CodegenState {
+            preserve_unknown: self.options.preserve_unknown,
+            ..Default::default()
+        };
+
+        let mut items = vec![];
+        for (path, item) in schema_items {
+            let code = state
+                .codegen(item)
+                .and_then(CodegenState::format_tokens)
+                .with_context(|| {
+                    format!("Failed to generate code for file at `{}`", path.display())
+                })?;
+            items.push((path, code.unwrap_or_default()));
+        }
+
+        let core_db_item = state
+            .codegen_core_db_item()
+            .and_then(|c| CodegenState::format_tokens(Some(c)))
+            .with_context(|| "Failed to generate core DB item type".to_string())?
+            .unwrap_or_default();
+
+        let expressions = state
+            .codegen_expressions()
+            .and_then(|c| CodegenState::format_tokens(Some(c)))
+            .with_context(|| "Failed to generate expression types".to_string())?
+            .unwrap_or_default();
+
+        let helpers = state
+            .codegen_extra_functions()
+            .and_then(|c| CodegenState::format_tokens(Some(c)))
+            .with_context(|| "Failed to generate extra functions".to_string())?
+            .unwrap_or_default();
+
+        Ok(GeneratedArtifacts {
+            rust: RustArtifacts {
+                header: self.options.rust_header.clone(),
+                items,
+                core_db_item,
+                expressions,
+                helpers,
+            },
+        })
+    }
+
+    /// Loads the schema from the directory and generates code for it
+    pub fn generate_from_dir(&self, schema_dir: impl AsRef<Path>) -> Result<GeneratedArtifacts> {
+        let schema_dir = schema_dir.as_ref();
+        let files = codegen_schema::load_from_dir(schema_dir)?;
+        self.generate(files.into_iter().map(|(path, item)| {
+            let path = path
+                .strip_prefix(schema_dir)
+                .map(Path::to_path_buf)
+                .unwrap_or(path);
+            (path, item)
+        }))
+    }
+}
+
+/// Regenerates the schema code from the build script of the dependent crate
+///
+/// Writes the code into `schema.rs` in the output directory, only touching
+/// the file when the code changes, and asks cargo to rerun the script once
+/// the schema directory changes. Returns the path of the written file
+///
+/// Generated code lacks the crate-level attributes of
+/// [DEFAULT_RUST_HEADER], so it can be pulled in with `include!`. Module
+/// that includes the code should carry these attributes instead, and
+/// `rust_header` of the options is ignored
+pub fn build_script(
+    schema_dir: impl AsRef<Path>,
+    out_dir: impl AsRef<Path>,
+    options: CodegenOptions,
+) -> Result<PathBuf> {
+    let schema_dir = schema_dir.as_ref();
+    println!("cargo:rerun-if-changed={}", schema_dir.display());
+
+    let artifacts = Codegen::new(CodegenOptions {
+        rust_header: String::new(),
+        ..options
+    })
+    .generate_from_dir(schema_dir)?;
+
+    let path = out_dir.as_ref().join("schema.rs");
+    let code = artifacts.rust.source();
+    if fs_err::read_to_string(&path).ok().as_deref() != Some(code.as_str()) {
+        fs_err::write(&path, code)
+            .into_diagnostic()
+            .context("Failed to write generated schema code")?;
+    }
+    Ok(path)
+}
+
+/// Outputs of the code generation, grouped by target
+#[derive(Debug, Clone)]
+pub struct GeneratedArtifacts {
+    pub rust: RustArtifacts,
+}
+
+/// Generated Rust code, split into sections
+#[derive(Debug, Clone)]
+pub struct RustArtifacts {
+    pub header: String,
+    /// Code of each schema file, alongside the file path. Files that don't
+    /// produce any code have it empty
+    pub items: Vec<(PathBuf, String)>,
+    /// The `Item` enum, covering all database item types
+    pub core_db_item: String,
+    /// Metadata of the expression types
+    pub expressions: String,
+    pub helpers: String,
+}
+
+impl RustArtifacts {
+    /// Assembles all sections into a single source file
+    pub fn source(&self) -> String {
+        let mut code = self.header.clone();
+        for (path, item) in &self.items {
+            code += &format!("\n// {}\n", path.display());
+            code += item;
+        }
+        code += "\n// Core Database Item\n";
+        code += &self.core_db_item;
+        code += "\n// Expression types\n";
+        code += &self.expressions;
+        code += "\n// Helper functions\n";
+        code += &self.helpers;
+        code
+    }
+}
+
+/// Helper for wrapping a code block to help with contextualizing errors
+/// Better editor support but slightly worse ergonomic than a macro
+#[inline(always)]
+pub(crate) fn m_try<T>(func: impl FnOnce() -> Result<T>) -> Result<T> {
+    func()
+}
@@ -0,0 +1,18 @@
+//! Small recipes for the common modding tasks
+//!
+//! Each module builds a single piece of content into the given database,
+//! and is tested against the minimal database, so the recipes keep
+//! compiling and working as the API changes
+
+pub mod localization;
+pub mod loot_scaling;
+pub mod new_weapon;
+pub mod quest_branch;
+pub mod vanilla_patch;
+
+#[cfg(test)]
+fn test_database() -> eh_mod_dev::database::Database {
+    let db = eh_mod_dev::database::DatabaseHolder::ephemeral();
+    db_minimal::load_minimal(&db);
+    db
+}
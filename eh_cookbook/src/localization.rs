@@ -0,0 +1,69 @@
+//! Translated texts for the mod content
+//!
+//! Texts starting with `$` are looked up in the localization of the current
+//! game language, so items reference the keys instead of the text itself
+
+use eh_mod_dev::database::Database;
+use eh_mod_dev::schema::schema::QuestItemId;
+
+/// Builds the localization XML file from the key and text pairs
+pub fn localization_xml<'a>(entries: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<resources>\n");
+    for (key, text) in entries {
+        xml.push_str(&format!(
+            "  <string name=\"{}\">{}</string>\n",
+            escape(key),
+            escape(text)
+        ));
+    }
+    xml.push_str("</resources>\n");
+    xml
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Adds a quest item with the English and Russian name and description
+pub fn translated_item(db: &Database) -> QuestItemId {
+    db.insert_localization(
+        "English",
+        localization_xml([
+            ("CookbookRelic", "Ancient relic"),
+            ("CookbookRelicDesc", "Hums when the <Xmas> fleet is near"),
+        ]),
+    );
+    db.insert_localization(
+        "Russian",
+        localization_xml([
+            ("CookbookRelic", "Древняя реликвия"),
+            ("CookbookRelicDesc", "Гудит, когда рядом флот <Xmas>"),
+        ]),
+    );
+
+    db.new_quest_item("cookbook:relic")
+        .with(|item| {
+            item.with_name("$CookbookRelic")
+                .with_description("$CookbookRelicDesc")
+        })
+        .id
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_database;
+
+    #[test]
+    fn translated_item() {
+        let db = test_database();
+        super::translated_item(&db);
+
+        let english = db.insert_localization("English", "").unwrap();
+        assert!(english.contains(
+            "<string name=\"CookbookRelicDesc\">Hums when the &lt;Xmas&gt; fleet is near</string>"
+        ));
+    }
+}
@@ -0,0 +1,49 @@
+//! Loot rewards that scale with the difficulty
+
+use eh_mod_dev::database::Database;
+use eh_mod_dev::difficulty::{scale_loot, DifficultyProfile};
+use eh_mod_dev::schema::schema::{LootContent, LootId};
+
+/// Adds a money reward, scaled by `multiplier` on top of the base amount
+///
+/// Single items are scaled with [scale_loot], while the whole database is
+/// scaled at once with [DifficultyProfile], see [harder_game]
+pub fn money_reward(db: &Database, multiplier: f32) -> LootId {
+    let mut loot = LootContent::money()
+        .with_min_amount(100)
+        .with_max_amount(200)
+        .wrap();
+    scale_loot(&mut loot, multiplier);
+    db.new_loot("cookbook:money_reward")
+        .with(|l| l.with_loot(loot))
+        .id
+}
+
+/// Halves the rewards of all loot in the database
+pub fn harder_game(db: &Database) {
+    DifficultyProfile::new().with_loot_multiplier(0.5).apply(db);
+}
+
+#[cfg(test)]
+mod tests {
+    use eh_mod_dev::schema::schema::{Loot, LootContent};
+
+    use crate::test_database;
+
+    #[test]
+    fn money_reward() {
+        let db = test_database();
+        let id = super::money_reward(&db, 2.0);
+        let amounts = |db: &eh_mod_dev::database::Database| {
+            db.with_item::<Loot, _>(id, |l| match &l.loot {
+                LootContent::Money(m) => (m.min_amount, m.max_amount),
+                _ => panic!("Reward should be money"),
+            })
+            .unwrap()
+        };
+        assert_eq!(amounts(&db), (200, 400));
+
+        super::harder_game(&db);
+        assert_eq!(amounts(&db), (100, 200));
+    }
+}
@@ -0,0 +1,75 @@
+//! New weapon component, along with its ammunition and firing parameters
+
+use eh_mod_dev::database::Database;
+use eh_mod_dev::schema::color::Color;
+use eh_mod_dev::schema::schema::{
+    ActivationType, BulletBody, CellType, ComponentId, DamageType, ImpactEffect, ImpactEffectType,
+    WeaponClass, WeaponSlotType,
+};
+
+/// Adds a laser cannon that fires two shots per second, and returns its ID
+pub fn laser_cannon(db: &Database) -> ComponentId {
+    let stats = db.new_component_stats("cookbook:weapon_stats").id;
+
+    let ammo = db.new_ammunition("cookbook:laser_bolt").with(|ammo| {
+        ammo.with_body(
+            BulletBody::new()
+                .with_velocity(30.0)
+                .with_range(15.0)
+                .with_lifetime(1.0)
+                .with_size(0.5)
+                .with_color(Color::rgb(0x50, 0xC0, 0xFF)),
+        )
+        .with_effects(vec![ImpactEffect {
+            r#type: ImpactEffectType::Damage,
+            damage_type: DamageType::Energy,
+            power: 12.0,
+            factor: 0.0,
+        }])
+    });
+
+    let weapon = db.new_weapon("cookbook:laser_cannon").with(|weapon| {
+        weapon
+            .with_weapon_class(WeaponClass::Common)
+            .with_activation_type(ActivationType::Manual)
+            .with_fire_rate(2.0)
+            .with_shot_sound("shot_01")
+            .with_shot_effect_prefab("FlashAdditive")
+            .with_control_button_icon("controls_shot")
+    });
+
+    db.new_component("cookbook:laser_cannon", stats)
+        .with(|c| {
+            c.with_name("Laser cannon")
+                .with_description("Fires fast energy bolts")
+                .with_icon("gun1")
+                .with_color(Color::WHITE)
+                .with_layout("1")
+                .with_cell_type(CellType::Weapon.to_string())
+                .with_weapon_slot_type(WeaponSlotType::Cannon.to_string())
+                .with_weapon_id(weapon.id)
+                .with_ammunition_id(ammo.id)
+        })
+        .id
+}
+
+#[cfg(test)]
+mod tests {
+    use eh_mod_dev::schema::schema::{Ammunition, Component, Weapon};
+
+    use crate::test_database;
+
+    #[test]
+    fn laser_cannon() {
+        let db = test_database();
+        let id = super::laser_cannon(&db);
+
+        let (weapon, ammo) = db
+            .with_item::<Component, _>(id, |c| (c.weapon_id, c.ammunition_id))
+            .unwrap();
+        let fire_rate = db.with_item::<Weapon, _>(weapon.unwrap(), |w| w.fire_rate);
+        assert_eq!(fire_rate, Some(2.0));
+        let color = db.with_item::<Ammunition, _>(ammo.unwrap(), |a| a.body.color.clone());
+        assert_eq!(color.as_deref(), Some("#50C0FF"));
+    }
+}
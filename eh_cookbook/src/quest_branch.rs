@@ -0,0 +1,54 @@
+//! Quest with a dialog that branches into a reward or an early exit
+
+use eh_mod_dev::database::{Database, Remember};
+use eh_mod_dev::schema::schema::{LootContent, QuestId, QuestType, StartCondition};
+use quests::quests::QuestContext;
+use quests::{MSG_CANCEL, MSG_CONTINUE};
+
+/// Adds a quest that offers fuel to the player, and returns its ID
+pub fn fuel_offer(db: &Database) -> QuestId {
+    let fuel = db
+        .new_loot("cookbook:fuel_reward")
+        .with(|loot| loot.with_loot(LootContent::fuel().with_min_amount(5).with_max_amount(10)))
+        .id;
+
+    let mut ctx = QuestContext::new(db, "cookbook:fuel_offer", "offer");
+    ctx.branch().dialog_end("offer", "Need some fuel?", |d| {
+        d.action("Take it", |ctx| {
+            ctx.branch()
+                .receive_item("reward", fuel)
+                .dialog("thanks", "Safe travels", |d| d.next(MSG_CONTINUE))
+                .complete_quest()
+                .entrypoint()
+        })
+        .action(MSG_CANCEL, |ctx| ctx.branch().cancel_quest().entrypoint())
+    });
+
+    let mut quest = ctx.into_quest();
+    quest.name = "Fuel offer".to_string();
+    quest.quest_type = QuestType::Common;
+    quest.start_condition = StartCondition::Manual;
+    quest.remember(db).id
+}
+
+#[cfg(test)]
+mod tests {
+    use eh_mod_dev::schema::schema::Quest;
+    use quests::testing::{assert_no_dangling_transitions, assert_path};
+
+    use crate::test_database;
+
+    #[test]
+    fn fuel_offer() {
+        let db = test_database();
+        let id = super::fuel_offer(&db);
+
+        let quest = db.with_item::<Quest, _>(id, |q| q.clone()).unwrap();
+        assert_path(
+            &quest,
+            &["ShowDialog", "ReceiveItem", "ShowDialog", "CompleteQuest"],
+        );
+        assert_path(&quest, &["ShowDialog", "CancelQuest"]);
+        assert_no_dangling_transitions(&quest);
+    }
+}
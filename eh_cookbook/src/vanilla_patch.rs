@@ -0,0 +1,44 @@
+//! Changes to the items of the base game, instead of adding new ones
+//!
+//! Vanilla items are loaded into the database before the mod is built, and
+//! are referenced by their string IDs, which are mapped to the vanilla
+//! numeric IDs by the loaders
+
+use eh_mod_dev::database::Database;
+use eh_mod_dev::schema::color::Color;
+use eh_mod_dev::schema::schema::{Faction, ShipType};
+
+/// Recolors the infected faction and makes all starbases tougher
+pub fn tougher_starbases(db: &Database) {
+    db.with_item_mut::<Faction, _>("eh:infected", |faction| {
+        faction.set_color(Color::rgb(0x80, 0xFF, 0x40));
+    })
+    .expect("Infected faction should be present in the base game");
+
+    db.ship_iter_mut(|ships| {
+        for mut ship in ships.filter(|ship| ship.ship_type == ShipType::Starbase) {
+            ship.energy_resistance += 10.0;
+            ship.kinetic_resistance += 10.0;
+            ship.heat_resistance += 10.0;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use eh_mod_dev::schema::schema::{Faction, Ship};
+
+    use crate::test_database;
+
+    #[test]
+    fn tougher_starbases() {
+        let db = test_database();
+        let before = db.with_item::<Ship, _>("eh:starbase", |s| s.kinetic_resistance);
+        super::tougher_starbases(&db);
+
+        let after = db.with_item::<Ship, _>("eh:starbase", |s| s.kinetic_resistance);
+        assert_eq!(after, before.map(|r| r + 10.0));
+        let color = db.with_item::<Faction, _>("eh:infected", |f| f.color.clone());
+        assert_eq!(color.as_deref(), Some("#80FF40"));
+    }
+}
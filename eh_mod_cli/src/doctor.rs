@@ -0,0 +1,285 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+
+use eh_mod_dev::database::{MAPPINGS_BACKUP_NAME, MAPPINGS_NAME};
+use eh_mod_dev::schema::schema::Item;
+use smart_output::MANAGED_FILES_BACKUP_NAME;
+
+/// Checks the environment for the common problems
+#[derive(Debug, Parser)]
+#[command(name = "doctor")]
+pub struct DoctorArgs {
+    #[cfg(feature = "base_dir")]
+    pub base_dir: PathBuf,
+    pub output_dir: PathBuf,
+    pub output_mod: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CheckStatus {
+    Ok,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub message: String,
+    /// Suggested way of fixing the problem
+    pub fix: Option<String>,
+}
+
+impl CheckResult {
+    fn ok(name: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Ok,
+            message: message.into(),
+            fix: None,
+        }
+    }
+
+    fn warning(name: &'static str, message: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Warning,
+            message: message.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn error(name: &'static str, message: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Error,
+            message: message.into(),
+            fix: Some(fix.into()),
+        }
+    }
+}
+
+impl Display for CheckResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let status = match self.status {
+            CheckStatus::Ok => "ok",
+            CheckStatus::Warning => "warn",
+            CheckStatus::Error => "error",
+        };
+        write!(f, "[{status:>5}] {}: {}", self.name, self.message)?;
+        if let Some(fix) = &self.fix {
+            write!(f, "\n        fix: {fix}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs all checks, printing the results. Returns `false` if any of the
+/// checks failed
+pub fn run_doctor(args: &DoctorArgs) -> bool {
+    let results = check_all(args);
+    for result in &results {
+        println!("{result}");
+    }
+    results.iter().all(|r| r.status != CheckStatus::Error)
+}
+
+pub fn check_all(args: &DoctorArgs) -> Vec<CheckResult> {
+    let output = args.output_dir.as_path();
+    let mut results = vec![check_output_dir(output)];
+    if output.is_dir() {
+        results.push(check_trash(output));
+        results.push(check_backups(output));
+        results.push(check_output_files(output));
+    }
+    results.push(check_game_install(args.output_mod.as_deref()));
+    results.push(check_vanilla());
+    results
+}
+
+fn check_output_dir(output: &Path) -> CheckResult {
+    const NAME: &str = "output directory";
+    if !output.exists() {
+        return CheckResult::error(
+            NAME,
+            format!("`{}` does not exist", output.display()),
+            "create the directory, saving the database doesn't do this automatically",
+        );
+    }
+    if !output.is_dir() {
+        return CheckResult::error(
+            NAME,
+            format!("`{}` is not a directory", output.display()),
+            "point the output to a directory",
+        );
+    }
+    let probe = output.join(".eh_doctor_probe");
+    match std::fs::write(&probe, []).and_then(|_| std::fs::remove_file(&probe)) {
+        Ok(()) => CheckResult::ok(NAME, format!("`{}` is writable", output.display())),
+        Err(err) => CheckResult::error(
+            NAME,
+            format!("`{}` is not writable: {err}", output.display()),
+            "check the directory permissions",
+        ),
+    }
+}
+
+/// Stale output files are moved to trash, so a missing trash backend only
+/// surfaces once some file gets removed
+fn check_trash(output: &Path) -> CheckResult {
+    const NAME: &str = "trash";
+    let probe = output.join(".eh_doctor_trash_probe");
+    if let Err(err) = std::fs::write(&probe, []) {
+        return CheckResult::error(
+            NAME,
+            format!("failed to create a probe file: {err}"),
+            "check the output directory permissions",
+        );
+    }
+    match trash::delete(&probe) {
+        Ok(()) => CheckResult::ok(NAME, "files can be moved to trash"),
+        Err(err) => {
+            let _ = std::fs::remove_file(&probe);
+            CheckResult::error(
+                NAME,
+                format!("failed to move a file to trash: {err}"),
+                "install a trash implementation for your desktop environment, like `gio` or \
+                `trash-cli`, or set `SaveOptions::cleanup_policy` to `CleanupPolicy::Delete`",
+            )
+        }
+    }
+}
+
+fn check_backups(output: &Path) -> CheckResult {
+    const NAME: &str = "backups";
+    let present: Vec<_> = [MAPPINGS_BACKUP_NAME, MANAGED_FILES_BACKUP_NAME]
+        .into_iter()
+        .map(|name| output.join(name))
+        .filter(|path| path.exists())
+        .collect();
+    if present.is_empty() {
+        return CheckResult::ok(NAME, "no leftover backups from interrupted runs");
+    }
+    CheckResult::error(
+        NAME,
+        format!(
+            "previous run was interrupted, leaving {}",
+            present
+                .iter()
+                .map(|p| format!("`{}`", p.display()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        format!(
+            "rerun the build with `--accept-backup` or `--discard-backup` and `SaveOptions::recover_backup` set, or compare the backups with `{MAPPINGS_NAME}` and remove them once data integrity is ensured"
+        ),
+    )
+}
+
+/// Checks that the existing output can be read by the current schema
+fn check_output_files(output: &Path) -> CheckResult {
+    const NAME: &str = "schema compatibility";
+    let mut total = 0;
+    let mut invalid = vec![];
+    for entry in walkdir::WalkDir::new(output).into_iter().flatten() {
+        let path = entry.path();
+        if !entry.file_type().is_file() || path.extension().is_none_or(|ext| ext != "json") {
+            continue;
+        }
+        total += 1;
+        let parsed = std::fs::read(path)
+            .map_err(|err| err.to_string())
+            .and_then(|data| {
+                serde_json5::from_slice::<Item>(&data)
+                    .map(|_| ())
+                    .map_err(|err| err.to_string())
+            });
+        if let Err(err) = parsed {
+            invalid.push(format!("`{}`: {err}", path.display()));
+        }
+    }
+    if invalid.is_empty() {
+        return CheckResult::ok(NAME, format!("all {total} item files are readable"));
+    }
+    CheckResult::error(
+        NAME,
+        format!(
+            "{} of {total} item files can't be read:\n        {}",
+            invalid.len(),
+            invalid.join("\n        ")
+        ),
+        "the output was likely produced with a different schema version, update the files or remove them",
+    )
+}
+
+fn check_game_install(output_mod: Option<&Path>) -> CheckResult {
+    const NAME: &str = "game install";
+    if let Some(output_mod) = output_mod {
+        return match output_mod.parent() {
+            Some(dir) if dir.as_os_str().is_empty() || dir.is_dir() => CheckResult::ok(
+                NAME,
+                format!("mod file will be written to `{}`", output_mod.display()),
+            ),
+            _ => CheckResult::error(
+                NAME,
+                format!(
+                    "directory of the mod file `{}` does not exist",
+                    output_mod.display()
+                ),
+                "create the directory, or point the mod output to the game mods directory",
+            ),
+        };
+    }
+    match mods_dirs().into_iter().find(|dir| dir.is_dir()) {
+        Some(dir) => CheckResult::ok(
+            NAME,
+            format!("found game mods directory at `{}`", dir.display()),
+        ),
+        None => CheckResult::warning(
+            NAME,
+            "game mods directory was not found",
+            "run the game at least once, or pass the mod output path explicitly",
+        ),
+    }
+}
+
+/// Default locations of the game mods directory
+fn mods_dirs() -> Vec<PathBuf> {
+    const GAME_DIR: &str = "Zipagames/EventHorizon/Mods";
+    let mut dirs = vec![];
+    if let Some(profile) = std::env::var_os("USERPROFILE") {
+        dirs.push(
+            PathBuf::from(profile)
+                .join("AppData/LocalLow")
+                .join(GAME_DIR),
+        );
+    }
+    if let Some(home) = std::env::var_os("HOME") {
+        let home = PathBuf::from(home);
+        dirs.push(home.join(".config/unity3d").join(GAME_DIR));
+        dirs.push(home.join("Library/Application Support/Zipagames/EventHorizon/Mods"));
+    }
+    dirs
+}
+
+fn check_vanilla() -> CheckResult {
+    const NAME: &str = "vanilla data";
+    #[cfg(feature = "include_vanilla")]
+    {
+        let images = db_vanilla::asset_index()
+            .names(eh_mod_dev::schema::schema::AssetKind::Image)
+            .len();
+        CheckResult::ok(NAME, format!("included, referencing {images} images"))
+    }
+    #[cfg(not(feature = "include_vanilla"))]
+    {
+        CheckResult::warning(
+            NAME,
+            "vanilla database is not included",
+            "enable the `include_vanilla` feature of `eh_mod_cli` to build on top of vanilla content",
+        )
+    }
+}
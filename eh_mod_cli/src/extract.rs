@@ -0,0 +1,140 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+use walkdir::WalkDir;
+
+use eh_mod_dev::mod_format::{decode, has_header, ModEntry};
+use eh_mod_dev::schema::schema::Item;
+
+/// Unpacks the built-in database of the game into the layout expected by
+/// `db_vanilla`, for updating the bundled vanilla data after game updates
+#[derive(Debug, Parser)]
+#[command(name = "extract-vanilla")]
+pub struct ExtractArgs {
+    /// Game installation directory, or the database file itself
+    pub game_dir: PathBuf,
+    /// Directory to unpack the database into, usually `db/db_vanilla/vanilla`
+    pub out_dir: PathBuf,
+    /// Removes the existing content of the output directory
+    #[arg(long)]
+    pub overwrite: bool,
+}
+
+/// Extracts the database, returning `false` on failure
+pub fn run_extract(args: &ExtractArgs) -> bool {
+    match extract(args) {
+        Ok(count) => {
+            eprintln!("Extracted {count} files into `{}`", args.out_dir.display());
+            true
+        }
+        Err(err) => {
+            eprintln!("Failed to extract the vanilla database: {err}");
+            false
+        }
+    }
+}
+
+fn extract(args: &ExtractArgs) -> Result<usize, String> {
+    let source = locate_database(&args.game_dir)?;
+    eprintln!("Reading the database from `{}`", source.display());
+
+    let data = fs_err::read(&source).map_err(|e| e.to_string())?;
+    let file = decode(&data).map_err(|e| format!("`{}`: {e}", source.display()))?;
+
+    prepare_out_dir(&args.out_dir, args.overwrite)?;
+
+    let mut count = 0;
+    for entry in file.entries {
+        let (path, data) = match entry {
+            ModEntry::Data(data) => {
+                let item: Item = serde_json5::from_slice(&data)
+                    .map_err(|e| format!("Database contains an invalid item: {e}"))?;
+                (item_path(&item), data)
+            }
+            ModEntry::Image { name, data } => (PathBuf::from("Images").join(name), data),
+            ModEntry::Localization { name, data } => (
+                PathBuf::from("Localization").join(format!("{name}.xml")),
+                data,
+            ),
+            ModEntry::WaveAudio { name, data } => {
+                (PathBuf::from("Audio").join(format!("{name}.wav")), data)
+            }
+            ModEntry::OggAudio { name, data } => {
+                (PathBuf::from("Audio").join(format!("{name}.ogg")), data)
+            }
+        };
+        let path = args.out_dir.join(path);
+        if let Some(parent) = path.parent() {
+            fs_err::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs_err::write(&path, data).map_err(|e| e.to_string())?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Items are grouped by type and named by ID, since the database doesn't
+/// store the original file names
+fn item_path(item: &Item) -> PathBuf {
+    let ty = item.inner_type_name();
+    match item.id() {
+        Some(id) => PathBuf::from(ty).join(format!("{ty}_{id}.json")),
+        None => PathBuf::from("Settings").join(format!("{ty}.json")),
+    }
+}
+
+/// Finds the single database file inside the game directory
+fn locate_database(game_dir: &Path) -> Result<PathBuf, String> {
+    if game_dir.is_file() {
+        return Ok(game_dir.to_path_buf());
+    }
+    if !game_dir.is_dir() {
+        return Err(format!("`{}` does not exist", game_dir.display()));
+    }
+
+    let candidates: Vec<_> = WalkDir::new(game_dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .filter(|path| is_database_file(path))
+        .collect();
+
+    match candidates.as_slice() {
+        [] => Err(format!(
+            "no database file found in `{}`",
+            game_dir.display()
+        )),
+        [path] => Ok(path.clone()),
+        paths => Err(format!(
+            "found several database files, pass one of them instead of the game directory: {}",
+            paths
+                .iter()
+                .map(|p| format!("`{}`", p.display()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+fn is_database_file(path: &Path) -> bool {
+    let mut header = [0; 4];
+    fs_err::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .is_ok_and(|_| has_header(&header))
+}
+
+fn prepare_out_dir(out_dir: &Path, overwrite: bool) -> Result<(), String> {
+    let is_empty = fs_err::read_dir(out_dir).map_or(true, |mut dir| dir.next().is_none());
+    if !is_empty {
+        if !overwrite {
+            return Err(format!(
+                "`{}` is not empty, rerun with `--overwrite` to replace its content",
+                out_dir.display()
+            ));
+        }
+        fs_err::remove_dir_all(out_dir).map_err(|e| e.to_string())?;
+    }
+    fs_err::create_dir_all(out_dir).map_err(|e| e.to_string())
+}
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{FromArgMatches, Parser, Subcommand};
 use tracing_panic::panic_hook;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::EnvFilter;
@@ -12,27 +12,258 @@ pub use db_minimal;
 pub use db_vanilla;
 pub use eh_mod_dev as dev;
 
+use eh_mod_dev::database::backup::{
+    check_mappings_backup, resolve_mappings_backup, BackupResolution,
+};
+use eh_mod_dev::database::{DatabaseHolder, SaveOptions};
+use eh_mod_dev::diagnostic::context::DiagnosticContext;
+use eh_mod_dev::hot_reload::ReloadTarget;
+use eh_mod_dev::profile::ValidationProfile;
+use eh_mod_dev::reporting::{report_diagnostics_with, ReportFormat, Severity};
+
+use crate::doctor::{run_doctor, DoctorArgs};
+use crate::extract::{run_extract, ExtractArgs};
+use crate::replay::{run_replay, ReplayArgs};
+use crate::stress::{run_stress, StressArgs};
+
+pub mod doctor;
+pub mod extract;
+pub mod progress;
+pub mod replay;
+pub mod stress;
+
 #[derive(Debug, Parser)]
 pub struct Args {
     #[cfg(feature = "base_dir")]
     pub base_dir: PathBuf,
     pub output_dir: PathBuf,
     pub output_mod: Option<PathBuf>,
+    /// Restores ID mappings from the backup left over by the interrupted
+    /// build
+    #[arg(long, conflicts_with = "discard_backup")]
+    pub accept_backup: bool,
+    /// Removes the backup left over by the interrupted build, keeping the
+    /// current ID mappings
+    #[arg(long)]
+    pub discard_backup: bool,
+    /// Records database mutations into the file, to be re-applied with the
+    /// `replay` command. Applied by [Args::apply_to]
+    #[arg(long)]
+    pub journal: Option<PathBuf>,
+    /// Notifies the running game about the changed files after each save,
+    /// either via `tcp://host:port` or by writing the changes into the
+    /// file. Applied by [Args::apply_to]
+    #[arg(long)]
+    pub hot_reload: Option<ReloadTarget>,
+    /// Check levels of the diagnostics: `strict`, `vanilla-compat` or
+    /// `permissive`. Applied by [Args::apply_to]
+    #[arg(long, env = "EH_VALIDATION_PROFILE", default_value_t = ValidationProfile::VanillaCompat)]
+    pub validation_profile: ValidationProfile,
+    #[command(flatten)]
+    pub report: ReportArgs,
+    #[command(flatten)]
+    pub threads: ThreadArgs,
+}
+
+impl Args {
+    /// Applies the `--journal`, `--hot-reload` and `--validation-profile`
+    /// flags to the database, replacing its save options
+    ///
+    /// Journal only records the mutations made after this call, so it
+    /// should be called right after loading the base database
+    pub fn apply_to(&self, db: &DatabaseHolder) {
+        if let Some(journal) = &self.journal {
+            db.record_journal(journal)
+                .expect("Should be able to create the journal file");
+        }
+        db.set_save_options(SaveOptions {
+            reload: self.hot_reload.clone(),
+            validation_profile: self.validation_profile,
+            ..Default::default()
+        });
+    }
+}
+
+/// Commands that run instead of the mod build
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    Doctor(DoctorArgs),
+    ExtractVanilla(ExtractArgs),
+    Replay(ReplayArgs),
+    Stress(StressArgs),
+}
+
+impl Command {
+    /// Runs the command, returning whether it succeeded
+    pub fn run(&self) -> bool {
+        match self {
+            Command::Doctor(args) => run_doctor(args),
+            Command::ExtractVanilla(args) => run_extract(args),
+            Command::Replay(args) => {
+                args.threads.configure();
+                run_replay(args)
+            }
+            Command::Stress(args) => {
+                args.threads.configure();
+                run_stress(args)
+            }
+        }
+    }
+}
+
+/// Parsed command line, either the mod build or one of the [Command]s
+#[derive(Debug)]
+pub enum Invocation<A> {
+    Build(A),
+    Command(Command),
+}
+
+impl<A: EhArgs> Invocation<A> {
+    /// Parses the arguments of the build, with the commands added as the
+    /// subcommands. The build arguments are positional, so the first
+    /// argument is treated as a command whenever it matches one by name
+    pub fn try_parse_from<I, T>(args: I) -> Result<Self, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        // Only the subcommands are taken, since augmenting the build
+        // directly replaces its description with the one of [Command]
+        let commands = Command::augment_subcommands(clap::Command::default());
+        let mut command = A::command()
+            .subcommands(commands.get_subcommands().cloned())
+            .subcommand_negates_reqs(true)
+            .args_conflicts_with_subcommands(true);
+        let matches = command.try_get_matches_from_mut(args)?;
+        let invocation = if matches.subcommand().is_some() {
+            Command::from_arg_matches(&matches).map(Invocation::Command)
+        } else {
+            A::from_arg_matches(&matches).map(Invocation::Build)
+        };
+        invocation.map_err(|err| err.format(&mut command))
+    }
+}
+
+/// Arguments of the mod build that extend the standard [Args]
+///
+/// Build still has to apply the standard flags to its database via
+/// [Args::apply_to]
+///
+/// Standard arguments are usually included via `#[command(flatten)]`:
+///
+/// ```ignore
+/// #[derive(Debug, clap::Parser)]
+/// struct MyArgs {
+///     #[command(flatten)]
+///     eh: Args,
+///     #[arg(long)]
+///     seed: Option<u64>,
+/// }
+///
+/// impl EhArgs for MyArgs {
+///     fn eh_args(&self) -> &Args {
+///         &self.eh
+///     }
+/// }
+/// ```
+pub trait EhArgs: Parser {
+    fn eh_args(&self) -> &Args;
+}
+
+impl EhArgs for Args {
+    fn eh_args(&self) -> &Args {
+        self
+    }
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct ReportArgs {
+    /// Output format of the diagnostics: `pretty`, `compact`, `json` or
+    /// `quiet`
+    #[arg(long, default_value_t = ReportFormat::Pretty)]
+    pub report_format: ReportFormat,
+    /// Lowest severity of the diagnostics that fails the build: `warning`
+    /// or `error`
+    #[arg(long, default_value_t = Severity::Error)]
+    pub fail_on: Severity,
+}
+
+impl ReportArgs {
+    /// Reports the diagnostics in the selected format, exiting with a
+    /// non-zero code if any of them reached the severity threshold
+    pub fn report(&self, ctx: DiagnosticContext) {
+        let summary = report_diagnostics_with(ctx, self.report_format.reporter().as_mut());
+        let code = summary.exit_code(self.fail_on);
+        if code != 0 {
+            std::process::exit(code);
+        }
+    }
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct ThreadArgs {
+    /// Number of the worker threads, defaults to the number of CPUs
+    #[arg(long, env = "EH_THREADS")]
+    pub threads: Option<usize>,
+    /// Stack size of the worker threads in bytes, defaults to the rayon
+    /// default
+    #[arg(long, env = "EH_RAYON_STACK_SIZE")]
+    pub rayon_stack_size: Option<usize>,
+    /// Leaves the global rayon pool as is, for when the build is embedded
+    /// into the application that configures rayon by itself
+    #[arg(long, env = "EH_NO_GLOBAL_POOL")]
+    pub no_global_pool: bool,
+}
+
+impl ThreadArgs {
+    /// Installs the global rayon pool, unless `--no-global-pool` is set
+    ///
+    /// Fails if the global pool was already initialized, either explicitly
+    /// or by running any parallel work beforehand
+    pub fn install_global_pool(&self) -> Result<(), rayon::ThreadPoolBuildError> {
+        if self.no_global_pool {
+            return Ok(());
+        }
+        let mut builder =
+            rayon::ThreadPoolBuilder::new().num_threads(self.threads.unwrap_or_else(num_cpus::get));
+        if let Some(stack_size) = self.rayon_stack_size {
+            builder = builder.stack_size(stack_size);
+        }
+        builder.build_global()
+    }
+
+    /// Same as [ThreadArgs::install_global_pool], but reports the failure
+    /// and proceeds with the existing pool
+    pub fn configure(&self) {
+        if let Err(err) = self.install_global_pool() {
+            eprintln!("Failed to configure the thread pool, using the existing one: {err}");
+        }
+    }
 }
 
 pub fn run_main(build: impl FnOnce(Args)) {
+    run_main_with::<Args>(build)
+}
+
+/// Same as [run_main], but parses the custom arguments, so mods can add
+/// their own flags
+pub fn run_main_with<A: EhArgs>(build: impl FnOnce(A)) {
     let subscriber = tracing_subscriber::Registry::default()
         .with(tracing_subscriber::fmt::Layer::default().pretty())
         .with(EnvFilter::from_default_env());
 
     tracing::subscriber::set_global_default(subscriber).unwrap();
 
-    rayon::ThreadPoolBuilder::new()
-        .num_threads(num_cpus::get())
-        .build_global()
-        .unwrap();
-
-    let args = Args::parse();
+    let args = match Invocation::<A>::try_parse_from(std::env::args_os()) {
+        Ok(Invocation::Build(args)) => args,
+        Ok(Invocation::Command(command)) => {
+            let success = command.run();
+            std::process::exit(if success { 0 } else { 1 });
+        }
+        Err(err) => err.exit(),
+    };
+    args.eh_args().threads.configure();
+    handle_mappings_backup(args.eh_args());
 
     color_backtrace::install();
     let prev_hook = std::panic::take_hook();
@@ -43,3 +274,65 @@ pub fn run_main(build: impl FnOnce(Args)) {
 
     build(args)
 }
+
+fn handle_mappings_backup(args: &Args) {
+    let resolution = if args.accept_backup {
+        BackupResolution::Accept
+    } else if args.discard_backup {
+        BackupResolution::Discard
+    } else {
+        if let Err(err) = check_mappings_backup(&args.output_dir) {
+            eprintln!("{err}");
+            eprintln!("Rerun with `--accept-backup` or `--discard-backup` to resolve");
+            std::process::exit(1);
+        }
+        return;
+    };
+    if let Err(err) = resolve_mappings_backup(&args.output_dir, resolution) {
+        eprintln!("Failed to resolve the mappings backup: {err}");
+        std::process::exit(1);
+    }
+}
+
+// Build arguments are shifted by the base directory
+#[cfg(all(test, not(feature = "base_dir")))]
+mod tests {
+    use std::path::Path;
+
+    use super::{Args, Command, Invocation};
+
+    fn parse(args: &[&str]) -> Invocation<Args> {
+        Invocation::try_parse_from(std::iter::once("eh_mod").chain(args.iter().copied())).unwrap()
+    }
+
+    #[test]
+    fn build_is_default() {
+        let Invocation::Build(args) = parse(&["output", "--journal", "journal.jsonl"]) else {
+            panic!("Expected the build");
+        };
+        assert_eq!(args.output_dir, Path::new("output"));
+        assert_eq!(args.journal.as_deref(), Some(Path::new("journal.jsonl")));
+    }
+
+    #[test]
+    fn commands() {
+        assert!(matches!(
+            parse(&["doctor", "output"]),
+            Invocation::Command(Command::Doctor(_))
+        ));
+        assert!(matches!(
+            parse(&["replay", "journal.jsonl", "output"]),
+            Invocation::Command(Command::Replay(args)) if args.output_dir == Path::new("output")
+        ));
+        assert!(matches!(
+            parse(&["stress", "output"]),
+            Invocation::Command(Command::Stress(_))
+        ));
+    }
+
+    #[test]
+    fn commands_reject_build_flags() {
+        let args = ["eh_mod", "doctor", "output", "--journal", "journal.jsonl"];
+        assert!(Invocation::<Args>::try_parse_from(args).is_err());
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use eh_mod_dev::database::{FlushEvent, FlushProgress};
+
+/// Files processed between the progress updates
+const UPDATE_INTERVAL: usize = 100;
+
+/// Progress receiver that prints the number of the processed output files
+/// to stderr, to be set as `SaveOptions::progress`
+pub fn terminal_progress() -> FlushProgress {
+    let total = Arc::new(AtomicUsize::new(0));
+    let done = Arc::new(AtomicUsize::new(0));
+    FlushProgress::new(move |event| match event {
+        FlushEvent::Started { files } => {
+            total.store(files, Ordering::Relaxed);
+            done.store(0, Ordering::Relaxed);
+        }
+        FlushEvent::Skipped(_) | FlushEvent::Written(_) => {
+            let done = done.fetch_add(1, Ordering::Relaxed) + 1;
+            let total = total.load(Ordering::Relaxed);
+            if done.is_multiple_of(UPDATE_INTERVAL) || done == total {
+                eprint!("\rWriting output files: {done}/{total}");
+            }
+        }
+        FlushEvent::Cleanup { files } if files > 0 => {
+            eprint!("\nRemoving {files} stale files");
+        }
+        FlushEvent::Finished => eprintln!(),
+        FlushEvent::Cleanup { .. } | FlushEvent::Removed(_) => {}
+    })
+}
@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use eh_mod_dev::database::database;
+use eh_mod_dev::reporting::report_diagnostics;
+
+use crate::ThreadArgs;
+
+/// Re-applies the recorded journal onto a fresh base database and saves
+/// the result
+#[derive(Debug, Parser)]
+#[command(name = "replay")]
+pub struct ReplayArgs {
+    /// Journal file written by the build ran with `--journal`
+    pub journal: PathBuf,
+    pub output_dir: PathBuf,
+    pub output_mod: Option<PathBuf>,
+    #[command(flatten)]
+    pub threads: ThreadArgs,
+}
+
+/// Replays the journal, returning `false` if replay or the saved database
+/// had errors
+pub fn run_replay(args: &ReplayArgs) -> bool {
+    if let Err(err) = fs_err::create_dir_all(&args.output_dir) {
+        eprintln!("{err}");
+        return false;
+    }
+    let db = database(&args.output_dir, args.output_mod.as_ref());
+
+    #[cfg(feature = "include_vanilla")]
+    db_vanilla::load_vanilla(&db, Default::default());
+    #[cfg(all(feature = "include_minimal", not(feature = "include_vanilla")))]
+    db_minimal::load_minimal(&db);
+
+    match db.replay_journal(&args.journal) {
+        Ok(count) => eprintln!("Replayed {count} journal entries"),
+        Err(err) => {
+            eprintln!("Failed to replay the journal: {err}");
+            return false;
+        }
+    }
+
+    report_diagnostics(db.save()).errors == 0
+}
@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+
+use eh_mod_dev::database::{database, Database};
+use eh_mod_dev::random::ItemGenerator;
+use eh_mod_dev::reporting::{report_diagnostics_with, ReportFormat};
+
+use crate::ThreadArgs;
+
+/// Fills the database with random items and measures how long it takes to
+/// save it, to catch the performance regressions
+///
+/// The database is saved twice, the second save has the same items and
+/// should skip writing all of them
+#[derive(Debug, Parser)]
+#[command(name = "stress")]
+pub struct StressArgs {
+    pub output_dir: PathBuf,
+    /// Number of the random items to add
+    #[arg(long, default_value_t = 10_000)]
+    pub items: usize,
+    #[arg(long, default_value_t = 0)]
+    pub seed: u64,
+    /// Directory of the items to derive the random ones from, in addition
+    /// to the included base database
+    #[arg(long)]
+    pub templates: Option<PathBuf>,
+    #[command(flatten)]
+    pub threads: ThreadArgs,
+}
+
+/// Runs the stress test, returning `false` if there were no template items
+pub fn run_stress(args: &StressArgs) -> bool {
+    if let Err(err) = fs_err::create_dir_all(&args.output_dir) {
+        eprintln!("{err}");
+        return false;
+    }
+
+    for run in ["fresh", "unchanged"] {
+        let start = Instant::now();
+        let db = database(&args.output_dir, None::<PathBuf>);
+        load_templates(&db, args);
+        let mut generator = ItemGenerator::from_database(&db, args.seed);
+        if generator.is_empty() {
+            eprintln!("No template items, pass `--templates` or enable the included database");
+            return false;
+        }
+        let loaded = start.elapsed();
+
+        let start = Instant::now();
+        db.add_random_items(&mut generator, args.items);
+        let generated = start.elapsed();
+
+        let start = Instant::now();
+        let ctx = db.save();
+        let saved = start.elapsed();
+
+        // Random items are not expected to pass the validation, only the
+        // timings are of interest
+        report_diagnostics_with(ctx, ReportFormat::Quiet.reporter().as_mut());
+        eprintln!(
+            "Save ({run}): load {}, generate {}, save {}",
+            format_duration(loaded),
+            format_duration(generated),
+            format_duration(saved)
+        );
+    }
+
+    true
+}
+
+fn load_templates(db: &Database, args: &StressArgs) {
+    #[cfg(feature = "include_vanilla")]
+    db_vanilla::load_vanilla(db, Default::default());
+    #[cfg(all(feature = "include_minimal", not(feature = "include_vanilla")))]
+    db_minimal::load_minimal(db);
+    if let Some(dir) = &args.templates {
+        db.load_from_dir(dir);
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2}s", duration.as_secs_f64())
+}
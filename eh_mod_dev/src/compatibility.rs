@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use eh_schema::schema::{DatabaseItem, DynamicFields, FieldValue, Node, Quest, QuestItem};
+
+use crate::database::DatabaseHolder;
+
+/// Savegame-relevant IDs of a released build
+///
+/// Savegames reference quests, their nodes and quest items by numeric IDs,
+/// so once a build is released, these IDs must keep their meaning. The
+/// snapshot is compared against the database on save, see
+/// [DatabaseHolder::guard_compatibility]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompatibilitySnapshot {
+    pub quests: BTreeMap<i32, FrozenQuest>,
+    /// String IDs of the quest items
+    pub quest_items: BTreeMap<i32, Option<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrozenQuest {
+    pub string_id: Option<String>,
+    /// Types of the quest nodes
+    pub nodes: BTreeMap<i32, String>,
+}
+
+/// Way of reporting changes relative to the [CompatibilitySnapshot]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompatibilityPolicy {
+    /// Changes are reported as errors, failing the build
+    #[default]
+    Strict,
+    /// Changes are reported as warnings
+    Warn,
+}
+
+/// Single ID that changed its meaning since the snapshot was taken
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompatibilityBreak {
+    /// Type name of the item the ID belongs to
+    pub type_name: &'static str,
+    pub item_id: i32,
+    /// Human-readable description of the ID, like `Node 5`
+    pub what: String,
+    pub frozen: String,
+    /// `None` if the ID is no longer present
+    pub current: Option<String>,
+}
+
+/// Snapshot to check the database against, stored as a database extra
+#[derive(Debug, Default)]
+pub(crate) struct CompatibilityGuard {
+    pub snapshot: Option<(CompatibilitySnapshot, CompatibilityPolicy)>,
+}
+
+impl CompatibilitySnapshot {
+    /// Reads the snapshot written by [CompatibilitySnapshot::save]
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let data = fs_err::read_to_string(path)?;
+        serde_json::from_str(&data)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let code =
+            serde_json::to_string_pretty(self).expect("Should be able to serialize snapshot");
+        fs_err::write(path, code)
+    }
+
+    /// Lists all IDs of this snapshot that are missing or mean something
+    /// else in the `current` snapshot
+    ///
+    /// IDs added since the snapshot was taken are not considered breaking
+    pub fn breaks(&self, current: &CompatibilitySnapshot) -> Vec<CompatibilityBreak> {
+        let mut breaks = vec![];
+        for (id, frozen) in &self.quests {
+            let Some(quest) = current.quests.get(id) else {
+                breaks.push(CompatibilityBreak {
+                    type_name: Quest::type_name(),
+                    item_id: *id,
+                    what: format!("Quest {id}"),
+                    frozen: display_name(&frozen.string_id),
+                    current: None,
+                });
+                continue;
+            };
+            if quest.string_id != frozen.string_id {
+                breaks.push(CompatibilityBreak {
+                    type_name: Quest::type_name(),
+                    item_id: *id,
+                    what: format!("Quest {id}"),
+                    frozen: display_name(&frozen.string_id),
+                    current: Some(display_name(&quest.string_id)),
+                });
+            }
+            for (node_id, ty) in &frozen.nodes {
+                let current = quest.nodes.get(node_id);
+                if current != Some(ty) {
+                    breaks.push(CompatibilityBreak {
+                        type_name: Quest::type_name(),
+                        item_id: *id,
+                        what: format!("Node {node_id}"),
+                        frozen: ty.clone(),
+                        current: current.cloned(),
+                    });
+                }
+            }
+        }
+        for (id, frozen) in &self.quest_items {
+            let current = current.quest_items.get(id);
+            if current != Some(frozen) {
+                breaks.push(CompatibilityBreak {
+                    type_name: QuestItem::type_name(),
+                    item_id: *id,
+                    what: format!("Quest item {id}"),
+                    frozen: display_name(frozen),
+                    current: current.map(display_name),
+                });
+            }
+        }
+        breaks
+    }
+}
+
+impl DatabaseHolder {
+    /// Captures savegame-relevant IDs of the current database state
+    ///
+    /// Should be saved alongside each released build, and passed to
+    /// [DatabaseHolder::guard_compatibility] in the following builds
+    pub fn compatibility_snapshot(self: &Arc<Self>) -> CompatibilitySnapshot {
+        let inverse_ids = self.use_id_mappings(|ids| ids.get_inverse_ids());
+        let string_id =
+            |ty: &str, id: i32| inverse_ids.get(ty).and_then(|ids| ids.get(&id)).cloned();
+
+        let quests = self.quest_iter(|quests| {
+            quests
+                .map(|quest| {
+                    let nodes = quest
+                        .nodes
+                        .iter()
+                        .filter_map(|node| {
+                            Some((node_id(node)?, node.inner_type_name().to_string()))
+                        })
+                        .collect();
+                    let frozen = FrozenQuest {
+                        string_id: string_id(Quest::type_name(), quest.id.0),
+                        nodes,
+                    };
+                    (quest.id.0, frozen)
+                })
+                .collect()
+        });
+        let quest_items = self.quest_item_iter(|items| {
+            items
+                .map(|item| (item.id.0, string_id(QuestItem::type_name(), item.id.0)))
+                .collect()
+        });
+
+        CompatibilitySnapshot {
+            quests,
+            quest_items,
+        }
+    }
+
+    /// Checks the database against the snapshot of the released build on
+    /// save, reporting IDs that were removed or changed their meaning
+    pub fn guard_compatibility(
+        &self,
+        snapshot: CompatibilitySnapshot,
+        policy: CompatibilityPolicy,
+    ) {
+        self.extra_or_init::<CompatibilityGuard>().write().snapshot = Some((snapshot, policy));
+    }
+}
+
+pub(crate) fn node_id(node: &Node) -> Option<i32> {
+    match node.get_field("id") {
+        Some(FieldValue::Int(id)) => id.try_into().ok(),
+        _ => None,
+    }
+}
+
+fn display_name(string_id: &Option<String>) -> String {
+    match string_id {
+        Some(id) => format!("`{id}`"),
+        None => "an unnamed item".to_string(),
+    }
+}
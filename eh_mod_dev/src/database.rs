@@ -5,28 +5,67 @@ use std::fmt::{Debug, Formatter};
 use std::ops::{DerefMut, Range};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Instant;
 
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 use parking_lot::{Mutex, RwLock};
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 use serde::{Deserialize, Serialize};
-use tracing::{error, error_span, info};
+use thiserror::Error;
+use tracing::{error, error_span, info, warn};
 
-use crate::builder::{ModBuilderData, ModBuilderInfo};
+use crate::database::backup::check_mappings_backup;
+use crate::database::checkpoint::CheckpointExtra;
+use crate::database::collision::{CollisionPolicy, ItemCollision};
 pub use crate::database::db_item::DbItem;
+use crate::database::db_item::PendingBatch;
 use crate::database::extra_item::ExtraItem;
 pub use crate::database::iters::{DatabaseItemIter, DatabaseItemIterMut};
+use crate::database::journal::{Journal, JournalEntry};
+pub use crate::database::macro_impls::{ItemTypeInfo, ITEM_TYPES};
+use crate::database::provenance::Provenance;
 pub use crate::database::stored_db_item::StoredDbItem;
-pub use crate::mapping::DatabaseIdLike;
-use crate::mapping::{IdIter, IdMapping, IdMappingSerialized, KindProvider, RegexIter};
+use crate::hot_reload::{ChangeSet, ReloadTarget};
+pub use crate::mapping::{DatabaseIdLike, IdAllocationError, IdExhaustionPolicy};
+use crate::mapping::{
+    IdIter, IdMapping, IdMappingSerialized, IdUsageReport, KindProvider, OptionalDatabaseIdLike,
+    RegexIter,
+};
+use crate::mod_format::{ModBuilderData, ModBuilderInfo};
+use crate::profile::ValidationProfile;
+use crate::utils::{gunzip, gzip};
+use crate::validators::{
+    check_references, validate_compatibility, validate_component_fit, validate_engine_limits,
+    validate_expressions, validate_image_names, validate_images, validate_loadouts,
+    validate_mod_registry, validate_relations, validate_requirements, validate_save_checks,
+};
 use diagnostic::context::DiagnosticContext;
+use diagnostic::diagnostic::DiagnosticKind;
 use eh_schema::schema::{DatabaseItem, DatabaseItemId, DatabaseSettings, Item};
-use smart_output::SmartOutput;
-
+use smart_output::interrupt::CriticalSection;
+pub use smart_output::metrics::{Metric, MetricsSink};
+pub use smart_output::{
+    CancelToken, CleanupPolicy, DiffPolicy, EditPolicy, FlushEvent, FlushParallelism,
+    FlushProgress, HashAlgorithm, ReadOnlyPolicy, SymlinkPolicy,
+};
+use smart_output::{OutputOptions, SmartOutput};
+
+pub mod backup;
+pub mod changes;
+pub mod checkpoint;
+pub mod collision;
+pub mod comments;
+pub mod csv;
 pub mod db_item;
+pub mod dedup;
+pub mod derive;
 pub mod extra_item;
+pub mod images;
 pub mod iters;
+pub mod journal;
+pub mod provenance;
 pub mod stored_db_item;
+pub mod texts;
 
 mod macro_impls;
 
@@ -40,13 +79,30 @@ pub fn database(
     )
 }
 
-const MAPPINGS_NAME: &str = "id_mappings.json5";
-const MAPPINGS_BACKUP_NAME: &str = "id_mappings.json5.backup";
+/// Name of the ID mappings file in the output directory
+pub const MAPPINGS_NAME: &str = "id_mappings.json5";
+/// Name of the ID mappings backup, which is only present while saving
+pub const MAPPINGS_BACKUP_NAME: &str = "id_mappings.json5.backup";
+const ID_USAGE_NAME: &str = "id_usage.json5";
+const COMMENTS_NAME: &str = "item_comments.json5";
+
+/// Histogram of the [DatabaseHolder::save] durations, in seconds, see
+/// [SaveOptions::metrics]
+pub const SAVE_SECONDS_METRIC: &str = "eh_mod_dev.save_seconds";
+/// Histogram of the save validation durations, in seconds
+pub const VALIDATE_SECONDS_METRIC: &str = "eh_mod_dev.validate_seconds";
+/// Counter of the item files written by the save
+pub const ITEMS_SAVED_METRIC: &str = "eh_mod_dev.items_saved";
 
 pub type Database = Arc<DatabaseHolder>;
 
 pub struct DatabaseHolder {
     inner: Mutex<DatabaseInner>,
+    /// Kept outside of the main lock, so ID allocation doesn't serialize
+    /// with the item storage
+    ids: IdMapping,
+    /// Active journal, see [DatabaseHolder::record_journal]
+    journal: RwLock<Option<Arc<Journal>>>,
 }
 
 impl Debug for DatabaseHolder {
@@ -64,14 +120,128 @@ type ItemsMap = Arc<RwLock<AHashMap<Option<i32>, SharedItem>>>;
 pub struct DatabaseInner {
     output_path: PathBuf,
     output_file_path: Option<PathBuf>,
-    ids: IdMapping,
     other_ids: AHashMap<Cow<'static, str>, Arc<RwLock<IdMapping>>>,
     items: AHashMap<&'static str, ItemsMap>,
     images: AHashMap<String, Arc<image::DynamicImage>>,
+    /// Localization XML files, by language
+    localizations: BTreeMap<String, String>,
     extras: AHashMap<TypeId, Arc<RwLock<dyn Any + Send + Sync>>>,
+    /// Extras included into checkpoints
+    checkpoint_extras: AHashMap<TypeId, CheckpointExtra>,
+    /// Origins of the stored items
+    provenance: AHashMap<(&'static str, Option<i32>), Provenance>,
+    /// Comments attached to the stored items
+    comments: AHashMap<(&'static str, Option<i32>), String>,
+    collision_policy: CollisionPolicy,
+    collisions: Vec<ItemCollision>,
+    save_options: SaveOptions,
+    /// Database was created via [DatabaseHolder::ephemeral]
+    ephemeral: bool,
     // items: Vec<Item>,
 }
 
+/// Format of the item files written by [DatabaseHolder::save]
+#[derive(Debug, Clone, Default)]
+pub struct SaveOptions {
+    /// Writes item files without indentation and line breaks
+    pub minify_json: bool,
+    /// Writes item files compressed, as `.json.gz`. Compressed files are
+    /// still read by [DatabaseHolder::load_from_dir], but the game can't
+    /// read them, so this is only useful alongside the mod file output
+    pub gzip: bool,
+    /// Handling of the output files that were modified by hand since the
+    /// last save
+    pub edit_policy: EditPolicy,
+    /// Refuse to save into a non-empty directory that was not written by
+    /// the previous saves
+    pub strict_adoption: bool,
+    /// Handling of the output files that are no longer written
+    pub cleanup_policy: CleanupPolicy,
+    /// Hash function used to detect the changes of the output files
+    pub hash_algorithm: HashAlgorithm,
+    /// Reconciles the managed files backup left over by the interrupted
+    /// save instead of panicking, see [OutputOptions::recover_backup]
+    pub recover_backup: bool,
+    /// Handling of the output files that are symlinks
+    pub symlink_policy: SymlinkPolicy,
+    /// Handling of the output files that are marked read-only
+    pub read_only_policy: ReadOnlyPolicy,
+    /// Logs the unified diffs of the rewritten text files, like the item
+    /// JSONs, see [DiffPolicy::Log]
+    pub log_diffs: bool,
+    /// Thread count and batching of writing the output files
+    pub parallelism: FlushParallelism,
+    /// Receiver of the progress of writing the output files, see
+    /// [SmartOutput::on_progress]
+    pub progress: Option<FlushProgress>,
+    /// Receiver of the save metrics, alongside the metrics of writing the
+    /// output files, see [smart_output::metrics]
+    pub metrics: Option<MetricsSink>,
+    /// Globs of the hand-authored files in the output directories, which
+    /// are never removed by the cleanup, see [smart_output::glob::Glob]
+    pub preserve: Vec<String>,
+    /// Directories for the non-item files
+    pub roots: OutputRoots,
+    /// Running game instance to notify about the changed files after the
+    /// save
+    pub reload: Option<ReloadTarget>,
+    /// Check levels applied to the diagnostics returned by the save
+    pub validation_profile: ValidationProfile,
+}
+
+/// Error of the [DatabaseHolder::save_with] that was cancelled
+#[derive(Debug, Error)]
+#[error("Database save was cancelled")]
+pub struct SaveCancelled;
+
+/// Output directories of the files written by [DatabaseHolder::save]
+///
+/// Item data, ID mappings and reports are always written into the database
+/// output directory. Other categories default to the output directory, but
+/// can be staged elsewhere, in which case each directory tracks its files
+/// separately
+#[derive(Debug, Clone, Default)]
+pub struct OutputRoots {
+    /// Directory for the inserted images, written into its `Images`
+    /// subdirectory
+    pub assets_root: Option<PathBuf>,
+    /// Directory for the inserted localizations, written into its
+    /// `Localization` subdirectory
+    pub localization_root: Option<PathBuf>,
+}
+
+type LoadFilter = Arc<dyn Fn(&Path, &Item) -> bool + Send + Sync>;
+
+/// Item selection for [DatabaseHolder::load_from_dir_with] and
+/// [DatabaseHolder::load_from_included_dir_with]
+#[derive(Clone, Default)]
+pub struct LoadOptions {
+    filter: Option<LoadFilter>,
+}
+
+impl Debug for LoadOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoadOptions")
+            .field("filter", &self.filter.is_some())
+            .finish()
+    }
+}
+
+impl LoadOptions {
+    /// Only loads items for which the predicate returns `true`
+    ///
+    /// Predicate receives the path of the item file, relative to the loaded
+    /// directory, and the parsed item
+    pub fn filter(mut self, filter: impl Fn(&Path, &Item) -> bool + Send + Sync + 'static) -> Self {
+        self.filter = Some(Arc::new(filter));
+        self
+    }
+
+    fn accepts(&self, path: &Path, item: &Item) -> bool {
+        self.filter.as_ref().is_none_or(|filter| filter(path, item))
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Default)]
 struct MappingsSerde {
     ids: IdMappingSerialized,
@@ -79,11 +249,66 @@ struct MappingsSerde {
     others: BTreeMap<Cow<'static, str>, IdMappingSerialized>,
 }
 
-fn check_no_backup(path: &Path) {
+fn check_no_backup(output_path: &Path) {
     let _guard =
-        error_span!("Checking for mapping backup file presence", path=%path.display()).entered();
-    if path.exists() {
-        panic!("Mappings backup file exists, this means that there was an error during the previous invocation, please manually check your ID files to avoid data corruption. Remove the file after data integrity is ensured.")
+        error_span!("Checking for mapping backup file presence", path=%output_path.display())
+            .entered();
+    if let Err(err) = check_mappings_backup(output_path) {
+        panic!("{err}")
+    }
+}
+
+/// Restores the ID mappings of the previous save from the backup
+fn rollback_mappings(mappings_path: &Path, backup_path: &Path, had_mappings: bool) {
+    if had_mappings {
+        fs_err::rename(backup_path, mappings_path)
+            .expect("Should be able to restore mappings file");
+    } else {
+        fs_err::remove_file(mappings_path).expect("Should be able to remove mappings file");
+        fs_err::remove_file(backup_path).expect("Should remove mappings backup file");
+    }
+}
+
+/// Builds a path of the item file, relative to the output directory
+pub(crate) fn item_file_name(
+    inverse_ids: &AHashMap<Cow<'static, str>, AHashMap<i32, String>>,
+    type_name: &str,
+    id: Option<i32>,
+) -> String {
+    let Some(id) = id else {
+        return format!("settings/{type_name}.json");
+    };
+
+    inverse_ids
+        .get(type_name)
+        .and_then(|ids| ids.get(&id))
+        .map(|id| {
+            let id = id.split(':').collect::<Vec<_>>();
+
+            format!("{}/{}/{}.json", id[0], type_name, id[1])
+        })
+        .unwrap_or_else(|| format!("auto/{type_name}/{id}.json"))
+}
+
+/// Checks whether the file is an item file, returning whether it's
+/// compressed
+fn item_file_compression(path: &Path) -> Option<bool> {
+    let name = path.file_name()?.to_str()?;
+    if name.ends_with(".json.gz") {
+        Some(true)
+    } else if name.ends_with(".json") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+fn parse_item_file(data: &[u8], compressed: bool) -> Item {
+    if compressed {
+        let data = gunzip(data).expect("Should be a valid gzip file");
+        serde_json5::from_slice(&data).expect("Should be a valid json")
+    } else {
+        serde_json5::from_slice(data).expect("Should be a valid json")
     }
 }
 
@@ -94,7 +319,9 @@ impl DatabaseHolder {
     /// # Panics
     /// Will panic if output path contains a mappings file but it can't be read or invalid
     ///
-    /// Will panic if mappings backup exists
+    /// Will panic if mappings backup exists, use
+    /// [check_mappings_backup](backup::check_mappings_backup) to handle it
+    /// beforehand
     pub fn new(output_path: PathBuf, output_mod_file_path: Option<PathBuf>) -> Database {
         let cur_dir = std::env::current_dir()
             .expect("Should be able to get current directory info from process env");
@@ -109,16 +336,35 @@ impl DatabaseHolder {
         }
 
         let mappings_path = output_path.join(MAPPINGS_NAME);
-        let mappings: MappingsSerde = mappings_path
-            .exists()
-            .then(|| {
-                let data = fs_err::read_to_string(output_path.join(MAPPINGS_NAME))
-                    .expect("Should be able to read mappings file");
-                serde_json5::from_str(&data).expect("Should be able to deserialize mappings file")
-            })
-            .unwrap_or_default();
+        let mappings: MappingsSerde = if mappings_path.exists() {
+            let data = fs_err::read_to_string(output_path.join(MAPPINGS_NAME))
+                .expect("Should be able to read mappings file");
+            serde_json5::from_str(&data).expect("Should be able to deserialize mappings file")
+        } else {
+            Default::default()
+        };
 
-        check_no_backup(&output_path.join(MAPPINGS_BACKUP_NAME));
+        check_no_backup(&output_path);
+        Self::from_parts(output_path, output_mod_file_path, mappings, false)
+    }
+
+    /// Constructs an in-memory database, that doesn't touch the file system
+    ///
+    /// IDs are allocated sequentially starting from 1, so they are the same
+    /// between runs. Meant for tests and examples, saving such database
+    /// will panic
+    pub fn ephemeral() -> Database {
+        let db = Self::from_parts(PathBuf::new(), None, Default::default(), true);
+        db.add_id_range(1..i32::MAX);
+        db
+    }
+
+    fn from_parts(
+        output_path: PathBuf,
+        output_mod_file_path: Option<PathBuf>,
+        mappings: MappingsSerde,
+        ephemeral: bool,
+    ) -> Database {
         let other_ids = mappings
             .others
             .into_iter()
@@ -129,38 +375,71 @@ impl DatabaseHolder {
             inner: Mutex::new(DatabaseInner {
                 output_path,
                 output_file_path: output_mod_file_path,
-                ids: IdMapping::new(mappings.ids),
                 other_ids,
                 items: Default::default(),
                 images: Default::default(),
+                localizations: Default::default(),
                 extras: Default::default(),
+                checkpoint_extras: Default::default(),
+                provenance: Default::default(),
+                comments: Default::default(),
+                collision_policy: Default::default(),
+                collisions: Default::default(),
+                save_options: Default::default(),
+                ephemeral,
             }),
+            ids: IdMapping::new(mappings.ids),
+            journal: Default::default(),
         };
         Arc::new(db)
     }
 
     /// Adds another ID range to use for all types
     pub fn add_id_range(&self, range: Range<i32>) {
-        self.lock(|db| db.ids.add_id_range(range));
+        self.ids.add_id_range(range);
     }
 
     /// Adds another ID range to use for one specified type
     pub fn add_id_range_for<T: 'static + DatabaseItem>(&self, range: Range<i32>) {
-        self.lock(|db| db.ids.add_id_range_for(T::type_name(), range));
+        self.ids.add_id_range_for(T::type_name(), range);
     }
 
     /// Clears allocated ID ranges for the specified type, preventing obtaining
     /// of new IDs until [add_id_range] or [add_id_range_for] are used to
     /// allocate new ID space for this type
     pub fn clear_id_ranges_for<T: 'static + DatabaseItem>(&mut self) {
-        self.lock(|db| db.ids.clear_id_ranges_for(T::type_name()));
+        self.ids.clear_id_ranges_for(T::type_name());
+    }
+
+    /// Sets the behavior of the ID allocation once allocated ranges are used up
+    pub fn set_id_exhaustion_policy(&self, policy: IdExhaustionPolicy) {
+        self.ids.set_exhaustion_policy(policy);
+    }
+
+    /// Summarizes allocated ID ranges, their usage and remaining capacity
+    /// for every type
+    ///
+    /// Same report is written into the output directory on save
+    pub fn id_usage_report(&self) -> IdUsageReport {
+        self.ids.usage_report()
     }
 
     /// Converts string ID into database item ID
     ///
     /// Aborts the execution if generating ID is not possible
     pub fn id<T: 'static + DatabaseItem>(&self, id: impl DatabaseIdLike<T>) -> DatabaseItemId<T> {
-        DatabaseItemId::new(self.lock(|db| id.into_id(&db.ids)))
+        DatabaseItemId::new(id.into_id(&self.ids))
+    }
+
+    /// Converts optional string ID into optional database item ID, to be
+    /// passed into builders of optional ID fields
+    ///
+    /// Aborts the execution if the ID is given but missing
+    pub fn opt_id<T: 'static + DatabaseItem, ID: DatabaseIdLike<T>>(
+        &self,
+        id: impl OptionalDatabaseIdLike<T, ID>,
+    ) -> Option<DatabaseItemId<T>> {
+        id.into_opt().map(|id| self.id(id))
     }
 
     /// Converts string ID into new database item ID
@@ -170,7 +449,19 @@ impl DatabaseHolder {
         &self,
         id: impl DatabaseIdLike<T>,
     ) -> DatabaseItemId<T> {
-        DatabaseItemId::new(self.lock(|db| id.into_new_id(&mut db.ids)))
+        DatabaseItemId::new(id.into_new_id(&self.ids))
+    }
+
+    /// Same as [DatabaseHolder::new_id], but returns an error instead of
+    /// panicking when ID is already used, or when generating ID is not
+    /// possible and [IdExhaustionPolicy::Error] is used
+    pub fn try_new_id<T: 'static + DatabaseItem>(
+        &self,
+        id: impl Into<String>,
+    ) -> Result<DatabaseItemId<T>, IdAllocationError> {
+        self.ids
+            .try_new_id(T::type_name(), id)
+            .map(DatabaseItemId::new)
     }
 
     /// Returns raw ID without checking if it exists or marking it as existing
@@ -178,7 +469,14 @@ impl DatabaseHolder {
         &self,
         id: impl Into<String>,
     ) -> DatabaseItemId<T> {
-        DatabaseItemId::new(self.lock(|db| db.ids.get_id_raw(T::type_name(), id)))
+        DatabaseItemId::new(self.ids.get_id_raw(T::type_name(), id))
+    }
+
+    /// Path of the item file relative to the output directory, which is
+    /// used by the diagnostics to refer to the item
+    pub fn item_file_name<T: DatabaseItem>(&self, id: DatabaseItemId<T>) -> String {
+        let inverse_ids = self.ids.get_inverse_ids();
+        item_file_name(&inverse_ids, T::type_name(), Some(id.0))
     }
 
     /// Forcefully assigns numeric ID to a string
@@ -187,31 +485,31 @@ impl DatabaseHolder {
         string_id: impl Into<String>,
         numeric_id: i32,
     ) -> DatabaseItemId<T> {
-        DatabaseItemId::new(self.lock(|db| db.ids.set_id(T::type_name(), string_id, numeric_id)))
+        DatabaseItemId::new(self.ids.set_id(T::type_name(), string_id, numeric_id))
     }
 
     pub fn forget_used_id<T: 'static + DatabaseItem>(&self, string_id: &str) {
-        self.lock(|db| db.ids.forget_used_id(T::type_name(), string_id))
+        self.ids.forget_used_id(T::type_name(), string_id)
     }
 
     pub fn is_id_used<T: 'static + DatabaseItem>(&self, string_id: &str) -> bool {
-        self.lock(|db| db.ids.is_used(T::type_name(), string_id))
+        self.ids.is_used(T::type_name(), string_id)
     }
 
-    pub fn iter_ids<T: 'static + DatabaseItem, U>(&self, func: impl FnOnce(IdIter) -> U) -> U {
-        self.lock(|db| func(db.ids.used_ids(T::kind())))
+    pub fn iter_ids<T: 'static + DatabaseItem, U>(&self, func: impl FnOnce(IdIter<'_>) -> U) -> U {
+        func(self.ids.used_ids(T::kind()).iter())
     }
 
     pub fn iter_ids_filtered<T: 'static + DatabaseItem, U>(
         &self,
         pat: &str,
-        func: impl FnOnce(RegexIter) -> U,
+        func: impl FnOnce(RegexIter<'_>) -> U,
     ) -> U {
-        self.lock(|db| func(db.ids.used_ids_filtered(pat, T::kind())))
+        func(self.ids.used_ids(T::kind()).filtered(pat))
     }
 
     pub fn get_id_name<T: 'static + DatabaseItem>(&self, id: DatabaseItemId<T>) -> Option<String> {
-        self.lock(|db| db.ids.get_inverse_id(T::type_name(), id.0))
+        self.ids.get_inverse_id(T::type_name(), id.0)
     }
 
     pub fn cached<T: 'static + DatabaseItem>(
@@ -232,6 +530,7 @@ impl DatabaseHolder {
     ///
     /// # Panics
     /// All items are stored behind a [Mutex], so regular runtime borrowing rules apply
+    #[track_caller]
     pub fn add_item<T: Into<Item> + DatabaseItem>(self: &Arc<Self>, item: T) -> DbItem<T> {
         DbItem::new(item, self.clone())
     }
@@ -240,13 +539,15 @@ impl DatabaseHolder {
         self.lock(|db| db.other_ids.entry(T::kind()).or_default().clone())
     }
 
-    pub fn use_id_mappings<T>(&self, func: impl FnOnce(&mut IdMapping) -> T) -> T {
-        self.lock(|db| func(&mut db.ids))
+    pub fn use_id_mappings<T>(&self, func: impl FnOnce(&IdMapping) -> T) -> T {
+        func(&self.ids)
     }
 
     /// Gets the item that was saved to the database previously
     ///
     /// All returned handles **must** be dropped before saving the database, otherwise a panic will occur.
+    /// Prefer [DatabaseHolder::with_item] and [DatabaseHolder::with_item_mut] for short edits, they
+    /// release the item right away.
     ///
     /// # Panics
     /// Each item is individually stored behind a [RwLock], so regular runtime borrowing rules apply
@@ -254,9 +555,9 @@ impl DatabaseHolder {
         self: &Arc<Self>,
         id: impl DatabaseIdLike<T>,
     ) -> Option<StoredDbItem<T>> {
+        let id = id.into_id(&self.ids);
         let mut db = self.inner.lock();
         let db = db.deref_mut();
-        let id = id.into_id(&db.ids);
 
         let item = db
             .items
@@ -267,6 +568,95 @@ impl DatabaseHolder {
         item
     }
 
+    /// Runs the closure with read access to the item saved to the
+    /// database previously, returning `None` if the item is missing
+    ///
+    /// Unlike [DatabaseHolder::get_item], the item is released as soon as
+    /// the closure returns, so it can't outlive the database save
+    ///
+    /// # Panics
+    /// Each item is individually stored behind a [RwLock], so regular runtime borrowing rules apply
+    pub fn with_item<T: Into<Item> + DatabaseItem + Any, R>(
+        self: &Arc<Self>,
+        id: impl DatabaseIdLike<T>,
+        func: impl FnOnce(&T) -> R,
+    ) -> Option<R> {
+        let item = self.get_item::<T>(id)?;
+        let result = func(&item.read());
+        Some(result)
+    }
+
+    /// Same as [DatabaseHolder::with_item], but provides write access to
+    /// the item
+    pub fn with_item_mut<T: Into<Item> + DatabaseItem + Any, R>(
+        self: &Arc<Self>,
+        id: impl DatabaseIdLike<T>,
+        func: impl FnOnce(&mut T) -> R,
+    ) -> Option<R> {
+        let item = self.get_item::<T>(id)?;
+        let result = func(&mut item.write());
+        Some(result)
+    }
+
+    /// Number of the stored items of the type, settings count as a single
+    /// item
+    pub fn count<T: Into<Item> + DatabaseItem + Any>(&self) -> usize {
+        self.lock(|db| {
+            db.items
+                .get(T::type_name())
+                .map_or(0, |items| items.read().len())
+        })
+    }
+
+    /// Checks whether the item was saved to the database
+    pub fn contains<T: Into<Item> + DatabaseItem + Any>(&self, id: impl DatabaseIdLike<T>) -> bool {
+        let id = id.into_id(&self.ids);
+        self.lock(|db| {
+            db.items
+                .get(T::type_name())
+                .is_some_and(|items| items.read().contains_key(&Some(id)))
+        })
+    }
+
+    /// Removes the item from the database, returning `false` if it was
+    /// missing
+    ///
+    /// Items referencing the removed one are left as is, and are reported
+    /// as unresolved references on save
+    pub fn remove_item<T: Into<Item> + DatabaseItem + Any>(
+        &self,
+        id: impl DatabaseIdLike<T>,
+    ) -> bool {
+        let id = id.into_id(&self.ids);
+        self.remove_stored(T::type_name(), Some(id))
+    }
+
+    /// Same as [DatabaseHolder::remove_item], but removes the setting
+    pub fn remove_setting<T: Into<Item> + DatabaseItem + Any>(&self) -> bool {
+        self.remove_stored(T::type_name(), None)
+    }
+
+    fn remove_stored(&self, ty: &'static str, id: Option<i32>) -> bool {
+        let removed = self.lock(|db| {
+            let removed = db
+                .items
+                .get(ty)
+                .is_some_and(|items| items.write().remove(&id).is_some());
+            if removed {
+                db.provenance.remove(&(ty, id));
+                db.comments.remove(&(ty, id));
+            }
+            removed
+        });
+        if removed {
+            self.record(|| JournalEntry::ItemRemoved {
+                ty: ty.to_string(),
+                id,
+            });
+        }
+        removed
+    }
+
     pub fn get_singleton<T: Into<Item> + DatabaseItem + Any>(
         self: &Arc<Self>,
     ) -> Option<StoredDbItem<T>> {
@@ -286,24 +676,134 @@ impl DatabaseHolder {
     ///
     /// It is not possible to get back an item added this way, if you want to
     /// reference or modify the added item, use [add_item]
-    pub(crate) fn consume_item<T: Into<Item>>(&self, item: T) {
+    ///
+    /// ID collisions are resolved according to the [CollisionPolicy]
+    pub(crate) fn consume_item<T: Into<Item>>(&self, item: T, source: Provenance) {
+        let mut db = self.inner.lock();
+        self.consume_item_locked(db.deref_mut(), item.into(), source);
+    }
+
+    /// Same as calling [Remember::remember] on each of the items, but the
+    /// items are stored under a single lock once the last of the returned
+    /// handles is dropped
+    ///
+    /// Remembering the items one by one locks the database each time a
+    /// [DbItem] is dropped, so this is preferable in procedural generation
+    /// loops. The iterator is consumed before the database is locked, so it
+    /// may freely access the database
+    #[track_caller]
+    pub fn remember_all<T: Into<Item> + DatabaseItem>(
+        self: &Arc<Self>,
+        items: impl IntoIterator<Item = T>,
+    ) -> Vec<DbItem<T>> {
+        let provenance = Provenance::capture();
+        let batch = Arc::new(PendingBatch::new(self.clone()));
+        items
+            .into_iter()
+            .map(|item| DbItem::in_batch(item, provenance.clone(), batch.clone()))
+            .collect()
+    }
+
+    /// Stores the items of the [PendingBatch] under a single lock
+    pub(crate) fn consume_items(&self, items: Vec<(Item, Provenance, Option<String>)>) {
         let mut db = self.inner.lock();
         let db = db.deref_mut();
+        for (item, source, comment) in items {
+            if let Some(comment) = comment {
+                db.comments
+                    .insert((item.inner_type_name(), item.id()), comment);
+            }
+            self.consume_item_locked(db, item, source);
+        }
+    }
+
+    /// Runs the closure on each of the items saved to the database
+    /// previously, looking them all up under a single lock, and returns the
+    /// number of the edited items
+    ///
+    /// Missing items are skipped
+    ///
+    /// # Panics
+    /// Each item is individually stored behind a [RwLock], so regular runtime borrowing rules apply
+    pub fn edit_many<T: Into<Item> + DatabaseItem + Any>(
+        self: &Arc<Self>,
+        ids: impl IntoIterator<Item = impl DatabaseIdLike<T>>,
+        mut func: impl FnMut(&mut T),
+    ) -> usize {
+        let items = self.lock(|db| {
+            let Some(map) = db.items.get(T::type_name()) else {
+                return vec![];
+            };
+            let map = map.read();
+            ids.into_iter()
+                .filter_map(|id| map.get(&Some(id.into_id(&self.ids))).cloned())
+                .collect::<Vec<_>>()
+        });
+        for item in &items {
+            func(&mut StoredDbItem::<T>::new(item.clone(), self.clone()).write());
+        }
+        items.len()
+    }
 
-        let item = item.into();
+    fn consume_item_locked(&self, db: &mut DatabaseInner, item: Item, source: Provenance) {
         let type_name = item.inner_type_name();
         let id = item.id();
-        let map = db.items.entry(type_name).or_default();
-        if map
-            .write()
-            .insert(id, Arc::new(RwLock::new(item)))
-            .is_some()
-        {
-            if let Some(id) = id {
-                error!(id, ty = type_name, "Item ID collision detected")
-            } else {
-                error!(ty = type_name, "Duplicate setting detected")
+        let map = db.items.entry(type_name).or_default().clone();
+        let mut map = map.write();
+
+        let Some(existing) = map.get(&id).cloned() else {
+            self.record(|| JournalEntry::ItemAdded {
+                source: source.to_string(),
+                item: item.clone(),
+            });
+            map.insert(id, Arc::new(RwLock::new(item)));
+            db.provenance.insert((type_name, id), source);
+            return;
+        };
+
+        let existing_source = db
+            .provenance
+            .get(&(type_name, id))
+            .cloned()
+            .expect("Stored items should have provenance");
+
+        let keep_new = match &db.collision_policy {
+            CollisionPolicy::Error => {
+                if let Some(id) = id {
+                    error!(id, ty = type_name, existing = %existing_source, new = %source, "Item ID collision detected")
+                } else {
+                    error!(ty = type_name, existing = %existing_source, new = %source, "Duplicate setting detected")
+                }
+                true
+            }
+            CollisionPolicy::KeepFirst => false,
+            CollisionPolicy::KeepLast => true,
+            CollisionPolicy::Merge(merge) => {
+                let mut existing = existing.write();
+                *existing = merge(existing.clone(), item.clone());
+                self.record(|| JournalEntry::ItemAdded {
+                    source: source.to_string(),
+                    item: existing.clone(),
+                });
+                false
             }
+        };
+
+        db.collisions.push(ItemCollision {
+            type_name,
+            id,
+            existing: existing_source,
+            new: source.clone(),
+            policy: db.collision_policy.clone(),
+        });
+
+        if keep_new {
+            self.record(|| JournalEntry::ItemAdded {
+                source: source.to_string(),
+                item: item.clone(),
+            });
+            map.insert(id, Arc::new(RwLock::new(item)));
+            db.provenance.insert((type_name, id), source);
         }
     }
 
@@ -347,21 +847,101 @@ impl DatabaseHolder {
         self.lock(|db| db.images.get(name).cloned())
     }
 
+    /// Returns all inserted images, sorted by name
+    pub fn images(&self) -> Vec<(String, Arc<image::DynamicImage>)> {
+        let mut images: Vec<_> = self.lock(|db| {
+            db.images
+                .iter()
+                .map(|(name, image)| (name.clone(), image.clone()))
+                .collect()
+        });
+        images.sort_by(|(a, _), (b, _)| a.cmp(b));
+        images
+    }
+
+    /// Inserts a localization XML file for the language, returning the
+    /// previous file of the same language if it existed
+    pub fn insert_localization(
+        &self,
+        language: impl Into<String>,
+        xml: impl Into<String>,
+    ) -> Option<String> {
+        self.lock(|db| db.localizations.insert(language.into(), xml.into()))
+    }
+
+    /// Sets the format of the item files written on save
+    pub fn set_save_options(&self, options: SaveOptions) {
+        self.lock(|db| db.save_options = options);
+    }
+
     /// Saves database to the file system, overriding old files
+    ///
+    /// # Panics
+    /// Will panic if the database was created via [DatabaseHolder::ephemeral]
+    ///
+    /// Termination signals received while the mappings backup is present
+    /// are deferred until the output is written
     pub fn save(self: Arc<Self>) -> DiagnosticContext {
+        self.save_with(&CancelToken::new())
+            .expect("Save without the cancellation should not be cancelled")
+    }
+
+    /// Same as [DatabaseHolder::save], but stops with [SaveCancelled] once
+    /// the token is cancelled, for the front-ends that abort the stale
+    /// builds
+    ///
+    /// Cancellation is checked between the items and while writing the
+    /// main output directory. Cancelled save restores the ID mappings of the
+    /// previous save, and leaves the output directory as described in
+    /// [SmartOutput::flush_with]. Once the main output is written, the
+    /// [OutputRoots] are written to the end and the save is not cancelled
+    pub fn save_with(
+        self: Arc<Self>,
+        cancel: &CancelToken,
+    ) -> Result<DiagnosticContext, SaveCancelled> {
         const ERR_DANGLING_DATABASE: &str = "Should not have dangling references to the database before saving. Check your item handles for leakage";
         const ERR_DANGLING_COLLECTION: &str = "Should not have dangling references to the database collections before saving. Check your iterator usage for leaking";
         const ERR_DANGLING_ITEM: &str = "Should not have dangling references to the database item before saving. Check your item handles for leakage";
         const ERR_DANGLING_MAPPINGS: &str = "Should not have dangling references to the database mappings before saving. Check your contexts handles for leakage";
 
+        if self.lock(|db| db.ephemeral) {
+            panic!("Ephemeral databases can't be saved")
+        }
+
+        let started = Instant::now();
         let settings = self
             .get_singleton::<DatabaseSettings>()
             .map(|s| s.new_clone().forget());
 
+        self.materialize_derived();
+
+        let mut ctx = DiagnosticContext::default();
+
+        validate_component_fit(&self, &mut ctx);
+        validate_loadouts(&self, &mut ctx);
+        validate_relations(&self, &mut ctx);
+        validate_expressions(&self, &mut ctx);
+        validate_images(&self, &mut ctx);
+        validate_image_names(&self, &mut ctx);
+        validate_requirements(&self, &mut ctx);
+        validate_engine_limits(&self, &mut ctx);
+        validate_compatibility(&self, &mut ctx);
+        validate_mod_registry(&self, &mut ctx);
+        validate_save_checks(&self, &mut ctx);
+        self.report_collisions(&mut ctx);
+        self.report_unused_ids(&mut ctx);
+        let validated = started.elapsed();
+
+        if cancel.is_cancelled() {
+            return Err(SaveCancelled);
+        }
+
         let guard_a = error_span!("Saving database").entered();
         let db = Arc::into_inner(self).expect(ERR_DANGLING_DATABASE);
+        let ids = db.ids;
         let db = db.inner.into_inner();
         let output_path = db.output_path;
+        let save_options = db.save_options;
         drop(guard_a);
 
         let _guard = error_span!("Saving database", path=%output_path.display()).entered();
@@ -374,15 +954,65 @@ impl DatabaseHolder {
             panic!("Output path is not a directory");
         }
 
-        let mut output =
-            SmartOutput::init(output_path.clone()).expect("Should be able to init output");
+        let output_options = OutputOptions {
+            edit_policy: save_options.edit_policy,
+            strict_adoption: save_options.strict_adoption,
+            cleanup_policy: save_options.cleanup_policy,
+            hash_algorithm: save_options.hash_algorithm,
+            recover_backup: save_options.recover_backup,
+            symlink_policy: save_options.symlink_policy,
+            read_only_policy: save_options.read_only_policy,
+            diff_policy: if save_options.log_diffs {
+                DiffPolicy::Log
+            } else {
+                DiffPolicy::Off
+            },
+            parallelism: save_options.parallelism,
+        };
+        let with_progress = |mut output: SmartOutput| {
+            if let Some(progress) = &save_options.progress {
+                output = output.on_progress(progress.clone());
+            }
+            if let Some(metrics) = &save_options.metrics {
+                output = output.with_metrics(metrics.clone());
+            }
+            output
+        };
+        let mut output = SmartOutput::init_with(output_path.clone(), output_options)
+            .expect("Should be able to init output")
+            .preserve_globs(save_options.preserve.iter().cloned());
+        output = with_progress(output);
+        // Roots inside the output directory are managed as its sub-roots, so
+        // the main output doesn't clean up their files
+        let mut init_root = |root: &Option<PathBuf>, name: &str| {
+            let root = root.as_ref()?;
+            let _guard =
+                error_span!("Initializing output root", name, path=%root.display()).entered();
+            let root = root
+                .canonicalize()
+                .expect("Should be able to canonicalize path");
+            if root == output_path {
+                return None;
+            }
+            let root_output = match root.strip_prefix(&output_path) {
+                Ok(relative) => output.sub_root(relative),
+                Err(_) => SmartOutput::init_with(root.clone(), output_options).map(with_progress),
+            };
+            let output = root_output
+                .expect("Should be able to init output")
+                .preserve_globs(save_options.preserve.iter().cloned());
+            Some((root, output))
+        };
+        let mut assets_output = init_root(&save_options.roots.assets_root, "assets");
+        let mut localization_output =
+            init_root(&save_options.roots.localization_root, "localization");
 
         let mappings_path = output_path.join(MAPPINGS_NAME);
         let mappings_bk_path = output_path.join(MAPPINGS_BACKUP_NAME);
-        check_no_backup(&mappings_bk_path);
+        check_no_backup(&output_path);
 
         let mappings = MappingsSerde {
-            ids: db.ids.as_serializable().clone(),
+            ids: ids.to_serializable(),
             others: db
                 .other_ids
                 .into_iter()
@@ -401,7 +1031,14 @@ impl DatabaseHolder {
         let code =
             serde_json::to_string_pretty(&mappings).expect("Should be able to serialize mappings");
 
-        if mappings_path.exists() {
+        // Backup only gets removed once the output is flushed
+        let section = CriticalSection::enter();
+        let had_mappings = mappings_path.exists();
+        let cancelled = || {
+            rollback_mappings(&mappings_path, &mappings_bk_path, had_mappings);
+            Err(SaveCancelled)
+        };
+        if had_mappings {
             fs_err::copy(&mappings_path, &mappings_bk_path)
                 .expect("Should be able to create mappings backup");
             fs_err::write(&mappings_path, code).expect("Should be able to write mappings file");
@@ -411,7 +1048,43 @@ impl DatabaseHolder {
                 .expect("Should be able to create mappings backup");
         }
 
-        let inverse_ids = db.ids.get_inverse_ids();
+        let inverse_ids = ids.get_inverse_ids();
+        let explicit_ids = ids.explicit_ids();
+
+        let id_usage = ids.usage_report();
+        for (kind, usage) in &id_usage.kinds {
+            if usage.is_nearly_exhausted() {
+                warn!(
+                    ty = %kind,
+                    remaining = usage.remaining,
+                    capacity = usage.capacity,
+                    "ID space is nearly exhausted, consider allocating more IDs"
+                );
+            }
+        }
+        output
+            .add_file(
+                output_path.join(ID_USAGE_NAME),
+                serde_json::to_string_pretty(&id_usage)
+                    .expect("Should be able to serialize ID usage report"),
+            )
+            .expect("Should be able to save ID usage report");
+
+        let comments: BTreeMap<String, &String> = db
+            .comments
+            .iter()
+            .filter(|((ty, id), _)| db.items.get(ty).is_some_and(|m| m.read().contains_key(id)))
+            .map(|((ty, id), comment)| (item_file_name(&inverse_ids, ty, *id), comment))
+            .collect();
+        if !comments.is_empty() {
+            output
+                .add_file(
+                    output_path.join(COMMENTS_NAME),
+                    serde_json::to_string_pretty(&comments)
+                        .expect("Should be able to serialize item comments"),
+                )
+                .expect("Should be able to save item comments");
+        }
 
         let (mut build_data, info) = if let Some(path) = db.output_file_path {
             let info = ModBuilderInfo::from_settings(
@@ -423,59 +1096,141 @@ impl DatabaseHolder {
             (ModBuilderData::dummy(), None)
         };
 
-        let mut ctx = DiagnosticContext::default();
+        let occupied: AHashMap<&'static str, AHashSet<i32>> = db
+            .items
+            .iter()
+            .map(|(ty, items)| (*ty, items.read().keys().flatten().copied().collect()))
+            .collect();
 
+        let mut items_saved = 0;
+        // Validators enter the item contexts before the save, so they can't
+        // catch the file name collisions
+        let mut saved_files = AHashSet::new();
         for item in db.items.into_values().flat_map(|m| {
             Arc::into_inner(m)
                 .expect(ERR_DANGLING_COLLECTION)
                 .into_inner()
                 .into_values()
         }) {
+            if cancel.is_cancelled() {
+                return cancelled();
+            }
             let item_handle = item.read();
             let type_name = item_handle.inner_type_name();
             let id = item_handle.id();
             drop(item_handle);
 
             let guard_early = error_span!("Saving item", ty = type_name, id).entered();
-            let item = Arc::into_inner(item).expect(ERR_DANGLING_ITEM).into_inner();
+            let mut item = Arc::into_inner(item).expect(ERR_DANGLING_ITEM).into_inner();
             let type_name = item.inner_type_name();
-            let file_name = item
-                .id()
-                .map(|id| {
-                    inverse_ids
-                        .get(type_name)
-                        .and_then(|ids| ids.get(&id).cloned())
-                        .map(|id| {
-                            let id = id.split(':').collect::<Vec<_>>();
-
-                            format!("{}/{}/{}.json", id[0], type_name, id[1])
-                        })
-                        .unwrap_or_else(|| format!("auto/{type_name}/{id}.json"))
-                })
-                .unwrap_or_else(|| format!("settings/{type_name}.json"));
+            let file_name = item_file_name(&inverse_ids, type_name, item.id());
+            if !saved_files.insert(file_name.clone()) {
+                panic!("Multiple items are saved into {file_name}");
+            }
 
             let path = output_path.join(&file_name);
+            let output_file = if save_options.gzip {
+                output_path.join(format!("{file_name}.gz"))
+            } else {
+                path.clone()
+            };
 
             drop(guard_early);
             let _guard = error_span!("Saving item", ty = type_name, id, file_name).entered();
 
-            item.validate(ctx.enter_new(file_name));
+            item.validate(ctx.enter(&file_name));
+            check_references(&mut item, &occupied, &explicit_ids, ctx.enter(&file_name));
+            if ctx
+                .diagnostics
+                .get(&file_name)
+                .is_some_and(|d| !d.is_empty())
+            {
+                match (
+                    db.provenance.get(&(type_name, id)),
+                    db.comments.get(&(type_name, id)),
+                ) {
+                    (Some(provenance), Some(comment)) => {
+                        ctx.set_origin(&file_name, format!("{provenance}, {comment:?}"))
+                    }
+                    (Some(provenance), None) => ctx.set_origin(&file_name, provenance),
+                    (None, _) => {}
+                }
+            }
 
-            let _save_file_guard = error_span!("Writing file", path=%path.display()).entered();
+            let _save_file_guard =
+                error_span!("Writing file", path=%output_file.display()).entered();
 
-            let json = serde_json::ser::to_string_pretty(&item)
-                .expect("Should be able to serialize the item");
+            let json = if save_options.minify_json {
+                serde_json::ser::to_string(&item)
+            } else {
+                serde_json::ser::to_string_pretty(&item)
+            }
+            .expect("Should be able to serialize the item");
 
-            build_data.add_file(path.clone(), json.as_bytes());
+            build_data.add_file(path, json.as_bytes());
 
+            let content = if save_options.gzip {
+                gzip(json.as_bytes())
+            } else {
+                json.into_bytes()
+            };
             output
-                .add_file(path, json)
+                .add_file(output_file, content)
                 .expect("Should be able to save the file");
+            items_saved += 1;
         }
 
-        output.flush().expect("Should be able to flush the output");
+        let mut images: Vec<_> = db.images.into_iter().collect();
+        images.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (name, image) in images {
+            let _guard = error_span!("Saving image", name).entered();
+            let file_name = format!("Images/{name}.png");
+            let mut png = vec![];
+            image
+                .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+                .expect("Should be able to encode the image");
+            build_data.add_file(output_path.join(&file_name), &png);
+            let (root, output) = match &mut assets_output {
+                Some((root, output)) => (&*root, output),
+                None => (&output_path, &mut output),
+            };
+            output
+                .add_file(root.join(file_name), png)
+                .expect("Should be able to save the image");
+        }
+
+        for (language, xml) in db.localizations {
+            let _guard = error_span!("Saving localization", language).entered();
+            let file_name = format!("Localization/{language}.xml");
+            build_data.add_file(output_path.join(&file_name), xml.as_bytes());
+            let (root, output) = match &mut localization_output {
+                Some((root, output)) => (&*root, output),
+                None => (&output_path, &mut output),
+            };
+            output
+                .add_file(root.join(file_name), xml)
+                .expect("Should be able to save the localization");
+        }
+
+        let report = match output.flush_with(cancel) {
+            Ok(report) => report,
+            Err(smart_output::Error::Cancelled) => return cancelled(),
+            Err(err) => panic!("Should be able to flush the output: {err:?}"),
+        };
+        let mut changes = ChangeSet::from_report(&output_path, &report);
+        // Item files and their marker are committed at this point, so rolling
+        // back the mappings would desync them. Other roots are flushed to the
+        // end regardless of the cancellation
+        for (root, output) in assets_output.into_iter().chain(localization_output) {
+            let _guard = error_span!("Flushing output root", path=%root.display()).entered();
+            let report = output
+                .flush()
+                .expect("Should be able to flush the output root");
+            changes.extend(ChangeSet::from_report(&root, &report));
+        }
 
         fs_err::remove_file(mappings_bk_path).expect("Should remove mappings backup file");
+        drop(section);
 
         if let Some(info) = info {
             build_data
@@ -484,8 +1239,42 @@ impl DatabaseHolder {
         }
 
         info!("Database saved successfully!");
+        if let Some(metrics) = &save_options.metrics {
+            metrics.duration(VALIDATE_SECONDS_METRIC, validated);
+            metrics.counter(ITEMS_SAVED_METRIC, items_saved);
+            metrics.duration(SAVE_SECONDS_METRIC, started.elapsed());
+        }
+
+        if let Some(target) = &save_options.reload {
+            target.notify(&changes);
+        }
+
+        save_options.validation_profile.apply(&mut ctx);
+        Ok(ctx)
+    }
 
-        ctx
+    /// Reports IDs that were allocated via [DatabaseHolder::new_id], but
+    /// never got an item added with them
+    fn report_unused_ids(&self, ctx: &mut DiagnosticContext) {
+        let missing: Vec<_> = self.lock(|db| {
+            self.ids
+                .allocated_entries()
+                .into_iter()
+                .filter(|(kind, _, id)| {
+                    !db.items
+                        .get(kind.as_ref())
+                        .is_some_and(|m| m.read().contains_key(&Some(*id)))
+                })
+                .collect()
+        });
+        if missing.is_empty() {
+            return;
+        }
+        let inverse_ids = self.ids.get_inverse_ids();
+        for (kind, string_id, id) in missing {
+            ctx.enter(item_file_name(&inverse_ids, &kind, Some(id)))
+                .emit(DiagnosticKind::unused_id(string_id));
+        }
     }
 
     fn lock<T>(&self, actions: impl FnOnce(&mut DatabaseInner) -> T) -> T {
@@ -496,9 +1285,15 @@ impl DatabaseHolder {
 
 impl DatabaseHolder {
     pub fn load_from_dir(&self, dir: impl AsRef<Path>) {
-        let path = dir.as_ref();
-        let _guard = error_span!("Loading existing database files", path=%path.display()).entered();
-        let walk: Vec<_> = walkdir::WalkDir::new(dir)
+        self.load_from_dir_with(dir, &LoadOptions::default())
+    }
+
+    /// Same as [DatabaseHolder::load_from_dir], but skips items rejected by
+    /// the [LoadOptions::filter]
+    pub fn load_from_dir_with(&self, dir: impl AsRef<Path>, options: &LoadOptions) {
+        let root = dir.as_ref();
+        let _guard = error_span!("Loading existing database files", path=%root.display()).entered();
+        let walk: Vec<_> = walkdir::WalkDir::new(root)
             .into_iter()
             .collect::<Result<_, _>>()
             .expect("Should be able to read all files in the directory");
@@ -511,17 +1306,18 @@ impl DatabaseHolder {
 
                 let path = entry.path();
 
-                let ext = path.extension().and_then(|ext| ext.to_str())?;
-
-                if ext != "json" {
-                    return None;
-                }
+                let compressed = item_file_compression(path)?;
 
                 let _guard = error_span!("Loading file", path=%path.display()).entered();
 
                 let data = fs_err::read(path).expect("Should be able to read a file");
 
-                let data: Item = serde_json5::from_slice(&data).expect("Should be a valid json");
+                let data = parse_item_file(&data, compressed);
+
+                let relative = path.strip_prefix(root).unwrap_or(path);
+                if !options.accepts(relative, &data) {
+                    return None;
+                }
 
                 Some((path.to_path_buf(), data))
             })
@@ -530,11 +1326,17 @@ impl DatabaseHolder {
         for (path, data) in items {
             let _guard = error_span!("Registering file", path=%path.display()).entered();
 
-            self.consume_item(data);
+            self.consume_item(data, Provenance::File(path));
         }
     }
 
     pub fn load_from_included_dir(&self, dir: &include_dir::Dir) {
+        self.load_from_included_dir_with(dir, &LoadOptions::default())
+    }
+
+    /// Same as [DatabaseHolder::load_from_included_dir], but skips items
+    /// rejected by the [LoadOptions::filter]
+    pub fn load_from_included_dir_with(&self, dir: &include_dir::Dir, options: &LoadOptions) {
         fn walkdir<'a>(dir: &include_dir::Dir<'a>) -> Vec<include_dir::File<'a>> {
             let mut items = vec![];
             append_files(dir, &mut items);
@@ -557,17 +1359,15 @@ impl DatabaseHolder {
             .filter_map(|entry| {
                 let path = entry.path();
 
-                let ext = path.extension().and_then(|ext| ext.to_str())?;
-
-                if ext != "json" {
-                    return None;
-                }
+                let compressed = item_file_compression(path)?;
 
                 let _guard = error_span!("Loading file", path=%path.display()).entered();
 
-                let data = entry.contents();
+                let data = parse_item_file(entry.contents(), compressed);
 
-                let data: Item = serde_json5::from_slice(data).expect("Should be a valid json");
+                if !options.accepts(path, &data) {
+                    return None;
+                }
 
                 Some((path.to_path_buf(), data))
             })
@@ -576,7 +1376,7 @@ impl DatabaseHolder {
         for (path, data) in items {
             let _guard = error_span!("Registering file", path=%path.display()).entered();
 
-            self.consume_item(data);
+            self.consume_item(data, Provenance::File(path));
         }
     }
 }
@@ -584,3 +1384,147 @@ impl DatabaseHolder {
 pub trait Remember: Into<Item> + DatabaseItem {
     fn remember(self, db: &Database) -> DbItem<Self>;
 }
+
+#[cfg(test)]
+mod tests {
+    use diagnostic::diagnostic::DiagnosticKind;
+    use eh_schema::schema::{DatabaseItemId, Faction, Ship, ShipBuild};
+    use smart_output::testing::test_dir;
+    use smart_output::{CancelToken, CleanupPolicy, FlushEvent, FlushParallelism, FlushProgress};
+
+    use super::{
+        database, DatabaseHolder, OutputRoots, Remember, SaveOptions, MAPPINGS_BACKUP_NAME,
+        MAPPINGS_NAME,
+    };
+
+    /// Sorted textual dump of the stored state, for comparing databases
+    pub(super) fn snapshot(db: &DatabaseHolder) -> Vec<String> {
+        let mut lines = vec![];
+        db.iter_all(|ty, id, item| {
+            let json = serde_json::to_string(item).unwrap();
+            lines.push(format!("item {ty} {id:?} {json}"));
+        });
+        db.use_id_mappings(|ids| {
+            for (kind, entries) in ids.to_serializable() {
+                for (string_id, id) in entries {
+                    let used = ids.is_used(kind.clone(), &string_id);
+                    let explicit = ids.is_explicit(kind.clone(), &string_id);
+                    lines.push(format!("id {kind} {string_id} {id} {used} {explicit}"));
+                }
+            }
+        });
+        db.lock(|db| {
+            for ((ty, id), comment) in &db.comments {
+                lines.push(format!("comment {ty} {id:?} {comment}"));
+            }
+            for (lang, xml) in &db.localizations {
+                lines.push(format!("localization {lang} {xml}"));
+            }
+            for (name, image) in &db.images {
+                lines.push(format!("image {name} {:?}", image.as_bytes()));
+            }
+        });
+        lines.sort();
+        lines
+    }
+
+    #[test]
+    fn cancel_during_second_root_flush() {
+        let (_output_dir, output) = test_dir("cancel_output");
+        let (_localization_dir, localization) = test_dir("cancel_localization");
+        let cancel = CancelToken::new();
+
+        let db = database(&output, None::<&str>);
+        db.add_id_range(0..100);
+        Faction::new(db.new_id("test:faction")).remember(&db);
+        db.insert_localization("English", "<resources/>");
+        db.insert_localization("German", "<resources/>");
+        let progress = {
+            let cancel = cancel.clone();
+            let localization = localization.canonicalize().unwrap();
+            FlushProgress::new(move |event| {
+                if let FlushEvent::Written(path) = event {
+                    if path.starts_with(&localization) {
+                        cancel.cancel();
+                    }
+                }
+            })
+        };
+        db.set_save_options(SaveOptions {
+            cleanup_policy: CleanupPolicy::Delete,
+            parallelism: FlushParallelism {
+                threads: Some(1),
+                batch_size: 1,
+                ..Default::default()
+            },
+            progress: Some(progress),
+            roots: OutputRoots {
+                localization_root: Some(localization.clone()),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        // Main output is already committed, so the save runs to the end
+        assert!(db.save_with(&cancel).is_ok());
+        assert!(cancel.is_cancelled());
+        assert!(output.join(MAPPINGS_NAME).exists());
+        assert!(!output.join(MAPPINGS_BACKUP_NAME).exists());
+        assert!(localization.join("Localization/English.xml").exists());
+        assert!(localization.join("Localization/German.xml").exists());
+    }
+
+    #[test]
+    #[should_panic = "Multiple items are saved into test/Faction/a.json"]
+    fn duplicate_file_names() {
+        let (_dir, output) = test_dir("duplicate_file_names");
+        let db = database(&output, None::<&str>);
+        db.add_id_range(0..100);
+        // Only the first segment of the ID after the namespace names the file
+        Faction::new(db.new_id("test:a:1")).remember(&db);
+        Faction::new(db.new_id("test:a:2")).remember(&db);
+        db.save();
+    }
+
+    #[test]
+    fn explicit_references() {
+        let (_dir, output) = test_dir("explicit_references");
+        let db = database(&output, None::<&str>);
+        db.add_id_range(0..100);
+        let vanilla = db.set_id::<Ship>("eh:ship", 500);
+        ShipBuild::new(db.new_id("test:vanilla"), vanilla).remember(&db);
+        ShipBuild::new(db.new_id("test:missing"), DatabaseItemId::new(600)).remember(&db);
+
+        let ctx = db.save();
+        let unresolved = |file: &str| {
+            ctx.diagnostics.get(file).is_some_and(|d| {
+                d.iter()
+                    .any(|d| matches!(d.kind, DiagnosticKind::UnresolvedReference { .. }))
+            })
+        };
+        assert!(!unresolved("test/ShipBuild/vanilla.json"));
+        assert!(unresolved("test/ShipBuild/missing.json"));
+    }
+
+    #[test]
+    fn unused_ids() {
+        let (_dir, output) = test_dir("unused_ids");
+        let db = database(&output, None::<&str>);
+        db.add_id_range(0..100);
+        db.set_id::<Ship>("eh:ship", 500);
+        Faction::new(db.new_id("test:used")).remember(&db);
+        db.new_id::<Faction>("test:unused");
+
+        let ctx = db.save();
+        let reported: Vec<_> = ctx
+            .diagnostics
+            .values()
+            .flatten()
+            .filter_map(|d| match &d.kind {
+                DiagnosticKind::UnusedId { id } => Some(id.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(reported, vec!["test:unused"]);
+    }
+}
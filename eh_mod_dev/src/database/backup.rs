@@ -0,0 +1,181 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::database::{MappingsSerde, MAPPINGS_BACKUP_NAME, MAPPINGS_NAME};
+
+/// Mappings backup left over from the interrupted save
+#[derive(Debug, Error)]
+#[error(
+    "Mappings backup file `{}` exists, this means that there was an error during the previous invocation\n\
+     Changes compared to `{}`: {}\n\
+     Accept the backup to restore the mappings from before the failed save, or discard it to keep the current mappings",
+    .backup_path.display(),
+    .mappings_path.display(),
+    .diff
+)]
+pub struct MappingsBackupError {
+    pub mappings_path: PathBuf,
+    pub backup_path: PathBuf,
+    /// Changes of the current mappings relative to the backup
+    pub diff: Box<MappingsDiff>,
+}
+
+/// Way of resolving the leftover mappings backup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackupResolution {
+    /// Replaces the current mappings with the backup
+    Accept,
+    /// Removes the backup, keeping the current mappings
+    Discard,
+}
+
+#[derive(Debug, Error)]
+pub enum ResolveBackupError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("No mappings backup is present at `{}`", .0.display())]
+    NoBackup(PathBuf),
+}
+
+/// Differences between two ID mappings files
+///
+/// Entries are formatted as `kind/string_id`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MappingsDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    /// Entries that got assigned a different ID, along with the old and
+    /// new IDs
+    pub changed: Vec<(String, i32, i32)>,
+    /// Error of reading either of the files, in which case the rest of the
+    /// diff is empty
+    pub unreadable: Option<String>,
+}
+
+impl MappingsDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && self.unreadable.is_none()
+    }
+
+    fn compute(old: &Path, new: &Path) -> Self {
+        let (old, new) = match (read_flat(old), read_flat(new)) {
+            (Ok(old), Ok(new)) => (old, new),
+            (Err(err), _) | (_, Err(err)) => {
+                return Self {
+                    unreadable: Some(err),
+                    ..Default::default()
+                }
+            }
+        };
+        let mut diff = Self::default();
+        for (key, id) in &new {
+            match old.get(key) {
+                None => diff.added.push(key.clone()),
+                Some(old_id) if old_id != id => diff.changed.push((key.clone(), *old_id, *id)),
+                Some(_) => {}
+            }
+        }
+        diff.removed = old
+            .into_keys()
+            .filter(|key| !new.contains_key(key))
+            .collect();
+        diff
+    }
+}
+
+impl Display for MappingsDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        /// Amount of entries of each category to list
+        const SHOWN: usize = 5;
+
+        if let Some(err) = &self.unreadable {
+            return write!(f, "failed to compare: {err}");
+        }
+        if self.is_empty() {
+            return write!(f, "files are identical");
+        }
+
+        fn list(f: &mut Formatter<'_>, name: &str, entries: &[String]) -> std::fmt::Result {
+            if entries.is_empty() {
+                return Ok(());
+            }
+            write!(
+                f,
+                "\n  {} {name}: {}",
+                entries.len(),
+                entries[..entries.len().min(SHOWN)].join(", ")
+            )?;
+            if entries.len() > SHOWN {
+                write!(f, ", ...")?;
+            }
+            Ok(())
+        }
+
+        list(f, "added", &self.added)?;
+        list(f, "removed", &self.removed)?;
+        let changed = self
+            .changed
+            .iter()
+            .map(|(key, old, new)| format!("{key} ({old} -> {new})"))
+            .collect::<Vec<_>>();
+        list(f, "changed", &changed)
+    }
+}
+
+fn read_flat(path: &Path) -> Result<BTreeMap<String, i32>, String> {
+    if !path.exists() {
+        return Ok(Default::default());
+    }
+    let data = fs_err::read_to_string(path).map_err(|err| err.to_string())?;
+    let mappings: MappingsSerde = serde_json5::from_str(&data)
+        .map_err(|err| format!("`{}` is invalid: {err}", path.display()))?;
+    Ok(std::iter::once(mappings.ids)
+        .chain(mappings.others.into_values())
+        .flatten()
+        .flat_map(|(kind, ids)| {
+            ids.into_iter()
+                .map(move |(id, num)| (format!("{kind}/{id}"), num))
+        })
+        .collect())
+}
+
+/// Checks that the output directory has no mappings backup left over from
+/// the interrupted save
+pub fn check_mappings_backup(output_path: impl AsRef<Path>) -> Result<(), MappingsBackupError> {
+    let output_path = output_path.as_ref();
+    let backup_path = output_path.join(MAPPINGS_BACKUP_NAME);
+    if !backup_path.exists() {
+        return Ok(());
+    }
+    let mappings_path = output_path.join(MAPPINGS_NAME);
+    Err(MappingsBackupError {
+        diff: Box::new(MappingsDiff::compute(&backup_path, &mappings_path)),
+        mappings_path,
+        backup_path,
+    })
+}
+
+/// Resolves the mappings backup left over from the interrupted save
+pub fn resolve_mappings_backup(
+    output_path: impl AsRef<Path>,
+    resolution: BackupResolution,
+) -> Result<(), ResolveBackupError> {
+    let output_path = output_path.as_ref();
+    let backup_path = output_path.join(MAPPINGS_BACKUP_NAME);
+    if !backup_path.exists() {
+        return Err(ResolveBackupError::NoBackup(backup_path));
+    }
+    match resolution {
+        BackupResolution::Accept => {
+            fs_err::rename(&backup_path, output_path.join(MAPPINGS_NAME))?;
+        }
+        BackupResolution::Discard => fs_err::remove_file(&backup_path)?,
+    }
+    Ok(())
+}
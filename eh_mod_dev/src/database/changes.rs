@@ -0,0 +1,98 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ahash::{AHashMap, AHashSet};
+
+use eh_schema::schema::{DynamicFields, Item};
+
+use crate::database::DatabaseHolder;
+
+/// Type name and ID of the stored item
+pub type ItemKey = (&'static str, Option<i32>);
+
+/// State of the items at the moment [DatabaseHolder::track_field_changes]
+/// was called, stored as a database extra
+#[derive(Debug, Default)]
+pub(crate) struct FieldBaseline {
+    items: Option<AHashMap<ItemKey, Item>>,
+}
+
+/// Changes of the items since [DatabaseHolder::track_field_changes] was
+/// called
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldChanges {
+    /// Names of the modified fields, only includes items that changed
+    pub modified: BTreeMap<ItemKey, BTreeSet<&'static str>>,
+    pub added: BTreeSet<ItemKey>,
+    pub removed: BTreeSet<ItemKey>,
+}
+
+impl FieldChanges {
+    pub fn is_empty(&self) -> bool {
+        self.modified.is_empty() && self.added.is_empty() && self.removed.is_empty()
+    }
+
+    /// Modified fields of the item, or `None` if it was not modified
+    pub fn fields(&self, type_name: &str, id: Option<i32>) -> Option<&BTreeSet<&'static str>> {
+        self.modified
+            .iter()
+            .find(|((ty, item_id), _)| *ty == type_name && *item_id == id)
+            .map(|(_, fields)| fields)
+    }
+}
+
+impl DatabaseHolder {
+    /// Starts tracking changes of the item fields, replacing the previous
+    /// baseline
+    ///
+    /// Captures a copy of all stored items, so changes made by any means
+    /// are seen by [DatabaseHolder::field_changes]. Usually called right
+    /// after loading the vanilla database
+    pub fn track_field_changes(&self) {
+        let mut items = AHashMap::default();
+        self.for_each_item(|item| {
+            items.insert((item.inner_type_name(), item.id()), item.clone());
+        });
+        self.extra_or_init::<FieldBaseline>().write().items = Some(items);
+    }
+
+    /// Lists the fields changed since [DatabaseHolder::track_field_changes]
+    /// was called, or `None` if changes are not tracked
+    ///
+    /// Fields are compared by their serialized values, so assigning the
+    /// same value is not considered a change
+    pub fn field_changes(&self) -> Option<FieldChanges> {
+        let baseline = self.extra_or_init::<FieldBaseline>();
+        let baseline = baseline.read();
+        let baseline = baseline.items.as_ref()?;
+
+        let mut changes = FieldChanges::default();
+        let mut seen = AHashSet::default();
+        self.for_each_item(|item| {
+            let key = (item.inner_type_name(), item.id());
+            seen.insert(key);
+            let Some(old) = baseline.get(&key) else {
+                changes.added.insert(key);
+                return;
+            };
+            if old == item {
+                return;
+            }
+            let fields: BTreeSet<_> = item
+                .field_names()
+                .iter()
+                .copied()
+                .filter(|name| old.get_field(name) != item.get_field(name))
+                .collect();
+            if !fields.is_empty() {
+                changes.modified.insert(key, fields);
+            }
+        });
+        changes.removed = baseline
+            .keys()
+            .filter(|key| !seen.contains(*key))
+            .copied()
+            .collect();
+
+        Some(changes)
+    }
+}
@@ -0,0 +1,337 @@
+use std::any::{Any, TypeId};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use thiserror::Error;
+use tracing::{error_span, info};
+
+use eh_schema::schema::Item;
+
+use crate::database::provenance::Provenance;
+use crate::database::DatabaseHolder;
+use crate::mapping::IdMapping;
+
+/// Directory of the checkpoints, relative to the output directory
+pub const CHECKPOINTS_DIR: &str = ".checkpoints";
+
+#[derive(Debug, Error)]
+pub enum CheckpointError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Checkpoint is corrupted: {}", .0)]
+    Corrupted(String),
+}
+
+type SharedExtra = Arc<RwLock<dyn Any + Send + Sync>>;
+
+/// Serializer of an extra registered via
+/// [DatabaseHolder::register_checkpoint_extra]
+#[derive(Clone)]
+pub(crate) struct CheckpointExtra {
+    name: &'static str,
+    save: fn(&(dyn Any + Send + Sync)) -> String,
+    load: fn(&str) -> serde_json::Result<SharedExtra>,
+}
+
+/// Items are stored as JSON, since their serde representation relies on a
+/// self-describing format
+#[derive(bitcode::Encode, bitcode::Decode)]
+struct Checkpoint {
+    ids: Vec<KindEntries>,
+    other_ids: Vec<(String, Vec<KindEntries>)>,
+    items: Vec<String>,
+    comments: Vec<(String, Option<i32>, String)>,
+    images: Vec<(String, Vec<u8>)>,
+    localizations: Vec<(String, String)>,
+    extras: Vec<(String, String)>,
+}
+
+#[derive(bitcode::Encode, bitcode::Decode)]
+struct KindEntries {
+    kind: String,
+    /// String ID, numeric ID, whether the ID is used and whether it was
+    /// assigned explicitly
+    ids: Vec<(String, i32, bool, bool)>,
+}
+
+impl DatabaseHolder {
+    /// Includes the extra of the given type into the checkpoints
+    ///
+    /// Extras can hold arbitrary data, so only the registered ones are
+    /// saved by [DatabaseHolder::checkpoint]
+    pub fn register_checkpoint_extra<T: Any + Send + Sync + Serialize + DeserializeOwned>(&self) {
+        let extra = CheckpointExtra {
+            name: std::any::type_name::<T>(),
+            save: |extra| {
+                let extra = extra
+                    .downcast_ref::<T>()
+                    .expect("Extra should be of the registered type");
+                serde_json::to_string(extra).expect("Should be able to serialize the extra")
+            },
+            load: |data| {
+                let extra: T = serde_json::from_str(data)?;
+                Ok(Arc::new(RwLock::new(extra)))
+            },
+        };
+        self.lock(|db| db.checkpoint_extras.insert(TypeId::of::<T>(), extra));
+    }
+
+    /// Saves the current state of the database under the given name
+    ///
+    /// Checkpoint includes items, ID mappings, images, localizations,
+    /// comments and extras registered via
+    /// [DatabaseHolder::register_checkpoint_extra]. Pending derivations and
+    /// unstable IDs are not saved
+    pub fn checkpoint(&self, name: &str) -> Result<(), CheckpointError> {
+        let path = self.checkpoint_path(name);
+        let _guard = error_span!("Saving checkpoint", path=%path.display()).entered();
+
+        let checkpoint = self.lock(|db| Checkpoint {
+            ids: kind_entries(&self.ids),
+            other_ids: db
+                .other_ids
+                .iter()
+                .map(|(kind, ids)| (kind.to_string(), kind_entries(&ids.read())))
+                .collect(),
+            items: db
+                .items
+                .values()
+                .flat_map(|items| {
+                    items
+                        .read()
+                        .values()
+                        .map(|item| {
+                            serde_json::to_string(&*item.read())
+                                .expect("Should be able to serialize the item")
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+            comments: db
+                .comments
+                .iter()
+                .map(|((ty, id), comment)| (ty.to_string(), *id, comment.clone()))
+                .collect(),
+            images: db
+                .images
+                .iter()
+                .map(|(name, image)| {
+                    let mut png = vec![];
+                    image
+                        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+                        .expect("Should be able to encode the image");
+                    (name.clone(), png)
+                })
+                .collect(),
+            localizations: db
+                .localizations
+                .iter()
+                .map(|(lang, xml)| (lang.clone(), xml.clone()))
+                .collect(),
+            extras: db
+                .checkpoint_extras
+                .iter()
+                .filter_map(|(ty, extra)| {
+                    let data = db.extras.get(ty)?;
+                    Some((extra.name.to_string(), (extra.save)(&*data.read())))
+                })
+                .collect(),
+        });
+
+        if let Some(parent) = path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        fs_err::write(&path, bitcode::encode(&checkpoint))?;
+        info!("Checkpoint saved");
+        Ok(())
+    }
+
+    /// Restores the state saved by [DatabaseHolder::checkpoint], returning
+    /// `false` if there is no checkpoint with the given name
+    ///
+    /// Should be called on a fresh database, after the ID ranges are
+    /// allocated. Checkpoints are not invalidated automatically, so they
+    /// must be removed once the code that ran before the checkpoint changes
+    pub fn resume_from_checkpoint(&self, name: &str) -> Result<bool, CheckpointError> {
+        let path = self.checkpoint_path(name);
+        let _guard = error_span!("Resuming from checkpoint", path=%path.display()).entered();
+        if !path.exists() {
+            return Ok(false);
+        }
+
+        let data = fs_err::read(&path)?;
+        let checkpoint: Checkpoint =
+            bitcode::decode(&data).map_err(|e| CheckpointError::Corrupted(e.to_string()))?;
+        let items = checkpoint
+            .items
+            .iter()
+            .map(|item| serde_json::from_str::<Item>(item))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| CheckpointError::Corrupted(e.to_string()))?;
+
+        restore_entries(&self.ids, checkpoint.ids);
+        for (kind, entries) in checkpoint.other_ids {
+            let ids = self.lock(|db| db.other_ids.entry(kind.into()).or_default().clone());
+            restore_entries(&ids.read(), entries);
+        }
+
+        let source = Provenance::File(path.clone());
+        for item in items {
+            self.consume_item(item, source.clone());
+        }
+
+        let images = checkpoint
+            .images
+            .into_iter()
+            .map(|(name, png)| {
+                let image = image::load_from_memory_with_format(&png, image::ImageFormat::Png)
+                    .map_err(|e| CheckpointError::Corrupted(e.to_string()))?;
+                Ok((name, Arc::new(image)))
+            })
+            .collect::<Result<Vec<_>, CheckpointError>>()?;
+
+        self.lock(|db| {
+            for (ty, id, comment) in checkpoint.comments {
+                if let Some((&ty, _)) = db.items.get_key_value(ty.as_str()) {
+                    db.comments.insert((ty, id), comment);
+                }
+            }
+            db.images.extend(images);
+            db.localizations.extend(checkpoint.localizations);
+            for (name, data) in checkpoint.extras {
+                let Some((ty, extra)) = db
+                    .checkpoint_extras
+                    .iter()
+                    .find(|(_, extra)| extra.name == name)
+                else {
+                    return Err(CheckpointError::Corrupted(format!(
+                        "extra `{name}` is not registered"
+                    )));
+                };
+                let extra = (extra.load)(&data).map_err(|e| {
+                    CheckpointError::Corrupted(format!("invalid extra `{name}`: {e}"))
+                })?;
+                db.extras.insert(*ty, extra);
+            }
+            Ok(())
+        })?;
+
+        info!("Resumed from checkpoint");
+        Ok(true)
+    }
+
+    fn checkpoint_path(&self, name: &str) -> PathBuf {
+        self.checkpoints_dir().join(format!("{name}.bin"))
+    }
+
+    /// Directory of the checkpoints in the output directory
+    pub(crate) fn checkpoints_dir(&self) -> PathBuf {
+        self.lock(|db| db.output_path.join(CHECKPOINTS_DIR))
+    }
+}
+
+fn kind_entries(ids: &IdMapping) -> Vec<KindEntries> {
+    ids.to_serializable()
+        .into_iter()
+        .map(|(kind, entries)| {
+            let ids = entries
+                .into_iter()
+                .map(|(string_id, id)| {
+                    let used = ids.is_used(kind.clone(), &string_id);
+                    let explicit = ids.is_explicit(kind.clone(), &string_id);
+                    (string_id, id, used, explicit)
+                })
+                .collect();
+            KindEntries {
+                kind: kind.to_string(),
+                ids,
+            }
+        })
+        .collect()
+}
+
+fn restore_entries(ids: &IdMapping, entries: Vec<KindEntries>) {
+    for entries in entries {
+        for (string_id, id, used, explicit) in entries.ids {
+            ids.restore_id(entries.kind.clone(), string_id, id, used, explicit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eh_schema::schema::{DatabaseSettings, Faction};
+    use serde::{Deserialize, Serialize};
+    use smart_output::testing::test_dir;
+
+    use super::CheckpointError;
+    use crate::database::tests::snapshot;
+    use crate::database::{database, Remember};
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Counter(u32);
+
+    #[test]
+    fn resume_restores_state() {
+        let (_dir, output) = test_dir("checkpoint_resume");
+
+        let db = database(&output, None::<&str>);
+        db.add_id_range(0..100);
+        db.register_checkpoint_extra::<Counter>();
+        Faction::new(db.new_id("test:faction"))
+            .with_name("Faction")
+            .remember(&db);
+        DatabaseSettings::new().remember(&db);
+        db.get_id_raw::<Faction>("test:reserved");
+        db.set_id::<Faction>("test:pinned", 50);
+        db.set_comment::<Faction>("test:faction", "Commented");
+        db.insert_localization("English", "<resources/>");
+        db.insert_image(
+            "icon.png".to_string(),
+            image::RgbaImage::from_pixel(2, 2, image::Rgba([1, 2, 3, 4])).into(),
+        );
+        db.insert_extra(Counter(7));
+        db.checkpoint("test").unwrap();
+
+        let resumed = database(&output, None::<&str>);
+        resumed.add_id_range(0..100);
+        resumed.register_checkpoint_extra::<Counter>();
+        assert!(resumed.resume_from_checkpoint("test").unwrap());
+
+        assert_eq!(snapshot(&resumed), snapshot(&db));
+        assert_eq!(*resumed.extra::<Counter>().read(), Counter(7));
+        // Restored IDs are not handed out again
+        assert_ne!(
+            resumed.new_id::<Faction>("test:new").0,
+            resumed.id::<Faction>("test:faction").0
+        );
+    }
+
+    #[test]
+    fn missing_checkpoint() {
+        let (_dir, output) = test_dir("checkpoint_missing");
+        let db = database(&output, None::<&str>);
+        db.add_id_range(0..100);
+
+        assert!(!db.resume_from_checkpoint("test").unwrap());
+    }
+
+    #[test]
+    fn unregistered_extra() {
+        let (_dir, output) = test_dir("checkpoint_unregistered_extra");
+        let db = database(&output, None::<&str>);
+        db.add_id_range(0..100);
+        db.register_checkpoint_extra::<Counter>();
+        db.insert_extra(Counter(7));
+        db.checkpoint("test").unwrap();
+
+        let resumed = database(&output, None::<&str>);
+        resumed.add_id_range(0..100);
+        let err = resumed.resume_from_checkpoint("test").unwrap_err();
+
+        assert!(matches!(err, CheckpointError::Corrupted(_)));
+    }
+}
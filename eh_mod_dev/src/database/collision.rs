@@ -0,0 +1,182 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use diagnostic::context::DiagnosticContext;
+use diagnostic::diagnostic::DiagnosticKind;
+use eh_schema::schema::Item;
+
+use crate::database::provenance::Provenance;
+use crate::database::{item_file_name, DatabaseHolder};
+
+/// Strategy for resolving items added with an already occupied ID
+#[derive(Clone, Default)]
+pub enum CollisionPolicy {
+    /// Keep the last added item, reporting the collision as an error
+    #[default]
+    Error,
+    /// Keep the item that was added first, discarding the new one
+    KeepFirst,
+    /// Replace the existing item with the new one
+    KeepLast,
+    /// Combine items via the callback, which receives the existing and the
+    /// new item. Merged collisions are not reported
+    Merge(Arc<dyn Fn(Item, Item) -> Item + Send + Sync>),
+}
+
+impl Debug for CollisionPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CollisionPolicy::Error => write!(f, "Error"),
+            CollisionPolicy::KeepFirst => write!(f, "KeepFirst"),
+            CollisionPolicy::KeepLast => write!(f, "KeepLast"),
+            CollisionPolicy::Merge(_) => write!(f, "Merge"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ItemCollision {
+    pub type_name: &'static str,
+    pub id: Option<i32>,
+    pub existing: Provenance,
+    pub new: Provenance,
+    pub policy: CollisionPolicy,
+}
+
+impl DatabaseHolder {
+    /// Sets the strategy for resolving items added with an already occupied
+    /// ID. Only affects items added after the call
+    pub fn set_collision_policy(&self, policy: CollisionPolicy) {
+        self.lock(|db| db.collision_policy = policy);
+    }
+
+    pub(crate) fn report_collisions(&self, ctx: &mut DiagnosticContext) {
+        let collisions = self.lock(|db| std::mem::take(&mut db.collisions));
+        if collisions.is_empty() {
+            return;
+        }
+        let inverse_ids = self.use_id_mappings(|ids| ids.get_inverse_ids());
+
+        for collision in collisions {
+            let file_name = item_file_name(&inverse_ids, collision.type_name, collision.id);
+            let existing = collision.existing.to_string();
+            let new = collision.new.to_string();
+            let kind = match collision.policy {
+                CollisionPolicy::Error => DiagnosticKind::item_collision(existing, new),
+                CollisionPolicy::KeepFirst => {
+                    DiagnosticKind::item_collision_resolved(existing, new)
+                }
+                CollisionPolicy::KeepLast => DiagnosticKind::item_collision_resolved(new, existing),
+                CollisionPolicy::Merge(_) => continue,
+            };
+            ctx.enter(file_name).emit(kind);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use diagnostic::context::DiagnosticContext;
+    use diagnostic::diagnostic::DiagnosticKind;
+    use eh_schema::schema::{Faction, Item};
+
+    use super::CollisionPolicy;
+    use crate::database::provenance::Provenance;
+    use crate::database::{Database, DatabaseHolder};
+
+    const FILE: &str = "test/Faction/faction.json";
+
+    /// Adds two factions with the same ID from different files, returning
+    /// the provenances of the first and the second one
+    fn collide(policy: CollisionPolicy) -> (Database, String, String) {
+        let db = DatabaseHolder::ephemeral();
+        db.add_id_range(0..100);
+        db.set_collision_policy(policy);
+        let id = db.new_id("test:faction");
+        let first = Provenance::File("first.json".into());
+        let second = Provenance::File("second.json".into());
+        db.consume_item(Faction::new(id).with_name("First"), first.clone());
+        db.consume_item(
+            Faction::new(id).with_name("Second").with_color("#FFFFFFFF"),
+            second.clone(),
+        );
+        (db, first.to_string(), second.to_string())
+    }
+
+    fn stored(db: &Database) -> Faction {
+        db.with_item::<Faction, _>("test:faction", |f| f.clone())
+            .unwrap()
+    }
+
+    fn reported(db: &Database) -> Vec<DiagnosticKind> {
+        let mut ctx = DiagnosticContext::default();
+        db.report_collisions(&mut ctx);
+        ctx.diagnostics
+            .into_iter()
+            .flat_map(|(file, diagnostics)| {
+                assert_eq!(file, FILE);
+                diagnostics.into_iter().map(|d| d.kind)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn error() {
+        let (db, first, second) = collide(CollisionPolicy::Error);
+
+        assert_eq!(stored(&db).name, "Second");
+        assert!(matches!(
+            &reported(&db)[..],
+            [DiagnosticKind::ItemCollision { existing, new }]
+                if *existing == first && *new == second
+        ));
+    }
+
+    #[test]
+    fn keep_first() {
+        let (db, first, second) = collide(CollisionPolicy::KeepFirst);
+
+        assert_eq!(stored(&db).name, "First");
+        assert_eq!(
+            db.provenance(db.id::<Faction>("test:faction"))
+                .unwrap()
+                .to_string(),
+            first
+        );
+        assert!(matches!(
+            &reported(&db)[..],
+            [DiagnosticKind::ItemCollisionResolved { kept, discarded }]
+                if *kept == first && *discarded == second
+        ));
+    }
+
+    #[test]
+    fn keep_last() {
+        let (db, first, second) = collide(CollisionPolicy::KeepLast);
+
+        assert_eq!(stored(&db).name, "Second");
+        assert!(matches!(
+            &reported(&db)[..],
+            [DiagnosticKind::ItemCollisionResolved { kept, discarded }]
+                if *kept == second && *discarded == first
+        ));
+    }
+
+    #[test]
+    fn merge() {
+        let (db, _, _) = collide(CollisionPolicy::Merge(Arc::new(|existing, new| {
+            let (Item::Faction(mut existing), Item::Faction(new)) = (existing, new) else {
+                panic!("Only factions are added");
+            };
+            existing.color = new.color;
+            existing.into()
+        })));
+
+        let stored = stored(&db);
+        assert_eq!(stored.name, "First");
+        assert_eq!(stored.color, "#FFFFFFFF");
+        assert!(reported(&db).is_empty());
+    }
+}
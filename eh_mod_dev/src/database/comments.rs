@@ -0,0 +1,34 @@
+use eh_schema::schema::DatabaseItem;
+
+use crate::database::{DatabaseHolder, DatabaseIdLike};
+
+impl DatabaseHolder {
+    /// Attaches a comment explaining the purpose of the item
+    ///
+    /// Comments are not part of the item data, they are written into the
+    /// separate manifest in the output directory on save. Replaces the
+    /// previous comment of the item
+    pub fn set_comment<T: 'static + DatabaseItem>(
+        &self,
+        id: impl DatabaseIdLike<T>,
+        comment: impl Into<String>,
+    ) {
+        let id = id.into_id(&self.ids);
+        self.set_comment_raw(T::type_name(), Some(id), comment.into());
+    }
+
+    /// Returns the comment attached to the item
+    pub fn comment<T: 'static + DatabaseItem>(&self, id: impl DatabaseIdLike<T>) -> Option<String> {
+        let id = id.into_id(&self.ids);
+        self.lock(|db| db.comments.get(&(T::type_name(), Some(id))).cloned())
+    }
+
+    pub(crate) fn set_comment_raw(
+        &self,
+        type_name: &'static str,
+        id: Option<i32>,
+        comment: String,
+    ) {
+        self.lock(|db| db.comments.insert((type_name, id), comment));
+    }
+}
@@ -0,0 +1,507 @@
+use std::any::Any;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+
+use eh_schema::schema::{DatabaseItem, DatabaseItemId, DatabaseItemWithId, Item};
+
+use crate::database::DatabaseHolder;
+
+#[derive(Debug, Error)]
+pub enum CsvError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Table is empty")]
+    Empty,
+    #[error("Table header is missing the `id` column")]
+    MissingIdColumn,
+    #[error("Table header should be `{}`", .expected)]
+    UnexpectedHeader { expected: &'static str },
+    #[error("Row {}: unterminated quoted cell", .row)]
+    UnterminatedQuote { row: usize },
+    #[error("Row {}: expected {} cells, but got {}", .row, .expected, .found)]
+    RowLength {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("Row {}: item `{}` is not present in the database", .row, .id)]
+    UnknownItem { row: usize, id: String },
+    #[error("Row {}: column `{}` is not an editable value", .row, .column)]
+    NotAValue { row: usize, column: String },
+    #[error("Column `{}` is not a field of `{}`", .column, .ty)]
+    UnknownColumn { column: String, ty: &'static str },
+    #[error("Row {}: `{}` is not a text of the item", .row, .path)]
+    NotAText { row: usize, path: String },
+    #[error("Row {}: invalid value in column `{}`: {}", .row, .column, .error)]
+    InvalidValue {
+        row: usize,
+        column: String,
+        error: serde_json::Error,
+    },
+}
+
+impl DatabaseHolder {
+    /// Exports selected fields of all items of the given type into a table
+    ///
+    /// Columns are field paths in snake case, with nested fields separated
+    /// by a dot, like `body.lifetime`. First column of the table is always
+    /// the item string ID, or the numeric ID for unmapped items. Fields that
+    /// are lists or structures are written as JSON, and fields that are left
+    /// at their default value are written as empty cells
+    ///
+    /// Table is written as TSV if the file extension is `.tsv`, and as CSV
+    /// otherwise
+    pub fn export_csv<T: Into<Item> + DatabaseItemWithId + Any + Serialize>(
+        &self,
+        path: impl AsRef<Path>,
+        columns: &[&str],
+    ) -> Result<(), CsvError> {
+        let path = path.as_ref();
+        let separator = separator(path);
+        let names = self.use_id_mappings(|ids| ids.get_inverse_ids());
+        let names = names.get(T::type_name());
+
+        let mut rows: Vec<(i32, Vec<String>)> = self.iter::<T, _>(|items| {
+            items
+                .map(|item| {
+                    let id = item.id().0;
+                    let json = serde_json::to_value(&*item).expect("Item should be serializable");
+                    let mut row = vec![names
+                        .and_then(|names| names.get(&id))
+                        .cloned()
+                        .unwrap_or_else(|| id.to_string())];
+                    row.extend(
+                        columns.iter().map(|column| {
+                            lookup(&json, column).map(format_value).unwrap_or_default()
+                        }),
+                    );
+                    (id, row)
+                })
+                .collect()
+        });
+        rows.sort_by_key(|(id, _)| *id);
+
+        let mut out = String::new();
+        let header = std::iter::once("id").chain(columns.iter().copied());
+        write_row(&mut out, header, separator);
+        for (_, row) in &rows {
+            write_row(&mut out, row.iter().map(|c| c.as_str()), separator);
+        }
+
+        if let Some(parent) = path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        fs_err::write(path, out)?;
+        Ok(())
+    }
+
+    /// Applies values edited in the table produced by [DatabaseHolder::export_csv]
+    /// back onto the database items
+    ///
+    /// All rows are validated before any changes are made, so a failed import
+    /// leaves the database untouched. Columns that are not fields of the item
+    /// type are rejected, and empty cells are skipped. Returns the amount of
+    /// the updated items
+    pub fn import_csv<T: Into<Item> + DatabaseItem + Any + Serialize + DeserializeOwned>(
+        self: &Arc<Self>,
+        path: impl AsRef<Path>,
+    ) -> Result<usize, CsvError> {
+        let path = path.as_ref();
+        let data = fs_err::read_to_string(path)?;
+        let mut rows = parse_rows(&data, separator(path))?.into_iter();
+        let (_, header) = rows.next().ok_or(CsvError::Empty)?;
+        if header.first().map(|h| h.as_str()) != Some("id") {
+            return Err(CsvError::MissingIdColumn);
+        }
+
+        let mut updated = vec![];
+        let mut columns_checked = false;
+        for (row, cells) in rows {
+            if cells.len() != header.len() {
+                return Err(CsvError::RowLength {
+                    row,
+                    expected: header.len(),
+                    found: cells.len(),
+                });
+            }
+            let string_id = &cells[0];
+            let id = if self.is_id_used::<T>(string_id) {
+                Some(self.id::<T>(string_id.as_str()))
+            } else {
+                string_id.parse::<i32>().ok().map(DatabaseItemId::new)
+            };
+            let item = id.and_then(|id| self.get_item::<T>(id));
+            let Some(item) = item else {
+                return Err(CsvError::UnknownItem {
+                    row,
+                    id: string_id.clone(),
+                });
+            };
+
+            let mut json =
+                serde_json::to_value(&*item.read()).expect("Item should be serializable");
+            if !columns_checked {
+                if let Some(column) = header[1..]
+                    .iter()
+                    .find(|column| !is_known_column::<T>(&json, column))
+                {
+                    return Err(CsvError::UnknownColumn {
+                        column: column.clone(),
+                        ty: T::type_name(),
+                    });
+                }
+                columns_checked = true;
+            }
+            for (column, cell) in header.iter().zip(&cells).skip(1) {
+                if cell.is_empty() {
+                    continue;
+                }
+                let Some(slot) = lookup_mut(&mut json, column) else {
+                    return Err(CsvError::NotAValue {
+                        row,
+                        column: column.clone(),
+                    });
+                };
+                *slot = parse_value(cell, slot).map_err(|error| CsvError::InvalidValue {
+                    row,
+                    column: column.clone(),
+                    error,
+                })?;
+            }
+            let value =
+                serde_json::from_value::<T>(json).map_err(|error| CsvError::InvalidValue {
+                    row,
+                    column: "id".to_string(),
+                    error,
+                })?;
+            updated.push((item, value));
+        }
+
+        let count = updated.len();
+        for (item, value) in updated {
+            *item.write() = value;
+        }
+        Ok(count)
+    }
+}
+
+pub(super) fn separator(path: &Path) -> char {
+    if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("tsv"))
+    {
+        '\t'
+    } else {
+        ','
+    }
+}
+
+/// Converts snake case field name into the serialized pascal case name
+pub(super) fn field_key(field: &str) -> String {
+    field
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn lookup<'a>(json: &'a Value, column: &str) -> Option<&'a Value> {
+    column
+        .split('.')
+        .try_fold(json, |value, field| value.get(field_key(field)))
+}
+
+/// Finds the field in the serialized item, inserting a null for missing
+/// leaf fields, since fields with default values are skipped during
+/// serialization
+fn lookup_mut<'a>(json: &'a mut Value, column: &str) -> Option<&'a mut Value> {
+    let mut value = json;
+    for field in column.split('.') {
+        value = value
+            .as_object_mut()?
+            .entry(field_key(field))
+            .or_insert(Value::Null);
+    }
+    Some(value)
+}
+
+/// Checks whether the column names a field of the item type
+///
+/// Deserialization skips unknown fields, so the column is probed with
+/// values of different types, and is known if any of them is rejected
+fn is_known_column<T: DeserializeOwned>(json: &Value, column: &str) -> bool {
+    [Value::Bool(true), Value::String(String::new())]
+        .into_iter()
+        .any(|probe| {
+            let mut json = json.clone();
+            let Some(slot) = lookup_mut(&mut json, column) else {
+                // Reported as not a value once the cell is applied
+                return true;
+            };
+            *slot = probe;
+            serde_json::from_value::<T>(json).is_err()
+        })
+}
+
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        // Schema floats are `f32`, print them as such to avoid noise like
+        // `0.10000000149011612`
+        Value::Number(n) => match n.as_f64() {
+            Some(f) if !n.is_i64() && !n.is_u64() && f as f32 as f64 == f => (f as f32).to_string(),
+            _ => n.to_string(),
+        },
+        Value::Bool(_) | Value::Array(_) | Value::Object(_) => value.to_string(),
+    }
+}
+
+/// Parses the cell, using the type of the current value as a hint
+fn parse_value(cell: &str, current: &Value) -> serde_json::Result<Value> {
+    match current {
+        Value::String(_) => Ok(Value::String(cell.to_string())),
+        Value::Null => {
+            Ok(serde_json::from_str(cell).unwrap_or_else(|_| Value::String(cell.to_string())))
+        }
+        _ => serde_json::from_str(cell),
+    }
+}
+
+pub(super) fn write_row<'a>(
+    out: &mut String,
+    cells: impl Iterator<Item = &'a str>,
+    separator: char,
+) {
+    for (i, cell) in cells.enumerate() {
+        if i > 0 {
+            out.push(separator);
+        }
+        if cell.contains([separator, '"', '\n', '\r']) {
+            out.push('"');
+            out.push_str(&cell.replace('"', "\"\""));
+            out.push('"');
+        } else {
+            out.push_str(cell);
+        }
+    }
+    out.push('\n');
+}
+
+/// Parses rows of the table, returning them alongside their 1-based line
+/// numbers
+pub(super) fn parse_rows(
+    data: &str,
+    separator: char,
+) -> Result<Vec<(usize, Vec<String>)>, CsvError> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut row_start = 1;
+    let mut chars = data.chars().peekable();
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => quoted = false,
+                '\n' => {
+                    line += 1;
+                    cell.push(c);
+                }
+                _ => cell.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if cell.is_empty() => quoted = true,
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut cell));
+                if !(row.len() == 1 && row[0].is_empty()) {
+                    rows.push((row_start, std::mem::take(&mut row)));
+                }
+                row.clear();
+                line += 1;
+                row_start = line;
+            }
+            c if c == separator => row.push(std::mem::take(&mut cell)),
+            c => cell.push(c),
+        }
+    }
+    if quoted {
+        return Err(CsvError::UnterminatedQuote { row: row_start });
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push((row_start, row));
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use eh_schema::schema::{Availability, Component, Faction};
+    use smart_output::testing::test_dir;
+
+    use super::{parse_rows, write_row, CsvError};
+    use crate::database::{DatabaseHolder, Remember};
+
+    #[test]
+    fn quoting_round_trip() {
+        let cells = [
+            "plain",
+            "",
+            "with,comma",
+            "with\ttab",
+            "with \"quotes\"",
+            "\"",
+            "multi\nline",
+            "crlf\r\nline",
+            " spaces ",
+        ];
+        for separator in [',', '\t'] {
+            let mut out = String::new();
+            write_row(&mut out, cells.into_iter(), separator);
+            write_row(&mut out, ["last"].into_iter(), separator);
+            let rows = parse_rows(&out, separator).unwrap();
+            // Line numbers count the line breaks inside the quoted cells
+            assert_eq!(
+                rows,
+                vec![
+                    (1, cells.map(String::from).to_vec()),
+                    (4, vec!["last".to_string()])
+                ],
+                "{out:?}"
+            );
+        }
+
+        let rows = parse_rows("id,name\r\na,\"b\"\"c\"\r\n\r\nd,e", ',').unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                (1, vec!["id".to_string(), "name".to_string()]),
+                (2, vec!["a".to_string(), "b\"c".to_string()]),
+                (4, vec!["d".to_string(), "e".to_string()]),
+            ]
+        );
+        assert!(matches!(
+            parse_rows("id\n\"a\nb", ','),
+            Err(CsvError::UnterminatedQuote { row: 2 })
+        ));
+    }
+
+    #[test]
+    fn item_round_trip() {
+        let (_dir, dir) = test_dir("csv_round_trip");
+        let path = dir.join("components.csv");
+        let db = DatabaseHolder::ephemeral();
+        db.add_id_range(0..100);
+        let faction = Faction::new(db.new_id("test:faction")).remember(&db).id;
+        let plain = Component::new(db.new_id("test:plain"), db.new_id("test:stats"))
+            .with_name("Plain")
+            .remember(&db)
+            .id;
+        let edited = Component::new(db.new_id("test:edited"), db.new_id("test:stats_2"))
+            .with_name("Laser, \"mk 2\"\nupgraded")
+            .with_availability(Availability::Rare)
+            .with_faction(faction)
+            .with_level(5)
+            .remember(&db)
+            .id;
+
+        let columns = ["name", "availability", "faction", "level"];
+        db.export_csv::<Component>(&path, &columns).unwrap();
+        let table = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            table,
+            format!(
+                "id,name,availability,faction,level\n\
+                 test:plain,Plain,0,,\n\
+                 test:edited,\"Laser, \"\"mk 2\"\"\nupgraded\",2,{},5\n",
+                faction.0
+            )
+        );
+
+        let edited_table = table
+            .replace("Plain,0,,", &format!("\"Plain, now\",5,{},3", faction.0))
+            .replace("mk 2", "mk 3")
+            .replace(",2,", ",4,");
+        std::fs::write(&path, &edited_table).unwrap();
+        assert_eq!(db.import_csv::<Component>(&path).unwrap(), 2);
+        let plain = db.get_item::<Component>(plain).unwrap().read().clone();
+        assert_eq!(plain.name, "Plain, now");
+        assert_eq!(plain.availability, Availability::LootOnly);
+        assert_eq!(plain.faction, Some(faction));
+        assert_eq!(plain.level, 3);
+        let edited = db.get_item::<Component>(edited).unwrap().read().clone();
+        assert_eq!(edited.name, "Laser, \"mk 3\"\nupgraded");
+        assert_eq!(edited.availability, Availability::Hidden);
+
+        db.export_csv::<Component>(&path, &columns).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), edited_table);
+
+        // Invalid enum value fails the whole import
+        std::fs::write(
+            &path,
+            "id,availability,name\ntest:plain,1,A\ntest:edited,9,B\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            db.import_csv::<Component>(&path),
+            Err(CsvError::InvalidValue { row: 3, .. })
+        ));
+        let name = db.with_item::<Component, _>(plain.id, |c| c.name.clone());
+        assert_eq!(name.as_deref(), Some("Plain, now"));
+    }
+
+    #[test]
+    fn unknown_columns() {
+        let (_dir, dir) = test_dir("csv_unknown_columns");
+        let path = dir.join("factions.tsv");
+        let db = DatabaseHolder::ephemeral();
+        db.add_id_range(0..100);
+        let faction = Faction::new(db.new_id("test:faction"))
+            .with_name("Old")
+            .remember(&db)
+            .id;
+
+        std::fs::write(&path, "id\tname\tno_such\ntest:faction\tNew\t1\n").unwrap();
+        assert!(matches!(
+            db.import_csv::<Faction>(&path),
+            Err(CsvError::UnknownColumn { column, ty: "Faction" }) if column == "no_such"
+        ));
+        std::fs::write(&path, "id\tname.inner\ntest:faction\tNew\n").unwrap();
+        assert!(matches!(
+            db.import_csv::<Faction>(&path),
+            Err(CsvError::NotAValue { row: 2, column }) if column == "name.inner"
+        ));
+        let name = db.with_item::<Faction, _>(faction, |f| f.name.clone());
+        assert_eq!(name.as_deref(), Some("Old"));
+
+        // Fields of any type are known, even if left at their default value
+        std::fs::write(
+            &path,
+            "id\tname\tno_territories\thome_star_distance\ntest:faction\tNew\ttrue\t\n",
+        )
+        .unwrap();
+        assert_eq!(db.import_csv::<Faction>(&path).unwrap(), 1);
+        let faction = db.get_item::<Faction>(faction).unwrap().read().clone();
+        assert_eq!(faction.name, "New");
+        assert!(faction.no_territories);
+    }
+}
@@ -1,5 +1,7 @@
+use crate::database::provenance::Provenance;
 use crate::database::DatabaseHolder;
 use eh_schema::schema::Item;
+use parking_lot::Mutex;
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 
@@ -7,13 +9,54 @@ use std::sync::Arc;
 pub struct DbItem<T: Into<Item>> {
     item: Option<T>,
     db: Arc<DatabaseHolder>,
+    provenance: Provenance,
+    comment: Option<String>,
+    batch: Option<Arc<PendingBatch>>,
+}
+
+/// Items of the handles returned by [DatabaseHolder::remember_all], stored
+/// under a single lock once the last handle is dropped
+#[derive(Debug)]
+pub(crate) struct PendingBatch {
+    db: Arc<DatabaseHolder>,
+    items: Mutex<Vec<(Item, Provenance, Option<String>)>>,
+}
+
+impl PendingBatch {
+    pub fn new(db: Arc<DatabaseHolder>) -> Self {
+        Self {
+            db,
+            items: Default::default(),
+        }
+    }
+}
+
+impl Drop for PendingBatch {
+    fn drop(&mut self) {
+        let items = std::mem::take(self.items.get_mut());
+        self.db.consume_items(items);
+    }
 }
 
 impl<T: Into<Item>> DbItem<T> {
+    #[track_caller]
     pub(crate) fn new(item: T, db: Arc<DatabaseHolder>) -> Self {
         Self {
             item: Some(item),
             db,
+            provenance: Provenance::capture(),
+            comment: None,
+            batch: None,
+        }
+    }
+
+    pub(crate) fn in_batch(item: T, provenance: Provenance, batch: Arc<PendingBatch>) -> Self {
+        Self {
+            item: Some(item),
+            db: batch.db.clone(),
+            provenance,
+            comment: None,
+            batch: Some(batch),
         }
     }
 }
@@ -44,16 +87,27 @@ impl<T: Into<Item>> DbItem<T> {
         self.item = item.map(actions);
         self
     }
+
+    /// Attaches a comment explaining the purpose of the item, see
+    /// [DatabaseHolder::set_comment]
+    pub fn with_comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
 }
 
 impl<T: Into<Item> + Clone> DbItem<T> {
     /// Creates a new database item that is a clone of the current one
     ///
     /// Don't forget to change ID, otherwise the app will panic
+    #[track_caller]
     pub fn new_clone(&self) -> Self {
         Self {
             item: self.item.clone(),
             db: self.db.clone(),
+            provenance: Provenance::capture(),
+            comment: None,
+            batch: None,
         }
     }
 }
@@ -75,7 +129,20 @@ impl<T: Into<Item>> DerefMut for DbItem<T> {
 impl<T: Into<Item>> Drop for DbItem<T> {
     fn drop(&mut self) {
         if let Some(i) = std::mem::take(&mut self.item) {
-            self.db.consume_item(i)
+            let item: Item = i.into();
+            if let Some(batch) = &self.batch {
+                let comment = std::mem::take(&mut self.comment);
+                batch
+                    .items
+                    .lock()
+                    .push((item, self.provenance.clone(), comment));
+                return;
+            }
+            if let Some(comment) = std::mem::take(&mut self.comment) {
+                self.db
+                    .set_comment_raw(item.inner_type_name(), item.id(), comment);
+            }
+            self.db.consume_item(item, self.provenance.clone())
         }
     }
 }
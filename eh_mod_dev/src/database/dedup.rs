@@ -0,0 +1,180 @@
+use std::any::Any;
+
+use ahash::AHashMap;
+
+use eh_schema::schema::{DatabaseItemWithId, Item, VisitIds};
+
+use crate::database::journal::JournalEntry;
+use crate::database::DatabaseHolder;
+
+impl DatabaseHolder {
+    /// Removes items of the given type that are equal to another item in
+    /// everything but the ID. See [DatabaseHolder::dedup_by]
+    pub fn dedup<T: Into<Item> + DatabaseItemWithId + Any + Clone + PartialEq>(&self) -> usize {
+        self.dedup_by::<T>(|a, b| {
+            let mut b = b.clone();
+            *b.id_mut() = a.id();
+            a == &b
+        })
+    }
+
+    /// Finds duplicate items of the given type, keeping the one with the
+    /// lowest ID. References to removed items are redirected to the kept
+    /// one across the whole database
+    ///
+    /// Comparator receives the kept item first. String IDs of removed items
+    /// keep pointing at the removed numeric IDs, so this should be called
+    /// once all the content is generated
+    ///
+    /// Returns the amount of removed items
+    pub fn dedup_by<T: Into<Item> + DatabaseItemWithId + Any + Clone>(
+        &self,
+        mut eq: impl FnMut(&T, &T) -> bool,
+    ) -> usize {
+        let (items, all_items) = self.lock(|db| {
+            let items = db.items.get(T::type_name()).cloned();
+            let all_items: Vec<_> = db.items.values().cloned().collect();
+            (items, all_items)
+        });
+        let Some(items) = items else {
+            return 0;
+        };
+
+        let mut remap = AHashMap::new();
+        {
+            let mut items = items.write();
+            let mut ids: Vec<i32> = items.keys().flatten().copied().collect();
+            ids.sort_unstable();
+
+            let mut canonical: Vec<T> = vec![];
+            for id in ids {
+                let item = items[&Some(id)].read();
+                let item = item
+                    .as_inner_any_ref()
+                    .downcast_ref::<T>()
+                    .expect("Items should be stored under their own type name");
+                match canonical.iter().find(|kept| eq(kept, item)) {
+                    Some(kept) => {
+                        remap.insert(id, kept.id().0);
+                    }
+                    None => canonical.push(item.clone()),
+                }
+            }
+
+            for id in remap.keys() {
+                items.remove(&Some(*id));
+                self.record(|| JournalEntry::ItemRemoved {
+                    ty: T::type_name().to_string(),
+                    id: Some(*id),
+                });
+            }
+        }
+        if remap.is_empty() {
+            return 0;
+        }
+
+        self.lock(|db| {
+            for id in remap.keys() {
+                db.provenance.remove(&(T::type_name(), Some(*id)));
+                db.comments.remove(&(T::type_name(), Some(*id)));
+            }
+        });
+
+        let type_name = T::type_name();
+        for items in all_items {
+            for item in items.read().values() {
+                let mut changed = false;
+                item.write().visit_ids(&mut |ty, id| {
+                    if ty == type_name {
+                        if let Some(kept) = remap.get(id) {
+                            *id = *kept;
+                            changed = true;
+                        }
+                    }
+                });
+                if changed {
+                    self.record_edits([item]);
+                }
+            }
+        }
+
+        remap.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eh_schema::schema::{Faction, FactionId, Ship};
+
+    use crate::database::{Database, DatabaseHolder, Remember};
+
+    /// Adds factions with the given names, and a ship referencing each one
+    fn factions(names: &[&str]) -> Database {
+        let db = DatabaseHolder::ephemeral();
+        db.add_id_range(0..100);
+        for (i, name) in names.iter().enumerate() {
+            let faction = Faction::new(db.new_id(format!("test:faction_{i}")))
+                .with_name(*name)
+                .remember(&db)
+                .id;
+            db.set_comment::<Faction>(faction, *name);
+            Ship::new(db.new_id(format!("test:ship_{i}")))
+                .with_faction(faction)
+                .remember(&db);
+        }
+        db
+    }
+
+    fn ship_factions(db: &Database, count: usize) -> Vec<FactionId> {
+        (0..count)
+            .map(|i| {
+                db.with_item::<Ship, _>(format!("test:ship_{i}"), |s| s.faction.unwrap())
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    fn faction(db: &Database, i: usize) -> FactionId {
+        db.id(format!("test:faction_{i}"))
+    }
+
+    #[test]
+    fn structural() {
+        let db = factions(&["A", "B", "A", "B", "A"]);
+
+        assert_eq!(db.dedup::<Faction>(), 3);
+        assert_eq!(db.count::<Faction>(), 2);
+        for removed in [2, 3, 4] {
+            assert!(!db.contains::<Faction>(faction(&db, removed)));
+            assert!(db.comment::<Faction>(faction(&db, removed)).is_none());
+        }
+        let (a, b) = (faction(&db, 0), faction(&db, 1));
+        assert_eq!(ship_factions(&db, 5), vec![a, b, a, b, a]);
+        assert_eq!(db.dedup::<Faction>(), 0);
+    }
+
+    #[test]
+    fn custom_comparator() {
+        let db = factions(&["Alpha", "Beta", "Another", "Gamma"]);
+
+        // Comparator receives the kept item first
+        let removed = db.dedup_by::<Faction>(|kept, item| {
+            assert!(kept.id.0 < item.id.0);
+            kept.name.chars().next() == item.name.chars().next()
+        });
+
+        assert_eq!(removed, 1);
+        assert!(!db.contains::<Faction>(faction(&db, 2)));
+        let ids: Vec<_> = [0, 1, 0, 3].into_iter().map(|i| faction(&db, i)).collect();
+        assert_eq!(ship_factions(&db, 4), ids);
+    }
+
+    #[test]
+    fn no_duplicates() {
+        let db = factions(&["A", "B"]);
+
+        assert_eq!(db.dedup::<Faction>(), 0);
+        assert_eq!(db.dedup::<Ship>(), 0);
+        assert_eq!(db.count::<Faction>(), 2);
+    }
+}
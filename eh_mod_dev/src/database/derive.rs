@@ -0,0 +1,65 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use tracing::error_span;
+
+use eh_schema::schema::{DatabaseItemId, DatabaseItemWithId, Item};
+
+use crate::database::{Database, DatabaseHolder, DatabaseIdLike};
+
+type Materializer = Box<dyn FnOnce(&Database) + Send + Sync>;
+
+/// Derived items waiting to be materialized, in the declaration order
+#[derive(Default)]
+struct Derivations {
+    pending: Vec<Materializer>,
+}
+
+impl DatabaseHolder {
+    /// Declares an item that is based on the prototype item
+    ///
+    /// Derived item is created when the database is saved, by cloning the
+    /// final state of the prototype and applying the edits on top, so
+    /// changes made to the prototype after this call are reflected in the
+    /// derived item. Prototype can itself be a derived item
+    ///
+    /// # Panics
+    /// Will panic on save if the prototype item is not present in the
+    /// database
+    pub fn derive<T: Into<Item> + DatabaseItemWithId + Any + Clone>(
+        self: &Arc<Self>,
+        id: impl DatabaseIdLike<T>,
+        prototype: impl DatabaseIdLike<T>,
+        edit: impl FnOnce(&mut T) + Send + Sync + 'static,
+    ) -> DatabaseItemId<T> {
+        let id = self.new_id(id);
+        // Raw IDs are captured to keep the closure `Send`
+        let (raw_id, raw_prototype) = (id.0, self.id(prototype).0);
+        self.extra_or_init::<Derivations>().edit(|d| {
+            d.pending.push(Box::new(move |db| {
+                let _guard = error_span!(
+                    "Materializing derived item",
+                    ty = T::type_name(),
+                    id = raw_id,
+                    prototype = raw_prototype
+                )
+                .entered();
+                let mut item = db
+                    .get_item::<T>(DatabaseItemId::new(raw_prototype))
+                    .expect("Prototype item should be present in the database")
+                    .new_clone();
+                *item.id_mut() = DatabaseItemId::new(raw_id);
+                edit(&mut item);
+            }))
+        });
+        id
+    }
+
+    /// Creates all items declared via [DatabaseHolder::derive]
+    pub(crate) fn materialize_derived(self: &Arc<Self>) {
+        let pending = std::mem::take(&mut self.extra_or_init::<Derivations>().write().pending);
+        for materialize in pending {
+            materialize(self);
+        }
+    }
+}
@@ -32,11 +32,11 @@ impl<T: Any + Send + Sync> ExtraItem<T> {
         self
     }
 
-    pub fn read(&self) -> MappedRwLockReadGuard<T> {
+    pub fn read(&self) -> MappedRwLockReadGuard<'_, T> {
         RwLockReadGuard::map(self.0.read(), |i| i.downcast_ref().unwrap())
     }
 
-    pub fn write(&self) -> MappedRwLockWriteGuard<T> {
+    pub fn write(&self) -> MappedRwLockWriteGuard<'_, T> {
         RwLockWriteGuard::map(self.0.write(), |i| i.downcast_mut().unwrap())
     }
 }
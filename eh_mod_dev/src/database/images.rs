@@ -0,0 +1,186 @@
+use std::sync::Arc;
+
+use image::{DynamicImage, Rgba, RgbaImage};
+use tracing::error_span;
+
+use eh_schema::schema::Ship;
+
+use crate::database::{DatabaseHolder, DatabaseIdLike, DbItem};
+
+/// Transformation producing a variant of an image
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImageTransform {
+    /// Rotates the hue of all pixels by the given amount of degrees
+    Recolor(f32),
+    /// Multiplies all pixels by the color
+    Tint(Rgba<u8>),
+    /// Replaces the color with its luminance
+    Grayscale,
+    /// Applies the transform only to the parts of the image covered by the
+    /// mask image, weighted by the mask luminance and alpha. Mask must be
+    /// inserted into the database and have the same size as the image
+    Masked {
+        mask: String,
+        transform: Box<ImageTransform>,
+    },
+    /// Applies transforms one after another
+    Chain(Vec<ImageTransform>),
+}
+
+impl ImageTransform {
+    pub fn masked(mask: impl Into<String>, transform: ImageTransform) -> Self {
+        ImageTransform::Masked {
+            mask: mask.into(),
+            transform: Box::new(transform),
+        }
+    }
+
+    fn apply(&self, db: &DatabaseHolder, image: &mut RgbaImage) {
+        match self {
+            ImageTransform::Recolor(degrees) => {
+                for pixel in image.pixels_mut() {
+                    *pixel = rotate_hue(*pixel, *degrees);
+                }
+            }
+            ImageTransform::Tint(Rgba([r, g, b, a])) => {
+                let tint = [*r, *g, *b, *a].map(|c| c as f32 / 255.0);
+                for pixel in image.pixels_mut() {
+                    for (c, t) in pixel.0.iter_mut().zip(tint) {
+                        *c = (*c as f32 * t).round() as u8;
+                    }
+                }
+            }
+            ImageTransform::Grayscale => {
+                for pixel in image.pixels_mut() {
+                    let Rgba([r, g, b, a]) = *pixel;
+                    let l = luminance(r, g, b);
+                    *pixel = Rgba([l, l, l, a]);
+                }
+            }
+            ImageTransform::Masked { mask, transform } => {
+                let _guard = error_span!("Applying image mask", mask).entered();
+                let mask = db
+                    .get_image(mask)
+                    .expect("Mask image should be present in the database")
+                    .to_rgba8();
+                if mask.dimensions() != image.dimensions() {
+                    panic!("Mask image should have the same size as the masked image")
+                }
+                let mut transformed = image.clone();
+                transform.apply(db, &mut transformed);
+                for ((pixel, new), m) in image
+                    .pixels_mut()
+                    .zip(transformed.pixels())
+                    .zip(mask.pixels())
+                {
+                    let Rgba([r, g, b, a]) = *m;
+                    let weight = luminance(r, g, b) as f32 / 255.0 * a as f32 / 255.0;
+                    for (c, n) in pixel.0.iter_mut().zip(new.0) {
+                        *c = (*c as f32 + (n as f32 - *c as f32) * weight).round() as u8;
+                    }
+                }
+            }
+            ImageTransform::Chain(transforms) => {
+                for transform in transforms {
+                    transform.apply(db, image);
+                }
+            }
+        }
+    }
+}
+
+impl DatabaseHolder {
+    /// Creates a variant of the inserted image by applying the transform,
+    /// inserting it under the new name
+    ///
+    /// # Panics
+    /// Will panic if the source image is not present in the database
+    pub fn image_variant(
+        &self,
+        source: &str,
+        transform: ImageTransform,
+        name: impl Into<String>,
+    ) -> Arc<DynamicImage> {
+        let name = name.into();
+        let _guard = error_span!("Creating image variant", source, name).entered();
+        let mut image = self
+            .get_image(source)
+            .expect("Source image should be present in the database")
+            .to_rgba8();
+        transform.apply(self, &mut image);
+        let image = Arc::new(DynamicImage::ImageRgba8(image));
+        self.lock(|db| db.images.insert(name, image.clone()));
+        image
+    }
+
+    /// Creates a copy of the ship with the new ID, using variants of the
+    /// ship sprites produced by the transform
+    ///
+    /// Variant of the model image is named after the new ship ID. Icon
+    /// image gets an `_icon` suffix, and is left untouched if it's not
+    /// present in the database, since vanilla icons can be shared between
+    /// ships
+    ///
+    /// # Panics
+    /// Will panic if the source ship or its model image are not present in
+    /// the database
+    #[track_caller]
+    pub fn ship_variant(
+        self: &Arc<Self>,
+        source: impl DatabaseIdLike<Ship>,
+        id: &str,
+        transform: ImageTransform,
+    ) -> DbItem<Ship> {
+        let _guard = error_span!("Creating ship variant", id).entered();
+        let mut ship = self
+            .get_item::<Ship>(source)
+            .expect("Source ship should be present in the database")
+            .new_clone();
+        ship.id = self.new_id(id);
+
+        self.image_variant(&ship.model_image, transform.clone(), id);
+        ship.model_image = id.to_string();
+
+        if self.get_image(&ship.icon_image).is_some() {
+            let icon = format!("{id}_icon");
+            self.image_variant(&ship.icon_image, transform, icon.as_str());
+            ship.icon_image = icon;
+        }
+        ship
+    }
+}
+
+fn luminance(r: u8, g: u8, b: u8) -> u8 {
+    (0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32).round() as u8
+}
+
+fn rotate_hue(pixel: Rgba<u8>, degrees: f32) -> Rgba<u8> {
+    let Rgba([r, g, b, a]) = pixel;
+    let [r, g, b] = [r, g, b].map(|c| c as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+    if chroma == 0.0 {
+        return pixel;
+    }
+    let hue = if max == r {
+        ((g - b) / chroma).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    };
+    let hue = (hue + degrees / 60.0).rem_euclid(6.0);
+    let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = max - chroma;
+    let channel = |c: f32| ((c + m) * 255.0).round() as u8;
+    Rgba([channel(r), channel(g), channel(b), a])
+}
@@ -5,7 +5,7 @@ use parking_lot::{
     MappedRwLockReadGuard, MappedRwLockWriteGuard, RwLockReadGuard, RwLockWriteGuard,
 };
 
-use eh_schema::schema::{DatabaseItem, Item};
+use eh_schema::schema::{DatabaseItem, DatabaseItemId, Item};
 
 use crate::database::{DatabaseHolder, SharedItem};
 
@@ -19,13 +19,46 @@ impl DatabaseHolder {
         drop(db_lock);
         let items = items.read();
         let values = DatabaseItemIter {
-            values: items.values(),
+            values: items.iter(),
             _type: Default::default(),
         };
 
         func(values)
     }
 
+    /// Runs the closure on every stored item of every type, along with the
+    /// item type name and ID
+    ///
+    /// Items are visited in order of their type names and IDs, so passes
+    /// like exports produce the same output on every run
+    pub fn iter_all(&self, mut func: impl FnMut(&'static str, Option<i32>, &Item)) {
+        let mut maps: Vec<_> = self.lock(|db| {
+            db.items
+                .iter()
+                .map(|(ty, items)| (*ty, items.clone()))
+                .collect()
+        });
+        maps.sort_by_key(|(ty, _)| *ty);
+        for (ty, items) in maps {
+            let items = items.read();
+            let mut items: Vec<_> = items.iter().collect();
+            items.sort_by_key(|(id, _)| **id);
+            for (id, item) in items {
+                func(ty, *id, &item.read());
+            }
+        }
+    }
+
+    /// Runs the closure on every stored item of every type
+    pub(crate) fn for_each_item(&self, mut func: impl FnMut(&Item)) {
+        let maps: Vec<_> = self.lock(|db| db.items.values().cloned().collect());
+        for items in maps {
+            for item in items.read().values() {
+                func(&item.read());
+            }
+        }
+    }
+
     pub fn iter_mut<T: Into<Item> + DatabaseItem + Any, U>(
         &self,
         func: impl FnOnce(DatabaseItemIterMut<'_, T>) -> U,
@@ -33,46 +66,135 @@ impl DatabaseHolder {
         let mut db_lock = self.inner.lock();
         let items = db_lock.items.entry(T::type_name()).or_default().clone();
         drop(db_lock);
+        let mut touched = vec![];
         let mut items = items.write();
         let values = DatabaseItemIterMut {
-            values: items.values_mut(),
+            values: items.iter_mut(),
+            touched: &mut touched,
             _type: Default::default(),
         };
 
-        func(values)
+        let result = func(values);
+        self.record_edits(&touched);
+        result
     }
 }
 
 pub struct DatabaseItemIter<'a, T: Into<Item> + DatabaseItem + Any> {
-    values: std::collections::hash_map::Values<'a, Option<i32>, SharedItem>,
+    values: std::collections::hash_map::Iter<'a, Option<i32>, SharedItem>,
     _type: PhantomData<T>,
 }
 
+impl<'a, T: Into<Item> + DatabaseItem + Any> DatabaseItemIter<'a, T> {
+    /// Yields the items along with their IDs
+    ///
+    /// Singleton items have no ID and are skipped
+    pub fn with_ids(self) -> DatabaseItemIdIter<'a, T> {
+        DatabaseItemIdIter { inner: self }
+    }
+
+    fn lock_item(value: &'a SharedItem) -> MappedRwLockReadGuard<'a, T> {
+        RwLockReadGuard::map(value.read(), |lock| {
+            lock.as_inner_any_ref().downcast_ref::<T>().unwrap()
+        })
+    }
+}
+
 impl<'a, T: Into<Item> + DatabaseItem + Any> Iterator for DatabaseItemIter<'a, T> {
     type Item = MappedRwLockReadGuard<'a, T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next_value = self.values.next()?;
+        let (_, next_value) = self.values.next()?;
 
-        return Some(RwLockReadGuard::map(next_value.read(), |lock| {
-            lock.as_inner_any_ref().downcast_ref::<T>().unwrap()
-        }));
+        Some(DatabaseItemIter::lock_item(next_value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<T: Into<Item> + DatabaseItem + Any> ExactSizeIterator for DatabaseItemIter<'_, T> {}
+
+pub struct DatabaseItemIdIter<'a, T: Into<Item> + DatabaseItem + Any> {
+    inner: DatabaseItemIter<'a, T>,
+}
+
+impl<'a, T: Into<Item> + DatabaseItem + Any> Iterator for DatabaseItemIdIter<'a, T> {
+    type Item = (DatabaseItemId<T>, MappedRwLockReadGuard<'a, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let (Some(id), next_value) = self.inner.values.next()? {
+                return Some((
+                    DatabaseItemId::new(*id),
+                    DatabaseItemIter::lock_item(next_value),
+                ));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
     }
 }
 
 pub struct DatabaseItemIterMut<'a, T: Into<Item> + DatabaseItem + Any> {
-    values: std::collections::hash_map::ValuesMut<'a, Option<i32>, SharedItem>,
+    values: std::collections::hash_map::IterMut<'a, Option<i32>, SharedItem>,
+    /// Items given out by the iterator, for the journal
+    touched: &'a mut Vec<SharedItem>,
     _type: PhantomData<T>,
 }
 
+impl<'a, T: Into<Item> + DatabaseItem + Any> DatabaseItemIterMut<'a, T> {
+    /// Yields the items along with their IDs
+    ///
+    /// Singleton items have no ID and are skipped
+    pub fn with_ids(self) -> DatabaseItemIdIterMut<'a, T> {
+        DatabaseItemIdIterMut { inner: self }
+    }
+
+    fn lock_item(&mut self, value: &'a SharedItem) -> MappedRwLockWriteGuard<'a, T> {
+        self.touched.push(value.clone());
+
+        RwLockWriteGuard::map(value.write(), |lock| {
+            lock.as_inner_any_mut().downcast_mut::<T>().unwrap()
+        })
+    }
+}
+
 impl<'a, T: Into<Item> + DatabaseItem + Any> Iterator for DatabaseItemIterMut<'a, T> {
     type Item = MappedRwLockWriteGuard<'a, T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next_value = self.values.next()?;
+        let (_, next_value) = self.values.next()?;
 
-        return Some(RwLockWriteGuard::map(next_value.write(), |lock| {
-            lock.as_inner_any_mut().downcast_mut::<T>().unwrap()
-        }));
+        Some(self.lock_item(next_value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<T: Into<Item> + DatabaseItem + Any> ExactSizeIterator for DatabaseItemIterMut<'_, T> {}
+
+pub struct DatabaseItemIdIterMut<'a, T: Into<Item> + DatabaseItem + Any> {
+    inner: DatabaseItemIterMut<'a, T>,
+}
+
+impl<'a, T: Into<Item> + DatabaseItem + Any> Iterator for DatabaseItemIdIterMut<'a, T> {
+    type Item = (DatabaseItemId<T>, MappedRwLockWriteGuard<'a, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let (Some(id), next_value) = self.inner.values.next()? {
+                return Some((DatabaseItemId::new(*id), self.inner.lock_item(next_value)));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
     }
 }
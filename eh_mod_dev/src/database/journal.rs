@@ -0,0 +1,253 @@
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use eh_schema::schema::Item;
+
+use crate::database::provenance::Provenance;
+use crate::database::{DatabaseHolder, SharedItem};
+
+/// Mutation of the database, recorded by [DatabaseHolder::record_journal]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "Event")]
+pub enum JournalEntry {
+    /// String ID got a numeric ID assigned, or was marked as used
+    IdAssigned {
+        kind: String,
+        string_id: String,
+        id: i32,
+        used: bool,
+        /// ID was assigned via [DatabaseHolder::set_id]
+        #[serde(default)]
+        explicit: bool,
+    },
+    /// String ID was unmarked as used
+    IdReleased { kind: String, string_id: String },
+    /// Item was stored in the database, possibly replacing an existing one
+    ItemAdded { source: String, item: Item },
+    /// Stored item was modified in place
+    ItemEdited { item: Item },
+    /// Item was removed from the database
+    ItemRemoved { ty: String, id: Option<i32> },
+}
+
+#[derive(Debug, Error)]
+pub enum JournalError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Line {}: invalid journal entry: {}", .line, .error)]
+    InvalidEntry {
+        line: usize,
+        error: serde_json::Error,
+    },
+}
+
+/// Append-only journal file, with one JSON entry per line
+#[derive(Debug)]
+pub(crate) struct Journal {
+    file: Mutex<fs_err::File>,
+}
+
+impl Journal {
+    /// Writes the entry right away, so the journal stays usable even if the
+    /// build crashes
+    pub fn record(&self, entry: &JournalEntry) {
+        let mut line = serde_json::to_string(entry).expect("Journal entry should be serializable");
+        line.push('\n');
+        self.file
+            .lock()
+            .write_all(line.as_bytes())
+            .expect("Should be able to write into the journal file");
+    }
+}
+
+impl DatabaseHolder {
+    /// Starts recording all mutations of the database into the journal
+    /// file, overwriting the existing one
+    ///
+    /// Items and IDs are recorded starting from this call, so it's usually
+    /// called right after loading the vanilla database. Mutations made
+    /// through the item handles are recorded once the handle is dropped, and
+    /// numeric IDs obtained without a string ID are not recorded at all
+    pub fn record_journal(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        let journal = Arc::new(Journal {
+            file: Mutex::new(fs_err::File::create(path)?),
+        });
+        self.ids.set_journal(Some(journal.clone()));
+        *self.journal.write() = Some(journal);
+        Ok(())
+    }
+
+    /// Stops the recording started by [DatabaseHolder::record_journal]
+    pub fn stop_journal(&self) {
+        self.ids.set_journal(None);
+        *self.journal.write() = None;
+    }
+
+    /// Re-applies the journal written by [DatabaseHolder::record_journal]
+    ///
+    /// All entries are parsed before any changes are made, so a malformed
+    /// journal leaves the database untouched. Replayed items have the
+    /// journal file as their provenance. Returns the amount of the applied
+    /// entries
+    pub fn replay_journal(&self, path: impl AsRef<Path>) -> Result<usize, JournalError> {
+        let path = path.as_ref();
+        let data = fs_err::read_to_string(path)?;
+        let entries: Vec<_> = data
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str::<JournalEntry>(line)
+                    .map_err(|error| JournalError::InvalidEntry { line: i + 1, error })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let source = Provenance::File(path.to_path_buf());
+        let count = entries.len();
+        for entry in entries {
+            match entry {
+                JournalEntry::IdAssigned {
+                    kind,
+                    string_id,
+                    id,
+                    used,
+                    explicit,
+                } => self.ids.restore_id(kind, string_id, id, used, explicit),
+                JournalEntry::IdReleased { kind, string_id } => {
+                    self.ids.forget_used_id(kind, &string_id);
+                }
+                JournalEntry::ItemAdded { item, .. } => self.replace_item(item, source.clone()),
+                JournalEntry::ItemEdited { item } => {
+                    let existing = self.lock(|db| {
+                        db.items
+                            .get(item.inner_type_name())
+                            .and_then(|items| items.read().get(&item.id()).cloned())
+                    });
+                    match existing {
+                        Some(existing) => *existing.write() = item,
+                        None => self.replace_item(item, source.clone()),
+                    }
+                }
+                JournalEntry::ItemRemoved { ty, id } => {
+                    self.lock(|db| {
+                        if let Some((&ty, items)) = db.items.get_key_value(ty.as_str()) {
+                            items.write().remove(&id);
+                            db.provenance.remove(&(ty, id));
+                            db.comments.remove(&(ty, id));
+                        }
+                    });
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    /// Records the entry if the journal is active
+    pub(crate) fn record(&self, entry: impl FnOnce() -> JournalEntry) {
+        if let Some(journal) = &*self.journal.read() {
+            journal.record(&entry());
+        }
+    }
+
+    /// Records the current state of the stored items as edited
+    pub(crate) fn record_edits<'a>(&self, items: impl IntoIterator<Item = &'a SharedItem>) {
+        if let Some(journal) = &*self.journal.read() {
+            for item in items {
+                journal.record(&JournalEntry::ItemEdited {
+                    item: item.read().clone(),
+                });
+            }
+        }
+    }
+
+    fn replace_item(&self, item: Item, source: Provenance) {
+        self.lock(|db| {
+            let type_name = item.inner_type_name();
+            let id = item.id();
+            db.items
+                .entry(type_name)
+                .or_default()
+                .write()
+                .insert(id, Arc::new(RwLock::new(item)));
+            db.provenance.insert((type_name, id), source);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eh_schema::schema::{DatabaseSettings, Faction};
+    use smart_output::testing::test_dir;
+
+    use super::JournalError;
+    use crate::database::tests::snapshot;
+    use crate::database::{DatabaseHolder, Remember};
+
+    #[test]
+    fn replay_reproduces_database() {
+        let (_dir, dir) = test_dir("journal_replay");
+        let journal = dir.join("journal.jsonl");
+
+        let db = DatabaseHolder::ephemeral();
+        db.add_id_range(0..100);
+        db.record_journal(&journal).unwrap();
+
+        Faction::new(db.new_id("test:kept"))
+            .with_name("Kept")
+            .remember(&db);
+        Faction::new(db.new_id("test:edited")).remember(&db);
+        Faction::new(db.new_id("test:removed")).remember(&db);
+        DatabaseSettings::new().remember(&db);
+        db.get_id_raw::<Faction>("test:reserved");
+        db.set_id::<Faction>("test:pinned", 50);
+        db.new_id::<Faction>("test:released");
+        db.forget_used_id::<Faction>("test:released");
+
+        db.with_item_mut::<Faction, _>("test:edited", |f| f.name = "Edited".to_string());
+        db.edit_many::<Faction>(["test:kept", "test:edited"], |f| f.no_territories = true);
+        // Replaces the stored item, rather than editing it
+        Faction::new(db.id("test:kept"))
+            .with_name("Replaced")
+            .remember(&db);
+        assert!(db.remove_item::<Faction>("test:removed"));
+
+        let replayed = DatabaseHolder::ephemeral();
+        replayed.add_id_range(0..100);
+        let applied = replayed.replay_journal(&journal).unwrap();
+
+        assert!(applied > 0);
+        assert_eq!(snapshot(&replayed), snapshot(&db));
+    }
+
+    #[test]
+    fn malformed_journal() {
+        let (_dir, dir) = test_dir("journal_malformed");
+        let journal = dir.join("journal.jsonl");
+
+        let db = DatabaseHolder::ephemeral();
+        db.add_id_range(0..100);
+        db.record_journal(&journal).unwrap();
+        Faction::new(db.new_id("test:faction")).remember(&db);
+        let mut data = fs_err::read_to_string(&journal).unwrap();
+        data.push_str("\n{\"Event\":\"Unknown\"}\n");
+        fs_err::write(&journal, data).unwrap();
+
+        let replayed = DatabaseHolder::ephemeral();
+        replayed.add_id_range(0..100);
+        let before = snapshot(&replayed);
+        let err = replayed.replay_journal(&journal).unwrap_err();
+
+        // Entries before the malformed one are not applied either
+        assert!(matches!(err, JournalError::InvalidEntry { line: 4, .. }));
+        assert_eq!(snapshot(&replayed), before);
+    }
+}
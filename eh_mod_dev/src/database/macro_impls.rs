@@ -16,7 +16,7 @@ macro_rules! process_arg_type {
 }
 macro_rules! process_arg_conversion {
     (DatabaseItemId<$ty:ty>, $arg:ident, $target:ident) => {
-        DatabaseItemId::new($target.lock(|db| $arg.into_new_id(&mut db.ids)))
+        DatabaseItemId::new($arg.into_new_id(&$target.ids))
     };
     ($ty:ty, $arg:ident, $target:ident) => {
         $arg.into()
@@ -28,6 +28,7 @@ macro_rules! constructor_impls {
         impl DatabaseHolder {
             $(
                 paste::paste! {
+                    #[track_caller]
                     pub fn [< new_ $name >](self: &Arc<Self>, $($arg: process_arg_type!($($arg_ty)*)),*) -> DbItem::<$ty> {
                         self.add_item(<$ty>::new($(process_arg_conversion!($($arg_ty)*, $arg, self)),*))
                     }
@@ -38,7 +39,7 @@ macro_rules! constructor_impls {
 }
 
 macro_rules! collections_impls {
-    ($($name:ident : $ty:ty),*) => {
+    ($($name:ident : $ty:ident { $($meta:tt)* }),*) => {
         impl DatabaseHolder {
             $(
                 paste::paste! {
@@ -61,9 +62,27 @@ macro_rules! collections_impls {
 }
 
 macro_rules! all_items_impls {
-    ($($name:ident : $ty:ty),*) => {
+    ($($name:ident : $ty:ident {
+        has_id: $has_id:tt,
+        is_setting: $is_setting:tt,
+        type_name: $type_name:literal
+    }),*) => {
+        /// All database item types, in the schema order
+        pub const ITEM_TYPES: &[ItemTypeInfo] = &[
+            $(
+                ItemTypeInfo {
+                    type_name: $type_name,
+                    has_id: $has_id,
+                    is_setting: $is_setting,
+                }
+            ),*
+        ];
+
         $(
+            item_type_impls!($has_id, $name, $ty);
+
             impl Remember for $ty {
+                #[track_caller]
                 fn remember(self, db: &Database) -> DbItem<Self> {
                     db.add_item(self)
                 }
@@ -72,6 +91,114 @@ macro_rules! all_items_impls {
     }
 }
 
+macro_rules! item_type_impls {
+    (true, $name:ident, $ty:ty) => {
+        impl DatabaseHolder {
+            paste::paste! {
+                pub fn [< $name _count >](&self) -> usize {
+                    self.count::<$ty>()
+                }
+                pub fn [< $name _exists >](&self, id: impl DatabaseIdLike<$ty>) -> bool {
+                    self.contains::<$ty>(id)
+                }
+                pub fn [< delete_ $name >](&self, id: impl DatabaseIdLike<$ty>) -> bool {
+                    self.remove_item::<$ty>(id)
+                }
+            }
+        }
+    };
+    (false, $name:ident, $ty:ty) => {
+        impl DatabaseHolder {
+            paste::paste! {
+                pub fn [< has_ $name >](&self) -> bool {
+                    self.count::<$ty>() > 0
+                }
+                pub fn [< delete_ $name >](&self) -> bool {
+                    self.remove_setting::<$ty>()
+                }
+            }
+        }
+    };
+}
+
+/// Metadata of the database item type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItemTypeInfo {
+    /// Name of the type, same as [DatabaseItem::type_name]
+    pub type_name: &'static str,
+    /// Whether items of the type are identified by ID
+    pub has_id: bool,
+    /// Whether the type is a setting, with at most one item in the database
+    pub is_setting: bool,
+}
+
 apply_constructors!(constructor_impls);
 apply_all_collections!(collections_impls);
 apply_all_items!(all_items_impls);
+
+#[cfg(test)]
+mod tests {
+    use eh_schema::schema::{DatabaseItem, Faction, UiSettings};
+
+    use super::ITEM_TYPES;
+    use crate::database::{DatabaseHolder, Remember};
+
+    #[test]
+    fn item_type_api() {
+        let info = ITEM_TYPES
+            .iter()
+            .find(|info| info.type_name == Faction::type_name())
+            .unwrap();
+        assert!(info.has_id && !info.is_setting);
+
+        let db = DatabaseHolder::ephemeral();
+        let faction = Faction::new(db.new_id("test:faction")).remember(&db).id;
+        UiSettings::new().remember(&db);
+        assert_eq!(db.faction_count(), 1);
+        assert!(db.faction_exists(faction));
+        assert!(db.has_ui_settings());
+
+        assert!(db.delete_faction("test:faction"));
+        assert!(!db.delete_faction(faction));
+        assert!(!db.faction_exists(faction));
+        assert!(db.delete_ui_settings());
+        assert!(!db.has_ui_settings());
+    }
+
+    #[test]
+    fn bulk_remember_and_edit() {
+        let db = DatabaseHolder::ephemeral();
+        let factions = (0..3)
+            .map(|i| Faction::new(db.new_id(format!("test:faction_{i}"))).with_name("Faction"))
+            .collect::<Vec<_>>();
+        let mut remembered = db.remember_all(factions);
+        remembered[0].name = "Edited".to_string();
+        let ids = remembered
+            .iter()
+            .map(|faction| faction.id)
+            .collect::<Vec<_>>();
+        assert_eq!(db.faction_count(), 0);
+        drop(remembered);
+        assert_eq!(db.faction_count(), 3);
+        let name = db.with_item::<Faction, _>(ids[0], |faction| faction.name.clone());
+        assert_eq!(name.as_deref(), Some("Edited"));
+
+        // Iterator is consumed before the database is locked
+        let copies = db.remember_all(ids.iter().map(|id| {
+            let mut faction = db.get_item::<Faction>(*id).unwrap().read().clone();
+            faction.id = db.new_id(format!("test:copy_{}", id.0));
+            faction
+        }));
+        drop(copies);
+        assert_eq!(db.faction_count(), 6);
+
+        let missing = db.new_id::<Faction>("test:missing");
+        let edited = db.edit_many(
+            ids.iter().copied().chain([missing]),
+            |faction: &mut Faction| faction.name = format!("{} {}", faction.name, faction.id.0),
+        );
+        assert_eq!(edited, 3);
+        let name = db.with_item::<Faction, _>(ids[1], |faction| faction.name.clone());
+        assert_eq!(name, Some(format!("Faction {}", ids[1].0)));
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::{Display, Formatter};
+use std::panic::Location;
+use std::path::PathBuf;
+
+use eh_schema::schema::DatabaseItem;
+
+use crate::database::{DatabaseHolder, DatabaseIdLike};
+
+/// Origin of the item in the database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Provenance {
+    /// Item was loaded from the file
+    File(PathBuf),
+    /// Item was created by the mod code
+    Code {
+        /// Location of the code that created the item
+        location: &'static Location<'static>,
+        /// Name of the tracing span, active at the moment of creation
+        ///
+        /// Only present when a tracing subscriber is enabled
+        span: Option<&'static str>,
+    },
+}
+
+impl Provenance {
+    /// Captures provenance of the item created by the caller
+    #[track_caller]
+    pub fn capture() -> Self {
+        Provenance::Code {
+            location: Location::caller(),
+            span: tracing::Span::current().metadata().map(|m| m.name()),
+        }
+    }
+}
+
+impl Display for Provenance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Provenance::File(path) => write!(f, "file `{}`", path.display()),
+            Provenance::Code { location, span } => {
+                write!(f, "code at `{location}`")?;
+                if let Some(span) = span {
+                    write!(f, " in `{span}`")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl DatabaseHolder {
+    /// Returns the origin of the item currently stored under the given ID
+    pub fn provenance<T: 'static + DatabaseItem>(
+        &self,
+        id: impl DatabaseIdLike<T>,
+    ) -> Option<Provenance> {
+        let id = id.into_id(&self.ids);
+        self.lock(|db| db.provenance.get(&(T::type_name(), Some(id))).cloned())
+    }
+
+    /// Returns the origin of the settings item of the given type
+    pub fn singleton_provenance<T: 'static + DatabaseItem>(&self) -> Option<Provenance> {
+        self.lock(|db| db.provenance.get(&(T::type_name(), None)).cloned())
+    }
+}
@@ -6,12 +6,15 @@ use parking_lot::{MappedRwLockReadGuard, MappedRwLockWriteGuard, RwLockWriteGuar
 use std::any::Any;
 use std::marker::PhantomData;
 use std::ops::DerefMut;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 /// Database item temporarily taken from the database
 pub struct StoredDbItem<T: Any> {
     item: SharedItem,
     db: Arc<DatabaseHolder>,
+    /// Whether write access was given out, for the journal
+    written: AtomicBool,
     _item: PhantomData<T>,
 }
 
@@ -20,6 +23,7 @@ impl<T: Any> StoredDbItem<T> {
         Self {
             item,
             db,
+            written: AtomicBool::new(false),
             _item: Default::default(),
         }
     }
@@ -45,6 +49,7 @@ impl<T: Any> StoredDbItem<T> {
 
     /// Provides write access to the underlying data
     pub fn write(&self) -> MappedRwLockWriteGuard<'_, T> {
+        self.written.store(true, Ordering::Relaxed);
         RwLockWriteGuard::map(self.item.write(), |i| {
             i.as_inner_any_mut().downcast_mut::<T>().unwrap()
         })
@@ -62,11 +67,20 @@ impl<T: Any + Clone + Into<Item>> StoredDbItem<T> {
     /// Creates a new database item that is a clone of the current one
     ///
     /// Don't forget to change ID, otherwise the app will panic
+    #[track_caller]
     pub fn new_clone(&self) -> DbItem<T> {
         DbItem::new(transmogrify(self.item.read().clone()), self.db.clone())
     }
 }
 
+impl<T: Any> Drop for StoredDbItem<T> {
+    fn drop(&mut self) {
+        if *self.written.get_mut() {
+            self.db.record_edits([&self.item]);
+        }
+    }
+}
+
 fn transmogrify<T: Any>(item: Item) -> T {
     *item.into_inner_any().downcast::<T>().unwrap()
 }
@@ -0,0 +1,321 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use eh_schema::schema::{
+    Character, Component, ComponentMod, DatabaseItem, DatabaseItemId, DatabaseItemWithId, Faction,
+    Item, Quest, QuestItem, Satellite, Ship,
+};
+
+use crate::database::csv::{field_key, parse_rows, separator, write_row, CsvError};
+use crate::database::DatabaseHolder;
+
+/// Serialized names of the fields that hold the player-visible texts
+const TEXT_FIELDS: &[&str] = &["Name", "Description", "Message", "ButtonText"];
+
+const HEADER: [&str; 4] = ["type", "id", "path", "text"];
+
+type InverseIds = AHashMap<Cow<'static, str>, AHashMap<i32, String>>;
+
+/// Edited text from the row of the table
+struct TextEdit {
+    row: usize,
+    path: String,
+    text: String,
+}
+
+/// Item with the applied edits, and the amount of the changed texts
+type PendingEdit = (usize, Box<dyn FnOnce()>);
+
+macro_rules! text_types {
+    ($($ty:ident),*) => {
+        impl DatabaseHolder {
+            fn collect_all_texts(&self, names: &InverseIds) -> Vec<[String; 4]> {
+                let mut rows = vec![];
+                $(self.collect_texts::<$ty>(names, &mut rows);)*
+                rows
+            }
+
+            fn edit_item_texts(
+                self: &Arc<Self>,
+                type_name: &str,
+                id: &str,
+                edits: &[TextEdit],
+            ) -> Result<Option<PendingEdit>, CsvError> {
+                $(
+                    if type_name == $ty::type_name() {
+                        return self.edit_texts::<$ty>(id, edits);
+                    }
+                )*
+                Err(CsvError::UnknownItem {
+                    row: edits[0].row,
+                    id: format!("{type_name} {id}"),
+                })
+            }
+        }
+    };
+}
+
+text_types!(
+    Component,
+    ComponentMod,
+    Ship,
+    Satellite,
+    Faction,
+    Character,
+    QuestItem,
+    Quest
+);
+
+impl DatabaseHolder {
+    /// Exports all player-visible texts into a single table, for
+    /// proofreading and localization handoff
+    ///
+    /// Covers the names and descriptions of the components, component mods,
+    /// ships, satellites, factions, characters, quest items and quests,
+    /// alongside the quest dialog messages and button texts. Each row holds
+    /// the item type, item string ID or numeric ID for unmapped items, path
+    /// of the text inside the item in snake case, like
+    /// `nodes.3.actions.0.button_text`, and the text itself. Empty texts
+    /// are skipped
+    ///
+    /// Table is written as TSV if the file extension is `.tsv`, and as CSV
+    /// otherwise
+    pub fn export_texts(&self, path: impl AsRef<Path>) -> Result<(), CsvError> {
+        let path = path.as_ref();
+        let separator = separator(path);
+        let names = self.use_id_mappings(|ids| ids.get_inverse_ids());
+        let rows = self.collect_all_texts(&names);
+
+        let mut out = String::new();
+        write_row(&mut out, HEADER.into_iter(), separator);
+        for row in &rows {
+            write_row(&mut out, row.iter().map(|c| c.as_str()), separator);
+        }
+
+        if let Some(parent) = path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        fs_err::write(path, out)?;
+        Ok(())
+    }
+
+    /// Applies texts edited in the table produced by
+    /// [DatabaseHolder::export_texts] back onto the database items
+    ///
+    /// Texts are matched by their item and path, so list entries like the
+    /// quest nodes should not be reordered between the export and the
+    /// import. All rows are validated before any changes are made, so a
+    /// failed import leaves the database untouched. Returns the amount of
+    /// the changed texts
+    pub fn import_texts(self: &Arc<Self>, path: impl AsRef<Path>) -> Result<usize, CsvError> {
+        let path = path.as_ref();
+        let data = fs_err::read_to_string(path)?;
+        let mut rows = parse_rows(&data, separator(path))?.into_iter();
+        let (_, header) = rows.next().ok_or(CsvError::Empty)?;
+        if header != HEADER {
+            return Err(CsvError::UnexpectedHeader {
+                expected: "type,id,path,text",
+            });
+        }
+
+        let mut items: BTreeMap<(String, String), Vec<TextEdit>> = Default::default();
+        for (row, cells) in rows {
+            if cells.len() != HEADER.len() {
+                return Err(CsvError::RowLength {
+                    row,
+                    expected: HEADER.len(),
+                    found: cells.len(),
+                });
+            }
+            let [type_name, id, path, text] =
+                <[String; 4]>::try_from(cells).expect("Row length is checked");
+            items
+                .entry((type_name, id))
+                .or_default()
+                .push(TextEdit { row, path, text });
+        }
+
+        let mut pending = vec![];
+        for ((type_name, id), edits) in &items {
+            pending.extend(self.edit_item_texts(type_name, id, edits)?);
+        }
+
+        let mut count = 0;
+        for (changed, apply) in pending {
+            apply();
+            count += changed;
+        }
+        Ok(count)
+    }
+
+    fn collect_texts<T: Into<Item> + DatabaseItemWithId + Any + Serialize>(
+        &self,
+        names: &InverseIds,
+        rows: &mut Vec<[String; 4]>,
+    ) {
+        let names = names.get(T::type_name());
+        let mut items: Vec<(i32, Value)> = self.iter::<T, _>(|items| {
+            items
+                .map(|item| {
+                    let json = serde_json::to_value(&*item).expect("Item should be serializable");
+                    (item.id().0, json)
+                })
+                .collect()
+        });
+        items.sort_by_key(|(id, _)| *id);
+
+        for (id, json) in items {
+            let id = names
+                .and_then(|names| names.get(&id))
+                .cloned()
+                .unwrap_or_else(|| id.to_string());
+            let mut texts = vec![];
+            find_texts(&json, &mut vec![], &mut texts);
+            for (path, text) in texts {
+                rows.push([T::type_name().to_string(), id.clone(), path, text]);
+            }
+        }
+    }
+
+    /// Applies the edits onto the serialized item, returning the updated
+    /// item to be written once all rows are validated
+    fn edit_texts<T: Into<Item> + DatabaseItem + Any + Serialize + DeserializeOwned>(
+        self: &Arc<Self>,
+        id: &str,
+        edits: &[TextEdit],
+    ) -> Result<Option<PendingEdit>, CsvError> {
+        let row = edits[0].row;
+        let item_id = if self.is_id_used::<T>(id) {
+            Some(self.id::<T>(id))
+        } else {
+            id.parse::<i32>().ok().map(DatabaseItemId::new)
+        };
+        let Some(item) = item_id.and_then(|id| self.get_item::<T>(id)) else {
+            return Err(CsvError::UnknownItem {
+                row,
+                id: id.to_string(),
+            });
+        };
+
+        let mut json = serde_json::to_value(&*item.read()).expect("Item should be serializable");
+        let mut changed = 0;
+        for edit in edits {
+            let Some(Value::String(text)) = lookup_text(&mut json, &edit.path) else {
+                return Err(CsvError::NotAText {
+                    row: edit.row,
+                    path: edit.path.clone(),
+                });
+            };
+            if *text != edit.text {
+                *text = edit.text.clone();
+                changed += 1;
+            }
+        }
+        if changed == 0 {
+            return Ok(None);
+        }
+
+        let value = serde_json::from_value::<T>(json).map_err(|error| CsvError::InvalidValue {
+            row,
+            column: "text".to_string(),
+            error,
+        })?;
+        Ok(Some((changed, Box::new(move || *item.write() = value))))
+    }
+}
+
+/// Collects the non-empty texts of the serialized item alongside their
+/// snake case paths
+fn find_texts(value: &Value, path: &mut Vec<String>, texts: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                path.push(snake_key(key));
+                match value {
+                    Value::String(text) if TEXT_FIELDS.contains(&key.as_str()) => {
+                        if !text.is_empty() {
+                            texts.push((path.join("."), text.clone()));
+                        }
+                    }
+                    _ => find_texts(value, path, texts),
+                }
+                path.pop();
+            }
+        }
+        Value::Array(values) => {
+            for (i, value) in values.iter().enumerate() {
+                path.push(i.to_string());
+                find_texts(value, path, texts);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Converts serialized pascal case field name into the snake case name
+fn snake_key(key: &str) -> String {
+    let mut out = String::with_capacity(key.len() + 4);
+    for (i, c) in key.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn lookup_text<'a>(json: &'a mut Value, path: &str) -> Option<&'a mut Value> {
+    path.split('.').try_fold(json, |value, field| match value {
+        Value::Array(values) => values.get_mut(field.parse::<usize>().ok()?),
+        Value::Object(fields) => fields.get_mut(&field_key(field)),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use eh_schema::schema::Faction;
+    use smart_output::testing::test_dir;
+
+    use crate::database::{DatabaseHolder, Remember};
+
+    #[test]
+    fn texts_round_trip() {
+        let (_dir, dir) = test_dir("eh_texts");
+        let path = dir.join("texts.csv");
+        let db = DatabaseHolder::ephemeral();
+        let id = Faction::new(db.new_id("test:faction"))
+            .with_name("Fedration, \"the\"")
+            .remember(&db)
+            .id;
+
+        db.export_texts(&path).unwrap();
+        let table = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            table,
+            "type,id,path,text\nFaction,test:faction,name,\"Fedration, \"\"the\"\"\"\n"
+        );
+
+        std::fs::write(&path, table.replace("Fedration", "Federation")).unwrap();
+        assert_eq!(db.import_texts(&path).unwrap(), 1);
+        assert_eq!(db.import_texts(&path).unwrap(), 0);
+        let name = db.with_item::<Faction, _>(id, |faction| faction.name.clone());
+        assert_eq!(name.as_deref(), Some("Federation, \"the\""));
+
+        std::fs::write(&path, "type,id,path,text\nFaction,test:faction,color,red\n").unwrap();
+        assert!(db.import_texts(&path).is_err());
+    }
+}
@@ -0,0 +1,203 @@
+use eh_schema::expression::Expression;
+use eh_schema::schema::{Fleet, GalaxySettings, LootContent, Technology};
+use tracing::{debug, error_span};
+
+use crate::database::Database;
+use crate::economy::{apply_technology_prices, PriceCurve, PriceRules, Rounding};
+
+/// Set of difficulty adjustments applied across all item categories at once
+///
+/// All multipliers default to `1.0`, which leaves the respective items
+/// untouched
+#[derive(Debug, Clone, PartialEq)]
+pub struct DifficultyProfile {
+    /// Replacement for the [GalaxySettings::enemy_level] expression
+    pub enemy_level_expr: Option<Expression>,
+    /// Multiplier for the amounts of money, items and resources in all loot
+    pub loot_multiplier: f32,
+    /// Multiplier for the technology research prices
+    pub price_multiplier: f32,
+    /// Multiplier for the amount of enemies in combat
+    ///
+    /// Affects enemy ship counts of all combat rules, and the amount of
+    /// specific ships in fleets
+    pub fleet_size_scale: f32,
+}
+
+impl Default for DifficultyProfile {
+    fn default() -> Self {
+        Self {
+            enemy_level_expr: None,
+            loot_multiplier: 1.0,
+            price_multiplier: 1.0,
+            fleet_size_scale: 1.0,
+        }
+    }
+}
+
+impl DifficultyProfile {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_enemy_level_expr(mut self, expr: impl Into<Expression>) -> Self {
+        self.enemy_level_expr = Some(expr.into());
+        self
+    }
+
+    pub fn with_loot_multiplier(mut self, multiplier: f32) -> Self {
+        self.loot_multiplier = multiplier;
+        self
+    }
+
+    pub fn with_price_multiplier(mut self, multiplier: f32) -> Self {
+        self.price_multiplier = multiplier;
+        self
+    }
+
+    pub fn with_fleet_size_scale(mut self, scale: f32) -> Self {
+        self.fleet_size_scale = scale;
+        self
+    }
+
+    /// Applies the profile to all items currently present in the database
+    ///
+    /// Loot scaling edits `Loot` items in place, so loot created via content
+    /// caching after this call will not be scaled
+    ///
+    /// # Panics
+    /// Will panic if enemy level expression is set, but [GalaxySettings] are
+    /// missing from the database
+    pub fn apply(&self, db: &Database) {
+        let _guard = error_span!("Applying difficulty profile", profile=?self).entered();
+
+        if let Some(expr) = &self.enemy_level_expr {
+            db.get_singleton::<GalaxySettings>()
+                .expect("GalaxySettings should be present to set enemy level")
+                .edit(|s| s.enemy_level = expr.clone());
+        }
+
+        if self.loot_multiplier != 1.0 {
+            db.loot_iter_mut(|loot| {
+                for mut loot in loot {
+                    scale_loot(&mut loot.loot, self.loot_multiplier);
+                }
+            });
+        }
+
+        if self.price_multiplier != 1.0 {
+            let rules = PriceRules::new(PriceCurve::Scale(self.price_multiplier as f64))
+                .with_rounding(Rounding::Nearest)
+                .with_min_price(1);
+            let report = apply_technology_prices(db, &rules, |_: &Technology| true);
+            debug!("Technology prices after difficulty scaling:\n{report}");
+        }
+
+        if self.fleet_size_scale != 1.0 {
+            db.combat_rules_iter_mut(|rules| {
+                for mut rules in rules {
+                    rules.initial_enemy_ships =
+                        scale_expression(&rules.initial_enemy_ships, self.fleet_size_scale);
+                    rules.max_enemy_ships =
+                        scale_expression(&rules.max_enemy_ships, self.fleet_size_scale);
+                }
+            });
+            db.fleet_iter_mut(|fleets| {
+                for mut fleet in fleets {
+                    scale_fleet(&mut fleet, self.fleet_size_scale);
+                }
+            });
+        }
+    }
+}
+
+/// Scales amounts of everything given out by the loot
+///
+/// Nested loot is scaled recursively, while the amount of rolls for random
+/// item lists is kept as is. Value ratios are scaled quadratically
+pub fn scale_loot(loot: &mut LootContent, multiplier: f32) {
+    let times = |n: i32| -> i32 { (n as f32 * multiplier) as i32 };
+    match loot {
+        LootContent::None(_) => {}
+        LootContent::SomeMoney(m) => {
+            m.value_ratio *= multiplier * multiplier;
+            m.value_ratio = m.value_ratio.min(1000.0);
+        }
+        LootContent::Fuel(_) => {}
+        LootContent::Money(m) => {
+            m.min_amount = times(m.min_amount);
+            m.max_amount = times(m.max_amount);
+        }
+        LootContent::Stars(s) => {
+            s.min_amount = times(s.min_amount);
+            s.max_amount = times(s.max_amount);
+        }
+        LootContent::StarMap(_) => {}
+        LootContent::RandomComponents(c) => {
+            c.min_amount = times(c.min_amount);
+            c.max_amount = times(c.max_amount);
+            c.value_ratio *= multiplier * multiplier;
+        }
+        LootContent::RandomItems(i) => {
+            // Only upgrade inner loot, not min/max amounts
+            for item in &mut i.items {
+                scale_loot(&mut item.loot, multiplier)
+            }
+        }
+        LootContent::AllItems(i) => {
+            for item in &mut i.items {
+                scale_loot(&mut item.loot, multiplier)
+            }
+        }
+        LootContent::ItemsWithChance(i) => {
+            for item in &mut i.items {
+                scale_loot(&mut item.loot, multiplier)
+            }
+        }
+        LootContent::QuestItem(i) => {
+            i.min_amount = times(i.min_amount);
+            i.max_amount = times(i.max_amount);
+        }
+        LootContent::Ship(_) => {}
+        LootContent::EmptyShip(_) => {}
+        LootContent::Component(c) => {
+            c.min_amount = times(c.min_amount);
+            c.max_amount = times(c.max_amount);
+        }
+        LootContent::Blueprint(_) => {}
+        LootContent::ResearchPoints(rp) => {
+            rp.min_amount = times(rp.min_amount);
+            rp.max_amount = times(rp.max_amount);
+        }
+        LootContent::Satellite(sat) => {
+            sat.min_amount = times(sat.min_amount);
+            sat.max_amount = times(sat.max_amount);
+        }
+    }
+}
+
+/// Scales a numeric expression, keeping plain integers as integers
+fn scale_expression(expr: &Expression, scale: f32) -> Expression {
+    match expr.as_str().trim().parse::<i32>() {
+        Ok(value) => ((value as f32 * scale).round() as i32).max(1).to_string(),
+        Err(_) => format!("({expr}) * {scale}"),
+    }
+    .into()
+}
+
+/// Resizes the list of specific fleet ships, cycling through the existing
+/// ships when growing, and keeping at least one ship when shrinking
+fn scale_fleet(fleet: &mut Fleet, scale: f32) {
+    if fleet.specific_ships.is_empty() {
+        return;
+    }
+    let target = ((fleet.specific_ships.len() as f32 * scale).round() as usize).max(1);
+    let ships = fleet
+        .specific_ships
+        .iter()
+        .cycle()
+        .take(target)
+        .copied()
+        .collect();
+    fleet.specific_ships = ships;
+}
@@ -0,0 +1,468 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use ahash::{AHashMap, AHashSet};
+
+use eh_schema::schema::{Component, QuestItem, ShipBuild, Technology};
+
+use crate::database::Database;
+
+/// Curve for deriving the item price from its tier
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PriceCurve {
+    /// `base + per_tier * tier`, ignoring the existing price
+    Linear { base: f64, per_tier: f64 },
+    /// `base * factor ^ tier`, ignoring the existing price
+    Exponential { base: f64, factor: f64 },
+    /// Multiplies the existing price by a constant
+    Scale(f64),
+    /// Multiplies the existing price by `factor ^ tier`
+    ScalePerTier(f64),
+}
+
+impl PriceCurve {
+    pub fn linear(base: f64, per_tier: f64) -> Self {
+        Self::Linear { base, per_tier }
+    }
+
+    pub fn exponential(base: f64, factor: f64) -> Self {
+        Self::Exponential { base, factor }
+    }
+
+    /// Computes the raw, unrounded price
+    pub fn price(&self, tier: u32, current: i32) -> f64 {
+        let tier_f = tier as f64;
+        match *self {
+            PriceCurve::Linear { base, per_tier } => base + per_tier * tier_f,
+            PriceCurve::Exponential { base, factor } => base * factor.powf(tier_f),
+            PriceCurve::Scale(mult) => current as f64 * mult,
+            PriceCurve::ScalePerTier(factor) => current as f64 * factor.powf(tier_f),
+        }
+    }
+}
+
+/// Rounding mode for the computed prices
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Rounding {
+    /// Rounds down, matching integer division of non-negative prices
+    #[default]
+    Down,
+    Up,
+    Nearest,
+    /// Rounds to the nearest multiple of the given step
+    ToMultiple(i32),
+}
+
+impl Rounding {
+    pub fn round(&self, price: f64) -> i32 {
+        match *self {
+            Rounding::Down => price.floor() as i32,
+            Rounding::Up => price.ceil() as i32,
+            Rounding::Nearest => price.round() as i32,
+            Rounding::ToMultiple(step) => {
+                let step = step.max(1) as f64;
+                ((price / step).round() * step) as i32
+            }
+        }
+    }
+}
+
+/// Complete pricing rules: the curve, rounding and final price clamping
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceRules {
+    pub curve: PriceCurve,
+    pub rounding: Rounding,
+    pub min_price: i32,
+    pub max_price: i32,
+    /// Keeps items that are currently free at zero price
+    pub keep_free: bool,
+}
+
+impl PriceRules {
+    pub fn new(curve: PriceCurve) -> Self {
+        Self {
+            curve,
+            rounding: Default::default(),
+            min_price: 0,
+            max_price: i32::MAX,
+            keep_free: true,
+        }
+    }
+
+    pub fn with_rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    pub fn with_min_price(mut self, min_price: i32) -> Self {
+        self.min_price = min_price;
+        self
+    }
+
+    pub fn with_max_price(mut self, max_price: i32) -> Self {
+        self.max_price = max_price;
+        self
+    }
+
+    pub fn with_keep_free(mut self, keep_free: bool) -> Self {
+        self.keep_free = keep_free;
+        self
+    }
+
+    /// Computes the final price for an item of the given tier
+    pub fn price(&self, tier: u32, current: i32) -> i32 {
+        if self.keep_free && current == 0 {
+            return 0;
+        }
+        self.rounding
+            .round(self.curve.price(tier, current))
+            .clamp(self.min_price, self.max_price)
+    }
+}
+
+/// Computes tiers of all technologies in the database
+///
+/// Tier of a technology is the length of the longest dependency chain
+/// leading to it, so technologies without dependencies are tier 0.
+/// Dependency cycles and missing dependencies are ignored
+pub fn technology_tiers(db: &Database) -> AHashMap<i32, u32> {
+    let deps: AHashMap<i32, Vec<i32>> = db.iter::<Technology, _>(|techs| {
+        techs
+            .map(|t| (t.id().0, t.dependencies().iter().map(|d| d.0).collect()))
+            .collect()
+    });
+
+    fn visit(
+        id: i32,
+        deps: &AHashMap<i32, Vec<i32>>,
+        tiers: &mut AHashMap<i32, u32>,
+        visiting: &mut Vec<i32>,
+    ) -> u32 {
+        if let Some(tier) = tiers.get(&id) {
+            return *tier;
+        }
+        if visiting.contains(&id) {
+            return 0;
+        }
+        visiting.push(id);
+        let tier = deps
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .filter(|dep| deps.contains_key(dep))
+            .map(|dep| visit(*dep, deps, tiers, visiting) + 1)
+            .max()
+            .unwrap_or_default();
+        visiting.pop();
+        tiers.insert(id, tier);
+        tier
+    }
+
+    let mut tiers = AHashMap::default();
+    let mut visiting = vec![];
+    for id in deps.keys() {
+        visit(*id, &deps, &mut tiers, &mut visiting);
+    }
+    tiers
+}
+
+/// Applies pricing rules to all technologies matching the filter
+///
+/// Components and ship builds have no price of their own, the game derives
+/// their prices from the level and the hull, so technologies are the main
+/// way of controlling the economy. See [apply_component_prices] and
+/// [apply_ship_build_prices] for selecting technologies by their items
+pub fn apply_technology_prices(
+    db: &Database,
+    rules: &PriceRules,
+    filter: impl Fn(&Technology) -> bool,
+) -> PriceReport {
+    let tiers = technology_tiers(db);
+    let mut report = PriceReport::default();
+    db.iter_mut::<Technology, _>(|techs| {
+        for mut tech in techs {
+            if !filter(&tech) {
+                continue;
+            }
+            let tier = tiers.get(&tech.id().0).copied().unwrap_or_default();
+            let old = *tech.price();
+            let new = rules.price(tier, old);
+            tech.set_price(new);
+            report.push(tech.id().0, tier, old, new);
+        }
+    });
+    report
+}
+
+/// Applies pricing rules to the technologies of all components matching
+/// the filter
+pub fn apply_component_prices(
+    db: &Database,
+    rules: &PriceRules,
+    filter: impl Fn(&Component) -> bool,
+) -> PriceReport {
+    let components: AHashSet<i32> =
+        db.component_iter(|components| components.filter(|c| filter(c)).map(|c| c.id.0).collect());
+    apply_technology_prices(
+        db,
+        rules,
+        |tech| matches!(tech, Technology::Component(t) if components.contains(&t.item_id.0)),
+    )
+}
+
+/// Applies pricing rules to the technologies of the ships of all ship builds
+/// matching the filter
+///
+/// Technologies unlock ships rather than builds, so a ship is repriced if
+/// any of its builds matches
+pub fn apply_ship_build_prices(
+    db: &Database,
+    rules: &PriceRules,
+    filter: impl Fn(&ShipBuild) -> bool,
+) -> PriceReport {
+    let ships: AHashSet<i32> =
+        db.ship_build_iter(|builds| builds.filter(|b| filter(b)).map(|b| b.ship_id.0).collect());
+    apply_technology_prices(
+        db,
+        rules,
+        |tech| matches!(tech, Technology::Ship(t) if ships.contains(&t.item_id.0)),
+    )
+}
+
+/// Applies pricing rules to all quest items matching the filter
+///
+/// Quest items have no tiers, so all of them are priced as tier 0
+pub fn apply_quest_item_prices(
+    db: &Database,
+    rules: &PriceRules,
+    filter: impl Fn(&QuestItem) -> bool,
+) -> PriceReport {
+    let mut report = PriceReport::default();
+    db.quest_item_iter_mut(|items| {
+        for mut item in items {
+            if !filter(&item) {
+                continue;
+            }
+            let old = item.price;
+            item.price = rules.price(0, old);
+            report.push(item.id.0, 0, old, item.price);
+        }
+    });
+    report
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceChange {
+    pub id: i32,
+    pub tier: u32,
+    pub old: i32,
+    pub new: i32,
+}
+
+/// Price distribution over a group of items
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceStats {
+    pub count: usize,
+    pub min: i32,
+    pub max: i32,
+    pub mean: f64,
+}
+
+impl PriceStats {
+    fn of(prices: impl IntoIterator<Item = i32>) -> Option<Self> {
+        let prices: Vec<_> = prices.into_iter().collect();
+        Some(Self {
+            count: prices.len(),
+            min: *prices.iter().min()?,
+            max: *prices.iter().max()?,
+            mean: prices.iter().map(|p| *p as f64).sum::<f64>() / prices.len() as f64,
+        })
+    }
+}
+
+/// Summary of the price changes done by a single pass
+#[derive(Debug, Clone, Default)]
+pub struct PriceReport {
+    pub changes: Vec<PriceChange>,
+}
+
+impl PriceReport {
+    fn push(&mut self, id: i32, tier: u32, old: i32, new: i32) {
+        self.changes.push(PriceChange { id, tier, old, new })
+    }
+
+    /// Distribution of the resulting prices for every tier
+    pub fn distribution(&self) -> BTreeMap<u32, PriceStats> {
+        let mut by_tier: BTreeMap<u32, Vec<i32>> = Default::default();
+        for change in &self.changes {
+            by_tier.entry(change.tier).or_default().push(change.new);
+        }
+        by_tier
+            .into_iter()
+            .filter_map(|(tier, prices)| Some((tier, PriceStats::of(prices)?)))
+            .collect()
+    }
+
+    /// Distribution of all resulting prices
+    pub fn total(&self) -> Option<PriceStats> {
+        PriceStats::of(self.changes.iter().map(|c| c.new))
+    }
+}
+
+impl Display for PriceReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "tier | count |    min |    max |     mean")?;
+        for (tier, stats) in self.distribution() {
+            writeln!(
+                f,
+                "{tier:>4} | {:>5} | {:>6} | {:>6} | {:>8.1}",
+                stats.count, stats.min, stats.max, stats.mean
+            )?;
+        }
+        if let Some(stats) = self.total() {
+            writeln!(
+                f,
+                " all | {:>5} | {:>6} | {:>6} | {:>8.1}",
+                stats.count, stats.min, stats.max, stats.mean
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eh_schema::schema::{Component, ShipBuild, Technology, TechnologyId};
+
+    use crate::database::{Database, DatabaseHolder, Remember};
+
+    use super::{
+        apply_component_prices, apply_ship_build_prices, technology_tiers, PriceCurve, PriceRules,
+        Rounding,
+    };
+
+    #[test]
+    fn price_curves() {
+        assert_eq!(PriceCurve::linear(10.0, 5.0).price(0, 999), 10.0);
+        assert_eq!(PriceCurve::linear(10.0, 5.0).price(3, 999), 25.0);
+        assert_eq!(PriceCurve::exponential(10.0, 2.0).price(0, 999), 10.0);
+        assert_eq!(PriceCurve::exponential(10.0, 2.0).price(3, 999), 80.0);
+        assert_eq!(PriceCurve::Scale(0.2).price(3, 50), 10.0);
+        assert_eq!(PriceCurve::ScalePerTier(2.0).price(3, 5), 40.0);
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(Rounding::Down.round(2.7), 2);
+        assert_eq!(Rounding::Up.round(2.2), 3);
+        assert_eq!(Rounding::Nearest.round(2.5), 3);
+        assert_eq!(Rounding::Nearest.round(2.4), 2);
+        assert_eq!(Rounding::ToMultiple(10).round(44.0), 40);
+        assert_eq!(Rounding::ToMultiple(10).round(45.0), 50);
+        // Non-positive steps are treated as 1
+        assert_eq!(Rounding::ToMultiple(0).round(2.6), 3);
+    }
+
+    #[test]
+    fn price_rules() {
+        let rules = PriceRules::new(PriceCurve::Scale(0.2));
+        assert_eq!(rules.price(0, 99), 19);
+        assert_eq!(rules.price(0, 0), 0);
+
+        let rules = rules
+            .with_rounding(Rounding::Up)
+            .with_min_price(5)
+            .with_max_price(15);
+        assert_eq!(rules.price(0, 1), 5);
+        assert_eq!(rules.price(0, 51), 11);
+        assert_eq!(rules.price(0, 1000), 15);
+        // Free items stay free despite the minimum price
+        assert_eq!(rules.price(0, 0), 0);
+        assert_eq!(rules.with_keep_free(false).price(0, 0), 5);
+    }
+
+    fn tech(db: &Database, id: &str, deps: &[TechnologyId]) -> TechnologyId {
+        let component = Component::new(db.new_id(id), db.new_id(id));
+        let tech = Technology::component(db.new_id(id), component.id)
+            .with_price(100)
+            .with_dependencies(deps.to_vec())
+            .wrap();
+        let id = *tech.id();
+        component.remember(db);
+        tech.remember(db);
+        id
+    }
+
+    #[test]
+    fn tiers() {
+        let db = DatabaseHolder::ephemeral();
+        db.add_id_range(0..100);
+        let root = tech(&db, "test:root", &[]);
+        let missing = TechnologyId::new(99);
+        let a = tech(&db, "test:a", &[root, missing]);
+        let b = tech(&db, "test:b", &[root, a]);
+
+        let tiers = technology_tiers(&db);
+        assert_eq!(tiers[&root.0], 0);
+        assert_eq!(tiers[&a.0], 1);
+        assert_eq!(tiers[&b.0], 2);
+    }
+
+    #[test]
+    fn tiers_cycle() {
+        let db = DatabaseHolder::ephemeral();
+        db.add_id_range(0..100);
+        let a = db.new_id("test:a");
+        let b = db.new_id("test:b");
+        let c = tech(&db, "test:c", &[b]);
+        for (id, dep) in [(a, b), (b, a)] {
+            Technology::component(id, db.id("test:c"))
+                .with_dependencies(vec![dep])
+                .wrap()
+                .remember(&db);
+        }
+
+        // Cycle is broken at whichever technology is visited first, and
+        // dependents of the cycle are still placed after it
+        let tiers = technology_tiers(&db);
+        assert_eq!(tiers.len(), 3);
+        let mut cycle = [tiers[&a.0], tiers[&b.0]];
+        cycle.sort();
+        assert_eq!(cycle, [1, 2]);
+        assert_eq!(tiers[&c.0], tiers[&b.0] + 1);
+    }
+
+    #[test]
+    fn item_prices() {
+        let db = DatabaseHolder::ephemeral();
+        db.add_id_range(0..100);
+        let cheap = tech(&db, "test:cheap", &[]);
+        let expensive = tech(&db, "test:expensive", &[cheap]);
+        let ship = db.new_id("test:ship");
+        let ship_tech = Technology::ship(db.new_id("test:ship"), ship)
+            .with_price(100)
+            .wrap()
+            .remember(&db)
+            .id()
+            .to_owned();
+        ShipBuild::new(db.new_id("test:build"), ship).remember(&db);
+
+        let rules = PriceRules::new(PriceCurve::linear(10.0, 5.0));
+        let report = apply_component_prices(&db, &rules, |c| {
+            c.id == db.id::<Component>("test:expensive")
+        });
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(report.changes[0].id, expensive.0);
+        assert_eq!((report.changes[0].old, report.changes[0].new), (100, 15));
+
+        let report = apply_ship_build_prices(&db, &rules, |_| true);
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(report.changes[0].id, ship_tech.0);
+        assert_eq!(report.changes[0].new, 10);
+
+        let price = |id| *db.get_item::<Technology>(id).unwrap().read().price();
+        assert_eq!(price(cheap), 100);
+        assert_eq!(price(expensive), 15);
+        assert_eq!(price(ship_tech), 10);
+    }
+}
@@ -0,0 +1,3 @@
+//! Parser of the game expressions, see [eh_schema::expression]
+
+pub use eh_schema::expression::*;
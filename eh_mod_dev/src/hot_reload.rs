@@ -0,0 +1,401 @@
+//! Notifying the running game about the saved changes, and rebuilding the
+//! database when its source files change
+//!
+//! [Watch] splits the build into the base and the generators reading the
+//! source files. Once the sources change, only the generators reading them
+//! are re-run, while the items of the rest are replayed from the journals
+//! recorded by the previous build. Save only writes the files whose content
+//! changed, and lists them in the [ChangeSet] sent to the game
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use serde::Serialize;
+use tracing::{info, info_span, warn};
+
+use diagnostic::context::DiagnosticContext;
+use smart_output::FlushReport;
+
+use crate::database::{Database, DatabaseHolder};
+
+/// Connection attempts are kept short, since the game is usually not running
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+/// Name of the checkpoint with the base of the [Watch], and of the
+/// directory with the journals of its generators
+const WATCH_CHECKPOINT: &str = "watch";
+
+/// Way of notifying the running game about the saved changes, see
+/// [SaveOptions::reload](crate::database::SaveOptions::reload)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReloadTarget {
+    /// Writes the [ChangeSet] into the file, game is expected to watch for
+    /// its modifications
+    TouchFile(PathBuf),
+    /// Sends the [ChangeSet] as a single JSON line to the game listening on
+    /// the address
+    Socket(SocketAddr),
+}
+
+/// Files changed by the save, relative to the output directory
+///
+/// Only the listed files need to be re-read by the game, files that were
+/// written with the same content are not included
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ChangeSet {
+    pub updated: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl ChangeSet {
+    /// Collects changes of the flushed output, skipping files outside of
+    /// the root
+    pub fn from_report(root: &Path, report: &FlushReport) -> Self {
+        let relative = |paths: &[PathBuf]| {
+            paths
+                .iter()
+                .filter_map(|p| p.strip_prefix(root).ok())
+                .map(|p| p.to_string_lossy().replace('\\', "/"))
+                .collect()
+        };
+        Self {
+            updated: relative(&report.updated),
+            removed: relative(&report.removed),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.updated.is_empty() && self.removed.is_empty()
+    }
+
+    pub fn extend(&mut self, other: ChangeSet) {
+        self.updated.extend(other.updated);
+        self.removed.extend(other.removed);
+    }
+}
+
+impl ReloadTarget {
+    /// Notifies the game about the changes
+    ///
+    /// Failures are only logged, since the game not running is the common
+    /// case and should not fail the build
+    pub fn notify(&self, changes: &ChangeSet) {
+        if changes.is_empty() {
+            return;
+        }
+        let mut payload =
+            serde_json::to_string(changes).expect("Should be able to serialize the change set");
+        let result = match self {
+            ReloadTarget::TouchFile(path) => fs_err::write(path, payload),
+            ReloadTarget::Socket(addr) => {
+                payload.push('\n');
+                TcpStream::connect_timeout(addr, CONNECT_TIMEOUT)
+                    .and_then(|mut stream| stream.write_all(payload.as_bytes()))
+            }
+        };
+        match result {
+            Ok(()) => info!(
+                target = %self,
+                updated = changes.updated.len(),
+                removed = changes.removed.len(),
+                "Notified the game about the changes"
+            ),
+            Err(err) => warn!(target = %self, %err, "Failed to notify the game about the changes"),
+        }
+    }
+}
+
+impl Display for ReloadTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReloadTarget::TouchFile(path) => write!(f, "{}", path.display()),
+            ReloadTarget::Socket(addr) => write!(f, "tcp://{addr}"),
+        }
+    }
+}
+
+impl FromStr for ReloadTarget {
+    type Err = String;
+
+    /// Parses `tcp://host:port` as a socket address, and anything else as a
+    /// file path
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(addr) = s.strip_prefix("tcp://") else {
+            return Ok(ReloadTarget::TouchFile(PathBuf::from(s)));
+        };
+        addr.to_socket_addrs()
+            .map_err(|err| format!("invalid reload address `{addr}`: {err}"))?
+            .next()
+            .map(ReloadTarget::Socket)
+            .ok_or_else(|| format!("reload address `{addr}` did not resolve"))
+    }
+}
+
+type Generate = Box<dyn Fn(&Database)>;
+
+/// Incremental build of the database for the watch mode
+///
+/// Generators should only depend on their inputs and the items of the
+/// base. Items of the other generators are replayed as they were, so a
+/// generator reading them should list their inputs as well
+pub struct Watch {
+    create: Box<dyn Fn() -> Database>,
+    base: Generate,
+    generators: Vec<Generator>,
+}
+
+struct Generator {
+    name: String,
+    inputs: Vec<PathBuf>,
+    run: Generate,
+}
+
+impl Generator {
+    fn reads_any(&self, changed: &[PathBuf]) -> bool {
+        changed
+            .iter()
+            .any(|path| self.inputs.iter().any(|input| path.starts_with(input)))
+    }
+}
+
+impl Watch {
+    /// `create` makes a fresh database with the ID ranges and the save
+    /// options set, and `base` adds the items that don't depend on the
+    /// watched files
+    pub fn new(
+        create: impl Fn() -> Database + 'static,
+        base: impl Fn(&Database) + 'static,
+    ) -> Self {
+        Self {
+            create: Box::new(create),
+            base: Box::new(base),
+            generators: vec![],
+        }
+    }
+
+    /// Adds the generator reading the given files or directories
+    ///
+    /// Generators run in the order they were added, and their names must be
+    /// unique
+    pub fn generator(
+        mut self,
+        name: impl Into<String>,
+        inputs: impl IntoIterator<Item = impl Into<PathBuf>>,
+        run: impl Fn(&Database) + 'static,
+    ) -> Self {
+        self.generators.push(Generator {
+            name: name.into(),
+            inputs: inputs.into_iter().map(Into::into).collect(),
+            run: Box::new(run),
+        });
+        self
+    }
+
+    /// Builds and saves the whole database, recording the state for the
+    /// following [Watch::rebuild]s
+    pub fn build(&self) -> DiagnosticContext {
+        let db = (self.create)();
+        (self.base)(&db);
+        db.checkpoint(WATCH_CHECKPOINT)
+            .expect("Should be able to save the watch checkpoint");
+        for generator in &self.generators {
+            run_recorded(&db, generator);
+        }
+        db.save()
+    }
+
+    /// Rebuilds and saves the database after the given files changed,
+    /// re-running only the generators reading them
+    ///
+    /// Paths are matched against the generator inputs as is, so they should
+    /// be in the same form. Falls back to [Watch::build] if the state
+    /// recorded by the previous build is missing
+    pub fn rebuild(&self, changed: &[PathBuf]) -> DiagnosticContext {
+        let db = (self.create)();
+        let resumed = db
+            .resume_from_checkpoint(WATCH_CHECKPOINT)
+            .unwrap_or_else(|err| {
+                warn!(%err, "Failed to resume from the watch checkpoint");
+                false
+            });
+        if !resumed {
+            drop(db);
+            return self.build();
+        }
+
+        for generator in &self.generators {
+            let journal = generator_journal(&db, &generator.name);
+            if generator.reads_any(changed) || !journal.exists() {
+                run_recorded(&db, generator);
+            } else if let Err(err) = db.replay_journal(&journal) {
+                warn!(generator = generator.name, %err, "Failed to replay the generator journal");
+                run_recorded(&db, generator);
+            }
+        }
+        db.save()
+    }
+
+    /// Builds the database, then checks the inputs of the generators every
+    /// `interval` and rebuilds the database once they change
+    ///
+    /// Diagnostics of each save are passed to `report`. Never returns, so
+    /// it's meant to be the last call of the build
+    pub fn watch(&self, interval: Duration, mut report: impl FnMut(DiagnosticContext)) -> ! {
+        let mut stamps = self.input_stamps();
+        report(self.build());
+        loop {
+            std::thread::sleep(interval);
+            let current = self.input_stamps();
+            let changed: Vec<PathBuf> = current
+                .iter()
+                .filter(|(path, stamp)| stamps.get(*path) != Some(stamp))
+                .map(|(path, _)| path.clone())
+                .chain(
+                    stamps
+                        .keys()
+                        .filter(|path| !current.contains_key(*path))
+                        .cloned(),
+                )
+                .collect();
+            if changed.is_empty() {
+                continue;
+            }
+            stamps = current;
+            info!(changed = changed.len(), "Sources changed, rebuilding");
+            report(self.rebuild(&changed));
+        }
+    }
+
+    /// Size and modification time of all files among the inputs
+    fn input_stamps(&self) -> BTreeMap<PathBuf, (u64, Option<SystemTime>)> {
+        self.generators
+            .iter()
+            .flat_map(|generator| &generator.inputs)
+            .flat_map(walkdir::WalkDir::new)
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_file())
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+                Some((
+                    entry.into_path(),
+                    (metadata.len(), metadata.modified().ok()),
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Runs the generator, recording its changes into its journal
+fn run_recorded(db: &Database, generator: &Generator) {
+    let _guard = info_span!("Running generator", name = generator.name).entered();
+    db.record_journal(generator_journal(db, &generator.name))
+        .expect("Should be able to create the generator journal");
+    (generator.run)(db);
+    db.stop_journal();
+}
+
+fn generator_journal(db: &DatabaseHolder, name: &str) -> PathBuf {
+    db.checkpoints_dir()
+        .join(WATCH_CHECKPOINT)
+        .join(format!("{name}.jsonl"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::path::PathBuf;
+    use std::rc::Rc;
+
+    use eh_schema::schema::Faction;
+    use smart_output::testing::test_dir;
+
+    use super::Watch;
+    use crate::database::{database, Database, Remember};
+
+    /// Generator adding a faction named after the content of the source
+    fn faction(source: PathBuf, id: &'static str, runs: Rc<Cell<usize>>) -> impl Fn(&Database) {
+        move |db| {
+            runs.set(runs.get() + 1);
+            let name = fs_err::read_to_string(&source).unwrap();
+            Faction::new(db.new_id(id)).with_name(name).remember(db);
+        }
+    }
+
+    #[test]
+    fn rebuild_affected_generators() {
+        let (_output_dir, output) = test_dir("watch_output");
+        let (_source_dir, source) = test_dir("watch_source");
+        let (a, b) = (source.join("a.txt"), source.join("b.txt"));
+        fs_err::write(&a, "Alpha").unwrap();
+        fs_err::write(&b, "Beta").unwrap();
+        let (runs_a, runs_b) = (Rc::new(Cell::new(0)), Rc::new(Cell::new(0)));
+
+        let create = {
+            let output = output.clone();
+            move || {
+                let db = database(&output, None::<&str>);
+                db.add_id_range(0..100);
+                db
+            }
+        };
+        let watch = Watch::new(create, |db| {
+            Faction::new(db.new_id("test:base"))
+                .with_name("Base")
+                .remember(db);
+        })
+        .generator(
+            "a",
+            [a.clone()],
+            faction(a.clone(), "test:a", runs_a.clone()),
+        )
+        .generator(
+            "b",
+            [source.join("b.txt")],
+            faction(b.clone(), "test:b", runs_b.clone()),
+        );
+        let saved = |name: &str| fs_err::read_to_string(output.join(name)).unwrap();
+
+        watch.build();
+        assert_eq!((runs_a.get(), runs_b.get()), (1, 1));
+
+        fs_err::write(&a, "Gamma").unwrap();
+        fs_err::write(&b, "Delta").unwrap();
+        watch.rebuild(std::slice::from_ref(&a));
+
+        // Generator of the unchanged input is replayed from its journal
+        assert_eq!((runs_a.get(), runs_b.get()), (2, 1));
+        assert!(saved("test/Faction/a.json").contains("Gamma"));
+        assert!(saved("test/Faction/b.json").contains("Beta"));
+        assert!(saved("test/Faction/base.json").contains("Base"));
+
+        watch.rebuild(std::slice::from_ref(&b));
+        assert_eq!((runs_a.get(), runs_b.get()), (2, 2));
+        assert!(saved("test/Faction/a.json").contains("Gamma"));
+        assert!(saved("test/Faction/b.json").contains("Delta"));
+    }
+
+    #[test]
+    fn rebuild_without_build() {
+        let (_output_dir, output) = test_dir("watch_fresh");
+        let runs = Rc::new(Cell::new(0));
+        let watch = Watch::new(
+            move || {
+                let db = database(&output, None::<&str>);
+                db.add_id_range(0..100);
+                db
+            },
+            |_| {},
+        )
+        .generator("a", ["a.txt"], {
+            let runs = runs.clone();
+            move |_| runs.set(runs.get() + 1)
+        });
+
+        watch.rebuild(&[]);
+        assert_eq!(runs.get(), 1);
+    }
+}
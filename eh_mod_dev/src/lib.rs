@@ -1,11 +1,24 @@
+pub use diagnostic;
 pub use eh_schema as schema;
 
-pub mod builder;
+pub mod compatibility;
 pub mod database;
+pub mod difficulty;
+pub mod economy;
+pub mod expressions;
 pub mod helpers;
+pub mod hot_reload;
 pub mod layout;
 pub mod mapping;
+pub mod mod_format;
+pub mod passes;
+pub mod profile;
+pub mod random;
+pub mod registry;
+pub mod relations;
 pub mod reporting;
+pub mod starbases;
+pub mod start;
 pub mod utils;
 pub mod validators;
 pub mod vanilla_mappings;
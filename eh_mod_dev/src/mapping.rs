@@ -1,103 +1,254 @@
 use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
 use std::ops::Range;
+use std::sync::{Arc, OnceLock};
 
 use ahash::{AHashMap, AHashSet};
+use parking_lot::RwLock;
 use regex::Regex;
-use tracing::error_span;
+use serde::Serialize;
+use thiserror::Error;
+use tracing::{error_span, warn};
 
 use eh_schema::schema::{DatabaseItem, DatabaseItemId};
 
+use crate::database::journal::{Journal, JournalEntry};
+
 pub type IdMappingSerialized = BTreeMap<Cow<'static, str>, BTreeMap<String, i32>>;
 
-pub type IdIter<'a> =
-    std::iter::Flatten<std::option::IntoIter<std::collections::hash_set::Iter<'a, String>>>;
+pub type IdIter<'a> = std::slice::Iter<'a, String>;
 
-#[derive(Debug, Clone, Default)]
+/// Maximum amount of distinct kinds a single [IdMapping] can hold
+const KIND_SLOTS: usize = 256;
+
+/// String to numeric ID mappings, sharded by kind
+///
+/// Shards are looked up without any locking, and each kind is stored behind
+/// its own lock, so allocations of IDs for different kinds don't contend
+/// with each other, and lookups of already assigned IDs only take a single
+/// shared lock
+#[derive(Debug, Default)]
 pub struct IdMapping {
-    ids: BTreeMap<Cow<'static, str>, BTreeMap<String, i32>>,
-    used_ids: AHashMap<Cow<'static, str>, AHashSet<String>>,
-    occupied_ids: AHashMap<Cow<'static, str>, AHashSet<i32>>,
-    available_ids: AHashMap<Cow<'static, str>, Vec<Range<i32>>>,
-    default_ids: Vec<Range<i32>>,
+    kinds: KindTable,
+    default_ids: RwLock<Vec<Range<i32>>>,
+    exhaustion_policy: RwLock<IdExhaustionPolicy>,
+    journal: RwLock<Option<Arc<Journal>>>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct KindIds {
+    ids: BTreeMap<String, i32>,
+    used: AHashSet<String>,
+    /// String IDs assigned via [IdMapping::set_id], rather than allocated
+    explicit: AHashSet<String>,
+    occupied: AHashSet<i32>,
+    /// Remaining ID ranges, `None` until the kind diverges from the default
+    /// ranges
+    available: Option<Vec<Range<i32>>>,
+    /// Same as `available`, but never consumed by the allocation
+    allocated: Option<Vec<Range<i32>>>,
+    /// Snapshot of `used`, shared between [IdMapping::used_ids] calls until
+    /// `used` changes
+    used_snapshot: OnceLock<Arc<[String]>>,
+}
+
+/// Append-only open addressing table of kind shards
+///
+/// Slots are never emptied once filled, so lookups can probe the table
+/// without locking, and only the insertion of a new kind synchronizes on
+/// the slot it lands in
+#[derive(Debug)]
+struct KindTable {
+    hasher: ahash::RandomState,
+    slots: Box<[OnceLock<Box<KindShard>>]>,
+}
+
+type KindShard = (Cow<'static, str>, RwLock<KindIds>);
+
+/// Behavior of the ID allocation once all IDs in the allocated ranges are
+/// used up
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IdExhaustionPolicy {
+    /// Panic right away, even from the `try_` methods
+    #[default]
+    Panic,
+    /// Return an error from the `try_` methods. Other methods still panic,
+    /// since they have no way of reporting the error
+    Error,
+    /// Allocate a new range of `step` IDs right after the highest allocated
+    /// or occupied ID, logging a warning. Types without any allocated ranges
+    /// are handled the same as with [IdExhaustionPolicy::Error]
+    AutoExtend { step: i32 },
+}
+
+#[derive(Debug, Clone, Error)]
+pub enum IdAllocationError {
+    #[error("No ID range were given for Database to assign IDs for `{}`, please use `add_id_range` method", .kind)]
+    NoRanges { kind: Cow<'static, str> },
+    #[error("No free IDs are left for `{}`", .kind)]
+    Exhausted { kind: Cow<'static, str> },
+    #[error("ID `{}` is already in use for `{}`", .id, .kind)]
+    AlreadyUsed { kind: Cow<'static, str>, id: String },
+}
+
+impl Clone for IdMapping {
+    fn clone(&self) -> Self {
+        let kinds = KindTable::default();
+        for (kind, ids) in self.kinds.iter() {
+            *kinds.get_or_insert(kind.clone()).write() = ids.read().clone();
+        }
+        Self {
+            kinds,
+            default_ids: RwLock::new(self.default_ids.read().clone()),
+            exhaustion_policy: RwLock::new(*self.exhaustion_policy.read()),
+            journal: Default::default(),
+        }
+    }
 }
 
 impl IdMapping {
     pub fn new(mappings: IdMappingSerialized) -> Self {
-        let occupied_ids = mappings
-            .iter()
-            .map(|(k, v)| (k.clone(), v.values().copied().collect::<AHashSet<i32>>()))
-            .collect();
+        let kinds = KindTable::default();
+        for (kind, ids) in mappings {
+            *kinds.get_or_insert(kind).write() = KindIds {
+                occupied: ids.values().copied().collect(),
+                ids,
+                ..Default::default()
+            };
+        }
 
         Self {
-            occupied_ids,
-            used_ids: Default::default(),
-            ids: mappings,
-            available_ids: Default::default(),
+            kinds,
             default_ids: Default::default(),
+            exhaustion_policy: Default::default(),
+            journal: Default::default(),
         }
     }
 
-    pub fn as_serializable(&self) -> &IdMappingSerialized {
-        &self.ids
+    /// Collects mappings of all kinds into the serializable form
+    pub fn to_serializable(&self) -> IdMappingSerialized {
+        self.kinds
+            .iter()
+            .map(|(kind, ids)| (kind.clone(), ids.read().ids.clone()))
+            .filter(|(_, ids)| !ids.is_empty())
+            .collect()
     }
     pub fn into_serializable(self) -> IdMappingSerialized {
-        self.ids
+        self.to_serializable()
+    }
+
+    pub fn set_exhaustion_policy(&self, policy: IdExhaustionPolicy) {
+        *self.exhaustion_policy.write() = policy;
     }
 
     /// Adds another ID range to use for all entries
-    pub fn add_id_range(&mut self, range: Range<i32>) {
-        for ids in self.available_ids.values_mut() {
-            ids.push(range.clone());
+    pub fn add_id_range(&self, range: Range<i32>) {
+        let mut defaults = self.default_ids.write();
+        for (_, ids) in self.kinds.iter() {
+            let mut ids = ids.write();
+            let ids = &mut *ids;
+            for ranges in ids.available.iter_mut().chain(ids.allocated.iter_mut()) {
+                ranges.push(range.clone());
+            }
         }
-        self.default_ids.push(range);
+        defaults.push(range);
     }
 
     /// Adds another ID range to use for one specified kind
-    pub fn add_id_range_for(&mut self, kind: impl Into<Cow<'static, str>>, range: Range<i32>) {
-        self.available_ids
-            .entry(kind.into())
-            .or_insert_with(|| self.default_ids.clone())
-            .push(range)
+    pub fn add_id_range_for(&self, kind: impl Into<Cow<'static, str>>, range: Range<i32>) {
+        self.with_kind_mut(kind.into(), |ids, defaults| {
+            ids.allocated
+                .get_or_insert_with(|| defaults.to_vec())
+                .push(range.clone());
+            ids.available
+                .get_or_insert_with(|| defaults.to_vec())
+                .push(range);
+        })
     }
 
     /// Clears allocated ID ranges for the specified type, preventing obtaining
     /// of new IDs until [add_id_range] or [add_id_range_for] are used to
     /// allocate new ID space for this type
-    pub fn clear_id_ranges_for(&mut self, kind: impl Into<Cow<'static, str>>) {
-        self.available_ids
-            .entry(kind.into())
-            .and_modify(|e| e.clear());
+    pub fn clear_id_ranges_for(&self, kind: impl Into<Cow<'static, str>>) {
+        let Some(ids) = self.kinds.get(&kind.into()) else {
+            return;
+        };
+        let mut ids = ids.write();
+        if let Some(available) = &mut ids.available {
+            available.clear();
+            ids.allocated = Some(vec![]);
+        }
+    }
+
+    /// Summarizes allocated ID ranges and their usage for every kind
+    pub fn usage_report(&self) -> IdUsageReport {
+        let defaults = self.default_ids.read();
+        let kinds = self
+            .kinds
+            .iter()
+            .map(|(kind, ids)| (kind.clone(), ids.read().usage(&defaults)))
+            .collect();
+
+        IdUsageReport { kinds }
     }
 
     /// Converts string ID into database item ID
     ///
     /// Panics if generating ID is not possible
-    pub fn get_id_raw(&mut self, kind: impl Into<Cow<'static, str>>, id: impl Into<String>) -> i32 {
-        let id_str = id.into();
+    pub fn get_id_raw(&self, kind: impl Into<Cow<'static, str>>, id: impl Into<String>) -> i32 {
+        self.try_get_id_raw(kind, id)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
 
+    /// Same as [IdMapping::get_id_raw], but returns an error if generating
+    /// ID is not possible and [IdExhaustionPolicy::Error] is used
+    pub fn try_get_id_raw(
+        &self,
+        kind: impl Into<Cow<'static, str>>,
+        id: impl Into<String>,
+    ) -> Result<i32, IdAllocationError> {
+        let id_str = id.into();
         let kind = kind.into();
-        let mapping = self.ids.entry(kind.clone()).or_default();
-
-        match mapping.get(&id_str) {
-            None => {
-                let id = self.next_id_raw(kind.clone());
-                self.ids
-                    .get_mut(&kind)
-                    .expect("ID entry should be present at this point")
-                    .insert(id_str, id);
-                id
+        let policy = *self.exhaustion_policy.read();
+
+        self.with_kind_mut(kind.clone(), |ids, defaults| {
+            if let Some(id) = ids.ids.get(&id_str) {
+                return Ok(*id);
             }
-            Some(id) => *id,
-        }
+            let id = ids.next_id(kind.clone(), defaults, policy)?;
+            self.record(|| JournalEntry::IdAssigned {
+                kind: kind.to_string(),
+                string_id: id_str.clone(),
+                id,
+                used: false,
+                explicit: false,
+            });
+            ids.ids.insert(id_str, id);
+            Ok(id)
+        })
     }
 
     /// Returns an unstable numeric ID.
     ///
     /// IDs obtained this way are unstable and may change between runs, so
     /// they should not be used for any kind of savefile-persistent data
-    pub fn get_unstable_id(&mut self, kind: impl Into<Cow<'static, str>>) -> i32 {
-        self.next_id_raw(kind)
+    pub fn get_unstable_id(&self, kind: impl Into<Cow<'static, str>>) -> i32 {
+        self.try_get_unstable_id(kind)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Same as [IdMapping::get_unstable_id], but returns an error if
+    /// generating ID is not possible and [IdExhaustionPolicy::Error] is used
+    pub fn try_get_unstable_id(
+        &self,
+        kind: impl Into<Cow<'static, str>>,
+    ) -> Result<i32, IdAllocationError> {
+        let kind = kind.into();
+        let policy = *self.exhaustion_policy.read();
+        self.with_kind_mut(kind.clone(), |ids, defaults| {
+            ids.next_id(kind, defaults, policy)
+        })
     }
 
     /// Converts string ID into database item ID
@@ -108,135 +259,495 @@ impl IdMapping {
 
         let _guard = error_span!("Getting item ID", id, ty = %kind).entered();
 
-        if !self.used_ids.get(&kind).is_some_and(|ids| ids.contains(id)) {
-            panic!("ID is not present in the database")
-        }
-
-        *self
-            .ids
+        let ids = self
+            .kinds
             .get(&kind)
-            .expect("This kind should be present, based on used_id check")
+            .map(|ids| ids.read())
+            .filter(|ids| ids.used.contains(id))
+            .unwrap_or_else(|| panic!("ID is not present in the database"));
+
+        *ids.ids
             .get(id)
             .expect("This ID should be present based on used_id check")
     }
 
+    /// Returns the numeric ID assigned to the string ID, even if it's not
+    /// used by this run
+    pub fn assigned_id<'a>(&'a self, kind: impl Into<Cow<'a, str>>, id: &str) -> Option<i32> {
+        self.kinds
+            .get(&kind.into())
+            .and_then(|ids| ids.read().ids.get(id).copied())
+    }
+
     /// Converts string ID into new database item ID
     ///
     /// Panics if generating ID is not possible, or if ID is already used
-    pub fn new_id(&mut self, kind: impl Into<Cow<'static, str>>, id: impl Into<String>) -> i32 {
+    pub fn new_id(&self, kind: impl Into<Cow<'static, str>>, id: impl Into<String>) -> i32 {
         let id_str = id.into();
         let kind = kind.into();
-        {
-            let _guard = error_span!("Creating new item ID", id = id_str, ty = %kind).entered();
+        let _guard = error_span!("Creating new item ID", id = id_str, ty = %kind).entered();
 
-            let used_ids = self.used_ids.entry(kind.clone()).or_default();
+        self.try_new_id(kind, id_str).unwrap_or_else(|e| match e {
+            IdAllocationError::AlreadyUsed { .. } => panic!("ID is already in use"),
+            e => panic!("{e}"),
+        })
+    }
 
-            if !used_ids.insert(id_str.clone()) {
-                panic!("ID is already in use")
-            }
-        }
+    /// Same as [IdMapping::new_id], but returns an error instead of
+    /// panicking when ID is already used, or when generating ID is not
+    /// possible and [IdExhaustionPolicy::Error] is used
+    pub fn try_new_id(
+        &self,
+        kind: impl Into<Cow<'static, str>>,
+        id: impl Into<String>,
+    ) -> Result<i32, IdAllocationError> {
+        let id_str = id.into();
+        let kind = kind.into();
+        let policy = *self.exhaustion_policy.read();
 
-        self.get_id_raw(kind, id_str)
+        self.with_kind_mut(kind.clone(), |ids, defaults| {
+            if ids.used.contains(&id_str) {
+                return Err(IdAllocationError::AlreadyUsed { kind, id: id_str });
+            }
+            let id = match ids.ids.get(&id_str) {
+                Some(id) => *id,
+                None => {
+                    let id = ids.next_id(kind.clone(), defaults, policy)?;
+                    ids.ids.insert(id_str.clone(), id);
+                    id
+                }
+            };
+            self.record(|| JournalEntry::IdAssigned {
+                kind: kind.to_string(),
+                string_id: id_str.clone(),
+                id,
+                used: true,
+                explicit: false,
+            });
+            ids.explicit.remove(&id_str);
+            ids.used.insert(id_str);
+            ids.used_snapshot.take();
+            Ok(id)
+        })
     }
 
     pub fn is_used(&self, kind: impl Into<Cow<'static, str>>, id: &str) -> bool {
-        self.used_ids
+        self.kinds
             .get(&kind.into())
-            .is_some_and(|ids| ids.contains(id))
+            .is_some_and(|ids| ids.read().used.contains(id))
     }
 
     /// Forcefully assigns numeric ID to a string
+    ///
+    /// Such IDs usually point at the items of the base game, so they are
+    /// not expected to have an item in this database
     pub fn set_id(
-        &mut self,
+        &self,
         kind: impl Into<Cow<'static, str>>,
         string_id: impl Into<String>,
         numeric_id: i32,
     ) -> i32 {
-        let kind = kind.into();
-        let string_id = string_id.into();
-        self.ids
-            .entry(kind.clone())
-            .or_default()
-            .insert(string_id.clone(), numeric_id);
-        self.occupied_ids
-            .entry(kind.clone())
-            .or_default()
-            .insert(numeric_id);
-        self.used_ids.entry(kind).or_default().insert(string_id);
+        self.restore_id(kind, string_id, numeric_id, true, true);
         numeric_id
     }
 
-    pub fn forget_used_id(&mut self, kind: impl Into<Cow<'static, str>>, id: &str) {
+    /// Assigns the numeric ID to a string as it was recorded by the journal
+    /// or the checkpoint
+    pub(crate) fn restore_id(
+        &self,
+        kind: impl Into<Cow<'static, str>>,
+        string_id: impl Into<String>,
+        numeric_id: i32,
+        used: bool,
+        explicit: bool,
+    ) {
+        let string_id = string_id.into();
+        let kind = kind.into();
+        self.with_kind_mut(kind.clone(), |ids, _| {
+            self.record(|| JournalEntry::IdAssigned {
+                kind: kind.to_string(),
+                string_id: string_id.clone(),
+                id: numeric_id,
+                used,
+                explicit,
+            });
+            ids.ids.insert(string_id.clone(), numeric_id);
+            ids.occupied.insert(numeric_id);
+            if explicit {
+                ids.explicit.insert(string_id.clone());
+            } else {
+                ids.explicit.remove(&string_id);
+            }
+            if used {
+                ids.used.insert(string_id);
+            } else {
+                ids.used.remove(&string_id);
+            }
+            ids.used_snapshot.take();
+        });
+    }
+
+    /// Checks whether the string ID was assigned via [IdMapping::set_id]
+    pub fn is_explicit(&self, kind: impl Into<Cow<'static, str>>, id: &str) -> bool {
+        self.kinds
+            .get(&kind.into())
+            .is_some_and(|ids| ids.read().explicit.contains(id))
+    }
+
+    /// Numeric IDs assigned via [IdMapping::set_id], grouped by kind
+    pub fn explicit_ids(&self) -> AHashMap<Cow<'static, str>, AHashSet<i32>> {
+        self.kinds
+            .iter()
+            .map(|(kind, ids)| {
+                let ids = ids.read();
+                let explicit = ids
+                    .explicit
+                    .iter()
+                    .filter_map(|id| ids.ids.get(id).copied())
+                    .collect();
+                (kind.clone(), explicit)
+            })
+            .collect()
+    }
+
+    pub fn forget_used_id(&self, kind: impl Into<Cow<'static, str>>, id: &str) {
         let kind = kind.into();
-        self.used_ids.entry(kind).or_default().remove(id);
+        if let Some(ids) = self.kinds.get(&kind) {
+            let mut ids = ids.write();
+            if ids.used.remove(id) {
+                ids.used_snapshot.take();
+                self.record(|| JournalEntry::IdReleased {
+                    kind: kind.to_string(),
+                    string_id: id.to_string(),
+                });
+            }
+        }
     }
 
     pub fn get_inverse_id<'a>(&'a self, kind: impl Into<Cow<'a, str>>, id: i32) -> Option<String> {
         let kind = kind.into();
 
-        self.ids.get(&kind).and_then(|i| {
-            i.iter()
+        self.kinds.get(&kind).and_then(|i| {
+            i.read()
+                .ids
+                .iter()
                 .find_map(|(k, v)| if *v == id { Some(k.clone()) } else { None })
         })
     }
 
     pub fn get_inverse_ids(&self) -> AHashMap<Cow<'static, str>, AHashMap<i32, String>> {
-        self.ids
+        self.kinds
             .iter()
             .map(|(ty, ids)| {
-                let ids: AHashMap<_, _> = ids.iter().map(|(k, v)| (*v, k.clone())).collect();
+                let ids: AHashMap<_, _> = ids
+                    .read()
+                    .ids
+                    .iter()
+                    .map(|(k, v)| (*v, k.clone()))
+                    .collect();
                 (ty.clone(), ids)
             })
             .collect()
     }
 
-    // Iterator of all used string ids for the given kind
-    pub fn used_ids<'a>(&'a self, kind: impl Into<Cow<'a, str>>) -> IdIter {
-        self.used_ids
-            .get(&kind.into())
-            .map(|h| h.iter())
-            .into_iter()
-            .flatten()
+    /// All used string IDs of the given kind
+    ///
+    /// The snapshot is shared between calls until the used IDs of the kind
+    /// change, and holds no locks, so IDs can be allocated while iterating
+    /// over it
+    pub fn used_ids<'a>(&'a self, kind: impl Into<Cow<'a, str>>) -> UsedIds {
+        let ids = self.kinds.get(&kind.into()).map(|ids| {
+            let ids = ids.read();
+            ids.used_snapshot
+                .get_or_init(|| ids.used.iter().cloned().collect())
+                .clone()
+        });
+        UsedIds(ids.unwrap_or_else(|| Arc::new([])))
     }
 
-    pub fn used_ids_filtered<'a>(
-        &'a self,
-        filter: &str,
-        kind: impl Into<Cow<'a, str>>,
-    ) -> RegexIter {
-        RegexIter {
-            regex: Regex::new(filter).unwrap(),
-            items: self.used_ids(kind),
+    /// All used string IDs of all kinds, along with their numeric IDs
+    pub fn used_entries(&self) -> Vec<(Cow<'static, str>, String, i32)> {
+        self.kinds
+            .iter()
+            .flat_map(|(kind, ids)| {
+                let ids = ids.read();
+                ids.used
+                    .iter()
+                    .filter_map(|id| Some((kind.clone(), id.clone(), *ids.ids.get(id)?)))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Used string IDs of all kinds that were allocated via
+    /// [IdMapping::new_id] rather than assigned via [IdMapping::set_id],
+    /// along with their numeric IDs
+    pub fn allocated_entries(&self) -> Vec<(Cow<'static, str>, String, i32)> {
+        self.kinds
+            .iter()
+            .flat_map(|(kind, ids)| {
+                let ids = ids.read();
+                ids.used
+                    .iter()
+                    .filter(|id| !ids.explicit.contains(*id))
+                    .filter_map(|id| Some((kind.clone(), id.clone(), *ids.ids.get(id)?)))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    pub(crate) fn set_journal(&self, journal: Option<Arc<Journal>>) {
+        *self.journal.write() = journal;
+    }
+
+    fn record(&self, entry: impl FnOnce() -> JournalEntry) {
+        if let Some(journal) = &*self.journal.read() {
+            journal.record(&entry());
         }
     }
 
-    fn next_id_raw(&mut self, kind: impl Into<Cow<'static, str>>) -> i32 {
-        let kind = kind.into();
+    /// Runs the closure with the exclusive access to the shard of the kind,
+    /// creating it if needed
+    ///
+    /// Locks are always taken in the order of default ranges and then the
+    /// shard itself, so operations on different kinds never block each other
+    fn with_kind_mut<T>(
+        &self,
+        kind: Cow<'static, str>,
+        func: impl FnOnce(&mut KindIds, &[Range<i32>]) -> T,
+    ) -> T {
+        let defaults = self.default_ids.read();
+        let mut ids = self.kinds.get_or_insert(kind).write();
+        func(&mut ids, &defaults)
+    }
+}
 
-        let ids = self
-            .available_ids
-            .entry(kind.clone())
-            .or_insert_with(|| self.default_ids.clone());
-
-        if ids.is_empty() {
-            let _guard = error_span!("Getting next item ID", kind = %kind).entered();
-            panic!(
-                "No ID range were given for Database to assign or all ids were exhausted, please use `add_id_range` method"
-            )
+impl Default for KindTable {
+    fn default() -> Self {
+        Self {
+            hasher: Default::default(),
+            slots: (0..KIND_SLOTS).map(|_| OnceLock::new()).collect(),
+        }
+    }
+}
+
+impl KindTable {
+    /// Slots to probe for the kind, in order
+    fn probe<'a>(&'a self, kind: &str) -> impl Iterator<Item = &'a OnceLock<Box<KindShard>>> {
+        let start = self.hasher.hash_one(kind) as usize;
+        (0..KIND_SLOTS).map(move |i| &self.slots[start.wrapping_add(i) % KIND_SLOTS])
+    }
+
+    /// Gets the shard of the kind, without creating it
+    fn get(&self, kind: &str) -> Option<&RwLock<KindIds>> {
+        for slot in self.probe(kind) {
+            let (key, ids) = &**slot.get()?;
+            if key == kind {
+                return Some(ids);
+            }
+        }
+        None
+    }
+
+    /// Gets the shard of the kind, creating it if needed
+    ///
+    /// Panics if the table is full
+    fn get_or_insert(&self, kind: Cow<'static, str>) -> &RwLock<KindIds> {
+        for slot in self.probe(&kind) {
+            let (key, ids) = &**slot.get_or_init(|| Box::new((kind.clone(), Default::default())));
+            if *key == kind {
+                return ids;
+            }
         }
+        panic!("Too many ID kinds, at most {KIND_SLOTS} are supported")
+    }
 
-        let mappings = self.occupied_ids.entry(kind).or_default();
+    fn iter(&self) -> impl Iterator<Item = (&Cow<'static, str>, &RwLock<KindIds>)> {
+        self.slots
+            .iter()
+            .filter_map(|slot| slot.get())
+            .map(|shard| (&shard.0, &shard.1))
+    }
+}
 
-        while let Some(id) = ids.iter_mut().find_map(|range| range.next()) {
-            // Check that ID is not already occupied
-            if !mappings.contains(&id) {
-                mappings.insert(id);
-                return id;
+impl KindIds {
+    fn next_id(
+        &mut self,
+        kind: Cow<'static, str>,
+        defaults: &[Range<i32>],
+        policy: IdExhaustionPolicy,
+    ) -> Result<i32, IdAllocationError> {
+        let _guard = error_span!("Getting next item ID", kind = %kind).entered();
+
+        let on_exhausted = |error: IdAllocationError| match policy {
+            IdExhaustionPolicy::Panic => panic!("{error}"),
+            IdExhaustionPolicy::Error | IdExhaustionPolicy::AutoExtend { .. } => Err(error),
+        };
+
+        let available = self.available.get_or_insert_with(|| defaults.to_vec());
+        if available.is_empty() {
+            return on_exhausted(IdAllocationError::NoRanges { kind });
+        }
+
+        loop {
+            let available = self
+                .available
+                .as_mut()
+                .expect("Available ranges should be initialized at this point");
+            while let Some(id) = available.iter_mut().find_map(|range| range.next()) {
+                // Check that ID is not already occupied
+                if self.occupied.insert(id) {
+                    return Ok(id);
+                }
             }
+
+            let IdExhaustionPolicy::AutoExtend { step } = policy else {
+                return on_exhausted(IdAllocationError::Exhausted { kind });
+            };
+
+            let start = self
+                .allocated
+                .as_deref()
+                .unwrap_or(defaults)
+                .iter()
+                .map(|r| r.end)
+                .chain(self.occupied.iter().map(|id| id.saturating_add(1)))
+                .max()
+                .expect("Kind should have at least one allocated range at this point");
+            let Some(end) = start.checked_add(step).filter(|_| step > 0) else {
+                return Err(IdAllocationError::Exhausted { kind });
+            };
+            warn!(
+                range = ?(start..end),
+                "All allocated IDs were used, extending the ID space"
+            );
+            self.allocated
+                .get_or_insert_with(|| defaults.to_vec())
+                .push(start..end);
+            available.push(start..end);
+        }
+    }
+
+    fn usage(&self, defaults: &[Range<i32>]) -> IdUsage {
+        let allocated = merge_ranges(self.allocated.as_deref().unwrap_or(defaults).to_vec());
+        let available = merge_ranges(self.available.as_deref().unwrap_or(defaults).to_vec());
+        let mut occupied: Vec<i32> = self.occupied.iter().copied().collect();
+        occupied.sort_unstable();
+
+        let capacity = allocated.iter().map(range_len).sum();
+        let used = allocated
+            .iter()
+            .map(|r| occupied_in(&occupied, r).len())
+            .sum();
+        let remaining = available
+            .iter()
+            .map(|r| range_len(r) - occupied_in(&occupied, r).len())
+            .sum();
+        let free_runs = allocated
+            .iter()
+            .map(|r| {
+                let ids = occupied_in(&occupied, r);
+                let gaps = ids.windows(2).filter(|w| w[1] - w[0] > 1).count();
+                match (ids.first(), ids.last()) {
+                    (Some(first), Some(last)) => {
+                        gaps + (*first > r.start) as usize + (*last < r.end - 1) as usize
+                    }
+                    _ => (!r.is_empty()) as usize,
+                }
+            })
+            .sum();
+
+        IdUsage {
+            ranges: allocated,
+            capacity,
+            used,
+            remaining,
+            highest: occupied.last().copied(),
+            free_runs,
+        }
+    }
+}
+
+/// ID space usage of a single kind, see [IdMapping::usage_report]
+#[derive(Debug, Clone, Serialize)]
+pub struct IdUsage {
+    /// Allocated ID ranges, sorted and merged
+    pub ranges: Vec<Range<i32>>,
+    /// Total amount of IDs in the allocated ranges
+    pub capacity: usize,
+    /// Amount of occupied IDs inside the allocated ranges
+    pub used: usize,
+    /// Amount of IDs that can still be handed out
+    pub remaining: usize,
+    /// Highest occupied ID, including IDs outside of the allocated ranges
+    pub highest: Option<i32>,
+    /// Amount of contiguous runs of free IDs inside the allocated ranges
+    ///
+    /// Higher values mean that occupied IDs are scattered across the ranges
+    pub free_runs: usize,
+}
+
+impl IdUsage {
+    /// Whether less than a tenth of the allocated ID space is left
+    pub fn is_nearly_exhausted(&self) -> bool {
+        self.capacity > 0 && self.remaining * 10 < self.capacity
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IdUsageReport {
+    pub kinds: BTreeMap<Cow<'static, str>, IdUsage>,
+}
+
+impl Display for IdUsageReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (kind, usage) in &self.kinds {
+            let ranges = usage
+                .ranges
+                .iter()
+                .map(|r| format!("{}..{}", r.start, r.end))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                f,
+                "{kind}: {}/{} used, {} remaining, highest {}, {} free runs, ranges [{ranges}]",
+                usage.used,
+                usage.capacity,
+                usage.remaining,
+                usage
+                    .highest
+                    .map(|id| id.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                usage.free_runs,
+            )?;
         }
+        Ok(())
+    }
+}
+
+fn range_len(range: &Range<i32>) -> usize {
+    (range.end as i64 - range.start as i64).max(0) as usize
+}
 
-        panic!("No free IDs are left for this kind");
+/// Sorts ranges and merges overlapping ones
+fn merge_ranges(mut ranges: Vec<Range<i32>>) -> Vec<Range<i32>> {
+    ranges.retain(|r| !r.is_empty());
+    ranges.sort_by_key(|r| r.start);
+    let mut merged: Vec<Range<i32>> = vec![];
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
     }
+    merged
+}
+
+/// Slice of the sorted IDs falling into the range
+fn occupied_in<'a>(sorted: &'a [i32], range: &Range<i32>) -> &'a [i32] {
+    let start = sorted.partition_point(|id| *id < range.start);
+    let end = sorted.partition_point(|id| *id < range.end);
+    &sorted[start..end]
 }
 
 pub trait KindProvider {
@@ -251,14 +762,14 @@ impl<T: DatabaseItem> KindProvider for T {
 
 pub trait DatabaseIdLike<T: KindProvider> {
     fn into_id(self, ids: &IdMapping) -> i32;
-    fn into_new_id(self, ids: &mut IdMapping) -> i32;
+    fn into_new_id(self, ids: &IdMapping) -> i32;
 }
 
 impl<T: 'static + DatabaseItem> DatabaseIdLike<T> for DatabaseItemId<T> {
     fn into_id(self, _ids: &IdMapping) -> i32 {
         self.0
     }
-    fn into_new_id(self, _ids: &mut IdMapping) -> i32 {
+    fn into_new_id(self, _ids: &IdMapping) -> i32 {
         self.0
     }
 }
@@ -267,7 +778,7 @@ impl<T: KindProvider> DatabaseIdLike<T> for &str {
     fn into_id(self, ids: &IdMapping) -> i32 {
         ids.existing_id(T::kind(), self)
     }
-    fn into_new_id(self, ids: &mut IdMapping) -> i32 {
+    fn into_new_id(self, ids: &IdMapping) -> i32 {
         ids.new_id(T::kind(), self)
     }
 }
@@ -276,7 +787,7 @@ impl<T: KindProvider> DatabaseIdLike<T> for String {
     fn into_id(self, ids: &IdMapping) -> i32 {
         ids.existing_id(T::kind(), &self)
     }
-    fn into_new_id(self, ids: &mut IdMapping) -> i32 {
+    fn into_new_id(self, ids: &IdMapping) -> i32 {
         ids.new_id(T::kind(), self)
     }
 }
@@ -303,6 +814,33 @@ impl<K: KindProvider> OptionalDatabaseIdLike<K, String> for () {
     }
 }
 
+/// Snapshot of the used string IDs of a kind, see [IdMapping::used_ids]
+#[derive(Debug, Clone)]
+pub struct UsedIds(Arc<[String]>);
+
+impl UsedIds {
+    pub fn iter(&self) -> IdIter<'_> {
+        self.0.iter()
+    }
+
+    /// Iterator of the IDs matching the regex
+    pub fn filtered(&self, filter: &str) -> RegexIter<'_> {
+        RegexIter {
+            regex: Regex::new(filter).unwrap(),
+            items: self.iter(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a UsedIds {
+    type Item = &'a String;
+    type IntoIter = IdIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 #[derive(Debug)]
 pub struct RegexIter<'a> {
     regex: regex::Regex,
@@ -323,3 +861,195 @@ impl<'a> Iterator for RegexIter<'a> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::sync::Arc;
+
+    use super::{IdAllocationError, IdExhaustionPolicy, IdMapping};
+
+    const THREADS: usize = 8;
+    const PER_THREAD: usize = 100;
+
+    /// Numeric IDs of the used string IDs of the kind
+    fn used(ids: &IdMapping, kind: &'static str) -> BTreeSet<i32> {
+        ids.used_ids(kind)
+            .iter()
+            .map(|id| ids.existing_id(kind, id))
+            .collect()
+    }
+
+    #[test]
+    fn parallel_new_id() {
+        let ids = IdMapping::default();
+        ids.add_id_range(0..(THREADS * PER_THREAD) as i32);
+        let kinds = ["A", "B", "C", "D"];
+
+        std::thread::scope(|s| {
+            for t in 0..THREADS {
+                let ids = &ids;
+                s.spawn(move || {
+                    for i in 0..PER_THREAD {
+                        // Same kind from every thread, and a kind per
+                        // thread pair
+                        ids.new_id("Shared", format!("test:{t}_{i}"));
+                        ids.new_id(kinds[t % kinds.len()], format!("test:{t}_{i}"));
+                    }
+                });
+            }
+        });
+
+        let shared = used(&ids, "Shared");
+        assert_eq!(shared.len(), THREADS * PER_THREAD);
+        assert_eq!(shared, (0..(THREADS * PER_THREAD) as i32).collect());
+        for kind in kinds {
+            let expected = THREADS / kinds.len() * PER_THREAD;
+            assert_eq!(used(&ids, kind), (0..expected as i32).collect(), "{kind}");
+        }
+    }
+
+    #[test]
+    fn add_id_range_racing_allocations() {
+        const RANGES: i32 = 4;
+        const RANGE_LEN: i32 = 200;
+
+        let ids = IdMapping::default();
+        ids.set_exhaustion_policy(IdExhaustionPolicy::Error);
+        ids.add_id_range(0..RANGE_LEN);
+        // Kind with its own ranges gets the added default ranges too
+        ids.add_id_range_for("Diverged", -RANGE_LEN..0);
+        let kinds = ["Default", "Diverged"];
+
+        std::thread::scope(|s| {
+            let ids = &ids;
+            for t in 0..THREADS {
+                s.spawn(move || {
+                    let kind = kinds[t % kinds.len()];
+                    let count = RANGES as usize * RANGE_LEN as usize / (THREADS / kinds.len());
+                    for i in 0..count {
+                        // Retries until the racing ranges are added
+                        while let Err(e) = ids.try_new_id(kind, format!("test:{t}_{i}")) {
+                            assert!(matches!(e, IdAllocationError::Exhausted { .. }), "{e}");
+                            std::thread::yield_now();
+                        }
+                    }
+                });
+            }
+            s.spawn(move || {
+                for r in 1..RANGES {
+                    std::thread::yield_now();
+                    ids.add_id_range(r * 1000..r * 1000 + RANGE_LEN);
+                }
+            });
+        });
+
+        let added: BTreeSet<i32> = (0..RANGES)
+            .flat_map(|r| r * 1000..r * 1000 + RANGE_LEN)
+            .collect();
+        assert_eq!(used(&ids, "Default"), added);
+        let diverged = used(&ids, "Diverged");
+        assert_eq!(diverged.len(), (RANGES * RANGE_LEN) as usize);
+        assert!(diverged.iter().all(|id| *id < 0 || added.contains(id)));
+        assert!(ids.try_new_id("Default", "test:extra").is_err());
+    }
+
+    #[test]
+    fn used_ids_snapshot() {
+        let ids = IdMapping::default();
+        ids.add_id_range(0..10);
+        assert!(ids.used_ids("A").iter().next().is_none());
+        ids.new_id("A", "test:a");
+
+        let first = ids.used_ids("A");
+        assert!(Arc::ptr_eq(&first.0, &ids.used_ids("A").0));
+        // Snapshot holds no locks, so the kind can be changed while iterating
+        for id in &first {
+            ids.new_id("A", format!("{id}_copy"));
+        }
+        let second = ids.used_ids("A");
+        assert!(!Arc::ptr_eq(&first.0, &second.0));
+        assert_eq!(first.iter().collect::<Vec<_>>(), vec!["test:a"]);
+        assert_eq!(
+            second.filtered("_copy$").collect::<Vec<_>>(),
+            vec!["test:a_copy"]
+        );
+    }
+
+    #[test]
+    fn exhaustion_panic() {
+        let ids = IdMapping::default();
+        ids.add_id_range(0..1);
+        ids.new_id("A", "test:a");
+        let result = catch_unwind(AssertUnwindSafe(|| ids.try_new_id("A", "test:b")));
+        assert!(result.is_err());
+        let result = catch_unwind(AssertUnwindSafe(|| ids.try_new_id("B", "test:b")));
+        assert_eq!(result.unwrap().unwrap(), 0);
+    }
+
+    #[test]
+    fn exhaustion_error() {
+        let ids = IdMapping::default();
+        ids.set_exhaustion_policy(IdExhaustionPolicy::Error);
+        assert!(matches!(
+            ids.try_new_id("A", "test:a"),
+            Err(IdAllocationError::NoRanges { .. })
+        ));
+        ids.add_id_range(0..1);
+        assert_eq!(ids.try_new_id("A", "test:a").unwrap(), 0);
+        assert!(matches!(
+            ids.try_new_id("A", "test:b"),
+            Err(IdAllocationError::Exhausted { .. })
+        ));
+        assert!(matches!(
+            ids.try_get_unstable_id("A"),
+            Err(IdAllocationError::Exhausted { .. })
+        ));
+        // Methods without the error reporting still panic
+        assert!(catch_unwind(AssertUnwindSafe(|| ids.new_id("A", "test:c"))).is_err());
+
+        // Failed allocation doesn't consume anything
+        ids.add_id_range(10..11);
+        assert_eq!(ids.try_new_id("A", "test:b").unwrap(), 10);
+    }
+
+    #[test]
+    fn exhaustion_auto_extend() {
+        let ids = IdMapping::default();
+        ids.set_exhaustion_policy(IdExhaustionPolicy::AutoExtend { step: 3 });
+        assert!(matches!(
+            ids.try_new_id("A", "test:a"),
+            Err(IdAllocationError::NoRanges { .. })
+        ));
+
+        ids.add_id_range(0..2);
+        // Occupied ID past the ranges moves the extension start
+        ids.set_id("A", "test:set", 5);
+        let allocated: Vec<_> = (0..6)
+            .map(|i| ids.new_id("A", format!("test:{i}")))
+            .collect();
+        assert_eq!(allocated, vec![0, 1, 6, 7, 8, 9]);
+        assert_eq!(ids.usage_report().kinds["A"].ranges, vec![0..2, 6..12]);
+        // Other kinds extend from their own allocations
+        assert_eq!(ids.get_unstable_id("B"), 0);
+
+        let ids = IdMapping::default();
+        ids.set_exhaustion_policy(IdExhaustionPolicy::AutoExtend { step: 0 });
+        ids.add_id_range(0..1);
+        ids.new_id("A", "test:a");
+        assert!(matches!(
+            ids.try_new_id("A", "test:b"),
+            Err(IdAllocationError::Exhausted { .. })
+        ));
+
+        let ids = IdMapping::default();
+        ids.set_exhaustion_policy(IdExhaustionPolicy::AutoExtend { step: 10 });
+        ids.add_id_range(i32::MAX - 1..i32::MAX);
+        ids.new_id("A", "test:a");
+        assert!(matches!(
+            ids.try_new_id("A", "test:b"),
+            Err(IdAllocationError::Exhausted { .. })
+        ));
+    }
+}
@@ -0,0 +1,585 @@
+//! Encoder and decoder of the `.ehm` mod files
+//!
+//! Mod file consists of the `0xDA7ABA5E` header, followed by the
+//! zlib-compressed payload, XOR-ed with a pseudo-random sequence seeded by
+//! the payload length, and a single checksum byte. Payload holds the mod
+//! info and a list of typed entries
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+use flate2::Compression;
+use thiserror::Error;
+use tracing::warn;
+
+use eh_schema::schema::DatabaseSettings;
+
+use crate::utils::{compress, decompress};
+
+const HEADER: u32 = 0xDA7ABA5E;
+const DB_VERSION: i32 = 1;
+
+#[derive(Debug, Clone)]
+pub struct ModBuilderInfo {
+    pub output_path: PathBuf,
+    pub name: String,
+    pub guid: String,
+    pub version_major: i32,
+    pub version_minor: i32,
+}
+
+impl ModBuilderInfo {
+    pub fn from_settings(output_path: PathBuf, data: &DatabaseSettings) -> ModBuilderInfo {
+        ModBuilderInfo {
+            output_path,
+            name: data.mod_name.clone(),
+            guid: data.mod_id.clone(),
+            version_major: data.database_version,
+            version_minor: data.database_version_minor,
+        }
+    }
+
+    fn header(&self) -> ModHeader {
+        ModHeader {
+            name: self.name.clone(),
+            guid: self.guid.clone(),
+            version_major: self.version_major,
+            version_minor: self.version_minor,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ModBuilderData(Option<BTreeMap<PathBuf, Vec<u8>>>);
+
+impl Default for ModBuilderData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ModBuilderData {
+    pub fn dummy() -> Self {
+        Self(None)
+    }
+
+    pub fn new() -> Self {
+        Self(Some(BTreeMap::new()))
+    }
+
+    pub fn add_file(&mut self, path: PathBuf, data: &[u8]) {
+        self.0.as_mut().map(|m| m.insert(path, data.to_vec()));
+    }
+
+    pub fn build(self, info: &ModBuilderInfo) -> std::io::Result<()> {
+        let Some(data) = self.0 else {
+            return Ok(());
+        };
+        let file = ModFile {
+            header: info.header(),
+            entries: data
+                .into_iter()
+                .filter_map(|(path, bytes)| ModEntry::from_file(&path, bytes))
+                .collect(),
+        };
+        let mut w = std::fs::File::create(&info.output_path)?;
+        encode(&mut w, &file)
+    }
+}
+
+/// Decoded contents of the mod file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModFile {
+    pub header: ModHeader,
+    pub entries: Vec<ModEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModHeader {
+    pub name: String,
+    pub guid: String,
+    pub version_major: i32,
+    pub version_minor: i32,
+}
+
+/// Single file of the mod
+///
+/// Images are named with the file extension, while audio and localization
+/// names don't include it, matching the way game looks them up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModEntry {
+    Data(Vec<u8>),
+    Image { name: String, data: Vec<u8> },
+    Localization { name: String, data: Vec<u8> },
+    WaveAudio { name: String, data: Vec<u8> },
+    OggAudio { name: String, data: Vec<u8> },
+}
+
+#[derive(Debug, Error)]
+pub enum ModFormatError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Invalid mod file header {:#010X}", .0)]
+    InvalidHeader(u32),
+    #[error("Mod file checksum mismatch")]
+    ChecksumMismatch,
+    #[error("Unsupported mod format version {}", .0)]
+    UnsupportedVersion(i32),
+    #[error("Unknown entry type {}", .0)]
+    UnknownEntryType(u8),
+    #[error("Mod file ended unexpectedly")]
+    UnexpectedEnd,
+    #[error("Invalid string in the mod file: {}", .0)]
+    InvalidString(#[from] std::string::FromUtf8Error),
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[repr(u8)]
+enum FileType {
+    None = 0,
+    Data = 1,
+    Image = 2,
+    Localization = 3,
+    WaveAudio = 4,
+    OggAudio = 5,
+}
+
+impl ModEntry {
+    /// Converts the file into the entry according to its extension
+    ///
+    /// Files of unknown type are skipped with a warning
+    pub fn from_file(path: &std::path::Path, bytes: Vec<u8>) -> Option<Self> {
+        let Some(ext) = path
+            .extension()
+            .and_then(|s| s.to_str())
+            .map(|s| s.to_lowercase())
+        else {
+            warn!(path=%path.display(), "Skipping serializing file with no extension");
+            return None;
+        };
+
+        let Some(file_name) = path.file_name().and_then(|s| s.to_str()) else {
+            warn!(path=%path.display(), "Skipping serializing file with no file name");
+            return None;
+        };
+
+        let Some(file_name_no_ext) = path.file_stem().and_then(|s| s.to_str()) else {
+            warn!(path=%path.display(), "Skipping serializing file with no file name");
+            return None;
+        };
+
+        let entry = match ext.as_str() {
+            "json" => ModEntry::Data(bytes),
+            "png" | "jpg" | "jpeg" => ModEntry::Image {
+                name: file_name.to_string(),
+                data: bytes,
+            },
+            "wav" => ModEntry::WaveAudio {
+                name: file_name_no_ext.to_string(),
+                data: bytes,
+            },
+            "ogg" => ModEntry::OggAudio {
+                name: file_name_no_ext.to_string(),
+                data: bytes,
+            },
+            "xml" => ModEntry::Localization {
+                name: file_name_no_ext.to_string(),
+                data: bytes,
+            },
+            _ => {
+                warn!(path=%path.display(), "Skipping serializing unknown file type");
+                return None;
+            }
+        };
+        Some(entry)
+    }
+}
+
+/// Writes the mod file
+pub fn encode(stream: &mut impl Write, file: &ModFile) -> std::io::Result<()> {
+    let mut raw_data: Vec<u8> = Default::default();
+    serialize_data(&mut raw_data, file)?;
+
+    encrypt(stream, raw_data)
+}
+
+/// Checks whether the data starts with the mod file header
+pub fn has_header(data: &[u8]) -> bool {
+    data.get(..4)
+        .is_some_and(|header| header == HEADER.to_le_bytes())
+}
+
+/// Reads the mod file written by [encode] or by the game tools
+pub fn decode(data: &[u8]) -> Result<ModFile, ModFormatError> {
+    let raw_data = decrypt(data)?;
+    deserialize_data(&mut Reader(&raw_data))
+}
+
+fn encrypt(stream: &mut impl Write, raw_data: Vec<u8>) -> std::io::Result<()> {
+    serialize_header(stream)?;
+
+    let mut data = compress(&raw_data, Compression::best());
+
+    let size = data.len() as u32;
+
+    let mut w = 0x12345678 ^ size;
+    let mut z = 0x87654321 ^ size;
+    let mut checksum: u8 = 0;
+
+    for item in data.iter_mut() {
+        checksum = checksum.wrapping_add(*item);
+
+        *item ^= random(&mut w, &mut z) as u8
+    }
+
+    stream.write_all(&data)?;
+
+    stream.write_all(&[checksum ^ random(&mut w, &mut z) as u8])?;
+
+    Ok(())
+}
+
+fn decrypt(data: &[u8]) -> Result<Vec<u8>, ModFormatError> {
+    let mut reader = Reader(data);
+    let header = reader.uint()?;
+    if header != HEADER {
+        return Err(ModFormatError::InvalidHeader(header));
+    }
+    let Some((&stored_checksum, data)) = reader.0.split_last() else {
+        return Err(ModFormatError::UnexpectedEnd);
+    };
+
+    let size = data.len() as u32;
+
+    let mut w = 0x12345678 ^ size;
+    let mut z = 0x87654321 ^ size;
+    let mut checksum: u8 = 0;
+
+    let mut data = data.to_vec();
+    for item in data.iter_mut() {
+        *item ^= random(&mut w, &mut z) as u8;
+
+        checksum = checksum.wrapping_add(*item);
+    }
+
+    if stored_checksum ^ random(&mut w, &mut z) as u8 != checksum {
+        return Err(ModFormatError::ChecksumMismatch);
+    }
+
+    Ok(decompress(&data)?)
+}
+
+fn serialize_header(w: &mut impl Write) -> std::io::Result<()> {
+    serialize_uint(w, HEADER)
+}
+
+fn serialize_data(w: &mut impl Write, file: &ModFile) -> std::io::Result<()> {
+    serialize_int(w, DB_VERSION)?;
+    serialize_string(w, &file.header.name)?;
+    serialize_string(w, &file.header.guid)?;
+    serialize_int(w, file.header.version_major)?;
+    serialize_int(w, file.header.version_minor)?;
+
+    for entry in &file.entries {
+        match entry {
+            ModEntry::Data(data) => {
+                serialize_type(w, FileType::Data)?;
+                serialize_bytes(w, data)?;
+            }
+            ModEntry::Image { name, data } => {
+                serialize_type(w, FileType::Image)?;
+                serialize_string(w, name)?;
+                serialize_bytes(w, data)?;
+            }
+            ModEntry::WaveAudio { name, data } => {
+                serialize_type(w, FileType::WaveAudio)?;
+                serialize_string(w, name)?;
+                serialize_bytes(w, data)?;
+            }
+            ModEntry::OggAudio { name, data } => {
+                serialize_type(w, FileType::OggAudio)?;
+                serialize_string(w, name)?;
+                serialize_bytes(w, data)?;
+            }
+            ModEntry::Localization { name, data } => {
+                serialize_type(w, FileType::Localization)?;
+                serialize_string(w, name)?;
+                serialize_bytes(w, data)?;
+            }
+        }
+    }
+
+    serialize_type(w, FileType::None)?;
+
+    Ok(())
+}
+
+fn deserialize_data(r: &mut Reader) -> Result<ModFile, ModFormatError> {
+    let version = r.int()?;
+    if version != DB_VERSION {
+        return Err(ModFormatError::UnsupportedVersion(version));
+    }
+    let header = ModHeader {
+        name: r.string()?,
+        guid: r.string()?,
+        version_major: r.int()?,
+        version_minor: r.int()?,
+    };
+
+    let mut entries = vec![];
+    loop {
+        let ty = r.byte()?;
+        let entry = match ty {
+            ty if ty == FileType::None as u8 => break,
+            ty if ty == FileType::Data as u8 => ModEntry::Data(r.bytes()?),
+            ty if ty == FileType::Image as u8 => ModEntry::Image {
+                name: r.string()?,
+                data: r.bytes()?,
+            },
+            ty if ty == FileType::Localization as u8 => ModEntry::Localization {
+                name: r.string()?,
+                data: r.bytes()?,
+            },
+            ty if ty == FileType::WaveAudio as u8 => ModEntry::WaveAudio {
+                name: r.string()?,
+                data: r.bytes()?,
+            },
+            ty if ty == FileType::OggAudio as u8 => ModEntry::OggAudio {
+                name: r.string()?,
+                data: r.bytes()?,
+            },
+            ty => return Err(ModFormatError::UnknownEntryType(ty)),
+        };
+        entries.push(entry);
+    }
+
+    Ok(ModFile { header, entries })
+}
+
+fn serialize_type(w: &mut impl Write, data: FileType) -> std::io::Result<()> {
+    w.write_all(&[data as u8])
+}
+
+fn serialize_int(w: &mut impl Write, data: i32) -> std::io::Result<()> {
+    let bytes = data.to_le_bytes();
+    w.write_all(&bytes)
+}
+
+fn serialize_uint(w: &mut impl Write, data: u32) -> std::io::Result<()> {
+    let bytes = data.to_le_bytes();
+    w.write_all(&bytes)
+}
+
+fn serialize_string(w: &mut impl Write, data: &str) -> std::io::Result<()> {
+    serialize_bytes(w, data.as_bytes())
+}
+
+fn serialize_bytes(w: &mut impl Write, data: &[u8]) -> std::io::Result<()> {
+    if data.is_empty() {
+        return serialize_int(w, 0);
+    }
+
+    serialize_int(w, data.len() as i32)?;
+
+    w.write_all(data)
+}
+
+/// Cursor over the decoded data, mirroring the `serialize_` functions
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take(&mut self, len: usize) -> Result<&[u8], ModFormatError> {
+        if self.0.len() < len {
+            return Err(ModFormatError::UnexpectedEnd);
+        }
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn byte(&mut self) -> Result<u8, ModFormatError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn int(&mut self) -> Result<i32, ModFormatError> {
+        let bytes = self.take(4)?;
+        Ok(i32::from_le_bytes(
+            bytes.try_into().expect("Should take 4 bytes"),
+        ))
+    }
+
+    fn uint(&mut self) -> Result<u32, ModFormatError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes(
+            bytes.try_into().expect("Should take 4 bytes"),
+        ))
+    }
+
+    fn bytes(&mut self) -> Result<Vec<u8>, ModFormatError> {
+        let len = self.int()?;
+        let len = usize::try_from(len).map_err(|_| ModFormatError::UnexpectedEnd)?;
+        Ok(self.take(len)?.to_vec())
+    }
+
+    fn string(&mut self) -> Result<String, ModFormatError> {
+        Ok(String::from_utf8(self.bytes()?)?)
+    }
+}
+
+fn random(w: &mut u32, z: &mut u32) -> u32 {
+    *z = (36969u32.wrapping_mul((*z) & (u16::MAX as u32))) + (*z >> 16);
+    *w = (18000u32.wrapping_mul((*w) & (u16::MAX as u32))) + (*w >> 16);
+    (*z << 16).wrapping_add(*w)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{decode, decrypt, encode, encrypt, random, ModEntry, ModFile, ModHeader};
+
+    /// Directory of the `.ehm` files produced by the game tools
+    const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/mod_format");
+
+    /// Deterministic byte sequence, reusing the format's generator
+    fn bytes(seed: u32, len: usize) -> Vec<u8> {
+        let (mut w, mut z) = (seed ^ 0x5EED, seed.rotate_left(16) | 1);
+        (0..len).map(|_| random(&mut w, &mut z) as u8).collect()
+    }
+
+    fn sample_file(seed: u32) -> ModFile {
+        let entries = (0..seed % 7)
+            .map(|i| {
+                let data = bytes(seed * 31 + i, (seed * i) as usize % 300);
+                let name = format!("entry_{seed}_{i}");
+                match i % 5 {
+                    0 => ModEntry::Data(data),
+                    1 => ModEntry::Image { name, data },
+                    2 => ModEntry::Localization { name, data },
+                    3 => ModEntry::WaveAudio { name, data },
+                    _ => ModEntry::OggAudio { name, data },
+                }
+            })
+            .collect();
+        ModFile {
+            header: ModHeader {
+                name: format!("Mod {seed}"),
+                guid: format!("mod_{seed}"),
+                version_major: seed as i32,
+                version_minor: -(seed as i32),
+            },
+            entries,
+        }
+    }
+
+    fn encoded(file: &ModFile) -> Vec<u8> {
+        let mut buf = vec![];
+        encode(&mut buf, file).unwrap();
+        buf
+    }
+
+    #[test]
+    fn encode_bytes() {
+        let data = vec![
+            94, 186, 122, 218, 12, 36, 119, 53, 67, 251, 27, 41, 148, 224, 164, 255, 246,
+        ];
+        let mut buf = vec![];
+
+        encrypt(&mut buf, data.clone()).unwrap();
+
+        assert_eq!(
+            buf,
+            vec![
+                94, 186, 122, 218, 172, 35, 253, 196, 238, 156, 232, 96, 21, 110, 99, 215, 38, 10,
+                148, 28, 227, 142, 75, 71, 184, 185, 97, 255, 233, 199, 150
+            ]
+        );
+        assert_eq!(decrypt(&buf).unwrap(), data);
+    }
+
+    /// Regression vector produced by this encoder rather than the game, so
+    /// it only pins down the current output. Compatibility with the game is
+    /// checked by [fixtures]
+    #[test]
+    fn encode_file_bytes() {
+        let file = ModFile {
+            header: ModHeader {
+                name: "Test".to_string(),
+                guid: "test".to_string(),
+                version_major: 1,
+                version_minor: 2,
+            },
+            entries: vec![
+                ModEntry::Data(b"{}".to_vec()),
+                ModEntry::Localization {
+                    name: "en".to_string(),
+                    data: b"<a/>".to_vec(),
+                },
+            ],
+        };
+
+        assert_eq!(
+            encoded(&file),
+            vec![
+                94, 186, 122, 218, 60, 46, 254, 217, 50, 248, 92, 217, 92, 212, 169, 213, 140, 187,
+                10, 185, 71, 60, 240, 4, 213, 170, 253, 160, 72, 210, 97, 254, 241, 136, 203, 137,
+                127, 30, 213, 219, 201, 209, 245, 97, 36, 83, 199, 176, 155, 248, 27, 151, 252, 82,
+                160, 177, 155, 175
+            ]
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        for seed in 0..64 {
+            let file = sample_file(seed);
+            let data = encoded(&file);
+            let decoded = decode(&data).unwrap();
+            assert_eq!(decoded, file, "seed {seed}");
+            assert_eq!(encoded(&decoded), data, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn corrupted() {
+        for seed in 1..16 {
+            let mut data = encoded(&sample_file(seed));
+            let i = 4 + bytes(seed, 1)[0] as usize % (data.len() - 4);
+            data[i] ^= 0x10;
+            assert!(decode(&data).is_err(), "seed {seed}");
+        }
+    }
+
+    /// Decodes every `.ehm` file in [FIXTURES_DIR] and checks that encoding
+    /// it again reproduces the original bytes exactly
+    #[test]
+    fn fixtures() {
+        let dir = std::fs::read_dir(FIXTURES_DIR)
+            .unwrap_or_else(|e| panic!("{FIXTURES_DIR} should contain the fixtures: {e}"));
+        for entry in dir {
+            let path = entry.unwrap().path();
+            if path.extension().is_none_or(|ext| ext != "ehm") {
+                continue;
+            }
+            let data = std::fs::read(&path).unwrap();
+            let file = decode(&data).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+            assert!(
+                encoded(&file) == data,
+                "{}: re-encoded bytes differ from the original",
+                path.display()
+            );
+            assert_fixture_entries(&path, &file);
+        }
+    }
+
+    /// Checks that the fixture entries are readable by the database
+    fn assert_fixture_entries(path: &Path, file: &ModFile) {
+        for entry in &file.entries {
+            if let ModEntry::Data(data) = entry {
+                serde_json5::from_slice::<eh_schema::schema::Item>(data)
+                    .unwrap_or_else(|e| panic!("{}: invalid item: {e}", path.display()));
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::BTreeSet;
+use std::time::Instant;
+
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use tracing::{debug, error_span};
+
+use eh_schema::schema::DatabaseItem;
+
+use crate::database::Database;
+
+type PassFn = Box<dyn Fn(&Database) + Send + Sync>;
+
+/// Single step of the mod build, declaring which item types it accesses
+pub struct Pass {
+    name: String,
+    reads: BTreeSet<&'static str>,
+    writes: BTreeSet<&'static str>,
+    run: PassFn,
+}
+
+impl Pass {
+    pub fn new(name: impl Into<String>, run: impl Fn(&Database) + Send + Sync + 'static) -> Self {
+        Self {
+            name: name.into(),
+            reads: Default::default(),
+            writes: Default::default(),
+            run: Box::new(run),
+        }
+    }
+
+    /// Declares that the pass reads items of the given type
+    pub fn reads<T: DatabaseItem>(mut self) -> Self {
+        self.reads.insert(T::type_name());
+        self
+    }
+
+    /// Declares that the pass creates, modifies or removes items of the
+    /// given type
+    pub fn writes<T: DatabaseItem>(mut self) -> Self {
+        self.writes.insert(T::type_name());
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Checks whether the passes can't be run at the same time
+    fn conflicts(&self, other: &Pass) -> bool {
+        !self.writes.is_disjoint(&other.writes)
+            || !self.writes.is_disjoint(&other.reads)
+            || !self.reads.is_disjoint(&other.writes)
+    }
+}
+
+/// Runs passes according to their declared item accesses
+///
+/// Conflicting passes run in the order they were added, while independent
+/// passes run in parallel
+///
+/// Passes that allocate new IDs for the same type still get them in a
+/// non-deterministic order when run in parallel, declare a write to the
+/// type to keep the numeric IDs stable
+#[derive(Default)]
+pub struct Scheduler {
+    passes: Vec<Pass>,
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, pass: Pass) -> &mut Self {
+        self.passes.push(pass);
+        self
+    }
+
+    pub fn with(mut self, pass: Pass) -> Self {
+        self.add(pass);
+        self
+    }
+
+    /// Groups passes into stages, where passes of each stage are
+    /// independent of each other and only depend on the earlier stages
+    pub fn stages(&self) -> Vec<Vec<&str>> {
+        self.stage_indices()
+            .into_iter()
+            .map(|stage| stage.into_iter().map(|i| self.passes[i].name()).collect())
+            .collect()
+    }
+
+    fn stage_indices(&self) -> Vec<Vec<usize>> {
+        let mut stage_of: Vec<usize> = Vec::with_capacity(self.passes.len());
+        for (i, pass) in self.passes.iter().enumerate() {
+            let stage = self.passes[..i]
+                .iter()
+                .zip(&stage_of)
+                .filter(|(earlier, _)| earlier.conflicts(pass))
+                .map(|(_, stage)| stage + 1)
+                .max()
+                .unwrap_or(0);
+            stage_of.push(stage);
+        }
+
+        let mut stages = vec![vec![]; stage_of.iter().max().map_or(0, |s| s + 1)];
+        for (i, stage) in stage_of.into_iter().enumerate() {
+            stages[stage].push(i);
+        }
+        stages
+    }
+
+    /// Runs all the passes
+    pub fn run(&self, db: &Database) {
+        for stage in self.stage_indices() {
+            stage
+                .par_iter()
+                .for_each(|i| run_pass(&self.passes[*i], db));
+        }
+    }
+
+    /// Runs all the passes one by one, in the order they were added
+    pub fn run_sequential(&self, db: &Database) {
+        for pass in &self.passes {
+            run_pass(pass, db);
+        }
+    }
+}
+
+fn run_pass(pass: &Pass, db: &Database) {
+    let _guard = error_span!("Running pass", name = pass.name).entered();
+    let start = Instant::now();
+    (pass.run)(db);
+    debug!(time = ?start.elapsed(), "Pass finished");
+}
@@ -0,0 +1,130 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use diagnostic::context::DiagnosticContext;
+use diagnostic::diagnostic::CheckGroup;
+
+/// Handling of the diagnostics of a [CheckGroup]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckLevel {
+    /// Diagnostics are discarded
+    Off,
+    /// Diagnostics keep their own severity
+    Default,
+    /// Diagnostics are reported as warnings
+    Warning,
+    /// Diagnostics are reported as errors
+    Error,
+}
+
+/// Check levels applied to the diagnostics on save, see
+/// [SaveOptions::validation_profile](crate::database::SaveOptions::validation_profile)
+///
+/// Checks of the [CheckGroup::Core] always keep their own severity, since
+/// they report the data that is certainly broken
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValidationProfile {
+    /// All checks fail the build, for the release builds
+    Strict,
+    /// Diagnostics keep their own severity
+    #[default]
+    VanillaCompat,
+    /// Range checks and lints are skipped, while the reference and engine
+    /// limit checks are reported as warnings, for the quick experimental
+    /// builds
+    Permissive,
+}
+
+impl ValidationProfile {
+    pub fn level(self, group: CheckGroup) -> CheckLevel {
+        match (self, group) {
+            (_, CheckGroup::Core) => CheckLevel::Default,
+            (ValidationProfile::Strict, _) => CheckLevel::Error,
+            (ValidationProfile::VanillaCompat, _) => CheckLevel::Default,
+            (ValidationProfile::Permissive, CheckGroup::Range | CheckGroup::Lint) => {
+                CheckLevel::Off
+            }
+            (ValidationProfile::Permissive, CheckGroup::Reference | CheckGroup::EngineLimit) => {
+                CheckLevel::Warning
+            }
+        }
+    }
+
+    /// Discards the diagnostics of the disabled checks and adjusts the
+    /// severity of the rest
+    pub fn apply(self, ctx: &mut DiagnosticContext) {
+        for diagnostics in ctx.diagnostics.values_mut() {
+            diagnostics.retain_mut(|diagnostic| {
+                match self.level(diagnostic.kind.group()) {
+                    CheckLevel::Off => return false,
+                    CheckLevel::Default => {}
+                    CheckLevel::Warning => diagnostic.error = false,
+                    CheckLevel::Error => diagnostic.error = true,
+                }
+                true
+            });
+        }
+    }
+}
+
+impl Display for ValidationProfile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationProfile::Strict => write!(f, "strict"),
+            ValidationProfile::VanillaCompat => write!(f, "vanilla-compat"),
+            ValidationProfile::Permissive => write!(f, "permissive"),
+        }
+    }
+}
+
+impl FromStr for ValidationProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "strict" => Ok(ValidationProfile::Strict),
+            "vanilla-compat" => Ok(ValidationProfile::VanillaCompat),
+            "permissive" => Ok(ValidationProfile::Permissive),
+            _ => Err(format!(
+                "unknown validation profile `{s}`, expected one of `strict`, `vanilla-compat`, `permissive`"
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diagnostic::context::DiagnosticContext;
+    use diagnostic::diagnostic::DiagnosticKind;
+
+    use super::ValidationProfile;
+
+    fn context() -> DiagnosticContext {
+        let mut ctx = DiagnosticContext::default();
+        let mut entry = ctx.enter("item.json");
+        entry.emit(DiagnosticKind::too_small(0, -1));
+        entry.emit(DiagnosticKind::unresolved_reference("Ship", 1, vec![]));
+        entry.emit(DiagnosticKind::invalid_color("red"));
+        drop(entry);
+        ctx
+    }
+
+    fn severities(profile: ValidationProfile) -> Vec<bool> {
+        let mut ctx = context();
+        profile.apply(&mut ctx);
+        ctx.diagnostics["item.json"]
+            .iter()
+            .map(|d| d.error)
+            .collect()
+    }
+
+    #[test]
+    fn profiles() {
+        assert_eq!(
+            severities(ValidationProfile::VanillaCompat),
+            [false, true, true]
+        );
+        assert_eq!(severities(ValidationProfile::Strict), [true, true, true]);
+        assert_eq!(severities(ValidationProfile::Permissive), [false, true]);
+    }
+}
@@ -0,0 +1,344 @@
+//! Random items for stress testing, see [ItemGenerator]
+
+use std::collections::BTreeSet;
+
+use ahash::AHashMap;
+use serde_json::{Number, Value};
+
+use diagnostic::context::DiagnosticContext;
+use diagnostic::diagnostic::DiagnosticKind;
+use diagnostic::path::DiagnosticPathSegment;
+use eh_schema::schema::{DatabaseItem, Item};
+
+use crate::database::provenance::Provenance;
+use crate::database::DatabaseHolder;
+
+/// Integer fields with at most this many distinct values are treated as
+/// enums, and only get the observed values
+const ENUM_DOMAIN_LIMIT: usize = 32;
+/// Chance of each field or list to be changed
+const MUTATION_RATE: f64 = 0.3;
+/// Lists are not grown past this length
+const MAX_LIST_LEN: usize = 64;
+/// Fields identifying the item, which are never mutated
+const KEY_FIELDS: [&str; 2] = ["ItemType", "Id"];
+
+/// Values of the numeric field, observed across the template items
+#[derive(Debug, Clone)]
+struct Domain {
+    integer: bool,
+    min: f64,
+    max: f64,
+    /// Distinct values, or `None` once there are too many of them
+    values: Option<BTreeSet<i64>>,
+}
+
+impl Domain {
+    fn observe(&mut self, number: &Number) {
+        let value = number.as_f64().unwrap_or_default();
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        match number.as_i64() {
+            Some(int) if self.integer => {
+                if let Some(values) = &mut self.values {
+                    values.insert(int);
+                    if values.len() > ENUM_DOMAIN_LIMIT {
+                        self.values = None;
+                    }
+                }
+            }
+            _ => {
+                self.integer = false;
+                self.values = None;
+            }
+        }
+    }
+
+    fn sample(&self, rng: &mut SplitMix64) -> Option<Number> {
+        if let Some(values) = &self.values {
+            let index = rng.below(values.len());
+            return values.iter().nth(index).map(|value| Number::from(*value));
+        }
+        if self.integer {
+            let value = rng.range(self.min, self.max + 1.0).floor();
+            return Some(Number::from(value as i64));
+        }
+        // Floats are allowed to leave the observed range, the schema bounds
+        // are applied afterwards
+        let span = (self.max - self.min).max(1.0);
+        Number::from_f64(rng.range(self.min - span / 2.0, self.max + span / 2.0))
+    }
+}
+
+/// Generates random, but deserializable items of all types
+///
+/// Items are derived from the templates, which are the items stored in the
+/// database the generator was created from. Numeric fields get random
+/// values from the ranges observed across the templates, integer fields
+/// with few distinct values are treated as enums, and lists are randomly
+/// shrunk or grown. The results are clamped to the schema bounds, as
+/// reported by the item validation
+///
+/// Generation is deterministic for the same templates and seed
+pub struct ItemGenerator {
+    templates: Vec<Item>,
+    domains: AHashMap<String, Domain>,
+    next_ids: AHashMap<&'static str, i32>,
+    rng: SplitMix64,
+}
+
+impl ItemGenerator {
+    /// Creates the generator using the items with IDs stored in the database
+    /// as the templates
+    pub fn from_database(db: &DatabaseHolder, seed: u64) -> Self {
+        let mut generator = Self {
+            templates: vec![],
+            domains: Default::default(),
+            next_ids: Default::default(),
+            rng: SplitMix64(seed),
+        };
+        db.iter_all(|ty, id, item| {
+            let Some(id) = id else {
+                return;
+            };
+            let next_id = generator.next_ids.entry(ty).or_default();
+            *next_id = (*next_id).max(id + 1);
+            let value = serde_json::to_value(item).expect("Items should be serializable");
+            observe(&mut generator.domains, &mut String::new(), &value);
+            generator.templates.push(item.clone());
+        });
+        generator
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.templates.is_empty()
+    }
+
+    /// Generates the item with a fresh ID
+    ///
+    /// # Panics
+    /// Panics if there are no templates
+    pub fn generate(&mut self) -> Item {
+        assert!(!self.is_empty(), "Item generator has no templates");
+        let template = &self.templates[self.rng.below(self.templates.len())];
+        let next_id = self
+            .next_ids
+            .get_mut(template.inner_type_name())
+            .expect("Templates should have IDs");
+        let id = *next_id;
+        *next_id += 1;
+
+        let mut value = serde_json::to_value(template).expect("Items should be serializable");
+        mutate(&self.domains, &mut self.rng, &mut String::new(), &mut value);
+        value["Id"] = Value::from(id);
+
+        // Mutations are not guaranteed to produce a valid item, in which case
+        // the template is used as is
+        let Ok(item) = serde_json::from_value::<Item>(value.clone()) else {
+            let mut value = serde_json::to_value(template).expect("Items should be serializable");
+            value["Id"] = Value::from(id);
+            return serde_json::from_value(value).expect("Template should be deserializable");
+        };
+        clamp_to_bounds(item, value)
+    }
+}
+
+impl DatabaseHolder {
+    /// Adds the given number of items produced by the generator
+    pub fn add_random_items(&self, generator: &mut ItemGenerator, count: usize) {
+        for _ in 0..count {
+            self.consume_item(generator.generate(), Provenance::capture());
+        }
+    }
+}
+
+fn observe(domains: &mut AHashMap<String, Domain>, path: &mut String, value: &Value) {
+    let len = path.len();
+    match value {
+        Value::Number(number) => {
+            domains
+                .entry(path.clone())
+                .or_insert_with(|| Domain {
+                    integer: true,
+                    min: f64::INFINITY,
+                    max: f64::NEG_INFINITY,
+                    values: Some(Default::default()),
+                })
+                .observe(number);
+        }
+        Value::Array(items) => {
+            path.push_str("[]");
+            for item in items {
+                observe(domains, path, item);
+            }
+        }
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                path.push('.');
+                path.push_str(key);
+                observe(domains, path, value);
+                path.truncate(len);
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::String(_) => {}
+    }
+    path.truncate(len);
+}
+
+fn mutate(
+    domains: &AHashMap<String, Domain>,
+    rng: &mut SplitMix64,
+    path: &mut String,
+    value: &mut Value,
+) {
+    let len = path.len();
+    match value {
+        Value::Number(number) => {
+            if rng.chance(MUTATION_RATE) {
+                if let Some(sample) = domains.get(path.as_str()).and_then(|d| d.sample(rng)) {
+                    *number = sample;
+                }
+            }
+        }
+        Value::Bool(flag) => {
+            if rng.chance(MUTATION_RATE) {
+                *flag = !*flag;
+            }
+        }
+        Value::Array(items) => {
+            if !items.is_empty() && rng.chance(MUTATION_RATE) {
+                let index = rng.below(items.len());
+                if rng.chance(0.5) {
+                    items.remove(index);
+                } else if items.len() < MAX_LIST_LEN {
+                    items.push(items[index].clone());
+                }
+            }
+            path.push_str("[]");
+            for item in items {
+                mutate(domains, rng, path, item);
+            }
+        }
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                if path.is_empty() && KEY_FIELDS.contains(&key.as_str()) {
+                    continue;
+                }
+                path.push('.');
+                path.push_str(key);
+                mutate(domains, rng, path, value);
+                path.truncate(len);
+            }
+        }
+        Value::Null | Value::String(_) => {}
+    }
+    path.truncate(len);
+}
+
+/// Moves the values reported as out of bounds by the item validation to
+/// the bounds
+fn clamp_to_bounds(item: Item, mut value: Value) -> Item {
+    let mut ctx = DiagnosticContext::default();
+    item.validate(ctx.enter("item"));
+    let mut clamped = false;
+    for diagnostic in ctx.diagnostics.into_values().flatten() {
+        let bound = match diagnostic.kind {
+            DiagnosticKind::ValueTooSmall { min, .. } => min,
+            DiagnosticKind::ValueTooLarge { max, .. } => max,
+            _ => continue,
+        };
+        let Some(Value::Number(number)) = resolve(&mut value, diagnostic.path.iter()) else {
+            continue;
+        };
+        let bound = if number.is_f64() {
+            Number::from_f64(bound)
+        } else {
+            Some(Number::from(bound as i64))
+        };
+        if let Some(bound) = bound {
+            *number = bound;
+            clamped = true;
+        }
+    }
+    if !clamped {
+        return item;
+    }
+    serde_json::from_value(value).unwrap_or(item)
+}
+
+/// Finds the JSON value of the field at the diagnostic path
+fn resolve<'a>(
+    mut value: &'a mut Value,
+    path: impl Iterator<Item = &'a DiagnosticPathSegment>,
+) -> Option<&'a mut Value> {
+    for segment in path {
+        value = match segment {
+            DiagnosticPathSegment::Field(field) => value.get_mut(pascal_case(field))?,
+            DiagnosticPathSegment::Index(index) => value.get_mut(index)?,
+            // Variant fields are flattened into the object
+            DiagnosticPathSegment::Variant(_) => value,
+        };
+    }
+    Some(value)
+}
+
+/// Converts the field name into the serialized one, same as
+/// `#[serde(rename_all = "PascalCase")]`
+fn pascal_case(field: &str) -> String {
+    let mut name = String::with_capacity(field.len());
+    let mut capitalize = true;
+    for ch in field.chars() {
+        if ch == '_' {
+            capitalize = true;
+        } else if capitalize {
+            name.extend(ch.to_uppercase());
+            capitalize = false;
+        } else {
+            name.push(ch);
+        }
+    }
+    name
+}
+
+/// Small seedable generator, so stress runs can be reproduced
+#[derive(Debug, Clone)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Random number in `0..1`
+    fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn range(&mut self, min: f64, max: f64) -> f64 {
+        min + (max - min) * self.unit()
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    fn chance(&mut self, probability: f64) -> bool {
+        self.unit() < probability
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pascal_case;
+
+    #[test]
+    fn field_names() {
+        assert_eq!(pascal_case("fire_rate"), "FireRate");
+        assert_eq!(pascal_case("type"), "Type");
+        assert_eq!(pascal_case("hp"), "Hp");
+    }
+}
@@ -0,0 +1,133 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use diagnostic::diagnostic::DiagnosticKind;
+use eh_schema::schema::DatabaseSettings;
+
+use crate::database::DatabaseHolder;
+
+/// Mod listed in the [ModRegistry]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownMod {
+    pub guid: String,
+    pub name: String,
+    pub version: i32,
+}
+
+impl KnownMod {
+    pub fn from_settings(settings: &DatabaseSettings) -> Self {
+        Self {
+            guid: settings.mod_id.clone(),
+            name: settings.mod_name.clone(),
+            version: settings.mod_version,
+        }
+    }
+}
+
+/// List of the known mods, to check the mod identity against on save, see
+/// [DatabaseHolder::check_mod_registry]
+///
+/// Mods are told apart by their names. Entry with the same GUID and name
+/// is the last published build of this mod, while entry with the same GUID
+/// but a different name is another mod that uses the same GUID
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModRegistry {
+    pub mods: Vec<KnownMod>,
+}
+
+impl ModRegistry {
+    pub fn new(mods: impl IntoIterator<Item = KnownMod>) -> Self {
+        Self {
+            mods: mods.into_iter().collect(),
+        }
+    }
+
+    /// Reads the registry written by [ModRegistry::save]
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let data = fs_err::read_to_string(path)?;
+        serde_json::from_str(&data)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let code =
+            serde_json::to_string_pretty(self).expect("Should be able to serialize registry");
+        fs_err::write(path, code)
+    }
+
+    /// Records the published build, replacing the previous build of the
+    /// same mod
+    ///
+    /// Should be called for each released build, so the following builds
+    /// are checked for the version bump
+    pub fn record_published(&mut self, published: KnownMod) {
+        self.mods
+            .retain(|m| m.guid != published.guid || m.name != published.name);
+        self.mods.push(published);
+    }
+
+    /// Lists the problems of the mod identity, namely the GUID used by
+    /// other mods and the version that is not greater than the published
+    /// one
+    pub fn check(&self, current: &KnownMod) -> Vec<DiagnosticKind> {
+        let mut issues = vec![];
+        for known in self.mods.iter().filter(|m| m.guid == current.guid) {
+            if known.name != current.name {
+                issues.push(DiagnosticKind::mod_id_collision(&known.guid, &known.name));
+            } else if current.version <= known.version {
+                issues.push(DiagnosticKind::mod_version_not_bumped(
+                    current.version,
+                    known.version,
+                ));
+            }
+        }
+        issues
+    }
+}
+
+/// Registry to check the mod identity against, stored as a database extra
+#[derive(Debug, Default)]
+pub(crate) struct RegistryGuard {
+    pub registry: Option<ModRegistry>,
+}
+
+impl DatabaseHolder {
+    /// Checks the `DatabaseSettings` against the registry on save, warning
+    /// when the mod ID is used by another mod, or when the version wasn't
+    /// bumped since the published build
+    pub fn check_mod_registry(&self, registry: ModRegistry) {
+        self.extra_or_init::<RegistryGuard>().write().registry = Some(registry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KnownMod, ModRegistry};
+
+    fn known(guid: &str, name: &str, version: i32) -> KnownMod {
+        KnownMod {
+            guid: guid.to_string(),
+            name: name.to_string(),
+            version,
+        }
+    }
+
+    #[test]
+    fn registry_check() {
+        let mut registry = ModRegistry::new([known("abc", "Other mod", 3)]);
+        registry.record_published(known("xyz", "My mod", 1));
+        registry.record_published(known("xyz", "My mod", 2));
+        assert_eq!(registry.mods.len(), 2);
+
+        assert!(registry.check(&known("xyz", "My mod", 3)).is_empty());
+        assert_eq!(
+            registry.check(&known("xyz", "My mod", 2))[0].to_string(),
+            "Mod version 2 was not bumped since the last published version 2"
+        );
+        assert_eq!(
+            registry.check(&known("abc", "My mod", 1))[0].to_string(),
+            "Mod ID `abc` is already used by the mod `Other mod`"
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use ahash::{AHashMap, AHashSet};
+
+use eh_schema::schema::{Faction, FactionId};
+
+use crate::database::{Database, DatabaseHolder};
+
+/// Side of a relation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Party {
+    Player,
+    Faction(FactionId),
+}
+
+impl From<FactionId> for Party {
+    fn from(value: FactionId) -> Self {
+        Party::Faction(value)
+    }
+}
+
+impl Display for Party {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Party::Player => write!(f, "player"),
+            Party::Faction(id) => write!(f, "faction {}", id.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Relation {
+    Friendly,
+    #[default]
+    Neutral,
+    Hostile,
+}
+
+impl Display for Relation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Relation::Friendly => write!(f, "friendly"),
+            Relation::Neutral => write!(f, "neutral"),
+            Relation::Hostile => write!(f, "hostile"),
+        }
+    }
+}
+
+/// Explicitly defined directed relations, stored as a database extra
+#[derive(Debug, Default)]
+struct RelationMatrix {
+    relations: AHashMap<(Party, Party), Relation>,
+}
+
+/// Matrix-like view of the pairwise relations between factions and the player
+///
+/// Game data only has a notion of faction being hostile towards the player,
+/// so only the `faction -> player` relation is written into the items, via
+/// [Faction::hostile]. All other relations are kept for the lifetime of the
+/// database, to be consumed by the mod code and checked for consistency
+#[derive(Debug, Clone)]
+pub struct Relations {
+    db: Database,
+}
+
+impl DatabaseHolder {
+    pub fn relations(self: &Arc<Self>) -> Relations {
+        Relations { db: self.clone() }
+    }
+}
+
+impl Relations {
+    /// Relation of `from` towards `to`
+    ///
+    /// Relations that were never set are neutral, unless the faction is
+    /// marked hostile in its item
+    pub fn get(&self, from: impl Into<Party>, to: impl Into<Party>) -> Relation {
+        let (from, to) = (from.into(), to.into());
+        if from == to {
+            return Relation::Friendly;
+        }
+        let stored = self
+            .db
+            .extra_or_init::<RelationMatrix>()
+            .read()
+            .relations
+            .get(&(from, to))
+            .copied()
+            .unwrap_or_default();
+
+        if let (Party::Faction(faction), Party::Player) = (from, to) {
+            let hostile = self
+                .db
+                .get_item::<Faction>(faction)
+                .is_some_and(|f| f.read().hostile);
+            return match (hostile, stored) {
+                (true, _) => Relation::Hostile,
+                (false, Relation::Hostile) => Relation::Neutral,
+                (false, stored) => stored,
+            };
+        }
+
+        stored
+    }
+
+    /// Sets relation of `from` towards `to`, leaving the reverse relation
+    /// untouched
+    ///
+    /// # Panics
+    /// Will panic when setting a relation of the party towards itself, or
+    /// when setting the relation towards the player for a faction that is
+    /// not present in the database
+    pub fn set(&self, from: impl Into<Party>, to: impl Into<Party>, relation: Relation) -> &Self {
+        let (from, to) = (from.into(), to.into());
+        assert_ne!(from, to, "Party can't have a relation towards itself");
+        if let (Party::Faction(faction), Party::Player) = (from, to) {
+            self.db
+                .get_item::<Faction>(faction)
+                .expect("Faction should be present in the database")
+                .edit(|f| f.hostile = relation == Relation::Hostile);
+        }
+        self.db.extra_or_init::<RelationMatrix>().edit(|m| {
+            m.relations.insert((from, to), relation);
+        });
+        self
+    }
+
+    /// Sets relation in both directions
+    pub fn set_symmetric(
+        &self,
+        a: impl Into<Party>,
+        b: impl Into<Party>,
+        relation: Relation,
+    ) -> &Self {
+        let (a, b) = (a.into(), b.into());
+        self.set(a, b, relation).set(b, a, relation)
+    }
+
+    /// Sets relation of every other party in the database towards `target`
+    pub fn everyone_towards(&self, target: impl Into<Party>, relation: Relation) -> &Self {
+        let target = target.into();
+        for party in self.parties() {
+            if party != target {
+                self.set(party, target, relation);
+            }
+        }
+        self
+    }
+
+    /// Makes every other party in the database hostile towards `target`
+    pub fn everyone_hostile_to(&self, target: impl Into<Party>) -> &Self {
+        self.everyone_towards(target, Relation::Hostile)
+    }
+
+    /// Sets relation of `source` towards every other party in the database
+    pub fn towards_everyone(&self, source: impl Into<Party>, relation: Relation) -> &Self {
+        let source = source.into();
+        for party in self.parties() {
+            if party != source {
+                self.set(source, party, relation);
+            }
+        }
+        self
+    }
+
+    /// Player and all factions currently present in the database
+    pub fn parties(&self) -> Vec<Party> {
+        let mut parties = vec![Party::Player];
+        self.db
+            .faction_iter(|factions| parties.extend(factions.map(|f| Party::Faction(f.id))));
+        parties
+    }
+
+    /// Pairs of parties with differing relations towards each other, where
+    /// at least one direction was explicitly set
+    ///
+    /// Game has no notion of the player's relations, so the relations of
+    /// the player that were never set are implicit and don't disagree with
+    /// anything
+    ///
+    /// Each pair is reported once, as `(a, b, a -> b, b -> a)`
+    pub fn asymmetric(&self) -> Vec<(Party, Party, Relation, Relation)> {
+        let pairs: AHashSet<_> = self
+            .db
+            .extra_or_init::<RelationMatrix>()
+            .read()
+            .relations
+            .keys()
+            .copied()
+            .collect();
+
+        let mut seen = AHashSet::default();
+        let mut result = vec![];
+        for &(a, b) in &pairs {
+            if seen.contains(&(b, a)) || !seen.insert((a, b)) {
+                continue;
+            }
+            if b == Party::Player && !pairs.contains(&(b, a)) {
+                continue;
+            }
+            let forward = self.get(a, b);
+            let backward = self.get(b, a);
+            if forward != backward {
+                result.push((a, b, forward, backward));
+            }
+        }
+        result
+    }
+}
@@ -1,9 +1,267 @@
+use std::fmt::{Display, Formatter, Write as _};
+use std::path::Path;
+use std::str::FromStr;
+
+use ahash::AHashMap;
 use owo_colors::{AnsiColors, OwoColorize};
 
 use diagnostic::context::DiagnosticContext;
-use diagnostic::diagnostic::DiagnosticKind;
+use diagnostic::diagnostic::{Diagnostic, DiagnosticKind};
+use eh_schema::color::Color;
+use eh_schema::schema::{DatabaseItem, GalaxySettings, Technology};
+
+use crate::database::Database;
+use crate::economy::technology_tiers;
+
+/// Severity of the diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn of(diagnostic: &Diagnostic) -> Self {
+        if diagnostic.error {
+            Severity::Error
+        } else {
+            Severity::Warning
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "warning" => Ok(Severity::Warning),
+            "error" => Ok(Severity::Error),
+            _ => Err(format!(
+                "unknown severity `{s}`, expected `warning` or `error`"
+            )),
+        }
+    }
+}
+
+/// Amounts of the reported diagnostics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReportSummary {
+    pub errors: usize,
+    pub warnings: usize,
+    /// Amount of the entries with at least one reported diagnostic
+    pub entries: usize,
+}
+
+impl ReportSummary {
+    /// Returns whether any of the reported diagnostics is at least as severe
+    /// as the threshold
+    pub fn reached(&self, threshold: Severity) -> bool {
+        match threshold {
+            Severity::Warning => self.errors + self.warnings > 0,
+            Severity::Error => self.errors > 0,
+        }
+    }
+
+    /// Process exit code for the given severity threshold
+    pub fn exit_code(&self, threshold: Severity) -> i32 {
+        if self.reached(threshold) {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+/// Output of the diagnostics produced by [Database::save]
+pub trait Reporter {
+    /// Reports diagnostics of a single entry, usually an item file
+    fn report_entry(&mut self, entry: &str, origin: Option<&str>, diagnostics: &[&Diagnostic]);
+
+    /// Called once all entries were reported
+    fn finish(&mut self, summary: &ReportSummary) {
+        let _ = summary;
+    }
+}
+
+/// Colored terminal output, grouped by entry
+#[derive(Debug, Default)]
+pub struct PrettyReporter;
+
+impl Reporter for PrettyReporter {
+    fn report_entry(&mut self, entry: &str, origin: Option<&str>, diagnostics: &[&Diagnostic]) {
+        match origin {
+            Some(origin) => println!(
+                "\n{} {} {}:",
+                "Diagnostics for".bright_black(),
+                entry.bold(),
+                format!("(from {origin})").bright_black()
+            ),
+            None => println!("\n{} {}:", "Diagnostics for".bright_black(), entry.bold()),
+        }
+        for diagnostic in diagnostics {
+            let color = if diagnostic.error {
+                AnsiColors::Red
+            } else {
+                AnsiColors::Yellow
+            };
+            println!(
+                "{}: {}",
+                diagnostic.path.bold(),
+                diagnostic.kind.color(color)
+            );
+        }
+    }
+}
+
+/// Plain output with one line per diagnostic, suitable for grepping
+#[derive(Debug, Default)]
+pub struct CompactReporter;
+
+impl Reporter for CompactReporter {
+    fn report_entry(&mut self, entry: &str, _origin: Option<&str>, diagnostics: &[&Diagnostic]) {
+        for diagnostic in diagnostics {
+            println!(
+                "{entry}:{}: {}: {}",
+                diagnostic.path,
+                Severity::of(diagnostic),
+                diagnostic.kind
+            );
+        }
+    }
+
+    fn finish(&mut self, summary: &ReportSummary) {
+        print_summary(summary);
+    }
+}
+
+/// Machine-readable output, printed as a single JSON document once all
+/// entries are reported
+#[derive(Debug, Default)]
+pub struct JsonReporter {
+    diagnostics: Vec<serde_json::Value>,
+}
+
+impl Reporter for JsonReporter {
+    fn report_entry(&mut self, entry: &str, origin: Option<&str>, diagnostics: &[&Diagnostic]) {
+        self.diagnostics
+            .extend(diagnostics.iter().map(|diagnostic| {
+                serde_json::json!({
+                    "entry": entry,
+                    "origin": origin,
+                    "path": diagnostic.path.to_string(),
+                    "severity": Severity::of(diagnostic).to_string(),
+                    "message": diagnostic.kind.to_string(),
+                })
+            }));
+    }
+
+    fn finish(&mut self, summary: &ReportSummary) {
+        let report = serde_json::json!({
+            "errors": summary.errors,
+            "warnings": summary.warnings,
+            "diagnostics": std::mem::take(&mut self.diagnostics),
+        });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).expect("Report should be serializable")
+        );
+    }
+}
+
+/// Only prints the amounts of the reported diagnostics
+#[derive(Debug, Default)]
+pub struct QuietReporter;
+
+impl Reporter for QuietReporter {
+    fn report_entry(&mut self, _entry: &str, _origin: Option<&str>, _diagnostics: &[&Diagnostic]) {}
+
+    fn finish(&mut self, summary: &ReportSummary) {
+        print_summary(summary);
+    }
+}
+
+fn print_summary(summary: &ReportSummary) {
+    println!(
+        "{} errors, {} warnings in {} entries",
+        summary.errors, summary.warnings, summary.entries
+    );
+}
+
+/// Built-in reporters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// [PrettyReporter]
+    #[default]
+    Pretty,
+    /// [CompactReporter]
+    Compact,
+    /// [JsonReporter]
+    Json,
+    /// [QuietReporter]
+    Quiet,
+}
+
+impl ReportFormat {
+    pub fn reporter(self) -> Box<dyn Reporter> {
+        match self {
+            ReportFormat::Pretty => Box::new(PrettyReporter),
+            ReportFormat::Compact => Box::new(CompactReporter),
+            ReportFormat::Json => Box::new(JsonReporter::default()),
+            ReportFormat::Quiet => Box::new(QuietReporter),
+        }
+    }
+}
 
-pub fn report_diagnostics(ctx: DiagnosticContext) {
+impl Display for ReportFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportFormat::Pretty => write!(f, "pretty"),
+            ReportFormat::Compact => write!(f, "compact"),
+            ReportFormat::Json => write!(f, "json"),
+            ReportFormat::Quiet => write!(f, "quiet"),
+        }
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pretty" => Ok(ReportFormat::Pretty),
+            "compact" => Ok(ReportFormat::Compact),
+            "json" => Ok(ReportFormat::Json),
+            "quiet" => Ok(ReportFormat::Quiet),
+            _ => Err(format!(
+                "unknown report format `{s}`, expected one of `pretty`, `compact`, `json`, `quiet`"
+            )),
+        }
+    }
+}
+
+/// Reports diagnostics to the terminal with the [PrettyReporter]
+pub fn report_diagnostics(ctx: DiagnosticContext) -> ReportSummary {
+    report_diagnostics_with(ctx, &mut PrettyReporter)
+}
+
+/// Reports diagnostics with the given reporter, skipping the known noise
+/// coming from the built-in items
+pub fn report_diagnostics_with(
+    ctx: DiagnosticContext,
+    reporter: &mut dyn Reporter,
+) -> ReportSummary {
+    let origins = ctx.origins;
+    let mut summary = ReportSummary::default();
     for (entry, diagnostics) in ctx.diagnostics {
         let is_builtin = entry.starts_with("auto/") || entry.starts_with("eh/");
         let filtered: Vec<_> = diagnostics
@@ -30,7 +288,37 @@ pub fn report_diagnostics(ctx: DiagnosticContext) {
                         }
                     }
                     DiagnosticKind::ValueTooLarge { .. } => {}
-                    DiagnosticKind::LayoutNotSquare { .. } => {}
+                    DiagnosticKind::AsymmetricRelation { .. } => {}
+                    DiagnosticKind::SavegameIncompatible { .. } => {}
+                    DiagnosticKind::ModIdCollision { .. }
+                    | DiagnosticKind::ModVersionNotBumped { .. } => {}
+                    DiagnosticKind::Custom { .. } => {}
+                    DiagnosticKind::ItemCollision { .. }
+                    | DiagnosticKind::ItemCollisionResolved { .. } => {}
+                    DiagnosticKind::InvalidExpression { .. }
+                    | DiagnosticKind::UnknownExpressionVariable { .. }
+                    | DiagnosticKind::UnknownExpressionFunction { .. }
+                    | DiagnosticKind::InvalidArgumentCount { .. }
+                    | DiagnosticKind::UnsafeDivision { .. } => {}
+                    DiagnosticKind::ImageTooLarge { .. }
+                    | DiagnosticKind::ImageNotSquare { .. }
+                    | DiagnosticKind::UnsupportedImageColor { .. }
+                    | DiagnosticKind::UnknownImage { .. } => {}
+                    DiagnosticKind::ComponentNeverFits { .. }
+                    | DiagnosticKind::InvalidComponentPlacement { .. }
+                    | DiagnosticKind::BarrelOutOfRange { .. }
+                    | DiagnosticKind::WeaponClassMismatch { .. }
+                    | DiagnosticKind::InvalidColor { .. }
+                    | DiagnosticKind::UnsatisfiableRequirement
+                    | DiagnosticKind::EngineLimitExceeded { .. }
+                    | DiagnosticKind::UnusedId { .. }
+                    | DiagnosticKind::UnresolvedReference { .. }
+                    | DiagnosticKind::UnknownQuest { .. }
+                    | DiagnosticKind::UnknownQuestNode { .. } => {
+                        if is_builtin {
+                            return false;
+                        }
+                    }
                 }
                 true
             })
@@ -40,18 +328,346 @@ pub fn report_diagnostics(ctx: DiagnosticContext) {
             continue;
         }
 
-        println!("\n{} {}:", "Diagnostics for".bright_black(), entry.bold());
-        for diagnostic in filtered {
-            let color = if diagnostic.kind.is_error() {
-                AnsiColors::Red
-            } else {
-                AnsiColors::Yellow
-            };
-            println!(
-                "{}: {}",
-                diagnostic.path.bold(),
-                diagnostic.kind.color(color)
-            );
+        summary.entries += 1;
+        for diagnostic in &filtered {
+            match Severity::of(diagnostic) {
+                Severity::Warning => summary.warnings += 1,
+                Severity::Error => summary.errors += 1,
+            }
         }
+        reporter.report_entry(&entry, origins.get(&entry).map(|o| o.as_str()), &filtered);
+    }
+    reporter.finish(&summary);
+    summary
+}
+
+struct TechNode {
+    id: i32,
+    name: String,
+    kind: &'static str,
+    faction: Option<i32>,
+    price: i32,
+    tier: u32,
+    hidden: bool,
+    special: bool,
+    dependencies: Vec<i32>,
+}
+
+/// Exports the technology dependency graph for visual review
+///
+/// Output format is picked based on the file extension: `.dot` and `.gv`
+/// produce a Graphviz graph, anything else produces a self-contained HTML
+/// page with technologies laid out in tier columns. Technologies are colored
+/// by their faction, hidden technologies are drawn dashed
+pub fn export_tech_tree(db: &Database, path: impl AsRef<Path>) -> std::io::Result<()> {
+    let path = path.as_ref();
+    let inverse_ids = db.use_id_mappings(|ids| ids.get_inverse_ids());
+    let tech_names = inverse_ids.get(Technology::type_name());
+
+    let factions: AHashMap<i32, (String, String)> = db.faction_iter(|factions| {
+        factions
+            .map(|f| (f.id.0, (f.name.clone(), css_color(&f.color))))
+            .collect()
+    });
+    let components: AHashMap<i32, (String, Option<i32>)> = db.component_iter(|items| {
+        items
+            .map(|c| (c.id.0, (c.name.clone(), c.faction.map(|f| f.0))))
+            .collect()
+    });
+    let ships: AHashMap<i32, (String, Option<i32>)> = db.ship_iter(|items| {
+        items
+            .map(|s| (s.id.0, (s.name.clone(), s.faction.map(|f| f.0))))
+            .collect()
+    });
+    let satellites: AHashMap<i32, String> =
+        db.satellite_iter(|items| items.map(|s| (s.id.0, s.name.clone())).collect());
+    let tiers = technology_tiers(db);
+
+    let mut nodes: Vec<TechNode> = db.technology_iter(|techs| {
+        techs
+            .map(|tech| {
+                let tech = &*tech;
+                let (kind, item_name, faction) = match tech {
+                    Technology::Component(t) => {
+                        let (name, faction) =
+                            components.get(&t.item_id.0).cloned().unwrap_or_default();
+                        ("component", name, t.faction.map(|f| f.0).or(faction))
+                    }
+                    Technology::Ship(t) => {
+                        let (name, faction) = ships.get(&t.item_id.0).cloned().unwrap_or_default();
+                        ("ship", name, faction)
+                    }
+                    Technology::Satellite(t) => {
+                        let name = satellites.get(&t.item_id.0).cloned().unwrap_or_default();
+                        ("satellite", name, t.faction.map(|f| f.0))
+                    }
+                };
+                let id = tech.id().0;
+                let name = tech_names
+                    .and_then(|names| names.get(&id))
+                    .cloned()
+                    .or_else(|| (!item_name.is_empty()).then_some(item_name))
+                    .unwrap_or_else(|| id.to_string());
+                TechNode {
+                    id,
+                    name,
+                    kind,
+                    faction,
+                    price: *tech.price(),
+                    tier: tiers.get(&id).copied().unwrap_or_default(),
+                    hidden: *tech.hidden(),
+                    special: *tech.special(),
+                    dependencies: tech.dependencies().iter().map(|d| d.0).collect(),
+                }
+            })
+            .collect()
+    });
+    nodes.sort_by_key(|n| (n.tier, n.id));
+
+    let is_dot = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("dot") || ext.eq_ignore_ascii_case("gv"));
+    let data = if is_dot {
+        tech_tree_dot(&nodes, &factions)
+    } else {
+        tech_tree_html(&nodes, &factions)
+    };
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, data)
+}
+
+fn tech_tree_dot(nodes: &[TechNode], factions: &AHashMap<i32, (String, String)>) -> String {
+    let mut out = String::new();
+    writeln!(out, "digraph technologies {{").unwrap();
+    writeln!(out, "    rankdir=LR;").unwrap();
+    writeln!(
+        out,
+        "    node [shape=box, style=filled, fillcolor=\"#ffffff\"];"
+    )
+    .unwrap();
+    for node in nodes {
+        let color = node
+            .faction
+            .and_then(|f| factions.get(&f))
+            .map(|(_, color)| color.as_str())
+            .unwrap_or("#ffffff");
+        let style = if node.hidden {
+            "filled,dashed"
+        } else {
+            "filled"
+        };
+        let special = if node.special { " (special)" } else { "" };
+        writeln!(
+            out,
+            "    t{} [label=\"{}\\n{} | {} RP{}\", fillcolor=\"{}\", style=\"{}\"];",
+            node.id,
+            node.name.replace('"', "\\\""),
+            node.kind,
+            node.price,
+            special,
+            color,
+            style
+        )
+        .unwrap();
+    }
+    for node in nodes {
+        for dep in &node.dependencies {
+            writeln!(out, "    t{} -> t{};", dep, node.id).unwrap();
+        }
+    }
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+fn tech_tree_html(nodes: &[TechNode], factions: &AHashMap<i32, (String, String)>) -> String {
+    let names: AHashMap<i32, &str> = nodes.iter().map(|n| (n.id, n.name.as_str())).collect();
+    let mut out = String::new();
+    out.push_str(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>Technologies</title><style>\n\
+         body { font-family: sans-serif; background: #202020; color: #202020; }\n\
+         .tiers { display: flex; gap: 16px; align-items: flex-start; }\n\
+         .tier { display: flex; flex-direction: column; gap: 8px; min-width: 200px; }\n\
+         .tier h2 { color: #e0e0e0; margin: 0; }\n\
+         .tech { border: 2px solid #000; border-radius: 4px; padding: 4px; }\n\
+         .tech.hidden { border-style: dashed; opacity: 0.7; }\n\
+         .deps { font-size: 0.8em; }\n\
+         </style></head><body>\n<div class=\"tiers\">\n",
+    );
+    for nodes in nodes.chunk_by(|a, b| a.tier == b.tier) {
+        writeln!(out, "<div class=\"tier\"><h2>Tier {}</h2>", nodes[0].tier).unwrap();
+        for node in nodes {
+            let (faction, color) = node
+                .faction
+                .and_then(|f| factions.get(&f))
+                .map(|(name, color)| (name.as_str(), color.as_str()))
+                .unwrap_or(("", "#ffffff"));
+            let deps = node
+                .dependencies
+                .iter()
+                .map(|d| html_escape(names.get(d).copied().unwrap_or("?")))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                out,
+                "<div class=\"tech{}\" id=\"t{}\" style=\"background: {}\"><b>{}</b><br>{} | {} RP{}<br>{}<div class=\"deps\">{}</div></div>",
+                if node.hidden { " hidden" } else { "" },
+                node.id,
+                color,
+                html_escape(&node.name),
+                node.kind,
+                node.price,
+                if node.special { " | special" } else { "" },
+                html_escape(faction),
+                deps
+            )
+            .unwrap();
+        }
+        out.push_str("</div>\n");
+    }
+    out.push_str("</div>\n</body></html>\n");
+    out
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Converts the game `#RRGGBB[AA]` color into an opaque CSS color
+fn css_color(color: &str) -> String {
+    // Slicing by bytes would panic inside of a multibyte character
+    match color.trim_start_matches('#').get(..6) {
+        Some(hex) if hex.chars().all(|c| c.is_ascii_hexdigit()) => format!("#{hex}"),
+        _ => "#ffffff".to_string(),
+    }
+}
+
+/// Size of the galaxy preview, in pixels
+const PREVIEW_SIZE: u32 = 512;
+const PREVIEW_BACKGROUND: Color = Color::rgb(0x10, 0x10, 0x18);
+
+/// Faction territory drawn by [render_galaxy_preview], in star distances
+struct Territory {
+    color: Color,
+    home: (f32, f32),
+    wandering: Option<(f32, f32)>,
+}
+
+/// Renders the PNG preview of the faction territories, for a quick look at
+/// the galaxy shape without starting the game
+///
+/// Galaxy is drawn around the starting star in the center. Each faction
+/// with territories is a ring of its color spanning the distances of its
+/// home stars, with the range of its wandering ships outlined. Faint circles
+/// mark every 50 stars of distance, and the dashed circle marks the
+/// minimal enemy spawn distance if it's a constant. Hidden factions are
+/// skipped
+pub fn render_galaxy_preview(db: &Database, path: impl AsRef<Path>) -> std::io::Result<()> {
+    let path = path.as_ref();
+    let territories: Vec<Territory> = db.faction_iter(|factions| {
+        factions
+            .filter(|f| !f.hidden && !f.no_territories)
+            .map(|f| {
+                let home_max = f.home_star_distance_max.max(f.home_star_distance);
+                let wandering_max = f
+                    .wandering_ships_distance_max
+                    .max(f.wandering_ships_distance);
+                Territory {
+                    color: Color::parse(&f.color).unwrap_or(Color::WHITE),
+                    home: (f.home_star_distance as f32, home_max as f32),
+                    wandering: (!f.no_wandering_ships && wandering_max > 0)
+                        .then_some((f.wandering_ships_distance as f32, wandering_max as f32)),
+                }
+            })
+            .collect()
+    });
+    let spawn_distance = db.get_singleton::<GalaxySettings>().and_then(|s| {
+        s.read()
+            .ship_min_spawn_distance
+            .as_str()
+            .trim()
+            .parse::<f32>()
+            .ok()
+    });
+
+    let max_distance = territories
+        .iter()
+        .flat_map(|t| [t.home.1, t.wandering.map_or(0.0, |w| w.1)])
+        .chain(spawn_distance)
+        .fold(1.0f32, f32::max);
+    let center = PREVIEW_SIZE as f32 / 2.0;
+    // Pixels per star of distance
+    let scale = (center - 8.0) / max_distance;
+    let near = |distance: f32, radius: f32| ((distance - radius) * scale).abs() < 0.75;
+
+    let image = image::RgbImage::from_fn(PREVIEW_SIZE, PREVIEW_SIZE, |x, y| {
+        let (dx, dy) = (x as f32 + 0.5 - center, y as f32 + 0.5 - center);
+        let distance = dx.hypot(dy) / scale;
+        let mut pixel = [
+            PREVIEW_BACKGROUND.r,
+            PREVIEW_BACKGROUND.g,
+            PREVIEW_BACKGROUND.b,
+        ];
+        let mut blend = |color: Color, alpha: f32| {
+            for (channel, value) in pixel.iter_mut().zip([color.r, color.g, color.b]) {
+                *channel = (*channel as f32 * (1.0 - alpha) + value as f32 * alpha) as u8;
+            }
+        };
+
+        if (1..)
+            .map(|i| i as f32 * 50.0)
+            .take_while(|r| *r <= max_distance)
+            .any(|r| near(distance, r))
+        {
+            blend(Color::GRAY, 0.25);
+        }
+        for territory in &territories {
+            if (territory.home.0..=territory.home.1).contains(&distance) {
+                blend(territory.color, 0.35);
+            }
+            if let Some((min, max)) = territory.wandering {
+                if near(distance, min) || near(distance, max) {
+                    blend(territory.color, 0.8);
+                }
+            }
+        }
+        if let Some(radius) = spawn_distance {
+            let dashed = (dy.atan2(dx).to_degrees() / 5.0).rem_euclid(2.0) < 1.0;
+            if dashed && near(distance, radius) {
+                blend(Color::WHITE, 0.6);
+            }
+        }
+        if dx.hypot(dy) <= 3.0 {
+            blend(Color::WHITE, 1.0);
+        }
+        image::Rgb(pixel)
+    });
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    image
+        .save_with_format(path, image::ImageFormat::Png)
+        .map_err(std::io::Error::other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::css_color;
+
+    #[test]
+    fn css_colors() {
+        assert_eq!(css_color("#A0B1C2"), "#A0B1C2");
+        assert_eq!(css_color("#A0B1C2FF"), "#A0B1C2");
+        assert_eq!(css_color("A0B1C2"), "#A0B1C2");
+        assert_eq!(css_color("#A0B1"), "#ffffff");
+        assert_eq!(css_color("#red"), "#ffffff");
+        assert_eq!(css_color("#ÿÿÿÿ"), "#ffffff");
+        assert_eq!(css_color("#A0B1Cÿ"), "#ffffff");
     }
 }
@@ -0,0 +1,238 @@
+//! Bulk setup of the faction starbases, see
+//! [DatabaseHolder::populate_starbases]
+
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use thiserror::Error;
+
+use eh_schema::schema::{Faction, FactionId, Ship, ShipBuild, ShipBuildId, ShipType};
+
+use crate::database::DatabaseHolder;
+
+/// Starbase setup of a single faction
+///
+/// Game picks the starbase, its defense fleet and the planet occupants of
+/// the faction from the ship builds assigned to it, so the configured
+/// builds are assigned to the faction
+#[derive(Debug, Clone, Default)]
+pub struct StarbaseConfig {
+    /// Build of the starbase itself, must use a starbase hull
+    pub starbase: Option<ShipBuildId>,
+    /// Builds defending the starbase and occupying the planets, made
+    /// available for the enemies. Starbase hulls are not allowed
+    pub defenders: Vec<ShipBuildId>,
+    /// Removes other builds of the faction from the enemy pool, so only the
+    /// configured ones are used
+    pub exclusive: bool,
+    /// Whether the faction holds territories with starbases that can be
+    /// captured, leaves the faction as is if not set
+    pub territories: Option<bool>,
+}
+
+impl StarbaseConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_starbase(mut self, build: impl Into<ShipBuildId>) -> Self {
+        self.starbase = Some(build.into());
+        self
+    }
+
+    pub fn with_defender(mut self, build: impl Into<ShipBuildId>) -> Self {
+        self.defenders.push(build.into());
+        self
+    }
+
+    pub fn with_defenders(mut self, builds: impl IntoIterator<Item = ShipBuildId>) -> Self {
+        self.defenders.extend(builds);
+        self
+    }
+
+    pub fn with_exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
+    pub fn with_territories(mut self, territories: bool) -> Self {
+        self.territories = Some(territories);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Error)]
+pub enum StarbaseError {
+    #[error("Ship build {} configured for faction {} does not exist", .build.0, .faction.0)]
+    MissingBuild {
+        faction: FactionId,
+        build: ShipBuildId,
+    },
+    #[error("Ship of the build {} configured for faction {} does not exist", .build.0, .faction.0)]
+    MissingShip {
+        faction: FactionId,
+        build: ShipBuildId,
+    },
+    #[error("Starbase build {} of faction {} doesn't use a starbase hull", .build.0, .faction.0)]
+    NotStarbase {
+        faction: FactionId,
+        build: ShipBuildId,
+    },
+    #[error("Defender build {} of faction {} uses a starbase hull", .build.0, .faction.0)]
+    StarbaseDefender {
+        faction: FactionId,
+        build: ShipBuildId,
+    },
+    #[error("Ship build {} is configured for both faction {} and faction {}", .build.0, .first.0, .second.0)]
+    SharedBuild {
+        build: ShipBuildId,
+        first: FactionId,
+        second: FactionId,
+    },
+}
+
+impl DatabaseHolder {
+    /// Configures starbases of all factions for which `config` returns a
+    /// [StarbaseConfig]
+    ///
+    /// All configurations are validated before any item is changed, so the
+    /// database is left as is if any of the referenced builds is missing,
+    /// has the wrong hull type, or is configured for multiple factions
+    pub fn populate_starbases(
+        self: &Arc<Self>,
+        mut config: impl FnMut(&Faction) -> Option<StarbaseConfig>,
+    ) -> Result<(), Vec<StarbaseError>> {
+        let configs: Vec<(FactionId, StarbaseConfig)> = self.faction_iter(|factions| {
+            factions
+                .filter_map(|faction| Some((faction.id, config(&faction)?)))
+                .collect()
+        });
+
+        let mut errors = vec![];
+        let mut owners = AHashMap::<ShipBuildId, FactionId>::default();
+        for (faction, config) in &configs {
+            let builds = config
+                .starbase
+                .iter()
+                .map(|build| (*build, true))
+                .chain(config.defenders.iter().map(|build| (*build, false)));
+            for (build, starbase) in builds {
+                let faction = *faction;
+                match owners.insert(build, faction) {
+                    Some(first) if first != faction => errors.push(StarbaseError::SharedBuild {
+                        build,
+                        first,
+                        second: faction,
+                    }),
+                    _ => {}
+                }
+                let Some(ship) = self.with_item::<ShipBuild, _>(build, |b| b.ship_id) else {
+                    errors.push(StarbaseError::MissingBuild { faction, build });
+                    continue;
+                };
+                let Some(ship_type) = self.with_item::<Ship, _>(ship, |s| s.ship_type) else {
+                    errors.push(StarbaseError::MissingShip { faction, build });
+                    continue;
+                };
+                match (starbase, ship_type == ShipType::Starbase) {
+                    (true, false) => errors.push(StarbaseError::NotStarbase { faction, build }),
+                    (false, true) => {
+                        errors.push(StarbaseError::StarbaseDefender { faction, build })
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let exclusive = configs
+            .iter()
+            .filter(|(_, config)| config.exclusive)
+            .map(|(faction, _)| *faction)
+            .collect::<Vec<_>>();
+        if !exclusive.is_empty() {
+            self.ship_build_iter_mut(|builds| {
+                for mut build in builds {
+                    let owned_by_exclusive = build
+                        .build_faction
+                        .is_some_and(|faction| exclusive.contains(&faction));
+                    if owned_by_exclusive && !owners.contains_key(&build.id) {
+                        build.available_for_enemy = false;
+                    }
+                }
+            });
+        }
+
+        for (faction, config) in configs {
+            if let Some(territories) = config.territories {
+                self.with_item_mut::<Faction, _>(faction, |f| f.no_territories = !territories);
+            }
+            if let Some(starbase) = config.starbase {
+                self.with_item_mut::<ShipBuild, _>(starbase, |b| {
+                    b.build_faction = Some(faction);
+                });
+            }
+            for defender in config.defenders {
+                self.with_item_mut::<ShipBuild, _>(defender, |b| {
+                    b.build_faction = Some(faction);
+                    b.available_for_enemy = true;
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eh_schema::schema::{Faction, Ship, ShipBuild, ShipType};
+
+    use super::{StarbaseConfig, StarbaseError};
+    use crate::database::{DatabaseHolder, Remember};
+
+    #[test]
+    fn populate_starbases() {
+        let db = DatabaseHolder::ephemeral();
+        let faction = Faction::new(db.new_id("test:faction")).remember(&db).id;
+        let hull = Ship::new(db.new_id("test:hull")).remember(&db).id;
+        let base = Ship::new(db.new_id("test:base"))
+            .with_ship_type(ShipType::Starbase)
+            .remember(&db)
+            .id;
+        let fighter = ShipBuild::new(db.new_id("test:fighter"), hull)
+            .remember(&db)
+            .id;
+        let old = ShipBuild::new(db.new_id("test:old"), hull)
+            .with_build_faction(faction)
+            .with_available_for_enemy(true)
+            .remember(&db)
+            .id;
+        let starbase = ShipBuild::new(db.new_id("test:starbase"), base)
+            .remember(&db)
+            .id;
+
+        let errors = db
+            .populate_starbases(|_| Some(StarbaseConfig::new().with_starbase(fighter)))
+            .unwrap_err();
+        assert!(matches!(errors[..], [StarbaseError::NotStarbase { .. }]));
+
+        db.populate_starbases(|_| {
+            Some(
+                StarbaseConfig::new()
+                    .with_starbase(starbase)
+                    .with_defender(fighter)
+                    .with_exclusive(true),
+            )
+        })
+        .unwrap();
+        let build = |id| {
+            db.with_item::<ShipBuild, _>(id, |b| (b.build_faction, b.available_for_enemy))
+                .unwrap()
+        };
+        assert_eq!(build(fighter), (Some(faction), true));
+        assert_eq!(build(starbase).0, Some(faction));
+        assert_eq!(build(old), (Some(faction), false));
+    }
+}
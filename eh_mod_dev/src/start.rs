@@ -0,0 +1,167 @@
+use tracing::error_span;
+
+use eh_schema::schema::{
+    GalaxySettings, Loot, LootContent, LootId, Quest, QuestId, ShipBuild, ShipBuildId,
+    StartCondition,
+};
+
+use crate::database::{Database, DatabaseIdLike};
+
+/// String ID of the loot given to the player on the new game
+pub const STARTING_INVENTORY: &str = "eh:starting_inventory";
+/// String ID of the vanilla tutorial quest
+pub const TUTORIAL_QUEST: &str = "eh:tutorial";
+
+#[derive(Debug)]
+enum Inventory {
+    Keep,
+    Loot(LootId),
+    Content(LootContent),
+}
+
+/// Builder for the new game starting conditions
+///
+/// Nothing is written into the database until [StartingConditions::apply]
+/// is called, at which point all referenced items are checked for presence
+#[derive(Debug)]
+pub struct StartingConditions {
+    db: Database,
+    ship_builds: Option<Vec<ShipBuildId>>,
+    inventory: Inventory,
+    quests: Vec<QuestId>,
+    replace_quests: bool,
+    skip_tutorial: bool,
+}
+
+impl StartingConditions {
+    pub fn new(db: &Database) -> Self {
+        Self {
+            db: db.clone(),
+            ship_builds: None,
+            inventory: Inventory::Keep,
+            quests: vec![],
+            replace_quests: false,
+            skip_tutorial: false,
+        }
+    }
+
+    /// Adds a ship build to the list of builds the player can start with
+    ///
+    /// The first call replaces the existing list of starting builds
+    pub fn ship_build(mut self, build: impl DatabaseIdLike<ShipBuild>) -> Self {
+        let build = self.db.id(build);
+        self.ship_builds.get_or_insert_with(Vec::new).push(build);
+        self
+    }
+
+    /// Uses an existing loot as the starting inventory
+    pub fn inventory_loot(mut self, loot: impl DatabaseIdLike<Loot>) -> Self {
+        self.inventory = Inventory::Loot(self.db.id(loot));
+        self
+    }
+
+    /// Sets the content of the starting inventory loot
+    ///
+    /// Content is written into the [STARTING_INVENTORY] loot, which is created
+    /// if not present
+    pub fn inventory(mut self, content: impl Into<LootContent>) -> Self {
+        self.inventory = Inventory::Content(content.into());
+        self
+    }
+
+    /// Makes the quest start on the new game
+    pub fn quest(mut self, quest: impl DatabaseIdLike<Quest>) -> Self {
+        self.quests.push(self.db.id(quest));
+        self
+    }
+
+    /// Prevents all other quests from starting on the new game, making only
+    /// quests added via [StartingConditions::quest] start
+    pub fn only_listed_quests(mut self) -> Self {
+        self.replace_quests = true;
+        self
+    }
+
+    /// Prevents the vanilla tutorial from starting
+    pub fn skip_tutorial(mut self) -> Self {
+        self.skip_tutorial = true;
+        self
+    }
+
+    /// Writes the starting conditions into the database
+    ///
+    /// # Panics
+    /// Will panic if any of the referenced items is missing from the database,
+    /// or if [GalaxySettings] are not present
+    pub fn apply(self) {
+        let _guard = error_span!("Applying starting conditions").entered();
+        let db = &self.db;
+
+        if let Some(builds) = &self.ship_builds {
+            for build in builds {
+                let _guard = error_span!("Checking starting ship build", id = build.0).entered();
+                db.get_item::<ShipBuild>(*build)
+                    .expect("Starting ship build should be present in the database");
+            }
+        }
+
+        for quest in &self.quests {
+            let _guard = error_span!("Checking starting quest", id = quest.0).entered();
+            db.get_item::<Quest>(*quest)
+                .expect("Starting quest should be present in the database");
+        }
+
+        let inventory = match self.inventory {
+            Inventory::Keep => None,
+            Inventory::Loot(loot) => {
+                let _guard = error_span!("Checking starting inventory", id = loot.0).entered();
+                db.get_item::<Loot>(loot)
+                    .expect("Starting inventory loot should be present in the database");
+                Some(loot)
+            }
+            Inventory::Content(content) => {
+                let loot = if db.is_id_used::<Loot>(STARTING_INVENTORY) {
+                    db.id(STARTING_INVENTORY)
+                } else {
+                    db.new_id(STARTING_INVENTORY)
+                };
+                match db.get_item::<Loot>(loot) {
+                    Some(item) => {
+                        item.edit(|l| l.loot = content);
+                    }
+                    None => {
+                        db.new_loot(loot).set_loot(content);
+                    }
+                }
+                Some(loot)
+            }
+        };
+
+        db.get_singleton::<GalaxySettings>()
+            .expect("GalaxySettings should be present in the database")
+            .edit(|s| {
+                if let Some(builds) = self.ship_builds {
+                    s.starting_ship_builds = builds;
+                }
+                if let Some(loot) = inventory {
+                    s.starting_inventory = Some(loot);
+                }
+            });
+
+        let tutorial = (self.skip_tutorial && db.is_id_used::<Quest>(TUTORIAL_QUEST))
+            .then(|| db.id::<Quest>(TUTORIAL_QUEST));
+
+        db.quest_iter_mut(|quests| {
+            for mut quest in quests {
+                if self.quests.contains(&quest.id) {
+                    quest.start_condition = StartCondition::GameStart;
+                } else if Some(quest.id) == tutorial
+                    || (self.replace_quests
+                        && matches!(quest.start_condition, StartCondition::GameStart))
+                {
+                    quest.start_condition = StartCondition::Manual;
+                }
+            }
+        });
+    }
+}
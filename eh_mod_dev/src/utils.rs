@@ -1,6 +1,6 @@
 use flate2::Compression;
 use sha2::Digest;
-use std::io::Write;
+use std::io::{Read, Write};
 
 pub(crate) fn compress(data: &[u8], compression: Compression) -> Vec<u8> {
     let mut flate2_data = vec![];
@@ -10,15 +10,53 @@ pub(crate) fn compress(data: &[u8], compression: Compression) -> Vec<u8> {
     flate2_data
 }
 
-pub(crate) fn decompress(data: &[u8]) -> Vec<u8> {
-    let mut deflated = vec![];
-    let mut writer = flate2::write::ZlibDecoder::new(&mut deflated);
+/// Decompresses the output of [compress]
+///
+/// Stream written by [compress] ends with a sync flush instead of the final
+/// block, keeping the mod files byte-identical to the ones built before, so
+/// it is read to the end of the input rather than to the end of the stream
+pub(crate) fn decompress(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut decoder = flate2::Decompress::new(true);
+    let mut deflated = Vec::with_capacity(data.len() * 4);
+    loop {
+        if deflated.len() == deflated.capacity() {
+            deflated.reserve(data.len().max(64));
+        }
+        let (read, written) = (decoder.total_in(), deflated.len());
+        let status = decoder
+            .decompress_vec(
+                &data[read as usize..],
+                &mut deflated,
+                flate2::FlushDecompress::Sync,
+            )
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        let input_left = (decoder.total_in() as usize) < data.len();
+        let output_full = deflated.len() == deflated.capacity();
+        if status == flate2::Status::StreamEnd || (!input_left && !output_full) {
+            return Ok(deflated);
+        }
+        if decoder.total_in() == read && deflated.len() == written && !output_full {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "truncated zlib stream",
+            ));
+        }
+    }
+}
+
+pub(crate) fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut writer = flate2::write::GzEncoder::new(vec![], Compression::best());
     writer.write_all(data).unwrap();
-    writer.flush().unwrap();
-    drop(writer);
-    deflated
+    writer.finish().unwrap()
+}
+
+pub(crate) fn gunzip(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut out = vec![];
+    flate2::read::GzDecoder::new(data).read_to_end(&mut out)?;
+    Ok(out)
 }
 
+#[allow(dead_code)]
 pub(crate) fn sha256(data: &[u8]) -> Vec<u8> {
     let mut hasher = sha2::Sha256::new();
     hasher.update(data);
@@ -1,4 +1,25 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+use ahash::{AHashMap, AHashSet};
+use image::ColorType;
+
+use diagnostic::context::{DiagnosticContext, DiagnosticContextRef};
+use diagnostic::diagnostic::DiagnosticKind;
 use eh_schema::apply_all_settings;
+use eh_schema::schema::{
+    Ammunition, AssetKind, Barrel, BulletController, CellType, CombatRules, Component,
+    DatabaseItem, DatabaseSettings, DynamicFields, Faction, FieldValue, GalaxySettings,
+    InstalledComponent, Item, Loot, LootContent, Node, Quest, Requirement, SatelliteBuild,
+    ShipBuild, VisitAssets, VisitIds,
+};
+
+use crate::compatibility::{CompatibilityGuard, CompatibilityPolicy};
+use crate::database::{item_file_name, Database, DatabaseHolder};
+use crate::expressions::{function_arity, BinaryOp, Expr, Expression};
+use crate::layout::{cell_accepts, parse_cell_type, Layout};
+use crate::registry::{KnownMod, RegistryGuard};
+use crate::relations::Party;
 
 macro_rules! all_settings_impls {
     ($($name:ident : $ty:ty),*) => {
@@ -26,3 +47,970 @@ macro_rules! all_settings_impls {
 }
 
 apply_all_settings!(all_settings_impls);
+
+/// Reports components that can't be physically placed into any of the ship
+/// or satellite layouts they are installed on in builds
+///
+/// Only the shape of the component and its cell type are checked, actual
+/// component placement in the build is not taken into account. Ship builds
+/// with extended layout are skipped, since expanded cells are not a part of
+/// the ship layout data
+pub fn validate_component_fit(db: &Database, ctx: &mut DiagnosticContext) {
+    let ship_layouts: AHashMap<i32, Layout> =
+        db.ship_iter(|ships| ships.map(|ship| (ship.id.0, ship.layout.clone())).collect());
+    let satellite_layouts: AHashMap<i32, Layout> = db.satellite_iter(|satellites| {
+        satellites
+            .map(|satellite| (satellite.id.0, satellite.layout.clone()))
+            .collect()
+    });
+
+    let mut hulls: AHashMap<i32, Vec<&Layout>> = Default::default();
+    let mut unverifiable: AHashSet<i32> = Default::default();
+
+    db.ship_build_iter(|builds| {
+        for build in builds {
+            let Some(layout) = ship_layouts.get(&build.ship_id.0) else {
+                continue;
+            };
+            for component in &build.components {
+                if build.extended_layout {
+                    unverifiable.insert(component.component_id.0);
+                } else {
+                    hulls
+                        .entry(component.component_id.0)
+                        .or_default()
+                        .push(layout);
+                }
+            }
+        }
+    });
+
+    db.satellite_build_iter(|builds| {
+        for build in builds {
+            let Some(layout) = satellite_layouts.get(&build.satellite_id.0) else {
+                continue;
+            };
+            for component in &build.components {
+                hulls
+                    .entry(component.component_id.0)
+                    .or_default()
+                    .push(layout);
+            }
+        }
+    });
+
+    let inverse_ids = db.use_id_mappings(|ids| ids.get_inverse_ids());
+
+    db.component_iter(|components| {
+        for component in components {
+            let id = component.id.0;
+            if unverifiable.contains(&id) {
+                continue;
+            }
+            let Some(hulls) = hulls.get_mut(&id) else {
+                continue;
+            };
+            let layout = &component.layout;
+            let cell_type = component_cell_type(&component);
+
+            hulls.sort_by_key(|l| *l as *const Layout);
+            hulls.dedup_by_key(|l| *l as *const Layout);
+
+            let fits = hulls.iter().any(|hull| {
+                hull.find_fit(layout, |c| cell_accepts(c, cell_type))
+                    .is_some()
+            });
+
+            if !fits {
+                let file_name = item_file_name(&inverse_ids, Component::type_name(), Some(id));
+                ctx.enter(file_name)
+                    .enter_field("layout")
+                    .emit(DiagnosticKind::component_never_fits(hulls.len()));
+            }
+        }
+    });
+}
+
+struct HullInfo {
+    layout: Layout,
+    weapon_classes: Vec<String>,
+}
+
+struct InstalledInfo {
+    layout: Layout,
+    cell_type: CellType,
+    weapon_slot_type: Option<String>,
+}
+
+/// Checks components installed on ship and satellite builds for slot and
+/// weapon class compatibility
+///
+/// Each installed component must only occupy hull cells that accept its
+/// cell type, and weapons must be attached to an existing barrel that accepts
+/// the weapon slot type. Ship builds with extended layout are only checked
+/// for weapon compatibility
+pub fn validate_loadouts(db: &Database, ctx: &mut DiagnosticContext) {
+    fn hull(layout: &Layout, barrels: &[Barrel]) -> HullInfo {
+        HullInfo {
+            layout: layout.clone(),
+            weapon_classes: barrels.iter().map(|b| b.weapon_class.clone()).collect(),
+        }
+    }
+
+    let ships: AHashMap<i32, HullInfo> = db.ship_iter(|ships| {
+        ships
+            .map(|ship| (ship.id.0, hull(&ship.layout, &ship.barrels)))
+            .collect()
+    });
+    let satellites: AHashMap<i32, HullInfo> = db.satellite_iter(|satellites| {
+        satellites
+            .map(|satellite| (satellite.id.0, hull(&satellite.layout, &satellite.barrels)))
+            .collect()
+    });
+    let components: AHashMap<i32, InstalledInfo> = db.component_iter(|components| {
+        components
+            .map(|component| {
+                let info = InstalledInfo {
+                    layout: component.layout.clone(),
+                    cell_type: component_cell_type(&component),
+                    weapon_slot_type: component
+                        .weapon_id
+                        .map(|_| component.weapon_slot_type.clone()),
+                };
+                (component.id.0, info)
+            })
+            .collect()
+    });
+
+    let inverse_ids = db.use_id_mappings(|ids| ids.get_inverse_ids());
+
+    let mut check = |type_name: &str,
+                     build_id: i32,
+                     hull: &HullInfo,
+                     check_placement: bool,
+                     installed: &[InstalledComponent]| {
+        let mut ctx = ctx.enter(item_file_name(&inverse_ids, type_name, Some(build_id)));
+        let mut ctx = ctx.enter_field("components");
+        for (i, installed) in installed.iter().enumerate() {
+            let Some(component) = components.get(&installed.component_id.0) else {
+                continue;
+            };
+            let mut ctx = ctx.enter_index(i);
+
+            if check_placement {
+                let hull_layout = &hull.layout;
+                let fits = component.layout.filled_cells().all(|(x, y, _)| {
+                    let x = installed.x + x as i32;
+                    let y = installed.y + y as i32;
+                    x >= 0
+                        && y >= 0
+                        && hull_layout
+                            .get(x as usize, y as usize)
+                            .is_some_and(|cell| cell_accepts(cell, component.cell_type))
+                });
+                if !fits {
+                    ctx.emit(DiagnosticKind::invalid_component_placement(
+                        installed.x,
+                        installed.y,
+                    ));
+                }
+            }
+
+            let Some(slot) = &component.weapon_slot_type else {
+                continue;
+            };
+            // Negative barrel ID means that barrel is assigned automatically
+            if installed.barrel_id < 0 {
+                continue;
+            }
+            let Some(class) = hull.weapon_classes.get(installed.barrel_id as usize) else {
+                ctx.enter_field("barrel_id")
+                    .emit(DiagnosticKind::barrel_out_of_range(
+                        installed.barrel_id,
+                        hull.weapon_classes.len(),
+                    ));
+                continue;
+            };
+            if !slot.is_empty() && !class.is_empty() && !class.contains(slot.as_str()) {
+                ctx.enter_field("barrel_id")
+                    .emit(DiagnosticKind::weapon_class_mismatch(slot, class));
+            }
+        }
+    };
+
+    db.ship_build_iter(|builds| {
+        for build in builds {
+            let Some(hull) = ships.get(&build.ship_id.0) else {
+                continue;
+            };
+            check(
+                ShipBuild::type_name(),
+                build.id.0,
+                hull,
+                !build.extended_layout,
+                &build.components,
+            );
+        }
+    });
+
+    db.satellite_build_iter(|builds| {
+        for build in builds {
+            let Some(hull) = satellites.get(&build.satellite_id.0) else {
+                continue;
+            };
+            check(
+                SatelliteBuild::type_name(),
+                build.id.0,
+                hull,
+                true,
+                &build.components,
+            );
+        }
+    });
+}
+
+/// Reports pairs of parties with explicitly defined relations that differ
+/// between the two directions
+///
+/// Diagnostic is emitted on the faction item of the first party of the pair,
+/// or on the second party if the first is the player
+pub fn validate_relations(db: &Database, ctx: &mut DiagnosticContext) {
+    let asymmetric = db.relations().asymmetric();
+    if asymmetric.is_empty() {
+        return;
+    }
+    let inverse_ids = db.use_id_mappings(|ids| ids.get_inverse_ids());
+    for (a, b, forward, backward) in asymmetric {
+        let (faction, other, forward, backward) = match (a, b) {
+            (Party::Faction(faction), other) => (faction, other, forward, backward),
+            (other, Party::Faction(faction)) => (faction, other, backward, forward),
+            (Party::Player, Party::Player) => continue,
+        };
+        ctx.enter(item_file_name(
+            &inverse_ids,
+            Faction::type_name(),
+            Some(faction.0),
+        ))
+        .emit(DiagnosticKind::asymmetric_relation(
+            other.to_string(),
+            forward.to_string(),
+            backward.to_string(),
+        ));
+    }
+}
+
+/// Variables available in the parametric bullet controller expressions
+pub const PARAMETRIC_CONTROLLER_VARIABLES: &[&str] = &["t"];
+/// Variables available in the [GalaxySettings::enemy_level] expression
+pub const ENEMY_LEVEL_VARIABLES: &[&str] = &["distance", "MaxEnemyShipsLevel"];
+/// Variables available in the [CombatRules] expressions
+pub const COMBAT_RULES_VARIABLES: &[&str] = &["level"];
+
+/// Parses expression-typed fields, reporting syntax errors, unknown
+/// variables and functions, and divisions by values that can reach zero
+///
+/// Empty expressions are skipped, since the game treats them as defaults
+pub fn validate_expressions(db: &Database, ctx: &mut DiagnosticContext) {
+    let inverse_ids = db.use_id_mappings(|ids| ids.get_inverse_ids());
+
+    db.ammunition_iter(|ammo| {
+        for ammo in ammo {
+            let BulletController::Parametric(controller) = &ammo.controller else {
+                continue;
+            };
+            let mut ctx = ctx.enter(item_file_name(
+                &inverse_ids,
+                Ammunition::type_name(),
+                Some(ammo.id.0),
+            ));
+            let mut ctx = ctx.enter_field("controller");
+            let mut ctx = ctx.enter_variant("Parametric");
+            for (field, expr) in [
+                ("x", &controller.x),
+                ("y", &controller.y),
+                ("rotation", &controller.rotation),
+                ("size", &controller.size),
+                ("length", &controller.length),
+            ] {
+                check_expression(
+                    ctx.enter_field(field),
+                    expr,
+                    PARAMETRIC_CONTROLLER_VARIABLES,
+                );
+            }
+        }
+    });
+
+    if let Some(settings) = db.get_singleton::<GalaxySettings>() {
+        let settings = settings.read();
+        let mut ctx = ctx.enter(item_file_name(
+            &inverse_ids,
+            GalaxySettings::type_name(),
+            None,
+        ));
+        check_expression(
+            ctx.enter_field("enemy_level"),
+            &settings.enemy_level,
+            ENEMY_LEVEL_VARIABLES,
+        );
+        let size_variables = eh_schema::schema::expressions::SIZE_CLASS_TO_INT.variables();
+        check_expression(
+            ctx.enter_field("ship_min_spawn_distance"),
+            &settings.ship_min_spawn_distance,
+            &size_variables,
+        );
+    }
+
+    db.combat_rules_iter(|rules| {
+        for rules in rules {
+            let mut ctx = ctx.enter(item_file_name(
+                &inverse_ids,
+                CombatRules::type_name(),
+                Some(rules.id.0),
+            ));
+            for (field, expr) in [
+                ("initial_enemy_ships", &rules.initial_enemy_ships),
+                ("max_enemy_ships", &rules.max_enemy_ships),
+                ("time_limit", &rules.time_limit),
+            ] {
+                check_expression(ctx.enter_field(field), expr, COMBAT_RULES_VARIABLES);
+            }
+        }
+    });
+}
+
+fn check_expression(mut ctx: DiagnosticContextRef, expr: &Expression, variables: &[&str]) {
+    if expr.is_empty() {
+        return;
+    }
+    let expr = match expr.parse() {
+        Ok(expr) => expr,
+        Err(err) => {
+            ctx.emit(DiagnosticKind::invalid_expression(err));
+            return;
+        }
+    };
+    expr.visit(&mut |e| match e {
+        Expr::Variable(name) if !variables.iter().any(|v| v.eq_ignore_ascii_case(name)) => {
+            ctx.emit(DiagnosticKind::unknown_expression_variable(name))
+        }
+        Expr::Call(name, args) => match function_arity(name) {
+            None => ctx.emit(DiagnosticKind::unknown_expression_function(name)),
+            Some(arity) if !arity.contains(&args.len()) => {
+                ctx.emit(DiagnosticKind::invalid_argument_count(name, args.len()))
+            }
+            Some(_) => {}
+        },
+        Expr::Binary(BinaryOp::Div | BinaryOp::Rem, _, divisor) if divisor.may_be_zero() => {
+            ctx.emit(DiagnosticKind::unsafe_division(divisor))
+        }
+        _ => {}
+    });
+}
+
+/// Expectations for the images inserted into the database, checked by
+/// [validate_images]
+///
+/// Defaults are used unless the rules are inserted as a database extra
+#[derive(Debug, Clone)]
+pub struct ImageRules {
+    /// Largest allowed width and height, in pixels
+    pub max_size: u32,
+    /// Whether images must have equal width and height, which is the case
+    /// for all the icons
+    pub square: bool,
+}
+
+impl Default for ImageRules {
+    fn default() -> Self {
+        Self {
+            max_size: 1024,
+            square: true,
+        }
+    }
+}
+
+/// Reports inserted images with dimensions not matching the [ImageRules],
+/// or with a color format the game can't load
+pub fn validate_images(db: &Database, ctx: &mut DiagnosticContext) {
+    let rules = db.extra_or_init::<ImageRules>().read().clone();
+    for (name, image) in db.images() {
+        let mut ctx = ctx.enter(name);
+        let (width, height) = (image.width(), image.height());
+        if width > rules.max_size || height > rules.max_size {
+            ctx.emit(DiagnosticKind::image_too_large(
+                width,
+                height,
+                rules.max_size,
+            ));
+        }
+        if rules.square && width != height {
+            ctx.emit(DiagnosticKind::image_not_square(width, height));
+        }
+        let color = image.color();
+        if !matches!(
+            color,
+            ColorType::L8 | ColorType::La8 | ColorType::Rgb8 | ColorType::Rgba8
+        ) {
+            ctx.emit(DiagnosticKind::unsupported_image_color(format!(
+                "{color:?}"
+            )));
+        }
+    }
+}
+
+/// Names of the game assets that exist outside of the mod, like the ones
+/// referenced by the vanilla database
+///
+/// Stored as a database extra, and filled by the vanilla database loader
+#[derive(Debug, Clone, Default)]
+pub struct KnownAssets {
+    names: AHashMap<AssetKind, AHashSet<String>>,
+}
+
+impl KnownAssets {
+    pub fn extend<S: Into<String>>(&mut self, kind: AssetKind, names: impl IntoIterator<Item = S>) {
+        self.names
+            .entry(kind)
+            .or_default()
+            .extend(names.into_iter().map(Into::into));
+    }
+
+    pub fn contains(&self, kind: AssetKind, name: &str) -> bool {
+        self.names
+            .get(&kind)
+            .is_some_and(|names| names.contains(name))
+    }
+
+    pub fn names(&self, kind: AssetKind) -> impl Iterator<Item = &str> {
+        self.names
+            .get(&kind)
+            .into_iter()
+            .flatten()
+            .map(|s| s.as_str())
+    }
+}
+
+/// Reports images used by items that are neither [KnownAssets] nor
+/// inserted into the database, suggesting the closest known name
+///
+/// Skipped entirely if no known images are registered, since every vanilla
+/// image would be reported otherwise
+pub fn validate_image_names(db: &Database, ctx: &mut DiagnosticContext) {
+    let known = db.extra_or_init::<KnownAssets>().read().clone();
+    if known.names(AssetKind::Image).next().is_none() {
+        return;
+    }
+    let inserted: AHashSet<_> = db.images().into_iter().map(|(name, _)| name).collect();
+    let inverse_ids = db.use_id_mappings(|ids| ids.get_inverse_ids());
+
+    db.for_each_item(|item| {
+        let mut unknown = BTreeSet::new();
+        item.visit_assets(&mut |kind, name| {
+            if kind == AssetKind::Image && !known.contains(kind, name) && !inserted.contains(name) {
+                unknown.insert(name.to_string());
+            }
+        });
+        if unknown.is_empty() {
+            return;
+        }
+        let mut ctx = ctx.enter(item_file_name(
+            &inverse_ids,
+            item.inner_type_name(),
+            item.id(),
+        ));
+        for name in unknown {
+            let candidates = known
+                .names(AssetKind::Image)
+                .chain(inserted.iter().map(|s| s.as_str()));
+            let suggestion = closest_name(&name, candidates).map(str::to_string);
+            ctx.emit(DiagnosticKind::unknown_image(name, suggestion));
+        }
+    });
+}
+
+/// Reports quests, dialog actions and node transitions with requirements
+/// that can never be met, like `X & !X` or an empty `Any`
+pub fn validate_requirements(db: &Database, ctx: &mut DiagnosticContext) {
+    let inverse_ids = db.use_id_mappings(|ids| ids.get_inverse_ids());
+
+    db.quest_iter(|quests| {
+        for quest in quests {
+            let mut ctx = ctx.enter(item_file_name(
+                &inverse_ids,
+                Quest::type_name(),
+                Some(quest.id.0),
+            ));
+            for_each_requirement(&mut ctx, &quest, check_requirement);
+        }
+    });
+}
+
+/// Visits the quest requirement and requirements of all dialog actions and
+/// node transitions
+fn for_each_requirement(
+    ctx: &mut DiagnosticContextRef,
+    quest: &Quest,
+    mut visit: impl FnMut(DiagnosticContextRef, &Requirement),
+) {
+    visit(ctx.enter_field("requirement"), &quest.requirement);
+    let mut nodes_ctx = ctx.enter_field("nodes");
+    for (i, node) in quest.nodes.iter().enumerate() {
+        let mut ctx = nodes_ctx.enter_index(i);
+        let (variant, field, requirements): (_, _, Vec<_>) = match node {
+            Node::ShowDialog(node) => (
+                "ShowDialog",
+                "actions",
+                node.actions.iter().map(|a| &a.requirement).collect(),
+            ),
+            Node::Switch(node) => (
+                "Switch",
+                "transitions",
+                node.transitions.iter().map(|t| &t.requirement).collect(),
+            ),
+            Node::Random(node) => (
+                "Random",
+                "transitions",
+                node.transitions.iter().map(|t| &t.requirement).collect(),
+            ),
+            Node::Condition(node) => (
+                "Condition",
+                "transitions",
+                node.transitions.iter().map(|t| &t.requirement).collect(),
+            ),
+            _ => continue,
+        };
+        let mut ctx = ctx.enter_variant(variant);
+        let mut ctx = ctx.enter_field(field);
+        for (i, requirement) in requirements.into_iter().enumerate() {
+            let mut ctx = ctx.enter_index(i);
+            visit(ctx.enter_field("requirement"), requirement);
+        }
+    }
+}
+
+fn check_requirement(mut ctx: DiagnosticContextRef, requirement: &Requirement) {
+    if requirement.simplify().is_never() {
+        ctx.emit(DiagnosticKind::unsatisfiable_requirement());
+    }
+}
+
+/// Limits of the game engine, checked by [validate_engine_limits]
+///
+/// Content exceeding them doesn't produce any errors in game, but quests
+/// stop progressing or texts get cut off. Defaults are used unless the
+/// limits are inserted as a database extra
+#[derive(Debug, Clone)]
+pub struct EngineLimits {
+    pub max_quest_nodes: usize,
+    /// Maximum amount of the player choices in a single dialog
+    pub max_dialog_actions: usize,
+    /// Maximum nesting of `All`, `Any` and `None` requirements
+    pub max_requirement_depth: usize,
+    /// Maximum nesting of loot containing other loot
+    pub max_loot_depth: usize,
+    /// Maximum length of quest texts, in characters
+    pub max_string_length: usize,
+}
+
+impl Default for EngineLimits {
+    fn default() -> Self {
+        Self {
+            max_quest_nodes: 1000,
+            max_dialog_actions: 10,
+            max_requirement_depth: 16,
+            max_loot_depth: 16,
+            max_string_length: 4096,
+        }
+    }
+}
+
+/// Reports quests and loot exceeding the [EngineLimits]
+pub fn validate_engine_limits(db: &Database, ctx: &mut DiagnosticContext) {
+    let limits = db.extra_or_init::<EngineLimits>().read().clone();
+    let inverse_ids = db.use_id_mappings(|ids| ids.get_inverse_ids());
+
+    db.quest_iter(|quests| {
+        for quest in quests {
+            let mut ctx = ctx.enter(item_file_name(
+                &inverse_ids,
+                Quest::type_name(),
+                Some(quest.id.0),
+            ));
+            check_string_length(
+                ctx.enter_field("name"),
+                &quest.name,
+                limits.max_string_length,
+            );
+            for_each_requirement(&mut ctx, &quest, |ctx, requirement| {
+                check_requirement_depth(ctx, requirement, limits.max_requirement_depth)
+            });
+            let mut nodes_ctx = ctx.enter_field("nodes");
+            if quest.nodes.len() > limits.max_quest_nodes {
+                nodes_ctx.emit(DiagnosticKind::engine_limit_exceeded(
+                    "Amount of quest nodes",
+                    quest.nodes.len(),
+                    limits.max_quest_nodes,
+                ));
+            }
+            for (i, node) in quest.nodes.iter().enumerate() {
+                let mut ctx = nodes_ctx.enter_index(i);
+                for field in node.field_names() {
+                    if let Some(FieldValue::String(text)) = node.get_field(field) {
+                        check_string_length(
+                            ctx.enter_field(*field),
+                            &text,
+                            limits.max_string_length,
+                        );
+                    }
+                }
+                let Node::ShowDialog(node) = node else {
+                    continue;
+                };
+                let mut ctx = ctx.enter_variant("ShowDialog");
+                let mut ctx = ctx.enter_field("actions");
+                if node.actions.len() > limits.max_dialog_actions {
+                    ctx.emit(DiagnosticKind::engine_limit_exceeded(
+                        "Amount of dialog actions",
+                        node.actions.len(),
+                        limits.max_dialog_actions,
+                    ));
+                }
+                for (i, action) in node.actions.iter().enumerate() {
+                    check_string_length(
+                        ctx.enter_index(i).enter_field("button_text"),
+                        &action.button_text,
+                        limits.max_string_length,
+                    );
+                }
+            }
+        }
+    });
+
+    db.loot_iter(|loot| {
+        for loot in loot {
+            let depth = loot_depth(&loot.loot);
+            if depth > limits.max_loot_depth {
+                ctx.enter(item_file_name(
+                    &inverse_ids,
+                    Loot::type_name(),
+                    Some(loot.id.0),
+                ))
+                .enter_field("loot")
+                .emit(DiagnosticKind::engine_limit_exceeded(
+                    "Loot nesting depth",
+                    depth,
+                    limits.max_loot_depth,
+                ));
+            }
+        }
+    });
+}
+
+fn check_string_length(mut ctx: DiagnosticContextRef, text: &str, max: usize) {
+    let length = text.chars().count();
+    if length > max {
+        ctx.emit(DiagnosticKind::engine_limit_exceeded(
+            "Text length",
+            length,
+            max,
+        ));
+    }
+}
+
+fn check_requirement_depth(mut ctx: DiagnosticContextRef, requirement: &Requirement, max: usize) {
+    let depth = requirement_depth(requirement);
+    if depth > max {
+        ctx.emit(DiagnosticKind::engine_limit_exceeded(
+            "Requirement nesting depth",
+            depth,
+            max,
+        ));
+    }
+}
+
+fn requirement_depth(requirement: &Requirement) -> usize {
+    let children = match requirement {
+        Requirement::All(r) => &r.requirements,
+        Requirement::Any(r) => &r.requirements,
+        Requirement::None(r) => &r.requirements,
+        _ => return 0,
+    };
+    1 + children.iter().map(requirement_depth).max().unwrap_or(0)
+}
+
+fn loot_depth(loot: &LootContent) -> usize {
+    let items = match loot {
+        LootContent::RandomItems(l) => &l.items,
+        LootContent::AllItems(l) => &l.items,
+        LootContent::ItemsWithChance(l) => &l.items,
+        _ => return 0,
+    };
+    1 + items.iter().map(|i| loot_depth(&i.loot)).max().unwrap_or(0)
+}
+
+/// Reports quests, nodes and quest items that changed their meaning since
+/// the snapshot given to [DatabaseHolder::guard_compatibility] was taken
+///
+/// [DatabaseHolder::guard_compatibility]: crate::database::DatabaseHolder::guard_compatibility
+pub fn validate_compatibility(db: &Database, ctx: &mut DiagnosticContext) {
+    let Some((snapshot, policy)) = db
+        .extra_or_init::<CompatibilityGuard>()
+        .read()
+        .snapshot
+        .clone()
+    else {
+        return;
+    };
+    let breaks = snapshot.breaks(&db.compatibility_snapshot());
+    if breaks.is_empty() {
+        return;
+    }
+    let inverse_ids = db.use_id_mappings(|ids| ids.get_inverse_ids());
+    for change in breaks {
+        ctx.enter(item_file_name(
+            &inverse_ids,
+            change.type_name,
+            Some(change.item_id),
+        ))
+        .emit(DiagnosticKind::savegame_incompatible(
+            change.what,
+            change.frozen,
+            change.current,
+            policy == CompatibilityPolicy::Strict,
+        ));
+    }
+}
+
+/// Reports the mod ID used by other mods of the registry given to
+/// [DatabaseHolder::check_mod_registry], and the version that wasn't bumped
+/// since the published build
+///
+/// [DatabaseHolder::check_mod_registry]: crate::database::DatabaseHolder::check_mod_registry
+pub fn validate_mod_registry(db: &Database, ctx: &mut DiagnosticContext) {
+    let Some(registry) = db.extra_or_init::<RegistryGuard>().read().registry.clone() else {
+        return;
+    };
+    let Some(settings) = db.get_singleton::<DatabaseSettings>() else {
+        return;
+    };
+    let current = KnownMod::from_settings(&settings.read());
+    let mut ctx = ctx.enter(item_file_name(
+        &Default::default(),
+        DatabaseSettings::type_name(),
+        None,
+    ));
+    for issue in registry.check(&current) {
+        let field = match issue {
+            DiagnosticKind::ModVersionNotBumped { .. } => "mod_version",
+            _ => "mod_id",
+        };
+        ctx.enter_field(field).emit(issue);
+    }
+}
+
+type SaveCheckFn = Box<dyn Fn(&Database, &mut DiagnosticContext) + Send + Sync>;
+
+/// Checks registered via [DatabaseHolder::add_save_check]
+///
+/// [DatabaseHolder::add_save_check]: crate::database::DatabaseHolder::add_save_check
+#[derive(Default)]
+pub(crate) struct SaveChecks {
+    checks: Vec<SaveCheckFn>,
+}
+
+impl DatabaseHolder {
+    /// Registers the check to be run alongside the built-in validators when
+    /// the database is saved
+    ///
+    /// Useful for the references that can only be verified once all items
+    /// are built
+    pub fn add_save_check(
+        &self,
+        check: impl Fn(&Database, &mut DiagnosticContext) + Send + Sync + 'static,
+    ) {
+        self.extra_or_init::<SaveChecks>()
+            .edit(|c| c.checks.push(Box::new(check)));
+    }
+}
+
+/// Runs the checks registered via [DatabaseHolder::add_save_check], in the
+/// registration order
+///
+/// [DatabaseHolder::add_save_check]: crate::database::DatabaseHolder::add_save_check
+pub fn validate_save_checks(db: &Database, ctx: &mut DiagnosticContext) {
+    let checks = db.extra_or_init::<SaveChecks>();
+    for check in &checks.read().checks {
+        check(db, ctx);
+    }
+}
+
+/// Reports IDs referenced by the item that don't resolve to an item of the
+/// referenced type, mentioning types that do have an item with such ID
+///
+/// `occupied` holds IDs of all items in the database, grouped by type name.
+/// IDs in `explicit` were assigned via [crate::mapping::IdMapping::set_id], usually to
+/// reference the base game items that are not loaded, so they are not
+/// reported
+pub(crate) fn check_references(
+    item: &mut Item,
+    occupied: &AHashMap<&'static str, AHashSet<i32>>,
+    explicit: &AHashMap<Cow<'static, str>, AHashSet<i32>>,
+    mut ctx: DiagnosticContextRef,
+) {
+    item.visit_ids(&mut |ty, id| {
+        if occupied.get(ty).is_some_and(|ids| ids.contains(id))
+            || explicit.get(ty).is_some_and(|ids| ids.contains(id))
+        {
+            return;
+        }
+        let mut other_types: Vec<String> = occupied
+            .iter()
+            .filter(|(_, ids)| ids.contains(id))
+            .map(|(ty, _)| ty.to_string())
+            .collect();
+        other_types.sort();
+        ctx.emit(DiagnosticKind::unresolved_reference(ty, *id, other_types));
+    });
+}
+
+/// Finds the candidate with the smallest edit distance, if it's close
+/// enough to likely be a typo
+fn closest_name<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(2);
+    candidates
+        .map(|c| (edit_distance(&name.to_lowercase(), &c.to_lowercase()), c))
+        .filter(|(d, _)| *d <= max_distance)
+        .min()
+        .map(|(_, c)| c)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Parses component cell type, treating an empty or unknown cell type as
+/// [CellType::Empty]
+fn component_cell_type(component: &Component) -> CellType {
+    component
+        .cell_type
+        .chars()
+        .next()
+        .and_then(parse_cell_type)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use diagnostic::context::DiagnosticContext;
+    use diagnostic::diagnostic::DiagnosticKind;
+    use eh_schema::schema::{Component, Faction, InstalledComponent, Ship, ShipBuild};
+
+    use super::{validate_component_fit, validate_relations};
+    use crate::database::{DatabaseHolder, Remember};
+    use crate::layout::Layout;
+    use crate::relations::{Party, Relation};
+
+    #[test]
+    fn component_fit() {
+        let db = DatabaseHolder::ephemeral();
+        db.add_id_range(0..100);
+        let component = |id: &str, layout: Layout, cell_type: &str| {
+            Component::new(db.new_id(id), db.new_id(format!("{id}_stats")))
+                .with_layout(layout)
+                .with_cell_type(cell_type)
+                .remember(&db)
+                .id
+        };
+        let fits = component("test:fits", Layout::new_square(2, '1'), "1");
+        let too_big = component("test:too_big", Layout::new_square(4, '1'), "");
+        let wrong_cell = component("test:wrong_cell", Layout::new_square(1, '1'), "4");
+        let extended = component("test:extended", Layout::new_square(4, '1'), "");
+        component("test:unused", Layout::new_square(4, '1'), "");
+
+        let ship = Ship::new(db.new_id("test:ship"))
+            .with_layout(Layout::parse("110111011").unwrap())
+            .remember(&db)
+            .id;
+        let installed = |ids: &[_]| {
+            ids.iter()
+                .map(|id| InstalledComponent::new(*id))
+                .collect::<Vec<_>>()
+        };
+        ShipBuild::new(db.new_id("test:build"), ship)
+            .with_components(installed(&[fits, too_big, wrong_cell]))
+            .remember(&db);
+        ShipBuild::new(db.new_id("test:extended_build"), ship)
+            .with_extended_layout(true)
+            .with_components(installed(&[extended]))
+            .remember(&db);
+
+        let mut ctx = DiagnosticContext::default();
+        validate_component_fit(&db, &mut ctx);
+        let reported: Vec<_> = ctx
+            .diagnostics
+            .iter()
+            .filter(|(_, d)| {
+                d.iter()
+                    .any(|d| matches!(d.kind, DiagnosticKind::ComponentNeverFits { hulls: 1 }))
+            })
+            .map(|(file, _)| file.as_str())
+            .collect();
+        assert_eq!(
+            reported,
+            vec![
+                "test/Component/too_big.json",
+                "test/Component/wrong_cell.json"
+            ]
+        );
+    }
+
+    #[test]
+    fn relations() {
+        let db = DatabaseHolder::ephemeral();
+        db.add_id_range(0..100);
+        let faction = |id: &str| Faction::new(db.new_id(id)).remember(&db).id;
+        let pirates = faction("test:pirates");
+        let traders = faction("test:traders");
+        let allies = faction("test:allies");
+
+        let relations = db.relations();
+        // Relations of the player were never set, so they are implicit
+        relations.everyone_hostile_to(Party::Player);
+        let mut ctx = DiagnosticContext::default();
+        validate_relations(&db, &mut ctx);
+        assert!(ctx.diagnostics.is_empty(), "{:?}", ctx.diagnostics);
+
+        relations
+            .set(pirates, traders, Relation::Hostile)
+            .set(Party::Player, allies, Relation::Friendly)
+            .set_symmetric(Party::Player, pirates, Relation::Hostile);
+        let mut ctx = DiagnosticContext::default();
+        validate_relations(&db, &mut ctx);
+        let mut reported: Vec<_> = ctx
+            .diagnostics
+            .iter()
+            .filter(|(_, d)| {
+                d.iter()
+                    .any(|d| matches!(d.kind, DiagnosticKind::AsymmetricRelation { .. }))
+            })
+            .map(|(file, _)| file.as_str())
+            .collect();
+        reported.sort();
+        assert_eq!(
+            reported,
+            vec!["test/Faction/allies.json", "test/Faction/pirates.json"]
+        );
+    }
+}
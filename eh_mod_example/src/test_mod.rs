@@ -1,14 +1,16 @@
+use components::bullets::{mirrored, sine_path, spawn_grid_outline};
 use eh_mod_cli::dev::database::{database, Database, DatabaseIdLike, DbItem};
 use eh_mod_cli::dev::schema::schema::{
     ActivationType, Ammunition, BulletBody, BulletController, BulletControllerParametric,
     BulletImpactType, BulletPrefab, BulletPrefabId, BulletTrigger, BulletTriggerCondition,
-    CellType, ColorMode, ComponentStats, DamageType, ImpactEffect, ImpactEffectType, Weapon,
-    WeaponClass, WeaponSlotType,
+    CellType, ComponentStats, DamageType, ImpactEffect, ImpactEffectType, Weapon, WeaponClass,
+    WeaponSlotType,
 };
 use eh_mod_cli::Args;
 
 pub fn build_mod(args: Args) {
-    let db = database(args.output_dir, args.output_mod);
+    let db = database(&args.output_dir, args.output_mod.as_ref());
+    args.apply_to(&db);
 
     db.add_id_range(9870000..9999999);
     db.set_id::<BulletPrefab>("eh:mine", 9);
@@ -18,7 +20,7 @@ pub fn build_mod(args: Args) {
 
     parametric_ammo(&db);
 
-    db.save();
+    args.report.report(db.save());
 }
 
 fn parametric_ammo(db: &Database) {
@@ -87,32 +89,11 @@ fn parametric_ammo(db: &Database) {
         .new_ammunition("juh9870:square")
         .with(|a| a.with_body(simple_body(None, 20.0)))
         .edit(|a| {
-            let w = 5;
-            let h = 5;
-            let size = 15;
-            let sx = size / w;
-            let sy = size / h;
-            for y in 0..h {
-                for x in 0..w {
-                    if x != 0 && x != w - 1 && y != 0 && y != h - 1 {
-                        continue;
-                    }
-                    let px = x * sx - size / 2 + sx / 2;
-                    let py = y * sy - size / 2 + sy / 2;
-                    a.triggers.push(
-                        BulletTrigger::spawn_bullet()
-                            .with_condition(BulletTriggerCondition::Created)
-                            .with_ammunition(boolet.id)
-                            .with_offset_x(px.to_string())
-                            .with_offset_y(py.to_string())
-                            .with_size(5.0)
-                            .with_color("#FF0000")
-                            .with_color_mode(ColorMode::UseMyOwn)
-                            .with_rotation("0")
-                            .wrap(),
-                    )
-                }
-            }
+            a.triggers.extend(
+                spawn_grid_outline(5, 5, 3.0, boolet.id)
+                    .with_look(5.0, "#FF0000")
+                    .into_triggers(),
+            );
 
             a.impact_type = BulletImpactType::HitAllTargets;
 
@@ -144,23 +125,16 @@ fn sine_ammo(
     edit: impl Fn(&mut Ammunition),
     param_edit: impl Fn(&mut BulletControllerParametric),
 ) -> (DbItem<Ammunition>, DbItem<Ammunition>) {
-    let period = std::f32::consts::PI / period;
-    let y = format!("SIN(t * {period}) * {magnitude}");
-    let rotation = format!("COS(t * {period}) * {}", 180.0 / std::f32::consts::PI);
+    let path = sine_path(period, magnitude);
     let left = db.new_ammunition(format!("{id}_left")).edit(|ammo| {
         edit(ammo);
-        let mut controller = BulletController::parametric()
-            .with_y(y.clone())
-            .with_rotation(rotation.clone());
+        let mut controller = path.clone();
         param_edit(&mut controller);
         ammo.controller = controller.into();
     });
     let right = db.new_ammunition(format!("{id}_right")).edit(|ammo| {
         edit(ammo);
-
-        let mut controller = BulletController::parametric()
-            .with_y(format!("-{y}"))
-            .with_rotation(format!("-{rotation}"));
+        let mut controller = mirrored(&path);
         param_edit(&mut controller);
         ammo.controller = controller.into();
     });
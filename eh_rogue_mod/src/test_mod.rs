@@ -6,16 +6,18 @@ use tracing::{debug, error_span, instrument};
 
 use eh_mod_cli::db_vanilla::load_vanilla;
 use eh_mod_cli::dev::database::{database, Database, Remember};
+use eh_mod_cli::dev::difficulty::scale_loot;
+use eh_mod_cli::dev::economy::{apply_technology_prices, PriceCurve, PriceRules, Rounding};
 use eh_mod_cli::dev::helpers::from_json_string;
 use eh_mod_cli::dev::json;
-use eh_mod_cli::dev::reporting::report_diagnostics;
+use eh_mod_cli::dev::passes::{Pass, Scheduler};
 use eh_mod_cli::dev::schema::schema::{
-    DatabaseSettings, Loot, LootContent, LootContentAllItems, LootContentMoney,
-    LootContentQuestItem, LootContentStarMap, LootId, LootItem, Node, NodeAction,
-    NodeCompleteQuest, NodeFailQuest, NodeReceiveItem, NodeShowDialog, Quest, QuestId, QuestItem,
-    QuestType, Requirement, RequirementAll, RequirementHaveQuestItem, RequirementNone,
-    StartCondition, Technology,
+    Component, DatabaseSettings, Loot, LootContentAllItems, LootContentMoney, LootContentQuestItem,
+    LootContentStarMap, LootId, LootItem, Node, NodeAction, NodeCompleteQuest, NodeFailQuest,
+    NodeReceiveItem, NodeShowDialog, Quest, QuestId, QuestItem, QuestType, Requirement,
+    RequirementAll, RequirementHaveQuestItem, RequirementNone, Ship, StartCondition, Technology,
 };
+use eh_mod_cli::dev::vanilla_mappings::GameVersion;
 use eh_mod_cli::Args;
 
 use crate::test_mod::quest_surgeon::next_id;
@@ -24,11 +26,13 @@ pub mod quest_surgeon;
 
 #[instrument]
 pub fn build_mod(args: Args) {
-    let db = database(args.output_dir, args.output_mod);
+    let db = database(&args.output_dir, args.output_mod.as_ref());
 
     let start = Instant::now();
 
-    load_vanilla(&db);
+    load_vanilla(&db, GameVersion::V1_6);
+
+    args.apply_to(&db);
 
     debug!(
         time = pretty_duration(&start.elapsed(), None),
@@ -45,11 +49,27 @@ pub fn build_mod(args: Args) {
 
     let start = Instant::now();
 
-    permadeath(&db);
-    cheap_tech(&db);
-    bonus_loot(&db);
-    encounter_patches(&db);
-    // debug(&db);
+    Scheduler::new()
+        .with(
+            Pass::new("permadeath", permadeath)
+                .writes::<QuestItem>()
+                .writes::<Loot>()
+                .writes::<Quest>(),
+        )
+        .with(
+            Pass::new("cheap_tech", cheap_tech)
+                .reads::<Component>()
+                .reads::<Ship>()
+                .writes::<Technology>(),
+        )
+        .with(Pass::new("bonus_loot", bonus_loot).writes::<Loot>())
+        .with(
+            Pass::new("encounter_patches", encounter_patches)
+                .writes::<Loot>()
+                .writes::<Quest>(),
+        )
+        // .with(Pass::new("debug", debug).writes::<Quest>())
+        .run(&db);
 
     debug!(
         time = pretty_duration(&start.elapsed(), None),
@@ -57,7 +77,7 @@ pub fn build_mod(args: Args) {
     );
 
     let start = Instant::now();
-    report_diagnostics(db.save());
+    args.report.report(db.save());
     debug!(
         time = pretty_duration(&start.elapsed(), None),
         "Saved the resulting mod"
@@ -277,68 +297,6 @@ fn debug(db: &Database) {
     .remember(db);
 }
 
-#[instrument]
-fn upgrade_loot(loot: &mut LootContent, multiplier: f32) {
-    let times = |n: i32| -> i32 { (n as f32 * multiplier) as i32 };
-    match loot {
-        LootContent::None(_) => {}
-        LootContent::SomeMoney(m) => {
-            m.value_ratio *= multiplier * multiplier;
-            m.value_ratio = m.value_ratio.min(1000.0);
-        }
-        LootContent::Fuel(_) => {}
-        LootContent::Money(m) => {
-            m.min_amount = times(m.min_amount);
-            m.max_amount = times(m.max_amount);
-        }
-        LootContent::Stars(s) => {
-            s.min_amount = times(s.min_amount);
-            s.max_amount = times(s.max_amount);
-        }
-        LootContent::StarMap(_) => {}
-        LootContent::RandomComponents(c) => {
-            c.min_amount = times(c.min_amount);
-            c.max_amount = times(c.max_amount);
-            c.value_ratio *= multiplier * multiplier;
-        }
-        LootContent::RandomItems(i) => {
-            // Only upgrade inner loot, not min/max amounts
-            for item in &mut i.items {
-                upgrade_loot(&mut item.loot, multiplier)
-            }
-        }
-        LootContent::AllItems(i) => {
-            for item in &mut i.items {
-                upgrade_loot(&mut item.loot, multiplier)
-            }
-        }
-        LootContent::ItemsWithChance(i) => {
-            for item in &mut i.items {
-                upgrade_loot(&mut item.loot, multiplier)
-            }
-        }
-        LootContent::QuestItem(i) => {
-            i.min_amount = times(i.min_amount);
-            i.max_amount = times(i.max_amount);
-        }
-        LootContent::Ship(_) => {}
-        LootContent::EmptyShip(_) => {}
-        LootContent::Component(c) => {
-            c.min_amount = times(c.min_amount);
-            c.max_amount = times(c.max_amount);
-        }
-        LootContent::Blueprint(_) => {}
-        LootContent::ResearchPoints(rp) => {
-            rp.min_amount = times(rp.min_amount);
-            rp.max_amount = times(rp.max_amount);
-        }
-        LootContent::Satellite(sat) => {
-            sat.min_amount = times(sat.min_amount);
-            sat.max_amount = times(sat.max_amount);
-        }
-    }
-}
-
 #[instrument]
 fn bonus_loot(db: &Database) {
     let mults = vec![
@@ -355,30 +313,31 @@ fn bonus_loot(db: &Database) {
 
     for (id, mult) in mults {
         let _guard = error_span!("Loot", id, mult).entered();
-        let loot = db.get_item::<Loot>(id).unwrap();
-        let mut loot = loot.write();
-        upgrade_loot(&mut loot.loot, mult);
+        db.with_item_mut::<Loot, _>(id, |loot| scale_loot(&mut loot.loot, mult))
+            .unwrap();
     }
 
-    let merchant_loot = db.get_item::<Loot>("eh:merchant_loot").unwrap();
-    let mut merchant_loot = merchant_loot.write();
-    merchant_loot.loot = from_json_string(include_str!("merchant_loot.json"));
+    db.with_item_mut::<Loot, _>("eh:merchant_loot", |loot| {
+        loot.loot = from_json_string(include_str!("merchant_loot.json"));
+    })
+    .unwrap();
 
-    let random_stuff = db.get_item::<Loot>("eh:random_stuff").unwrap();
-    let mut random_stuff = random_stuff.write();
-    random_stuff.loot = from_json_string(include_str!("random_stuff.json"));
+    db.with_item_mut::<Loot, _>("eh:random_stuff", |loot| {
+        loot.loot = from_json_string(include_str!("random_stuff.json"));
+    })
+    .unwrap();
 }
 
 #[instrument]
 fn cheap_tech(db: &Database) {
+    let rules = PriceRules::new(PriceCurve::Scale(0.2))
+        .with_rounding(Rounding::Down)
+        .with_min_price(1);
+    let report = apply_technology_prices(db, &rules, |_| true);
+    debug!("Technology prices:\n{report}");
+
     db.iter_mut::<Technology, _>(|i| {
         for mut x in i {
-            let price = x.price_mut();
-            if *price > 0 {
-                *price /= 5;
-                *price = (*price).max(1);
-            }
-
             let hidden = x.hidden_mut();
             if *hidden {
                 *hidden = false;
@@ -394,66 +353,66 @@ fn encounter_patches(db: &Database) {
     scavenger_loot.loot = from_json_string(include_str!("scav_loot.json"));
 
     let patch_combat_encounters = |quest: QuestId, reward: LootId| {
-        let quest = db.get_item::<Quest>(quest).unwrap();
-        let mut quest = quest.write();
+        db.with_item_mut::<Quest, _>(quest, |quest| {
+            let mut next_id = next_id(quest);
 
-        let mut next_id = next_id(&quest);
+            let mut extra_nodes: Vec<Node> = vec![];
 
-        let mut extra_nodes: Vec<Node> = vec![];
+            let mut transitions = AHashMap::<i32, i32>::default();
 
-        let mut transitions = AHashMap::<i32, i32>::default();
+            let mut reward_node = |transition: i32| {
+                if let Some(&node_id) = transitions.get(&transition) {
+                    return node_id;
+                }
 
-        let mut reward_node = |transition: i32| {
-            if let Some(&node_id) = transitions.get(&transition) {
-                return node_id;
-            }
+                let dialog_node_id = next_id();
+                let reward_node_id = next_id();
+                extra_nodes.push(
+                    NodeShowDialog {
+                        id: dialog_node_id,
+                        required_view: Default::default(),
+                        message: "$MessageCombatReward".to_string(),
+                        enemy: None,
+                        loot: Some(reward),
+                        character: None,
+                        actions: vec![NodeAction {
+                            target_node: reward_node_id,
+                            requirement: Default::default(),
+                            button_text: "$ACTION_Continue".to_string(),
+                        }],
+                    }
+                    .into(),
+                );
+                extra_nodes.push(
+                    NodeReceiveItem {
+                        id: reward_node_id,
+                        default_transition: transition,
+                        loot: Some(reward),
+                    }
+                    .into(),
+                );
+                transitions.insert(transition, dialog_node_id);
+                dialog_node_id
+            };
 
-            let dialog_node_id = next_id();
-            let reward_node_id = next_id();
-            extra_nodes.push(
-                NodeShowDialog {
-                    id: dialog_node_id,
-                    required_view: Default::default(),
-                    message: "$MessageCombatReward".to_string(),
-                    enemy: None,
-                    loot: Some(reward),
-                    character: None,
-                    actions: vec![NodeAction {
-                        target_node: reward_node_id,
-                        requirement: Default::default(),
-                        button_text: "$ACTION_Continue".to_string(),
-                    }],
-                }
-                .into(),
-            );
-            extra_nodes.push(
-                NodeReceiveItem {
-                    id: reward_node_id,
-                    default_transition: transition,
-                    loot: Some(reward),
-                }
-                .into(),
-            );
-            transitions.insert(transition, dialog_node_id);
-            dialog_node_id
-        };
-
-        for node in &mut quest.nodes {
-            match node {
-                Node::AttackFleet(attack) => {
-                    attack.default_transition = reward_node(attack.default_transition);
-                }
-                Node::DestroyOccupants(attack) => {
-                    attack.default_transition = reward_node(attack.default_transition);
-                }
-                Node::AttackStarbase(attack) => {
-                    attack.default_transition = reward_node(attack.default_transition);
+            for node in &mut quest.nodes {
+                match node {
+                    Node::AttackFleet(attack) => {
+                        attack.default_transition = reward_node(attack.default_transition);
+                    }
+                    Node::DestroyOccupants(attack) => {
+                        attack.default_transition = reward_node(attack.default_transition);
+                    }
+                    Node::AttackStarbase(attack) => {
+                        attack.default_transition = reward_node(attack.default_transition);
+                    }
+                    _ => {}
                 }
-                _ => {}
             }
-        }
 
-        quest.nodes.extend(extra_nodes);
+            quest.nodes.extend(extra_nodes);
+        })
+        .unwrap();
     };
 
     patch_combat_encounters(db.id("eh:scavenger_trade"), scavenger_loot.id);
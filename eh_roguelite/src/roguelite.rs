@@ -8,6 +8,7 @@ use eh_mod_cli::dev::schema::schema::{
     QuestItem, StartCondition,
 };
 use eh_mod_cli::dev::validators::validate_settings;
+use eh_mod_cli::dev::vanilla_mappings::GameVersion;
 use eh_mod_cli::Args;
 
 use crate::roguelite::core::{core_quest, ITEM_PLAYER_DID_MOVE};
@@ -20,9 +21,10 @@ mod events;
 
 #[instrument]
 pub fn build_mod(args: Args) {
-    let db = database(args.output_dir.clone(), args.output_mod.clone());
+    let db = database(&args.output_dir, args.output_mod.as_ref());
 
-    load_vanilla(&db);
+    load_vanilla(&db, GameVersion::V1_6);
+    args.apply_to(&db);
 
     db.add_id_range(10000..999999999);
 
@@ -34,7 +36,7 @@ pub fn build_mod(args: Args) {
 
     core_quest(&db);
 
-    db.save();
+    args.report.report(db.save());
 }
 
 fn patch_vanilla(db: &Database) {
@@ -102,7 +104,7 @@ fn settings(db: &Database) {
     });
 
     db.get_singleton::<GalaxySettings>().unwrap().edit(|s| {
-        s.enemy_level = "MAX(distance - 100, 0) / 4".to_string();
+        s.enemy_level = "MAX(distance - 100, 0) / 4".into();
         s.max_enemy_ships_level = 500;
     });
 
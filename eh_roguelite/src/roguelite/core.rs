@@ -1,6 +1,8 @@
 use itertools::Itertools;
 
 use eh_mod_cli::dev::database::{Database, DbItem, Remember};
+use eh_mod_cli::dev::diagnostic::context::DiagnosticContext;
+use eh_mod_cli::dev::diagnostic::diagnostic::DiagnosticKind;
 use eh_mod_cli::dev::mapping::DatabaseIdLike;
 use eh_mod_cli::dev::schema::schema::{
     Loot, LootContent, LootContentRandomItems, Quest, QuestId, QuestItem, QuestItemId, QuestType,
@@ -10,7 +12,7 @@ use quests::quests::branch::{BranchBuilder, BranchDone};
 use quests::quests::{IntoNodeId, NodeId, QuestContext, QuestContextData};
 
 use crate::roguelite::core::new_game::startup;
-use crate::roguelite::events::Events;
+use crate::roguelite::events::{validate_events, EventProblem, Events};
 
 type Ctx<'a> = &'a mut QuestContextData;
 
@@ -91,6 +93,8 @@ fn item(db: &Database, id: impl DatabaseIdLike<QuestItem>) -> QuestItemId {
 }
 
 const CHAPTERS: usize = 5;
+/// Number of the events each chapter is expected to have
+const MIN_CHAPTER_EVENTS: usize = 1;
 
 const ALL_EVENT_ITEMS_100: &str = "rgl:all_event_items";
 const ALL_SHIPS_100: &str = "rgl:all_ships_100x";
@@ -147,6 +151,8 @@ fn init_chapter_event_items(db: &Database) {
     let events = db.extra::<Events>();
     let events = events.read();
 
+    db.add_save_check(check_events);
+
     let ch_item = db.new_quest_item(ITEM_CHAPTER).edit(|i| {
         i.set_name("Chapter indicator")
             .set_description("Indicates the current chapter")
@@ -162,8 +168,8 @@ fn init_chapter_event_items(db: &Database) {
     for chapter in 1..=CHAPTERS {
         let chapter_events = events
             .iter()
-            .filter(|evt| !evt.chapters.as_ref().is_some_and(|c| !c.contains(&chapter)))
-            .map(|evt| evt.item.as_loot(1).wrap_item(evt.weight))
+            .filter(|evt| evt.is_available_in(chapter))
+            .map(|evt| evt.item.as_loot(1).wrap_item(evt.weight_at(chapter)))
             .collect_vec();
 
         db.new_loot(loot_chapter(chapter))
@@ -174,3 +180,21 @@ fn init_chapter_event_items(db: &Database) {
             });
     }
 }
+
+/// Reports the problems of the registered events as warnings, since the
+/// content is still being written
+fn check_events(db: &Database, ctx: &mut DiagnosticContext) {
+    let events = db.extra::<Events>();
+    let events = events.read();
+    for problem in validate_events(&events, 1..=CHAPTERS, MIN_CHAPTER_EVENTS) {
+        let entry = match &problem {
+            EventProblem::NotEnoughEvents { chapter, .. } => {
+                db.item_file_name(db.id::<Loot>(loot_chapter(*chapter)))
+            }
+            EventProblem::NoFleets { event } | EventProblem::NeverAvailable { event } => {
+                db.item_file_name(db.id::<QuestItem>(event.as_str()))
+            }
+        };
+        ctx.enter(entry).emit(DiagnosticKind::custom(problem));
+    }
+}
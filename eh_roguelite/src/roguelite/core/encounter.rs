@@ -16,8 +16,10 @@ use crate::roguelite::events::{Event, EventKind, Events, WeightedVec};
 const QUEST_ENCOUNTER_INIT: &str = "rgl:encounter/init";
 const QUEST_ENCOUNTER_CHOICE: &str = "rgl:encounter/path_choice";
 const QUEST_ENCOUNTER_CANCEL_BUTTON: &str = "rgl:encounter/cancel_button";
+#[allow(dead_code)]
 const QUEST_ENCOUNTER_REWARDS: &str = "rgl:encounter/rewards";
 
+#[allow(dead_code)]
 const QUEST_ENCOUNTER_COMBAT_: &str = "rgl:encounter/combat/";
 
 const ITEM_RESUME_BUTTON_INDICATOR: &str = "rgl:encounter/resume_button_indicator";
@@ -67,7 +67,7 @@ pub fn end_game_start_new(db: &Database) -> QuestId {
                         "init",
                         item(&db, ITEM_RUN_ON_PROGRESS).as_loot(1000).loot(&db),
                     )
-                    .start_quest("start_new_game", db.get_id_raw(QUEST_NEW_GAME))
+                    .start_quest_node("start_new_game", QUEST_NEW_GAME, "init")
                     .cancel_quest()
                     .entrypoint()
             })
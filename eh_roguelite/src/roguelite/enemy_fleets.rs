@@ -1,31 +1,25 @@
-use std::ops::DerefMut;
-
-use eh_mod_cli::dev::database::{Database, DbItem, Remember};
+use eh_mod_cli::dev::database::{Database, Remember};
 use eh_mod_cli::dev::mapping::DatabaseIdLike;
 use eh_mod_cli::dev::schema::schema::{
     CombatRules, CombatRulesId, Fleet, PlayerShipSelectionMode, RewardCondition, ShipBuild,
     TimeOutMode,
 };
 
-use crate::roguelite::events::{Event, EventKind};
-use crate::roguelite::Events;
+use crate::roguelite::events::{ContentPack, Event, EventKind};
 
 pub fn create_fleets(db: &Database) {
     rules(db);
 
-    let events = db.extra::<Events>();
-    let mut events = events.write();
-
-    chapter_1(db, events.deref_mut())
+    early_game(db).register(db);
 }
 
 fn rules(db: &Database) {
     let basic_rules = CombatRules {
         id: db.new_id("rgl:basic"),
-        initial_enemy_ships: "RANDOM(1,4)".to_string(),
-        max_enemy_ships: "12".to_string(),
+        initial_enemy_ships: "RANDOM(1,4)".into(),
+        max_enemy_ships: "12".into(),
         battle_map_size: 200,
-        time_limit: "30".to_string(),
+        time_limit: "30".into(),
         time_out_mode: TimeOutMode::CallNextEnemy,
         loot_condition: RewardCondition::Default,
         exp_condition: RewardCondition::Default,
@@ -54,18 +48,18 @@ fn rules(db: &Database) {
         .set_time_limit("10");
 }
 
-fn chapter_1(db: &Database, events: &mut Events) {
+fn early_game(db: &Database) -> ContentPack {
     let scouts = vec![db.id::<ShipBuild>("eh:scout"); 5];
     let fleet = fleet(db, "rgl:scouts", 0, scouts, None);
 
-    events.push(
-        Event::new(
+    ContentPack::new("rgl:early_game", 1..=2)
+        .with_weight_scaling(0.5)
+        .event(Event::new(
             db,
             "rgl:scouts",
             EventKind::Combat(vec![fleet.id.into()], None),
-        )
-        .with_chapters(1..=1),
-    )
+        ))
+        .content(fleet)
 }
 
 fn fleet(
@@ -74,7 +68,7 @@ fn fleet(
     level: i32,
     ships: Vec<impl DatabaseIdLike<ShipBuild>>,
     rules: impl Into<Option<CombatRulesId>>,
-) -> DbItem<Fleet> {
+) -> Fleet {
     Fleet {
         id: db.new_id(id),
         factions: Default::default(),
@@ -88,5 +82,4 @@ fn fleet(
         player_has_one_ship: false,
         combat_rules: Some(rules.into().unwrap_or_else(|| db.id("rgl:basic"))),
     }
-    .remember(db)
 }
@@ -1,7 +1,10 @@
+use std::fmt::{Display, Formatter};
 use std::ops::RangeInclusive;
 
+use tracing::debug;
+
 use eh_mod_cli::dev::database::Database;
-use eh_mod_cli::dev::schema::schema::{FleetId, LootId, QuestItemId, Requirement};
+use eh_mod_cli::dev::schema::schema::{FleetId, Item, LootId, QuestItemId, Requirement};
 
 pub type Events = Vec<Event>;
 
@@ -45,6 +48,9 @@ pub struct Event {
     pub item: QuestItemId,
     pub kind: EventKind,
     pub weight: f32,
+    /// Multiplier applied to the weight for each chapter past the first
+    /// one the event is available in
+    pub weight_scaling: f32,
     pub chapters: Option<RangeInclusive<usize>>,
 }
 
@@ -62,6 +68,7 @@ impl Event {
             item: item.id,
             kind,
             weight: 0.0,
+            weight_scaling: 1.0,
             chapters: None,
         }
     }
@@ -78,13 +85,170 @@ impl Event {
         format!("{}.desc", self.id)
     }
 
+    #[allow(dead_code)]
     pub fn with_weight(mut self, weight: f32) -> Self {
         self.weight = weight;
         self
     }
 
+    #[allow(dead_code)]
     pub fn with_chapters(mut self, chapter: impl Into<Option<RangeInclusive<usize>>>) -> Self {
         self.chapters = chapter.into();
         self
     }
+
+    pub fn is_available_in(&self, chapter: usize) -> bool {
+        self.chapters.as_ref().is_none_or(|c| c.contains(&chapter))
+    }
+
+    /// Weight of the event in the given chapter, with the scaling applied
+    pub fn weight_at(&self, chapter: usize) -> f32 {
+        let first = self.chapters.as_ref().map_or(1, |c| *c.start());
+        let steps = chapter.saturating_sub(first) as i32;
+        self.weight * self.weight_scaling.powi(steps)
+    }
+}
+
+/// Bundle of the events available in the range of chapters, along with
+/// the fleets, loots and items they use
+///
+/// Nothing is added into the run until the pack is registered
+#[derive(Debug, Clone)]
+pub struct ContentPack {
+    pub id: String,
+    pub chapters: RangeInclusive<usize>,
+    pub weight_scaling: f32,
+    pub events: Events,
+    pub content: Vec<Item>,
+}
+
+impl ContentPack {
+    pub fn new(id: impl Into<String>, chapters: RangeInclusive<usize>) -> Self {
+        Self {
+            id: id.into(),
+            chapters,
+            weight_scaling: 1.0,
+            events: vec![],
+            content: vec![],
+        }
+    }
+
+    /// Sets the weight multiplier applied for each chapter past the first
+    /// one the event is available in, on top of the event's own scaling
+    pub fn with_weight_scaling(mut self, scaling: f32) -> Self {
+        self.weight_scaling = scaling;
+        self
+    }
+
+    /// Adds the event, which is only available in the chapters of the pack
+    pub fn event(mut self, event: Event) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Adds the fleet, loot or item used by the events of the pack
+    pub fn content(mut self, item: impl Into<Item>) -> Self {
+        self.content.push(item.into());
+        self
+    }
+
+    /// Adds the events and the content of the pack to the run
+    ///
+    /// Chapters of the events are narrowed down to the chapters of the pack,
+    /// and their weight scaling is multiplied by the scaling of the pack
+    pub fn register(self, db: &Database) {
+        debug!(
+            pack = self.id,
+            events = self.events.len(),
+            content = self.content.len(),
+            "Registering content pack"
+        );
+        db.remember_all(self.content);
+
+        let events = db.extra::<Events>();
+        let mut events = events.write();
+        for mut event in self.events {
+            let chapters = match event.chapters {
+                Some(c) => {
+                    *c.start().max(self.chapters.start())..=*c.end().min(self.chapters.end())
+                }
+                None => self.chapters.clone(),
+            };
+            event.chapters = Some(chapters);
+            event.weight_scaling *= self.weight_scaling;
+            events.push(event);
+        }
+    }
+}
+
+/// Inconsistency in the registered events, see [validate_events]
+#[derive(Debug, Clone)]
+pub enum EventProblem {
+    NotEnoughEvents {
+        chapter: usize,
+        events: usize,
+        min: usize,
+    },
+    NoFleets {
+        event: String,
+    },
+    /// Chapters of the event and of its pack don't overlap
+    NeverAvailable {
+        event: String,
+    },
+}
+
+impl Display for EventProblem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventProblem::NotEnoughEvents {
+                chapter,
+                events,
+                min,
+            } => write!(
+                f,
+                "Chapter {chapter} has {events} events, expected at least {min}"
+            ),
+            EventProblem::NoFleets { event } => write!(f, "Combat event `{event}` has no fleets"),
+            EventProblem::NeverAvailable { event } => {
+                write!(f, "Event `{event}` is not available in any chapter")
+            }
+        }
+    }
+}
+
+/// Checks that every chapter has at least `min_events` events, and every
+/// event is available in some chapter and has fleets to fight
+pub fn validate_events(
+    events: &Events,
+    chapters: RangeInclusive<usize>,
+    min_events: usize,
+) -> Vec<EventProblem> {
+    let mut problems = vec![];
+    for chapter in chapters {
+        let count = events.iter().filter(|e| e.is_available_in(chapter)).count();
+        if count < min_events {
+            problems.push(EventProblem::NotEnoughEvents {
+                chapter,
+                events: count,
+                min: min_events,
+            });
+        }
+    }
+    for event in events {
+        if event.chapters.as_ref().is_some_and(|c| c.is_empty()) {
+            problems.push(EventProblem::NeverAvailable {
+                event: event.id.clone(),
+            });
+        }
+        match &event.kind {
+            EventKind::Combat(fleets, _) if fleets.is_empty() => {
+                problems.push(EventProblem::NoFleets {
+                    event: event.id.clone(),
+                });
+            }
+            EventKind::Combat(_, _) => {}
+        }
+    }
+    problems
 }
@@ -0,0 +1,19 @@
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "regenerate")]
+    {
+        println!("cargo:rerun-if-env-changed=CODEGEN_SCHEMA_INPUT");
+        let schema = std::env::var("CODEGEN_SCHEMA_INPUT").expect(
+            "`regenerate` feature requires `CODEGEN_SCHEMA_INPUT` to point to the schema directory",
+        );
+        let out_dir = std::env::var("OUT_DIR").expect("Cargo should set OUT_DIR");
+        let options = eh_codegen::CodegenOptions {
+            preserve_unknown: std::env::var_os("CARGO_FEATURE_PRESERVE_UNKNOWN").is_some(),
+            ..Default::default()
+        };
+        if let Err(err) = eh_codegen::build_script(schema, out_dir, options) {
+            panic!("{err:?}")
+        }
+    }
+}
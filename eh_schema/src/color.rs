@@ -0,0 +1,220 @@
+//! Colors of the schema string-color fields
+//!
+//! Game stores colors as `#RRGGBB` or `#AARRGGBB` hex strings, with the
+//! alpha channel coming first. Generated validation of the color fields
+//! uses [Color::parse], so malformed strings are reported on save
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+#[derive(Debug, Clone, Error, Eq, PartialEq)]
+pub enum ColorError {
+    #[error("Color `{}` doesn't start with `#`", .0)]
+    MissingHash(String),
+    #[error("Color `{}` has {} digits, expected 6 or 8", .0, .1)]
+    InvalidLength(String, usize),
+    #[error("Color `{}` contains non-hex characters", .0)]
+    InvalidDigit(String),
+}
+
+impl Color {
+    pub const TRANSPARENT: Color = Color::rgba(0, 0, 0, 0);
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const GRAY: Color = Color::rgb(128, 128, 128);
+    pub const RED: Color = Color::rgb(255, 0, 0);
+    pub const GREEN: Color = Color::rgb(0, 255, 0);
+    pub const BLUE: Color = Color::rgb(0, 0, 255);
+    pub const YELLOW: Color = Color::rgb(255, 255, 0);
+    pub const CYAN: Color = Color::rgb(0, 255, 255);
+    pub const MAGENTA: Color = Color::rgb(255, 0, 255);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::rgba(r, g, b, 255)
+    }
+
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Parses the `#RRGGBB` or `#AARRGGBB` color
+    pub fn parse(text: &str) -> Result<Self, ColorError> {
+        let Some(digits) = text.strip_prefix('#') else {
+            return Err(ColorError::MissingHash(text.to_string()));
+        };
+        if !digits.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ColorError::InvalidDigit(text.to_string()));
+        }
+        let channel = |i: usize| u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).unwrap();
+        match digits.len() {
+            6 => Ok(Self::rgb(channel(0), channel(1), channel(2))),
+            8 => Ok(Self::rgba(channel(1), channel(2), channel(3), channel(0))),
+            len => Err(ColorError::InvalidLength(text.to_string(), len)),
+        }
+    }
+
+    /// Checks whether the string is a valid color, see [Color::parse]
+    pub fn is_valid(text: &str) -> bool {
+        Self::parse(text).is_ok()
+    }
+
+    pub fn with_alpha(mut self, a: u8) -> Self {
+        self.a = a;
+        self
+    }
+
+    /// Creates the color from hue in degrees, and saturation and lightness
+    /// in the `0..=1` range
+    pub fn from_hsl(h: f32, s: f32, l: f32, a: u8) -> Self {
+        let h = h.rem_euclid(360.0) / 60.0;
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = l - chroma / 2.0;
+        let channel = |v: f32| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Self::rgba(channel(r), channel(g), channel(b), a)
+    }
+
+    /// Hue in degrees, and saturation and lightness in the `0..=1` range
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let r = self.r as f32 / 255.0;
+        let g = self.g as f32 / 255.0;
+        let b = self.b as f32 / 255.0;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return (0.0, 0.0, l);
+        }
+        let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        (h, s, l)
+    }
+
+    fn map_hsl(self, map: impl FnOnce(f32, f32, f32) -> (f32, f32, f32)) -> Self {
+        let (h, s, l) = self.to_hsl();
+        let (h, s, l) = map(h, s, l);
+        Self::from_hsl(h, s, l, self.a)
+    }
+
+    /// Increases the lightness by `amount`, in the `0..=1` range
+    pub fn lighten(self, amount: f32) -> Self {
+        self.map_hsl(|h, s, l| (h, s, l + amount))
+    }
+
+    /// Decreases the lightness by `amount`, in the `0..=1` range
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Increases the saturation by `amount`, in the `0..=1` range
+    pub fn saturate(self, amount: f32) -> Self {
+        self.map_hsl(|h, s, l| (h, s + amount, l))
+    }
+
+    /// Decreases the saturation by `amount`, in the `0..=1` range
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        self.map_hsl(|h, s, l| (h + degrees, s, l))
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Formats the color as `#RRGGBB` if it's opaque, or as `#AARRGGBB`
+/// otherwise
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.a == 255 {
+            write!(f, "#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+        } else {
+            write!(
+                f,
+                "#{:02X}{:02X}{:02X}{:02X}",
+                self.a, self.r, self.g, self.b
+            )
+        }
+    }
+}
+
+impl From<Color> for String {
+    fn from(color: Color) -> Self {
+        color.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, ColorError};
+
+    #[test]
+    fn parse_and_format() {
+        assert_eq!(Color::parse("#50C0FF"), Ok(Color::rgb(0x50, 0xC0, 0xFF)));
+        assert_eq!(
+            Color::parse("#C050c0ff"),
+            Ok(Color::rgba(0x50, 0xC0, 0xFF, 0xC0))
+        );
+        assert_eq!(Color::rgb(0x50, 0xC0, 0xFF).to_string(), "#50C0FF");
+        assert_eq!(Color::TRANSPARENT.to_string(), "#00000000");
+        assert_eq!(
+            Color::parse("000000"),
+            Err(ColorError::MissingHash("000000".to_string()))
+        );
+        assert_eq!(
+            Color::parse("#00000"),
+            Err(ColorError::InvalidLength("#00000".to_string(), 5))
+        );
+        assert_eq!(
+            Color::parse("#00000G"),
+            Err(ColorError::InvalidDigit("#00000G".to_string()))
+        );
+    }
+
+    #[test]
+    fn hsl_adjust() {
+        let color = Color::rgb(0x50, 0xC0, 0xFF).with_alpha(0x80);
+        assert_eq!(Color::from_hsl(0.0, 0.0, 0.0, 255), Color::BLACK);
+        let (h, s, l) = color.to_hsl();
+        assert_eq!(Color::from_hsl(h, s, l, color.a), color);
+        assert_eq!(Color::RED.rotate_hue(120.0), Color::GREEN);
+        assert_eq!(Color::RED.lighten(1.0), Color::WHITE);
+        assert_eq!(Color::RED.darken(1.0), Color::BLACK);
+        assert_eq!(Color::RED.desaturate(1.0), Color::rgb(128, 128, 128));
+        assert_eq!(color.lighten(0.1).a, 0x80);
+    }
+}
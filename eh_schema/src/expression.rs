@@ -0,0 +1,712 @@
+//! Expressions of the schema expression fields
+//!
+//! Game evaluates these formulas at runtime, with the variables depending
+//! on the field. [Expression] keeps the source text, which is parsed into
+//! the [Expr] tree on demand
+
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+
+use thiserror::Error;
+
+/// Value of the expression-typed schema field
+///
+/// Serialized as the source string it was created from, so the data is
+/// written back exactly as it was read, even if it fails to parse
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    Eq,
+    PartialEq,
+    Hash,
+    Ord,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct Expression(String);
+
+impl Expression {
+    pub fn new(source: impl Into<String>) -> Self {
+        Self(source.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Checks whether the expression is blank, which the game treats as the
+    /// default value of the field
+    pub fn is_empty(&self) -> bool {
+        self.0.trim().is_empty()
+    }
+
+    pub fn parse(&self) -> Result<Expr, ExpressionError> {
+        parse(&self.0)
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl AsRef<str> for Expression {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Expression {
+    fn from(source: String) -> Self {
+        Self(source)
+    }
+}
+
+impl From<&str> for Expression {
+    fn from(source: &str) -> Self {
+        Self(source.to_string())
+    }
+}
+
+impl From<&Expr> for Expression {
+    fn from(expr: &Expr) -> Self {
+        Self(expr.to_string())
+    }
+}
+
+impl From<Expr> for Expression {
+    fn from(expr: Expr) -> Self {
+        Self::from(&expr)
+    }
+}
+
+impl From<Expression> for String {
+    fn from(expr: Expression) -> Self {
+        expr.0
+    }
+}
+
+impl PartialEq<str> for Expression {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Expression {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Parsed game expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Variable(String),
+    Unary(UnaryOp, Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    Neg,
+    Not,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl BinaryOp {
+    fn precedence(&self) -> u8 {
+        match self {
+            BinaryOp::Or => 0,
+            BinaryOp::And => 1,
+            BinaryOp::Eq | BinaryOp::Ne => 2,
+            BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => 3,
+            BinaryOp::Add | BinaryOp::Sub => 4,
+            BinaryOp::Mul | BinaryOp::Div | BinaryOp::Rem => 5,
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOp::Or => "||",
+            BinaryOp::And => "&&",
+            BinaryOp::Eq => "==",
+            BinaryOp::Ne => "!=",
+            BinaryOp::Lt => "<",
+            BinaryOp::Le => "<=",
+            BinaryOp::Gt => ">",
+            BinaryOp::Ge => ">=",
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Rem => "%",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum ExpressionError {
+    #[error("Unexpected character `{}` at position {}", .0, .1)]
+    UnexpectedChar(char, usize),
+    #[error("Unexpected `{}` at position {}", .0, .1)]
+    UnexpectedToken(String, usize),
+    #[error("Unexpected end of expression")]
+    UnexpectedEnd,
+    #[error("Expression is empty")]
+    Empty,
+}
+
+/// Functions supported by the game, with their allowed argument counts
+pub const FUNCTIONS: &[(&str, RangeInclusive<usize>)] = &[
+    ("IF", 3..=usize::MAX),
+    ("MIN", 2..=usize::MAX),
+    ("MAX", 2..=usize::MAX),
+    ("ABS", 1..=1),
+    ("FLOOR", 1..=1),
+    ("CEIL", 1..=1),
+    ("ROUND", 1..=1),
+    ("SQRT", 1..=1),
+    ("POW", 2..=2),
+    ("EXP", 1..=1),
+    ("LOG", 1..=2),
+    ("SIN", 1..=1),
+    ("COS", 1..=1),
+    ("TAN", 1..=1),
+    ("ASIN", 1..=1),
+    ("ACOS", 1..=1),
+    ("ATAN", 1..=1),
+    ("RANDOM", 2..=2),
+];
+
+/// Allowed argument count for the function, or `None` if the function is
+/// not known
+pub fn function_arity(name: &str) -> Option<RangeInclusive<usize>> {
+    FUNCTIONS
+        .iter()
+        .find(|(f, _)| f.eq_ignore_ascii_case(name))
+        .map(|(_, arity)| arity.clone())
+}
+
+/// Parses the game expression
+pub fn parse(expr: &str) -> Result<Expr, ExpressionError> {
+    let tokens = tokenize(expr)?;
+    if tokens.is_empty() {
+        return Err(ExpressionError::Empty);
+    }
+    let mut parser = Parser { tokens, pos: 0 };
+    let result = parser.expression(0)?;
+    match parser.tokens.get(parser.pos) {
+        None => Ok(result),
+        Some((token, pos)) => Err(ExpressionError::UnexpectedToken(token.to_string(), *pos)),
+    }
+}
+
+impl Expr {
+    /// Calls the function for this expression and all of its subexpressions
+    pub fn visit<'a>(&'a self, func: &mut impl FnMut(&'a Expr)) {
+        func(self);
+        match self {
+            Expr::Number(_) | Expr::Variable(_) => {}
+            Expr::Unary(_, expr) => expr.visit(func),
+            Expr::Binary(_, lhs, rhs) => {
+                lhs.visit(func);
+                rhs.visit(func);
+            }
+            Expr::Call(_, args) => {
+                for arg in args {
+                    arg.visit(func)
+                }
+            }
+        }
+    }
+
+    /// Evaluates the expression if it does not depend on any variables or
+    /// random values
+    pub fn constant(&self) -> Option<f64> {
+        let bool = |b: bool| if b { 1.0 } else { 0.0 };
+        Some(match self {
+            Expr::Number(n) => *n,
+            Expr::Variable(_) => return None,
+            Expr::Unary(UnaryOp::Neg, expr) => -expr.constant()?,
+            Expr::Unary(UnaryOp::Not, expr) => bool(expr.constant()? == 0.0),
+            Expr::Binary(op, lhs, rhs) => {
+                let (a, b) = (lhs.constant()?, rhs.constant()?);
+                match op {
+                    BinaryOp::Or => bool(a != 0.0 || b != 0.0),
+                    BinaryOp::And => bool(a != 0.0 && b != 0.0),
+                    BinaryOp::Eq => bool(a == b),
+                    BinaryOp::Ne => bool(a != b),
+                    BinaryOp::Lt => bool(a < b),
+                    BinaryOp::Le => bool(a <= b),
+                    BinaryOp::Gt => bool(a > b),
+                    BinaryOp::Ge => bool(a >= b),
+                    BinaryOp::Add => a + b,
+                    BinaryOp::Sub => a - b,
+                    BinaryOp::Mul => a * b,
+                    BinaryOp::Div => a / b,
+                    BinaryOp::Rem => a % b,
+                }
+            }
+            Expr::Call(name, args) => {
+                let args = args
+                    .iter()
+                    .map(|a| a.constant())
+                    .collect::<Option<Vec<_>>>()?;
+                match (name.to_ascii_uppercase().as_str(), args.as_slice()) {
+                    ("MIN", [first, rest @ ..]) => rest.iter().fold(*first, |a, b| a.min(*b)),
+                    ("MAX", [first, rest @ ..]) => rest.iter().fold(*first, |a, b| a.max(*b)),
+                    ("ABS", [x]) => x.abs(),
+                    ("FLOOR", [x]) => x.floor(),
+                    ("CEIL", [x]) => x.ceil(),
+                    ("ROUND", [x]) => x.round(),
+                    ("SQRT", [x]) => x.sqrt(),
+                    ("POW", [x, y]) => x.powf(*y),
+                    ("SIN", [x]) => x.sin(),
+                    ("COS", [x]) => x.cos(),
+                    _ => return None,
+                }
+            }
+        })
+    }
+
+    /// Checks whether the expression can evaluate to zero for some values of
+    /// the variables
+    ///
+    /// This is a conservative estimation, any non-trivial expression is
+    /// assumed to be able to reach zero
+    pub fn may_be_zero(&self) -> bool {
+        if let Some(value) = self.constant() {
+            return value == 0.0;
+        }
+        match self {
+            Expr::Unary(UnaryOp::Neg, expr) => expr.may_be_zero(),
+            Expr::Binary(BinaryOp::Mul, lhs, rhs) => lhs.may_be_zero() || rhs.may_be_zero(),
+            Expr::Binary(BinaryOp::Div, lhs, _) => lhs.may_be_zero(),
+            Expr::Call(name, args) if name.eq_ignore_ascii_case("ABS") => {
+                args.iter().any(|a| a.may_be_zero())
+            }
+            Expr::Call(name, args) if name.eq_ignore_ascii_case("MAX") => !args
+                .iter()
+                .any(|a| a.constant().is_some_and(|value| value > 0.0)),
+            Expr::Call(name, args) if name.eq_ignore_ascii_case("MIN") => !args
+                .iter()
+                .all(|a| a.constant().is_some_and(|value| value > 0.0)),
+            _ => true,
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Number(n) => write!(f, "{n}"),
+            Expr::Variable(name) => write!(f, "{name}"),
+            Expr::Unary(UnaryOp::Neg, expr) => write!(f, "-{}", Parenthesized(expr, 6)),
+            Expr::Unary(UnaryOp::Not, expr) => write!(f, "!{}", Parenthesized(expr, 6)),
+            Expr::Binary(op, lhs, rhs) => {
+                let precedence = op.precedence();
+                write!(
+                    f,
+                    "{} {} {}",
+                    Parenthesized(lhs, precedence),
+                    op.symbol(),
+                    Parenthesized(rhs, precedence + 1)
+                )
+            }
+            Expr::Call(name, args) => {
+                write!(f, "{name}(")?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{arg}")?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// Wraps the expression into parentheses if its precedence is lower than
+/// the required one
+struct Parenthesized<'a>(&'a Expr, u8);
+
+impl Display for Parenthesized<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let precedence = match self.0 {
+            Expr::Binary(op, _, _) => op.precedence(),
+            _ => u8::MAX,
+        };
+        if precedence < self.1 {
+            write!(f, "({})", self.0)
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(&'static str),
+    LParen,
+    RParen,
+    Comma,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "{n}"),
+            Token::Ident(name) => write!(f, "{name}"),
+            Token::Op(op) => write!(f, "{op}"),
+            Token::LParen => write!(f, "("),
+            Token::RParen => write!(f, ")"),
+            Token::Comma => write!(f, ","),
+        }
+    }
+}
+
+const OPERATORS: &[&str] = &[
+    "||", "&&", "==", "!=", "<=", ">=", "<", ">", "+", "-", "*", "/", "%", "!", "=",
+];
+
+fn tokenize(expr: &str) -> Result<Vec<(Token, usize)>, ExpressionError> {
+    let mut tokens = vec![];
+    let mut chars = expr.char_indices().peekable();
+    while let Some(&(pos, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let rest = &expr[pos..];
+        if c.is_ascii_digit() || c == '.' {
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(rest.len());
+            let number = rest[..len]
+                .parse()
+                .map_err(|_| ExpressionError::UnexpectedToken(rest[..len].to_string(), pos))?;
+            tokens.push((Token::Number(number), pos));
+            for _ in 0..len {
+                chars.next();
+            }
+            continue;
+        }
+        if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !c.is_alphanumeric() && c != '_')
+                .unwrap_or(rest.len());
+            let ident = &rest[..len];
+            let token = match ident.to_ascii_uppercase().as_str() {
+                "AND" => Token::Op("&&"),
+                "OR" => Token::Op("||"),
+                "NOT" => Token::Op("!"),
+                _ => Token::Ident(ident.to_string()),
+            };
+            tokens.push((token, pos));
+            for _ in 0..ident.chars().count() {
+                chars.next();
+            }
+            continue;
+        }
+        let token = match c {
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ',' => Token::Comma,
+            _ => {
+                let op = OPERATORS
+                    .iter()
+                    .find(|op| rest.starts_with(**op))
+                    .ok_or(ExpressionError::UnexpectedChar(c, pos))?;
+                for _ in 1..op.len() {
+                    chars.next();
+                }
+                // Single `=` is treated as a comparison
+                Token::Op(if *op == "=" { "==" } else { op })
+            }
+        };
+        tokens.push((token, pos));
+        chars.next();
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Result<(Token, usize), ExpressionError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or(ExpressionError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), ExpressionError> {
+        let (token, pos) = self.next()?;
+        if token != expected {
+            return Err(ExpressionError::UnexpectedToken(token.to_string(), pos));
+        }
+        Ok(())
+    }
+
+    fn binary_op(&self) -> Option<BinaryOp> {
+        let Some(Token::Op(op)) = self.peek() else {
+            return None;
+        };
+        Some(match *op {
+            "||" => BinaryOp::Or,
+            "&&" => BinaryOp::And,
+            "==" => BinaryOp::Eq,
+            "!=" => BinaryOp::Ne,
+            "<" => BinaryOp::Lt,
+            "<=" => BinaryOp::Le,
+            ">" => BinaryOp::Gt,
+            ">=" => BinaryOp::Ge,
+            "+" => BinaryOp::Add,
+            "-" => BinaryOp::Sub,
+            "*" => BinaryOp::Mul,
+            "/" => BinaryOp::Div,
+            "%" => BinaryOp::Rem,
+            _ => return None,
+        })
+    }
+
+    fn expression(&mut self, min_precedence: u8) -> Result<Expr, ExpressionError> {
+        let mut lhs = self.unary()?;
+        while let Some(op) = self.binary_op() {
+            if op.precedence() < min_precedence {
+                break;
+            }
+            self.pos += 1;
+            let rhs = self.expression(op.precedence() + 1)?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ExpressionError> {
+        let (token, pos) = self.next()?;
+        match token {
+            Token::Op("-") => Ok(Expr::Unary(UnaryOp::Neg, Box::new(self.unary()?))),
+            Token::Op("+") => self.unary(),
+            Token::Op("!") => Ok(Expr::Unary(UnaryOp::Not, Box::new(self.unary()?))),
+            Token::Number(n) => Ok(Expr::Number(n)),
+            Token::LParen => {
+                let expr = self.expression(0)?;
+                self.expect(Token::RParen)?;
+                Ok(expr)
+            }
+            Token::Ident(name) => {
+                if self.peek() != Some(&Token::LParen) {
+                    return Ok(Expr::Variable(name));
+                }
+                self.pos += 1;
+                let mut args = vec![];
+                if self.peek() == Some(&Token::RParen) {
+                    self.pos += 1;
+                    return Ok(Expr::Call(name, args));
+                }
+                loop {
+                    args.push(self.expression(0)?);
+                    let (token, pos) = self.next()?;
+                    match token {
+                        Token::Comma => continue,
+                        Token::RParen => break,
+                        token => {
+                            return Err(ExpressionError::UnexpectedToken(token.to_string(), pos))
+                        }
+                    }
+                }
+                Ok(Expr::Call(name, args))
+            }
+            token => Err(ExpressionError::UnexpectedToken(token.to_string(), pos)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{function_arity, parse, Expr, Expression, ExpressionError, UnaryOp};
+
+    /// Fully parenthesized form of the parsed expression
+    fn tree(expr: &str) -> String {
+        fn write(expr: &Expr) -> String {
+            match expr {
+                Expr::Number(n) => n.to_string(),
+                Expr::Variable(name) => name.clone(),
+                Expr::Unary(UnaryOp::Neg, expr) => format!("(-{})", write(expr)),
+                Expr::Unary(UnaryOp::Not, expr) => format!("(!{})", write(expr)),
+                Expr::Binary(op, lhs, rhs) => {
+                    format!("({} {} {})", write(lhs), op.symbol(), write(rhs))
+                }
+                Expr::Call(name, args) => {
+                    let args = args.iter().map(write).collect::<Vec<_>>();
+                    format!("{name}({})", args.join(", "))
+                }
+            }
+        }
+        let parsed = parse(expr).unwrap_or_else(|e| panic!("{expr}: {e}"));
+        // Printed expression parses back into the same tree
+        assert_eq!(parse(&parsed.to_string()), Ok(parsed.clone()), "{expr}");
+        write(&parsed)
+    }
+
+    #[test]
+    fn transparent_serde() {
+        let expr: Expression = serde_json::from_str(r#""MAX(level, 1) * 2""#).unwrap();
+        assert_eq!(expr, "MAX(level, 1) * 2");
+        assert!(expr.parse().is_ok());
+        assert_eq!(
+            serde_json::to_string(&expr).unwrap(),
+            r#""MAX(level, 1) * 2""#
+        );
+        assert!(Expression::new("  ").is_empty());
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(tree("1 + 2 * 3"), "(1 + (2 * 3))");
+        assert_eq!(tree("1 * 2 + 3"), "((1 * 2) + 3)");
+        assert_eq!(tree("a + b % c"), "(a + (b % c))");
+        assert_eq!(tree("a < b + c"), "(a < (b + c))");
+        assert_eq!(tree("a == b < c"), "(a == (b < c))");
+        assert_eq!(tree("a && b == c"), "(a && (b == c))");
+        assert_eq!(tree("a || b && c"), "(a || (b && c))");
+        assert_eq!(tree("a OR b AND c = d"), "(a || (b && (c == d)))");
+        assert_eq!(tree("(1 + 2) * 3"), "((1 + 2) * 3)");
+        assert_eq!(tree("MAX(1 + 2, 3) * 4"), "(MAX((1 + 2), 3) * 4)");
+        assert_eq!(parse("2 + 3 * 4").unwrap().constant(), Some(14.0));
+    }
+
+    #[test]
+    fn associativity() {
+        assert_eq!(tree("a - b - c"), "((a - b) - c)");
+        assert_eq!(tree("a - b + c"), "((a - b) + c)");
+        assert_eq!(tree("a / b / c"), "((a / b) / c)");
+        assert_eq!(tree("a % b * c"), "((a % b) * c)");
+        assert_eq!(tree("a - (b - c)"), "(a - (b - c))");
+        assert_eq!(tree("a < b < c"), "((a < b) < c)");
+        assert_eq!(tree("a || b || c"), "((a || b) || c)");
+        assert_eq!(parse("10 - 4 - 3").unwrap().constant(), Some(3.0));
+        assert_eq!(parse("64 / 4 / 2").unwrap().constant(), Some(8.0));
+        assert_eq!(parse("10 - (4 - 3)").unwrap().constant(), Some(9.0));
+    }
+
+    #[test]
+    fn unary_minus() {
+        assert_eq!(tree("-a"), "(-a)");
+        assert_eq!(tree("-a - b"), "((-a) - b)");
+        assert_eq!(tree("a - -b"), "(a - (-b))");
+        assert_eq!(tree("a--b"), "(a - (-b))");
+        assert_eq!(tree("--a"), "(-(-a))");
+        assert_eq!(tree("-a * b"), "((-a) * b)");
+        assert_eq!(tree("a * -b"), "(a * (-b))");
+        assert_eq!(tree("-(a + b)"), "(-(a + b))");
+        assert_eq!(tree("-MIN(a, b)"), "(-MIN(a, b))");
+        assert_eq!(tree("+a - b"), "(a - b)");
+        assert_eq!(tree("!a && b"), "((!a) && b)");
+        assert_eq!(tree("NOT a OR b"), "((!a) || b)");
+        assert_eq!(parse("-2 - 3").unwrap().constant(), Some(-5.0));
+        assert_eq!(parse("2 - -3").unwrap().constant(), Some(5.0));
+        assert_eq!(parse("-2 * -3").unwrap().constant(), Some(6.0));
+    }
+
+    #[test]
+    fn function_arity_errors() {
+        let argument_count = |expr: &str| match parse(expr).unwrap() {
+            Expr::Call(name, args) => {
+                let arity = function_arity(&name).unwrap();
+                (args.len(), arity.contains(&args.len()))
+            }
+            expr => panic!("{expr} is not a call"),
+        };
+        assert_eq!(argument_count("ABS(x)"), (1, true));
+        assert_eq!(argument_count("ABS()"), (0, false));
+        assert_eq!(argument_count("abs(x, y)"), (2, false));
+        assert_eq!(argument_count("POW(x, 2)"), (2, true));
+        assert_eq!(argument_count("POW(x, 2, 3)"), (3, false));
+        assert_eq!(argument_count("LOG(x)"), (1, true));
+        assert_eq!(argument_count("LOG(x, 2)"), (2, true));
+        assert_eq!(argument_count("LOG(x, 2, 3)"), (3, false));
+        assert_eq!(argument_count("MIN(x)"), (1, false));
+        assert_eq!(argument_count("MIN(x, y, z, w)"), (4, true));
+        assert_eq!(function_arity("UNKNOWN"), None);
+    }
+
+    #[test]
+    fn variadic_if() {
+        assert_eq!(function_arity("if"), Some(3..=usize::MAX));
+        assert_eq!(tree("IF(a, b, c)"), "IF(a, b, c)");
+        // Any amount of arguments past the third is accepted
+        assert_eq!(
+            tree("IF(a < 1, 10, a < 2, 20, 30)"),
+            "IF((a < 1), 10, (a < 2), 20, 30)"
+        );
+        assert_eq!(tree("IF(a, IF(b, 1, 2), 3)"), "IF(a, IF(b, 1, 2), 3)");
+        assert_eq!(tree("IF(a, b, c, d, e, f, g)").matches(", ").count(), 6);
+        let Expr::Call(_, args) = parse("IF(a, b)").unwrap() else {
+            panic!("IF should parse into a call");
+        };
+        assert!(!function_arity("IF").unwrap().contains(&args.len()));
+    }
+
+    #[test]
+    fn error_positions() {
+        use ExpressionError::*;
+
+        assert_eq!(parse(""), Err(Empty));
+        assert_eq!(parse("   "), Err(Empty));
+        assert_eq!(parse("1 + $"), Err(UnexpectedChar('$', 4)));
+        assert_eq!(parse("MAX(1; 2)"), Err(UnexpectedChar(';', 5)));
+        assert_eq!(parse("1 + * 2"), Err(UnexpectedToken("*".to_string(), 4)));
+        assert_eq!(parse("1 2"), Err(UnexpectedToken("2".to_string(), 2)));
+        assert_eq!(parse("MAX(1 2)"), Err(UnexpectedToken("2".to_string(), 6)));
+        assert_eq!(parse("a + )"), Err(UnexpectedToken(")".to_string(), 4)));
+        assert_eq!(parse("(a + b))"), Err(UnexpectedToken(")".to_string(), 7)));
+        assert_eq!(
+            parse("x + 1..2"),
+            Err(UnexpectedToken("1..2".to_string(), 4))
+        );
+        assert_eq!(parse("(1 + 2"), Err(UnexpectedEnd));
+        assert_eq!(parse("MAX(1, "), Err(UnexpectedEnd));
+        assert_eq!(parse("1 -"), Err(UnexpectedEnd));
+        assert_eq!(
+            UnexpectedChar('$', 4).to_string(),
+            "Unexpected character `$` at position 4"
+        );
+    }
+}
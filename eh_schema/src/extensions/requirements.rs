@@ -1,6 +1,6 @@
 use auto_ops::*;
 
-use crate::schema::Requirement;
+use crate::schema::{QuestState, Requirement, RequirementState};
 
 impl std::ops::Not for Requirement {
     type Output = Requirement;
@@ -91,3 +91,302 @@ impl From<()> for Requirement {
         Self::empty().wrap()
     }
 }
+
+/// State with no knowledge of the game, only logical combinators and
+/// [Requirement::Empty] are evaluated
+impl RequirementState for () {
+    fn evaluate_leaf(&self, _requirement: &Requirement) -> Option<bool> {
+        None
+    }
+}
+
+impl RequirementState for QuestState {
+    fn evaluate_leaf(&self, requirement: &Requirement) -> Option<bool> {
+        match requirement {
+            Requirement::QuestCompleted(r) => Some(
+                r.item_id
+                    .is_some_and(|id| self.completed_quests.contains(&id.0)),
+            ),
+            Requirement::QuestActive(r) => Some(
+                r.item_id
+                    .is_some_and(|id| self.active_quests.contains(&id.0)),
+            ),
+            Requirement::HaveQuestItem(r) => {
+                let amount = r
+                    .item_id
+                    .and_then(|id| self.quest_items.get(&id.0))
+                    .copied()
+                    .unwrap_or(0);
+                Some(amount >= r.min_value.max(1))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Requirement {
+    /// Requirement that is never met
+    pub fn never() -> Requirement {
+        Requirement::none()
+            .with_requirements([Requirement::empty().wrap()])
+            .wrap()
+    }
+
+    /// Whether the requirement is always met, without looking into the game
+    /// state
+    pub fn is_always(&self) -> bool {
+        self.evaluate(&()) == Some(true)
+    }
+
+    /// Whether the requirement can never be met, without looking into the
+    /// game state
+    pub fn is_never(&self) -> bool {
+        self.evaluate(&()) == Some(false)
+    }
+
+    /// Evaluates the requirement, returning `None` if the result depends on
+    /// parts of the game the state has no knowledge of
+    ///
+    /// Empty [Requirement::Any] is never met, while empty [Requirement::All]
+    /// and [Requirement::None] are always met
+    pub fn evaluate(&self, state: &impl RequirementState) -> Option<bool> {
+        match self {
+            Requirement::Empty(_) => Some(true),
+            Requirement::All(all) => {
+                let mut result = Some(true);
+                for r in &all.requirements {
+                    match r.evaluate(state) {
+                        Some(false) => return Some(false),
+                        None => result = None,
+                        Some(true) => {}
+                    }
+                }
+                result
+            }
+            Requirement::Any(any) => evaluate_any(&any.requirements, state),
+            Requirement::None(none) => evaluate_any(&none.requirements, state).map(|r| !r),
+            _ => state.evaluate_leaf(self),
+        }
+    }
+
+    /// Produces an equivalent requirement with nested combinators flattened,
+    /// duplicates and neutral [Requirement::Empty] entries removed, and
+    /// combinators that always or never pass collapsed
+    ///
+    /// Requirements that never pass are represented by [Requirement::never],
+    /// and ones that always pass by [Requirement::Empty]. Contradictions like
+    /// `X & !X` and tautologies like `X | !X` are detected
+    pub fn simplify(&self) -> Requirement {
+        match self {
+            Requirement::All(all) => {
+                let mut items = vec![];
+                for r in &all.requirements {
+                    match r.simplify() {
+                        r if r.is_always() => {}
+                        r if r.is_never() => return Requirement::never(),
+                        Requirement::All(inner) => items.extend(inner.requirements),
+                        r => items.push(r),
+                    }
+                }
+                dedup(&mut items);
+                let contradiction = items.iter().any(|r| {
+                    let Requirement::None(none) = r else {
+                        return false;
+                    };
+                    none.requirements.iter().any(|n| items.contains(n))
+                });
+                if contradiction {
+                    return Requirement::never();
+                }
+                match items.len() {
+                    0 => Requirement::empty().wrap(),
+                    1 => items.pop().unwrap(),
+                    _ => Requirement::all().with_requirements(items).wrap(),
+                }
+            }
+            Requirement::Any(any) => simplify_any(&any.requirements),
+            Requirement::None(none) => match simplify_any(&none.requirements) {
+                r if r.is_always() => Requirement::never(),
+                r if r.is_never() => Requirement::empty().wrap(),
+                Requirement::Any(any) => Requirement::none()
+                    .with_requirements(any.requirements)
+                    .wrap(),
+                Requirement::None(none) if none.requirements.len() == 1 => {
+                    none.requirements.into_iter().next().unwrap()
+                }
+                r => Requirement::none().with_requirements([r]).wrap(),
+            },
+            _ => self.clone(),
+        }
+    }
+}
+
+fn evaluate_any(requirements: &[Requirement], state: &impl RequirementState) -> Option<bool> {
+    let mut result = Some(false);
+    for r in requirements {
+        match r.evaluate(state) {
+            Some(true) => return Some(true),
+            None => result = None,
+            Some(false) => {}
+        }
+    }
+    result
+}
+
+fn simplify_any(requirements: &[Requirement]) -> Requirement {
+    let mut items = vec![];
+    for r in requirements {
+        match r.simplify() {
+            r if r.is_always() => return Requirement::empty().wrap(),
+            r if r.is_never() => {}
+            Requirement::Any(inner) => items.extend(inner.requirements),
+            r => items.push(r),
+        }
+    }
+    dedup(&mut items);
+    let tautology = items.iter().any(|r| {
+        let Requirement::None(none) = r else {
+            return false;
+        };
+        matches!(none.requirements.as_slice(), [n] if items.contains(n))
+    });
+    if tautology {
+        return Requirement::empty().wrap();
+    }
+    match items.len() {
+        0 => Requirement::never(),
+        1 => items.pop().unwrap(),
+        _ => Requirement::any().with_requirements(items).wrap(),
+    }
+}
+
+fn dedup(items: &mut Vec<Requirement>) {
+    let mut seen = std::collections::HashSet::new();
+    items.retain(|r| seen.insert(r.clone()));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{DatabaseItemId, QuestState, Requirement};
+
+    const VARIABLES: i32 = 3;
+
+    /// Requirement met when the quest of the variable is completed
+    fn var(i: i32) -> Requirement {
+        Requirement::quest_completed()
+            .with_item_id(DatabaseItemId::new(i))
+            .wrap()
+    }
+
+    /// Requirement that the quest state can't evaluate
+    fn unknown() -> Requirement {
+        Requirement::faction().wrap()
+    }
+
+    /// Quest states for every assignment of the variables
+    fn states() -> impl Iterator<Item = QuestState> {
+        (0..1 << VARIABLES).map(|bits| QuestState {
+            completed_quests: (0..VARIABLES).filter(|i| bits & (1 << i) != 0).collect(),
+            ..Default::default()
+        })
+    }
+
+    /// Deterministic pseudo-random nesting of combinators over the
+    /// variables and constants
+    fn generate(seed: &mut u64, depth: usize, with_unknown: bool) -> Requirement {
+        *seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let roll = (*seed >> 33) as usize;
+        let leaf = depth == 0 || roll.is_multiple_of(4);
+        if leaf {
+            return match roll / 4 % (VARIABLES as usize + 2 + with_unknown as usize) {
+                0 => Requirement::empty().wrap(),
+                1 => Requirement::never(),
+                2 if with_unknown => unknown(),
+                i => var((i - 2 - with_unknown as usize) as i32),
+            };
+        }
+        let children: Vec<_> = (0..roll / 4 % 4)
+            .map(|_| generate(seed, depth - 1, with_unknown))
+            .collect();
+        match roll / 16 % 3 {
+            0 => Requirement::any().with_requirements(children).wrap(),
+            1 => Requirement::all().with_requirements(children).wrap(),
+            _ => Requirement::none().with_requirements(children).wrap(),
+        }
+    }
+
+    #[test]
+    fn simplify_preserves_truth_table() {
+        let mut seed = 0;
+        for _ in 0..2000 {
+            let requirement = generate(&mut seed, 4, false);
+            let simplified = requirement.simplify();
+            for state in states() {
+                assert_eq!(
+                    requirement.evaluate(&state),
+                    simplified.evaluate(&state),
+                    "{requirement:?} simplified to {simplified:?} with {state:?}"
+                );
+            }
+            assert_eq!(simplified.simplify(), simplified, "{requirement:?}");
+        }
+    }
+
+    #[test]
+    fn simplify_preserves_known_results() {
+        let mut seed = 1;
+        for _ in 0..2000 {
+            let requirement = generate(&mut seed, 4, true);
+            let simplified = requirement.simplify();
+            for state in states() {
+                // Tautologies over the unknown parts become known
+                if let Some(result) = requirement.evaluate(&state) {
+                    assert_eq!(
+                        simplified.evaluate(&state),
+                        Some(result),
+                        "{requirement:?} simplified to {simplified:?} with {state:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn constant_folding() {
+        let empty = || Requirement::empty().wrap();
+        let x = var(0);
+        let y = var(1);
+
+        assert_eq!(Requirement::all().wrap().simplify(), empty());
+        assert_eq!(Requirement::any().wrap().simplify(), Requirement::never());
+        assert_eq!(Requirement::none().wrap().simplify(), empty());
+        assert_eq!((x.clone() & empty()).simplify(), x);
+        assert_eq!((x.clone() | empty()).simplify(), empty());
+        assert_eq!(
+            (x.clone() & Requirement::never()).simplify(),
+            Requirement::never()
+        );
+        assert_eq!((x.clone() | Requirement::never()).simplify(), x);
+        assert_eq!((x.clone() & !&x).simplify(), Requirement::never());
+        assert_eq!((x.clone() | !&x).simplify(), empty());
+        assert_eq!((!!x.clone()).simplify(), x);
+        assert_eq!(
+            ((x.clone() & y.clone()) & x.clone()).simplify(),
+            x.clone() & y.clone()
+        );
+        assert_eq!(
+            Requirement::none()
+                .with_requirements([
+                    x.clone(),
+                    Requirement::any()
+                        .with_requirements([y.clone(), x.clone()])
+                        .wrap()
+                ])
+                .wrap()
+                .simplify(),
+            Requirement::none().with_requirements([x, y]).wrap()
+        );
+    }
+}
@@ -1,5 +1,6 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::hash::{Hash, Hasher};
-use std::ops::{RangeInclusive, RangeToInclusive};
+use std::ops::{BitOr, BitOrAssign, Deref, DerefMut, RangeInclusive, RangeToInclusive};
 
 use serde::Deserializer;
 
@@ -10,8 +11,391 @@ pub trait DatabaseItem: serde::Serialize + for<'a> serde::Deserialize<'a> {
     fn type_name() -> &'static str;
 }
 
+/// Fields of the item that are not part of the schema
+///
+/// Only present on the generated types when the schema is regenerated with
+/// the `preserve_unknown` feature. Unknown fields are kept as is and written
+/// back on save, so files from newer game versions can be edited without
+/// losing data
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct UnknownFields(pub BTreeMap<String, serde_json::Value>);
+
+impl Hash for UnknownFields {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for (name, value) in &self.0 {
+            name.hash(state);
+            value.to_string().hash(state);
+        }
+    }
+}
+
+impl Deref for UnknownFields {
+    type Target = BTreeMap<String, serde_json::Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for UnknownFields {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Error of parsing an enum from the name of its variant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownEnumVariant {
+    pub ty: &'static str,
+    pub name: String,
+}
+
+impl UnknownEnumVariant {
+    pub fn new(ty: &'static str, name: impl Into<String>) -> Self {
+        Self {
+            ty,
+            name: name.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for UnknownEnumVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` is not a variant of `{}`", self.name, self.ty)
+    }
+}
+
+impl std::error::Error for UnknownEnumVariant {}
+
+/// Set of enum flags, stored on disk as a plain list of values
+///
+/// Flags can be combined with the `|` operator, like `SizeClass::Frigate |
+/// SizeClass::Destroyer`
+#[derive(
+    Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, serde::Serialize, serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct Flags<T: Ord>(pub BTreeSet<T>);
+
+impl<T: Ord> Flags<T> {
+    pub fn new() -> Self {
+        Self(BTreeSet::new())
+    }
+
+    /// Adds a flag, builder-style
+    pub fn with(mut self, flag: T) -> Self {
+        self.0.insert(flag);
+        self
+    }
+
+    pub fn contains(&self, flag: &T) -> bool {
+        self.0.contains(flag)
+    }
+}
+
+impl<T: Ord> Default for Flags<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> Deref for Flags<T> {
+    type Target = BTreeSet<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Ord> DerefMut for Flags<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Ord> From<T> for Flags<T> {
+    fn from(value: T) -> Self {
+        Self::new().with(value)
+    }
+}
+
+impl<T: Ord, const N: usize> From<[T; N]> for Flags<T> {
+    fn from(value: [T; N]) -> Self {
+        Self(BTreeSet::from(value))
+    }
+}
+
+impl<T: Ord> From<BTreeSet<T>> for Flags<T> {
+    fn from(value: BTreeSet<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Ord> From<Flags<T>> for BTreeSet<T> {
+    fn from(value: Flags<T>) -> Self {
+        value.0
+    }
+}
+
+impl<T: Ord> FromIterator<T> for Flags<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord> IntoIterator for Flags<T> {
+    type Item = T;
+    type IntoIter = std::collections::btree_set::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a Flags<T> {
+    type Item = &'a T;
+    type IntoIter = std::collections::btree_set::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T: Ord> BitOr<T> for Flags<T> {
+    type Output = Self;
+
+    fn bitor(self, rhs: T) -> Self::Output {
+        self.with(rhs)
+    }
+}
+
+impl<T: Ord> BitOr for Flags<T> {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self.0.extend(rhs.0);
+        self
+    }
+}
+
+impl<T: Ord> BitOrAssign<T> for Flags<T> {
+    fn bitor_assign(&mut self, rhs: T) {
+        self.0.insert(rhs);
+    }
+}
+
+/// Signature of the expression type, listing the parameters passed to the
+/// expression by the game
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpressionType {
+    pub name: &'static str,
+    pub params: &'static [ExpressionParam],
+}
+
+impl ExpressionType {
+    /// Returns names that can be referenced in the expression, including
+    /// variant names of the enum parameters
+    pub fn variables(&self) -> Vec<&'static str> {
+        let mut variables = vec![];
+        for param in self.params {
+            variables.push(param.name);
+            if let ExpressionParamType::Enum { variants, .. } = param.ty {
+                variables.extend_from_slice(variants);
+            }
+        }
+        variables
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpressionParam {
+    pub name: &'static str,
+    pub ty: ExpressionParamType,
+    pub description: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpressionParamType {
+    Float,
+    Int,
+    /// Enum parameter, variants of which are available as named constants
+    Enum {
+        name: &'static str,
+        variants: &'static [&'static str],
+    },
+}
+
 pub trait DatabaseItemWithId: DatabaseItem + Sized {
     fn id(&self) -> DatabaseItemId<Self>;
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self>;
+}
+
+/// Access to the IDs of other items referenced by the item
+pub trait VisitIds {
+    /// Calls the visitor with the type name and the numeric ID of every
+    /// referenced item. Own ID of the item is not visited
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32));
+}
+
+impl<T: DatabaseItem> VisitIds for DatabaseItemId<T> {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        visitor(T::type_name(), &mut self.0)
+    }
+}
+
+impl<T: VisitIds> VisitIds for Option<T> {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        if let Some(x) = self {
+            x.visit_ids(visitor)
+        }
+    }
+}
+
+impl<T: VisitIds> VisitIds for Vec<T> {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        for x in self {
+            x.visit_ids(visitor)
+        }
+    }
+}
+
+impl<T: VisitIds> VisitIds for Box<T> {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        (**self).visit_ids(visitor)
+    }
+}
+
+/// Kind of the game asset referenced by name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AssetKind {
+    Image,
+    AudioClip,
+    Prefab,
+}
+
+/// Access to the names of game assets used by the item
+pub trait VisitAssets {
+    /// Calls the visitor with every non-empty asset name used by the item,
+    /// including nested structures but not the referenced items
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str));
+}
+
+impl<T: VisitAssets> VisitAssets for Option<T> {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if let Some(x) = self {
+            x.visit_assets(visitor)
+        }
+    }
+}
+
+impl<T: VisitAssets> VisitAssets for Vec<T> {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        for x in self {
+            x.visit_assets(visitor)
+        }
+    }
+}
+
+impl<T: VisitAssets> VisitAssets for Box<T> {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        (**self).visit_assets(visitor)
+    }
+}
+
+/// Untyped value of the item field
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Bool(bool),
+    /// Integer values, including enums
+    Int(i64),
+    Float(f64),
+    String(String),
+    /// Lists, structures, optional values and IDs in their serialized form
+    Json(serde_json::Value),
+}
+
+impl FieldValue {
+    /// Captures the serialized value of the field
+    pub fn of<T: serde::Serialize>(value: &T) -> Self {
+        serde_json::to_value(value)
+            .expect("Field should be serializable")
+            .into()
+    }
+
+    pub fn into_json(self) -> serde_json::Value {
+        match self {
+            FieldValue::Bool(b) => b.into(),
+            FieldValue::Int(i) => i.into(),
+            FieldValue::Float(f) => f.into(),
+            FieldValue::String(s) => s.into(),
+            FieldValue::Json(json) => json,
+        }
+    }
+
+    /// Deserializes the value into the field slot, leaving the slot intact
+    /// on error
+    pub fn write_to<T: serde::de::DeserializeOwned>(
+        self,
+        slot: &mut T,
+        field: &'static str,
+    ) -> Result<(), FieldError> {
+        *slot = serde_json::from_value(self.into_json())
+            .map_err(|error| FieldError::InvalidValue { field, error })?;
+        Ok(())
+    }
+}
+
+impl From<serde_json::Value> for FieldValue {
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Bool(b) => FieldValue::Bool(b),
+            serde_json::Value::String(s) => FieldValue::String(s),
+            serde_json::Value::Number(n) => match (n.as_i64(), n.as_f64()) {
+                (Some(i), _) => FieldValue::Int(i),
+                (None, Some(f)) => FieldValue::Float(f),
+                (None, None) => FieldValue::Json(n.into()),
+            },
+            json => FieldValue::Json(json),
+        }
+    }
+}
+
+/// Error of setting the item field by name
+#[derive(Debug)]
+pub enum FieldError {
+    UnknownField(String),
+    InvalidValue {
+        field: &'static str,
+        error: serde_json::Error,
+    },
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldError::UnknownField(name) => write!(f, "Unknown field `{name}`"),
+            FieldError::InvalidValue { field, error } => {
+                write!(f, "Invalid value for field `{field}`: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FieldError {}
+
+/// Access to the item fields by their snake case names, for generic tools
+/// that don't know the item type
+///
+/// Fields renamed in the schema can also be accessed by their old names
+pub trait DynamicFields {
+    /// Names of all fields of the item, in the declaration order
+    fn field_names(&self) -> &'static [&'static str];
+
+    /// Returns the value of the field, or `None` if the field doesn't exist
+    fn get_field(&self, name: &str) -> Option<FieldValue>;
+
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError>;
 }
 
 pub struct DatabaseItemId<T: DatabaseItem>(pub i32, std::marker::PhantomData<T>);
@@ -151,3 +535,20 @@ impl<T> MinMax<T> for (T, T) {
         self
     }
 }
+
+/// Abstract game state to evaluate requirements against
+pub trait RequirementState {
+    /// Evaluates the requirement that is not a logical combinator, returning
+    /// `None` if the state has no knowledge of it
+    fn evaluate_leaf(&self, requirement: &crate::schema::Requirement) -> Option<bool>;
+}
+
+/// Quest progression of the player, treating quests and quest items not
+/// present in the state as not completed and not owned
+#[derive(Debug, Clone, Default)]
+pub struct QuestState {
+    pub completed_quests: BTreeSet<i32>,
+    pub active_quests: BTreeSet<i32>,
+    /// Amounts of the owned quest items
+    pub quest_items: BTreeMap<i32, i32>,
+}
@@ -0,0 +1,399 @@
+//! Layouts of the ships, satellites and components
+//!
+//! Game stores layouts as flat strings of `size * size` cells, row by row.
+//! [Layout] can only be constructed as a square grid, so the generated
+//! layout fields are always valid, and malformed layouts are rejected on
+//! load
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use serde::de::Error as _;
+use thiserror::Error;
+
+use crate::schema::CellType;
+
+/// Square grid of the layout cells
+///
+/// Serialized as the flat string of cells
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Layout {
+    layout: Vec<char>,
+    size: usize,
+}
+
+#[derive(Debug, Clone, Error, Eq, PartialEq)]
+#[error("Layout `{}` of {} cells is not a square", .0, .0.chars().count())]
+pub struct LayoutNotSquare(pub String);
+
+impl Layout {
+    pub const EMPTY: char = '0';
+
+    pub const S1: &'static str = "1";
+    pub const S2: &'static str = "1111";
+    pub const S3: &'static str = "111111111";
+    pub const S4: &'static str = "1111111111111111";
+    pub const S5: &'static str = "1111111111111111111111111";
+
+    pub const V1X2: &'static str = "0101";
+    pub const V1X3: &'static str = "010010010";
+    pub const V1X4: &'static str = "0010001000100010";
+    pub const V1X5: &'static str = "0010000100001000010000100";
+
+    pub const V2X3: &'static str = "011011011";
+    pub const V2X4: &'static str = "0110011001100110";
+    pub const V2X5: &'static str = "0011000110001100011000110";
+
+    pub const V3X4: &'static str = "0111011101110111";
+    pub const V3X5: &'static str = "0111001110011100111001110";
+
+    pub const H1X2: &'static str = "0011";
+    pub const H1X3: &'static str = "000111000";
+
+    pub const H2X3: &'static str = "000111111";
+
+    pub fn new(size: usize) -> Self {
+        Self {
+            layout: vec!['0'; size * size],
+            size,
+        }
+    }
+    pub fn new_square(size: usize, brush: char) -> Self {
+        Self {
+            layout: vec![brush; size * size],
+            size,
+        }
+    }
+    pub fn new_rect(width: usize, height: usize, brush: char) -> Self {
+        let side = width.max(height);
+        let mut layout = Self::new(side);
+
+        layout.rect(0, 0, width, height, brush);
+
+        layout
+    }
+
+    pub fn rect(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+        brush: char,
+    ) -> &mut Layout {
+        let w = self.size;
+        for y in y..(y + height) {
+            let oy = y * w;
+            for x in x..(x + width) {
+                self.layout[x + oy] = brush;
+            }
+        }
+        self
+    }
+
+    /// Parses a layout string, failing if its length is not a perfect square
+    pub fn parse(layout: &str) -> Result<Self, LayoutNotSquare> {
+        let cells: Vec<char> = layout.chars().collect();
+        let size = (cells.len() as f64).sqrt() as usize;
+        if size * size != cells.len() {
+            return Err(LayoutNotSquare(layout.to_string()));
+        }
+        Ok(Self {
+            layout: cells,
+            size,
+        })
+    }
+
+    /// Length of the layout side
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns a cell at the given position, or [None] if position is out of bounds
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        if x >= self.size || y >= self.size {
+            return None;
+        }
+        Some(self[(x, y)])
+    }
+
+    /// Sets a single cell
+    ///
+    /// # Panics
+    /// Will panic if position is out of bounds
+    pub fn set(&mut self, x: usize, y: usize, brush: char) -> &mut Layout {
+        self[(x, y)] = brush;
+        self
+    }
+
+    /// Iterates over all cells of the layout as `(x, y, cell)` tuples, row by row
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize, char)> + '_ {
+        self.layout
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (i % self.size, i / self.size, *c))
+    }
+
+    /// Iterates over all non-empty cells of the layout
+    pub fn filled_cells(&self) -> impl Iterator<Item = (usize, usize, char)> + '_ {
+        self.cells().filter(|(_, _, c)| *c != Self::EMPTY)
+    }
+
+    /// Checks if layout contains no filled cells
+    pub fn is_empty(&self) -> bool {
+        self.filled_cells().next().is_none()
+    }
+
+    /// Returns the filled cells of the layout, shifted so the top left
+    /// corner of their bounding box is at `(0, 0)`
+    pub fn shape(&self) -> Vec<(usize, usize)> {
+        let cells = self
+            .filled_cells()
+            .map(|(x, y, _)| (x, y))
+            .collect::<Vec<_>>();
+        let min_x = cells.iter().map(|(x, _)| *x).min().unwrap_or_default();
+        let min_y = cells.iter().map(|(_, y)| *y).min().unwrap_or_default();
+        cells
+            .into_iter()
+            .map(|(x, y)| (x - min_x, y - min_y))
+            .collect()
+    }
+
+    /// Checks if the shape can be placed with its top left corner at the
+    /// given position, with every shape cell landing on a cell accepted by
+    /// the `accepts` predicate
+    pub fn fits_at(
+        &self,
+        shape: &[(usize, usize)],
+        x: usize,
+        y: usize,
+        mut accepts: impl FnMut(char) -> bool,
+    ) -> bool {
+        shape
+            .iter()
+            .all(|(sx, sy)| self.get(x + sx, y + sy).is_some_and(&mut accepts))
+    }
+
+    /// Finds the first position where the filled cells of `other` can be
+    /// placed into this layout, see [Layout::fits_at]
+    pub fn find_fit(
+        &self,
+        other: &Layout,
+        mut accepts: impl FnMut(char) -> bool,
+    ) -> Option<(usize, usize)> {
+        let shape = other.shape();
+        let width = shape.iter().map(|(x, _)| x + 1).max()?;
+        let height = shape.iter().map(|(_, y)| y + 1).max()?;
+        if width > self.size || height > self.size {
+            return None;
+        }
+        (0..=(self.size - height))
+            .flat_map(|y| (0..=(self.size - width)).map(move |x| (x, y)))
+            .find(|(x, y)| self.fits_at(&shape, *x, *y, &mut accepts))
+    }
+}
+
+/// Checks if the ship cell can hold a part of the component with the given
+/// cell type
+///
+/// Components with an empty cell type can be placed on any non-empty cell,
+/// and mixed inner-outer cells accept both inner and outer components
+pub fn cell_accepts(cell: char, component_cell_type: CellType) -> bool {
+    let Some(cell) = parse_cell_type(cell) else {
+        return false;
+    };
+    match (cell, component_cell_type) {
+        (CellType::Empty, _) => false,
+        (_, CellType::Empty) => true,
+        (CellType::InnerOuter, CellType::Inner | CellType::Outer) => true,
+        (cell, component) => cell == component,
+    }
+}
+
+/// Parses a single layout character into a cell type
+pub fn parse_cell_type(cell: char) -> Option<CellType> {
+    Some(match cell {
+        '0' => CellType::Empty,
+        '1' => CellType::Outer,
+        '2' => CellType::Inner,
+        '3' => CellType::InnerOuter,
+        '4' => CellType::Weapon,
+        '5' => CellType::Engine,
+        _ => return None,
+    })
+}
+
+impl Index<(usize, usize)> for Layout {
+    type Output = char;
+
+    /// # Panics
+    /// Will panic if position is out of bounds
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(
+            x < self.size && y < self.size,
+            "Cell position ({x}, {y}) is outside of the {0}x{0} layout",
+            self.size
+        );
+        &self.layout[x + y * self.size]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Layout {
+    /// # Panics
+    /// Will panic if position is out of bounds
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(
+            x < self.size && y < self.size,
+            "Cell position ({x}, {y}) is outside of the {0}x{0} layout",
+            self.size
+        );
+        &mut self.layout[x + y * self.size]
+    }
+}
+
+impl FromStr for Layout {
+    type Err = LayoutNotSquare;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<String> for Layout {
+    type Error = LayoutNotSquare;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+/// Shorthand for the layout literals in the builders
+///
+/// # Panics
+/// Will panic if the layout is not a square, use [Layout::parse] for
+/// fallible parsing
+impl From<&str> for Layout {
+    fn from(value: &str) -> Self {
+        Self::parse(value).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl serde::Serialize for Layout {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Layout {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let layout = String::deserialize(deserializer)?;
+        Self::parse(&layout).map_err(D::Error::custom)
+    }
+}
+
+impl Display for Layout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.layout.iter().collect::<String>())
+    }
+}
+
+impl From<Layout> for String {
+    fn from(val: Layout) -> Self {
+        val.layout.into_iter().collect::<String>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cell_accepts, Layout, LayoutNotSquare};
+    use crate::schema::CellType;
+
+    #[test]
+    fn square_only() {
+        let layout = Layout::parse("010111010").unwrap();
+        assert_eq!(layout.size(), 3);
+        assert_eq!(layout[(1, 0)], '1');
+        assert_eq!(layout[(0, 2)], '0');
+        assert_eq!(
+            Layout::parse("01011"),
+            Err(LayoutNotSquare("01011".to_string()))
+        );
+        assert!(Layout::parse("").is_ok());
+    }
+
+    #[test]
+    fn flat_string_serde() {
+        let layout: Layout = serde_json::from_str(r#""1111""#).unwrap();
+        assert_eq!(layout, Layout::new_square(2, '1'));
+        assert_eq!(serde_json::to_string(&layout).unwrap(), r#""1111""#);
+        assert!(serde_json::from_str::<Layout>(r#""111""#).is_err());
+    }
+
+    #[test]
+    fn shape() {
+        let layout = Layout::parse("000001011").unwrap();
+        assert_eq!(layout.shape(), vec![(1, 0), (0, 1), (1, 1)]);
+        assert_eq!(
+            Layout::parse(Layout::V1X3).unwrap().shape(),
+            vec![(0, 0), (0, 1), (0, 2)]
+        );
+        assert!(Layout::new(3).shape().is_empty());
+    }
+
+    #[test]
+    fn fits_at() {
+        let hull = Layout::parse("110111011").unwrap();
+        let shape = Layout::new_square(2, '1').shape();
+        let accepts = |c| c != Layout::EMPTY;
+        assert!(hull.fits_at(&shape, 1, 1, accepts));
+        // Hole at (2, 0)
+        assert!(!hull.fits_at(&shape, 1, 0, accepts));
+        // Out of bounds
+        assert!(!hull.fits_at(&shape, 2, 1, accepts));
+        // Predicate is applied to every cell under the shape
+        assert!(!hull.fits_at(&shape, 1, 1, |c| c == '2'));
+    }
+
+    #[test]
+    fn find_fit() {
+        let hull = Layout::parse("100011011").unwrap();
+        let accepts = |c| c != Layout::EMPTY;
+        let square = Layout::new_square(2, '1');
+        assert_eq!(hull.find_fit(&square, accepts), Some((1, 1)));
+        // Empty rows and columns of the component don't take up space
+        let offset = Layout::parse(Layout::H1X3).unwrap();
+        assert_eq!(
+            Layout::new_rect(3, 1, '1').find_fit(&offset, accepts),
+            Some((0, 0))
+        );
+        // First position is picked row by row
+        let single = Layout::new_square(1, '1');
+        assert_eq!(hull.find_fit(&single, accepts), Some((0, 0)));
+        assert_eq!(hull.find_fit(&single, |c| c == '2'), None);
+        assert_eq!(hull.find_fit(&Layout::new_square(4, '1'), accepts), None);
+        assert_eq!(hull.find_fit(&Layout::new(2), accepts), None);
+    }
+
+    #[test]
+    fn cell_acceptance() {
+        use CellType::*;
+
+        assert!(cell_accepts('1', Outer));
+        assert!(!cell_accepts('1', Inner));
+        assert!(cell_accepts('2', Inner));
+        assert!(!cell_accepts('2', Weapon));
+        assert!(cell_accepts('3', Inner));
+        assert!(cell_accepts('3', Outer));
+        assert!(!cell_accepts('3', Engine));
+        assert!(cell_accepts('4', Weapon));
+        assert!(cell_accepts('5', Engine));
+        // Components without a cell type go anywhere but the empty cells
+        for cell in ['1', '2', '3', '4', '5'] {
+            assert!(cell_accepts(cell, Empty), "{cell}");
+        }
+        assert!(!cell_accepts('0', Empty));
+        assert!(!cell_accepts('0', Outer));
+        assert!(!cell_accepts('x', Empty));
+    }
+}
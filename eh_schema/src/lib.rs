@@ -1,3 +1,17 @@
+pub mod color;
+pub mod expression;
 mod extensions;
 mod helpers;
+pub mod layout;
+#[cfg(not(feature = "regenerate"))]
 pub mod schema;
+#[cfg(feature = "regenerate")]
+#[allow(clippy::large_enum_variant)]
+#[allow(clippy::op_ref)]
+#[allow(dead_code)]
+#[allow(unused_variables)]
+#[allow(unreachable_patterns)]
+#[allow(clippy::match_single_binding)]
+pub mod schema {
+    include!(concat!(env!("OUT_DIR"), "/schema.rs"));
+}
@@ -3,6 +3,7 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 #![allow(unreachable_patterns)]
+#![allow(clippy::match_single_binding)]
 
 // versions.xml
 pub use crate::helpers::*;
@@ -35,6 +36,44 @@ impl DatabaseItem for ActivationType {
         "ActivationType"
     }
 }
+impl ActivationType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 3] = [Self::None, Self::Manual, Self::Mixed];
+    pub const fn variant_count() -> usize {
+        3
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Manual => "Manual",
+            Self::Mixed => "Mixed",
+        }
+    }
+}
+impl std::str::FromStr for ActivationType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "None" => Ok(Self::None),
+            "Manual" => Ok(Self::Manual),
+            "Mixed" => Ok(Self::Mixed),
+            _ => Err(UnknownEnumVariant::new("ActivationType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for ActivationType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for ActivationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Ai/AiDifficultyLevel.xml
 #[repr(i32)]
@@ -66,6 +105,44 @@ impl DatabaseItem for AiDifficultyLevel {
         "AiDifficultyLevel"
     }
 }
+impl AiDifficultyLevel {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 3] = [Self::Easy, Self::Medium, Self::Hard];
+    pub const fn variant_count() -> usize {
+        3
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Easy => "Easy",
+            Self::Medium => "Medium",
+            Self::Hard => "Hard",
+        }
+    }
+}
+impl std::str::FromStr for AiDifficultyLevel {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Easy" => Ok(Self::Easy),
+            "Medium" => Ok(Self::Medium),
+            "Hard" => Ok(Self::Hard),
+            _ => Err(UnknownEnumVariant::new("AiDifficultyLevel", s)),
+        }
+    }
+}
+impl std::ops::BitOr for AiDifficultyLevel {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for AiDifficultyLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Ai/AiWeaponCategory.xml
 #[repr(i32)]
@@ -96,6 +173,54 @@ impl DatabaseItem for AiWeaponCategory {
         "AiWeaponCategory"
     }
 }
+impl AiWeaponCategory {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 5] = [
+        Self::All,
+        Self::Repair,
+        Self::Damage,
+        Self::CaptureDrone,
+        Self::Recharge,
+    ];
+    pub const fn variant_count() -> usize {
+        5
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::All => "All",
+            Self::Repair => "Repair",
+            Self::Damage => "Damage",
+            Self::CaptureDrone => "CaptureDrone",
+            Self::Recharge => "Recharge",
+        }
+    }
+}
+impl std::str::FromStr for AiWeaponCategory {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "All" => Ok(Self::All),
+            "Repair" => Ok(Self::Repair),
+            "Damage" => Ok(Self::Damage),
+            "CaptureDrone" => Ok(Self::CaptureDrone),
+            "Recharge" => Ok(Self::Recharge),
+            _ => Err(UnknownEnumVariant::new("AiWeaponCategory", s)),
+        }
+    }
+}
+impl std::ops::BitOr for AiWeaponCategory {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for AiWeaponCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Ai/BehaviorNodeType.xml
 #[repr(i32)]
@@ -271,6 +396,288 @@ impl DatabaseItem for BehaviorNodeType {
         "BehaviorNodeType"
     }
 }
+impl BehaviorNodeType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 83] = [
+        Self::Success,
+        Self::Failure,
+        Self::SubTree,
+        Self::Selector,
+        Self::Sequence,
+        Self::Parallel,
+        Self::RandomSelector,
+        Self::Invertor,
+        Self::Cooldown,
+        Self::Execute,
+        Self::ParallelSequence,
+        Self::PreserveTarget,
+        Self::IfThenElse,
+        Self::HasEnoughEnergy,
+        Self::IsLowOnHp,
+        Self::IsNotControledByPlayer,
+        Self::HasIncomingThreat,
+        Self::HasAdditionalTargets,
+        Self::IsFasterThanTarget,
+        Self::HasMainTarget,
+        Self::MainTargetIsAlly,
+        Self::MainTargetIsEnemy,
+        Self::MainTargetLowHp,
+        Self::MainTargetWithinAttackRange,
+        Self::HasMothership,
+        Self::TargetDistance,
+        Self::HasLongerAttackRange,
+        Self::FindEnemy,
+        Self::MoveToAttackRange,
+        Self::AttackMainTarget,
+        Self::SelectWeapon,
+        Self::SpawnDrones,
+        Self::Ram,
+        Self::DetonateShip,
+        Self::Vanish,
+        Self::MaintainAttackRange,
+        Self::Wait,
+        Self::LookAtTarget,
+        Self::LookForAdditionalTargets,
+        Self::LookForThreats,
+        Self::MatchVelocityWithTarget,
+        Self::ActivateDevice,
+        Self::RechargeEnergy,
+        Self::SustainAim,
+        Self::ChargeWeapons,
+        Self::Chase,
+        Self::AvoidThreats,
+        Self::SlowDown,
+        Self::UseRecoil,
+        Self::DefendWithFronalShield,
+        Self::TrackControllableAmmo,
+        Self::KeepDistance,
+        Self::ForgetMainTarget,
+        Self::EscapeTargetAttackRadius,
+        Self::AttackAdditionalTargets,
+        Self::TargetAllyStarbase,
+        Self::TargetEnemyStarbase,
+        Self::BypassObstacles,
+        Self::AttackTurretTargets,
+        Self::HoldHarpoon,
+        Self::FindDamagedAlly,
+        Self::EnginePropulsionForce,
+        Self::MotherShipRetreated,
+        Self::MotherShipDestroyed,
+        Self::FlyAroundMothership,
+        Self::GoBerserk,
+        Self::TargetMothership,
+        Self::MothershipLowHp,
+        Self::MothershipDistanceExceeded,
+        Self::MakeTargetMothership,
+        Self::MothershipLowEnergy,
+        Self::MothershipLowShield,
+        Self::ShowMessage,
+        Self::DebugLog,
+        Self::SetValue,
+        Self::GetValue,
+        Self::SendMessage,
+        Self::MessageReceived,
+        Self::TargetMessageSender,
+        Self::SaveTarget,
+        Self::LoadTarget,
+        Self::HasSavedTarget,
+        Self::ForgetSavedTarget,
+    ];
+    pub const fn variant_count() -> usize {
+        83
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Success => "Success",
+            Self::Failure => "Failure",
+            Self::SubTree => "SubTree",
+            Self::Selector => "Selector",
+            Self::Sequence => "Sequence",
+            Self::Parallel => "Parallel",
+            Self::RandomSelector => "RandomSelector",
+            Self::Invertor => "Invertor",
+            Self::Cooldown => "Cooldown",
+            Self::Execute => "Execute",
+            Self::ParallelSequence => "ParallelSequence",
+            Self::PreserveTarget => "PreserveTarget",
+            Self::IfThenElse => "IfThenElse",
+            Self::HasEnoughEnergy => "HasEnoughEnergy",
+            Self::IsLowOnHp => "IsLowOnHp",
+            Self::IsNotControledByPlayer => "IsNotControledByPlayer",
+            Self::HasIncomingThreat => "HasIncomingThreat",
+            Self::HasAdditionalTargets => "HasAdditionalTargets",
+            Self::IsFasterThanTarget => "IsFasterThanTarget",
+            Self::HasMainTarget => "HasMainTarget",
+            Self::MainTargetIsAlly => "MainTargetIsAlly",
+            Self::MainTargetIsEnemy => "MainTargetIsEnemy",
+            Self::MainTargetLowHp => "MainTargetLowHp",
+            Self::MainTargetWithinAttackRange => "MainTargetWithinAttackRange",
+            Self::HasMothership => "HasMothership",
+            Self::TargetDistance => "TargetDistance",
+            Self::HasLongerAttackRange => "HasLongerAttackRange",
+            Self::FindEnemy => "FindEnemy",
+            Self::MoveToAttackRange => "MoveToAttackRange",
+            Self::AttackMainTarget => "AttackMainTarget",
+            Self::SelectWeapon => "SelectWeapon",
+            Self::SpawnDrones => "SpawnDrones",
+            Self::Ram => "Ram",
+            Self::DetonateShip => "DetonateShip",
+            Self::Vanish => "Vanish",
+            Self::MaintainAttackRange => "MaintainAttackRange",
+            Self::Wait => "Wait",
+            Self::LookAtTarget => "LookAtTarget",
+            Self::LookForAdditionalTargets => "LookForAdditionalTargets",
+            Self::LookForThreats => "LookForThreats",
+            Self::MatchVelocityWithTarget => "MatchVelocityWithTarget",
+            Self::ActivateDevice => "ActivateDevice",
+            Self::RechargeEnergy => "RechargeEnergy",
+            Self::SustainAim => "SustainAim",
+            Self::ChargeWeapons => "ChargeWeapons",
+            Self::Chase => "Chase",
+            Self::AvoidThreats => "AvoidThreats",
+            Self::SlowDown => "SlowDown",
+            Self::UseRecoil => "UseRecoil",
+            Self::DefendWithFronalShield => "DefendWithFronalShield",
+            Self::TrackControllableAmmo => "TrackControllableAmmo",
+            Self::KeepDistance => "KeepDistance",
+            Self::ForgetMainTarget => "ForgetMainTarget",
+            Self::EscapeTargetAttackRadius => "EscapeTargetAttackRadius",
+            Self::AttackAdditionalTargets => "AttackAdditionalTargets",
+            Self::TargetAllyStarbase => "TargetAllyStarbase",
+            Self::TargetEnemyStarbase => "TargetEnemyStarbase",
+            Self::BypassObstacles => "BypassObstacles",
+            Self::AttackTurretTargets => "AttackTurretTargets",
+            Self::HoldHarpoon => "HoldHarpoon",
+            Self::FindDamagedAlly => "FindDamagedAlly",
+            Self::EnginePropulsionForce => "EnginePropulsionForce",
+            Self::MotherShipRetreated => "MotherShipRetreated",
+            Self::MotherShipDestroyed => "MotherShipDestroyed",
+            Self::FlyAroundMothership => "FlyAroundMothership",
+            Self::GoBerserk => "GoBerserk",
+            Self::TargetMothership => "TargetMothership",
+            Self::MothershipLowHp => "MothershipLowHp",
+            Self::MothershipDistanceExceeded => "MothershipDistanceExceeded",
+            Self::MakeTargetMothership => "MakeTargetMothership",
+            Self::MothershipLowEnergy => "MothershipLowEnergy",
+            Self::MothershipLowShield => "MothershipLowShield",
+            Self::ShowMessage => "ShowMessage",
+            Self::DebugLog => "DebugLog",
+            Self::SetValue => "SetValue",
+            Self::GetValue => "GetValue",
+            Self::SendMessage => "SendMessage",
+            Self::MessageReceived => "MessageReceived",
+            Self::TargetMessageSender => "TargetMessageSender",
+            Self::SaveTarget => "SaveTarget",
+            Self::LoadTarget => "LoadTarget",
+            Self::HasSavedTarget => "HasSavedTarget",
+            Self::ForgetSavedTarget => "ForgetSavedTarget",
+        }
+    }
+}
+impl std::str::FromStr for BehaviorNodeType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Success" => Ok(Self::Success),
+            "Failure" => Ok(Self::Failure),
+            "SubTree" => Ok(Self::SubTree),
+            "Selector" => Ok(Self::Selector),
+            "Sequence" => Ok(Self::Sequence),
+            "Parallel" => Ok(Self::Parallel),
+            "RandomSelector" => Ok(Self::RandomSelector),
+            "Invertor" => Ok(Self::Invertor),
+            "Cooldown" => Ok(Self::Cooldown),
+            "Execute" => Ok(Self::Execute),
+            "ParallelSequence" => Ok(Self::ParallelSequence),
+            "PreserveTarget" => Ok(Self::PreserveTarget),
+            "IfThenElse" => Ok(Self::IfThenElse),
+            "HasEnoughEnergy" => Ok(Self::HasEnoughEnergy),
+            "IsLowOnHp" => Ok(Self::IsLowOnHp),
+            "IsNotControledByPlayer" => Ok(Self::IsNotControledByPlayer),
+            "HasIncomingThreat" => Ok(Self::HasIncomingThreat),
+            "HasAdditionalTargets" => Ok(Self::HasAdditionalTargets),
+            "IsFasterThanTarget" => Ok(Self::IsFasterThanTarget),
+            "HasMainTarget" => Ok(Self::HasMainTarget),
+            "MainTargetIsAlly" => Ok(Self::MainTargetIsAlly),
+            "MainTargetIsEnemy" => Ok(Self::MainTargetIsEnemy),
+            "MainTargetLowHp" => Ok(Self::MainTargetLowHp),
+            "MainTargetWithinAttackRange" => Ok(Self::MainTargetWithinAttackRange),
+            "HasMothership" => Ok(Self::HasMothership),
+            "TargetDistance" => Ok(Self::TargetDistance),
+            "HasLongerAttackRange" => Ok(Self::HasLongerAttackRange),
+            "FindEnemy" => Ok(Self::FindEnemy),
+            "MoveToAttackRange" => Ok(Self::MoveToAttackRange),
+            "AttackMainTarget" => Ok(Self::AttackMainTarget),
+            "SelectWeapon" => Ok(Self::SelectWeapon),
+            "SpawnDrones" => Ok(Self::SpawnDrones),
+            "Ram" => Ok(Self::Ram),
+            "DetonateShip" => Ok(Self::DetonateShip),
+            "Vanish" => Ok(Self::Vanish),
+            "MaintainAttackRange" => Ok(Self::MaintainAttackRange),
+            "Wait" => Ok(Self::Wait),
+            "LookAtTarget" => Ok(Self::LookAtTarget),
+            "LookForAdditionalTargets" => Ok(Self::LookForAdditionalTargets),
+            "LookForThreats" => Ok(Self::LookForThreats),
+            "MatchVelocityWithTarget" => Ok(Self::MatchVelocityWithTarget),
+            "ActivateDevice" => Ok(Self::ActivateDevice),
+            "RechargeEnergy" => Ok(Self::RechargeEnergy),
+            "SustainAim" => Ok(Self::SustainAim),
+            "ChargeWeapons" => Ok(Self::ChargeWeapons),
+            "Chase" => Ok(Self::Chase),
+            "AvoidThreats" => Ok(Self::AvoidThreats),
+            "SlowDown" => Ok(Self::SlowDown),
+            "UseRecoil" => Ok(Self::UseRecoil),
+            "DefendWithFronalShield" => Ok(Self::DefendWithFronalShield),
+            "TrackControllableAmmo" => Ok(Self::TrackControllableAmmo),
+            "KeepDistance" => Ok(Self::KeepDistance),
+            "ForgetMainTarget" => Ok(Self::ForgetMainTarget),
+            "EscapeTargetAttackRadius" => Ok(Self::EscapeTargetAttackRadius),
+            "AttackAdditionalTargets" => Ok(Self::AttackAdditionalTargets),
+            "TargetAllyStarbase" => Ok(Self::TargetAllyStarbase),
+            "TargetEnemyStarbase" => Ok(Self::TargetEnemyStarbase),
+            "BypassObstacles" => Ok(Self::BypassObstacles),
+            "AttackTurretTargets" => Ok(Self::AttackTurretTargets),
+            "HoldHarpoon" => Ok(Self::HoldHarpoon),
+            "FindDamagedAlly" => Ok(Self::FindDamagedAlly),
+            "EnginePropulsionForce" => Ok(Self::EnginePropulsionForce),
+            "MotherShipRetreated" => Ok(Self::MotherShipRetreated),
+            "MotherShipDestroyed" => Ok(Self::MotherShipDestroyed),
+            "FlyAroundMothership" => Ok(Self::FlyAroundMothership),
+            "GoBerserk" => Ok(Self::GoBerserk),
+            "TargetMothership" => Ok(Self::TargetMothership),
+            "MothershipLowHp" => Ok(Self::MothershipLowHp),
+            "MothershipDistanceExceeded" => Ok(Self::MothershipDistanceExceeded),
+            "MakeTargetMothership" => Ok(Self::MakeTargetMothership),
+            "MothershipLowEnergy" => Ok(Self::MothershipLowEnergy),
+            "MothershipLowShield" => Ok(Self::MothershipLowShield),
+            "ShowMessage" => Ok(Self::ShowMessage),
+            "DebugLog" => Ok(Self::DebugLog),
+            "SetValue" => Ok(Self::SetValue),
+            "GetValue" => Ok(Self::GetValue),
+            "SendMessage" => Ok(Self::SendMessage),
+            "MessageReceived" => Ok(Self::MessageReceived),
+            "TargetMessageSender" => Ok(Self::TargetMessageSender),
+            "SaveTarget" => Ok(Self::SaveTarget),
+            "LoadTarget" => Ok(Self::LoadTarget),
+            "HasSavedTarget" => Ok(Self::HasSavedTarget),
+            "ForgetSavedTarget" => Ok(Self::ForgetSavedTarget),
+            _ => Err(UnknownEnumVariant::new("BehaviorNodeType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for BehaviorNodeType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for BehaviorNodeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Ai/BehaviorRequirementType.xml
 #[repr(i32)]
@@ -321,6 +728,105 @@ impl DatabaseItem for BehaviorRequirementType {
         "BehaviorRequirementType"
     }
 }
+impl BehaviorRequirementType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 22] = [
+        Self::Empty,
+        Self::Any,
+        Self::All,
+        Self::None,
+        Self::AiLevel,
+        Self::MinAiLevel,
+        Self::SizeClass,
+        Self::HasDevice,
+        Self::HasDrones,
+        Self::HasAnyWeapon,
+        Self::CanRepairAllies,
+        Self::HasHighRecoilWeapon,
+        Self::HasChargeableWeapon,
+        Self::HasRemotelyControlledWeapon,
+        Self::HasLongRangeWeapon,
+        Self::HasEngine,
+        Self::HasHarpoon,
+        Self::CanRechargeAllies,
+        Self::IsDrone,
+        Self::HasKineticResistance,
+        Self::HasHighManeuverability,
+        Self::HasHighRammingDamage,
+    ];
+    pub const fn variant_count() -> usize {
+        22
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Empty => "Empty",
+            Self::Any => "Any",
+            Self::All => "All",
+            Self::None => "None",
+            Self::AiLevel => "AiLevel",
+            Self::MinAiLevel => "MinAiLevel",
+            Self::SizeClass => "SizeClass",
+            Self::HasDevice => "HasDevice",
+            Self::HasDrones => "HasDrones",
+            Self::HasAnyWeapon => "HasAnyWeapon",
+            Self::CanRepairAllies => "CanRepairAllies",
+            Self::HasHighRecoilWeapon => "HasHighRecoilWeapon",
+            Self::HasChargeableWeapon => "HasChargeableWeapon",
+            Self::HasRemotelyControlledWeapon => "HasRemotelyControlledWeapon",
+            Self::HasLongRangeWeapon => "HasLongRangeWeapon",
+            Self::HasEngine => "HasEngine",
+            Self::HasHarpoon => "HasHarpoon",
+            Self::CanRechargeAllies => "CanRechargeAllies",
+            Self::IsDrone => "IsDrone",
+            Self::HasKineticResistance => "HasKineticResistance",
+            Self::HasHighManeuverability => "HasHighManeuverability",
+            Self::HasHighRammingDamage => "HasHighRammingDamage",
+        }
+    }
+}
+impl std::str::FromStr for BehaviorRequirementType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Empty" => Ok(Self::Empty),
+            "Any" => Ok(Self::Any),
+            "All" => Ok(Self::All),
+            "None" => Ok(Self::None),
+            "AiLevel" => Ok(Self::AiLevel),
+            "MinAiLevel" => Ok(Self::MinAiLevel),
+            "SizeClass" => Ok(Self::SizeClass),
+            "HasDevice" => Ok(Self::HasDevice),
+            "HasDrones" => Ok(Self::HasDrones),
+            "HasAnyWeapon" => Ok(Self::HasAnyWeapon),
+            "CanRepairAllies" => Ok(Self::CanRepairAllies),
+            "HasHighRecoilWeapon" => Ok(Self::HasHighRecoilWeapon),
+            "HasChargeableWeapon" => Ok(Self::HasChargeableWeapon),
+            "HasRemotelyControlledWeapon" => Ok(Self::HasRemotelyControlledWeapon),
+            "HasLongRangeWeapon" => Ok(Self::HasLongRangeWeapon),
+            "HasEngine" => Ok(Self::HasEngine),
+            "HasHarpoon" => Ok(Self::HasHarpoon),
+            "CanRechargeAllies" => Ok(Self::CanRechargeAllies),
+            "IsDrone" => Ok(Self::IsDrone),
+            "HasKineticResistance" => Ok(Self::HasKineticResistance),
+            "HasHighManeuverability" => Ok(Self::HasHighManeuverability),
+            "HasHighRammingDamage" => Ok(Self::HasHighRammingDamage),
+            _ => Err(UnknownEnumVariant::new("BehaviorRequirementType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for BehaviorRequirementType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for BehaviorRequirementType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Ai/NodeExecutionMode.xml
 #[repr(i32)]
@@ -351,6 +857,54 @@ impl DatabaseItem for NodeExecutionMode {
         "NodeExecutionMode"
     }
 }
+impl NodeExecutionMode {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 5] = [
+        Self::UntilSucceeds,
+        Self::UntilFails,
+        Self::UntilFinishes,
+        Self::Infinitely,
+        Self::OneTime,
+    ];
+    pub const fn variant_count() -> usize {
+        5
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UntilSucceeds => "UntilSucceeds",
+            Self::UntilFails => "UntilFails",
+            Self::UntilFinishes => "UntilFinishes",
+            Self::Infinitely => "Infinitely",
+            Self::OneTime => "OneTime",
+        }
+    }
+}
+impl std::str::FromStr for NodeExecutionMode {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "UntilSucceeds" => Ok(Self::UntilSucceeds),
+            "UntilFails" => Ok(Self::UntilFails),
+            "UntilFinishes" => Ok(Self::UntilFinishes),
+            "Infinitely" => Ok(Self::Infinitely),
+            "OneTime" => Ok(Self::OneTime),
+            _ => Err(UnknownEnumVariant::new("NodeExecutionMode", s)),
+        }
+    }
+}
+impl std::ops::BitOr for NodeExecutionMode {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for NodeExecutionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/AmmunitionClassObsolete.xml
 #[repr(i32)]
@@ -408,6 +962,135 @@ impl DatabaseItem for AmmunitionClassObsolete {
         "AmmunitionClassObsolete"
     }
 }
+impl AmmunitionClassObsolete {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 32] = [
+        Self::Common,
+        Self::Acid,
+        Self::Aura,
+        Self::Carrier,
+        Self::Bomb,
+        Self::DamageOverTime,
+        Self::DroneControl,
+        Self::Emp,
+        Self::EmpMissile,
+        Self::EnergyBeam,
+        Self::EnergySiphon,
+        Self::Explosion,
+        Self::Fireworks,
+        Self::FragBomb,
+        Self::Fragment,
+        Self::HomingImmobilizer,
+        Self::HomingTorpedo,
+        Self::Immobilizer,
+        Self::LaserBeam,
+        Self::Rocket,
+        Self::Singularity,
+        Self::TractorBeam,
+        Self::VampiricRay,
+        Self::SmallVampiricRay,
+        Self::RepairRay,
+        Self::PlasmaWeb,
+        Self::UnguidedRocket,
+        Self::IonBeam,
+        Self::AcidRocket,
+        Self::BlackHole,
+        Self::ClusterMissile,
+        Self::HomingCarrier,
+    ];
+    pub const fn variant_count() -> usize {
+        32
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Common => "Common",
+            Self::Acid => "Acid",
+            Self::Aura => "Aura",
+            Self::Carrier => "Carrier",
+            Self::Bomb => "Bomb",
+            Self::DamageOverTime => "DamageOverTime",
+            Self::DroneControl => "DroneControl",
+            Self::Emp => "Emp",
+            Self::EmpMissile => "EmpMissile",
+            Self::EnergyBeam => "EnergyBeam",
+            Self::EnergySiphon => "EnergySiphon",
+            Self::Explosion => "Explosion",
+            Self::Fireworks => "Fireworks",
+            Self::FragBomb => "FragBomb",
+            Self::Fragment => "Fragment",
+            Self::HomingImmobilizer => "HomingImmobilizer",
+            Self::HomingTorpedo => "HomingTorpedo",
+            Self::Immobilizer => "Immobilizer",
+            Self::LaserBeam => "LaserBeam",
+            Self::Rocket => "Rocket",
+            Self::Singularity => "Singularity",
+            Self::TractorBeam => "TractorBeam",
+            Self::VampiricRay => "VampiricRay",
+            Self::SmallVampiricRay => "SmallVampiricRay",
+            Self::RepairRay => "RepairRay",
+            Self::PlasmaWeb => "PlasmaWeb",
+            Self::UnguidedRocket => "UnguidedRocket",
+            Self::IonBeam => "IonBeam",
+            Self::AcidRocket => "AcidRocket",
+            Self::BlackHole => "BlackHole",
+            Self::ClusterMissile => "ClusterMissile",
+            Self::HomingCarrier => "HomingCarrier",
+        }
+    }
+}
+impl std::str::FromStr for AmmunitionClassObsolete {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Common" => Ok(Self::Common),
+            "Acid" => Ok(Self::Acid),
+            "Aura" => Ok(Self::Aura),
+            "Carrier" => Ok(Self::Carrier),
+            "Bomb" => Ok(Self::Bomb),
+            "DamageOverTime" => Ok(Self::DamageOverTime),
+            "DroneControl" => Ok(Self::DroneControl),
+            "Emp" => Ok(Self::Emp),
+            "EmpMissile" => Ok(Self::EmpMissile),
+            "EnergyBeam" => Ok(Self::EnergyBeam),
+            "EnergySiphon" => Ok(Self::EnergySiphon),
+            "Explosion" => Ok(Self::Explosion),
+            "Fireworks" => Ok(Self::Fireworks),
+            "FragBomb" => Ok(Self::FragBomb),
+            "Fragment" => Ok(Self::Fragment),
+            "HomingImmobilizer" => Ok(Self::HomingImmobilizer),
+            "HomingTorpedo" => Ok(Self::HomingTorpedo),
+            "Immobilizer" => Ok(Self::Immobilizer),
+            "LaserBeam" => Ok(Self::LaserBeam),
+            "Rocket" => Ok(Self::Rocket),
+            "Singularity" => Ok(Self::Singularity),
+            "TractorBeam" => Ok(Self::TractorBeam),
+            "VampiricRay" => Ok(Self::VampiricRay),
+            "SmallVampiricRay" => Ok(Self::SmallVampiricRay),
+            "RepairRay" => Ok(Self::RepairRay),
+            "PlasmaWeb" => Ok(Self::PlasmaWeb),
+            "UnguidedRocket" => Ok(Self::UnguidedRocket),
+            "IonBeam" => Ok(Self::IonBeam),
+            "AcidRocket" => Ok(Self::AcidRocket),
+            "BlackHole" => Ok(Self::BlackHole),
+            "ClusterMissile" => Ok(Self::ClusterMissile),
+            "HomingCarrier" => Ok(Self::HomingCarrier),
+            _ => Err(UnknownEnumVariant::new("AmmunitionClassObsolete", s)),
+        }
+    }
+}
+impl std::ops::BitOr for AmmunitionClassObsolete {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for AmmunitionClassObsolete {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Availability.xml
 #[repr(i32)]
@@ -439,6 +1122,57 @@ impl DatabaseItem for Availability {
         "Availability"
     }
 }
+impl Availability {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 6] = [
+        Self::None,
+        Self::Common,
+        Self::Rare,
+        Self::Special,
+        Self::Hidden,
+        Self::LootOnly,
+    ];
+    pub const fn variant_count() -> usize {
+        6
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Common => "Common",
+            Self::Rare => "Rare",
+            Self::Special => "Special",
+            Self::Hidden => "Hidden",
+            Self::LootOnly => "LootOnly",
+        }
+    }
+}
+impl std::str::FromStr for Availability {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "None" => Ok(Self::None),
+            "Common" => Ok(Self::Common),
+            "Rare" => Ok(Self::Rare),
+            "Special" => Ok(Self::Special),
+            "Hidden" => Ok(Self::Hidden),
+            "LootOnly" => Ok(Self::LootOnly),
+            _ => Err(UnknownEnumVariant::new("Availability", s)),
+        }
+    }
+}
+impl std::ops::BitOr for Availability {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for Availability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/CellType.xml
 #[repr(u32)]
@@ -464,6 +1198,52 @@ impl DatabaseItem for CellType {
         "CellType"
     }
 }
+impl CellType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 6] = [
+        Self::Empty,
+        Self::Weapon,
+        Self::Outer,
+        Self::Inner,
+        Self::InnerOuter,
+        Self::Engine,
+    ];
+    pub const fn variant_count() -> usize {
+        6
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Empty => "Empty",
+            Self::Weapon => "Weapon",
+            Self::Outer => "Outer",
+            Self::Inner => "Inner",
+            Self::InnerOuter => "InnerOuter",
+            Self::Engine => "Engine",
+        }
+    }
+}
+impl std::str::FromStr for CellType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Empty" => Ok(Self::Empty),
+            "Weapon" => Ok(Self::Weapon),
+            "Outer" => Ok(Self::Outer),
+            "Inner" => Ok(Self::Inner),
+            "InnerOuter" => Ok(Self::InnerOuter),
+            "Engine" => Ok(Self::Engine),
+            _ => Err(UnknownEnumVariant::new("CellType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for CellType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
 impl serde::Serialize for CellType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -534,6 +1314,60 @@ impl DatabaseItem for ComponentCategory {
         "ComponentCategory"
     }
 }
+impl ComponentCategory {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 7] = [
+        Self::Undefined,
+        Self::Weapon,
+        Self::Defense,
+        Self::Energy,
+        Self::Engine,
+        Self::Drones,
+        Self::Special,
+    ];
+    pub const fn variant_count() -> usize {
+        7
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::Weapon => "Weapon",
+            Self::Defense => "Defense",
+            Self::Energy => "Energy",
+            Self::Engine => "Engine",
+            Self::Drones => "Drones",
+            Self::Special => "Special",
+        }
+    }
+}
+impl std::str::FromStr for ComponentCategory {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Undefined" => Ok(Self::Undefined),
+            "Weapon" => Ok(Self::Weapon),
+            "Defense" => Ok(Self::Defense),
+            "Energy" => Ok(Self::Energy),
+            "Engine" => Ok(Self::Engine),
+            "Drones" => Ok(Self::Drones),
+            "Special" => Ok(Self::Special),
+            _ => Err(UnknownEnumVariant::new("ComponentCategory", s)),
+        }
+    }
+}
+impl std::ops::BitOr for ComponentCategory {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for ComponentCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/ComponentStatsType.xml
 #[repr(i32)]
@@ -561,6 +1395,42 @@ impl DatabaseItem for ComponentStatsType {
         "ComponentStatsType"
     }
 }
+impl ComponentStatsType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 2] = [Self::PerComponent, Self::PerOneCell];
+    pub const fn variant_count() -> usize {
+        2
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::PerComponent => "PerComponent",
+            Self::PerOneCell => "PerOneCell",
+        }
+    }
+}
+impl std::str::FromStr for ComponentStatsType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "PerComponent" => Ok(Self::PerComponent),
+            "PerOneCell" => Ok(Self::PerOneCell),
+            _ => Err(UnknownEnumVariant::new("ComponentStatsType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for ComponentStatsType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for ComponentStatsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/DeviceClass.xml
 #[repr(i32)]
@@ -609,6 +1479,108 @@ impl DatabaseItem for DeviceClass {
         "DeviceClass"
     }
 }
+impl DeviceClass {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 23] = [
+        Self::Accelerator,
+        Self::Decoy,
+        Self::EnergyShield,
+        Self::Ghost,
+        Self::GravityGenerator,
+        Self::PartialShield,
+        Self::PointDefense,
+        Self::RepairBot,
+        Self::Detonator,
+        Self::Stealth,
+        Self::Teleporter,
+        Self::Brake,
+        Self::SuperStealth,
+        Self::Fortification,
+        Self::ToxicWaste,
+        Self::WormTail,
+        Self::ClonningCenter,
+        Self::TimeMachine,
+        Self::Jammer,
+        Self::DroneCamouflage,
+        Self::MissileCamouflage,
+        Self::TeleporterV2,
+        Self::WormTailV2,
+    ];
+    pub const fn variant_count() -> usize {
+        23
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Accelerator => "Accelerator",
+            Self::Decoy => "Decoy",
+            Self::EnergyShield => "EnergyShield",
+            Self::Ghost => "Ghost",
+            Self::GravityGenerator => "GravityGenerator",
+            Self::PartialShield => "PartialShield",
+            Self::PointDefense => "PointDefense",
+            Self::RepairBot => "RepairBot",
+            Self::Detonator => "Detonator",
+            Self::Stealth => "Stealth",
+            Self::Teleporter => "Teleporter",
+            Self::Brake => "Brake",
+            Self::SuperStealth => "SuperStealth",
+            Self::Fortification => "Fortification",
+            Self::ToxicWaste => "ToxicWaste",
+            Self::WormTail => "WormTail",
+            Self::ClonningCenter => "ClonningCenter",
+            Self::TimeMachine => "TimeMachine",
+            Self::Jammer => "Jammer",
+            Self::DroneCamouflage => "DroneCamouflage",
+            Self::MissileCamouflage => "MissileCamouflage",
+            Self::TeleporterV2 => "TeleporterV2",
+            Self::WormTailV2 => "WormTailV2",
+        }
+    }
+}
+impl std::str::FromStr for DeviceClass {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Accelerator" => Ok(Self::Accelerator),
+            "Decoy" => Ok(Self::Decoy),
+            "EnergyShield" => Ok(Self::EnergyShield),
+            "Ghost" => Ok(Self::Ghost),
+            "GravityGenerator" => Ok(Self::GravityGenerator),
+            "PartialShield" => Ok(Self::PartialShield),
+            "PointDefense" => Ok(Self::PointDefense),
+            "RepairBot" => Ok(Self::RepairBot),
+            "Detonator" => Ok(Self::Detonator),
+            "Stealth" => Ok(Self::Stealth),
+            "Teleporter" => Ok(Self::Teleporter),
+            "Brake" => Ok(Self::Brake),
+            "SuperStealth" => Ok(Self::SuperStealth),
+            "Fortification" => Ok(Self::Fortification),
+            "ToxicWaste" => Ok(Self::ToxicWaste),
+            "WormTail" => Ok(Self::WormTail),
+            "ClonningCenter" => Ok(Self::ClonningCenter),
+            "TimeMachine" => Ok(Self::TimeMachine),
+            "Jammer" => Ok(Self::Jammer),
+            "DroneCamouflage" => Ok(Self::DroneCamouflage),
+            "MissileCamouflage" => Ok(Self::MissileCamouflage),
+            "TeleporterV2" => Ok(Self::TeleporterV2),
+            "WormTailV2" => Ok(Self::WormTailV2),
+            _ => Err(UnknownEnumVariant::new("DeviceClass", s)),
+        }
+    }
+}
+impl std::ops::BitOr for DeviceClass {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for DeviceClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/DifficultyClass.xml
 #[repr(i32)]
@@ -638,6 +1610,46 @@ impl DatabaseItem for DifficultyClass {
         "DifficultyClass"
     }
 }
+impl DifficultyClass {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 4] = [Self::Default, Self::Class1, Self::Class2, Self::Class3];
+    pub const fn variant_count() -> usize {
+        4
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Class1 => "Class1",
+            Self::Class2 => "Class2",
+            Self::Class3 => "Class3",
+        }
+    }
+}
+impl std::str::FromStr for DifficultyClass {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Default" => Ok(Self::Default),
+            "Class1" => Ok(Self::Class1),
+            "Class2" => Ok(Self::Class2),
+            "Class3" => Ok(Self::Class3),
+            _ => Err(UnknownEnumVariant::new("DifficultyClass", s)),
+        }
+    }
+}
+impl std::ops::BitOr for DifficultyClass {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for DifficultyClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/ItemType.xml
 #[repr(i32)]
@@ -711,6 +1723,183 @@ impl DatabaseItem for ItemType {
         "ItemType"
     }
 }
+impl ItemType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 48] = [
+        Self::Undefined,
+        Self::Component,
+        Self::Device,
+        Self::Weapon,
+        Self::AmmunitionObsolete,
+        Self::DroneBay,
+        Self::Ship,
+        Self::Satellite,
+        Self::ShipBuild,
+        Self::SatelliteBuild,
+        Self::Technology,
+        Self::ComponentStats,
+        Self::ComponentMod,
+        Self::Skill,
+        Self::Faction,
+        Self::Quest,
+        Self::Loot,
+        Self::Fleet,
+        Self::Character,
+        Self::QuestItem,
+        Self::Ammunition,
+        Self::VisualEffect,
+        Self::BulletPrefab,
+        Self::BehaviorTree,
+        Self::GameObjectPrefab,
+        Self::CombatRules,
+        Self::ComponentStatUpgrade,
+        Self::StatUpgradeTemplate,
+        Self::FrontierLevel,
+        Self::FrontierCommonLevel,
+        Self::ShipSettings,
+        Self::GalaxySettings,
+        Self::DatabaseSettings,
+        Self::ExplorationSettings,
+        Self::FrontierSettings,
+        Self::ShipModSettings,
+        Self::SpecialEventSettings,
+        Self::SkillSettings,
+        Self::DebugSettings,
+        Self::CombatSettings,
+        Self::UiSettings,
+        Self::FactionsSettings,
+        Self::MusicPlaylist,
+        Self::ResearchSetting,
+        Self::PvpSettings,
+        Self::FrontierNpcSettings,
+        Self::FrontierLevelSettings,
+        Self::LocalizationSettings,
+    ];
+    pub const fn variant_count() -> usize {
+        48
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::Component => "Component",
+            Self::Device => "Device",
+            Self::Weapon => "Weapon",
+            Self::AmmunitionObsolete => "AmmunitionObsolete",
+            Self::DroneBay => "DroneBay",
+            Self::Ship => "Ship",
+            Self::Satellite => "Satellite",
+            Self::ShipBuild => "ShipBuild",
+            Self::SatelliteBuild => "SatelliteBuild",
+            Self::Technology => "Technology",
+            Self::ComponentStats => "ComponentStats",
+            Self::ComponentMod => "ComponentMod",
+            Self::Skill => "Skill",
+            Self::Faction => "Faction",
+            Self::Quest => "Quest",
+            Self::Loot => "Loot",
+            Self::Fleet => "Fleet",
+            Self::Character => "Character",
+            Self::QuestItem => "QuestItem",
+            Self::Ammunition => "Ammunition",
+            Self::VisualEffect => "VisualEffect",
+            Self::BulletPrefab => "BulletPrefab",
+            Self::BehaviorTree => "BehaviorTree",
+            Self::GameObjectPrefab => "GameObjectPrefab",
+            Self::CombatRules => "CombatRules",
+            Self::ComponentStatUpgrade => "ComponentStatUpgrade",
+            Self::StatUpgradeTemplate => "StatUpgradeTemplate",
+            Self::FrontierLevel => "FrontierLevel",
+            Self::FrontierCommonLevel => "FrontierCommonLevel",
+            Self::ShipSettings => "ShipSettings",
+            Self::GalaxySettings => "GalaxySettings",
+            Self::DatabaseSettings => "DatabaseSettings",
+            Self::ExplorationSettings => "ExplorationSettings",
+            Self::FrontierSettings => "FrontierSettings",
+            Self::ShipModSettings => "ShipModSettings",
+            Self::SpecialEventSettings => "SpecialEventSettings",
+            Self::SkillSettings => "SkillSettings",
+            Self::DebugSettings => "DebugSettings",
+            Self::CombatSettings => "CombatSettings",
+            Self::UiSettings => "UiSettings",
+            Self::FactionsSettings => "FactionsSettings",
+            Self::MusicPlaylist => "MusicPlaylist",
+            Self::ResearchSetting => "ResearchSetting",
+            Self::PvpSettings => "PvpSettings",
+            Self::FrontierNpcSettings => "FrontierNpcSettings",
+            Self::FrontierLevelSettings => "FrontierLevelSettings",
+            Self::LocalizationSettings => "LocalizationSettings",
+        }
+    }
+}
+impl std::str::FromStr for ItemType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Undefined" => Ok(Self::Undefined),
+            "Component" => Ok(Self::Component),
+            "Device" => Ok(Self::Device),
+            "Weapon" => Ok(Self::Weapon),
+            "AmmunitionObsolete" => Ok(Self::AmmunitionObsolete),
+            "DroneBay" => Ok(Self::DroneBay),
+            "Ship" => Ok(Self::Ship),
+            "Satellite" => Ok(Self::Satellite),
+            "ShipBuild" => Ok(Self::ShipBuild),
+            "SatelliteBuild" => Ok(Self::SatelliteBuild),
+            "Technology" => Ok(Self::Technology),
+            "ComponentStats" => Ok(Self::ComponentStats),
+            "ComponentMod" => Ok(Self::ComponentMod),
+            "Skill" => Ok(Self::Skill),
+            "Faction" => Ok(Self::Faction),
+            "Quest" => Ok(Self::Quest),
+            "Loot" => Ok(Self::Loot),
+            "Fleet" => Ok(Self::Fleet),
+            "Character" => Ok(Self::Character),
+            "QuestItem" => Ok(Self::QuestItem),
+            "Ammunition" => Ok(Self::Ammunition),
+            "VisualEffect" => Ok(Self::VisualEffect),
+            "BulletPrefab" => Ok(Self::BulletPrefab),
+            "BehaviorTree" => Ok(Self::BehaviorTree),
+            "GameObjectPrefab" => Ok(Self::GameObjectPrefab),
+            "CombatRules" => Ok(Self::CombatRules),
+            "ComponentStatUpgrade" => Ok(Self::ComponentStatUpgrade),
+            "StatUpgradeTemplate" => Ok(Self::StatUpgradeTemplate),
+            "FrontierLevel" => Ok(Self::FrontierLevel),
+            "FrontierCommonLevel" => Ok(Self::FrontierCommonLevel),
+            "ShipSettings" => Ok(Self::ShipSettings),
+            "GalaxySettings" => Ok(Self::GalaxySettings),
+            "DatabaseSettings" => Ok(Self::DatabaseSettings),
+            "ExplorationSettings" => Ok(Self::ExplorationSettings),
+            "FrontierSettings" => Ok(Self::FrontierSettings),
+            "ShipModSettings" => Ok(Self::ShipModSettings),
+            "SpecialEventSettings" => Ok(Self::SpecialEventSettings),
+            "SkillSettings" => Ok(Self::SkillSettings),
+            "DebugSettings" => Ok(Self::DebugSettings),
+            "CombatSettings" => Ok(Self::CombatSettings),
+            "UiSettings" => Ok(Self::UiSettings),
+            "FactionsSettings" => Ok(Self::FactionsSettings),
+            "MusicPlaylist" => Ok(Self::MusicPlaylist),
+            "ResearchSetting" => Ok(Self::ResearchSetting),
+            "PvpSettings" => Ok(Self::PvpSettings),
+            "FrontierNpcSettings" => Ok(Self::FrontierNpcSettings),
+            "FrontierLevelSettings" => Ok(Self::FrontierLevelSettings),
+            "LocalizationSettings" => Ok(Self::LocalizationSettings),
+            _ => Err(UnknownEnumVariant::new("ItemType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for ItemType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for ItemType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/ModificationQuality.xml
 #[repr(i32)]
@@ -742,6 +1931,51 @@ impl DatabaseItem for ModificationQuality {
         "ModificationQuality"
     }
 }
+impl ModificationQuality {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 6] =
+        [Self::N3, Self::N2, Self::N1, Self::P1, Self::P2, Self::P3];
+    pub const fn variant_count() -> usize {
+        6
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::N3 => "N3",
+            Self::N2 => "N2",
+            Self::N1 => "N1",
+            Self::P1 => "P1",
+            Self::P2 => "P2",
+            Self::P3 => "P3",
+        }
+    }
+}
+impl std::str::FromStr for ModificationQuality {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "N3" => Ok(Self::N3),
+            "N2" => Ok(Self::N2),
+            "N1" => Ok(Self::N1),
+            "P1" => Ok(Self::P1),
+            "P2" => Ok(Self::P2),
+            "P3" => Ok(Self::P3),
+            _ => Err(UnknownEnumVariant::new("ModificationQuality", s)),
+        }
+    }
+}
+impl std::ops::BitOr for ModificationQuality {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for ModificationQuality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/ObjectPrefabType.xml
 #[repr(i32)]
@@ -771,6 +2005,51 @@ impl DatabaseItem for ObjectPrefabType {
         "ObjectPrefabType"
     }
 }
+impl ObjectPrefabType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 4] = [
+        Self::Undefined,
+        Self::WormTailSegment,
+        Self::CircularSpriteObject,
+        Self::CircularOutlineObject,
+    ];
+    pub const fn variant_count() -> usize {
+        4
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::WormTailSegment => "WormTailSegment",
+            Self::CircularSpriteObject => "CircularSpriteObject",
+            Self::CircularOutlineObject => "CircularOutlineObject",
+        }
+    }
+}
+impl std::str::FromStr for ObjectPrefabType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Undefined" => Ok(Self::Undefined),
+            "WormTailSegment" => Ok(Self::WormTailSegment),
+            "CircularSpriteObject" => Ok(Self::CircularSpriteObject),
+            "CircularOutlineObject" => Ok(Self::CircularOutlineObject),
+            _ => Err(UnknownEnumVariant::new("ObjectPrefabType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for ObjectPrefabType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for ObjectPrefabType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Quests/FactionFilterType.xml
 #[repr(i32)]
@@ -800,6 +2079,51 @@ impl DatabaseItem for FactionFilterType {
         "FactionFilterType"
     }
 }
+impl FactionFilterType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 4] = [
+        Self::AllButList,
+        Self::ListOnly,
+        Self::StarOwnersAndList,
+        Self::AllAvailable,
+    ];
+    pub const fn variant_count() -> usize {
+        4
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::AllButList => "AllButList",
+            Self::ListOnly => "ListOnly",
+            Self::StarOwnersAndList => "StarOwnersAndList",
+            Self::AllAvailable => "AllAvailable",
+        }
+    }
+}
+impl std::str::FromStr for FactionFilterType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AllButList" => Ok(Self::AllButList),
+            "ListOnly" => Ok(Self::ListOnly),
+            "StarOwnersAndList" => Ok(Self::StarOwnersAndList),
+            "AllAvailable" => Ok(Self::AllAvailable),
+            _ => Err(UnknownEnumVariant::new("FactionFilterType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for FactionFilterType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for FactionFilterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Quests/LootItemType.xml
 #[repr(i32)]
@@ -842,6 +2166,90 @@ impl DatabaseItem for LootItemType {
         "LootItemType"
     }
 }
+impl LootItemType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 17] = [
+        Self::None,
+        Self::SomeMoney,
+        Self::Fuel,
+        Self::Money,
+        Self::Stars,
+        Self::StarMap,
+        Self::RandomComponents,
+        Self::RandomItems,
+        Self::AllItems,
+        Self::ItemsWithChance,
+        Self::QuestItem,
+        Self::Ship,
+        Self::EmptyShip,
+        Self::Component,
+        Self::Blueprint,
+        Self::ResearchPoints,
+        Self::Satellite,
+    ];
+    pub const fn variant_count() -> usize {
+        17
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::SomeMoney => "SomeMoney",
+            Self::Fuel => "Fuel",
+            Self::Money => "Money",
+            Self::Stars => "Stars",
+            Self::StarMap => "StarMap",
+            Self::RandomComponents => "RandomComponents",
+            Self::RandomItems => "RandomItems",
+            Self::AllItems => "AllItems",
+            Self::ItemsWithChance => "ItemsWithChance",
+            Self::QuestItem => "QuestItem",
+            Self::Ship => "Ship",
+            Self::EmptyShip => "EmptyShip",
+            Self::Component => "Component",
+            Self::Blueprint => "Blueprint",
+            Self::ResearchPoints => "ResearchPoints",
+            Self::Satellite => "Satellite",
+        }
+    }
+}
+impl std::str::FromStr for LootItemType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "None" => Ok(Self::None),
+            "SomeMoney" => Ok(Self::SomeMoney),
+            "Fuel" => Ok(Self::Fuel),
+            "Money" => Ok(Self::Money),
+            "Stars" => Ok(Self::Stars),
+            "StarMap" => Ok(Self::StarMap),
+            "RandomComponents" => Ok(Self::RandomComponents),
+            "RandomItems" => Ok(Self::RandomItems),
+            "AllItems" => Ok(Self::AllItems),
+            "ItemsWithChance" => Ok(Self::ItemsWithChance),
+            "QuestItem" => Ok(Self::QuestItem),
+            "Ship" => Ok(Self::Ship),
+            "EmptyShip" => Ok(Self::EmptyShip),
+            "Component" => Ok(Self::Component),
+            "Blueprint" => Ok(Self::Blueprint),
+            "ResearchPoints" => Ok(Self::ResearchPoints),
+            "Satellite" => Ok(Self::Satellite),
+            _ => Err(UnknownEnumVariant::new("LootItemType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for LootItemType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for LootItemType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Quests/NodeType.xml
 #[repr(i32)]
@@ -897,6 +2305,129 @@ impl DatabaseItem for NodeType {
         "NodeType"
     }
 }
+impl NodeType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 30] = [
+        Self::Undefined,
+        Self::ComingSoon,
+        Self::ShowDialog,
+        Self::OpenShipyard,
+        Self::OpenWorkshop,
+        Self::Switch,
+        Self::Random,
+        Self::Condition,
+        Self::AttackFleet,
+        Self::AttackOccupants,
+        Self::AttackStarbase,
+        Self::DestroyOccupants,
+        Self::SuppressOccupants,
+        Self::Retreat,
+        Self::ReceiveItem,
+        Self::RemoveItem,
+        Self::Trade,
+        Self::CompleteQuest,
+        Self::FailQuest,
+        Self::CancelQuest,
+        Self::StartQuest,
+        Self::SetCharacterRelations,
+        Self::SetFactionRelations,
+        Self::SetFactionStarbasePower,
+        Self::ChangeCharacterRelations,
+        Self::ChangeFactionRelations,
+        Self::ChangeFactionStarbasePower,
+        Self::CaptureStarBase,
+        Self::LiberateStarBase,
+        Self::ChangeFaction,
+    ];
+    pub const fn variant_count() -> usize {
+        30
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::ComingSoon => "ComingSoon",
+            Self::ShowDialog => "ShowDialog",
+            Self::OpenShipyard => "OpenShipyard",
+            Self::OpenWorkshop => "OpenWorkshop",
+            Self::Switch => "Switch",
+            Self::Random => "Random",
+            Self::Condition => "Condition",
+            Self::AttackFleet => "AttackFleet",
+            Self::AttackOccupants => "AttackOccupants",
+            Self::AttackStarbase => "AttackStarbase",
+            Self::DestroyOccupants => "DestroyOccupants",
+            Self::SuppressOccupants => "SuppressOccupants",
+            Self::Retreat => "Retreat",
+            Self::ReceiveItem => "ReceiveItem",
+            Self::RemoveItem => "RemoveItem",
+            Self::Trade => "Trade",
+            Self::CompleteQuest => "CompleteQuest",
+            Self::FailQuest => "FailQuest",
+            Self::CancelQuest => "CancelQuest",
+            Self::StartQuest => "StartQuest",
+            Self::SetCharacterRelations => "SetCharacterRelations",
+            Self::SetFactionRelations => "SetFactionRelations",
+            Self::SetFactionStarbasePower => "SetFactionStarbasePower",
+            Self::ChangeCharacterRelations => "ChangeCharacterRelations",
+            Self::ChangeFactionRelations => "ChangeFactionRelations",
+            Self::ChangeFactionStarbasePower => "ChangeFactionStarbasePower",
+            Self::CaptureStarBase => "CaptureStarBase",
+            Self::LiberateStarBase => "LiberateStarBase",
+            Self::ChangeFaction => "ChangeFaction",
+        }
+    }
+}
+impl std::str::FromStr for NodeType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Undefined" => Ok(Self::Undefined),
+            "ComingSoon" => Ok(Self::ComingSoon),
+            "ShowDialog" => Ok(Self::ShowDialog),
+            "OpenShipyard" => Ok(Self::OpenShipyard),
+            "OpenWorkshop" => Ok(Self::OpenWorkshop),
+            "Switch" => Ok(Self::Switch),
+            "Random" => Ok(Self::Random),
+            "Condition" => Ok(Self::Condition),
+            "AttackFleet" => Ok(Self::AttackFleet),
+            "AttackOccupants" => Ok(Self::AttackOccupants),
+            "AttackStarbase" => Ok(Self::AttackStarbase),
+            "DestroyOccupants" => Ok(Self::DestroyOccupants),
+            "SuppressOccupants" => Ok(Self::SuppressOccupants),
+            "Retreat" => Ok(Self::Retreat),
+            "ReceiveItem" => Ok(Self::ReceiveItem),
+            "RemoveItem" => Ok(Self::RemoveItem),
+            "Trade" => Ok(Self::Trade),
+            "CompleteQuest" => Ok(Self::CompleteQuest),
+            "FailQuest" => Ok(Self::FailQuest),
+            "CancelQuest" => Ok(Self::CancelQuest),
+            "StartQuest" => Ok(Self::StartQuest),
+            "SetCharacterRelations" => Ok(Self::SetCharacterRelations),
+            "SetFactionRelations" => Ok(Self::SetFactionRelations),
+            "SetFactionStarbasePower" => Ok(Self::SetFactionStarbasePower),
+            "ChangeCharacterRelations" => Ok(Self::ChangeCharacterRelations),
+            "ChangeFactionRelations" => Ok(Self::ChangeFactionRelations),
+            "ChangeFactionStarbasePower" => Ok(Self::ChangeFactionStarbasePower),
+            "CaptureStarBase" => Ok(Self::CaptureStarBase),
+            "LiberateStarBase" => Ok(Self::LiberateStarBase),
+            "ChangeFaction" => Ok(Self::ChangeFaction),
+            _ => Err(UnknownEnumVariant::new("NodeType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for NodeType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for NodeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Quests/PlayerShipSelectionMode.xml
 #[repr(i32)]
@@ -929,6 +2460,51 @@ impl DatabaseItem for PlayerShipSelectionMode {
         "PlayerShipSelectionMode"
     }
 }
+impl PlayerShipSelectionMode {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 4] = [
+        Self::Default,
+        Self::OnlyOneShip,
+        Self::ByOrder,
+        Self::NoRetreats,
+    ];
+    pub const fn variant_count() -> usize {
+        4
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::OnlyOneShip => "OnlyOneShip",
+            Self::ByOrder => "ByOrder",
+            Self::NoRetreats => "NoRetreats",
+        }
+    }
+}
+impl std::str::FromStr for PlayerShipSelectionMode {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Default" => Ok(Self::Default),
+            "OnlyOneShip" => Ok(Self::OnlyOneShip),
+            "ByOrder" => Ok(Self::ByOrder),
+            "NoRetreats" => Ok(Self::NoRetreats),
+            _ => Err(UnknownEnumVariant::new("PlayerShipSelectionMode", s)),
+        }
+    }
+}
+impl std::ops::BitOr for PlayerShipSelectionMode {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for PlayerShipSelectionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Quests/QuestOriginType.xml
 #[repr(i32)]
@@ -959,6 +2535,54 @@ impl DatabaseItem for QuestOriginType {
         "QuestOriginType"
     }
 }
+impl QuestOriginType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 5] = [
+        Self::CurrentStar,
+        Self::CurrentFactionBase,
+        Self::RandomFactionBase,
+        Self::HomeStar,
+        Self::RandomStar,
+    ];
+    pub const fn variant_count() -> usize {
+        5
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CurrentStar => "CurrentStar",
+            Self::CurrentFactionBase => "CurrentFactionBase",
+            Self::RandomFactionBase => "RandomFactionBase",
+            Self::HomeStar => "HomeStar",
+            Self::RandomStar => "RandomStar",
+        }
+    }
+}
+impl std::str::FromStr for QuestOriginType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "CurrentStar" => Ok(Self::CurrentStar),
+            "CurrentFactionBase" => Ok(Self::CurrentFactionBase),
+            "RandomFactionBase" => Ok(Self::RandomFactionBase),
+            "HomeStar" => Ok(Self::HomeStar),
+            "RandomStar" => Ok(Self::RandomStar),
+            _ => Err(UnknownEnumVariant::new("QuestOriginType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for QuestOriginType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for QuestOriginType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Quests/QuestType.xml
 #[repr(i32)]
@@ -989,6 +2613,54 @@ impl DatabaseItem for QuestType {
         "QuestType"
     }
 }
+impl QuestType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 5] = [
+        Self::Common,
+        Self::Singleton,
+        Self::Storyline,
+        Self::Temporary,
+        Self::Urgent,
+    ];
+    pub const fn variant_count() -> usize {
+        5
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Common => "Common",
+            Self::Singleton => "Singleton",
+            Self::Storyline => "Storyline",
+            Self::Temporary => "Temporary",
+            Self::Urgent => "Urgent",
+        }
+    }
+}
+impl std::str::FromStr for QuestType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Common" => Ok(Self::Common),
+            "Singleton" => Ok(Self::Singleton),
+            "Storyline" => Ok(Self::Storyline),
+            "Temporary" => Ok(Self::Temporary),
+            "Urgent" => Ok(Self::Urgent),
+            _ => Err(UnknownEnumVariant::new("QuestType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for QuestType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for QuestType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Quests/RequiredViewMode.xml
 #[repr(i32)]
@@ -1018,6 +2690,47 @@ impl DatabaseItem for RequiredViewMode {
         "RequiredViewMode"
     }
 }
+impl RequiredViewMode {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 4] =
+        [Self::Any, Self::StarSystem, Self::StarMap, Self::GalaxyMap];
+    pub const fn variant_count() -> usize {
+        4
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Any => "Any",
+            Self::StarSystem => "StarSystem",
+            Self::StarMap => "StarMap",
+            Self::GalaxyMap => "GalaxyMap",
+        }
+    }
+}
+impl std::str::FromStr for RequiredViewMode {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Any" => Ok(Self::Any),
+            "StarSystem" => Ok(Self::StarSystem),
+            "StarMap" => Ok(Self::StarMap),
+            "GalaxyMap" => Ok(Self::GalaxyMap),
+            _ => Err(UnknownEnumVariant::new("RequiredViewMode", s)),
+        }
+    }
+}
+impl std::ops::BitOr for RequiredViewMode {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for RequiredViewMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Quests/RequirementType.xml
 #[repr(i32)]
@@ -1064,6 +2777,102 @@ impl DatabaseItem for RequirementType {
         "RequirementType"
     }
 }
+impl RequirementType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 21] = [
+        Self::Empty,
+        Self::Any,
+        Self::All,
+        Self::None,
+        Self::PlayerPosition,
+        Self::RandomStarSystem,
+        Self::AggressiveOccupants,
+        Self::QuestCompleted,
+        Self::QuestActive,
+        Self::CharacterRelations,
+        Self::FactionRelations,
+        Self::StarbaseCaptured,
+        Self::FactionStarbasePower,
+        Self::IsHostileFaction,
+        Self::Faction,
+        Self::HaveQuestItem,
+        Self::HaveItem,
+        Self::HaveItemById,
+        Self::ComeToOrigin,
+        Self::TimeSinceQuestStart,
+        Self::TimeSinceLastCompletion,
+    ];
+    pub const fn variant_count() -> usize {
+        21
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Empty => "Empty",
+            Self::Any => "Any",
+            Self::All => "All",
+            Self::None => "None",
+            Self::PlayerPosition => "PlayerPosition",
+            Self::RandomStarSystem => "RandomStarSystem",
+            Self::AggressiveOccupants => "AggressiveOccupants",
+            Self::QuestCompleted => "QuestCompleted",
+            Self::QuestActive => "QuestActive",
+            Self::CharacterRelations => "CharacterRelations",
+            Self::FactionRelations => "FactionRelations",
+            Self::StarbaseCaptured => "StarbaseCaptured",
+            Self::FactionStarbasePower => "FactionStarbasePower",
+            Self::IsHostileFaction => "IsHostileFaction",
+            Self::Faction => "Faction",
+            Self::HaveQuestItem => "HaveQuestItem",
+            Self::HaveItem => "HaveItem",
+            Self::HaveItemById => "HaveItemById",
+            Self::ComeToOrigin => "ComeToOrigin",
+            Self::TimeSinceQuestStart => "TimeSinceQuestStart",
+            Self::TimeSinceLastCompletion => "TimeSinceLastCompletion",
+        }
+    }
+}
+impl std::str::FromStr for RequirementType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Empty" => Ok(Self::Empty),
+            "Any" => Ok(Self::Any),
+            "All" => Ok(Self::All),
+            "None" => Ok(Self::None),
+            "PlayerPosition" => Ok(Self::PlayerPosition),
+            "RandomStarSystem" => Ok(Self::RandomStarSystem),
+            "AggressiveOccupants" => Ok(Self::AggressiveOccupants),
+            "QuestCompleted" => Ok(Self::QuestCompleted),
+            "QuestActive" => Ok(Self::QuestActive),
+            "CharacterRelations" => Ok(Self::CharacterRelations),
+            "FactionRelations" => Ok(Self::FactionRelations),
+            "StarbaseCaptured" => Ok(Self::StarbaseCaptured),
+            "FactionStarbasePower" => Ok(Self::FactionStarbasePower),
+            "IsHostileFaction" => Ok(Self::IsHostileFaction),
+            "Faction" => Ok(Self::Faction),
+            "HaveQuestItem" => Ok(Self::HaveQuestItem),
+            "HaveItem" => Ok(Self::HaveItem),
+            "HaveItemById" => Ok(Self::HaveItemById),
+            "ComeToOrigin" => Ok(Self::ComeToOrigin),
+            "TimeSinceQuestStart" => Ok(Self::TimeSinceQuestStart),
+            "TimeSinceLastCompletion" => Ok(Self::TimeSinceLastCompletion),
+            _ => Err(UnknownEnumVariant::new("RequirementType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for RequirementType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for RequirementType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Quests/RewardCondition.xml
 #[repr(i32)]
@@ -1092,6 +2901,44 @@ impl DatabaseItem for RewardCondition {
         "RewardCondition"
     }
 }
+impl RewardCondition {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 3] = [Self::Default, Self::Always, Self::Never];
+    pub const fn variant_count() -> usize {
+        3
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Always => "Always",
+            Self::Never => "Never",
+        }
+    }
+}
+impl std::str::FromStr for RewardCondition {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Default" => Ok(Self::Default),
+            "Always" => Ok(Self::Always),
+            "Never" => Ok(Self::Never),
+            _ => Err(UnknownEnumVariant::new("RewardCondition", s)),
+        }
+    }
+}
+impl std::ops::BitOr for RewardCondition {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for RewardCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Quests/StartCondition.xml
 #[repr(i32)]
@@ -1125,6 +2972,63 @@ impl DatabaseItem for StartCondition {
         "StartCondition"
     }
 }
+impl StartCondition {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 8] = [
+        Self::Manual,
+        Self::Beacon,
+        Self::LocalEncounter,
+        Self::FactionMission,
+        Self::GameStart,
+        Self::NewStarExplored,
+        Self::ArrivedAtStar,
+        Self::Daily,
+    ];
+    pub const fn variant_count() -> usize {
+        8
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Manual => "Manual",
+            Self::Beacon => "Beacon",
+            Self::LocalEncounter => "LocalEncounter",
+            Self::FactionMission => "FactionMission",
+            Self::GameStart => "GameStart",
+            Self::NewStarExplored => "NewStarExplored",
+            Self::ArrivedAtStar => "ArrivedAtStar",
+            Self::Daily => "Daily",
+        }
+    }
+}
+impl std::str::FromStr for StartCondition {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Manual" => Ok(Self::Manual),
+            "Beacon" => Ok(Self::Beacon),
+            "LocalEncounter" => Ok(Self::LocalEncounter),
+            "FactionMission" => Ok(Self::FactionMission),
+            "GameStart" => Ok(Self::GameStart),
+            "NewStarExplored" => Ok(Self::NewStarExplored),
+            "ArrivedAtStar" => Ok(Self::ArrivedAtStar),
+            "Daily" => Ok(Self::Daily),
+            _ => Err(UnknownEnumVariant::new("StartCondition", s)),
+        }
+    }
+}
+impl std::ops::BitOr for StartCondition {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for StartCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Quests/TimeOutMode.xml
 #[repr(i32)]
@@ -1154,6 +3058,48 @@ impl DatabaseItem for TimeOutMode {
         "TimeOutMode"
     }
 }
+impl TimeOutMode {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 3] = [
+        Self::CallNextEnemy,
+        Self::DrainPlayerHp,
+        Self::CallNextEnemyOrDraw,
+    ];
+    pub const fn variant_count() -> usize {
+        3
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CallNextEnemy => "CallNextEnemy",
+            Self::DrainPlayerHp => "DrainPlayerHp",
+            Self::CallNextEnemyOrDraw => "CallNextEnemyOrDraw",
+        }
+    }
+}
+impl std::str::FromStr for TimeOutMode {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "CallNextEnemy" => Ok(Self::CallNextEnemy),
+            "DrainPlayerHp" => Ok(Self::DrainPlayerHp),
+            "CallNextEnemyOrDraw" => Ok(Self::CallNextEnemyOrDraw),
+            _ => Err(UnknownEnumVariant::new("TimeOutMode", s)),
+        }
+    }
+}
+impl std::ops::BitOr for TimeOutMode {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for TimeOutMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/ShipPerkType.xml
 #[repr(i32)]
@@ -1191,6 +3137,75 @@ impl DatabaseItem for ShipPerkType {
         "ShipPerkType"
     }
 }
+impl ShipPerkType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 12] = [
+        Self::Empty,
+        Self::AutoTargeting,
+        Self::HeatDefense,
+        Self::KineticDefense,
+        Self::EnergyDefense,
+        Self::LightWeight,
+        Self::Infected,
+        Self::UnlimitedRespawn,
+        Self::WeaponClass,
+        Self::SatelliteSize,
+        Self::EnergyRechargeCooldown,
+        Self::ShieldRechargeCooldown,
+    ];
+    pub const fn variant_count() -> usize {
+        12
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Empty => "Empty",
+            Self::AutoTargeting => "AutoTargeting",
+            Self::HeatDefense => "HeatDefense",
+            Self::KineticDefense => "KineticDefense",
+            Self::EnergyDefense => "EnergyDefense",
+            Self::LightWeight => "LightWeight",
+            Self::Infected => "Infected",
+            Self::UnlimitedRespawn => "UnlimitedRespawn",
+            Self::WeaponClass => "WeaponClass",
+            Self::SatelliteSize => "SatelliteSize",
+            Self::EnergyRechargeCooldown => "EnergyRechargeCooldown",
+            Self::ShieldRechargeCooldown => "ShieldRechargeCooldown",
+        }
+    }
+}
+impl std::str::FromStr for ShipPerkType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Empty" => Ok(Self::Empty),
+            "AutoTargeting" => Ok(Self::AutoTargeting),
+            "HeatDefense" => Ok(Self::HeatDefense),
+            "KineticDefense" => Ok(Self::KineticDefense),
+            "EnergyDefense" => Ok(Self::EnergyDefense),
+            "LightWeight" => Ok(Self::LightWeight),
+            "Infected" => Ok(Self::Infected),
+            "UnlimitedRespawn" => Ok(Self::UnlimitedRespawn),
+            "WeaponClass" => Ok(Self::WeaponClass),
+            "SatelliteSize" => Ok(Self::SatelliteSize),
+            "EnergyRechargeCooldown" => Ok(Self::EnergyRechargeCooldown),
+            "ShieldRechargeCooldown" => Ok(Self::ShieldRechargeCooldown),
+            _ => Err(UnknownEnumVariant::new("ShipPerkType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for ShipPerkType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for ShipPerkType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/ShipRarity.xml
 #[repr(i32)]
@@ -1220,6 +3235,46 @@ impl DatabaseItem for ShipRarity {
         "ShipRarity"
     }
 }
+impl ShipRarity {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 4] = [Self::Normal, Self::Rare, Self::Hidden, Self::Unique];
+    pub const fn variant_count() -> usize {
+        4
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Normal => "Normal",
+            Self::Rare => "Rare",
+            Self::Hidden => "Hidden",
+            Self::Unique => "Unique",
+        }
+    }
+}
+impl std::str::FromStr for ShipRarity {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Normal" => Ok(Self::Normal),
+            "Rare" => Ok(Self::Rare),
+            "Hidden" => Ok(Self::Hidden),
+            "Unique" => Ok(Self::Unique),
+            _ => Err(UnknownEnumVariant::new("ShipRarity", s)),
+        }
+    }
+}
+impl std::ops::BitOr for ShipRarity {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for ShipRarity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/ShipType.xml
 #[repr(i32)]
@@ -1250,6 +3305,54 @@ impl DatabaseItem for ShipType {
         "ShipType"
     }
 }
+impl ShipType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 5] = [
+        Self::Common,
+        Self::Drone,
+        Self::Starbase,
+        Self::Special,
+        Self::Flagship,
+    ];
+    pub const fn variant_count() -> usize {
+        5
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Common => "Common",
+            Self::Drone => "Drone",
+            Self::Starbase => "Starbase",
+            Self::Special => "Special",
+            Self::Flagship => "Flagship",
+        }
+    }
+}
+impl std::str::FromStr for ShipType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Common" => Ok(Self::Common),
+            "Drone" => Ok(Self::Drone),
+            "Starbase" => Ok(Self::Starbase),
+            "Special" => Ok(Self::Special),
+            "Flagship" => Ok(Self::Flagship),
+            _ => Err(UnknownEnumVariant::new("ShipType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for ShipType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for ShipType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/SizeClass.xml
 #[repr(i32)]
@@ -1282,6 +3385,60 @@ impl DatabaseItem for SizeClass {
         "SizeClass"
     }
 }
+impl SizeClass {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 7] = [
+        Self::Undefined,
+        Self::Frigate,
+        Self::Destroyer,
+        Self::Cruiser,
+        Self::Battleship,
+        Self::Titan,
+        Self::Starbase,
+    ];
+    pub const fn variant_count() -> usize {
+        7
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::Frigate => "Frigate",
+            Self::Destroyer => "Destroyer",
+            Self::Cruiser => "Cruiser",
+            Self::Battleship => "Battleship",
+            Self::Titan => "Titan",
+            Self::Starbase => "Starbase",
+        }
+    }
+}
+impl std::str::FromStr for SizeClass {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Undefined" => Ok(Self::Undefined),
+            "Frigate" => Ok(Self::Frigate),
+            "Destroyer" => Ok(Self::Destroyer),
+            "Cruiser" => Ok(Self::Cruiser),
+            "Battleship" => Ok(Self::Battleship),
+            "Titan" => Ok(Self::Titan),
+            "Starbase" => Ok(Self::Starbase),
+            _ => Err(UnknownEnumVariant::new("SizeClass", s)),
+        }
+    }
+}
+impl std::ops::BitOr for SizeClass {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for SizeClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/SkillType.xml
 #[repr(i32)]
@@ -1318,6 +3475,72 @@ impl DatabaseItem for SkillType {
         "SkillType"
     }
 }
+impl SkillType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 11] = [
+        Self::Undefined,
+        Self::ShipAttack,
+        Self::ShipDefense,
+        Self::StarbaseAttack,
+        Self::StarbaseDefense,
+        Self::QuickLearning,
+        Self::BetterPrices,
+        Self::BetterLoot,
+        Self::CommandPoints,
+        Self::SalvageDrones,
+        Self::Engineer,
+    ];
+    pub const fn variant_count() -> usize {
+        11
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::ShipAttack => "ShipAttack",
+            Self::ShipDefense => "ShipDefense",
+            Self::StarbaseAttack => "StarbaseAttack",
+            Self::StarbaseDefense => "StarbaseDefense",
+            Self::QuickLearning => "QuickLearning",
+            Self::BetterPrices => "BetterPrices",
+            Self::BetterLoot => "BetterLoot",
+            Self::CommandPoints => "CommandPoints",
+            Self::SalvageDrones => "SalvageDrones",
+            Self::Engineer => "Engineer",
+        }
+    }
+}
+impl std::str::FromStr for SkillType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Undefined" => Ok(Self::Undefined),
+            "ShipAttack" => Ok(Self::ShipAttack),
+            "ShipDefense" => Ok(Self::ShipDefense),
+            "StarbaseAttack" => Ok(Self::StarbaseAttack),
+            "StarbaseDefense" => Ok(Self::StarbaseDefense),
+            "QuickLearning" => Ok(Self::QuickLearning),
+            "BetterPrices" => Ok(Self::BetterPrices),
+            "BetterLoot" => Ok(Self::BetterLoot),
+            "CommandPoints" => Ok(Self::CommandPoints),
+            "SalvageDrones" => Ok(Self::SalvageDrones),
+            "Engineer" => Ok(Self::Engineer),
+            _ => Err(UnknownEnumVariant::new("SkillType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for SkillType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for SkillType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/StatModificationType.xml
 #[repr(i32)]
@@ -1369,6 +3592,117 @@ impl DatabaseItem for StatModificationType {
         "StatModificationType"
     }
 }
+impl StatModificationType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 26] = [
+        Self::None,
+        Self::WeaponDamage,
+        Self::WeaponRange,
+        Self::WeaponFireRate,
+        Self::WeaponBulletSpeed,
+        Self::WeaponBulletMass,
+        Self::WeaponAoe,
+        Self::DroneAttack,
+        Self::DroneDefense,
+        Self::DroneSpeed,
+        Self::DroneRange,
+        Self::EnergyCapacity,
+        Self::EnergyRechargeRate,
+        Self::ShieldPoints,
+        Self::ShieldRechargeRate,
+        Self::ArmorPoints,
+        Self::ArmorRepairRate,
+        Self::Resistance,
+        Self::DeviceCooldown,
+        Self::DeviceRange,
+        Self::DevicePower,
+        Self::EnginePower,
+        Self::EngineTurnRate,
+        Self::Mass,
+        Self::EnergyCost,
+        Self::ExtraHitPoints,
+    ];
+    pub const fn variant_count() -> usize {
+        26
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::WeaponDamage => "WeaponDamage",
+            Self::WeaponRange => "WeaponRange",
+            Self::WeaponFireRate => "WeaponFireRate",
+            Self::WeaponBulletSpeed => "WeaponBulletSpeed",
+            Self::WeaponBulletMass => "WeaponBulletMass",
+            Self::WeaponAoe => "WeaponAoe",
+            Self::DroneAttack => "DroneAttack",
+            Self::DroneDefense => "DroneDefense",
+            Self::DroneSpeed => "DroneSpeed",
+            Self::DroneRange => "DroneRange",
+            Self::EnergyCapacity => "EnergyCapacity",
+            Self::EnergyRechargeRate => "EnergyRechargeRate",
+            Self::ShieldPoints => "ShieldPoints",
+            Self::ShieldRechargeRate => "ShieldRechargeRate",
+            Self::ArmorPoints => "ArmorPoints",
+            Self::ArmorRepairRate => "ArmorRepairRate",
+            Self::Resistance => "Resistance",
+            Self::DeviceCooldown => "DeviceCooldown",
+            Self::DeviceRange => "DeviceRange",
+            Self::DevicePower => "DevicePower",
+            Self::EnginePower => "EnginePower",
+            Self::EngineTurnRate => "EngineTurnRate",
+            Self::Mass => "Mass",
+            Self::EnergyCost => "EnergyCost",
+            Self::ExtraHitPoints => "ExtraHitPoints",
+        }
+    }
+}
+impl std::str::FromStr for StatModificationType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "None" => Ok(Self::None),
+            "WeaponDamage" => Ok(Self::WeaponDamage),
+            "WeaponRange" => Ok(Self::WeaponRange),
+            "WeaponFireRate" => Ok(Self::WeaponFireRate),
+            "WeaponBulletSpeed" => Ok(Self::WeaponBulletSpeed),
+            "WeaponBulletMass" => Ok(Self::WeaponBulletMass),
+            "WeaponAoe" => Ok(Self::WeaponAoe),
+            "DroneAttack" => Ok(Self::DroneAttack),
+            "DroneDefense" => Ok(Self::DroneDefense),
+            "DroneSpeed" => Ok(Self::DroneSpeed),
+            "DroneRange" => Ok(Self::DroneRange),
+            "EnergyCapacity" => Ok(Self::EnergyCapacity),
+            "EnergyRechargeRate" => Ok(Self::EnergyRechargeRate),
+            "ShieldPoints" => Ok(Self::ShieldPoints),
+            "ShieldRechargeRate" => Ok(Self::ShieldRechargeRate),
+            "ArmorPoints" => Ok(Self::ArmorPoints),
+            "ArmorRepairRate" => Ok(Self::ArmorRepairRate),
+            "Resistance" => Ok(Self::Resistance),
+            "DeviceCooldown" => Ok(Self::DeviceCooldown),
+            "DeviceRange" => Ok(Self::DeviceRange),
+            "DevicePower" => Ok(Self::DevicePower),
+            "EnginePower" => Ok(Self::EnginePower),
+            "EngineTurnRate" => Ok(Self::EngineTurnRate),
+            "Mass" => Ok(Self::Mass),
+            "EnergyCost" => Ok(Self::EnergyCost),
+            "ExtraHitPoints" => Ok(Self::ExtraHitPoints),
+            _ => Err(UnknownEnumVariant::new("StatModificationType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for StatModificationType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for StatModificationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/TechType.xml
 #[repr(i32)]
@@ -1397,6 +3731,44 @@ impl DatabaseItem for TechType {
         "TechType"
     }
 }
+impl TechType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 3] = [Self::Component, Self::Ship, Self::Satellite];
+    pub const fn variant_count() -> usize {
+        3
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Component => "Component",
+            Self::Ship => "Ship",
+            Self::Satellite => "Satellite",
+        }
+    }
+}
+impl std::str::FromStr for TechType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Component" => Ok(Self::Component),
+            "Ship" => Ok(Self::Ship),
+            "Satellite" => Ok(Self::Satellite),
+            _ => Err(UnknownEnumVariant::new("TechType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for TechType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for TechType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/ToggleState.xml
 #[repr(i32)]
@@ -1425,6 +3797,44 @@ impl DatabaseItem for ToggleState {
         "ToggleState"
     }
 }
+impl ToggleState {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 3] = [Self::Default, Self::Enabled, Self::Disabled];
+    pub const fn variant_count() -> usize {
+        3
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Enabled => "Enabled",
+            Self::Disabled => "Disabled",
+        }
+    }
+}
+impl std::str::FromStr for ToggleState {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Default" => Ok(Self::Default),
+            "Enabled" => Ok(Self::Enabled),
+            "Disabled" => Ok(Self::Disabled),
+            _ => Err(UnknownEnumVariant::new("ToggleState", s)),
+        }
+    }
+}
+impl std::ops::BitOr for ToggleState {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for ToggleState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Weapon/AiBulletBehavior.xml
 #[repr(i32)]
@@ -1456,6 +3866,57 @@ impl DatabaseItem for AiBulletBehavior {
         "AiBulletBehavior"
     }
 }
+impl AiBulletBehavior {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 6] = [
+        Self::Projectile,
+        Self::Homing,
+        Self::Beam,
+        Self::AreaOfEffect,
+        Self::Trap,
+        Self::Harpoon,
+    ];
+    pub const fn variant_count() -> usize {
+        6
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Projectile => "Projectile",
+            Self::Homing => "Homing",
+            Self::Beam => "Beam",
+            Self::AreaOfEffect => "AreaOfEffect",
+            Self::Trap => "Trap",
+            Self::Harpoon => "Harpoon",
+        }
+    }
+}
+impl std::str::FromStr for AiBulletBehavior {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Projectile" => Ok(Self::Projectile),
+            "Homing" => Ok(Self::Homing),
+            "Beam" => Ok(Self::Beam),
+            "AreaOfEffect" => Ok(Self::AreaOfEffect),
+            "Trap" => Ok(Self::Trap),
+            "Harpoon" => Ok(Self::Harpoon),
+            _ => Err(UnknownEnumVariant::new("AiBulletBehavior", s)),
+        }
+    }
+}
+impl std::ops::BitOr for AiBulletBehavior {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for AiBulletBehavior {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Weapon/BulletControllerType.xml
 #[repr(i32)]
@@ -1487,6 +3948,57 @@ impl DatabaseItem for BulletControllerType {
         "BulletControllerType"
     }
 }
+impl BulletControllerType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 6] = [
+        Self::Projectile,
+        Self::Homing,
+        Self::Beam,
+        Self::Parametric,
+        Self::Harpoon,
+        Self::AuraEmitter,
+    ];
+    pub const fn variant_count() -> usize {
+        6
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Projectile => "Projectile",
+            Self::Homing => "Homing",
+            Self::Beam => "Beam",
+            Self::Parametric => "Parametric",
+            Self::Harpoon => "Harpoon",
+            Self::AuraEmitter => "AuraEmitter",
+        }
+    }
+}
+impl std::str::FromStr for BulletControllerType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Projectile" => Ok(Self::Projectile),
+            "Homing" => Ok(Self::Homing),
+            "Beam" => Ok(Self::Beam),
+            "Parametric" => Ok(Self::Parametric),
+            "Harpoon" => Ok(Self::Harpoon),
+            "AuraEmitter" => Ok(Self::AuraEmitter),
+            _ => Err(UnknownEnumVariant::new("BulletControllerType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for BulletControllerType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for BulletControllerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Weapon/BulletEffectType.xml
 #[repr(i32)]
@@ -1518,6 +4030,57 @@ impl DatabaseItem for BulletEffectType {
         "BulletEffectType"
     }
 }
+impl BulletEffectType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 6] = [
+        Self::None,
+        Self::PlaySfx,
+        Self::SpawnBullet,
+        Self::Detonate,
+        Self::SpawnStaticSfx,
+        Self::GravityField,
+    ];
+    pub const fn variant_count() -> usize {
+        6
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::PlaySfx => "PlaySfx",
+            Self::SpawnBullet => "SpawnBullet",
+            Self::Detonate => "Detonate",
+            Self::SpawnStaticSfx => "SpawnStaticSfx",
+            Self::GravityField => "GravityField",
+        }
+    }
+}
+impl std::str::FromStr for BulletEffectType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "None" => Ok(Self::None),
+            "PlaySfx" => Ok(Self::PlaySfx),
+            "SpawnBullet" => Ok(Self::SpawnBullet),
+            "Detonate" => Ok(Self::Detonate),
+            "SpawnStaticSfx" => Ok(Self::SpawnStaticSfx),
+            "GravityField" => Ok(Self::GravityField),
+            _ => Err(UnknownEnumVariant::new("BulletEffectType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for BulletEffectType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for BulletEffectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Weapon/BulletImpactType.xml
 #[repr(i32)]
@@ -1546,6 +4109,48 @@ impl DatabaseItem for BulletImpactType {
         "BulletImpactType"
     }
 }
+impl BulletImpactType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 3] = [
+        Self::HitFirstTarget,
+        Self::HitAllTargets,
+        Self::DamageOverTime,
+    ];
+    pub const fn variant_count() -> usize {
+        3
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::HitFirstTarget => "HitFirstTarget",
+            Self::HitAllTargets => "HitAllTargets",
+            Self::DamageOverTime => "DamageOverTime",
+        }
+    }
+}
+impl std::str::FromStr for BulletImpactType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "HitFirstTarget" => Ok(Self::HitFirstTarget),
+            "HitAllTargets" => Ok(Self::HitAllTargets),
+            "DamageOverTime" => Ok(Self::DamageOverTime),
+            _ => Err(UnknownEnumVariant::new("BulletImpactType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for BulletImpactType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for BulletImpactType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Weapon/BulletShape.xml
 #[repr(i32)]
@@ -1583,6 +4188,75 @@ impl DatabaseItem for BulletShape {
         "BulletShape"
     }
 }
+impl BulletShape {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 12] = [
+        Self::Projectile,
+        Self::Rocket,
+        Self::LaserBeam,
+        Self::LightningBolt,
+        Self::EnergyBeam,
+        Self::Spark,
+        Self::Mine,
+        Self::Wave,
+        Self::BlackHole,
+        Self::Harpoon,
+        Self::CircularSaw,
+        Self::PiercingLaser,
+    ];
+    pub const fn variant_count() -> usize {
+        12
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Projectile => "Projectile",
+            Self::Rocket => "Rocket",
+            Self::LaserBeam => "LaserBeam",
+            Self::LightningBolt => "LightningBolt",
+            Self::EnergyBeam => "EnergyBeam",
+            Self::Spark => "Spark",
+            Self::Mine => "Mine",
+            Self::Wave => "Wave",
+            Self::BlackHole => "BlackHole",
+            Self::Harpoon => "Harpoon",
+            Self::CircularSaw => "CircularSaw",
+            Self::PiercingLaser => "PiercingLaser",
+        }
+    }
+}
+impl std::str::FromStr for BulletShape {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Projectile" => Ok(Self::Projectile),
+            "Rocket" => Ok(Self::Rocket),
+            "LaserBeam" => Ok(Self::LaserBeam),
+            "LightningBolt" => Ok(Self::LightningBolt),
+            "EnergyBeam" => Ok(Self::EnergyBeam),
+            "Spark" => Ok(Self::Spark),
+            "Mine" => Ok(Self::Mine),
+            "Wave" => Ok(Self::Wave),
+            "BlackHole" => Ok(Self::BlackHole),
+            "Harpoon" => Ok(Self::Harpoon),
+            "CircularSaw" => Ok(Self::CircularSaw),
+            "PiercingLaser" => Ok(Self::PiercingLaser),
+            _ => Err(UnknownEnumVariant::new("BulletShape", s)),
+        }
+    }
+}
+impl std::ops::BitOr for BulletShape {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for BulletShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Weapon/BulletTriggerCondition.xml
 #[repr(i32)]
@@ -1617,6 +4291,66 @@ impl DatabaseItem for BulletTriggerCondition {
         "BulletTriggerCondition"
     }
 }
+impl BulletTriggerCondition {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 9] = [
+        Self::Undefined,
+        Self::Created,
+        Self::Destroyed,
+        Self::Hit,
+        Self::Disarmed,
+        Self::Expired,
+        Self::Detonated,
+        Self::OutOfAmmo,
+        Self::Cooldown,
+    ];
+    pub const fn variant_count() -> usize {
+        9
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Undefined => "Undefined",
+            Self::Created => "Created",
+            Self::Destroyed => "Destroyed",
+            Self::Hit => "Hit",
+            Self::Disarmed => "Disarmed",
+            Self::Expired => "Expired",
+            Self::Detonated => "Detonated",
+            Self::OutOfAmmo => "OutOfAmmo",
+            Self::Cooldown => "Cooldown",
+        }
+    }
+}
+impl std::str::FromStr for BulletTriggerCondition {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Undefined" => Ok(Self::Undefined),
+            "Created" => Ok(Self::Created),
+            "Destroyed" => Ok(Self::Destroyed),
+            "Hit" => Ok(Self::Hit),
+            "Disarmed" => Ok(Self::Disarmed),
+            "Expired" => Ok(Self::Expired),
+            "Detonated" => Ok(Self::Detonated),
+            "OutOfAmmo" => Ok(Self::OutOfAmmo),
+            "Cooldown" => Ok(Self::Cooldown),
+            _ => Err(UnknownEnumVariant::new("BulletTriggerCondition", s)),
+        }
+    }
+}
+impl std::ops::BitOr for BulletTriggerCondition {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for BulletTriggerCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Weapon/BulletTypeObsolete.xml
 #[repr(i32)]
@@ -1647,6 +4381,54 @@ impl DatabaseItem for BulletTypeObsolete {
         "BulletTypeObsolete"
     }
 }
+impl BulletTypeObsolete {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 5] = [
+        Self::Projectile,
+        Self::Homing,
+        Self::Static,
+        Self::Continuous,
+        Self::Magnetic,
+    ];
+    pub const fn variant_count() -> usize {
+        5
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Projectile => "Projectile",
+            Self::Homing => "Homing",
+            Self::Static => "Static",
+            Self::Continuous => "Continuous",
+            Self::Magnetic => "Magnetic",
+        }
+    }
+}
+impl std::str::FromStr for BulletTypeObsolete {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Projectile" => Ok(Self::Projectile),
+            "Homing" => Ok(Self::Homing),
+            "Static" => Ok(Self::Static),
+            "Continuous" => Ok(Self::Continuous),
+            "Magnetic" => Ok(Self::Magnetic),
+            _ => Err(UnknownEnumVariant::new("BulletTypeObsolete", s)),
+        }
+    }
+}
+impl std::ops::BitOr for BulletTypeObsolete {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for BulletTypeObsolete {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Weapon/ColorMode.xml
 #[repr(i32)]
@@ -1676,6 +4458,51 @@ impl DatabaseItem for ColorMode {
         "ColorMode"
     }
 }
+impl ColorMode {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 4] = [
+        Self::TakeFromOwner,
+        Self::UseMyOwn,
+        Self::Blend,
+        Self::Multiply,
+    ];
+    pub const fn variant_count() -> usize {
+        4
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TakeFromOwner => "TakeFromOwner",
+            Self::UseMyOwn => "UseMyOwn",
+            Self::Blend => "Blend",
+            Self::Multiply => "Multiply",
+        }
+    }
+}
+impl std::str::FromStr for ColorMode {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "TakeFromOwner" => Ok(Self::TakeFromOwner),
+            "UseMyOwn" => Ok(Self::UseMyOwn),
+            "Blend" => Ok(Self::Blend),
+            "Multiply" => Ok(Self::Multiply),
+            _ => Err(UnknownEnumVariant::new("ColorMode", s)),
+        }
+    }
+}
+impl std::ops::BitOr for ColorMode {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for ColorMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Weapon/DamageType.xml
 #[repr(i32)]
@@ -1705,6 +4532,46 @@ impl DatabaseItem for DamageType {
         "DamageType"
     }
 }
+impl DamageType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 4] = [Self::Impact, Self::Energy, Self::Heat, Self::Corrosive];
+    pub const fn variant_count() -> usize {
+        4
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Impact => "Impact",
+            Self::Energy => "Energy",
+            Self::Heat => "Heat",
+            Self::Corrosive => "Corrosive",
+        }
+    }
+}
+impl std::str::FromStr for DamageType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Impact" => Ok(Self::Impact),
+            "Energy" => Ok(Self::Energy),
+            "Heat" => Ok(Self::Heat),
+            "Corrosive" => Ok(Self::Corrosive),
+            _ => Err(UnknownEnumVariant::new("DamageType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for DamageType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for DamageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Weapon/ImpactEffectType.xml
 #[repr(i32)]
@@ -1749,6 +4616,96 @@ impl DatabaseItem for ImpactEffectType {
         "ImpactEffectType"
     }
 }
+impl ImpactEffectType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 19] = [
+        Self::Damage,
+        Self::Push,
+        Self::Pull,
+        Self::DrainEnergy,
+        Self::SiphonHitPoints,
+        Self::SlowDown,
+        Self::CaptureDrones,
+        Self::Repair,
+        Self::RestoreLifetime,
+        Self::Devour,
+        Self::Teleport,
+        Self::DrainShield,
+        Self::DriveDronesCrazy,
+        Self::IgnoreShield,
+        Self::RechargeShield,
+        Self::RechargeEnergy,
+        Self::ProgressiveDamage,
+        Self::PushFromCenter,
+        Self::PullToCenter,
+    ];
+    pub const fn variant_count() -> usize {
+        19
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Damage => "Damage",
+            Self::Push => "Push",
+            Self::Pull => "Pull",
+            Self::DrainEnergy => "DrainEnergy",
+            Self::SiphonHitPoints => "SiphonHitPoints",
+            Self::SlowDown => "SlowDown",
+            Self::CaptureDrones => "CaptureDrones",
+            Self::Repair => "Repair",
+            Self::RestoreLifetime => "RestoreLifetime",
+            Self::Devour => "Devour",
+            Self::Teleport => "Teleport",
+            Self::DrainShield => "DrainShield",
+            Self::DriveDronesCrazy => "DriveDronesCrazy",
+            Self::IgnoreShield => "IgnoreShield",
+            Self::RechargeShield => "RechargeShield",
+            Self::RechargeEnergy => "RechargeEnergy",
+            Self::ProgressiveDamage => "ProgressiveDamage",
+            Self::PushFromCenter => "PushFromCenter",
+            Self::PullToCenter => "PullToCenter",
+        }
+    }
+}
+impl std::str::FromStr for ImpactEffectType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Damage" => Ok(Self::Damage),
+            "Push" => Ok(Self::Push),
+            "Pull" => Ok(Self::Pull),
+            "DrainEnergy" => Ok(Self::DrainEnergy),
+            "SiphonHitPoints" => Ok(Self::SiphonHitPoints),
+            "SlowDown" => Ok(Self::SlowDown),
+            "CaptureDrones" => Ok(Self::CaptureDrones),
+            "Repair" => Ok(Self::Repair),
+            "RestoreLifetime" => Ok(Self::RestoreLifetime),
+            "Devour" => Ok(Self::Devour),
+            "Teleport" => Ok(Self::Teleport),
+            "DrainShield" => Ok(Self::DrainShield),
+            "DriveDronesCrazy" => Ok(Self::DriveDronesCrazy),
+            "IgnoreShield" => Ok(Self::IgnoreShield),
+            "RechargeShield" => Ok(Self::RechargeShield),
+            "RechargeEnergy" => Ok(Self::RechargeEnergy),
+            "ProgressiveDamage" => Ok(Self::ProgressiveDamage),
+            "PushFromCenter" => Ok(Self::PushFromCenter),
+            "PullToCenter" => Ok(Self::PullToCenter),
+            _ => Err(UnknownEnumVariant::new("ImpactEffectType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for ImpactEffectType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for ImpactEffectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Weapon/VisualEffectType.xml
 #[repr(i32)]
@@ -1784,6 +4741,69 @@ impl DatabaseItem for VisualEffectType {
         "VisualEffectType"
     }
 }
+impl VisualEffectType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 10] = [
+        Self::Flash,
+        Self::FlashAdditive,
+        Self::Shockwave,
+        Self::Smoke,
+        Self::SmokeAdditive,
+        Self::Shake,
+        Self::Spark,
+        Self::Lightning,
+        Self::LightningStrike,
+        Self::Sprite,
+    ];
+    pub const fn variant_count() -> usize {
+        10
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Flash => "Flash",
+            Self::FlashAdditive => "FlashAdditive",
+            Self::Shockwave => "Shockwave",
+            Self::Smoke => "Smoke",
+            Self::SmokeAdditive => "SmokeAdditive",
+            Self::Shake => "Shake",
+            Self::Spark => "Spark",
+            Self::Lightning => "Lightning",
+            Self::LightningStrike => "LightningStrike",
+            Self::Sprite => "Sprite",
+        }
+    }
+}
+impl std::str::FromStr for VisualEffectType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Flash" => Ok(Self::Flash),
+            "FlashAdditive" => Ok(Self::FlashAdditive),
+            "Shockwave" => Ok(Self::Shockwave),
+            "Smoke" => Ok(Self::Smoke),
+            "SmokeAdditive" => Ok(Self::SmokeAdditive),
+            "Shake" => Ok(Self::Shake),
+            "Spark" => Ok(Self::Spark),
+            "Lightning" => Ok(Self::Lightning),
+            "LightningStrike" => Ok(Self::LightningStrike),
+            "Sprite" => Ok(Self::Sprite),
+            _ => Err(UnknownEnumVariant::new("VisualEffectType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for VisualEffectType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for VisualEffectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Weapon/WeaponClass.xml
 #[repr(i32)]
@@ -1815,6 +4835,57 @@ impl DatabaseItem for WeaponClass {
         "WeaponClass"
     }
 }
+impl WeaponClass {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 6] = [
+        Self::Common,
+        Self::Manageable,
+        Self::Continuous,
+        Self::MashineGun,
+        Self::MultiShot,
+        Self::RequiredCharging,
+    ];
+    pub const fn variant_count() -> usize {
+        6
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Common => "Common",
+            Self::Manageable => "Manageable",
+            Self::Continuous => "Continuous",
+            Self::MashineGun => "MashineGun",
+            Self::MultiShot => "MultiShot",
+            Self::RequiredCharging => "RequiredCharging",
+        }
+    }
+}
+impl std::str::FromStr for WeaponClass {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Common" => Ok(Self::Common),
+            "Manageable" => Ok(Self::Manageable),
+            "Continuous" => Ok(Self::Continuous),
+            "MashineGun" => Ok(Self::MashineGun),
+            "MultiShot" => Ok(Self::MultiShot),
+            "RequiredCharging" => Ok(Self::RequiredCharging),
+            _ => Err(UnknownEnumVariant::new("WeaponClass", s)),
+        }
+    }
+}
+impl std::ops::BitOr for WeaponClass {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
+impl std::fmt::Display for WeaponClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
 
 // v1/Enums/Weapon/WeaponSlotType.xml
 #[repr(u32)]
@@ -1839,6 +4910,52 @@ impl DatabaseItem for WeaponSlotType {
         "WeaponSlotType"
     }
 }
+impl WeaponSlotType {
+    /// All variants, in the schema order
+    pub const ALL_VARIANTS: [Self; 6] = [
+        Self::Default,
+        Self::Cannon,
+        Self::Torpedo,
+        Self::Missile,
+        Self::Laser,
+        Self::Special,
+    ];
+    pub const fn variant_count() -> usize {
+        6
+    }
+    /// Name of the variant, as defined in the schema
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Cannon => "Cannon",
+            Self::Torpedo => "Torpedo",
+            Self::Missile => "Missile",
+            Self::Laser => "Laser",
+            Self::Special => "Special",
+        }
+    }
+}
+impl std::str::FromStr for WeaponSlotType {
+    type Err = UnknownEnumVariant;
+    /// Parses the variant from its schema name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Default" => Ok(Self::Default),
+            "Cannon" => Ok(Self::Cannon),
+            "Torpedo" => Ok(Self::Torpedo),
+            "Missile" => Ok(Self::Missile),
+            "Laser" => Ok(Self::Laser),
+            "Special" => Ok(Self::Special),
+            _ => Err(UnknownEnumVariant::new("WeaponSlotType", s)),
+        }
+    }
+}
+impl std::ops::BitOr for WeaponSlotType {
+    type Output = Flags<Self>;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Flags::from([self, rhs])
+    }
+}
 impl serde::Serialize for WeaponSlotType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1930,6 +5047,27 @@ impl DatabaseItem for BehaviorNodeRequirementEmpty {
         "BehaviorNodeRequirementEmpty"
     }
 }
+impl VisitIds for BehaviorNodeRequirementEmpty {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementEmpty {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementEmpty {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementEmpty {
     fn default() -> Self {
         Self::new()
@@ -1991,6 +5129,33 @@ impl DatabaseItem for BehaviorNodeRequirementAny {
         "BehaviorNodeRequirementAny"
     }
 }
+impl VisitIds for BehaviorNodeRequirementAny {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirements.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorNodeRequirementAny {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirements.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorNodeRequirementAny {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirements"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirements" => Some(FieldValue::of(&self.r#requirements)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirements" => value.write_to(&mut self.r#requirements, "requirements"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementAny {
     fn default() -> Self {
         Self::new()
@@ -2052,6 +5217,33 @@ impl DatabaseItem for BehaviorNodeRequirementAll {
         "BehaviorNodeRequirementAll"
     }
 }
+impl VisitIds for BehaviorNodeRequirementAll {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirements.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorNodeRequirementAll {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirements.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorNodeRequirementAll {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirements"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirements" => Some(FieldValue::of(&self.r#requirements)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirements" => value.write_to(&mut self.r#requirements, "requirements"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementAll {
     fn default() -> Self {
         Self::new()
@@ -2113,6 +5305,33 @@ impl DatabaseItem for BehaviorNodeRequirementNone {
         "BehaviorNodeRequirementNone"
     }
 }
+impl VisitIds for BehaviorNodeRequirementNone {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirements.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorNodeRequirementNone {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirements.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorNodeRequirementNone {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirements"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirements" => Some(FieldValue::of(&self.r#requirements)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirements" => value.write_to(&mut self.r#requirements, "requirements"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementNone {
     fn default() -> Self {
         Self::new()
@@ -2167,6 +5386,29 @@ impl DatabaseItem for BehaviorNodeRequirementAiLevel {
         "BehaviorNodeRequirementAiLevel"
     }
 }
+impl VisitIds for BehaviorNodeRequirementAiLevel {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementAiLevel {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementAiLevel {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["difficulty_level"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "difficulty_level" => Some(FieldValue::of(&self.r#difficulty_level)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "difficulty_level" => value.write_to(&mut self.r#difficulty_level, "difficulty_level"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementAiLevel {
     fn default() -> Self {
         Self::new()
@@ -2221,6 +5463,29 @@ impl DatabaseItem for BehaviorNodeRequirementMinAiLevel {
         "BehaviorNodeRequirementMinAiLevel"
     }
 }
+impl VisitIds for BehaviorNodeRequirementMinAiLevel {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementMinAiLevel {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementMinAiLevel {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["difficulty_level"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "difficulty_level" => Some(FieldValue::of(&self.r#difficulty_level)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "difficulty_level" => value.write_to(&mut self.r#difficulty_level, "difficulty_level"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementMinAiLevel {
     fn default() -> Self {
         Self::new()
@@ -2268,6 +5533,29 @@ impl DatabaseItem for BehaviorNodeRequirementSizeClass {
         "BehaviorNodeRequirementSizeClass"
     }
 }
+impl VisitIds for BehaviorNodeRequirementSizeClass {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementSizeClass {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementSizeClass {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["size_class"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "size_class" => Some(FieldValue::of(&self.r#size_class)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "size_class" => value.write_to(&mut self.r#size_class, "size_class"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementSizeClass {
     fn default() -> Self {
         Self::new()
@@ -2315,6 +5603,29 @@ impl DatabaseItem for BehaviorNodeRequirementHasDevice {
         "BehaviorNodeRequirementHasDevice"
     }
 }
+impl VisitIds for BehaviorNodeRequirementHasDevice {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementHasDevice {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementHasDevice {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["device_class"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "device_class" => Some(FieldValue::of(&self.r#device_class)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "device_class" => value.write_to(&mut self.r#device_class, "device_class"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementHasDevice {
     fn default() -> Self {
         Self::new()
@@ -2349,6 +5660,27 @@ impl DatabaseItem for BehaviorNodeRequirementHasDrones {
         "BehaviorNodeRequirementHasDrones"
     }
 }
+impl VisitIds for BehaviorNodeRequirementHasDrones {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementHasDrones {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementHasDrones {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementHasDrones {
     fn default() -> Self {
         Self::new()
@@ -2383,6 +5715,27 @@ impl DatabaseItem for BehaviorNodeRequirementHasAnyWeapon {
         "BehaviorNodeRequirementHasAnyWeapon"
     }
 }
+impl VisitIds for BehaviorNodeRequirementHasAnyWeapon {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementHasAnyWeapon {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementHasAnyWeapon {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementHasAnyWeapon {
     fn default() -> Self {
         Self::new()
@@ -2417,6 +5770,27 @@ impl DatabaseItem for BehaviorNodeRequirementCanRepairAllies {
         "BehaviorNodeRequirementCanRepairAllies"
     }
 }
+impl VisitIds for BehaviorNodeRequirementCanRepairAllies {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementCanRepairAllies {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementCanRepairAllies {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementCanRepairAllies {
     fn default() -> Self {
         Self::new()
@@ -2451,6 +5825,27 @@ impl DatabaseItem for BehaviorNodeRequirementHasHighRecoilWeapon {
         "BehaviorNodeRequirementHasHighRecoilWeapon"
     }
 }
+impl VisitIds for BehaviorNodeRequirementHasHighRecoilWeapon {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementHasHighRecoilWeapon {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementHasHighRecoilWeapon {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementHasHighRecoilWeapon {
     fn default() -> Self {
         Self::new()
@@ -2485,6 +5880,27 @@ impl DatabaseItem for BehaviorNodeRequirementHasChargeableWeapon {
         "BehaviorNodeRequirementHasChargeableWeapon"
     }
 }
+impl VisitIds for BehaviorNodeRequirementHasChargeableWeapon {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementHasChargeableWeapon {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementHasChargeableWeapon {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementHasChargeableWeapon {
     fn default() -> Self {
         Self::new()
@@ -2519,6 +5935,27 @@ impl DatabaseItem for BehaviorNodeRequirementHasRemotelyControlledWeapon {
         "BehaviorNodeRequirementHasRemotelyControlledWeapon"
     }
 }
+impl VisitIds for BehaviorNodeRequirementHasRemotelyControlledWeapon {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementHasRemotelyControlledWeapon {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementHasRemotelyControlledWeapon {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementHasRemotelyControlledWeapon {
     fn default() -> Self {
         Self::new()
@@ -2542,6 +5979,8 @@ impl BehaviorNodeRequirement {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BehaviorNodeRequirementHasLongRangeWeapon {
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#value: f32,
@@ -2572,6 +6011,29 @@ impl DatabaseItem for BehaviorNodeRequirementHasLongRangeWeapon {
         "BehaviorNodeRequirementHasLongRangeWeapon"
     }
 }
+impl VisitIds for BehaviorNodeRequirementHasLongRangeWeapon {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementHasLongRangeWeapon {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementHasLongRangeWeapon {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "value" => Some(FieldValue::of(&self.r#value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "value" => value.write_to(&mut self.r#value, "value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorNodeRequirementHasLongRangeWeapon {}
 impl std::cmp::PartialEq for BehaviorNodeRequirementHasLongRangeWeapon {
     fn eq(&self, other: &Self) -> bool {
@@ -2617,6 +6079,27 @@ impl DatabaseItem for BehaviorNodeRequirementHasEngine {
         "BehaviorNodeRequirementHasEngine"
     }
 }
+impl VisitIds for BehaviorNodeRequirementHasEngine {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementHasEngine {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementHasEngine {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementHasEngine {
     fn default() -> Self {
         Self::new()
@@ -2651,6 +6134,27 @@ impl DatabaseItem for BehaviorNodeRequirementHasHarpoon {
         "BehaviorNodeRequirementHasHarpoon"
     }
 }
+impl VisitIds for BehaviorNodeRequirementHasHarpoon {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementHasHarpoon {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementHasHarpoon {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementHasHarpoon {
     fn default() -> Self {
         Self::new()
@@ -2685,6 +6189,27 @@ impl DatabaseItem for BehaviorNodeRequirementCanRechargeAllies {
         "BehaviorNodeRequirementCanRechargeAllies"
     }
 }
+impl VisitIds for BehaviorNodeRequirementCanRechargeAllies {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementCanRechargeAllies {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementCanRechargeAllies {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementCanRechargeAllies {
     fn default() -> Self {
         Self::new()
@@ -2719,6 +6244,27 @@ impl DatabaseItem for BehaviorNodeRequirementIsDrone {
         "BehaviorNodeRequirementIsDrone"
     }
 }
+impl VisitIds for BehaviorNodeRequirementIsDrone {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementIsDrone {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementIsDrone {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorNodeRequirementIsDrone {
     fn default() -> Self {
         Self::new()
@@ -2742,6 +6288,8 @@ impl BehaviorNodeRequirement {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BehaviorNodeRequirementHasKineticResistance {
+    /// - Minimum: `0`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#value: f32,
@@ -2772,6 +6320,29 @@ impl DatabaseItem for BehaviorNodeRequirementHasKineticResistance {
         "BehaviorNodeRequirementHasKineticResistance"
     }
 }
+impl VisitIds for BehaviorNodeRequirementHasKineticResistance {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementHasKineticResistance {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementHasKineticResistance {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "value" => Some(FieldValue::of(&self.r#value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "value" => value.write_to(&mut self.r#value, "value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorNodeRequirementHasKineticResistance {}
 impl std::cmp::PartialEq for BehaviorNodeRequirementHasKineticResistance {
     fn eq(&self, other: &Self) -> bool {
@@ -2806,6 +6377,8 @@ impl BehaviorNodeRequirement {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BehaviorNodeRequirementHasHighManeuverability {
+    /// - Minimum: `0`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#value: f32,
@@ -2836,6 +6409,29 @@ impl DatabaseItem for BehaviorNodeRequirementHasHighManeuverability {
         "BehaviorNodeRequirementHasHighManeuverability"
     }
 }
+impl VisitIds for BehaviorNodeRequirementHasHighManeuverability {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementHasHighManeuverability {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementHasHighManeuverability {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "value" => Some(FieldValue::of(&self.r#value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "value" => value.write_to(&mut self.r#value, "value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorNodeRequirementHasHighManeuverability {}
 impl std::cmp::PartialEq for BehaviorNodeRequirementHasHighManeuverability {
     fn eq(&self, other: &Self) -> bool {
@@ -2870,6 +6466,8 @@ impl BehaviorNodeRequirement {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BehaviorNodeRequirementHasHighRammingDamage {
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#value: f32,
@@ -2900,6 +6498,29 @@ impl DatabaseItem for BehaviorNodeRequirementHasHighRammingDamage {
         "BehaviorNodeRequirementHasHighRammingDamage"
     }
 }
+impl VisitIds for BehaviorNodeRequirementHasHighRammingDamage {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BehaviorNodeRequirementHasHighRammingDamage {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BehaviorNodeRequirementHasHighRammingDamage {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "value" => Some(FieldValue::of(&self.r#value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "value" => value.write_to(&mut self.r#value, "value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorNodeRequirementHasHighRammingDamage {}
 impl std::cmp::PartialEq for BehaviorNodeRequirementHasHighRammingDamage {
     fn eq(&self, other: &Self) -> bool {
@@ -3325,6 +6946,142 @@ impl DatabaseItem for BehaviorNodeRequirement {
         "BehaviorNodeRequirement"
     }
 }
+impl VisitIds for BehaviorNodeRequirement {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        match self {
+            Self::Empty(x) => x.visit_ids(visitor),
+            Self::Any(x) => x.visit_ids(visitor),
+            Self::All(x) => x.visit_ids(visitor),
+            Self::None(x) => x.visit_ids(visitor),
+            Self::AiLevel(x) => x.visit_ids(visitor),
+            Self::MinAiLevel(x) => x.visit_ids(visitor),
+            Self::SizeClass(x) => x.visit_ids(visitor),
+            Self::HasDevice(x) => x.visit_ids(visitor),
+            Self::HasDrones(x) => x.visit_ids(visitor),
+            Self::HasAnyWeapon(x) => x.visit_ids(visitor),
+            Self::CanRepairAllies(x) => x.visit_ids(visitor),
+            Self::HasHighRecoilWeapon(x) => x.visit_ids(visitor),
+            Self::HasChargeableWeapon(x) => x.visit_ids(visitor),
+            Self::HasRemotelyControlledWeapon(x) => x.visit_ids(visitor),
+            Self::HasLongRangeWeapon(x) => x.visit_ids(visitor),
+            Self::HasEngine(x) => x.visit_ids(visitor),
+            Self::HasHarpoon(x) => x.visit_ids(visitor),
+            Self::CanRechargeAllies(x) => x.visit_ids(visitor),
+            Self::IsDrone(x) => x.visit_ids(visitor),
+            Self::HasKineticResistance(x) => x.visit_ids(visitor),
+            Self::HasHighManeuverability(x) => x.visit_ids(visitor),
+            Self::HasHighRammingDamage(x) => x.visit_ids(visitor),
+        }
+    }
+}
+impl VisitAssets for BehaviorNodeRequirement {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        match self {
+            Self::Empty(x) => x.visit_assets(visitor),
+            Self::Any(x) => x.visit_assets(visitor),
+            Self::All(x) => x.visit_assets(visitor),
+            Self::None(x) => x.visit_assets(visitor),
+            Self::AiLevel(x) => x.visit_assets(visitor),
+            Self::MinAiLevel(x) => x.visit_assets(visitor),
+            Self::SizeClass(x) => x.visit_assets(visitor),
+            Self::HasDevice(x) => x.visit_assets(visitor),
+            Self::HasDrones(x) => x.visit_assets(visitor),
+            Self::HasAnyWeapon(x) => x.visit_assets(visitor),
+            Self::CanRepairAllies(x) => x.visit_assets(visitor),
+            Self::HasHighRecoilWeapon(x) => x.visit_assets(visitor),
+            Self::HasChargeableWeapon(x) => x.visit_assets(visitor),
+            Self::HasRemotelyControlledWeapon(x) => x.visit_assets(visitor),
+            Self::HasLongRangeWeapon(x) => x.visit_assets(visitor),
+            Self::HasEngine(x) => x.visit_assets(visitor),
+            Self::HasHarpoon(x) => x.visit_assets(visitor),
+            Self::CanRechargeAllies(x) => x.visit_assets(visitor),
+            Self::IsDrone(x) => x.visit_assets(visitor),
+            Self::HasKineticResistance(x) => x.visit_assets(visitor),
+            Self::HasHighManeuverability(x) => x.visit_assets(visitor),
+            Self::HasHighRammingDamage(x) => x.visit_assets(visitor),
+        }
+    }
+}
+impl DynamicFields for BehaviorNodeRequirement {
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Empty(x) => x.field_names(),
+            Self::Any(x) => x.field_names(),
+            Self::All(x) => x.field_names(),
+            Self::None(x) => x.field_names(),
+            Self::AiLevel(x) => x.field_names(),
+            Self::MinAiLevel(x) => x.field_names(),
+            Self::SizeClass(x) => x.field_names(),
+            Self::HasDevice(x) => x.field_names(),
+            Self::HasDrones(x) => x.field_names(),
+            Self::HasAnyWeapon(x) => x.field_names(),
+            Self::CanRepairAllies(x) => x.field_names(),
+            Self::HasHighRecoilWeapon(x) => x.field_names(),
+            Self::HasChargeableWeapon(x) => x.field_names(),
+            Self::HasRemotelyControlledWeapon(x) => x.field_names(),
+            Self::HasLongRangeWeapon(x) => x.field_names(),
+            Self::HasEngine(x) => x.field_names(),
+            Self::HasHarpoon(x) => x.field_names(),
+            Self::CanRechargeAllies(x) => x.field_names(),
+            Self::IsDrone(x) => x.field_names(),
+            Self::HasKineticResistance(x) => x.field_names(),
+            Self::HasHighManeuverability(x) => x.field_names(),
+            Self::HasHighRammingDamage(x) => x.field_names(),
+        }
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match self {
+            Self::Empty(x) => x.get_field(name),
+            Self::Any(x) => x.get_field(name),
+            Self::All(x) => x.get_field(name),
+            Self::None(x) => x.get_field(name),
+            Self::AiLevel(x) => x.get_field(name),
+            Self::MinAiLevel(x) => x.get_field(name),
+            Self::SizeClass(x) => x.get_field(name),
+            Self::HasDevice(x) => x.get_field(name),
+            Self::HasDrones(x) => x.get_field(name),
+            Self::HasAnyWeapon(x) => x.get_field(name),
+            Self::CanRepairAllies(x) => x.get_field(name),
+            Self::HasHighRecoilWeapon(x) => x.get_field(name),
+            Self::HasChargeableWeapon(x) => x.get_field(name),
+            Self::HasRemotelyControlledWeapon(x) => x.get_field(name),
+            Self::HasLongRangeWeapon(x) => x.get_field(name),
+            Self::HasEngine(x) => x.get_field(name),
+            Self::HasHarpoon(x) => x.get_field(name),
+            Self::CanRechargeAllies(x) => x.get_field(name),
+            Self::IsDrone(x) => x.get_field(name),
+            Self::HasKineticResistance(x) => x.get_field(name),
+            Self::HasHighManeuverability(x) => x.get_field(name),
+            Self::HasHighRammingDamage(x) => x.get_field(name),
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match self {
+            Self::Empty(x) => x.set_field(name, value),
+            Self::Any(x) => x.set_field(name, value),
+            Self::All(x) => x.set_field(name, value),
+            Self::None(x) => x.set_field(name, value),
+            Self::AiLevel(x) => x.set_field(name, value),
+            Self::MinAiLevel(x) => x.set_field(name, value),
+            Self::SizeClass(x) => x.set_field(name, value),
+            Self::HasDevice(x) => x.set_field(name, value),
+            Self::HasDrones(x) => x.set_field(name, value),
+            Self::HasAnyWeapon(x) => x.set_field(name, value),
+            Self::CanRepairAllies(x) => x.set_field(name, value),
+            Self::HasHighRecoilWeapon(x) => x.set_field(name, value),
+            Self::HasChargeableWeapon(x) => x.set_field(name, value),
+            Self::HasRemotelyControlledWeapon(x) => x.set_field(name, value),
+            Self::HasLongRangeWeapon(x) => x.set_field(name, value),
+            Self::HasEngine(x) => x.set_field(name, value),
+            Self::HasHarpoon(x) => x.set_field(name, value),
+            Self::CanRechargeAllies(x) => x.set_field(name, value),
+            Self::IsDrone(x) => x.set_field(name, value),
+            Self::HasKineticResistance(x) => x.set_field(name, value),
+            Self::HasHighManeuverability(x) => x.set_field(name, value),
+            Self::HasHighRammingDamage(x) => x.set_field(name, value),
+        }
+    }
+}
 impl BehaviorNodeRequirement {
     pub fn inner_type_name(&self) -> &'static str {
         match self {
@@ -3361,6 +7118,36 @@ impl BehaviorNodeRequirement {
         }
     }
 }
+/// Runs the same code for every variant of [BehaviorNodeRequirement], binding the inner item to the given pattern
+#[macro_export]
+macro_rules! match_behavior_node_requirement {
+    ($value:expr, |$inner:pat_param| $body:expr) => {
+        match $value {
+            $crate::schema::BehaviorNodeRequirement::Empty($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::Any($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::All($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::None($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::AiLevel($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::MinAiLevel($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::SizeClass($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::HasDevice($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::HasDrones($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::HasAnyWeapon($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::CanRepairAllies($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::HasHighRecoilWeapon($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::HasChargeableWeapon($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::HasRemotelyControlledWeapon($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::HasLongRangeWeapon($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::HasEngine($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::HasHarpoon($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::CanRechargeAllies($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::IsDrone($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::HasKineticResistance($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::HasHighManeuverability($inner) => $body,
+            $crate::schema::BehaviorNodeRequirement::HasHighRammingDamage($inner) => $body,
+        }
+    };
+}
 
 // v1/Objects/Ai/BehaviorTreeNode.xml
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -3490,6 +7277,33 @@ impl DatabaseItem for BehaviorTreeNodeSuccess {
         "BehaviorTreeNodeSuccess"
     }
 }
+impl VisitIds for BehaviorTreeNodeSuccess {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeSuccess {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeSuccess {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeSuccess {
     fn default() -> Self {
         Self::new()
@@ -3546,6 +7360,33 @@ impl DatabaseItem for BehaviorTreeNodeFailure {
         "BehaviorTreeNodeFailure"
     }
 }
+impl VisitIds for BehaviorTreeNodeFailure {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeFailure {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeFailure {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeFailure {
     fn default() -> Self {
         Self::new()
@@ -3602,6 +7443,10 @@ impl BehaviorTreeNodeSubTree {
         self.r#item_id = r#item_id.into();
         self
     }
+    pub fn clear_item_id(&mut self) -> &mut Self {
+        self.r#item_id = None;
+        self
+    }
 }
 impl DatabaseItem for BehaviorTreeNodeSubTree {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
@@ -3614,6 +7459,36 @@ impl DatabaseItem for BehaviorTreeNodeSubTree {
         "BehaviorTreeNodeSubTree"
     }
 }
+impl VisitIds for BehaviorTreeNodeSubTree {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+        self.r#item_id.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeSubTree {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeSubTree {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "item_id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeSubTree {
     fn default() -> Self {
         Self::new()
@@ -3688,6 +7563,37 @@ impl DatabaseItem for BehaviorTreeNodeSelector {
         "BehaviorTreeNodeSelector"
     }
 }
+impl VisitIds for BehaviorTreeNodeSelector {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+        self.r#nodes.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeSelector {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+        self.r#nodes.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeSelector {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "nodes"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "nodes" => Some(FieldValue::of(&self.r#nodes)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "nodes" => value.write_to(&mut self.r#nodes, "nodes"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeSelector {
     fn default() -> Self {
         Self::new()
@@ -3762,6 +7668,37 @@ impl DatabaseItem for BehaviorTreeNodeSequence {
         "BehaviorTreeNodeSequence"
     }
 }
+impl VisitIds for BehaviorTreeNodeSequence {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+        self.r#nodes.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeSequence {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+        self.r#nodes.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeSequence {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "nodes"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "nodes" => Some(FieldValue::of(&self.r#nodes)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "nodes" => value.write_to(&mut self.r#nodes, "nodes"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeSequence {
     fn default() -> Self {
         Self::new()
@@ -3836,6 +7773,37 @@ impl DatabaseItem for BehaviorTreeNodeParallel {
         "BehaviorTreeNodeParallel"
     }
 }
+impl VisitIds for BehaviorTreeNodeParallel {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+        self.r#nodes.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeParallel {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+        self.r#nodes.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeParallel {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "nodes"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "nodes" => Some(FieldValue::of(&self.r#nodes)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "nodes" => value.write_to(&mut self.r#nodes, "nodes"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeParallel {
     fn default() -> Self {
         Self::new()
@@ -3864,6 +7832,8 @@ pub struct BehaviorTreeNodeRandomSelector {
     pub r#requirement: BehaviorNodeRequirement,
     #[serde(default)]
     pub r#nodes: Vec<BehaviorTreeNode>,
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#cooldown: f32,
@@ -3928,6 +7898,39 @@ impl DatabaseItem for BehaviorTreeNodeRandomSelector {
         "BehaviorTreeNodeRandomSelector"
     }
 }
+impl VisitIds for BehaviorTreeNodeRandomSelector {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+        self.r#nodes.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeRandomSelector {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+        self.r#nodes.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeRandomSelector {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "nodes", "cooldown"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "nodes" => Some(FieldValue::of(&self.r#nodes)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "nodes" => value.write_to(&mut self.r#nodes, "nodes"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeRandomSelector {}
 impl std::cmp::PartialEq for BehaviorTreeNodeRandomSelector {
     fn eq(&self, other: &Self) -> bool {
@@ -4015,6 +8018,37 @@ impl DatabaseItem for BehaviorTreeNodeInvertor {
         "BehaviorTreeNodeInvertor"
     }
 }
+impl VisitIds for BehaviorTreeNodeInvertor {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+        self.r#node.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeInvertor {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+        self.r#node.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeInvertor {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "node"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "node" => Some(FieldValue::of(&self.r#node)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "node" => value.write_to(&mut self.r#node, "node"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeInvertor {
     fn default() -> Self {
         Self::new()
@@ -4045,9 +8079,12 @@ pub struct BehaviorTreeNodeCooldown {
     pub r#node: Box<BehaviorTreeNode>,
     #[serde(default)]
     pub r#execution_mode: NodeExecutionMode,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#result: bool,
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#cooldown: f32,
@@ -4130,6 +8167,49 @@ impl DatabaseItem for BehaviorTreeNodeCooldown {
         "BehaviorTreeNodeCooldown"
     }
 }
+impl VisitIds for BehaviorTreeNodeCooldown {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+        self.r#node.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeCooldown {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+        self.r#node.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeCooldown {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "requirement",
+            "node",
+            "execution_mode",
+            "result",
+            "cooldown",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "node" => Some(FieldValue::of(&self.r#node)),
+            "execution_mode" => Some(FieldValue::of(&self.r#execution_mode)),
+            "result" => Some(FieldValue::of(&self.r#result)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "node" => value.write_to(&mut self.r#node, "node"),
+            "execution_mode" => value.write_to(&mut self.r#execution_mode, "execution_mode"),
+            "result" => value.write_to(&mut self.r#result, "result"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeCooldown {}
 impl std::cmp::PartialEq for BehaviorTreeNodeCooldown {
     fn eq(&self, other: &Self) -> bool {
@@ -4180,6 +8260,7 @@ pub struct BehaviorTreeNodeExecute {
     pub r#node: Box<BehaviorTreeNode>,
     #[serde(default)]
     pub r#execution_mode: NodeExecutionMode,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#result: bool,
@@ -4247,6 +8328,41 @@ impl DatabaseItem for BehaviorTreeNodeExecute {
         "BehaviorTreeNodeExecute"
     }
 }
+impl VisitIds for BehaviorTreeNodeExecute {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+        self.r#node.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeExecute {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+        self.r#node.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeExecute {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "node", "execution_mode", "result"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "node" => Some(FieldValue::of(&self.r#node)),
+            "execution_mode" => Some(FieldValue::of(&self.r#execution_mode)),
+            "result" => Some(FieldValue::of(&self.r#result)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "node" => value.write_to(&mut self.r#node, "node"),
+            "execution_mode" => value.write_to(&mut self.r#execution_mode, "execution_mode"),
+            "result" => value.write_to(&mut self.r#result, "result"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeExecute {
     fn default() -> Self {
         Self::new()
@@ -4321,6 +8437,37 @@ impl DatabaseItem for BehaviorTreeNodeParallelSequence {
         "BehaviorTreeNodeParallelSequence"
     }
 }
+impl VisitIds for BehaviorTreeNodeParallelSequence {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+        self.r#nodes.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeParallelSequence {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+        self.r#nodes.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeParallelSequence {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "nodes"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "nodes" => Some(FieldValue::of(&self.r#nodes)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "nodes" => value.write_to(&mut self.r#nodes, "nodes"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeParallelSequence {
     fn default() -> Self {
         Self::new()
@@ -4392,6 +8539,37 @@ impl DatabaseItem for BehaviorTreeNodePreserveTarget {
         "BehaviorTreeNodePreserveTarget"
     }
 }
+impl VisitIds for BehaviorTreeNodePreserveTarget {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+        self.r#node.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodePreserveTarget {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+        self.r#node.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodePreserveTarget {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "node"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "node" => Some(FieldValue::of(&self.r#node)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "node" => value.write_to(&mut self.r#node, "node"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodePreserveTarget {
     fn default() -> Self {
         Self::new()
@@ -4466,6 +8644,37 @@ impl DatabaseItem for BehaviorTreeNodeIfThenElse {
         "BehaviorTreeNodeIfThenElse"
     }
 }
+impl VisitIds for BehaviorTreeNodeIfThenElse {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+        self.r#nodes.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeIfThenElse {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+        self.r#nodes.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeIfThenElse {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "nodes"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "nodes" => Some(FieldValue::of(&self.r#nodes)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "nodes" => value.write_to(&mut self.r#nodes, "nodes"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeIfThenElse {
     fn default() -> Self {
         Self::new()
@@ -4492,6 +8701,9 @@ pub struct BehaviorTreeNodeHasEnoughEnergy {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.1`
     #[serde(default = "default_0ඞdotඞ1f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ1f32")]
     pub r#min_value: f32,
@@ -4543,6 +8755,35 @@ impl DatabaseItem for BehaviorTreeNodeHasEnoughEnergy {
         "BehaviorTreeNodeHasEnoughEnergy"
     }
 }
+impl VisitIds for BehaviorTreeNodeHasEnoughEnergy {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeHasEnoughEnergy {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeHasEnoughEnergy {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeHasEnoughEnergy {}
 impl std::cmp::PartialEq for BehaviorTreeNodeHasEnoughEnergy {
     fn eq(&self, other: &Self) -> bool {
@@ -4583,6 +8824,9 @@ pub struct BehaviorTreeNodeIsLowOnHp {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#min_value: f32,
@@ -4634,6 +8878,35 @@ impl DatabaseItem for BehaviorTreeNodeIsLowOnHp {
         "BehaviorTreeNodeIsLowOnHp"
     }
 }
+impl VisitIds for BehaviorTreeNodeIsLowOnHp {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeIsLowOnHp {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeIsLowOnHp {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeIsLowOnHp {}
 impl std::cmp::PartialEq for BehaviorTreeNodeIsLowOnHp {
     fn eq(&self, other: &Self) -> bool {
@@ -4674,6 +8947,8 @@ pub struct BehaviorTreeNodeIsNotControledByPlayer {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0.1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#cooldown: f32,
@@ -4722,6 +8997,35 @@ impl DatabaseItem for BehaviorTreeNodeIsNotControledByPlayer {
         "BehaviorTreeNodeIsNotControledByPlayer"
     }
 }
+impl VisitIds for BehaviorTreeNodeIsNotControledByPlayer {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeIsNotControledByPlayer {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeIsNotControledByPlayer {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "cooldown"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeIsNotControledByPlayer {}
 impl std::cmp::PartialEq for BehaviorTreeNodeIsNotControledByPlayer {
     fn eq(&self, other: &Self) -> bool {
@@ -4762,6 +9066,8 @@ pub struct BehaviorTreeNodeHasIncomingThreat {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0`
+    /// - Default: `5`
     #[serde(default = "default_5f32")]
     #[serde(skip_serializing_if = "skip_if_5f32")]
     pub r#cooldown: f32,
@@ -4810,6 +9116,35 @@ impl DatabaseItem for BehaviorTreeNodeHasIncomingThreat {
         "BehaviorTreeNodeHasIncomingThreat"
     }
 }
+impl VisitIds for BehaviorTreeNodeHasIncomingThreat {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeHasIncomingThreat {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeHasIncomingThreat {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "cooldown"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeHasIncomingThreat {}
 impl std::cmp::PartialEq for BehaviorTreeNodeHasIncomingThreat {
     fn eq(&self, other: &Self) -> bool {
@@ -4880,6 +9215,33 @@ impl DatabaseItem for BehaviorTreeNodeHasAdditionalTargets {
         "BehaviorTreeNodeHasAdditionalTargets"
     }
 }
+impl VisitIds for BehaviorTreeNodeHasAdditionalTargets {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeHasAdditionalTargets {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeHasAdditionalTargets {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeHasAdditionalTargets {
     fn default() -> Self {
         Self::new()
@@ -4906,6 +9268,9 @@ pub struct BehaviorTreeNodeIsFasterThanTarget {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `1`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#min_value: f32,
@@ -4957,6 +9322,35 @@ impl DatabaseItem for BehaviorTreeNodeIsFasterThanTarget {
         "BehaviorTreeNodeIsFasterThanTarget"
     }
 }
+impl VisitIds for BehaviorTreeNodeIsFasterThanTarget {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeIsFasterThanTarget {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeIsFasterThanTarget {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeIsFasterThanTarget {}
 impl std::cmp::PartialEq for BehaviorTreeNodeIsFasterThanTarget {
     fn eq(&self, other: &Self) -> bool {
@@ -5027,6 +9421,33 @@ impl DatabaseItem for BehaviorTreeNodeHasMainTarget {
         "BehaviorTreeNodeHasMainTarget"
     }
 }
+impl VisitIds for BehaviorTreeNodeHasMainTarget {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeHasMainTarget {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeHasMainTarget {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeHasMainTarget {
     fn default() -> Self {
         Self::new()
@@ -5083,6 +9504,33 @@ impl DatabaseItem for BehaviorTreeNodeMainTargetIsAlly {
         "BehaviorTreeNodeMainTargetIsAlly"
     }
 }
+impl VisitIds for BehaviorTreeNodeMainTargetIsAlly {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMainTargetIsAlly {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMainTargetIsAlly {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeMainTargetIsAlly {
     fn default() -> Self {
         Self::new()
@@ -5139,6 +9587,33 @@ impl DatabaseItem for BehaviorTreeNodeMainTargetIsEnemy {
         "BehaviorTreeNodeMainTargetIsEnemy"
     }
 }
+impl VisitIds for BehaviorTreeNodeMainTargetIsEnemy {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMainTargetIsEnemy {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMainTargetIsEnemy {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeMainTargetIsEnemy {
     fn default() -> Self {
         Self::new()
@@ -5165,6 +9640,9 @@ pub struct BehaviorTreeNodeMainTargetLowHp {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#min_value: f32,
@@ -5216,6 +9694,35 @@ impl DatabaseItem for BehaviorTreeNodeMainTargetLowHp {
         "BehaviorTreeNodeMainTargetLowHp"
     }
 }
+impl VisitIds for BehaviorTreeNodeMainTargetLowHp {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMainTargetLowHp {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMainTargetLowHp {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeMainTargetLowHp {}
 impl std::cmp::PartialEq for BehaviorTreeNodeMainTargetLowHp {
     fn eq(&self, other: &Self) -> bool {
@@ -5257,6 +9764,10 @@ pub struct BehaviorTreeNodeMainTargetWithinAttackRange {
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
     ///Linear interpolation between shortest and longest weapon ranges
+    ///
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#min_value: f32,
@@ -5308,6 +9819,35 @@ impl DatabaseItem for BehaviorTreeNodeMainTargetWithinAttackRange {
         "BehaviorTreeNodeMainTargetWithinAttackRange"
     }
 }
+impl VisitIds for BehaviorTreeNodeMainTargetWithinAttackRange {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMainTargetWithinAttackRange {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMainTargetWithinAttackRange {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeMainTargetWithinAttackRange {}
 impl std::cmp::PartialEq for BehaviorTreeNodeMainTargetWithinAttackRange {
     fn eq(&self, other: &Self) -> bool {
@@ -5378,6 +9918,33 @@ impl DatabaseItem for BehaviorTreeNodeHasMothership {
         "BehaviorTreeNodeHasMothership"
     }
 }
+impl VisitIds for BehaviorTreeNodeHasMothership {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeHasMothership {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeHasMothership {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeHasMothership {
     fn default() -> Self {
         Self::new()
@@ -5405,6 +9972,9 @@ pub struct BehaviorTreeNodeTargetDistance {
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
     ///Max distance. If value is 0, prefefined value will be used (e.g. DroneBay range)
+    ///
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#max_value: f32,
@@ -5453,6 +10023,35 @@ impl DatabaseItem for BehaviorTreeNodeTargetDistance {
         "BehaviorTreeNodeTargetDistance"
     }
 }
+impl VisitIds for BehaviorTreeNodeTargetDistance {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeTargetDistance {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeTargetDistance {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "max_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeTargetDistance {}
 impl std::cmp::PartialEq for BehaviorTreeNodeTargetDistance {
     fn eq(&self, other: &Self) -> bool {
@@ -5493,6 +10092,9 @@ pub struct BehaviorTreeNodeHasLongerAttackRange {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `1`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#min_value: f32,
@@ -5544,6 +10146,35 @@ impl DatabaseItem for BehaviorTreeNodeHasLongerAttackRange {
         "BehaviorTreeNodeHasLongerAttackRange"
     }
 }
+impl VisitIds for BehaviorTreeNodeHasLongerAttackRange {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeHasLongerAttackRange {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeHasLongerAttackRange {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeHasLongerAttackRange {}
 impl std::cmp::PartialEq for BehaviorTreeNodeHasLongerAttackRange {
     fn eq(&self, other: &Self) -> bool {
@@ -5584,15 +10215,21 @@ pub struct BehaviorTreeNodeFindEnemy {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0.5`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#min_value: f32,
+    /// - Minimum: `0`
+    /// - Default: `5`
     #[serde(default = "default_5f32")]
     #[serde(skip_serializing_if = "skip_if_5f32")]
     pub r#max_value: f32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#in_range: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#no_drones: bool,
@@ -5674,6 +10311,47 @@ impl DatabaseItem for BehaviorTreeNodeFindEnemy {
         "BehaviorTreeNodeFindEnemy"
     }
 }
+impl VisitIds for BehaviorTreeNodeFindEnemy {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeFindEnemy {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeFindEnemy {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "requirement",
+            "min_value",
+            "max_value",
+            "in_range",
+            "no_drones",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            "in_range" => Some(FieldValue::of(&self.r#in_range)),
+            "no_drones" => Some(FieldValue::of(&self.r#no_drones)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            "in_range" => value.write_to(&mut self.r#in_range, "in_range"),
+            "no_drones" => value.write_to(&mut self.r#no_drones, "no_drones"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeFindEnemy {}
 impl std::cmp::PartialEq for BehaviorTreeNodeFindEnemy {
     fn eq(&self, other: &Self) -> bool {
@@ -5722,9 +10400,16 @@ pub struct BehaviorTreeNodeMoveToAttackRange {
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
     ///Linear interpolation between shortest and longest weapon ranges
+    ///
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#min_value: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#max_value: f32,
@@ -5794,6 +10479,37 @@ impl DatabaseItem for BehaviorTreeNodeMoveToAttackRange {
         "BehaviorTreeNodeMoveToAttackRange"
     }
 }
+impl VisitIds for BehaviorTreeNodeMoveToAttackRange {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMoveToAttackRange {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMoveToAttackRange {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value", "max_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeMoveToAttackRange {}
 impl std::cmp::PartialEq for BehaviorTreeNodeMoveToAttackRange {
     fn eq(&self, other: &Self) -> bool {
@@ -5837,6 +10553,7 @@ pub struct BehaviorTreeNodeAttackMainTarget {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#in_range: bool,
@@ -5879,6 +10596,35 @@ impl DatabaseItem for BehaviorTreeNodeAttackMainTarget {
         "BehaviorTreeNodeAttackMainTarget"
     }
 }
+impl VisitIds for BehaviorTreeNodeAttackMainTarget {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeAttackMainTarget {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeAttackMainTarget {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "in_range"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "in_range" => Some(FieldValue::of(&self.r#in_range)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "in_range" => value.write_to(&mut self.r#in_range, "in_range"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeAttackMainTarget {
     fn default() -> Self {
         Self::new()
@@ -5946,6 +10692,35 @@ impl DatabaseItem for BehaviorTreeNodeSelectWeapon {
         "BehaviorTreeNodeSelectWeapon"
     }
 }
+impl VisitIds for BehaviorTreeNodeSelectWeapon {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeSelectWeapon {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeSelectWeapon {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "weapon_type"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "weapon_type" => Some(FieldValue::of(&self.r#weapon_type)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "weapon_type" => value.write_to(&mut self.r#weapon_type, "weapon_type"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeSelectWeapon {
     fn default() -> Self {
         Self::new()
@@ -6002,6 +10777,33 @@ impl DatabaseItem for BehaviorTreeNodeSpawnDrones {
         "BehaviorTreeNodeSpawnDrones"
     }
 }
+impl VisitIds for BehaviorTreeNodeSpawnDrones {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeSpawnDrones {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeSpawnDrones {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeSpawnDrones {
     fn default() -> Self {
         Self::new()
@@ -6028,6 +10830,7 @@ pub struct BehaviorTreeNodeRam {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#use_systems: bool,
@@ -6070,6 +10873,35 @@ impl DatabaseItem for BehaviorTreeNodeRam {
         "BehaviorTreeNodeRam"
     }
 }
+impl VisitIds for BehaviorTreeNodeRam {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeRam {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeRam {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "use_systems"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "use_systems" => Some(FieldValue::of(&self.r#use_systems)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "use_systems" => value.write_to(&mut self.r#use_systems, "use_systems"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeRam {
     fn default() -> Self {
         Self::new()
@@ -6096,6 +10928,7 @@ pub struct BehaviorTreeNodeDetonateShip {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#in_range: bool,
@@ -6138,6 +10971,35 @@ impl DatabaseItem for BehaviorTreeNodeDetonateShip {
         "BehaviorTreeNodeDetonateShip"
     }
 }
+impl VisitIds for BehaviorTreeNodeDetonateShip {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeDetonateShip {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeDetonateShip {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "in_range"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "in_range" => Some(FieldValue::of(&self.r#in_range)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "in_range" => value.write_to(&mut self.r#in_range, "in_range"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeDetonateShip {
     fn default() -> Self {
         Self::new()
@@ -6194,6 +11056,33 @@ impl DatabaseItem for BehaviorTreeNodeVanish {
         "BehaviorTreeNodeVanish"
     }
 }
+impl VisitIds for BehaviorTreeNodeVanish {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeVanish {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeVanish {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeVanish {
     fn default() -> Self {
         Self::new()
@@ -6221,10 +11110,18 @@ pub struct BehaviorTreeNodeMaintainAttackRange {
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
     ///Linear interpolation between shortest and longest weapon ranges
+    ///
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#min_value: f32,
     ///A valid distance between ships will be [range*(1-tolerance) .. range]
+    ///
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.2`
     #[serde(default = "default_0ඞdotඞ2f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ2f32")]
     pub r#max_value: f32,
@@ -6294,6 +11191,37 @@ impl DatabaseItem for BehaviorTreeNodeMaintainAttackRange {
         "BehaviorTreeNodeMaintainAttackRange"
     }
 }
+impl VisitIds for BehaviorTreeNodeMaintainAttackRange {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMaintainAttackRange {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMaintainAttackRange {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value", "max_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeMaintainAttackRange {}
 impl std::cmp::PartialEq for BehaviorTreeNodeMaintainAttackRange {
     fn eq(&self, other: &Self) -> bool {
@@ -6337,9 +11265,12 @@ pub struct BehaviorTreeNodeWait {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#cooldown: f32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#in_range: bool,
@@ -6397,6 +11328,37 @@ impl DatabaseItem for BehaviorTreeNodeWait {
         "BehaviorTreeNodeWait"
     }
 }
+impl VisitIds for BehaviorTreeNodeWait {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeWait {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeWait {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "cooldown", "in_range"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            "in_range" => Some(FieldValue::of(&self.r#in_range)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            "in_range" => value.write_to(&mut self.r#in_range, "in_range"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeWait {}
 impl std::cmp::PartialEq for BehaviorTreeNodeWait {
     fn eq(&self, other: &Self) -> bool {
@@ -6469,6 +11431,33 @@ impl DatabaseItem for BehaviorTreeNodeLookAtTarget {
         "BehaviorTreeNodeLookAtTarget"
     }
 }
+impl VisitIds for BehaviorTreeNodeLookAtTarget {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeLookAtTarget {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeLookAtTarget {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeLookAtTarget {
     fn default() -> Self {
         Self::new()
@@ -6495,6 +11484,8 @@ pub struct BehaviorTreeNodeLookForAdditionalTargets {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0.1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#cooldown: f32,
@@ -6543,6 +11534,35 @@ impl DatabaseItem for BehaviorTreeNodeLookForAdditionalTargets {
         "BehaviorTreeNodeLookForAdditionalTargets"
     }
 }
+impl VisitIds for BehaviorTreeNodeLookForAdditionalTargets {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeLookForAdditionalTargets {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeLookForAdditionalTargets {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "cooldown"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeLookForAdditionalTargets {}
 impl std::cmp::PartialEq for BehaviorTreeNodeLookForAdditionalTargets {
     fn eq(&self, other: &Self) -> bool {
@@ -6583,6 +11603,8 @@ pub struct BehaviorTreeNodeLookForThreats {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0.1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#cooldown: f32,
@@ -6631,6 +11653,35 @@ impl DatabaseItem for BehaviorTreeNodeLookForThreats {
         "BehaviorTreeNodeLookForThreats"
     }
 }
+impl VisitIds for BehaviorTreeNodeLookForThreats {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeLookForThreats {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeLookForThreats {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "cooldown"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeLookForThreats {}
 impl std::cmp::PartialEq for BehaviorTreeNodeLookForThreats {
     fn eq(&self, other: &Self) -> bool {
@@ -6672,6 +11723,10 @@ pub struct BehaviorTreeNodeMatchVelocityWithTarget {
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
     ///Acceptable speed deviation
+    ///
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.2`
     #[serde(default = "default_0ඞdotඞ2f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ2f32")]
     pub r#max_value: f32,
@@ -6723,6 +11778,35 @@ impl DatabaseItem for BehaviorTreeNodeMatchVelocityWithTarget {
         "BehaviorTreeNodeMatchVelocityWithTarget"
     }
 }
+impl VisitIds for BehaviorTreeNodeMatchVelocityWithTarget {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMatchVelocityWithTarget {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMatchVelocityWithTarget {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "max_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeMatchVelocityWithTarget {}
 impl std::cmp::PartialEq for BehaviorTreeNodeMatchVelocityWithTarget {
     fn eq(&self, other: &Self) -> bool {
@@ -6804,6 +11888,35 @@ impl DatabaseItem for BehaviorTreeNodeActivateDevice {
         "BehaviorTreeNodeActivateDevice"
     }
 }
+impl VisitIds for BehaviorTreeNodeActivateDevice {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeActivateDevice {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeActivateDevice {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "device_class"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "device_class" => Some(FieldValue::of(&self.r#device_class)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "device_class" => value.write_to(&mut self.r#device_class, "device_class"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeActivateDevice {
     fn default() -> Self {
         Self::new()
@@ -6830,9 +11943,15 @@ pub struct BehaviorTreeNodeRechargeEnergy {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.1`
     #[serde(default = "default_0ඞdotඞ1f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ1f32")]
     pub r#min_value: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.9`
     #[serde(default = "default_0ඞdotඞ9f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ9f32")]
     pub r#max_value: f32,
@@ -6902,6 +12021,37 @@ impl DatabaseItem for BehaviorTreeNodeRechargeEnergy {
         "BehaviorTreeNodeRechargeEnergy"
     }
 }
+impl VisitIds for BehaviorTreeNodeRechargeEnergy {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeRechargeEnergy {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeRechargeEnergy {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value", "max_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeRechargeEnergy {}
 impl std::cmp::PartialEq for BehaviorTreeNodeRechargeEnergy {
     fn eq(&self, other: &Self) -> bool {
@@ -6975,6 +12125,33 @@ impl DatabaseItem for BehaviorTreeNodeSustainAim {
         "BehaviorTreeNodeSustainAim"
     }
 }
+impl VisitIds for BehaviorTreeNodeSustainAim {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeSustainAim {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeSustainAim {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeSustainAim {
     fn default() -> Self {
         Self::new()
@@ -7031,6 +12208,33 @@ impl DatabaseItem for BehaviorTreeNodeChargeWeapons {
         "BehaviorTreeNodeChargeWeapons"
     }
 }
+impl VisitIds for BehaviorTreeNodeChargeWeapons {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeChargeWeapons {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeChargeWeapons {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeChargeWeapons {
     fn default() -> Self {
         Self::new()
@@ -7087,6 +12291,33 @@ impl DatabaseItem for BehaviorTreeNodeChase {
         "BehaviorTreeNodeChase"
     }
 }
+impl VisitIds for BehaviorTreeNodeChase {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeChase {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeChase {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeChase {
     fn default() -> Self {
         Self::new()
@@ -7143,6 +12374,33 @@ impl DatabaseItem for BehaviorTreeNodeAvoidThreats {
         "BehaviorTreeNodeAvoidThreats"
     }
 }
+impl VisitIds for BehaviorTreeNodeAvoidThreats {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeAvoidThreats {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeAvoidThreats {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeAvoidThreats {
     fn default() -> Self {
         Self::new()
@@ -7170,6 +12428,10 @@ pub struct BehaviorTreeNodeSlowDown {
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
     ///Acceptable speed deviation
+    ///
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.2`
     #[serde(default = "default_0ඞdotඞ2f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ2f32")]
     pub r#max_value: f32,
@@ -7221,6 +12483,35 @@ impl DatabaseItem for BehaviorTreeNodeSlowDown {
         "BehaviorTreeNodeSlowDown"
     }
 }
+impl VisitIds for BehaviorTreeNodeSlowDown {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeSlowDown {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeSlowDown {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "max_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeSlowDown {}
 impl std::cmp::PartialEq for BehaviorTreeNodeSlowDown {
     fn eq(&self, other: &Self) -> bool {
@@ -7291,6 +12582,33 @@ impl DatabaseItem for BehaviorTreeNodeUseRecoil {
         "BehaviorTreeNodeUseRecoil"
     }
 }
+impl VisitIds for BehaviorTreeNodeUseRecoil {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeUseRecoil {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeUseRecoil {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeUseRecoil {
     fn default() -> Self {
         Self::new()
@@ -7347,6 +12665,33 @@ impl DatabaseItem for BehaviorTreeNodeDefendWithFronalShield {
         "BehaviorTreeNodeDefendWithFronalShield"
     }
 }
+impl VisitIds for BehaviorTreeNodeDefendWithFronalShield {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeDefendWithFronalShield {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeDefendWithFronalShield {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeDefendWithFronalShield {
     fn default() -> Self {
         Self::new()
@@ -7403,6 +12748,33 @@ impl DatabaseItem for BehaviorTreeNodeTrackControllableAmmo {
         "BehaviorTreeNodeTrackControllableAmmo"
     }
 }
+impl VisitIds for BehaviorTreeNodeTrackControllableAmmo {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeTrackControllableAmmo {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeTrackControllableAmmo {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeTrackControllableAmmo {
     fn default() -> Self {
         Self::new()
@@ -7429,9 +12801,15 @@ pub struct BehaviorTreeNodeKeepDistance {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `2.5`
     #[serde(default = "default_2ඞdotඞ5f32")]
     #[serde(skip_serializing_if = "skip_if_2ඞdotඞ5f32")]
     pub r#min_value: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `3.5`
     #[serde(default = "default_3ඞdotඞ5f32")]
     #[serde(skip_serializing_if = "skip_if_3ඞdotඞ5f32")]
     pub r#max_value: f32,
@@ -7501,6 +12879,37 @@ impl DatabaseItem for BehaviorTreeNodeKeepDistance {
         "BehaviorTreeNodeKeepDistance"
     }
 }
+impl VisitIds for BehaviorTreeNodeKeepDistance {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeKeepDistance {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeKeepDistance {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value", "max_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeKeepDistance {}
 impl std::cmp::PartialEq for BehaviorTreeNodeKeepDistance {
     fn eq(&self, other: &Self) -> bool {
@@ -7574,6 +12983,33 @@ impl DatabaseItem for BehaviorTreeNodeForgetMainTarget {
         "BehaviorTreeNodeForgetMainTarget"
     }
 }
+impl VisitIds for BehaviorTreeNodeForgetMainTarget {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeForgetMainTarget {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeForgetMainTarget {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeForgetMainTarget {
     fn default() -> Self {
         Self::new()
@@ -7630,6 +13066,33 @@ impl DatabaseItem for BehaviorTreeNodeEscapeTargetAttackRadius {
         "BehaviorTreeNodeEscapeTargetAttackRadius"
     }
 }
+impl VisitIds for BehaviorTreeNodeEscapeTargetAttackRadius {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeEscapeTargetAttackRadius {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeEscapeTargetAttackRadius {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeEscapeTargetAttackRadius {
     fn default() -> Self {
         Self::new()
@@ -7656,6 +13119,7 @@ pub struct BehaviorTreeNodeAttackAdditionalTargets {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#in_range: bool,
@@ -7698,6 +13162,35 @@ impl DatabaseItem for BehaviorTreeNodeAttackAdditionalTargets {
         "BehaviorTreeNodeAttackAdditionalTargets"
     }
 }
+impl VisitIds for BehaviorTreeNodeAttackAdditionalTargets {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeAttackAdditionalTargets {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeAttackAdditionalTargets {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "in_range"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "in_range" => Some(FieldValue::of(&self.r#in_range)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "in_range" => value.write_to(&mut self.r#in_range, "in_range"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeAttackAdditionalTargets {
     fn default() -> Self {
         Self::new()
@@ -7754,6 +13247,33 @@ impl DatabaseItem for BehaviorTreeNodeTargetAllyStarbase {
         "BehaviorTreeNodeTargetAllyStarbase"
     }
 }
+impl VisitIds for BehaviorTreeNodeTargetAllyStarbase {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeTargetAllyStarbase {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeTargetAllyStarbase {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeTargetAllyStarbase {
     fn default() -> Self {
         Self::new()
@@ -7810,6 +13330,33 @@ impl DatabaseItem for BehaviorTreeNodeTargetEnemyStarbase {
         "BehaviorTreeNodeTargetEnemyStarbase"
     }
 }
+impl VisitIds for BehaviorTreeNodeTargetEnemyStarbase {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeTargetEnemyStarbase {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeTargetEnemyStarbase {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeTargetEnemyStarbase {
     fn default() -> Self {
         Self::new()
@@ -7866,6 +13413,33 @@ impl DatabaseItem for BehaviorTreeNodeBypassObstacles {
         "BehaviorTreeNodeBypassObstacles"
     }
 }
+impl VisitIds for BehaviorTreeNodeBypassObstacles {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeBypassObstacles {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeBypassObstacles {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeBypassObstacles {
     fn default() -> Self {
         Self::new()
@@ -7922,6 +13496,33 @@ impl DatabaseItem for BehaviorTreeNodeAttackTurretTargets {
         "BehaviorTreeNodeAttackTurretTargets"
     }
 }
+impl VisitIds for BehaviorTreeNodeAttackTurretTargets {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeAttackTurretTargets {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeAttackTurretTargets {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeAttackTurretTargets {
     fn default() -> Self {
         Self::new()
@@ -7978,6 +13579,33 @@ impl DatabaseItem for BehaviorTreeNodeHoldHarpoon {
         "BehaviorTreeNodeHoldHarpoon"
     }
 }
+impl VisitIds for BehaviorTreeNodeHoldHarpoon {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeHoldHarpoon {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeHoldHarpoon {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeHoldHarpoon {
     fn default() -> Self {
         Self::new()
@@ -8004,12 +13632,17 @@ pub struct BehaviorTreeNodeFindDamagedAlly {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0.5`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#min_value: f32,
+    /// - Minimum: `0`
+    /// - Default: `5`
     #[serde(default = "default_5f32")]
     #[serde(skip_serializing_if = "skip_if_5f32")]
     pub r#max_value: f32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#in_range: bool,
@@ -8082,6 +13715,39 @@ impl DatabaseItem for BehaviorTreeNodeFindDamagedAlly {
         "BehaviorTreeNodeFindDamagedAlly"
     }
 }
+impl VisitIds for BehaviorTreeNodeFindDamagedAlly {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeFindDamagedAlly {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeFindDamagedAlly {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value", "max_value", "in_range"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            "in_range" => Some(FieldValue::of(&self.r#in_range)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            "in_range" => value.write_to(&mut self.r#in_range, "in_range"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeFindDamagedAlly {}
 impl std::cmp::PartialEq for BehaviorTreeNodeFindDamagedAlly {
     fn eq(&self, other: &Self) -> bool {
@@ -8127,6 +13793,9 @@ pub struct BehaviorTreeNodeEnginePropulsionForce {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#min_value: f32,
@@ -8178,6 +13847,35 @@ impl DatabaseItem for BehaviorTreeNodeEnginePropulsionForce {
         "BehaviorTreeNodeEnginePropulsionForce"
     }
 }
+impl VisitIds for BehaviorTreeNodeEnginePropulsionForce {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeEnginePropulsionForce {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeEnginePropulsionForce {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeEnginePropulsionForce {}
 impl std::cmp::PartialEq for BehaviorTreeNodeEnginePropulsionForce {
     fn eq(&self, other: &Self) -> bool {
@@ -8248,6 +13946,33 @@ impl DatabaseItem for BehaviorTreeNodeMotherShipRetreated {
         "BehaviorTreeNodeMotherShipRetreated"
     }
 }
+impl VisitIds for BehaviorTreeNodeMotherShipRetreated {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMotherShipRetreated {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMotherShipRetreated {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeMotherShipRetreated {
     fn default() -> Self {
         Self::new()
@@ -8304,6 +14029,33 @@ impl DatabaseItem for BehaviorTreeNodeMotherShipDestroyed {
         "BehaviorTreeNodeMotherShipDestroyed"
     }
 }
+impl VisitIds for BehaviorTreeNodeMotherShipDestroyed {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMotherShipDestroyed {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMotherShipDestroyed {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeMotherShipDestroyed {
     fn default() -> Self {
         Self::new()
@@ -8330,9 +14082,15 @@ pub struct BehaviorTreeNodeFlyAroundMothership {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `2.5`
     #[serde(default = "default_2ඞdotඞ5f32")]
     #[serde(skip_serializing_if = "skip_if_2ඞdotඞ5f32")]
     pub r#min_value: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `3.5`
     #[serde(default = "default_3ඞdotඞ5f32")]
     #[serde(skip_serializing_if = "skip_if_3ඞdotඞ5f32")]
     pub r#max_value: f32,
@@ -8402,6 +14160,37 @@ impl DatabaseItem for BehaviorTreeNodeFlyAroundMothership {
         "BehaviorTreeNodeFlyAroundMothership"
     }
 }
+impl VisitIds for BehaviorTreeNodeFlyAroundMothership {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeFlyAroundMothership {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeFlyAroundMothership {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value", "max_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeFlyAroundMothership {}
 impl std::cmp::PartialEq for BehaviorTreeNodeFlyAroundMothership {
     fn eq(&self, other: &Self) -> bool {
@@ -8475,6 +14264,33 @@ impl DatabaseItem for BehaviorTreeNodeGoBerserk {
         "BehaviorTreeNodeGoBerserk"
     }
 }
+impl VisitIds for BehaviorTreeNodeGoBerserk {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeGoBerserk {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeGoBerserk {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeGoBerserk {
     fn default() -> Self {
         Self::new()
@@ -8531,6 +14347,33 @@ impl DatabaseItem for BehaviorTreeNodeTargetMothership {
         "BehaviorTreeNodeTargetMothership"
     }
 }
+impl VisitIds for BehaviorTreeNodeTargetMothership {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeTargetMothership {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeTargetMothership {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeTargetMothership {
     fn default() -> Self {
         Self::new()
@@ -8557,6 +14400,9 @@ pub struct BehaviorTreeNodeMothershipLowHp {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#min_value: f32,
@@ -8608,6 +14454,35 @@ impl DatabaseItem for BehaviorTreeNodeMothershipLowHp {
         "BehaviorTreeNodeMothershipLowHp"
     }
 }
+impl VisitIds for BehaviorTreeNodeMothershipLowHp {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMothershipLowHp {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMothershipLowHp {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeMothershipLowHp {}
 impl std::cmp::PartialEq for BehaviorTreeNodeMothershipLowHp {
     fn eq(&self, other: &Self) -> bool {
@@ -8649,6 +14524,9 @@ pub struct BehaviorTreeNodeMothershipDistanceExceeded {
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
     ///Max distance. If value is 0, prefefined value will be used (e.g. DroneBay range)
+    ///
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#max_value: f32,
@@ -8697,6 +14575,35 @@ impl DatabaseItem for BehaviorTreeNodeMothershipDistanceExceeded {
         "BehaviorTreeNodeMothershipDistanceExceeded"
     }
 }
+impl VisitIds for BehaviorTreeNodeMothershipDistanceExceeded {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMothershipDistanceExceeded {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMothershipDistanceExceeded {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "max_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeMothershipDistanceExceeded {}
 impl std::cmp::PartialEq for BehaviorTreeNodeMothershipDistanceExceeded {
     fn eq(&self, other: &Self) -> bool {
@@ -8767,6 +14674,33 @@ impl DatabaseItem for BehaviorTreeNodeMakeTargetMothership {
         "BehaviorTreeNodeMakeTargetMothership"
     }
 }
+impl VisitIds for BehaviorTreeNodeMakeTargetMothership {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMakeTargetMothership {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMakeTargetMothership {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeMakeTargetMothership {
     fn default() -> Self {
         Self::new()
@@ -8793,6 +14727,9 @@ pub struct BehaviorTreeNodeMothershipLowEnergy {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#min_value: f32,
@@ -8844,6 +14781,35 @@ impl DatabaseItem for BehaviorTreeNodeMothershipLowEnergy {
         "BehaviorTreeNodeMothershipLowEnergy"
     }
 }
+impl VisitIds for BehaviorTreeNodeMothershipLowEnergy {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMothershipLowEnergy {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMothershipLowEnergy {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeMothershipLowEnergy {}
 impl std::cmp::PartialEq for BehaviorTreeNodeMothershipLowEnergy {
     fn eq(&self, other: &Self) -> bool {
@@ -8884,6 +14850,9 @@ pub struct BehaviorTreeNodeMothershipLowShield {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#min_value: f32,
@@ -8935,6 +14904,35 @@ impl DatabaseItem for BehaviorTreeNodeMothershipLowShield {
         "BehaviorTreeNodeMothershipLowShield"
     }
 }
+impl VisitIds for BehaviorTreeNodeMothershipLowShield {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMothershipLowShield {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMothershipLowShield {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "min_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BehaviorTreeNodeMothershipLowShield {}
 impl std::cmp::PartialEq for BehaviorTreeNodeMothershipLowShield {
     fn eq(&self, other: &Self) -> bool {
@@ -8977,6 +14975,7 @@ pub struct BehaviorTreeNodeShowMessage {
     pub r#requirement: BehaviorNodeRequirement,
     #[serde(default)]
     pub r#text: String,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#color: String,
@@ -9023,11 +15022,48 @@ impl DatabaseItem for BehaviorTreeNodeShowMessage {
             let ctx = ctx.enter("requirement");
             self.r#requirement.validate(ctx);
         }
+        {
+            let mut ctx = ctx.enter("color");
+            if !crate::color::Color::is_valid(&self.r#color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#color));
+            }
+        }
     }
     fn type_name() -> &'static str {
         "BehaviorTreeNodeShowMessage"
     }
 }
+impl VisitIds for BehaviorTreeNodeShowMessage {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeShowMessage {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeShowMessage {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "text", "color"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "text" => Some(FieldValue::of(&self.r#text)),
+            "color" => Some(FieldValue::of(&self.r#color)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "text" => value.write_to(&mut self.r#text, "text"),
+            "color" => value.write_to(&mut self.r#color, "color"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeShowMessage {
     fn default() -> Self {
         Self::new()
@@ -9095,6 +15131,35 @@ impl DatabaseItem for BehaviorTreeNodeDebugLog {
         "BehaviorTreeNodeDebugLog"
     }
 }
+impl VisitIds for BehaviorTreeNodeDebugLog {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeDebugLog {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeDebugLog {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "text"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "text" => Some(FieldValue::of(&self.r#text)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "text" => value.write_to(&mut self.r#text, "text"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeDebugLog {
     fn default() -> Self {
         Self::new()
@@ -9121,6 +15186,7 @@ pub struct BehaviorTreeNodeSetValue {
     ///The node will not execute and will return FAILURE if the requirement is not met
     #[serde(default)]
     pub r#requirement: BehaviorNodeRequirement,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#result: bool,
@@ -9174,6 +15240,37 @@ impl DatabaseItem for BehaviorTreeNodeSetValue {
         "BehaviorTreeNodeSetValue"
     }
 }
+impl VisitIds for BehaviorTreeNodeSetValue {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeSetValue {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeSetValue {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "result", "text"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "result" => Some(FieldValue::of(&self.r#result)),
+            "text" => Some(FieldValue::of(&self.r#text)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "result" => value.write_to(&mut self.r#result, "result"),
+            "text" => value.write_to(&mut self.r#text, "text"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeSetValue {
     fn default() -> Self {
         Self::new()
@@ -9241,6 +15338,35 @@ impl DatabaseItem for BehaviorTreeNodeGetValue {
         "BehaviorTreeNodeGetValue"
     }
 }
+impl VisitIds for BehaviorTreeNodeGetValue {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeGetValue {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeGetValue {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "text"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "text" => Some(FieldValue::of(&self.r#text)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "text" => value.write_to(&mut self.r#text, "text"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeGetValue {
     fn default() -> Self {
         Self::new()
@@ -9308,6 +15434,35 @@ impl DatabaseItem for BehaviorTreeNodeSendMessage {
         "BehaviorTreeNodeSendMessage"
     }
 }
+impl VisitIds for BehaviorTreeNodeSendMessage {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeSendMessage {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeSendMessage {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "text"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "text" => Some(FieldValue::of(&self.r#text)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "text" => value.write_to(&mut self.r#text, "text"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeSendMessage {
     fn default() -> Self {
         Self::new()
@@ -9375,6 +15530,35 @@ impl DatabaseItem for BehaviorTreeNodeMessageReceived {
         "BehaviorTreeNodeMessageReceived"
     }
 }
+impl VisitIds for BehaviorTreeNodeMessageReceived {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeMessageReceived {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeMessageReceived {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "text"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "text" => Some(FieldValue::of(&self.r#text)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "text" => value.write_to(&mut self.r#text, "text"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeMessageReceived {
     fn default() -> Self {
         Self::new()
@@ -9431,6 +15615,33 @@ impl DatabaseItem for BehaviorTreeNodeTargetMessageSender {
         "BehaviorTreeNodeTargetMessageSender"
     }
 }
+impl VisitIds for BehaviorTreeNodeTargetMessageSender {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeTargetMessageSender {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeTargetMessageSender {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeTargetMessageSender {
     fn default() -> Self {
         Self::new()
@@ -9498,6 +15709,35 @@ impl DatabaseItem for BehaviorTreeNodeSaveTarget {
         "BehaviorTreeNodeSaveTarget"
     }
 }
+impl VisitIds for BehaviorTreeNodeSaveTarget {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeSaveTarget {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeSaveTarget {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "text"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "text" => Some(FieldValue::of(&self.r#text)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "text" => value.write_to(&mut self.r#text, "text"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeSaveTarget {
     fn default() -> Self {
         Self::new()
@@ -9565,6 +15805,35 @@ impl DatabaseItem for BehaviorTreeNodeLoadTarget {
         "BehaviorTreeNodeLoadTarget"
     }
 }
+impl VisitIds for BehaviorTreeNodeLoadTarget {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeLoadTarget {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeLoadTarget {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "text"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "text" => Some(FieldValue::of(&self.r#text)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "text" => value.write_to(&mut self.r#text, "text"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeLoadTarget {
     fn default() -> Self {
         Self::new()
@@ -9632,6 +15901,35 @@ impl DatabaseItem for BehaviorTreeNodeHasSavedTarget {
         "BehaviorTreeNodeHasSavedTarget"
     }
 }
+impl VisitIds for BehaviorTreeNodeHasSavedTarget {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeHasSavedTarget {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeHasSavedTarget {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "text"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "text" => Some(FieldValue::of(&self.r#text)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "text" => value.write_to(&mut self.r#text, "text"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeHasSavedTarget {
     fn default() -> Self {
         Self::new()
@@ -9699,6 +15997,35 @@ impl DatabaseItem for BehaviorTreeNodeForgetSavedTarget {
         "BehaviorTreeNodeForgetSavedTarget"
     }
 }
+impl VisitIds for BehaviorTreeNodeForgetSavedTarget {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTreeNodeForgetSavedTarget {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTreeNodeForgetSavedTarget {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirement", "text"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "text" => Some(FieldValue::of(&self.r#text)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "text" => value.write_to(&mut self.r#text, "text"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BehaviorTreeNodeForgetSavedTarget {
     fn default() -> Self {
         Self::new()
@@ -11380,6 +17707,447 @@ impl DatabaseItem for BehaviorTreeNode {
         "BehaviorTreeNode"
     }
 }
+impl VisitIds for BehaviorTreeNode {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        match self {
+            Self::Success(x) => x.visit_ids(visitor),
+            Self::Failure(x) => x.visit_ids(visitor),
+            Self::SubTree(x) => x.visit_ids(visitor),
+            Self::Selector(x) => x.visit_ids(visitor),
+            Self::Sequence(x) => x.visit_ids(visitor),
+            Self::Parallel(x) => x.visit_ids(visitor),
+            Self::RandomSelector(x) => x.visit_ids(visitor),
+            Self::Invertor(x) => x.visit_ids(visitor),
+            Self::Cooldown(x) => x.visit_ids(visitor),
+            Self::Execute(x) => x.visit_ids(visitor),
+            Self::ParallelSequence(x) => x.visit_ids(visitor),
+            Self::PreserveTarget(x) => x.visit_ids(visitor),
+            Self::IfThenElse(x) => x.visit_ids(visitor),
+            Self::HasEnoughEnergy(x) => x.visit_ids(visitor),
+            Self::IsLowOnHp(x) => x.visit_ids(visitor),
+            Self::IsNotControledByPlayer(x) => x.visit_ids(visitor),
+            Self::HasIncomingThreat(x) => x.visit_ids(visitor),
+            Self::HasAdditionalTargets(x) => x.visit_ids(visitor),
+            Self::IsFasterThanTarget(x) => x.visit_ids(visitor),
+            Self::HasMainTarget(x) => x.visit_ids(visitor),
+            Self::MainTargetIsAlly(x) => x.visit_ids(visitor),
+            Self::MainTargetIsEnemy(x) => x.visit_ids(visitor),
+            Self::MainTargetLowHp(x) => x.visit_ids(visitor),
+            Self::MainTargetWithinAttackRange(x) => x.visit_ids(visitor),
+            Self::HasMothership(x) => x.visit_ids(visitor),
+            Self::TargetDistance(x) => x.visit_ids(visitor),
+            Self::HasLongerAttackRange(x) => x.visit_ids(visitor),
+            Self::FindEnemy(x) => x.visit_ids(visitor),
+            Self::MoveToAttackRange(x) => x.visit_ids(visitor),
+            Self::AttackMainTarget(x) => x.visit_ids(visitor),
+            Self::SelectWeapon(x) => x.visit_ids(visitor),
+            Self::SpawnDrones(x) => x.visit_ids(visitor),
+            Self::Ram(x) => x.visit_ids(visitor),
+            Self::DetonateShip(x) => x.visit_ids(visitor),
+            Self::Vanish(x) => x.visit_ids(visitor),
+            Self::MaintainAttackRange(x) => x.visit_ids(visitor),
+            Self::Wait(x) => x.visit_ids(visitor),
+            Self::LookAtTarget(x) => x.visit_ids(visitor),
+            Self::LookForAdditionalTargets(x) => x.visit_ids(visitor),
+            Self::LookForThreats(x) => x.visit_ids(visitor),
+            Self::MatchVelocityWithTarget(x) => x.visit_ids(visitor),
+            Self::ActivateDevice(x) => x.visit_ids(visitor),
+            Self::RechargeEnergy(x) => x.visit_ids(visitor),
+            Self::SustainAim(x) => x.visit_ids(visitor),
+            Self::ChargeWeapons(x) => x.visit_ids(visitor),
+            Self::Chase(x) => x.visit_ids(visitor),
+            Self::AvoidThreats(x) => x.visit_ids(visitor),
+            Self::SlowDown(x) => x.visit_ids(visitor),
+            Self::UseRecoil(x) => x.visit_ids(visitor),
+            Self::DefendWithFronalShield(x) => x.visit_ids(visitor),
+            Self::TrackControllableAmmo(x) => x.visit_ids(visitor),
+            Self::KeepDistance(x) => x.visit_ids(visitor),
+            Self::ForgetMainTarget(x) => x.visit_ids(visitor),
+            Self::EscapeTargetAttackRadius(x) => x.visit_ids(visitor),
+            Self::AttackAdditionalTargets(x) => x.visit_ids(visitor),
+            Self::TargetAllyStarbase(x) => x.visit_ids(visitor),
+            Self::TargetEnemyStarbase(x) => x.visit_ids(visitor),
+            Self::BypassObstacles(x) => x.visit_ids(visitor),
+            Self::AttackTurretTargets(x) => x.visit_ids(visitor),
+            Self::HoldHarpoon(x) => x.visit_ids(visitor),
+            Self::FindDamagedAlly(x) => x.visit_ids(visitor),
+            Self::EnginePropulsionForce(x) => x.visit_ids(visitor),
+            Self::MotherShipRetreated(x) => x.visit_ids(visitor),
+            Self::MotherShipDestroyed(x) => x.visit_ids(visitor),
+            Self::FlyAroundMothership(x) => x.visit_ids(visitor),
+            Self::GoBerserk(x) => x.visit_ids(visitor),
+            Self::TargetMothership(x) => x.visit_ids(visitor),
+            Self::MothershipLowHp(x) => x.visit_ids(visitor),
+            Self::MothershipDistanceExceeded(x) => x.visit_ids(visitor),
+            Self::MakeTargetMothership(x) => x.visit_ids(visitor),
+            Self::MothershipLowEnergy(x) => x.visit_ids(visitor),
+            Self::MothershipLowShield(x) => x.visit_ids(visitor),
+            Self::ShowMessage(x) => x.visit_ids(visitor),
+            Self::DebugLog(x) => x.visit_ids(visitor),
+            Self::SetValue(x) => x.visit_ids(visitor),
+            Self::GetValue(x) => x.visit_ids(visitor),
+            Self::SendMessage(x) => x.visit_ids(visitor),
+            Self::MessageReceived(x) => x.visit_ids(visitor),
+            Self::TargetMessageSender(x) => x.visit_ids(visitor),
+            Self::SaveTarget(x) => x.visit_ids(visitor),
+            Self::LoadTarget(x) => x.visit_ids(visitor),
+            Self::HasSavedTarget(x) => x.visit_ids(visitor),
+            Self::ForgetSavedTarget(x) => x.visit_ids(visitor),
+        }
+    }
+}
+impl VisitAssets for BehaviorTreeNode {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        match self {
+            Self::Success(x) => x.visit_assets(visitor),
+            Self::Failure(x) => x.visit_assets(visitor),
+            Self::SubTree(x) => x.visit_assets(visitor),
+            Self::Selector(x) => x.visit_assets(visitor),
+            Self::Sequence(x) => x.visit_assets(visitor),
+            Self::Parallel(x) => x.visit_assets(visitor),
+            Self::RandomSelector(x) => x.visit_assets(visitor),
+            Self::Invertor(x) => x.visit_assets(visitor),
+            Self::Cooldown(x) => x.visit_assets(visitor),
+            Self::Execute(x) => x.visit_assets(visitor),
+            Self::ParallelSequence(x) => x.visit_assets(visitor),
+            Self::PreserveTarget(x) => x.visit_assets(visitor),
+            Self::IfThenElse(x) => x.visit_assets(visitor),
+            Self::HasEnoughEnergy(x) => x.visit_assets(visitor),
+            Self::IsLowOnHp(x) => x.visit_assets(visitor),
+            Self::IsNotControledByPlayer(x) => x.visit_assets(visitor),
+            Self::HasIncomingThreat(x) => x.visit_assets(visitor),
+            Self::HasAdditionalTargets(x) => x.visit_assets(visitor),
+            Self::IsFasterThanTarget(x) => x.visit_assets(visitor),
+            Self::HasMainTarget(x) => x.visit_assets(visitor),
+            Self::MainTargetIsAlly(x) => x.visit_assets(visitor),
+            Self::MainTargetIsEnemy(x) => x.visit_assets(visitor),
+            Self::MainTargetLowHp(x) => x.visit_assets(visitor),
+            Self::MainTargetWithinAttackRange(x) => x.visit_assets(visitor),
+            Self::HasMothership(x) => x.visit_assets(visitor),
+            Self::TargetDistance(x) => x.visit_assets(visitor),
+            Self::HasLongerAttackRange(x) => x.visit_assets(visitor),
+            Self::FindEnemy(x) => x.visit_assets(visitor),
+            Self::MoveToAttackRange(x) => x.visit_assets(visitor),
+            Self::AttackMainTarget(x) => x.visit_assets(visitor),
+            Self::SelectWeapon(x) => x.visit_assets(visitor),
+            Self::SpawnDrones(x) => x.visit_assets(visitor),
+            Self::Ram(x) => x.visit_assets(visitor),
+            Self::DetonateShip(x) => x.visit_assets(visitor),
+            Self::Vanish(x) => x.visit_assets(visitor),
+            Self::MaintainAttackRange(x) => x.visit_assets(visitor),
+            Self::Wait(x) => x.visit_assets(visitor),
+            Self::LookAtTarget(x) => x.visit_assets(visitor),
+            Self::LookForAdditionalTargets(x) => x.visit_assets(visitor),
+            Self::LookForThreats(x) => x.visit_assets(visitor),
+            Self::MatchVelocityWithTarget(x) => x.visit_assets(visitor),
+            Self::ActivateDevice(x) => x.visit_assets(visitor),
+            Self::RechargeEnergy(x) => x.visit_assets(visitor),
+            Self::SustainAim(x) => x.visit_assets(visitor),
+            Self::ChargeWeapons(x) => x.visit_assets(visitor),
+            Self::Chase(x) => x.visit_assets(visitor),
+            Self::AvoidThreats(x) => x.visit_assets(visitor),
+            Self::SlowDown(x) => x.visit_assets(visitor),
+            Self::UseRecoil(x) => x.visit_assets(visitor),
+            Self::DefendWithFronalShield(x) => x.visit_assets(visitor),
+            Self::TrackControllableAmmo(x) => x.visit_assets(visitor),
+            Self::KeepDistance(x) => x.visit_assets(visitor),
+            Self::ForgetMainTarget(x) => x.visit_assets(visitor),
+            Self::EscapeTargetAttackRadius(x) => x.visit_assets(visitor),
+            Self::AttackAdditionalTargets(x) => x.visit_assets(visitor),
+            Self::TargetAllyStarbase(x) => x.visit_assets(visitor),
+            Self::TargetEnemyStarbase(x) => x.visit_assets(visitor),
+            Self::BypassObstacles(x) => x.visit_assets(visitor),
+            Self::AttackTurretTargets(x) => x.visit_assets(visitor),
+            Self::HoldHarpoon(x) => x.visit_assets(visitor),
+            Self::FindDamagedAlly(x) => x.visit_assets(visitor),
+            Self::EnginePropulsionForce(x) => x.visit_assets(visitor),
+            Self::MotherShipRetreated(x) => x.visit_assets(visitor),
+            Self::MotherShipDestroyed(x) => x.visit_assets(visitor),
+            Self::FlyAroundMothership(x) => x.visit_assets(visitor),
+            Self::GoBerserk(x) => x.visit_assets(visitor),
+            Self::TargetMothership(x) => x.visit_assets(visitor),
+            Self::MothershipLowHp(x) => x.visit_assets(visitor),
+            Self::MothershipDistanceExceeded(x) => x.visit_assets(visitor),
+            Self::MakeTargetMothership(x) => x.visit_assets(visitor),
+            Self::MothershipLowEnergy(x) => x.visit_assets(visitor),
+            Self::MothershipLowShield(x) => x.visit_assets(visitor),
+            Self::ShowMessage(x) => x.visit_assets(visitor),
+            Self::DebugLog(x) => x.visit_assets(visitor),
+            Self::SetValue(x) => x.visit_assets(visitor),
+            Self::GetValue(x) => x.visit_assets(visitor),
+            Self::SendMessage(x) => x.visit_assets(visitor),
+            Self::MessageReceived(x) => x.visit_assets(visitor),
+            Self::TargetMessageSender(x) => x.visit_assets(visitor),
+            Self::SaveTarget(x) => x.visit_assets(visitor),
+            Self::LoadTarget(x) => x.visit_assets(visitor),
+            Self::HasSavedTarget(x) => x.visit_assets(visitor),
+            Self::ForgetSavedTarget(x) => x.visit_assets(visitor),
+        }
+    }
+}
+impl DynamicFields for BehaviorTreeNode {
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Success(x) => x.field_names(),
+            Self::Failure(x) => x.field_names(),
+            Self::SubTree(x) => x.field_names(),
+            Self::Selector(x) => x.field_names(),
+            Self::Sequence(x) => x.field_names(),
+            Self::Parallel(x) => x.field_names(),
+            Self::RandomSelector(x) => x.field_names(),
+            Self::Invertor(x) => x.field_names(),
+            Self::Cooldown(x) => x.field_names(),
+            Self::Execute(x) => x.field_names(),
+            Self::ParallelSequence(x) => x.field_names(),
+            Self::PreserveTarget(x) => x.field_names(),
+            Self::IfThenElse(x) => x.field_names(),
+            Self::HasEnoughEnergy(x) => x.field_names(),
+            Self::IsLowOnHp(x) => x.field_names(),
+            Self::IsNotControledByPlayer(x) => x.field_names(),
+            Self::HasIncomingThreat(x) => x.field_names(),
+            Self::HasAdditionalTargets(x) => x.field_names(),
+            Self::IsFasterThanTarget(x) => x.field_names(),
+            Self::HasMainTarget(x) => x.field_names(),
+            Self::MainTargetIsAlly(x) => x.field_names(),
+            Self::MainTargetIsEnemy(x) => x.field_names(),
+            Self::MainTargetLowHp(x) => x.field_names(),
+            Self::MainTargetWithinAttackRange(x) => x.field_names(),
+            Self::HasMothership(x) => x.field_names(),
+            Self::TargetDistance(x) => x.field_names(),
+            Self::HasLongerAttackRange(x) => x.field_names(),
+            Self::FindEnemy(x) => x.field_names(),
+            Self::MoveToAttackRange(x) => x.field_names(),
+            Self::AttackMainTarget(x) => x.field_names(),
+            Self::SelectWeapon(x) => x.field_names(),
+            Self::SpawnDrones(x) => x.field_names(),
+            Self::Ram(x) => x.field_names(),
+            Self::DetonateShip(x) => x.field_names(),
+            Self::Vanish(x) => x.field_names(),
+            Self::MaintainAttackRange(x) => x.field_names(),
+            Self::Wait(x) => x.field_names(),
+            Self::LookAtTarget(x) => x.field_names(),
+            Self::LookForAdditionalTargets(x) => x.field_names(),
+            Self::LookForThreats(x) => x.field_names(),
+            Self::MatchVelocityWithTarget(x) => x.field_names(),
+            Self::ActivateDevice(x) => x.field_names(),
+            Self::RechargeEnergy(x) => x.field_names(),
+            Self::SustainAim(x) => x.field_names(),
+            Self::ChargeWeapons(x) => x.field_names(),
+            Self::Chase(x) => x.field_names(),
+            Self::AvoidThreats(x) => x.field_names(),
+            Self::SlowDown(x) => x.field_names(),
+            Self::UseRecoil(x) => x.field_names(),
+            Self::DefendWithFronalShield(x) => x.field_names(),
+            Self::TrackControllableAmmo(x) => x.field_names(),
+            Self::KeepDistance(x) => x.field_names(),
+            Self::ForgetMainTarget(x) => x.field_names(),
+            Self::EscapeTargetAttackRadius(x) => x.field_names(),
+            Self::AttackAdditionalTargets(x) => x.field_names(),
+            Self::TargetAllyStarbase(x) => x.field_names(),
+            Self::TargetEnemyStarbase(x) => x.field_names(),
+            Self::BypassObstacles(x) => x.field_names(),
+            Self::AttackTurretTargets(x) => x.field_names(),
+            Self::HoldHarpoon(x) => x.field_names(),
+            Self::FindDamagedAlly(x) => x.field_names(),
+            Self::EnginePropulsionForce(x) => x.field_names(),
+            Self::MotherShipRetreated(x) => x.field_names(),
+            Self::MotherShipDestroyed(x) => x.field_names(),
+            Self::FlyAroundMothership(x) => x.field_names(),
+            Self::GoBerserk(x) => x.field_names(),
+            Self::TargetMothership(x) => x.field_names(),
+            Self::MothershipLowHp(x) => x.field_names(),
+            Self::MothershipDistanceExceeded(x) => x.field_names(),
+            Self::MakeTargetMothership(x) => x.field_names(),
+            Self::MothershipLowEnergy(x) => x.field_names(),
+            Self::MothershipLowShield(x) => x.field_names(),
+            Self::ShowMessage(x) => x.field_names(),
+            Self::DebugLog(x) => x.field_names(),
+            Self::SetValue(x) => x.field_names(),
+            Self::GetValue(x) => x.field_names(),
+            Self::SendMessage(x) => x.field_names(),
+            Self::MessageReceived(x) => x.field_names(),
+            Self::TargetMessageSender(x) => x.field_names(),
+            Self::SaveTarget(x) => x.field_names(),
+            Self::LoadTarget(x) => x.field_names(),
+            Self::HasSavedTarget(x) => x.field_names(),
+            Self::ForgetSavedTarget(x) => x.field_names(),
+        }
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match self {
+            Self::Success(x) => x.get_field(name),
+            Self::Failure(x) => x.get_field(name),
+            Self::SubTree(x) => x.get_field(name),
+            Self::Selector(x) => x.get_field(name),
+            Self::Sequence(x) => x.get_field(name),
+            Self::Parallel(x) => x.get_field(name),
+            Self::RandomSelector(x) => x.get_field(name),
+            Self::Invertor(x) => x.get_field(name),
+            Self::Cooldown(x) => x.get_field(name),
+            Self::Execute(x) => x.get_field(name),
+            Self::ParallelSequence(x) => x.get_field(name),
+            Self::PreserveTarget(x) => x.get_field(name),
+            Self::IfThenElse(x) => x.get_field(name),
+            Self::HasEnoughEnergy(x) => x.get_field(name),
+            Self::IsLowOnHp(x) => x.get_field(name),
+            Self::IsNotControledByPlayer(x) => x.get_field(name),
+            Self::HasIncomingThreat(x) => x.get_field(name),
+            Self::HasAdditionalTargets(x) => x.get_field(name),
+            Self::IsFasterThanTarget(x) => x.get_field(name),
+            Self::HasMainTarget(x) => x.get_field(name),
+            Self::MainTargetIsAlly(x) => x.get_field(name),
+            Self::MainTargetIsEnemy(x) => x.get_field(name),
+            Self::MainTargetLowHp(x) => x.get_field(name),
+            Self::MainTargetWithinAttackRange(x) => x.get_field(name),
+            Self::HasMothership(x) => x.get_field(name),
+            Self::TargetDistance(x) => x.get_field(name),
+            Self::HasLongerAttackRange(x) => x.get_field(name),
+            Self::FindEnemy(x) => x.get_field(name),
+            Self::MoveToAttackRange(x) => x.get_field(name),
+            Self::AttackMainTarget(x) => x.get_field(name),
+            Self::SelectWeapon(x) => x.get_field(name),
+            Self::SpawnDrones(x) => x.get_field(name),
+            Self::Ram(x) => x.get_field(name),
+            Self::DetonateShip(x) => x.get_field(name),
+            Self::Vanish(x) => x.get_field(name),
+            Self::MaintainAttackRange(x) => x.get_field(name),
+            Self::Wait(x) => x.get_field(name),
+            Self::LookAtTarget(x) => x.get_field(name),
+            Self::LookForAdditionalTargets(x) => x.get_field(name),
+            Self::LookForThreats(x) => x.get_field(name),
+            Self::MatchVelocityWithTarget(x) => x.get_field(name),
+            Self::ActivateDevice(x) => x.get_field(name),
+            Self::RechargeEnergy(x) => x.get_field(name),
+            Self::SustainAim(x) => x.get_field(name),
+            Self::ChargeWeapons(x) => x.get_field(name),
+            Self::Chase(x) => x.get_field(name),
+            Self::AvoidThreats(x) => x.get_field(name),
+            Self::SlowDown(x) => x.get_field(name),
+            Self::UseRecoil(x) => x.get_field(name),
+            Self::DefendWithFronalShield(x) => x.get_field(name),
+            Self::TrackControllableAmmo(x) => x.get_field(name),
+            Self::KeepDistance(x) => x.get_field(name),
+            Self::ForgetMainTarget(x) => x.get_field(name),
+            Self::EscapeTargetAttackRadius(x) => x.get_field(name),
+            Self::AttackAdditionalTargets(x) => x.get_field(name),
+            Self::TargetAllyStarbase(x) => x.get_field(name),
+            Self::TargetEnemyStarbase(x) => x.get_field(name),
+            Self::BypassObstacles(x) => x.get_field(name),
+            Self::AttackTurretTargets(x) => x.get_field(name),
+            Self::HoldHarpoon(x) => x.get_field(name),
+            Self::FindDamagedAlly(x) => x.get_field(name),
+            Self::EnginePropulsionForce(x) => x.get_field(name),
+            Self::MotherShipRetreated(x) => x.get_field(name),
+            Self::MotherShipDestroyed(x) => x.get_field(name),
+            Self::FlyAroundMothership(x) => x.get_field(name),
+            Self::GoBerserk(x) => x.get_field(name),
+            Self::TargetMothership(x) => x.get_field(name),
+            Self::MothershipLowHp(x) => x.get_field(name),
+            Self::MothershipDistanceExceeded(x) => x.get_field(name),
+            Self::MakeTargetMothership(x) => x.get_field(name),
+            Self::MothershipLowEnergy(x) => x.get_field(name),
+            Self::MothershipLowShield(x) => x.get_field(name),
+            Self::ShowMessage(x) => x.get_field(name),
+            Self::DebugLog(x) => x.get_field(name),
+            Self::SetValue(x) => x.get_field(name),
+            Self::GetValue(x) => x.get_field(name),
+            Self::SendMessage(x) => x.get_field(name),
+            Self::MessageReceived(x) => x.get_field(name),
+            Self::TargetMessageSender(x) => x.get_field(name),
+            Self::SaveTarget(x) => x.get_field(name),
+            Self::LoadTarget(x) => x.get_field(name),
+            Self::HasSavedTarget(x) => x.get_field(name),
+            Self::ForgetSavedTarget(x) => x.get_field(name),
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match self {
+            Self::Success(x) => x.set_field(name, value),
+            Self::Failure(x) => x.set_field(name, value),
+            Self::SubTree(x) => x.set_field(name, value),
+            Self::Selector(x) => x.set_field(name, value),
+            Self::Sequence(x) => x.set_field(name, value),
+            Self::Parallel(x) => x.set_field(name, value),
+            Self::RandomSelector(x) => x.set_field(name, value),
+            Self::Invertor(x) => x.set_field(name, value),
+            Self::Cooldown(x) => x.set_field(name, value),
+            Self::Execute(x) => x.set_field(name, value),
+            Self::ParallelSequence(x) => x.set_field(name, value),
+            Self::PreserveTarget(x) => x.set_field(name, value),
+            Self::IfThenElse(x) => x.set_field(name, value),
+            Self::HasEnoughEnergy(x) => x.set_field(name, value),
+            Self::IsLowOnHp(x) => x.set_field(name, value),
+            Self::IsNotControledByPlayer(x) => x.set_field(name, value),
+            Self::HasIncomingThreat(x) => x.set_field(name, value),
+            Self::HasAdditionalTargets(x) => x.set_field(name, value),
+            Self::IsFasterThanTarget(x) => x.set_field(name, value),
+            Self::HasMainTarget(x) => x.set_field(name, value),
+            Self::MainTargetIsAlly(x) => x.set_field(name, value),
+            Self::MainTargetIsEnemy(x) => x.set_field(name, value),
+            Self::MainTargetLowHp(x) => x.set_field(name, value),
+            Self::MainTargetWithinAttackRange(x) => x.set_field(name, value),
+            Self::HasMothership(x) => x.set_field(name, value),
+            Self::TargetDistance(x) => x.set_field(name, value),
+            Self::HasLongerAttackRange(x) => x.set_field(name, value),
+            Self::FindEnemy(x) => x.set_field(name, value),
+            Self::MoveToAttackRange(x) => x.set_field(name, value),
+            Self::AttackMainTarget(x) => x.set_field(name, value),
+            Self::SelectWeapon(x) => x.set_field(name, value),
+            Self::SpawnDrones(x) => x.set_field(name, value),
+            Self::Ram(x) => x.set_field(name, value),
+            Self::DetonateShip(x) => x.set_field(name, value),
+            Self::Vanish(x) => x.set_field(name, value),
+            Self::MaintainAttackRange(x) => x.set_field(name, value),
+            Self::Wait(x) => x.set_field(name, value),
+            Self::LookAtTarget(x) => x.set_field(name, value),
+            Self::LookForAdditionalTargets(x) => x.set_field(name, value),
+            Self::LookForThreats(x) => x.set_field(name, value),
+            Self::MatchVelocityWithTarget(x) => x.set_field(name, value),
+            Self::ActivateDevice(x) => x.set_field(name, value),
+            Self::RechargeEnergy(x) => x.set_field(name, value),
+            Self::SustainAim(x) => x.set_field(name, value),
+            Self::ChargeWeapons(x) => x.set_field(name, value),
+            Self::Chase(x) => x.set_field(name, value),
+            Self::AvoidThreats(x) => x.set_field(name, value),
+            Self::SlowDown(x) => x.set_field(name, value),
+            Self::UseRecoil(x) => x.set_field(name, value),
+            Self::DefendWithFronalShield(x) => x.set_field(name, value),
+            Self::TrackControllableAmmo(x) => x.set_field(name, value),
+            Self::KeepDistance(x) => x.set_field(name, value),
+            Self::ForgetMainTarget(x) => x.set_field(name, value),
+            Self::EscapeTargetAttackRadius(x) => x.set_field(name, value),
+            Self::AttackAdditionalTargets(x) => x.set_field(name, value),
+            Self::TargetAllyStarbase(x) => x.set_field(name, value),
+            Self::TargetEnemyStarbase(x) => x.set_field(name, value),
+            Self::BypassObstacles(x) => x.set_field(name, value),
+            Self::AttackTurretTargets(x) => x.set_field(name, value),
+            Self::HoldHarpoon(x) => x.set_field(name, value),
+            Self::FindDamagedAlly(x) => x.set_field(name, value),
+            Self::EnginePropulsionForce(x) => x.set_field(name, value),
+            Self::MotherShipRetreated(x) => x.set_field(name, value),
+            Self::MotherShipDestroyed(x) => x.set_field(name, value),
+            Self::FlyAroundMothership(x) => x.set_field(name, value),
+            Self::GoBerserk(x) => x.set_field(name, value),
+            Self::TargetMothership(x) => x.set_field(name, value),
+            Self::MothershipLowHp(x) => x.set_field(name, value),
+            Self::MothershipDistanceExceeded(x) => x.set_field(name, value),
+            Self::MakeTargetMothership(x) => x.set_field(name, value),
+            Self::MothershipLowEnergy(x) => x.set_field(name, value),
+            Self::MothershipLowShield(x) => x.set_field(name, value),
+            Self::ShowMessage(x) => x.set_field(name, value),
+            Self::DebugLog(x) => x.set_field(name, value),
+            Self::SetValue(x) => x.set_field(name, value),
+            Self::GetValue(x) => x.set_field(name, value),
+            Self::SendMessage(x) => x.set_field(name, value),
+            Self::MessageReceived(x) => x.set_field(name, value),
+            Self::TargetMessageSender(x) => x.set_field(name, value),
+            Self::SaveTarget(x) => x.set_field(name, value),
+            Self::LoadTarget(x) => x.set_field(name, value),
+            Self::HasSavedTarget(x) => x.set_field(name, value),
+            Self::ForgetSavedTarget(x) => x.set_field(name, value),
+        }
+    }
+}
 impl BehaviorTreeNode {
     pub fn inner_type_name(&self) -> &'static str {
         match self {
@@ -11481,6 +18249,97 @@ impl BehaviorTreeNode {
         }
     }
 }
+/// Runs the same code for every variant of [BehaviorTreeNode], binding the inner item to the given pattern
+#[macro_export]
+macro_rules! match_behavior_tree_node {
+    ($value:expr, |$inner:pat_param| $body:expr) => {
+        match $value {
+            $crate::schema::BehaviorTreeNode::Success($inner) => $body,
+            $crate::schema::BehaviorTreeNode::Failure($inner) => $body,
+            $crate::schema::BehaviorTreeNode::SubTree($inner) => $body,
+            $crate::schema::BehaviorTreeNode::Selector($inner) => $body,
+            $crate::schema::BehaviorTreeNode::Sequence($inner) => $body,
+            $crate::schema::BehaviorTreeNode::Parallel($inner) => $body,
+            $crate::schema::BehaviorTreeNode::RandomSelector($inner) => $body,
+            $crate::schema::BehaviorTreeNode::Invertor($inner) => $body,
+            $crate::schema::BehaviorTreeNode::Cooldown($inner) => $body,
+            $crate::schema::BehaviorTreeNode::Execute($inner) => $body,
+            $crate::schema::BehaviorTreeNode::ParallelSequence($inner) => $body,
+            $crate::schema::BehaviorTreeNode::PreserveTarget($inner) => $body,
+            $crate::schema::BehaviorTreeNode::IfThenElse($inner) => $body,
+            $crate::schema::BehaviorTreeNode::HasEnoughEnergy($inner) => $body,
+            $crate::schema::BehaviorTreeNode::IsLowOnHp($inner) => $body,
+            $crate::schema::BehaviorTreeNode::IsNotControledByPlayer($inner) => $body,
+            $crate::schema::BehaviorTreeNode::HasIncomingThreat($inner) => $body,
+            $crate::schema::BehaviorTreeNode::HasAdditionalTargets($inner) => $body,
+            $crate::schema::BehaviorTreeNode::IsFasterThanTarget($inner) => $body,
+            $crate::schema::BehaviorTreeNode::HasMainTarget($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MainTargetIsAlly($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MainTargetIsEnemy($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MainTargetLowHp($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MainTargetWithinAttackRange($inner) => $body,
+            $crate::schema::BehaviorTreeNode::HasMothership($inner) => $body,
+            $crate::schema::BehaviorTreeNode::TargetDistance($inner) => $body,
+            $crate::schema::BehaviorTreeNode::HasLongerAttackRange($inner) => $body,
+            $crate::schema::BehaviorTreeNode::FindEnemy($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MoveToAttackRange($inner) => $body,
+            $crate::schema::BehaviorTreeNode::AttackMainTarget($inner) => $body,
+            $crate::schema::BehaviorTreeNode::SelectWeapon($inner) => $body,
+            $crate::schema::BehaviorTreeNode::SpawnDrones($inner) => $body,
+            $crate::schema::BehaviorTreeNode::Ram($inner) => $body,
+            $crate::schema::BehaviorTreeNode::DetonateShip($inner) => $body,
+            $crate::schema::BehaviorTreeNode::Vanish($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MaintainAttackRange($inner) => $body,
+            $crate::schema::BehaviorTreeNode::Wait($inner) => $body,
+            $crate::schema::BehaviorTreeNode::LookAtTarget($inner) => $body,
+            $crate::schema::BehaviorTreeNode::LookForAdditionalTargets($inner) => $body,
+            $crate::schema::BehaviorTreeNode::LookForThreats($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MatchVelocityWithTarget($inner) => $body,
+            $crate::schema::BehaviorTreeNode::ActivateDevice($inner) => $body,
+            $crate::schema::BehaviorTreeNode::RechargeEnergy($inner) => $body,
+            $crate::schema::BehaviorTreeNode::SustainAim($inner) => $body,
+            $crate::schema::BehaviorTreeNode::ChargeWeapons($inner) => $body,
+            $crate::schema::BehaviorTreeNode::Chase($inner) => $body,
+            $crate::schema::BehaviorTreeNode::AvoidThreats($inner) => $body,
+            $crate::schema::BehaviorTreeNode::SlowDown($inner) => $body,
+            $crate::schema::BehaviorTreeNode::UseRecoil($inner) => $body,
+            $crate::schema::BehaviorTreeNode::DefendWithFronalShield($inner) => $body,
+            $crate::schema::BehaviorTreeNode::TrackControllableAmmo($inner) => $body,
+            $crate::schema::BehaviorTreeNode::KeepDistance($inner) => $body,
+            $crate::schema::BehaviorTreeNode::ForgetMainTarget($inner) => $body,
+            $crate::schema::BehaviorTreeNode::EscapeTargetAttackRadius($inner) => $body,
+            $crate::schema::BehaviorTreeNode::AttackAdditionalTargets($inner) => $body,
+            $crate::schema::BehaviorTreeNode::TargetAllyStarbase($inner) => $body,
+            $crate::schema::BehaviorTreeNode::TargetEnemyStarbase($inner) => $body,
+            $crate::schema::BehaviorTreeNode::BypassObstacles($inner) => $body,
+            $crate::schema::BehaviorTreeNode::AttackTurretTargets($inner) => $body,
+            $crate::schema::BehaviorTreeNode::HoldHarpoon($inner) => $body,
+            $crate::schema::BehaviorTreeNode::FindDamagedAlly($inner) => $body,
+            $crate::schema::BehaviorTreeNode::EnginePropulsionForce($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MotherShipRetreated($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MotherShipDestroyed($inner) => $body,
+            $crate::schema::BehaviorTreeNode::FlyAroundMothership($inner) => $body,
+            $crate::schema::BehaviorTreeNode::GoBerserk($inner) => $body,
+            $crate::schema::BehaviorTreeNode::TargetMothership($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MothershipLowHp($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MothershipDistanceExceeded($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MakeTargetMothership($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MothershipLowEnergy($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MothershipLowShield($inner) => $body,
+            $crate::schema::BehaviorTreeNode::ShowMessage($inner) => $body,
+            $crate::schema::BehaviorTreeNode::DebugLog($inner) => $body,
+            $crate::schema::BehaviorTreeNode::SetValue($inner) => $body,
+            $crate::schema::BehaviorTreeNode::GetValue($inner) => $body,
+            $crate::schema::BehaviorTreeNode::SendMessage($inner) => $body,
+            $crate::schema::BehaviorTreeNode::MessageReceived($inner) => $body,
+            $crate::schema::BehaviorTreeNode::TargetMessageSender($inner) => $body,
+            $crate::schema::BehaviorTreeNode::SaveTarget($inner) => $body,
+            $crate::schema::BehaviorTreeNode::LoadTarget($inner) => $body,
+            $crate::schema::BehaviorTreeNode::HasSavedTarget($inner) => $body,
+            $crate::schema::BehaviorTreeNode::ForgetSavedTarget($inner) => $body,
+        }
+    };
+}
 
 // v1/Objects/Barrel.xml
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -11489,18 +18348,32 @@ pub struct Barrel {
     #[serde(default)]
     #[serde(with = "crate::helpers::glam_ser")]
     pub r#position: glam::f32::Vec2,
+    /// - Minimum: `-360`
+    /// - Maximum: `360`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#rotation: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#offset: f32,
+    /// - Default: `0`
+    /// - Options: `obsolete`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#platform_type: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `360`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#auto_aiming_arc: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#rotation_speed: f32,
@@ -11508,6 +18381,9 @@ pub struct Barrel {
     pub r#weapon_class: String,
     #[serde(default)]
     pub r#image: String,
+    /// - Minimum: `0`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#size: f32,
@@ -11658,6 +18534,59 @@ impl DatabaseItem for Barrel {
         "Barrel"
     }
 }
+impl VisitIds for Barrel {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for Barrel {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#image.is_empty() {
+            visitor(AssetKind::Image, &self.r#image);
+        }
+    }
+}
+impl DynamicFields for Barrel {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "position",
+            "rotation",
+            "offset",
+            "platform_type",
+            "auto_aiming_arc",
+            "rotation_speed",
+            "weapon_class",
+            "image",
+            "size",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "position" => Some(FieldValue::of(&self.r#position)),
+            "rotation" => Some(FieldValue::of(&self.r#rotation)),
+            "offset" => Some(FieldValue::of(&self.r#offset)),
+            "platform_type" => Some(FieldValue::of(&self.r#platform_type)),
+            "auto_aiming_arc" => Some(FieldValue::of(&self.r#auto_aiming_arc)),
+            "rotation_speed" => Some(FieldValue::of(&self.r#rotation_speed)),
+            "weapon_class" => Some(FieldValue::of(&self.r#weapon_class)),
+            "image" => Some(FieldValue::of(&self.r#image)),
+            "size" => Some(FieldValue::of(&self.r#size)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "position" => value.write_to(&mut self.r#position, "position"),
+            "rotation" => value.write_to(&mut self.r#rotation, "rotation"),
+            "offset" => value.write_to(&mut self.r#offset, "offset"),
+            "platform_type" => value.write_to(&mut self.r#platform_type, "platform_type"),
+            "auto_aiming_arc" => value.write_to(&mut self.r#auto_aiming_arc, "auto_aiming_arc"),
+            "rotation_speed" => value.write_to(&mut self.r#rotation_speed, "rotation_speed"),
+            "weapon_class" => value.write_to(&mut self.r#weapon_class, "weapon_class"),
+            "image" => value.write_to(&mut self.r#image, "image"),
+            "size" => value.write_to(&mut self.r#size, "size"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for Barrel {}
 impl std::cmp::PartialEq for Barrel {
     fn eq(&self, other: &Self) -> bool {
@@ -11704,15 +18633,19 @@ impl Default for Barrel {
 #[serde(rename_all = "PascalCase")]
 pub struct ComponentRestrictions {
     #[serde(default)]
-    pub r#ship_sizes: std::collections::BTreeSet<SizeClass>,
+    pub r#ship_sizes: Flags<SizeClass>,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#not_for_organic_ships: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#not_for_mechanic_ships: bool,
     #[serde(default)]
     pub r#unique_component_tag: String,
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_component_amount: i32,
@@ -11727,17 +18660,11 @@ impl ComponentRestrictions {
             r#max_component_amount: 0,
         }
     }
-    pub fn with_ship_sizes(
-        mut self,
-        r#ship_sizes: impl Into<std::collections::BTreeSet<SizeClass>>,
-    ) -> Self {
+    pub fn with_ship_sizes(mut self, r#ship_sizes: impl Into<Flags<SizeClass>>) -> Self {
         self.r#ship_sizes = r#ship_sizes.into();
         self
     }
-    pub fn set_ship_sizes(
-        &mut self,
-        r#ship_sizes: impl Into<std::collections::BTreeSet<SizeClass>>,
-    ) -> &mut Self {
+    pub fn set_ship_sizes(&mut self, r#ship_sizes: impl Into<Flags<SizeClass>>) -> &mut Self {
         self.r#ship_sizes = r#ship_sizes.into();
         self
     }
@@ -11802,6 +18729,51 @@ impl DatabaseItem for ComponentRestrictions {
         "ComponentRestrictions"
     }
 }
+impl VisitIds for ComponentRestrictions {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for ComponentRestrictions {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for ComponentRestrictions {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "ship_sizes",
+            "not_for_organic_ships",
+            "not_for_mechanic_ships",
+            "unique_component_tag",
+            "max_component_amount",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "ship_sizes" => Some(FieldValue::of(&self.r#ship_sizes)),
+            "not_for_organic_ships" => Some(FieldValue::of(&self.r#not_for_organic_ships)),
+            "not_for_mechanic_ships" => Some(FieldValue::of(&self.r#not_for_mechanic_ships)),
+            "unique_component_tag" => Some(FieldValue::of(&self.r#unique_component_tag)),
+            "max_component_amount" => Some(FieldValue::of(&self.r#max_component_amount)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "ship_sizes" => value.write_to(&mut self.r#ship_sizes, "ship_sizes"),
+            "not_for_organic_ships" => {
+                value.write_to(&mut self.r#not_for_organic_ships, "not_for_organic_ships")
+            }
+            "not_for_mechanic_ships" => {
+                value.write_to(&mut self.r#not_for_mechanic_ships, "not_for_mechanic_ships")
+            }
+            "unique_component_tag" => {
+                value.write_to(&mut self.r#unique_component_tag, "unique_component_tag")
+            }
+            "max_component_amount" => {
+                value.write_to(&mut self.r#max_component_amount, "max_component_amount")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for ComponentRestrictions {
     fn default() -> Self {
         Self::new()
@@ -11815,6 +18787,9 @@ pub struct Engine {
     #[serde(default)]
     #[serde(with = "crate::helpers::glam_ser")]
     pub r#position: glam::f32::Vec2,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#size: f32,
@@ -11859,6 +18834,31 @@ impl DatabaseItem for Engine {
         "Engine"
     }
 }
+impl VisitIds for Engine {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for Engine {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for Engine {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["position", "size"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "position" => Some(FieldValue::of(&self.r#position)),
+            "size" => Some(FieldValue::of(&self.r#size)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "position" => value.write_to(&mut self.r#position, "position"),
+            "size" => value.write_to(&mut self.r#size, "size"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for Engine {}
 impl std::cmp::PartialEq for Engine {
     fn eq(&self, other: &Self) -> bool {
@@ -11886,24 +18886,40 @@ impl Default for Engine {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct InstalledComponent {
+    /// - Options: `notnull`
     pub r#component_id: ComponentId,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#modification: Option<ComponentModId>,
     #[serde(default)]
     pub r#quality: ModificationQuality,
+    /// - Minimum: `-32768`
+    /// - Maximum: `32767`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#x: i32,
+    /// - Minimum: `-32768`
+    /// - Maximum: `32767`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#y: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `255`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#barrel_id: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#behaviour: i32,
+    /// - Minimum: `-10`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#key_binding: i32,
@@ -11940,6 +18956,10 @@ impl InstalledComponent {
         self.r#modification = r#modification.into();
         self
     }
+    pub fn clear_modification(&mut self) -> &mut Self {
+        self.r#modification = None;
+        self
+    }
     pub fn with_quality(mut self, r#quality: impl Into<ModificationQuality>) -> Self {
         self.r#quality = r#quality.into();
         self
@@ -12041,6 +19061,55 @@ impl DatabaseItem for InstalledComponent {
         "InstalledComponent"
     }
 }
+impl VisitIds for InstalledComponent {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#component_id.visit_ids(visitor);
+        self.r#modification.visit_ids(visitor);
+    }
+}
+impl VisitAssets for InstalledComponent {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for InstalledComponent {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "component_id",
+            "modification",
+            "quality",
+            "x",
+            "y",
+            "barrel_id",
+            "behaviour",
+            "key_binding",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "component_id" => Some(FieldValue::of(&self.r#component_id)),
+            "modification" => Some(FieldValue::of(&self.r#modification)),
+            "quality" => Some(FieldValue::of(&self.r#quality)),
+            "x" => Some(FieldValue::of(&self.r#x)),
+            "y" => Some(FieldValue::of(&self.r#y)),
+            "barrel_id" => Some(FieldValue::of(&self.r#barrel_id)),
+            "behaviour" => Some(FieldValue::of(&self.r#behaviour)),
+            "key_binding" => Some(FieldValue::of(&self.r#key_binding)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "component_id" => value.write_to(&mut self.r#component_id, "component_id"),
+            "modification" => value.write_to(&mut self.r#modification, "modification"),
+            "quality" => value.write_to(&mut self.r#quality, "quality"),
+            "x" => value.write_to(&mut self.r#x, "x"),
+            "y" => value.write_to(&mut self.r#y, "y"),
+            "barrel_id" => value.write_to(&mut self.r#barrel_id, "barrel_id"),
+            "behaviour" => value.write_to(&mut self.r#behaviour, "behaviour"),
+            "key_binding" => value.write_to(&mut self.r#key_binding, "key_binding"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 
 // v1/Objects/Quests/FactionFilter.xml
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
@@ -12081,6 +19150,33 @@ impl DatabaseItem for FactionFilter {
         "FactionFilter"
     }
 }
+impl VisitIds for FactionFilter {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#list.visit_ids(visitor);
+    }
+}
+impl VisitAssets for FactionFilter {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for FactionFilter {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["type", "list"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "type" => Some(FieldValue::of(&self.r#type)),
+            "list" => Some(FieldValue::of(&self.r#list)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "type" => value.write_to(&mut self.r#type, "type"),
+            "list" => value.write_to(&mut self.r#list, "list"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for FactionFilter {
     fn default() -> Self {
         Self::new()
@@ -12127,6 +19223,27 @@ impl DatabaseItem for LootContentNone {
         "LootContentNone"
     }
 }
+impl VisitIds for LootContentNone {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for LootContentNone {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for LootContentNone {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for LootContentNone {
     fn default() -> Self {
         Self::new()
@@ -12150,6 +19267,9 @@ impl LootContent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootContentSomeMoney {
+    /// - Minimum: `0.001`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#value_ratio: f32,
@@ -12183,6 +19303,29 @@ impl DatabaseItem for LootContentSomeMoney {
         "LootContentSomeMoney"
     }
 }
+impl VisitIds for LootContentSomeMoney {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for LootContentSomeMoney {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for LootContentSomeMoney {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["value_ratio"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "value_ratio" => Some(FieldValue::of(&self.r#value_ratio)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "value_ratio" => value.write_to(&mut self.r#value_ratio, "value_ratio"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for LootContentSomeMoney {}
 impl std::cmp::PartialEq for LootContentSomeMoney {
     fn eq(&self, other: &Self) -> bool {
@@ -12218,9 +19361,15 @@ impl LootContent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootContentFuel {
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_amount: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_amount: i32,
@@ -12274,6 +19423,31 @@ impl DatabaseItem for LootContentFuel {
         "LootContentFuel"
     }
 }
+impl VisitIds for LootContentFuel {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for LootContentFuel {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for LootContentFuel {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["min_amount", "max_amount"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "min_amount" => Some(FieldValue::of(&self.r#min_amount)),
+            "max_amount" => Some(FieldValue::of(&self.r#max_amount)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "min_amount" => value.write_to(&mut self.r#min_amount, "min_amount"),
+            "max_amount" => value.write_to(&mut self.r#max_amount, "max_amount"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for LootContentFuel {
     fn default() -> Self {
         Self::new()
@@ -12297,9 +19471,15 @@ impl LootContent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootContentMoney {
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_amount: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_amount: i32,
@@ -12353,6 +19533,31 @@ impl DatabaseItem for LootContentMoney {
         "LootContentMoney"
     }
 }
+impl VisitIds for LootContentMoney {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for LootContentMoney {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for LootContentMoney {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["min_amount", "max_amount"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "min_amount" => Some(FieldValue::of(&self.r#min_amount)),
+            "max_amount" => Some(FieldValue::of(&self.r#max_amount)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "min_amount" => value.write_to(&mut self.r#min_amount, "min_amount"),
+            "max_amount" => value.write_to(&mut self.r#max_amount, "max_amount"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for LootContentMoney {
     fn default() -> Self {
         Self::new()
@@ -12376,9 +19581,15 @@ impl LootContent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootContentStars {
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_amount: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_amount: i32,
@@ -12432,6 +19643,31 @@ impl DatabaseItem for LootContentStars {
         "LootContentStars"
     }
 }
+impl VisitIds for LootContentStars {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for LootContentStars {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for LootContentStars {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["min_amount", "max_amount"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "min_amount" => Some(FieldValue::of(&self.r#min_amount)),
+            "max_amount" => Some(FieldValue::of(&self.r#max_amount)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "min_amount" => value.write_to(&mut self.r#min_amount, "min_amount"),
+            "max_amount" => value.write_to(&mut self.r#max_amount, "max_amount"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for LootContentStars {
     fn default() -> Self {
         Self::new()
@@ -12466,6 +19702,27 @@ impl DatabaseItem for LootContentStarMap {
         "LootContentStarMap"
     }
 }
+impl VisitIds for LootContentStarMap {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for LootContentStarMap {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for LootContentStarMap {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for LootContentStarMap {
     fn default() -> Self {
         Self::new()
@@ -12489,12 +19746,21 @@ impl LootContent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootContentRandomComponents {
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_amount: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_amount: i32,
+    /// - Minimum: `0.001`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#value_ratio: f32,
@@ -12581,6 +19847,39 @@ impl DatabaseItem for LootContentRandomComponents {
         "LootContentRandomComponents"
     }
 }
+impl VisitIds for LootContentRandomComponents {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#factions.visit_ids(visitor);
+    }
+}
+impl VisitAssets for LootContentRandomComponents {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#factions.visit_assets(visitor);
+    }
+}
+impl DynamicFields for LootContentRandomComponents {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["min_amount", "max_amount", "value_ratio", "factions"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "min_amount" => Some(FieldValue::of(&self.r#min_amount)),
+            "max_amount" => Some(FieldValue::of(&self.r#max_amount)),
+            "value_ratio" => Some(FieldValue::of(&self.r#value_ratio)),
+            "factions" => Some(FieldValue::of(&self.r#factions)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "min_amount" => value.write_to(&mut self.r#min_amount, "min_amount"),
+            "max_amount" => value.write_to(&mut self.r#max_amount, "max_amount"),
+            "value_ratio" => value.write_to(&mut self.r#value_ratio, "value_ratio"),
+            "factions" => value.write_to(&mut self.r#factions, "factions"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for LootContentRandomComponents {}
 impl std::cmp::PartialEq for LootContentRandomComponents {
     fn eq(&self, other: &Self) -> bool {
@@ -12622,9 +19921,15 @@ impl LootContent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootContentRandomItems {
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_amount: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_amount: i32,
@@ -12696,6 +20001,37 @@ impl DatabaseItem for LootContentRandomItems {
         "LootContentRandomItems"
     }
 }
+impl VisitIds for LootContentRandomItems {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#items.visit_ids(visitor);
+    }
+}
+impl VisitAssets for LootContentRandomItems {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#items.visit_assets(visitor);
+    }
+}
+impl DynamicFields for LootContentRandomItems {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["min_amount", "max_amount", "items"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "min_amount" => Some(FieldValue::of(&self.r#min_amount)),
+            "max_amount" => Some(FieldValue::of(&self.r#max_amount)),
+            "items" => Some(FieldValue::of(&self.r#items)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "min_amount" => value.write_to(&mut self.r#min_amount, "min_amount"),
+            "max_amount" => value.write_to(&mut self.r#max_amount, "max_amount"),
+            "items" => value.write_to(&mut self.r#items, "items"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for LootContentRandomItems {
     fn default() -> Self {
         Self::new()
@@ -12751,6 +20087,33 @@ impl DatabaseItem for LootContentAllItems {
         "LootContentAllItems"
     }
 }
+impl VisitIds for LootContentAllItems {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#items.visit_ids(visitor);
+    }
+}
+impl VisitAssets for LootContentAllItems {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#items.visit_assets(visitor);
+    }
+}
+impl DynamicFields for LootContentAllItems {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["items"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "items" => Some(FieldValue::of(&self.r#items)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "items" => value.write_to(&mut self.r#items, "items"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for LootContentAllItems {
     fn default() -> Self {
         Self::new()
@@ -12806,6 +20169,33 @@ impl DatabaseItem for LootContentItemsWithChance {
         "LootContentItemsWithChance"
     }
 }
+impl VisitIds for LootContentItemsWithChance {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#items.visit_ids(visitor);
+    }
+}
+impl VisitAssets for LootContentItemsWithChance {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#items.visit_assets(visitor);
+    }
+}
+impl DynamicFields for LootContentItemsWithChance {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["items"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "items" => Some(FieldValue::of(&self.r#items)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "items" => value.write_to(&mut self.r#items, "items"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for LootContentItemsWithChance {
     fn default() -> Self {
         Self::new()
@@ -12829,10 +20219,17 @@ impl LootContent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootContentQuestItem {
+    /// - Options: `notnull`
     pub r#item_id: QuestItemId,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_amount: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_amount: i32,
@@ -12895,6 +20292,35 @@ impl DatabaseItem for LootContentQuestItem {
         "LootContentQuestItem"
     }
 }
+impl VisitIds for LootContentQuestItem {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#item_id.visit_ids(visitor);
+    }
+}
+impl VisitAssets for LootContentQuestItem {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for LootContentQuestItem {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["item_id", "min_amount", "max_amount"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            "min_amount" => Some(FieldValue::of(&self.r#min_amount)),
+            "max_amount" => Some(FieldValue::of(&self.r#max_amount)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            "min_amount" => value.write_to(&mut self.r#min_amount, "min_amount"),
+            "max_amount" => value.write_to(&mut self.r#max_amount, "max_amount"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl From<LootContentQuestItem> for LootContent {
     fn from(item: LootContentQuestItem) -> Self {
         Self::QuestItem(item)
@@ -12913,6 +20339,7 @@ impl LootContent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootContentShip {
+    /// - Options: `notnull`
     pub r#item_id: ShipBuildId,
 }
 impl LootContentShip {
@@ -12934,6 +20361,31 @@ impl DatabaseItem for LootContentShip {
         "LootContentShip"
     }
 }
+impl VisitIds for LootContentShip {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#item_id.visit_ids(visitor);
+    }
+}
+impl VisitAssets for LootContentShip {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for LootContentShip {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["item_id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl From<LootContentShip> for LootContent {
     fn from(item: LootContentShip) -> Self {
         Self::Ship(item)
@@ -12952,6 +20404,7 @@ impl LootContent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootContentEmptyShip {
+    /// - Options: `notnull`
     pub r#item_id: ShipId,
 }
 impl LootContentEmptyShip {
@@ -12973,6 +20426,31 @@ impl DatabaseItem for LootContentEmptyShip {
         "LootContentEmptyShip"
     }
 }
+impl VisitIds for LootContentEmptyShip {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#item_id.visit_ids(visitor);
+    }
+}
+impl VisitAssets for LootContentEmptyShip {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for LootContentEmptyShip {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["item_id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl From<LootContentEmptyShip> for LootContent {
     fn from(item: LootContentEmptyShip) -> Self {
         Self::EmptyShip(item)
@@ -12991,10 +20469,17 @@ impl LootContent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootContentComponent {
+    /// - Options: `notnull`
     pub r#item_id: ComponentId,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_amount: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_amount: i32,
@@ -13057,6 +20542,35 @@ impl DatabaseItem for LootContentComponent {
         "LootContentComponent"
     }
 }
+impl VisitIds for LootContentComponent {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#item_id.visit_ids(visitor);
+    }
+}
+impl VisitAssets for LootContentComponent {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for LootContentComponent {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["item_id", "min_amount", "max_amount"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            "min_amount" => Some(FieldValue::of(&self.r#min_amount)),
+            "max_amount" => Some(FieldValue::of(&self.r#max_amount)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            "min_amount" => value.write_to(&mut self.r#min_amount, "min_amount"),
+            "max_amount" => value.write_to(&mut self.r#max_amount, "max_amount"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl From<LootContentComponent> for LootContent {
     fn from(item: LootContentComponent) -> Self {
         Self::Component(item)
@@ -13075,6 +20589,7 @@ impl LootContent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootContentBlueprint {
+    /// - Options: `notnull`
     pub r#item_id: TechnologyId,
 }
 impl LootContentBlueprint {
@@ -13096,6 +20611,31 @@ impl DatabaseItem for LootContentBlueprint {
         "LootContentBlueprint"
     }
 }
+impl VisitIds for LootContentBlueprint {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#item_id.visit_ids(visitor);
+    }
+}
+impl VisitAssets for LootContentBlueprint {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for LootContentBlueprint {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["item_id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl From<LootContentBlueprint> for LootContent {
     fn from(item: LootContentBlueprint) -> Self {
         Self::Blueprint(item)
@@ -13114,9 +20654,15 @@ impl LootContent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootContentResearchPoints {
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_amount: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_amount: i32,
@@ -13185,6 +20731,37 @@ impl DatabaseItem for LootContentResearchPoints {
         "LootContentResearchPoints"
     }
 }
+impl VisitIds for LootContentResearchPoints {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#factions.visit_ids(visitor);
+    }
+}
+impl VisitAssets for LootContentResearchPoints {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#factions.visit_assets(visitor);
+    }
+}
+impl DynamicFields for LootContentResearchPoints {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["min_amount", "max_amount", "factions"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "min_amount" => Some(FieldValue::of(&self.r#min_amount)),
+            "max_amount" => Some(FieldValue::of(&self.r#max_amount)),
+            "factions" => Some(FieldValue::of(&self.r#factions)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "min_amount" => value.write_to(&mut self.r#min_amount, "min_amount"),
+            "max_amount" => value.write_to(&mut self.r#max_amount, "max_amount"),
+            "factions" => value.write_to(&mut self.r#factions, "factions"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for LootContentResearchPoints {
     fn default() -> Self {
         Self::new()
@@ -13208,10 +20785,17 @@ impl LootContent {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootContentSatellite {
+    /// - Options: `notnull`
     pub r#item_id: SatelliteId,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_amount: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_amount: i32,
@@ -13274,6 +20858,35 @@ impl DatabaseItem for LootContentSatellite {
         "LootContentSatellite"
     }
 }
+impl VisitIds for LootContentSatellite {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#item_id.visit_ids(visitor);
+    }
+}
+impl VisitAssets for LootContentSatellite {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for LootContentSatellite {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["item_id", "min_amount", "max_amount"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            "min_amount" => Some(FieldValue::of(&self.r#min_amount)),
+            "max_amount" => Some(FieldValue::of(&self.r#max_amount)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            "min_amount" => value.write_to(&mut self.r#min_amount, "min_amount"),
+            "max_amount" => value.write_to(&mut self.r#max_amount, "max_amount"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl From<LootContentSatellite> for LootContent {
     fn from(item: LootContentSatellite) -> Self {
         Self::Satellite(item)
@@ -13606,6 +21219,117 @@ impl DatabaseItem for LootContent {
         "LootContent"
     }
 }
+impl VisitIds for LootContent {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        match self {
+            Self::None(x) => x.visit_ids(visitor),
+            Self::SomeMoney(x) => x.visit_ids(visitor),
+            Self::Fuel(x) => x.visit_ids(visitor),
+            Self::Money(x) => x.visit_ids(visitor),
+            Self::Stars(x) => x.visit_ids(visitor),
+            Self::StarMap(x) => x.visit_ids(visitor),
+            Self::RandomComponents(x) => x.visit_ids(visitor),
+            Self::RandomItems(x) => x.visit_ids(visitor),
+            Self::AllItems(x) => x.visit_ids(visitor),
+            Self::ItemsWithChance(x) => x.visit_ids(visitor),
+            Self::QuestItem(x) => x.visit_ids(visitor),
+            Self::Ship(x) => x.visit_ids(visitor),
+            Self::EmptyShip(x) => x.visit_ids(visitor),
+            Self::Component(x) => x.visit_ids(visitor),
+            Self::Blueprint(x) => x.visit_ids(visitor),
+            Self::ResearchPoints(x) => x.visit_ids(visitor),
+            Self::Satellite(x) => x.visit_ids(visitor),
+        }
+    }
+}
+impl VisitAssets for LootContent {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        match self {
+            Self::None(x) => x.visit_assets(visitor),
+            Self::SomeMoney(x) => x.visit_assets(visitor),
+            Self::Fuel(x) => x.visit_assets(visitor),
+            Self::Money(x) => x.visit_assets(visitor),
+            Self::Stars(x) => x.visit_assets(visitor),
+            Self::StarMap(x) => x.visit_assets(visitor),
+            Self::RandomComponents(x) => x.visit_assets(visitor),
+            Self::RandomItems(x) => x.visit_assets(visitor),
+            Self::AllItems(x) => x.visit_assets(visitor),
+            Self::ItemsWithChance(x) => x.visit_assets(visitor),
+            Self::QuestItem(x) => x.visit_assets(visitor),
+            Self::Ship(x) => x.visit_assets(visitor),
+            Self::EmptyShip(x) => x.visit_assets(visitor),
+            Self::Component(x) => x.visit_assets(visitor),
+            Self::Blueprint(x) => x.visit_assets(visitor),
+            Self::ResearchPoints(x) => x.visit_assets(visitor),
+            Self::Satellite(x) => x.visit_assets(visitor),
+        }
+    }
+}
+impl DynamicFields for LootContent {
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::None(x) => x.field_names(),
+            Self::SomeMoney(x) => x.field_names(),
+            Self::Fuel(x) => x.field_names(),
+            Self::Money(x) => x.field_names(),
+            Self::Stars(x) => x.field_names(),
+            Self::StarMap(x) => x.field_names(),
+            Self::RandomComponents(x) => x.field_names(),
+            Self::RandomItems(x) => x.field_names(),
+            Self::AllItems(x) => x.field_names(),
+            Self::ItemsWithChance(x) => x.field_names(),
+            Self::QuestItem(x) => x.field_names(),
+            Self::Ship(x) => x.field_names(),
+            Self::EmptyShip(x) => x.field_names(),
+            Self::Component(x) => x.field_names(),
+            Self::Blueprint(x) => x.field_names(),
+            Self::ResearchPoints(x) => x.field_names(),
+            Self::Satellite(x) => x.field_names(),
+        }
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match self {
+            Self::None(x) => x.get_field(name),
+            Self::SomeMoney(x) => x.get_field(name),
+            Self::Fuel(x) => x.get_field(name),
+            Self::Money(x) => x.get_field(name),
+            Self::Stars(x) => x.get_field(name),
+            Self::StarMap(x) => x.get_field(name),
+            Self::RandomComponents(x) => x.get_field(name),
+            Self::RandomItems(x) => x.get_field(name),
+            Self::AllItems(x) => x.get_field(name),
+            Self::ItemsWithChance(x) => x.get_field(name),
+            Self::QuestItem(x) => x.get_field(name),
+            Self::Ship(x) => x.get_field(name),
+            Self::EmptyShip(x) => x.get_field(name),
+            Self::Component(x) => x.get_field(name),
+            Self::Blueprint(x) => x.get_field(name),
+            Self::ResearchPoints(x) => x.get_field(name),
+            Self::Satellite(x) => x.get_field(name),
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match self {
+            Self::None(x) => x.set_field(name, value),
+            Self::SomeMoney(x) => x.set_field(name, value),
+            Self::Fuel(x) => x.set_field(name, value),
+            Self::Money(x) => x.set_field(name, value),
+            Self::Stars(x) => x.set_field(name, value),
+            Self::StarMap(x) => x.set_field(name, value),
+            Self::RandomComponents(x) => x.set_field(name, value),
+            Self::RandomItems(x) => x.set_field(name, value),
+            Self::AllItems(x) => x.set_field(name, value),
+            Self::ItemsWithChance(x) => x.set_field(name, value),
+            Self::QuestItem(x) => x.set_field(name, value),
+            Self::Ship(x) => x.set_field(name, value),
+            Self::EmptyShip(x) => x.set_field(name, value),
+            Self::Component(x) => x.set_field(name, value),
+            Self::Blueprint(x) => x.set_field(name, value),
+            Self::ResearchPoints(x) => x.set_field(name, value),
+            Self::Satellite(x) => x.set_field(name, value),
+        }
+    }
+}
 impl LootContent {
     pub fn inner_type_name(&self) -> &'static str {
         match self {
@@ -13629,11 +21353,37 @@ impl LootContent {
         }
     }
 }
+/// Runs the same code for every variant of [LootContent], binding the inner item to the given pattern
+#[macro_export]
+macro_rules! match_loot_content {
+    ($value:expr, |$inner:pat_param| $body:expr) => {
+        match $value {
+            $crate::schema::LootContent::None($inner) => $body,
+            $crate::schema::LootContent::SomeMoney($inner) => $body,
+            $crate::schema::LootContent::Fuel($inner) => $body,
+            $crate::schema::LootContent::Money($inner) => $body,
+            $crate::schema::LootContent::Stars($inner) => $body,
+            $crate::schema::LootContent::StarMap($inner) => $body,
+            $crate::schema::LootContent::RandomComponents($inner) => $body,
+            $crate::schema::LootContent::RandomItems($inner) => $body,
+            $crate::schema::LootContent::AllItems($inner) => $body,
+            $crate::schema::LootContent::ItemsWithChance($inner) => $body,
+            $crate::schema::LootContent::QuestItem($inner) => $body,
+            $crate::schema::LootContent::Ship($inner) => $body,
+            $crate::schema::LootContent::EmptyShip($inner) => $body,
+            $crate::schema::LootContent::Component($inner) => $body,
+            $crate::schema::LootContent::Blueprint($inner) => $body,
+            $crate::schema::LootContent::ResearchPoints($inner) => $body,
+            $crate::schema::LootContent::Satellite($inner) => $body,
+        }
+    };
+}
 
 // v1/Objects/Quests/LootItem.xml
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct LootItem {
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#weight: f32,
@@ -13675,6 +21425,35 @@ impl DatabaseItem for LootItem {
         "LootItem"
     }
 }
+impl VisitIds for LootItem {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#loot.visit_ids(visitor);
+    }
+}
+impl VisitAssets for LootItem {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#loot.visit_assets(visitor);
+    }
+}
+impl DynamicFields for LootItem {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["weight", "loot"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "weight" => Some(FieldValue::of(&self.r#weight)),
+            "loot" => Some(FieldValue::of(&self.r#loot)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "weight" => value.write_to(&mut self.r#weight, "weight"),
+            "loot" => value.write_to(&mut self.r#loot, "loot"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for LootItem {}
 impl std::cmp::PartialEq for LootItem {
     fn eq(&self, other: &Self) -> bool {
@@ -13736,6 +21515,9 @@ impl Default for Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeUndefined {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
@@ -13769,6 +21551,29 @@ impl DatabaseItem for NodeUndefined {
         "NodeUndefined"
     }
 }
+impl VisitIds for NodeUndefined {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeUndefined {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeUndefined {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeUndefined {
     fn default() -> Self {
         Self::new()
@@ -13792,6 +21597,9 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeComingSoon {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
@@ -13825,6 +21633,29 @@ impl DatabaseItem for NodeComingSoon {
         "NodeComingSoon"
     }
 }
+impl VisitIds for NodeComingSoon {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeComingSoon {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeComingSoon {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeComingSoon {
     fn default() -> Self {
         Self::new()
@@ -13848,6 +21679,9 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeShowDialog {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
@@ -13911,6 +21745,10 @@ impl NodeShowDialog {
         self.r#enemy = r#enemy.into();
         self
     }
+    pub fn clear_enemy(&mut self) -> &mut Self {
+        self.r#enemy = None;
+        self
+    }
     pub fn with_loot(mut self, r#loot: impl Into<Option<LootId>>) -> Self {
         self.r#loot = r#loot.into();
         self
@@ -13919,6 +21757,10 @@ impl NodeShowDialog {
         self.r#loot = r#loot.into();
         self
     }
+    pub fn clear_loot(&mut self) -> &mut Self {
+        self.r#loot = None;
+        self
+    }
     pub fn with_character(mut self, r#character: impl Into<Option<CharacterId>>) -> Self {
         self.r#character = r#character.into();
         self
@@ -13927,6 +21769,10 @@ impl NodeShowDialog {
         self.r#character = r#character.into();
         self
     }
+    pub fn clear_character(&mut self) -> &mut Self {
+        self.r#character = None;
+        self
+    }
     pub fn with_actions(mut self, r#actions: impl Into<Vec<NodeAction>>) -> Self {
         self.r#actions = r#actions.into();
         self
@@ -13959,6 +21805,56 @@ impl DatabaseItem for NodeShowDialog {
         "NodeShowDialog"
     }
 }
+impl VisitIds for NodeShowDialog {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#enemy.visit_ids(visitor);
+        self.r#loot.visit_ids(visitor);
+        self.r#character.visit_ids(visitor);
+        self.r#actions.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeShowDialog {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#actions.visit_assets(visitor);
+    }
+}
+impl DynamicFields for NodeShowDialog {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "required_view",
+            "message",
+            "enemy",
+            "loot",
+            "character",
+            "actions",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "required_view" => Some(FieldValue::of(&self.r#required_view)),
+            "message" => Some(FieldValue::of(&self.r#message)),
+            "enemy" => Some(FieldValue::of(&self.r#enemy)),
+            "loot" => Some(FieldValue::of(&self.r#loot)),
+            "character" => Some(FieldValue::of(&self.r#character)),
+            "actions" => Some(FieldValue::of(&self.r#actions)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "required_view" => value.write_to(&mut self.r#required_view, "required_view"),
+            "message" => value.write_to(&mut self.r#message, "message"),
+            "enemy" => value.write_to(&mut self.r#enemy, "enemy"),
+            "loot" => value.write_to(&mut self.r#loot, "loot"),
+            "character" => value.write_to(&mut self.r#character, "character"),
+            "actions" => value.write_to(&mut self.r#actions, "actions"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeShowDialog {
     fn default() -> Self {
         Self::new()
@@ -13982,15 +21878,24 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeOpenShipyard {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#faction: Option<FactionId>,
+    /// - Minimum: `0`
+    /// - Maximum: `10000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#value: i32,
@@ -14028,6 +21933,10 @@ impl NodeOpenShipyard {
         self.r#faction = r#faction.into();
         self
     }
+    pub fn clear_faction(&mut self) -> &mut Self {
+        self.r#faction = None;
+        self
+    }
     pub fn with_value(mut self, r#value: impl Into<i32>) -> Self {
         self.r#value = r#value.into();
         self
@@ -14074,6 +21983,39 @@ impl DatabaseItem for NodeOpenShipyard {
         "NodeOpenShipyard"
     }
 }
+impl VisitIds for NodeOpenShipyard {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#faction.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeOpenShipyard {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeOpenShipyard {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "faction", "value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "faction" => Some(FieldValue::of(&self.r#faction)),
+            "value" => Some(FieldValue::of(&self.r#value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "faction" => value.write_to(&mut self.r#faction, "faction"),
+            "value" => value.write_to(&mut self.r#value, "value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeOpenShipyard {
     fn default() -> Self {
         Self::new()
@@ -14097,15 +22039,24 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeOpenWorkshop {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#faction: Option<FactionId>,
+    /// - Minimum: `0`
+    /// - Maximum: `10000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#value: i32,
@@ -14143,6 +22094,10 @@ impl NodeOpenWorkshop {
         self.r#faction = r#faction.into();
         self
     }
+    pub fn clear_faction(&mut self) -> &mut Self {
+        self.r#faction = None;
+        self
+    }
     pub fn with_value(mut self, r#value: impl Into<i32>) -> Self {
         self.r#value = r#value.into();
         self
@@ -14189,6 +22144,39 @@ impl DatabaseItem for NodeOpenWorkshop {
         "NodeOpenWorkshop"
     }
 }
+impl VisitIds for NodeOpenWorkshop {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#faction.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeOpenWorkshop {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeOpenWorkshop {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "faction", "value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "faction" => Some(FieldValue::of(&self.r#faction)),
+            "value" => Some(FieldValue::of(&self.r#value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "faction" => value.write_to(&mut self.r#faction, "faction"),
+            "value" => value.write_to(&mut self.r#value, "value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeOpenWorkshop {
     fn default() -> Self {
         Self::new()
@@ -14212,11 +22200,17 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeSwitch {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
     #[serde(default)]
     pub r#message: String,
+    /// - Minimum: `0`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
@@ -14300,6 +22294,41 @@ impl DatabaseItem for NodeSwitch {
         "NodeSwitch"
     }
 }
+impl VisitIds for NodeSwitch {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#transitions.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeSwitch {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#transitions.visit_assets(visitor);
+    }
+}
+impl DynamicFields for NodeSwitch {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "message", "default_transition", "transitions"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "message" => Some(FieldValue::of(&self.r#message)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "transitions" => Some(FieldValue::of(&self.r#transitions)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "message" => value.write_to(&mut self.r#message, "message"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "transitions" => value.write_to(&mut self.r#transitions, "transitions"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeSwitch {
     fn default() -> Self {
         Self::new()
@@ -14323,11 +22352,17 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeRandom {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
     #[serde(default)]
     pub r#message: String,
+    /// - Minimum: `0`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
@@ -14411,6 +22446,41 @@ impl DatabaseItem for NodeRandom {
         "NodeRandom"
     }
 }
+impl VisitIds for NodeRandom {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#transitions.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeRandom {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#transitions.visit_assets(visitor);
+    }
+}
+impl DynamicFields for NodeRandom {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "message", "default_transition", "transitions"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "message" => Some(FieldValue::of(&self.r#message)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "transitions" => Some(FieldValue::of(&self.r#transitions)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "message" => value.write_to(&mut self.r#message, "message"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "transitions" => value.write_to(&mut self.r#transitions, "transitions"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeRandom {
     fn default() -> Self {
         Self::new()
@@ -14434,6 +22504,9 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeCondition {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
@@ -14498,6 +22571,37 @@ impl DatabaseItem for NodeCondition {
         "NodeCondition"
     }
 }
+impl VisitIds for NodeCondition {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#transitions.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeCondition {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#transitions.visit_assets(visitor);
+    }
+}
+impl DynamicFields for NodeCondition {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "message", "transitions"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "message" => Some(FieldValue::of(&self.r#message)),
+            "transitions" => Some(FieldValue::of(&self.r#transitions)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "message" => value.write_to(&mut self.r#message, "message"),
+            "transitions" => value.write_to(&mut self.r#transitions, "transitions"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeCondition {
     fn default() -> Self {
         Self::new()
@@ -14521,12 +22625,21 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeAttackFleet {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#failure_transition: i32,
@@ -14579,6 +22692,10 @@ impl NodeAttackFleet {
         self.r#enemy = r#enemy.into();
         self
     }
+    pub fn clear_enemy(&mut self) -> &mut Self {
+        self.r#enemy = None;
+        self
+    }
     pub fn with_loot(mut self, r#loot: impl Into<Option<LootId>>) -> Self {
         self.r#loot = r#loot.into();
         self
@@ -14587,6 +22704,10 @@ impl NodeAttackFleet {
         self.r#loot = r#loot.into();
         self
     }
+    pub fn clear_loot(&mut self) -> &mut Self {
+        self.r#loot = None;
+        self
+    }
 }
 impl DatabaseItem for NodeAttackFleet {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
@@ -14628,6 +22749,50 @@ impl DatabaseItem for NodeAttackFleet {
         "NodeAttackFleet"
     }
 }
+impl VisitIds for NodeAttackFleet {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#enemy.visit_ids(visitor);
+        self.r#loot.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeAttackFleet {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeAttackFleet {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "default_transition",
+            "failure_transition",
+            "enemy",
+            "loot",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "failure_transition" => Some(FieldValue::of(&self.r#failure_transition)),
+            "enemy" => Some(FieldValue::of(&self.r#enemy)),
+            "loot" => Some(FieldValue::of(&self.r#loot)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "failure_transition" => {
+                value.write_to(&mut self.r#failure_transition, "failure_transition")
+            }
+            "enemy" => value.write_to(&mut self.r#enemy, "enemy"),
+            "loot" => value.write_to(&mut self.r#loot, "loot"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeAttackFleet {
     fn default() -> Self {
         Self::new()
@@ -14651,12 +22816,21 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeAttackOccupants {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#failure_transition: i32,
@@ -14734,6 +22908,37 @@ impl DatabaseItem for NodeAttackOccupants {
         "NodeAttackOccupants"
     }
 }
+impl VisitIds for NodeAttackOccupants {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeAttackOccupants {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeAttackOccupants {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "failure_transition"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "failure_transition" => Some(FieldValue::of(&self.r#failure_transition)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "failure_transition" => {
+                value.write_to(&mut self.r#failure_transition, "failure_transition")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeAttackOccupants {
     fn default() -> Self {
         Self::new()
@@ -14757,12 +22962,21 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeAttackStarbase {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#failure_transition: i32,
@@ -14840,6 +23054,37 @@ impl DatabaseItem for NodeAttackStarbase {
         "NodeAttackStarbase"
     }
 }
+impl VisitIds for NodeAttackStarbase {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeAttackStarbase {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeAttackStarbase {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "failure_transition"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "failure_transition" => Some(FieldValue::of(&self.r#failure_transition)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "failure_transition" => {
+                value.write_to(&mut self.r#failure_transition, "failure_transition")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeAttackStarbase {
     fn default() -> Self {
         Self::new()
@@ -14863,9 +23108,15 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeDestroyOccupants {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
@@ -14922,6 +23173,33 @@ impl DatabaseItem for NodeDestroyOccupants {
         "NodeDestroyOccupants"
     }
 }
+impl VisitIds for NodeDestroyOccupants {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeDestroyOccupants {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeDestroyOccupants {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeDestroyOccupants {
     fn default() -> Self {
         Self::new()
@@ -14945,9 +23223,15 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeSuppressOccupants {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
@@ -15004,6 +23288,33 @@ impl DatabaseItem for NodeSuppressOccupants {
         "NodeSuppressOccupants"
     }
 }
+impl VisitIds for NodeSuppressOccupants {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeSuppressOccupants {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeSuppressOccupants {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeSuppressOccupants {
     fn default() -> Self {
         Self::new()
@@ -15027,9 +23338,15 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeRetreat {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
@@ -15086,6 +23403,33 @@ impl DatabaseItem for NodeRetreat {
         "NodeRetreat"
     }
 }
+impl VisitIds for NodeRetreat {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeRetreat {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeRetreat {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeRetreat {
     fn default() -> Self {
         Self::new()
@@ -15109,9 +23453,15 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeReceiveItem {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
@@ -15151,6 +23501,10 @@ impl NodeReceiveItem {
         self.r#loot = r#loot.into();
         self
     }
+    pub fn clear_loot(&mut self) -> &mut Self {
+        self.r#loot = None;
+        self
+    }
 }
 impl DatabaseItem for NodeReceiveItem {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
@@ -15180,6 +23534,37 @@ impl DatabaseItem for NodeReceiveItem {
         "NodeReceiveItem"
     }
 }
+impl VisitIds for NodeReceiveItem {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#loot.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeReceiveItem {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeReceiveItem {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "loot"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "loot" => Some(FieldValue::of(&self.r#loot)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "loot" => value.write_to(&mut self.r#loot, "loot"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeReceiveItem {
     fn default() -> Self {
         Self::new()
@@ -15203,9 +23588,15 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeRemoveItem {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
@@ -15245,6 +23636,10 @@ impl NodeRemoveItem {
         self.r#loot = r#loot.into();
         self
     }
+    pub fn clear_loot(&mut self) -> &mut Self {
+        self.r#loot = None;
+        self
+    }
 }
 impl DatabaseItem for NodeRemoveItem {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
@@ -15274,6 +23669,37 @@ impl DatabaseItem for NodeRemoveItem {
         "NodeRemoveItem"
     }
 }
+impl VisitIds for NodeRemoveItem {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#loot.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeRemoveItem {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeRemoveItem {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "loot"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "loot" => Some(FieldValue::of(&self.r#loot)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "loot" => value.write_to(&mut self.r#loot, "loot"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeRemoveItem {
     fn default() -> Self {
         Self::new()
@@ -15297,9 +23723,15 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeTrade {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
@@ -15339,6 +23771,10 @@ impl NodeTrade {
         self.r#loot = r#loot.into();
         self
     }
+    pub fn clear_loot(&mut self) -> &mut Self {
+        self.r#loot = None;
+        self
+    }
 }
 impl DatabaseItem for NodeTrade {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
@@ -15368,6 +23804,37 @@ impl DatabaseItem for NodeTrade {
         "NodeTrade"
     }
 }
+impl VisitIds for NodeTrade {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#loot.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeTrade {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeTrade {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "loot"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "loot" => Some(FieldValue::of(&self.r#loot)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "loot" => value.write_to(&mut self.r#loot, "loot"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeTrade {
     fn default() -> Self {
         Self::new()
@@ -15391,6 +23858,9 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeCompleteQuest {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
@@ -15424,6 +23894,29 @@ impl DatabaseItem for NodeCompleteQuest {
         "NodeCompleteQuest"
     }
 }
+impl VisitIds for NodeCompleteQuest {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeCompleteQuest {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeCompleteQuest {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeCompleteQuest {
     fn default() -> Self {
         Self::new()
@@ -15447,6 +23940,9 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeFailQuest {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
@@ -15480,6 +23976,29 @@ impl DatabaseItem for NodeFailQuest {
         "NodeFailQuest"
     }
 }
+impl VisitIds for NodeFailQuest {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeFailQuest {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeFailQuest {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeFailQuest {
     fn default() -> Self {
         Self::new()
@@ -15503,6 +24022,9 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeCancelQuest {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
@@ -15536,6 +24058,29 @@ impl DatabaseItem for NodeCancelQuest {
         "NodeCancelQuest"
     }
 }
+impl VisitIds for NodeCancelQuest {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeCancelQuest {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeCancelQuest {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeCancelQuest {
     fn default() -> Self {
         Self::new()
@@ -15559,9 +24104,15 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeStartQuest {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
@@ -15601,6 +24152,10 @@ impl NodeStartQuest {
         self.r#quest = r#quest.into();
         self
     }
+    pub fn clear_quest(&mut self) -> &mut Self {
+        self.r#quest = None;
+        self
+    }
 }
 impl DatabaseItem for NodeStartQuest {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
@@ -15630,6 +24185,37 @@ impl DatabaseItem for NodeStartQuest {
         "NodeStartQuest"
     }
 }
+impl VisitIds for NodeStartQuest {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#quest.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeStartQuest {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeStartQuest {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "quest"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "quest" => Some(FieldValue::of(&self.r#quest)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "quest" => value.write_to(&mut self.r#quest, "quest"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeStartQuest {
     fn default() -> Self {
         Self::new()
@@ -15653,15 +24239,24 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeSetCharacterRelations {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#character: Option<CharacterId>,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#value: i32,
@@ -15699,6 +24294,10 @@ impl NodeSetCharacterRelations {
         self.r#character = r#character.into();
         self
     }
+    pub fn clear_character(&mut self) -> &mut Self {
+        self.r#character = None;
+        self
+    }
     pub fn with_value(mut self, r#value: impl Into<i32>) -> Self {
         self.r#value = r#value.into();
         self
@@ -15745,6 +24344,39 @@ impl DatabaseItem for NodeSetCharacterRelations {
         "NodeSetCharacterRelations"
     }
 }
+impl VisitIds for NodeSetCharacterRelations {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#character.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeSetCharacterRelations {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeSetCharacterRelations {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "character", "value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "character" => Some(FieldValue::of(&self.r#character)),
+            "value" => Some(FieldValue::of(&self.r#value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "character" => value.write_to(&mut self.r#character, "character"),
+            "value" => value.write_to(&mut self.r#value, "value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeSetCharacterRelations {
     fn default() -> Self {
         Self::new()
@@ -15768,12 +24400,21 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeSetFactionRelations {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#value: i32,
@@ -15848,6 +24489,35 @@ impl DatabaseItem for NodeSetFactionRelations {
         "NodeSetFactionRelations"
     }
 }
+impl VisitIds for NodeSetFactionRelations {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeSetFactionRelations {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeSetFactionRelations {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "value" => Some(FieldValue::of(&self.r#value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "value" => value.write_to(&mut self.r#value, "value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeSetFactionRelations {
     fn default() -> Self {
         Self::new()
@@ -15871,13 +24541,23 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeSetFactionStarbasePower {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
     ///Percentage value
+    ///
+    /// - Minimum: `0`
+    /// - Maximum: `100000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#value: i32,
@@ -15952,6 +24632,35 @@ impl DatabaseItem for NodeSetFactionStarbasePower {
         "NodeSetFactionStarbasePower"
     }
 }
+impl VisitIds for NodeSetFactionStarbasePower {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeSetFactionStarbasePower {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeSetFactionStarbasePower {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "value" => Some(FieldValue::of(&self.r#value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "value" => value.write_to(&mut self.r#value, "value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeSetFactionStarbasePower {
     fn default() -> Self {
         Self::new()
@@ -15975,15 +24684,24 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeChangeCharacterRelations {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#character: Option<CharacterId>,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#value: i32,
@@ -16021,6 +24739,10 @@ impl NodeChangeCharacterRelations {
         self.r#character = r#character.into();
         self
     }
+    pub fn clear_character(&mut self) -> &mut Self {
+        self.r#character = None;
+        self
+    }
     pub fn with_value(mut self, r#value: impl Into<i32>) -> Self {
         self.r#value = r#value.into();
         self
@@ -16067,6 +24789,39 @@ impl DatabaseItem for NodeChangeCharacterRelations {
         "NodeChangeCharacterRelations"
     }
 }
+impl VisitIds for NodeChangeCharacterRelations {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#character.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeChangeCharacterRelations {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeChangeCharacterRelations {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "character", "value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "character" => Some(FieldValue::of(&self.r#character)),
+            "value" => Some(FieldValue::of(&self.r#value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "character" => value.write_to(&mut self.r#character, "character"),
+            "value" => value.write_to(&mut self.r#value, "value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeChangeCharacterRelations {
     fn default() -> Self {
         Self::new()
@@ -16090,12 +24845,21 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeChangeFactionRelations {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#value: i32,
@@ -16170,6 +24934,35 @@ impl DatabaseItem for NodeChangeFactionRelations {
         "NodeChangeFactionRelations"
     }
 }
+impl VisitIds for NodeChangeFactionRelations {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeChangeFactionRelations {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeChangeFactionRelations {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "value" => Some(FieldValue::of(&self.r#value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "value" => value.write_to(&mut self.r#value, "value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeChangeFactionRelations {
     fn default() -> Self {
         Self::new()
@@ -16193,13 +24986,23 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeChangeFactionStarbasePower {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
     ///Percentage value
+    ///
+    /// - Minimum: `-100000`
+    /// - Maximum: `100000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#value: i32,
@@ -16274,6 +25077,35 @@ impl DatabaseItem for NodeChangeFactionStarbasePower {
         "NodeChangeFactionStarbasePower"
     }
 }
+impl VisitIds for NodeChangeFactionStarbasePower {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeChangeFactionStarbasePower {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeChangeFactionStarbasePower {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "value" => Some(FieldValue::of(&self.r#value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "value" => value.write_to(&mut self.r#value, "value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeChangeFactionStarbasePower {
     fn default() -> Self {
         Self::new()
@@ -16297,9 +25129,15 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeCaptureStarBase {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
@@ -16356,6 +25194,33 @@ impl DatabaseItem for NodeCaptureStarBase {
         "NodeCaptureStarBase"
     }
 }
+impl VisitIds for NodeCaptureStarBase {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeCaptureStarBase {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeCaptureStarBase {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeCaptureStarBase {
     fn default() -> Self {
         Self::new()
@@ -16379,9 +25244,15 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeLiberateStarBase {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
@@ -16438,6 +25309,33 @@ impl DatabaseItem for NodeLiberateStarBase {
         "NodeLiberateStarBase"
     }
 }
+impl VisitIds for NodeLiberateStarBase {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for NodeLiberateStarBase {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeLiberateStarBase {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeLiberateStarBase {
     fn default() -> Self {
         Self::new()
@@ -16461,9 +25359,15 @@ impl Node {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeChangeFaction {
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#id: i32,
+    /// - Minimum: `1`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#default_transition: i32,
@@ -16503,6 +25407,10 @@ impl NodeChangeFaction {
         self.r#faction = r#faction.into();
         self
     }
+    pub fn clear_faction(&mut self) -> &mut Self {
+        self.r#faction = None;
+        self
+    }
 }
 impl DatabaseItem for NodeChangeFaction {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
@@ -16532,6 +25440,37 @@ impl DatabaseItem for NodeChangeFaction {
         "NodeChangeFaction"
     }
 }
+impl VisitIds for NodeChangeFaction {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#faction.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeChangeFaction {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for NodeChangeFaction {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "default_transition", "faction"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "default_transition" => Some(FieldValue::of(&self.r#default_transition)),
+            "faction" => Some(FieldValue::of(&self.r#faction)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "default_transition" => {
+                value.write_to(&mut self.r#default_transition, "default_transition")
+            }
+            "faction" => value.write_to(&mut self.r#faction, "faction"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeChangeFaction {
     fn default() -> Self {
         Self::new()
@@ -17204,6 +26143,182 @@ impl DatabaseItem for Node {
         "Node"
     }
 }
+impl VisitIds for Node {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        match self {
+            Self::Undefined(x) => x.visit_ids(visitor),
+            Self::ComingSoon(x) => x.visit_ids(visitor),
+            Self::ShowDialog(x) => x.visit_ids(visitor),
+            Self::OpenShipyard(x) => x.visit_ids(visitor),
+            Self::OpenWorkshop(x) => x.visit_ids(visitor),
+            Self::Switch(x) => x.visit_ids(visitor),
+            Self::Random(x) => x.visit_ids(visitor),
+            Self::Condition(x) => x.visit_ids(visitor),
+            Self::AttackFleet(x) => x.visit_ids(visitor),
+            Self::AttackOccupants(x) => x.visit_ids(visitor),
+            Self::AttackStarbase(x) => x.visit_ids(visitor),
+            Self::DestroyOccupants(x) => x.visit_ids(visitor),
+            Self::SuppressOccupants(x) => x.visit_ids(visitor),
+            Self::Retreat(x) => x.visit_ids(visitor),
+            Self::ReceiveItem(x) => x.visit_ids(visitor),
+            Self::RemoveItem(x) => x.visit_ids(visitor),
+            Self::Trade(x) => x.visit_ids(visitor),
+            Self::CompleteQuest(x) => x.visit_ids(visitor),
+            Self::FailQuest(x) => x.visit_ids(visitor),
+            Self::CancelQuest(x) => x.visit_ids(visitor),
+            Self::StartQuest(x) => x.visit_ids(visitor),
+            Self::SetCharacterRelations(x) => x.visit_ids(visitor),
+            Self::SetFactionRelations(x) => x.visit_ids(visitor),
+            Self::SetFactionStarbasePower(x) => x.visit_ids(visitor),
+            Self::ChangeCharacterRelations(x) => x.visit_ids(visitor),
+            Self::ChangeFactionRelations(x) => x.visit_ids(visitor),
+            Self::ChangeFactionStarbasePower(x) => x.visit_ids(visitor),
+            Self::CaptureStarBase(x) => x.visit_ids(visitor),
+            Self::LiberateStarBase(x) => x.visit_ids(visitor),
+            Self::ChangeFaction(x) => x.visit_ids(visitor),
+        }
+    }
+}
+impl VisitAssets for Node {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        match self {
+            Self::Undefined(x) => x.visit_assets(visitor),
+            Self::ComingSoon(x) => x.visit_assets(visitor),
+            Self::ShowDialog(x) => x.visit_assets(visitor),
+            Self::OpenShipyard(x) => x.visit_assets(visitor),
+            Self::OpenWorkshop(x) => x.visit_assets(visitor),
+            Self::Switch(x) => x.visit_assets(visitor),
+            Self::Random(x) => x.visit_assets(visitor),
+            Self::Condition(x) => x.visit_assets(visitor),
+            Self::AttackFleet(x) => x.visit_assets(visitor),
+            Self::AttackOccupants(x) => x.visit_assets(visitor),
+            Self::AttackStarbase(x) => x.visit_assets(visitor),
+            Self::DestroyOccupants(x) => x.visit_assets(visitor),
+            Self::SuppressOccupants(x) => x.visit_assets(visitor),
+            Self::Retreat(x) => x.visit_assets(visitor),
+            Self::ReceiveItem(x) => x.visit_assets(visitor),
+            Self::RemoveItem(x) => x.visit_assets(visitor),
+            Self::Trade(x) => x.visit_assets(visitor),
+            Self::CompleteQuest(x) => x.visit_assets(visitor),
+            Self::FailQuest(x) => x.visit_assets(visitor),
+            Self::CancelQuest(x) => x.visit_assets(visitor),
+            Self::StartQuest(x) => x.visit_assets(visitor),
+            Self::SetCharacterRelations(x) => x.visit_assets(visitor),
+            Self::SetFactionRelations(x) => x.visit_assets(visitor),
+            Self::SetFactionStarbasePower(x) => x.visit_assets(visitor),
+            Self::ChangeCharacterRelations(x) => x.visit_assets(visitor),
+            Self::ChangeFactionRelations(x) => x.visit_assets(visitor),
+            Self::ChangeFactionStarbasePower(x) => x.visit_assets(visitor),
+            Self::CaptureStarBase(x) => x.visit_assets(visitor),
+            Self::LiberateStarBase(x) => x.visit_assets(visitor),
+            Self::ChangeFaction(x) => x.visit_assets(visitor),
+        }
+    }
+}
+impl DynamicFields for Node {
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Undefined(x) => x.field_names(),
+            Self::ComingSoon(x) => x.field_names(),
+            Self::ShowDialog(x) => x.field_names(),
+            Self::OpenShipyard(x) => x.field_names(),
+            Self::OpenWorkshop(x) => x.field_names(),
+            Self::Switch(x) => x.field_names(),
+            Self::Random(x) => x.field_names(),
+            Self::Condition(x) => x.field_names(),
+            Self::AttackFleet(x) => x.field_names(),
+            Self::AttackOccupants(x) => x.field_names(),
+            Self::AttackStarbase(x) => x.field_names(),
+            Self::DestroyOccupants(x) => x.field_names(),
+            Self::SuppressOccupants(x) => x.field_names(),
+            Self::Retreat(x) => x.field_names(),
+            Self::ReceiveItem(x) => x.field_names(),
+            Self::RemoveItem(x) => x.field_names(),
+            Self::Trade(x) => x.field_names(),
+            Self::CompleteQuest(x) => x.field_names(),
+            Self::FailQuest(x) => x.field_names(),
+            Self::CancelQuest(x) => x.field_names(),
+            Self::StartQuest(x) => x.field_names(),
+            Self::SetCharacterRelations(x) => x.field_names(),
+            Self::SetFactionRelations(x) => x.field_names(),
+            Self::SetFactionStarbasePower(x) => x.field_names(),
+            Self::ChangeCharacterRelations(x) => x.field_names(),
+            Self::ChangeFactionRelations(x) => x.field_names(),
+            Self::ChangeFactionStarbasePower(x) => x.field_names(),
+            Self::CaptureStarBase(x) => x.field_names(),
+            Self::LiberateStarBase(x) => x.field_names(),
+            Self::ChangeFaction(x) => x.field_names(),
+        }
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match self {
+            Self::Undefined(x) => x.get_field(name),
+            Self::ComingSoon(x) => x.get_field(name),
+            Self::ShowDialog(x) => x.get_field(name),
+            Self::OpenShipyard(x) => x.get_field(name),
+            Self::OpenWorkshop(x) => x.get_field(name),
+            Self::Switch(x) => x.get_field(name),
+            Self::Random(x) => x.get_field(name),
+            Self::Condition(x) => x.get_field(name),
+            Self::AttackFleet(x) => x.get_field(name),
+            Self::AttackOccupants(x) => x.get_field(name),
+            Self::AttackStarbase(x) => x.get_field(name),
+            Self::DestroyOccupants(x) => x.get_field(name),
+            Self::SuppressOccupants(x) => x.get_field(name),
+            Self::Retreat(x) => x.get_field(name),
+            Self::ReceiveItem(x) => x.get_field(name),
+            Self::RemoveItem(x) => x.get_field(name),
+            Self::Trade(x) => x.get_field(name),
+            Self::CompleteQuest(x) => x.get_field(name),
+            Self::FailQuest(x) => x.get_field(name),
+            Self::CancelQuest(x) => x.get_field(name),
+            Self::StartQuest(x) => x.get_field(name),
+            Self::SetCharacterRelations(x) => x.get_field(name),
+            Self::SetFactionRelations(x) => x.get_field(name),
+            Self::SetFactionStarbasePower(x) => x.get_field(name),
+            Self::ChangeCharacterRelations(x) => x.get_field(name),
+            Self::ChangeFactionRelations(x) => x.get_field(name),
+            Self::ChangeFactionStarbasePower(x) => x.get_field(name),
+            Self::CaptureStarBase(x) => x.get_field(name),
+            Self::LiberateStarBase(x) => x.get_field(name),
+            Self::ChangeFaction(x) => x.get_field(name),
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match self {
+            Self::Undefined(x) => x.set_field(name, value),
+            Self::ComingSoon(x) => x.set_field(name, value),
+            Self::ShowDialog(x) => x.set_field(name, value),
+            Self::OpenShipyard(x) => x.set_field(name, value),
+            Self::OpenWorkshop(x) => x.set_field(name, value),
+            Self::Switch(x) => x.set_field(name, value),
+            Self::Random(x) => x.set_field(name, value),
+            Self::Condition(x) => x.set_field(name, value),
+            Self::AttackFleet(x) => x.set_field(name, value),
+            Self::AttackOccupants(x) => x.set_field(name, value),
+            Self::AttackStarbase(x) => x.set_field(name, value),
+            Self::DestroyOccupants(x) => x.set_field(name, value),
+            Self::SuppressOccupants(x) => x.set_field(name, value),
+            Self::Retreat(x) => x.set_field(name, value),
+            Self::ReceiveItem(x) => x.set_field(name, value),
+            Self::RemoveItem(x) => x.set_field(name, value),
+            Self::Trade(x) => x.set_field(name, value),
+            Self::CompleteQuest(x) => x.set_field(name, value),
+            Self::FailQuest(x) => x.set_field(name, value),
+            Self::CancelQuest(x) => x.set_field(name, value),
+            Self::StartQuest(x) => x.set_field(name, value),
+            Self::SetCharacterRelations(x) => x.set_field(name, value),
+            Self::SetFactionRelations(x) => x.set_field(name, value),
+            Self::SetFactionStarbasePower(x) => x.set_field(name, value),
+            Self::ChangeCharacterRelations(x) => x.set_field(name, value),
+            Self::ChangeFactionRelations(x) => x.set_field(name, value),
+            Self::ChangeFactionStarbasePower(x) => x.set_field(name, value),
+            Self::CaptureStarBase(x) => x.set_field(name, value),
+            Self::LiberateStarBase(x) => x.set_field(name, value),
+            Self::ChangeFaction(x) => x.set_field(name, value),
+        }
+    }
+}
 impl Node {
     pub fn inner_type_name(&self) -> &'static str {
         match self {
@@ -17240,11 +26355,52 @@ impl Node {
         }
     }
 }
+/// Runs the same code for every variant of [Node], binding the inner item to the given pattern
+#[macro_export]
+macro_rules! match_node {
+    ($value:expr, |$inner:pat_param| $body:expr) => {
+        match $value {
+            $crate::schema::Node::Undefined($inner) => $body,
+            $crate::schema::Node::ComingSoon($inner) => $body,
+            $crate::schema::Node::ShowDialog($inner) => $body,
+            $crate::schema::Node::OpenShipyard($inner) => $body,
+            $crate::schema::Node::OpenWorkshop($inner) => $body,
+            $crate::schema::Node::Switch($inner) => $body,
+            $crate::schema::Node::Random($inner) => $body,
+            $crate::schema::Node::Condition($inner) => $body,
+            $crate::schema::Node::AttackFleet($inner) => $body,
+            $crate::schema::Node::AttackOccupants($inner) => $body,
+            $crate::schema::Node::AttackStarbase($inner) => $body,
+            $crate::schema::Node::DestroyOccupants($inner) => $body,
+            $crate::schema::Node::SuppressOccupants($inner) => $body,
+            $crate::schema::Node::Retreat($inner) => $body,
+            $crate::schema::Node::ReceiveItem($inner) => $body,
+            $crate::schema::Node::RemoveItem($inner) => $body,
+            $crate::schema::Node::Trade($inner) => $body,
+            $crate::schema::Node::CompleteQuest($inner) => $body,
+            $crate::schema::Node::FailQuest($inner) => $body,
+            $crate::schema::Node::CancelQuest($inner) => $body,
+            $crate::schema::Node::StartQuest($inner) => $body,
+            $crate::schema::Node::SetCharacterRelations($inner) => $body,
+            $crate::schema::Node::SetFactionRelations($inner) => $body,
+            $crate::schema::Node::SetFactionStarbasePower($inner) => $body,
+            $crate::schema::Node::ChangeCharacterRelations($inner) => $body,
+            $crate::schema::Node::ChangeFactionRelations($inner) => $body,
+            $crate::schema::Node::ChangeFactionStarbasePower($inner) => $body,
+            $crate::schema::Node::CaptureStarBase($inner) => $body,
+            $crate::schema::Node::LiberateStarBase($inner) => $body,
+            $crate::schema::Node::ChangeFaction($inner) => $body,
+        }
+    };
+}
 
 // v1/Objects/Quests/NodeAction.xml
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeAction {
+    /// - Minimum: `1`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#target_node: i32,
@@ -17306,6 +26462,37 @@ impl DatabaseItem for NodeAction {
         "NodeAction"
     }
 }
+impl VisitIds for NodeAction {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeAction {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for NodeAction {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["target_node", "requirement", "button_text"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "target_node" => Some(FieldValue::of(&self.r#target_node)),
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "button_text" => Some(FieldValue::of(&self.r#button_text)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "target_node" => value.write_to(&mut self.r#target_node, "target_node"),
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "button_text" => value.write_to(&mut self.r#button_text, "button_text"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for NodeAction {
     fn default() -> Self {
         Self::new()
@@ -17316,11 +26503,17 @@ impl Default for NodeAction {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct NodeTransition {
+    /// - Minimum: `1`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#target_node: i32,
     #[serde(default)]
     pub r#requirement: Requirement,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#weight: f32,
@@ -17387,6 +26580,37 @@ impl DatabaseItem for NodeTransition {
         "NodeTransition"
     }
 }
+impl VisitIds for NodeTransition {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirement.visit_ids(visitor);
+    }
+}
+impl VisitAssets for NodeTransition {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirement.visit_assets(visitor);
+    }
+}
+impl DynamicFields for NodeTransition {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["target_node", "requirement", "weight"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "target_node" => Some(FieldValue::of(&self.r#target_node)),
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "weight" => Some(FieldValue::of(&self.r#weight)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "target_node" => value.write_to(&mut self.r#target_node, "target_node"),
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "weight" => value.write_to(&mut self.r#weight, "weight"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for NodeTransition {}
 impl std::cmp::PartialEq for NodeTransition {
     fn eq(&self, other: &Self) -> bool {
@@ -17417,15 +26641,27 @@ pub struct QuestOrigin {
     pub r#type: QuestOriginType,
     #[serde(default)]
     pub r#factions: FactionFilter,
+    /// - Minimum: `0`
+    /// - Maximum: `9999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_distance: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `9999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_distance: i32,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_relations: i32,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_relations: i32,
@@ -17537,6 +26773,50 @@ impl DatabaseItem for QuestOrigin {
         "QuestOrigin"
     }
 }
+impl VisitIds for QuestOrigin {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#factions.visit_ids(visitor);
+    }
+}
+impl VisitAssets for QuestOrigin {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#factions.visit_assets(visitor);
+    }
+}
+impl DynamicFields for QuestOrigin {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "type",
+            "factions",
+            "min_distance",
+            "max_distance",
+            "min_relations",
+            "max_relations",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "type" => Some(FieldValue::of(&self.r#type)),
+            "factions" => Some(FieldValue::of(&self.r#factions)),
+            "min_distance" => Some(FieldValue::of(&self.r#min_distance)),
+            "max_distance" => Some(FieldValue::of(&self.r#max_distance)),
+            "min_relations" => Some(FieldValue::of(&self.r#min_relations)),
+            "max_relations" => Some(FieldValue::of(&self.r#max_relations)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "type" => value.write_to(&mut self.r#type, "type"),
+            "factions" => value.write_to(&mut self.r#factions, "factions"),
+            "min_distance" => value.write_to(&mut self.r#min_distance, "min_distance"),
+            "max_distance" => value.write_to(&mut self.r#max_distance, "max_distance"),
+            "min_relations" => value.write_to(&mut self.r#min_relations, "min_relations"),
+            "max_relations" => value.write_to(&mut self.r#max_relations, "max_relations"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for QuestOrigin {
     fn default() -> Self {
         Self::new()
@@ -17587,6 +26867,27 @@ impl DatabaseItem for RequirementEmpty {
         "RequirementEmpty"
     }
 }
+impl VisitIds for RequirementEmpty {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for RequirementEmpty {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementEmpty {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementEmpty {
     fn default() -> Self {
         Self::new()
@@ -17642,6 +26943,33 @@ impl DatabaseItem for RequirementAny {
         "RequirementAny"
     }
 }
+impl VisitIds for RequirementAny {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirements.visit_ids(visitor);
+    }
+}
+impl VisitAssets for RequirementAny {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirements.visit_assets(visitor);
+    }
+}
+impl DynamicFields for RequirementAny {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirements"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirements" => Some(FieldValue::of(&self.r#requirements)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirements" => value.write_to(&mut self.r#requirements, "requirements"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementAny {
     fn default() -> Self {
         Self::new()
@@ -17697,6 +27025,33 @@ impl DatabaseItem for RequirementAll {
         "RequirementAll"
     }
 }
+impl VisitIds for RequirementAll {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirements.visit_ids(visitor);
+    }
+}
+impl VisitAssets for RequirementAll {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirements.visit_assets(visitor);
+    }
+}
+impl DynamicFields for RequirementAll {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirements"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirements" => Some(FieldValue::of(&self.r#requirements)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirements" => value.write_to(&mut self.r#requirements, "requirements"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementAll {
     fn default() -> Self {
         Self::new()
@@ -17752,6 +27107,33 @@ impl DatabaseItem for RequirementNone {
         "RequirementNone"
     }
 }
+impl VisitIds for RequirementNone {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#requirements.visit_ids(visitor);
+    }
+}
+impl VisitAssets for RequirementNone {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#requirements.visit_assets(visitor);
+    }
+}
+impl DynamicFields for RequirementNone {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["requirements"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "requirements" => Some(FieldValue::of(&self.r#requirements)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "requirements" => value.write_to(&mut self.r#requirements, "requirements"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementNone {
     fn default() -> Self {
         Self::new()
@@ -17775,12 +27157,19 @@ impl Requirement {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct RequirementPlayerPosition {
+    /// - Minimum: `0`
+    /// - Maximum: `10000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_value: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `10000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_value: i32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#bool_value: bool,
@@ -17843,6 +27232,33 @@ impl DatabaseItem for RequirementPlayerPosition {
         "RequirementPlayerPosition"
     }
 }
+impl VisitIds for RequirementPlayerPosition {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for RequirementPlayerPosition {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementPlayerPosition {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["min_value", "max_value", "bool_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            "bool_value" => Some(FieldValue::of(&self.r#bool_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            "bool_value" => value.write_to(&mut self.r#bool_value, "bool_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementPlayerPosition {
     fn default() -> Self {
         Self::new()
@@ -17866,12 +27282,19 @@ impl Requirement {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct RequirementRandomStarSystem {
+    /// - Minimum: `0`
+    /// - Maximum: `10000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_value: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `10000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_value: i32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#bool_value: bool,
@@ -17934,6 +27357,33 @@ impl DatabaseItem for RequirementRandomStarSystem {
         "RequirementRandomStarSystem"
     }
 }
+impl VisitIds for RequirementRandomStarSystem {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for RequirementRandomStarSystem {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementRandomStarSystem {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["min_value", "max_value", "bool_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            "bool_value" => Some(FieldValue::of(&self.r#bool_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            "bool_value" => value.write_to(&mut self.r#bool_value, "bool_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementRandomStarSystem {
     fn default() -> Self {
         Self::new()
@@ -17968,6 +27418,27 @@ impl DatabaseItem for RequirementAggressiveOccupants {
         "RequirementAggressiveOccupants"
     }
 }
+impl VisitIds for RequirementAggressiveOccupants {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for RequirementAggressiveOccupants {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementAggressiveOccupants {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementAggressiveOccupants {
     fn default() -> Self {
         Self::new()
@@ -18009,6 +27480,10 @@ impl RequirementQuestCompleted {
         self.r#item_id = r#item_id.into();
         self
     }
+    pub fn clear_item_id(&mut self) -> &mut Self {
+        self.r#item_id = None;
+        self
+    }
 }
 impl DatabaseItem for RequirementQuestCompleted {
     fn validate(&self, ctx: DiagnosticContextRef) {}
@@ -18016,6 +27491,31 @@ impl DatabaseItem for RequirementQuestCompleted {
         "RequirementQuestCompleted"
     }
 }
+impl VisitIds for RequirementQuestCompleted {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#item_id.visit_ids(visitor);
+    }
+}
+impl VisitAssets for RequirementQuestCompleted {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementQuestCompleted {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["item_id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementQuestCompleted {
     fn default() -> Self {
         Self::new()
@@ -18057,6 +27557,10 @@ impl RequirementQuestActive {
         self.r#item_id = r#item_id.into();
         self
     }
+    pub fn clear_item_id(&mut self) -> &mut Self {
+        self.r#item_id = None;
+        self
+    }
 }
 impl DatabaseItem for RequirementQuestActive {
     fn validate(&self, ctx: DiagnosticContextRef) {}
@@ -18064,6 +27568,31 @@ impl DatabaseItem for RequirementQuestActive {
         "RequirementQuestActive"
     }
 }
+impl VisitIds for RequirementQuestActive {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#item_id.visit_ids(visitor);
+    }
+}
+impl VisitAssets for RequirementQuestActive {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementQuestActive {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["item_id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementQuestActive {
     fn default() -> Self {
         Self::new()
@@ -18087,9 +27616,15 @@ impl Requirement {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct RequirementCharacterRelations {
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_value: i32,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_value: i32,
@@ -18129,6 +27664,10 @@ impl RequirementCharacterRelations {
         self.r#character = r#character.into();
         self
     }
+    pub fn clear_character(&mut self) -> &mut Self {
+        self.r#character = None;
+        self
+    }
 }
 impl DatabaseItem for RequirementCharacterRelations {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
@@ -18155,6 +27694,35 @@ impl DatabaseItem for RequirementCharacterRelations {
         "RequirementCharacterRelations"
     }
 }
+impl VisitIds for RequirementCharacterRelations {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#character.visit_ids(visitor);
+    }
+}
+impl VisitAssets for RequirementCharacterRelations {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementCharacterRelations {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["min_value", "max_value", "character"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            "character" => Some(FieldValue::of(&self.r#character)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            "character" => value.write_to(&mut self.r#character, "character"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementCharacterRelations {
     fn default() -> Self {
         Self::new()
@@ -18178,9 +27746,15 @@ impl Requirement {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct RequirementFactionRelations {
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_value: i32,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_value: i32,
@@ -18234,6 +27808,31 @@ impl DatabaseItem for RequirementFactionRelations {
         "RequirementFactionRelations"
     }
 }
+impl VisitIds for RequirementFactionRelations {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for RequirementFactionRelations {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementFactionRelations {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["min_value", "max_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementFactionRelations {
     fn default() -> Self {
         Self::new()
@@ -18268,6 +27867,27 @@ impl DatabaseItem for RequirementStarbaseCaptured {
         "RequirementStarbaseCaptured"
     }
 }
+impl VisitIds for RequirementStarbaseCaptured {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for RequirementStarbaseCaptured {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementStarbaseCaptured {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementStarbaseCaptured {
     fn default() -> Self {
         Self::new()
@@ -18292,10 +27912,18 @@ impl Requirement {
 #[serde(rename_all = "PascalCase")]
 pub struct RequirementFactionStarbasePower {
     ///Percentage value
+    ///
+    /// - Minimum: `0`
+    /// - Maximum: `100000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_value: i32,
     ///Percentage value
+    ///
+    /// - Minimum: `0`
+    /// - Maximum: `100000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_value: i32,
@@ -18349,6 +27977,31 @@ impl DatabaseItem for RequirementFactionStarbasePower {
         "RequirementFactionStarbasePower"
     }
 }
+impl VisitIds for RequirementFactionStarbasePower {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for RequirementFactionStarbasePower {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementFactionStarbasePower {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["min_value", "max_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementFactionStarbasePower {
     fn default() -> Self {
         Self::new()
@@ -18383,6 +28036,27 @@ impl DatabaseItem for RequirementIsHostileFaction {
         "RequirementIsHostileFaction"
     }
 }
+impl VisitIds for RequirementIsHostileFaction {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for RequirementIsHostileFaction {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementIsHostileFaction {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementIsHostileFaction {
     fn default() -> Self {
         Self::new()
@@ -18424,6 +28098,10 @@ impl RequirementFaction {
         self.r#faction = r#faction.into();
         self
     }
+    pub fn clear_faction(&mut self) -> &mut Self {
+        self.r#faction = None;
+        self
+    }
 }
 impl DatabaseItem for RequirementFaction {
     fn validate(&self, ctx: DiagnosticContextRef) {}
@@ -18431,6 +28109,31 @@ impl DatabaseItem for RequirementFaction {
         "RequirementFaction"
     }
 }
+impl VisitIds for RequirementFaction {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#faction.visit_ids(visitor);
+    }
+}
+impl VisitAssets for RequirementFaction {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementFaction {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["faction"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "faction" => Some(FieldValue::of(&self.r#faction)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "faction" => value.write_to(&mut self.r#faction, "faction"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementFaction {
     fn default() -> Self {
         Self::new()
@@ -18457,6 +28160,9 @@ pub struct RequirementHaveQuestItem {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#item_id: Option<QuestItemId>,
+    /// - Minimum: `1`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_value: i32,
@@ -18476,6 +28182,10 @@ impl RequirementHaveQuestItem {
         self.r#item_id = r#item_id.into();
         self
     }
+    pub fn clear_item_id(&mut self) -> &mut Self {
+        self.r#item_id = None;
+        self
+    }
     pub fn with_min_value(mut self, r#min_value: impl Into<i32>) -> Self {
         self.r#min_value = r#min_value.into();
         self
@@ -18501,6 +28211,33 @@ impl DatabaseItem for RequirementHaveQuestItem {
         "RequirementHaveQuestItem"
     }
 }
+impl VisitIds for RequirementHaveQuestItem {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#item_id.visit_ids(visitor);
+    }
+}
+impl VisitAssets for RequirementHaveQuestItem {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementHaveQuestItem {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["item_id", "min_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementHaveQuestItem {
     fn default() -> Self {
         Self::new()
@@ -18553,6 +28290,33 @@ impl DatabaseItem for RequirementHaveItem {
         "RequirementHaveItem"
     }
 }
+impl VisitIds for RequirementHaveItem {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#loot.visit_ids(visitor);
+    }
+}
+impl VisitAssets for RequirementHaveItem {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#loot.visit_assets(visitor);
+    }
+}
+impl DynamicFields for RequirementHaveItem {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["loot"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "loot" => Some(FieldValue::of(&self.r#loot)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "loot" => value.write_to(&mut self.r#loot, "loot"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementHaveItem {
     fn default() -> Self {
         Self::new()
@@ -18594,6 +28358,10 @@ impl RequirementHaveItemById {
         self.r#item_id = r#item_id.into();
         self
     }
+    pub fn clear_item_id(&mut self) -> &mut Self {
+        self.r#item_id = None;
+        self
+    }
 }
 impl DatabaseItem for RequirementHaveItemById {
     fn validate(&self, ctx: DiagnosticContextRef) {}
@@ -18601,6 +28369,31 @@ impl DatabaseItem for RequirementHaveItemById {
         "RequirementHaveItemById"
     }
 }
+impl VisitIds for RequirementHaveItemById {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#item_id.visit_ids(visitor);
+    }
+}
+impl VisitAssets for RequirementHaveItemById {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementHaveItemById {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["item_id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementHaveItemById {
     fn default() -> Self {
         Self::new()
@@ -18624,6 +28417,7 @@ impl Requirement {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct RequirementComeToOrigin {
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#bool_value: bool,
@@ -18649,6 +28443,29 @@ impl DatabaseItem for RequirementComeToOrigin {
         "RequirementComeToOrigin"
     }
 }
+impl VisitIds for RequirementComeToOrigin {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for RequirementComeToOrigin {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementComeToOrigin {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["bool_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "bool_value" => Some(FieldValue::of(&self.r#bool_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "bool_value" => value.write_to(&mut self.r#bool_value, "bool_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementComeToOrigin {
     fn default() -> Self {
         Self::new()
@@ -18672,9 +28489,15 @@ impl Requirement {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct RequirementTimeSinceQuestStart {
+    /// - Minimum: `0`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_value: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_value: i32,
@@ -18728,6 +28551,31 @@ impl DatabaseItem for RequirementTimeSinceQuestStart {
         "RequirementTimeSinceQuestStart"
     }
 }
+impl VisitIds for RequirementTimeSinceQuestStart {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for RequirementTimeSinceQuestStart {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementTimeSinceQuestStart {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["min_value", "max_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementTimeSinceQuestStart {
     fn default() -> Self {
         Self::new()
@@ -18751,9 +28599,15 @@ impl Requirement {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct RequirementTimeSinceLastCompletion {
+    /// - Minimum: `0`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#min_value: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_value: i32,
@@ -18807,6 +28661,31 @@ impl DatabaseItem for RequirementTimeSinceLastCompletion {
         "RequirementTimeSinceLastCompletion"
     }
 }
+impl VisitIds for RequirementTimeSinceLastCompletion {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for RequirementTimeSinceLastCompletion {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for RequirementTimeSinceLastCompletion {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["min_value", "max_value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "min_value" => Some(FieldValue::of(&self.r#min_value)),
+            "max_value" => Some(FieldValue::of(&self.r#max_value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "min_value" => value.write_to(&mut self.r#min_value, "min_value"),
+            "max_value" => value.write_to(&mut self.r#max_value, "max_value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for RequirementTimeSinceLastCompletion {
     fn default() -> Self {
         Self::new()
@@ -19204,6 +29083,137 @@ impl DatabaseItem for Requirement {
         "Requirement"
     }
 }
+impl VisitIds for Requirement {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        match self {
+            Self::Empty(x) => x.visit_ids(visitor),
+            Self::Any(x) => x.visit_ids(visitor),
+            Self::All(x) => x.visit_ids(visitor),
+            Self::None(x) => x.visit_ids(visitor),
+            Self::PlayerPosition(x) => x.visit_ids(visitor),
+            Self::RandomStarSystem(x) => x.visit_ids(visitor),
+            Self::AggressiveOccupants(x) => x.visit_ids(visitor),
+            Self::QuestCompleted(x) => x.visit_ids(visitor),
+            Self::QuestActive(x) => x.visit_ids(visitor),
+            Self::CharacterRelations(x) => x.visit_ids(visitor),
+            Self::FactionRelations(x) => x.visit_ids(visitor),
+            Self::StarbaseCaptured(x) => x.visit_ids(visitor),
+            Self::FactionStarbasePower(x) => x.visit_ids(visitor),
+            Self::IsHostileFaction(x) => x.visit_ids(visitor),
+            Self::Faction(x) => x.visit_ids(visitor),
+            Self::HaveQuestItem(x) => x.visit_ids(visitor),
+            Self::HaveItem(x) => x.visit_ids(visitor),
+            Self::HaveItemById(x) => x.visit_ids(visitor),
+            Self::ComeToOrigin(x) => x.visit_ids(visitor),
+            Self::TimeSinceQuestStart(x) => x.visit_ids(visitor),
+            Self::TimeSinceLastCompletion(x) => x.visit_ids(visitor),
+        }
+    }
+}
+impl VisitAssets for Requirement {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        match self {
+            Self::Empty(x) => x.visit_assets(visitor),
+            Self::Any(x) => x.visit_assets(visitor),
+            Self::All(x) => x.visit_assets(visitor),
+            Self::None(x) => x.visit_assets(visitor),
+            Self::PlayerPosition(x) => x.visit_assets(visitor),
+            Self::RandomStarSystem(x) => x.visit_assets(visitor),
+            Self::AggressiveOccupants(x) => x.visit_assets(visitor),
+            Self::QuestCompleted(x) => x.visit_assets(visitor),
+            Self::QuestActive(x) => x.visit_assets(visitor),
+            Self::CharacterRelations(x) => x.visit_assets(visitor),
+            Self::FactionRelations(x) => x.visit_assets(visitor),
+            Self::StarbaseCaptured(x) => x.visit_assets(visitor),
+            Self::FactionStarbasePower(x) => x.visit_assets(visitor),
+            Self::IsHostileFaction(x) => x.visit_assets(visitor),
+            Self::Faction(x) => x.visit_assets(visitor),
+            Self::HaveQuestItem(x) => x.visit_assets(visitor),
+            Self::HaveItem(x) => x.visit_assets(visitor),
+            Self::HaveItemById(x) => x.visit_assets(visitor),
+            Self::ComeToOrigin(x) => x.visit_assets(visitor),
+            Self::TimeSinceQuestStart(x) => x.visit_assets(visitor),
+            Self::TimeSinceLastCompletion(x) => x.visit_assets(visitor),
+        }
+    }
+}
+impl DynamicFields for Requirement {
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Empty(x) => x.field_names(),
+            Self::Any(x) => x.field_names(),
+            Self::All(x) => x.field_names(),
+            Self::None(x) => x.field_names(),
+            Self::PlayerPosition(x) => x.field_names(),
+            Self::RandomStarSystem(x) => x.field_names(),
+            Self::AggressiveOccupants(x) => x.field_names(),
+            Self::QuestCompleted(x) => x.field_names(),
+            Self::QuestActive(x) => x.field_names(),
+            Self::CharacterRelations(x) => x.field_names(),
+            Self::FactionRelations(x) => x.field_names(),
+            Self::StarbaseCaptured(x) => x.field_names(),
+            Self::FactionStarbasePower(x) => x.field_names(),
+            Self::IsHostileFaction(x) => x.field_names(),
+            Self::Faction(x) => x.field_names(),
+            Self::HaveQuestItem(x) => x.field_names(),
+            Self::HaveItem(x) => x.field_names(),
+            Self::HaveItemById(x) => x.field_names(),
+            Self::ComeToOrigin(x) => x.field_names(),
+            Self::TimeSinceQuestStart(x) => x.field_names(),
+            Self::TimeSinceLastCompletion(x) => x.field_names(),
+        }
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match self {
+            Self::Empty(x) => x.get_field(name),
+            Self::Any(x) => x.get_field(name),
+            Self::All(x) => x.get_field(name),
+            Self::None(x) => x.get_field(name),
+            Self::PlayerPosition(x) => x.get_field(name),
+            Self::RandomStarSystem(x) => x.get_field(name),
+            Self::AggressiveOccupants(x) => x.get_field(name),
+            Self::QuestCompleted(x) => x.get_field(name),
+            Self::QuestActive(x) => x.get_field(name),
+            Self::CharacterRelations(x) => x.get_field(name),
+            Self::FactionRelations(x) => x.get_field(name),
+            Self::StarbaseCaptured(x) => x.get_field(name),
+            Self::FactionStarbasePower(x) => x.get_field(name),
+            Self::IsHostileFaction(x) => x.get_field(name),
+            Self::Faction(x) => x.get_field(name),
+            Self::HaveQuestItem(x) => x.get_field(name),
+            Self::HaveItem(x) => x.get_field(name),
+            Self::HaveItemById(x) => x.get_field(name),
+            Self::ComeToOrigin(x) => x.get_field(name),
+            Self::TimeSinceQuestStart(x) => x.get_field(name),
+            Self::TimeSinceLastCompletion(x) => x.get_field(name),
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match self {
+            Self::Empty(x) => x.set_field(name, value),
+            Self::Any(x) => x.set_field(name, value),
+            Self::All(x) => x.set_field(name, value),
+            Self::None(x) => x.set_field(name, value),
+            Self::PlayerPosition(x) => x.set_field(name, value),
+            Self::RandomStarSystem(x) => x.set_field(name, value),
+            Self::AggressiveOccupants(x) => x.set_field(name, value),
+            Self::QuestCompleted(x) => x.set_field(name, value),
+            Self::QuestActive(x) => x.set_field(name, value),
+            Self::CharacterRelations(x) => x.set_field(name, value),
+            Self::FactionRelations(x) => x.set_field(name, value),
+            Self::StarbaseCaptured(x) => x.set_field(name, value),
+            Self::FactionStarbasePower(x) => x.set_field(name, value),
+            Self::IsHostileFaction(x) => x.set_field(name, value),
+            Self::Faction(x) => x.set_field(name, value),
+            Self::HaveQuestItem(x) => x.set_field(name, value),
+            Self::HaveItem(x) => x.set_field(name, value),
+            Self::HaveItemById(x) => x.set_field(name, value),
+            Self::ComeToOrigin(x) => x.set_field(name, value),
+            Self::TimeSinceQuestStart(x) => x.set_field(name, value),
+            Self::TimeSinceLastCompletion(x) => x.set_field(name, value),
+        }
+    }
+}
 impl Requirement {
     pub fn inner_type_name(&self) -> &'static str {
         match self {
@@ -19231,11 +29241,43 @@ impl Requirement {
         }
     }
 }
+/// Runs the same code for every variant of [Requirement], binding the inner item to the given pattern
+#[macro_export]
+macro_rules! match_requirement {
+    ($value:expr, |$inner:pat_param| $body:expr) => {
+        match $value {
+            $crate::schema::Requirement::Empty($inner) => $body,
+            $crate::schema::Requirement::Any($inner) => $body,
+            $crate::schema::Requirement::All($inner) => $body,
+            $crate::schema::Requirement::None($inner) => $body,
+            $crate::schema::Requirement::PlayerPosition($inner) => $body,
+            $crate::schema::Requirement::RandomStarSystem($inner) => $body,
+            $crate::schema::Requirement::AggressiveOccupants($inner) => $body,
+            $crate::schema::Requirement::QuestCompleted($inner) => $body,
+            $crate::schema::Requirement::QuestActive($inner) => $body,
+            $crate::schema::Requirement::CharacterRelations($inner) => $body,
+            $crate::schema::Requirement::FactionRelations($inner) => $body,
+            $crate::schema::Requirement::StarbaseCaptured($inner) => $body,
+            $crate::schema::Requirement::FactionStarbasePower($inner) => $body,
+            $crate::schema::Requirement::IsHostileFaction($inner) => $body,
+            $crate::schema::Requirement::Faction($inner) => $body,
+            $crate::schema::Requirement::HaveQuestItem($inner) => $body,
+            $crate::schema::Requirement::HaveItem($inner) => $body,
+            $crate::schema::Requirement::HaveItemById($inner) => $body,
+            $crate::schema::Requirement::ComeToOrigin($inner) => $body,
+            $crate::schema::Requirement::TimeSinceQuestStart($inner) => $body,
+            $crate::schema::Requirement::TimeSinceLastCompletion($inner) => $body,
+        }
+    };
+}
 
 // v1/Objects/Settings/DebugCode.xml
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct DebugCode {
+    /// - Minimum: `0`
+    /// - Maximum: `999999`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#code: i32,
@@ -19286,6 +29328,35 @@ impl DatabaseItem for DebugCode {
         "DebugCode"
     }
 }
+impl VisitIds for DebugCode {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#loot.visit_ids(visitor);
+    }
+}
+impl VisitAssets for DebugCode {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#loot.visit_assets(visitor);
+    }
+}
+impl DynamicFields for DebugCode {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["code", "loot"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "code" => Some(FieldValue::of(&self.r#code)),
+            "loot" => Some(FieldValue::of(&self.r#loot)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "code" => value.write_to(&mut self.r#code, "code"),
+            "loot" => value.write_to(&mut self.r#loot, "loot"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for DebugCode {
     fn default() -> Self {
         Self::new()
@@ -19299,6 +29370,8 @@ pub struct ShipToValue {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#ship: Option<ShipId>,
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#value: i32,
@@ -19318,6 +29391,10 @@ impl ShipToValue {
         self.r#ship = r#ship.into();
         self
     }
+    pub fn clear_ship(&mut self) -> &mut Self {
+        self.r#ship = None;
+        self
+    }
     pub fn with_value(mut self, r#value: impl Into<i32>) -> Self {
         self.r#value = r#value.into();
         self
@@ -19340,6 +29417,33 @@ impl DatabaseItem for ShipToValue {
         "ShipToValue"
     }
 }
+impl VisitIds for ShipToValue {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#ship.visit_ids(visitor);
+    }
+}
+impl VisitAssets for ShipToValue {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for ShipToValue {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["ship", "value"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "ship" => Some(FieldValue::of(&self.r#ship)),
+            "value" => Some(FieldValue::of(&self.r#value)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "ship" => value.write_to(&mut self.r#ship, "ship"),
+            "value" => value.write_to(&mut self.r#value, "value"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for ShipToValue {
     fn default() -> Self {
         Self::new()
@@ -19374,6 +29478,33 @@ impl DatabaseItem for SoundTrack {
         "SoundTrack"
     }
 }
+impl VisitIds for SoundTrack {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for SoundTrack {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#audio.is_empty() {
+            visitor(AssetKind::AudioClip, &self.r#audio);
+        }
+    }
+}
+impl DynamicFields for SoundTrack {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["audio"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "audio" => Some(FieldValue::of(&self.r#audio)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "audio" => value.write_to(&mut self.r#audio, "audio"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for SoundTrack {
     fn default() -> Self {
         Self::new()
@@ -19430,6 +29561,33 @@ impl DatabaseItem for ShipBuildPerks {
         "ShipBuildPerks"
     }
 }
+impl VisitIds for ShipBuildPerks {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for ShipBuildPerks {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for ShipBuildPerks {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["perk_1", "perk_2", "perk_3"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "perk_1" => Some(FieldValue::of(&self.r#perk_1)),
+            "perk_2" => Some(FieldValue::of(&self.r#perk_2)),
+            "perk_3" => Some(FieldValue::of(&self.r#perk_3)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "perk_1" => value.write_to(&mut self.r#perk_1, "perk_1"),
+            "perk_2" => value.write_to(&mut self.r#perk_2, "perk_2"),
+            "perk_3" => value.write_to(&mut self.r#perk_3, "perk_3"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for ShipBuildPerks {
     fn default() -> Self {
         Self::new()
@@ -19440,45 +29598,85 @@ impl Default for ShipBuildPerks {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ShipFeatures {
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#energy_resistance: f32,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#kinetic_resistance: f32,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#heat_resistance: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#ship_weight_bonus: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#equipment_weight_bonus: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#velocity_bonus: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#turn_rate_bonus: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#armor_bonus: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#shield_bonus: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#energy_bonus: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#drone_build_speed_bonus: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#drone_attack_bonus: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#drone_defense_bonus: f32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#regeneration: bool,
@@ -19777,6 +29975,90 @@ impl DatabaseItem for ShipFeatures {
         "ShipFeatures"
     }
 }
+impl VisitIds for ShipFeatures {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#builtin_devices.visit_ids(visitor);
+    }
+}
+impl VisitAssets for ShipFeatures {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for ShipFeatures {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "energy_resistance",
+            "kinetic_resistance",
+            "heat_resistance",
+            "ship_weight_bonus",
+            "equipment_weight_bonus",
+            "velocity_bonus",
+            "turn_rate_bonus",
+            "armor_bonus",
+            "shield_bonus",
+            "energy_bonus",
+            "drone_build_speed_bonus",
+            "drone_attack_bonus",
+            "drone_defense_bonus",
+            "regeneration",
+            "builtin_devices",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "energy_resistance" => Some(FieldValue::of(&self.r#energy_resistance)),
+            "kinetic_resistance" => Some(FieldValue::of(&self.r#kinetic_resistance)),
+            "heat_resistance" => Some(FieldValue::of(&self.r#heat_resistance)),
+            "ship_weight_bonus" => Some(FieldValue::of(&self.r#ship_weight_bonus)),
+            "equipment_weight_bonus" => Some(FieldValue::of(&self.r#equipment_weight_bonus)),
+            "velocity_bonus" => Some(FieldValue::of(&self.r#velocity_bonus)),
+            "turn_rate_bonus" => Some(FieldValue::of(&self.r#turn_rate_bonus)),
+            "armor_bonus" => Some(FieldValue::of(&self.r#armor_bonus)),
+            "shield_bonus" => Some(FieldValue::of(&self.r#shield_bonus)),
+            "energy_bonus" => Some(FieldValue::of(&self.r#energy_bonus)),
+            "drone_build_speed_bonus" => Some(FieldValue::of(&self.r#drone_build_speed_bonus)),
+            "drone_attack_bonus" => Some(FieldValue::of(&self.r#drone_attack_bonus)),
+            "drone_defense_bonus" => Some(FieldValue::of(&self.r#drone_defense_bonus)),
+            "regeneration" => Some(FieldValue::of(&self.r#regeneration)),
+            "builtin_devices" => Some(FieldValue::of(&self.r#builtin_devices)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "energy_resistance" => {
+                value.write_to(&mut self.r#energy_resistance, "energy_resistance")
+            }
+            "kinetic_resistance" => {
+                value.write_to(&mut self.r#kinetic_resistance, "kinetic_resistance")
+            }
+            "heat_resistance" => value.write_to(&mut self.r#heat_resistance, "heat_resistance"),
+            "ship_weight_bonus" => {
+                value.write_to(&mut self.r#ship_weight_bonus, "ship_weight_bonus")
+            }
+            "equipment_weight_bonus" => {
+                value.write_to(&mut self.r#equipment_weight_bonus, "equipment_weight_bonus")
+            }
+            "velocity_bonus" => value.write_to(&mut self.r#velocity_bonus, "velocity_bonus"),
+            "turn_rate_bonus" => value.write_to(&mut self.r#turn_rate_bonus, "turn_rate_bonus"),
+            "armor_bonus" => value.write_to(&mut self.r#armor_bonus, "armor_bonus"),
+            "shield_bonus" => value.write_to(&mut self.r#shield_bonus, "shield_bonus"),
+            "energy_bonus" => value.write_to(&mut self.r#energy_bonus, "energy_bonus"),
+            "drone_build_speed_bonus" => value.write_to(
+                &mut self.r#drone_build_speed_bonus,
+                "drone_build_speed_bonus",
+            ),
+            "drone_attack_bonus" => {
+                value.write_to(&mut self.r#drone_attack_bonus, "drone_attack_bonus")
+            }
+            "drone_defense_bonus" => {
+                value.write_to(&mut self.r#drone_defense_bonus, "drone_defense_bonus")
+            }
+            "regeneration" => value.write_to(&mut self.r#regeneration, "regeneration"),
+            "builtin_devices" => value.write_to(&mut self.r#builtin_devices, "builtin_devices"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for ShipFeatures {}
 impl std::cmp::PartialEq for ShipFeatures {
     fn eq(&self, other: &Self) -> bool {
@@ -19841,21 +30123,27 @@ impl Default for ShipFeatures {
 pub struct StatModification {
     #[serde(default)]
     pub r#type: StatModificationType,
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#gray_3: f32,
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#gray_2: f32,
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#gray_1: f32,
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#green: f32,
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#purple: f32,
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#gold: f32,
@@ -19935,6 +30223,43 @@ impl DatabaseItem for StatModification {
         "StatModification"
     }
 }
+impl VisitIds for StatModification {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for StatModification {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for StatModification {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "type", "gray_3", "gray_2", "gray_1", "green", "purple", "gold",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "type" => Some(FieldValue::of(&self.r#type)),
+            "gray_3" => Some(FieldValue::of(&self.r#gray_3)),
+            "gray_2" => Some(FieldValue::of(&self.r#gray_2)),
+            "gray_1" => Some(FieldValue::of(&self.r#gray_1)),
+            "green" => Some(FieldValue::of(&self.r#green)),
+            "purple" => Some(FieldValue::of(&self.r#purple)),
+            "gold" => Some(FieldValue::of(&self.r#gold)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "type" => value.write_to(&mut self.r#type, "type"),
+            "gray_3" => value.write_to(&mut self.r#gray_3, "gray_3"),
+            "gray_2" => value.write_to(&mut self.r#gray_2, "gray_2"),
+            "gray_1" => value.write_to(&mut self.r#gray_1, "gray_1"),
+            "green" => value.write_to(&mut self.r#green, "green"),
+            "purple" => value.write_to(&mut self.r#purple, "purple"),
+            "gold" => value.write_to(&mut self.r#gold, "gold"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for StatModification {}
 impl std::cmp::PartialEq for StatModification {
     fn eq(&self, other: &Self) -> bool {
@@ -19973,53 +30298,87 @@ impl Default for StatModification {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BulletBody {
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#size: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#length: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#velocity: f32,
     ///How hard is the ammunition affected by the parent velocity during spawn.
+    ///
+    /// - Minimum: `-1000`
+    /// - Maximum: `1000`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#parent_velocity_effect: f32,
     ///Specifies whenever ammunition is attached to the parent ship or ammo. Moving ammo will move in parent's coordinate space
+    ///
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#attached_to_parent: bool,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#range: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#lifetime: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#weight: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#hit_points: i32,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#color: String,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#bullet_prefab: Option<BulletPrefabId>,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#energy_cost: f32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#can_be_disarmed: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#friendly_fire: bool,
     ///Hints for AI and auto-aim0 on usage of this weapon
     #[serde(default)]
     pub r#ai_bullet_behavior: AiBulletBehavior,
+    /// - Options: `obsolete`
     #[serde(default)]
     pub r#type: BulletTypeObsolete,
 }
@@ -20141,6 +30500,10 @@ impl BulletBody {
         self.r#bullet_prefab = r#bullet_prefab.into();
         self
     }
+    pub fn clear_bullet_prefab(&mut self) -> &mut Self {
+        self.r#bullet_prefab = None;
+        self
+    }
     pub fn with_energy_cost(mut self, r#energy_cost: impl Into<f32>) -> Self {
         self.r#energy_cost = r#energy_cost.into();
         self
@@ -20268,6 +30631,12 @@ impl DatabaseItem for BulletBody {
                 ctx.emit(DiagnosticKind::too_large(1000000000f32, self.r#hit_points));
             }
         }
+        {
+            let mut ctx = ctx.enter("color");
+            if !crate::color::Color::is_valid(&self.r#color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#color));
+            }
+        }
         {
             let mut ctx = ctx.enter("energy_cost");
             if self.r#energy_cost < 0_f32 {
@@ -20289,6 +30658,84 @@ impl DatabaseItem for BulletBody {
         "BulletBody"
     }
 }
+impl VisitIds for BulletBody {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#bullet_prefab.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BulletBody {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BulletBody {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "size",
+            "length",
+            "velocity",
+            "parent_velocity_effect",
+            "attached_to_parent",
+            "range",
+            "lifetime",
+            "weight",
+            "hit_points",
+            "color",
+            "bullet_prefab",
+            "energy_cost",
+            "can_be_disarmed",
+            "friendly_fire",
+            "ai_bullet_behavior",
+            "type",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "size" => Some(FieldValue::of(&self.r#size)),
+            "length" => Some(FieldValue::of(&self.r#length)),
+            "velocity" => Some(FieldValue::of(&self.r#velocity)),
+            "parent_velocity_effect" => Some(FieldValue::of(&self.r#parent_velocity_effect)),
+            "attached_to_parent" => Some(FieldValue::of(&self.r#attached_to_parent)),
+            "range" => Some(FieldValue::of(&self.r#range)),
+            "lifetime" => Some(FieldValue::of(&self.r#lifetime)),
+            "weight" => Some(FieldValue::of(&self.r#weight)),
+            "hit_points" => Some(FieldValue::of(&self.r#hit_points)),
+            "color" => Some(FieldValue::of(&self.r#color)),
+            "bullet_prefab" => Some(FieldValue::of(&self.r#bullet_prefab)),
+            "energy_cost" => Some(FieldValue::of(&self.r#energy_cost)),
+            "can_be_disarmed" => Some(FieldValue::of(&self.r#can_be_disarmed)),
+            "friendly_fire" => Some(FieldValue::of(&self.r#friendly_fire)),
+            "ai_bullet_behavior" => Some(FieldValue::of(&self.r#ai_bullet_behavior)),
+            "type" => Some(FieldValue::of(&self.r#type)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "size" => value.write_to(&mut self.r#size, "size"),
+            "length" => value.write_to(&mut self.r#length, "length"),
+            "velocity" => value.write_to(&mut self.r#velocity, "velocity"),
+            "parent_velocity_effect" => {
+                value.write_to(&mut self.r#parent_velocity_effect, "parent_velocity_effect")
+            }
+            "attached_to_parent" => {
+                value.write_to(&mut self.r#attached_to_parent, "attached_to_parent")
+            }
+            "range" => value.write_to(&mut self.r#range, "range"),
+            "lifetime" => value.write_to(&mut self.r#lifetime, "lifetime"),
+            "weight" => value.write_to(&mut self.r#weight, "weight"),
+            "hit_points" => value.write_to(&mut self.r#hit_points, "hit_points"),
+            "color" => value.write_to(&mut self.r#color, "color"),
+            "bullet_prefab" => value.write_to(&mut self.r#bullet_prefab, "bullet_prefab"),
+            "energy_cost" => value.write_to(&mut self.r#energy_cost, "energy_cost"),
+            "can_be_disarmed" => value.write_to(&mut self.r#can_be_disarmed, "can_be_disarmed"),
+            "friendly_fire" => value.write_to(&mut self.r#friendly_fire, "friendly_fire"),
+            "ai_bullet_behavior" => {
+                value.write_to(&mut self.r#ai_bullet_behavior, "ai_bullet_behavior")
+            }
+            "type" => value.write_to(&mut self.r#type, "type"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BulletBody {}
 impl std::cmp::PartialEq for BulletBody {
     fn eq(&self, other: &Self) -> bool {
@@ -20372,6 +30819,27 @@ impl DatabaseItem for BulletControllerProjectile {
         "BulletControllerProjectile"
     }
 }
+impl VisitIds for BulletControllerProjectile {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BulletControllerProjectile {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BulletControllerProjectile {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BulletControllerProjectile {
     fn default() -> Self {
         Self::new()
@@ -20395,12 +30863,17 @@ impl BulletController {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BulletControllerHoming {
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#starting_velocity_modifier: f32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#ignore_rotation: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#smart_aim: bool,
@@ -20466,6 +30939,38 @@ impl DatabaseItem for BulletControllerHoming {
         "BulletControllerHoming"
     }
 }
+impl VisitIds for BulletControllerHoming {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BulletControllerHoming {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BulletControllerHoming {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["starting_velocity_modifier", "ignore_rotation", "smart_aim"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "starting_velocity_modifier" => {
+                Some(FieldValue::of(&self.r#starting_velocity_modifier))
+            }
+            "ignore_rotation" => Some(FieldValue::of(&self.r#ignore_rotation)),
+            "smart_aim" => Some(FieldValue::of(&self.r#smart_aim)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "starting_velocity_modifier" => value.write_to(
+                &mut self.r#starting_velocity_modifier,
+                "starting_velocity_modifier",
+            ),
+            "ignore_rotation" => value.write_to(&mut self.r#ignore_rotation, "ignore_rotation"),
+            "smart_aim" => value.write_to(&mut self.r#smart_aim, "smart_aim"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BulletControllerHoming {}
 impl std::cmp::PartialEq for BulletControllerHoming {
     fn eq(&self, other: &Self) -> bool {
@@ -20516,6 +31021,27 @@ impl DatabaseItem for BulletControllerBeam {
         "BulletControllerBeam"
     }
 }
+impl VisitIds for BulletControllerBeam {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BulletControllerBeam {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BulletControllerBeam {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BulletControllerBeam {
     fn default() -> Self {
         Self::new()
@@ -20539,21 +31065,26 @@ impl BulletController {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct BulletControllerParametric {
+    /// - Default: `"0"`
     #[serde(default = "default_ඞquoteඞ0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ0ඞquoteඞ")]
-    pub r#x: String,
+    pub r#x: crate::expression::Expression,
+    /// - Default: `"0"`
     #[serde(default = "default_ඞquoteඞ0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ0ඞquoteඞ")]
-    pub r#y: String,
+    pub r#y: crate::expression::Expression,
+    /// - Default: `"0"`
     #[serde(default = "default_ඞquoteඞ0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ0ඞquoteඞ")]
-    pub r#rotation: String,
+    pub r#rotation: crate::expression::Expression,
+    /// - Default: `"1"`
     #[serde(default = "default_ඞquoteඞ1ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ1ඞquoteඞ")]
-    pub r#size: String,
+    pub r#size: crate::expression::Expression,
+    /// - Default: `"1"`
     #[serde(default = "default_ඞquoteඞ1ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ1ඞquoteඞ")]
-    pub r#length: String,
+    pub r#length: crate::expression::Expression,
 }
 impl BulletControllerParametric {
     pub fn new() -> Self {
@@ -20565,43 +31096,46 @@ impl BulletControllerParametric {
             r#length: "1".into(),
         }
     }
-    pub fn with_x(mut self, r#x: impl Into<String>) -> Self {
+    pub fn with_x(mut self, r#x: impl Into<crate::expression::Expression>) -> Self {
         self.r#x = r#x.into();
         self
     }
-    pub fn set_x(&mut self, r#x: impl Into<String>) -> &mut Self {
+    pub fn set_x(&mut self, r#x: impl Into<crate::expression::Expression>) -> &mut Self {
         self.r#x = r#x.into();
         self
     }
-    pub fn with_y(mut self, r#y: impl Into<String>) -> Self {
+    pub fn with_y(mut self, r#y: impl Into<crate::expression::Expression>) -> Self {
         self.r#y = r#y.into();
         self
     }
-    pub fn set_y(&mut self, r#y: impl Into<String>) -> &mut Self {
+    pub fn set_y(&mut self, r#y: impl Into<crate::expression::Expression>) -> &mut Self {
         self.r#y = r#y.into();
         self
     }
-    pub fn with_rotation(mut self, r#rotation: impl Into<String>) -> Self {
+    pub fn with_rotation(mut self, r#rotation: impl Into<crate::expression::Expression>) -> Self {
         self.r#rotation = r#rotation.into();
         self
     }
-    pub fn set_rotation(&mut self, r#rotation: impl Into<String>) -> &mut Self {
+    pub fn set_rotation(
+        &mut self,
+        r#rotation: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#rotation = r#rotation.into();
         self
     }
-    pub fn with_size(mut self, r#size: impl Into<String>) -> Self {
+    pub fn with_size(mut self, r#size: impl Into<crate::expression::Expression>) -> Self {
         self.r#size = r#size.into();
         self
     }
-    pub fn set_size(&mut self, r#size: impl Into<String>) -> &mut Self {
+    pub fn set_size(&mut self, r#size: impl Into<crate::expression::Expression>) -> &mut Self {
         self.r#size = r#size.into();
         self
     }
-    pub fn with_length(mut self, r#length: impl Into<String>) -> Self {
+    pub fn with_length(mut self, r#length: impl Into<crate::expression::Expression>) -> Self {
         self.r#length = r#length.into();
         self
     }
-    pub fn set_length(&mut self, r#length: impl Into<String>) -> &mut Self {
+    pub fn set_length(&mut self, r#length: impl Into<crate::expression::Expression>) -> &mut Self {
         self.r#length = r#length.into();
         self
     }
@@ -20612,6 +31146,37 @@ impl DatabaseItem for BulletControllerParametric {
         "BulletControllerParametric"
     }
 }
+impl VisitIds for BulletControllerParametric {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BulletControllerParametric {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BulletControllerParametric {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["x", "y", "rotation", "size", "length"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "x" => Some(FieldValue::of(&self.r#x)),
+            "y" => Some(FieldValue::of(&self.r#y)),
+            "rotation" => Some(FieldValue::of(&self.r#rotation)),
+            "size" => Some(FieldValue::of(&self.r#size)),
+            "length" => Some(FieldValue::of(&self.r#length)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "x" => value.write_to(&mut self.r#x, "x"),
+            "y" => value.write_to(&mut self.r#y, "y"),
+            "rotation" => value.write_to(&mut self.r#rotation, "rotation"),
+            "size" => value.write_to(&mut self.r#size, "size"),
+            "length" => value.write_to(&mut self.r#length, "length"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BulletControllerParametric {
     fn default() -> Self {
         Self::new()
@@ -20646,6 +31211,27 @@ impl DatabaseItem for BulletControllerHarpoon {
         "BulletControllerHarpoon"
     }
 }
+impl VisitIds for BulletControllerHarpoon {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BulletControllerHarpoon {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BulletControllerHarpoon {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BulletControllerHarpoon {
     fn default() -> Self {
         Self::new()
@@ -20680,6 +31266,27 @@ impl DatabaseItem for BulletControllerAuraEmitter {
         "BulletControllerAuraEmitter"
     }
 }
+impl VisitIds for BulletControllerAuraEmitter {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BulletControllerAuraEmitter {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BulletControllerAuraEmitter {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for BulletControllerAuraEmitter {
     fn default() -> Self {
         Self::new()
@@ -20852,6 +31459,62 @@ impl DatabaseItem for BulletController {
         "BulletController"
     }
 }
+impl VisitIds for BulletController {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        match self {
+            Self::Projectile(x) => x.visit_ids(visitor),
+            Self::Homing(x) => x.visit_ids(visitor),
+            Self::Beam(x) => x.visit_ids(visitor),
+            Self::Parametric(x) => x.visit_ids(visitor),
+            Self::Harpoon(x) => x.visit_ids(visitor),
+            Self::AuraEmitter(x) => x.visit_ids(visitor),
+        }
+    }
+}
+impl VisitAssets for BulletController {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        match self {
+            Self::Projectile(x) => x.visit_assets(visitor),
+            Self::Homing(x) => x.visit_assets(visitor),
+            Self::Beam(x) => x.visit_assets(visitor),
+            Self::Parametric(x) => x.visit_assets(visitor),
+            Self::Harpoon(x) => x.visit_assets(visitor),
+            Self::AuraEmitter(x) => x.visit_assets(visitor),
+        }
+    }
+}
+impl DynamicFields for BulletController {
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Projectile(x) => x.field_names(),
+            Self::Homing(x) => x.field_names(),
+            Self::Beam(x) => x.field_names(),
+            Self::Parametric(x) => x.field_names(),
+            Self::Harpoon(x) => x.field_names(),
+            Self::AuraEmitter(x) => x.field_names(),
+        }
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match self {
+            Self::Projectile(x) => x.get_field(name),
+            Self::Homing(x) => x.get_field(name),
+            Self::Beam(x) => x.get_field(name),
+            Self::Parametric(x) => x.get_field(name),
+            Self::Harpoon(x) => x.get_field(name),
+            Self::AuraEmitter(x) => x.get_field(name),
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match self {
+            Self::Projectile(x) => x.set_field(name, value),
+            Self::Homing(x) => x.set_field(name, value),
+            Self::Beam(x) => x.set_field(name, value),
+            Self::Parametric(x) => x.set_field(name, value),
+            Self::Harpoon(x) => x.set_field(name, value),
+            Self::AuraEmitter(x) => x.set_field(name, value),
+        }
+    }
+}
 impl BulletController {
     pub fn inner_type_name(&self) -> &'static str {
         match self {
@@ -20864,6 +31527,20 @@ impl BulletController {
         }
     }
 }
+/// Runs the same code for every variant of [BulletController], binding the inner item to the given pattern
+#[macro_export]
+macro_rules! match_bullet_controller {
+    ($value:expr, |$inner:pat_param| $body:expr) => {
+        match $value {
+            $crate::schema::BulletController::Projectile($inner) => $body,
+            $crate::schema::BulletController::Homing($inner) => $body,
+            $crate::schema::BulletController::Beam($inner) => $body,
+            $crate::schema::BulletController::Parametric($inner) => $body,
+            $crate::schema::BulletController::Harpoon($inner) => $body,
+            $crate::schema::BulletController::AuraEmitter($inner) => $body,
+        }
+    };
+}
 
 // v1/Objects/Weapon/BulletTrigger.xml
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -20885,6 +31562,9 @@ impl Default for BulletTrigger {
 pub struct BulletTriggerNone {
     #[serde(default)]
     pub r#condition: BulletTriggerCondition,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#cooldown: f32,
@@ -20929,6 +31609,31 @@ impl DatabaseItem for BulletTriggerNone {
         "BulletTriggerNone"
     }
 }
+impl VisitIds for BulletTriggerNone {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BulletTriggerNone {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BulletTriggerNone {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["condition", "cooldown"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "condition" => Some(FieldValue::of(&self.r#condition)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "condition" => value.write_to(&mut self.r#condition, "condition"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BulletTriggerNone {}
 impl std::cmp::PartialEq for BulletTriggerNone {
     fn eq(&self, other: &Self) -> bool {
@@ -20973,26 +31678,39 @@ pub struct BulletTriggerPlaySfx {
     pub r#visual_effect: Option<VisualEffectId>,
     #[serde(default)]
     pub r#audio_clip: String,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#color: String,
     #[serde(default)]
     pub r#color_mode: ColorMode,
+    /// - Minimum: `0`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#size: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#lifetime: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#cooldown: f32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#once_per_collision: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#use_bullet_position: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#sync_lifetime_with_bullet: bool,
@@ -21035,6 +31753,10 @@ impl BulletTriggerPlaySfx {
         self.r#visual_effect = r#visual_effect.into();
         self
     }
+    pub fn clear_visual_effect(&mut self) -> &mut Self {
+        self.r#visual_effect = None;
+        self
+    }
     pub fn with_audio_clip(mut self, r#audio_clip: impl Into<String>) -> Self {
         self.r#audio_clip = r#audio_clip.into();
         self
@@ -21116,6 +31838,12 @@ impl BulletTriggerPlaySfx {
 }
 impl DatabaseItem for BulletTriggerPlaySfx {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
+        {
+            let mut ctx = ctx.enter("color");
+            if !crate::color::Color::is_valid(&self.r#color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#color));
+            }
+        }
         {
             let mut ctx = ctx.enter("size");
             if self.r#size < 0_f32 {
@@ -21148,6 +31876,74 @@ impl DatabaseItem for BulletTriggerPlaySfx {
         "BulletTriggerPlaySfx"
     }
 }
+impl VisitIds for BulletTriggerPlaySfx {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#visual_effect.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BulletTriggerPlaySfx {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#audio_clip.is_empty() {
+            visitor(AssetKind::AudioClip, &self.r#audio_clip);
+        }
+    }
+}
+impl DynamicFields for BulletTriggerPlaySfx {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "condition",
+            "visual_effect",
+            "audio_clip",
+            "color",
+            "color_mode",
+            "size",
+            "lifetime",
+            "cooldown",
+            "once_per_collision",
+            "use_bullet_position",
+            "sync_lifetime_with_bullet",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "condition" => Some(FieldValue::of(&self.r#condition)),
+            "visual_effect" => Some(FieldValue::of(&self.r#visual_effect)),
+            "audio_clip" => Some(FieldValue::of(&self.r#audio_clip)),
+            "color" => Some(FieldValue::of(&self.r#color)),
+            "color_mode" => Some(FieldValue::of(&self.r#color_mode)),
+            "size" => Some(FieldValue::of(&self.r#size)),
+            "lifetime" => Some(FieldValue::of(&self.r#lifetime)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            "once_per_collision" => Some(FieldValue::of(&self.r#once_per_collision)),
+            "use_bullet_position" => Some(FieldValue::of(&self.r#use_bullet_position)),
+            "sync_lifetime_with_bullet" => Some(FieldValue::of(&self.r#sync_lifetime_with_bullet)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "condition" => value.write_to(&mut self.r#condition, "condition"),
+            "visual_effect" => value.write_to(&mut self.r#visual_effect, "visual_effect"),
+            "audio_clip" => value.write_to(&mut self.r#audio_clip, "audio_clip"),
+            "color" => value.write_to(&mut self.r#color, "color"),
+            "color_mode" => value.write_to(&mut self.r#color_mode, "color_mode"),
+            "size" => value.write_to(&mut self.r#size, "size"),
+            "lifetime" => value.write_to(&mut self.r#lifetime, "lifetime"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            "once_per_collision" => {
+                value.write_to(&mut self.r#once_per_collision, "once_per_collision")
+            }
+            "use_bullet_position" => {
+                value.write_to(&mut self.r#use_bullet_position, "use_bullet_position")
+            }
+            "sync_lifetime_with_bullet" => value.write_to(
+                &mut self.r#sync_lifetime_with_bullet,
+                "sync_lifetime_with_bullet",
+            ),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BulletTriggerPlaySfx {}
 impl std::cmp::PartialEq for BulletTriggerPlaySfx {
     fn eq(&self, other: &Self) -> bool {
@@ -21211,46 +32007,67 @@ pub struct BulletTriggerSpawnBullet {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#ammunition: Option<AmmunitionId>,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#color: String,
     #[serde(default)]
     pub r#color_mode: ColorMode,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#quantity: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#size: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#cooldown: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#random_factor: f32,
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#power_multiplier: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#max_nesting_level: i32,
+    /// - Default: `"IF(Quantity <= 1, 0, RANDOM(0, 360))"`
     #[serde(
         default = "default_ඞquoteඞIFඞlparenඞQuantityඞspaceඞඞltඞඞeqඞඞspaceඞ1ඞcommaඞඞspaceඞ0ඞcommaඞඞspaceඞRANDOMඞlparenඞ0ඞcommaඞඞspaceඞ360ඞrparenඞඞrparenඞඞquoteඞ"
     )]
     #[serde(
         skip_serializing_if = "skip_if_ඞquoteඞIFඞlparenඞQuantityඞspaceඞඞltඞඞeqඞඞspaceඞ1ඞcommaඞඞspaceඞ0ඞcommaඞඞspaceඞRANDOMඞlparenඞ0ඞcommaඞඞspaceඞ360ඞrparenඞඞrparenඞඞquoteඞ"
     )]
-    pub r#rotation: String,
+    pub r#rotation: crate::expression::Expression,
+    /// - Default: `"IF(Quantity <= 1, 0, Size / 2)"`
     #[serde(
         default = "default_ඞquoteඞIFඞlparenඞQuantityඞspaceඞඞltඞඞeqඞඞspaceඞ1ඞcommaඞඞspaceඞ0ඞcommaඞඞspaceඞSizeඞspaceඞඞslashඞඞspaceඞ2ඞrparenඞඞquoteඞ"
     )]
     #[serde(
         skip_serializing_if = "skip_if_ඞquoteඞIFඞlparenඞQuantityඞspaceඞඞltඞඞeqඞඞspaceඞ1ඞcommaඞඞspaceඞ0ඞcommaඞඞspaceඞSizeඞspaceඞඞslashඞඞspaceඞ2ඞrparenඞඞquoteඞ"
     )]
-    pub r#offset_x: String,
+    pub r#offset_x: crate::expression::Expression,
+    /// - Default: `"0"`
     #[serde(default = "default_ඞquoteඞ0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ0ඞquoteඞ")]
-    pub r#offset_y: String,
+    pub r#offset_y: crate::expression::Expression,
 }
 impl BulletTriggerSpawnBullet {
     pub fn new() -> Self {
@@ -21295,6 +32112,10 @@ impl BulletTriggerSpawnBullet {
         self.r#ammunition = r#ammunition.into();
         self
     }
+    pub fn clear_ammunition(&mut self) -> &mut Self {
+        self.r#ammunition = None;
+        self
+    }
     pub fn with_color(mut self, r#color: impl Into<String>) -> Self {
         self.r#color = r#color.into();
         self
@@ -21359,33 +32180,48 @@ impl BulletTriggerSpawnBullet {
         self.r#max_nesting_level = r#max_nesting_level.into();
         self
     }
-    pub fn with_rotation(mut self, r#rotation: impl Into<String>) -> Self {
+    pub fn with_rotation(mut self, r#rotation: impl Into<crate::expression::Expression>) -> Self {
         self.r#rotation = r#rotation.into();
         self
     }
-    pub fn set_rotation(&mut self, r#rotation: impl Into<String>) -> &mut Self {
+    pub fn set_rotation(
+        &mut self,
+        r#rotation: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#rotation = r#rotation.into();
         self
     }
-    pub fn with_offset_x(mut self, r#offset_x: impl Into<String>) -> Self {
+    pub fn with_offset_x(mut self, r#offset_x: impl Into<crate::expression::Expression>) -> Self {
         self.r#offset_x = r#offset_x.into();
         self
     }
-    pub fn set_offset_x(&mut self, r#offset_x: impl Into<String>) -> &mut Self {
+    pub fn set_offset_x(
+        &mut self,
+        r#offset_x: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#offset_x = r#offset_x.into();
         self
     }
-    pub fn with_offset_y(mut self, r#offset_y: impl Into<String>) -> Self {
+    pub fn with_offset_y(mut self, r#offset_y: impl Into<crate::expression::Expression>) -> Self {
         self.r#offset_y = r#offset_y.into();
         self
     }
-    pub fn set_offset_y(&mut self, r#offset_y: impl Into<String>) -> &mut Self {
+    pub fn set_offset_y(
+        &mut self,
+        r#offset_y: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#offset_y = r#offset_y.into();
         self
     }
 }
 impl DatabaseItem for BulletTriggerSpawnBullet {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
+        {
+            let mut ctx = ctx.enter("color");
+            if !crate::color::Color::is_valid(&self.r#color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#color));
+            }
+        }
         {
             let mut ctx = ctx.enter("quantity");
             if self.r#quantity < (0f32 as i32) {
@@ -21442,6 +32278,78 @@ impl DatabaseItem for BulletTriggerSpawnBullet {
         "BulletTriggerSpawnBullet"
     }
 }
+impl VisitIds for BulletTriggerSpawnBullet {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#ammunition.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BulletTriggerSpawnBullet {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#audio_clip.is_empty() {
+            visitor(AssetKind::AudioClip, &self.r#audio_clip);
+        }
+    }
+}
+impl DynamicFields for BulletTriggerSpawnBullet {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "condition",
+            "audio_clip",
+            "ammunition",
+            "color",
+            "color_mode",
+            "quantity",
+            "size",
+            "cooldown",
+            "random_factor",
+            "power_multiplier",
+            "max_nesting_level",
+            "rotation",
+            "offset_x",
+            "offset_y",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "condition" => Some(FieldValue::of(&self.r#condition)),
+            "audio_clip" => Some(FieldValue::of(&self.r#audio_clip)),
+            "ammunition" => Some(FieldValue::of(&self.r#ammunition)),
+            "color" => Some(FieldValue::of(&self.r#color)),
+            "color_mode" => Some(FieldValue::of(&self.r#color_mode)),
+            "quantity" => Some(FieldValue::of(&self.r#quantity)),
+            "size" => Some(FieldValue::of(&self.r#size)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            "random_factor" => Some(FieldValue::of(&self.r#random_factor)),
+            "power_multiplier" => Some(FieldValue::of(&self.r#power_multiplier)),
+            "max_nesting_level" => Some(FieldValue::of(&self.r#max_nesting_level)),
+            "rotation" => Some(FieldValue::of(&self.r#rotation)),
+            "offset_x" => Some(FieldValue::of(&self.r#offset_x)),
+            "offset_y" => Some(FieldValue::of(&self.r#offset_y)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "condition" => value.write_to(&mut self.r#condition, "condition"),
+            "audio_clip" => value.write_to(&mut self.r#audio_clip, "audio_clip"),
+            "ammunition" => value.write_to(&mut self.r#ammunition, "ammunition"),
+            "color" => value.write_to(&mut self.r#color, "color"),
+            "color_mode" => value.write_to(&mut self.r#color_mode, "color_mode"),
+            "quantity" => value.write_to(&mut self.r#quantity, "quantity"),
+            "size" => value.write_to(&mut self.r#size, "size"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            "random_factor" => value.write_to(&mut self.r#random_factor, "random_factor"),
+            "power_multiplier" => value.write_to(&mut self.r#power_multiplier, "power_multiplier"),
+            "max_nesting_level" => {
+                value.write_to(&mut self.r#max_nesting_level, "max_nesting_level")
+            }
+            "rotation" => value.write_to(&mut self.r#rotation, "rotation"),
+            "offset_x" => value.write_to(&mut self.r#offset_x, "offset_x"),
+            "offset_y" => value.write_to(&mut self.r#offset_y, "offset_y"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BulletTriggerSpawnBullet {}
 impl std::cmp::PartialEq for BulletTriggerSpawnBullet {
     fn eq(&self, other: &Self) -> bool {
@@ -21507,6 +32415,9 @@ impl BulletTrigger {
 pub struct BulletTriggerDetonate {
     #[serde(default)]
     pub r#condition: BulletTriggerCondition,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#cooldown: f32,
@@ -21551,6 +32462,31 @@ impl DatabaseItem for BulletTriggerDetonate {
         "BulletTriggerDetonate"
     }
 }
+impl VisitIds for BulletTriggerDetonate {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BulletTriggerDetonate {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BulletTriggerDetonate {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["condition", "cooldown"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "condition" => Some(FieldValue::of(&self.r#condition)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "condition" => value.write_to(&mut self.r#condition, "condition"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BulletTriggerDetonate {}
 impl std::cmp::PartialEq for BulletTriggerDetonate {
     fn eq(&self, other: &Self) -> bool {
@@ -21595,20 +32531,31 @@ pub struct BulletTriggerSpawnStaticSfx {
     pub r#visual_effect: Option<VisualEffectId>,
     #[serde(default)]
     pub r#audio_clip: String,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#color: String,
     #[serde(default)]
     pub r#color_mode: ColorMode,
+    /// - Minimum: `0`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#size: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#lifetime: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#cooldown: f32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#once_per_collision: bool,
@@ -21649,6 +32596,10 @@ impl BulletTriggerSpawnStaticSfx {
         self.r#visual_effect = r#visual_effect.into();
         self
     }
+    pub fn clear_visual_effect(&mut self) -> &mut Self {
+        self.r#visual_effect = None;
+        self
+    }
     pub fn with_audio_clip(mut self, r#audio_clip: impl Into<String>) -> Self {
         self.r#audio_clip = r#audio_clip.into();
         self
@@ -21708,6 +32659,12 @@ impl BulletTriggerSpawnStaticSfx {
 }
 impl DatabaseItem for BulletTriggerSpawnStaticSfx {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
+        {
+            let mut ctx = ctx.enter("color");
+            if !crate::color::Color::is_valid(&self.r#color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#color));
+            }
+        }
         {
             let mut ctx = ctx.enter("size");
             if self.r#size < 0_f32 {
@@ -21740,6 +32697,63 @@ impl DatabaseItem for BulletTriggerSpawnStaticSfx {
         "BulletTriggerSpawnStaticSfx"
     }
 }
+impl VisitIds for BulletTriggerSpawnStaticSfx {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#visual_effect.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BulletTriggerSpawnStaticSfx {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#audio_clip.is_empty() {
+            visitor(AssetKind::AudioClip, &self.r#audio_clip);
+        }
+    }
+}
+impl DynamicFields for BulletTriggerSpawnStaticSfx {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "condition",
+            "visual_effect",
+            "audio_clip",
+            "color",
+            "color_mode",
+            "size",
+            "lifetime",
+            "cooldown",
+            "once_per_collision",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "condition" => Some(FieldValue::of(&self.r#condition)),
+            "visual_effect" => Some(FieldValue::of(&self.r#visual_effect)),
+            "audio_clip" => Some(FieldValue::of(&self.r#audio_clip)),
+            "color" => Some(FieldValue::of(&self.r#color)),
+            "color_mode" => Some(FieldValue::of(&self.r#color_mode)),
+            "size" => Some(FieldValue::of(&self.r#size)),
+            "lifetime" => Some(FieldValue::of(&self.r#lifetime)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            "once_per_collision" => Some(FieldValue::of(&self.r#once_per_collision)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "condition" => value.write_to(&mut self.r#condition, "condition"),
+            "visual_effect" => value.write_to(&mut self.r#visual_effect, "visual_effect"),
+            "audio_clip" => value.write_to(&mut self.r#audio_clip, "audio_clip"),
+            "color" => value.write_to(&mut self.r#color, "color"),
+            "color_mode" => value.write_to(&mut self.r#color_mode, "color_mode"),
+            "size" => value.write_to(&mut self.r#size, "size"),
+            "lifetime" => value.write_to(&mut self.r#lifetime, "lifetime"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            "once_per_collision" => {
+                value.write_to(&mut self.r#once_per_collision, "once_per_collision")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BulletTriggerSpawnStaticSfx {}
 impl std::cmp::PartialEq for BulletTriggerSpawnStaticSfx {
     fn eq(&self, other: &Self) -> bool {
@@ -21794,12 +32808,20 @@ impl BulletTrigger {
 pub struct BulletTriggerGravityField {
     #[serde(default)]
     pub r#condition: BulletTriggerCondition,
+    /// - Minimum: `0`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#size: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#cooldown: f32,
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#power_multiplier: f32,
@@ -21877,6 +32899,35 @@ impl DatabaseItem for BulletTriggerGravityField {
         "BulletTriggerGravityField"
     }
 }
+impl VisitIds for BulletTriggerGravityField {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BulletTriggerGravityField {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for BulletTriggerGravityField {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["condition", "size", "cooldown", "power_multiplier"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "condition" => Some(FieldValue::of(&self.r#condition)),
+            "size" => Some(FieldValue::of(&self.r#size)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            "power_multiplier" => Some(FieldValue::of(&self.r#power_multiplier)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "condition" => value.write_to(&mut self.r#condition, "condition"),
+            "size" => value.write_to(&mut self.r#size, "size"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            "power_multiplier" => value.write_to(&mut self.r#power_multiplier, "power_multiplier"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BulletTriggerGravityField {}
 impl std::cmp::PartialEq for BulletTriggerGravityField {
     fn eq(&self, other: &Self) -> bool {
@@ -22156,6 +33207,62 @@ impl DatabaseItem for BulletTrigger {
         "BulletTrigger"
     }
 }
+impl VisitIds for BulletTrigger {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        match self {
+            Self::None(x) => x.visit_ids(visitor),
+            Self::PlaySfx(x) => x.visit_ids(visitor),
+            Self::SpawnBullet(x) => x.visit_ids(visitor),
+            Self::Detonate(x) => x.visit_ids(visitor),
+            Self::SpawnStaticSfx(x) => x.visit_ids(visitor),
+            Self::GravityField(x) => x.visit_ids(visitor),
+        }
+    }
+}
+impl VisitAssets for BulletTrigger {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        match self {
+            Self::None(x) => x.visit_assets(visitor),
+            Self::PlaySfx(x) => x.visit_assets(visitor),
+            Self::SpawnBullet(x) => x.visit_assets(visitor),
+            Self::Detonate(x) => x.visit_assets(visitor),
+            Self::SpawnStaticSfx(x) => x.visit_assets(visitor),
+            Self::GravityField(x) => x.visit_assets(visitor),
+        }
+    }
+}
+impl DynamicFields for BulletTrigger {
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::None(x) => x.field_names(),
+            Self::PlaySfx(x) => x.field_names(),
+            Self::SpawnBullet(x) => x.field_names(),
+            Self::Detonate(x) => x.field_names(),
+            Self::SpawnStaticSfx(x) => x.field_names(),
+            Self::GravityField(x) => x.field_names(),
+        }
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match self {
+            Self::None(x) => x.get_field(name),
+            Self::PlaySfx(x) => x.get_field(name),
+            Self::SpawnBullet(x) => x.get_field(name),
+            Self::Detonate(x) => x.get_field(name),
+            Self::SpawnStaticSfx(x) => x.get_field(name),
+            Self::GravityField(x) => x.get_field(name),
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match self {
+            Self::None(x) => x.set_field(name, value),
+            Self::PlaySfx(x) => x.set_field(name, value),
+            Self::SpawnBullet(x) => x.set_field(name, value),
+            Self::Detonate(x) => x.set_field(name, value),
+            Self::SpawnStaticSfx(x) => x.set_field(name, value),
+            Self::GravityField(x) => x.set_field(name, value),
+        }
+    }
+}
 impl BulletTrigger {
     pub fn inner_type_name(&self) -> &'static str {
         match self {
@@ -22168,6 +33275,20 @@ impl BulletTrigger {
         }
     }
 }
+/// Runs the same code for every variant of [BulletTrigger], binding the inner item to the given pattern
+#[macro_export]
+macro_rules! match_bullet_trigger {
+    ($value:expr, |$inner:pat_param| $body:expr) => {
+        match $value {
+            $crate::schema::BulletTrigger::None($inner) => $body,
+            $crate::schema::BulletTrigger::PlaySfx($inner) => $body,
+            $crate::schema::BulletTrigger::SpawnBullet($inner) => $body,
+            $crate::schema::BulletTrigger::Detonate($inner) => $body,
+            $crate::schema::BulletTrigger::SpawnStaticSfx($inner) => $body,
+            $crate::schema::BulletTrigger::GravityField($inner) => $body,
+        }
+    };
+}
 
 // v1/Objects/Weapon/ImpactEffect.xml
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -22177,9 +33298,15 @@ pub struct ImpactEffect {
     pub r#type: ImpactEffectType,
     #[serde(default)]
     pub r#damage_type: DamageType,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#power: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#factor: f32,
@@ -22251,6 +33378,35 @@ impl DatabaseItem for ImpactEffect {
         "ImpactEffect"
     }
 }
+impl VisitIds for ImpactEffect {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for ImpactEffect {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for ImpactEffect {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["type", "damage_type", "power", "factor"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "type" => Some(FieldValue::of(&self.r#type)),
+            "damage_type" => Some(FieldValue::of(&self.r#damage_type)),
+            "power" => Some(FieldValue::of(&self.r#power)),
+            "factor" => Some(FieldValue::of(&self.r#factor)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "type" => value.write_to(&mut self.r#type, "type"),
+            "damage_type" => value.write_to(&mut self.r#damage_type, "damage_type"),
+            "power" => value.write_to(&mut self.r#power, "power"),
+            "factor" => value.write_to(&mut self.r#factor, "factor"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for ImpactEffect {}
 impl std::cmp::PartialEq for ImpactEffect {
     fn eq(&self, other: &Self) -> bool {
@@ -22286,42 +33442,70 @@ pub struct VisualEffectElement {
     pub r#image: String,
     #[serde(default)]
     pub r#color_mode: ColorMode,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#color: String,
+    /// - Minimum: `1`
+    /// - Maximum: `100`
+    /// - Default: `1`
     #[serde(default = "default_1i32")]
     #[serde(skip_serializing_if = "skip_if_1i32")]
     pub r#quantity: i32,
+    /// - Minimum: `0.001`
+    /// - Maximum: `100`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#size: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#growth_rate: f32,
+    /// - Minimum: `-1000`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#turn_rate: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#start_time: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#lifetime: f32,
+    /// - Minimum: `0.001`
+    /// - Maximum: `100`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#particle_size: f32,
     #[serde(default)]
     #[serde(with = "crate::helpers::glam_ser")]
     pub r#offset: glam::f32::Vec2,
+    /// - Minimum: `0`
+    /// - Maximum: `360`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#rotation: f32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#loop: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#inverse: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#use_real_time: bool,
@@ -22478,6 +33662,12 @@ impl VisualEffectElement {
 }
 impl DatabaseItem for VisualEffectElement {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
+        {
+            let mut ctx = ctx.enter("color");
+            if !crate::color::Color::is_valid(&self.r#color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#color));
+            }
+        }
         {
             let mut ctx = ctx.enter("quantity");
             if self.r#quantity < (1f32 as i32) {
@@ -22555,6 +33745,80 @@ impl DatabaseItem for VisualEffectElement {
         "VisualEffectElement"
     }
 }
+impl VisitIds for VisualEffectElement {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for VisualEffectElement {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#image.is_empty() {
+            visitor(AssetKind::Image, &self.r#image);
+        }
+    }
+}
+impl DynamicFields for VisualEffectElement {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "type",
+            "image",
+            "color_mode",
+            "color",
+            "quantity",
+            "size",
+            "growth_rate",
+            "turn_rate",
+            "start_time",
+            "lifetime",
+            "particle_size",
+            "offset",
+            "rotation",
+            "loop",
+            "inverse",
+            "use_real_time",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "type" => Some(FieldValue::of(&self.r#type)),
+            "image" => Some(FieldValue::of(&self.r#image)),
+            "color_mode" => Some(FieldValue::of(&self.r#color_mode)),
+            "color" => Some(FieldValue::of(&self.r#color)),
+            "quantity" => Some(FieldValue::of(&self.r#quantity)),
+            "size" => Some(FieldValue::of(&self.r#size)),
+            "growth_rate" => Some(FieldValue::of(&self.r#growth_rate)),
+            "turn_rate" => Some(FieldValue::of(&self.r#turn_rate)),
+            "start_time" => Some(FieldValue::of(&self.r#start_time)),
+            "lifetime" => Some(FieldValue::of(&self.r#lifetime)),
+            "particle_size" => Some(FieldValue::of(&self.r#particle_size)),
+            "offset" => Some(FieldValue::of(&self.r#offset)),
+            "rotation" => Some(FieldValue::of(&self.r#rotation)),
+            "loop" => Some(FieldValue::of(&self.r#loop)),
+            "inverse" => Some(FieldValue::of(&self.r#inverse)),
+            "use_real_time" => Some(FieldValue::of(&self.r#use_real_time)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "type" => value.write_to(&mut self.r#type, "type"),
+            "image" => value.write_to(&mut self.r#image, "image"),
+            "color_mode" => value.write_to(&mut self.r#color_mode, "color_mode"),
+            "color" => value.write_to(&mut self.r#color, "color"),
+            "quantity" => value.write_to(&mut self.r#quantity, "quantity"),
+            "size" => value.write_to(&mut self.r#size, "size"),
+            "growth_rate" => value.write_to(&mut self.r#growth_rate, "growth_rate"),
+            "turn_rate" => value.write_to(&mut self.r#turn_rate, "turn_rate"),
+            "start_time" => value.write_to(&mut self.r#start_time, "start_time"),
+            "lifetime" => value.write_to(&mut self.r#lifetime, "lifetime"),
+            "particle_size" => value.write_to(&mut self.r#particle_size, "particle_size"),
+            "offset" => value.write_to(&mut self.r#offset, "offset"),
+            "rotation" => value.write_to(&mut self.r#rotation, "rotation"),
+            "loop" => value.write_to(&mut self.r#loop, "loop"),
+            "inverse" => value.write_to(&mut self.r#inverse, "inverse"),
+            "use_real_time" => value.write_to(&mut self.r#use_real_time, "use_real_time"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for VisualEffectElement {}
 impl std::cmp::PartialEq for VisualEffectElement {
     fn eq(&self, other: &Self) -> bool {
@@ -22658,6 +33922,10 @@ impl CombatSettings {
         self.r#enemy_ai = r#enemy_ai.into();
         self
     }
+    pub fn clear_enemy_ai(&mut self) -> &mut Self {
+        self.r#enemy_ai = None;
+        self
+    }
     pub fn with_autopilot_ai(mut self, r#autopilot_ai: impl Into<Option<BehaviorTreeId>>) -> Self {
         self.r#autopilot_ai = r#autopilot_ai.into();
         self
@@ -22669,6 +33937,10 @@ impl CombatSettings {
         self.r#autopilot_ai = r#autopilot_ai.into();
         self
     }
+    pub fn clear_autopilot_ai(&mut self) -> &mut Self {
+        self.r#autopilot_ai = None;
+        self
+    }
     pub fn with_clone_ai(mut self, r#clone_ai: impl Into<Option<BehaviorTreeId>>) -> Self {
         self.r#clone_ai = r#clone_ai.into();
         self
@@ -22677,6 +33949,10 @@ impl CombatSettings {
         self.r#clone_ai = r#clone_ai.into();
         self
     }
+    pub fn clear_clone_ai(&mut self) -> &mut Self {
+        self.r#clone_ai = None;
+        self
+    }
     pub fn with_defensive_drone_ai(
         mut self,
         r#defensive_drone_ai: impl Into<Option<BehaviorTreeId>>,
@@ -22691,6 +33967,10 @@ impl CombatSettings {
         self.r#defensive_drone_ai = r#defensive_drone_ai.into();
         self
     }
+    pub fn clear_defensive_drone_ai(&mut self) -> &mut Self {
+        self.r#defensive_drone_ai = None;
+        self
+    }
     pub fn with_offensive_drone_ai(
         mut self,
         r#offensive_drone_ai: impl Into<Option<BehaviorTreeId>>,
@@ -22705,6 +33985,10 @@ impl CombatSettings {
         self.r#offensive_drone_ai = r#offensive_drone_ai.into();
         self
     }
+    pub fn clear_offensive_drone_ai(&mut self) -> &mut Self {
+        self.r#offensive_drone_ai = None;
+        self
+    }
     pub fn with_starbase_ai(mut self, r#starbase_ai: impl Into<Option<BehaviorTreeId>>) -> Self {
         self.r#starbase_ai = r#starbase_ai.into();
         self
@@ -22716,6 +34000,10 @@ impl CombatSettings {
         self.r#starbase_ai = r#starbase_ai.into();
         self
     }
+    pub fn clear_starbase_ai(&mut self) -> &mut Self {
+        self.r#starbase_ai = None;
+        self
+    }
     pub fn with_default_combat_rules(
         mut self,
         r#default_combat_rules: impl Into<Option<CombatRulesId>>,
@@ -22730,6 +34018,10 @@ impl CombatSettings {
         self.r#default_combat_rules = r#default_combat_rules.into();
         self
     }
+    pub fn clear_default_combat_rules(&mut self) -> &mut Self {
+        self.r#default_combat_rules = None;
+        self
+    }
 }
 impl DatabaseItem for CombatSettings {
     fn validate(&self, ctx: DiagnosticContextRef) {}
@@ -22737,6 +34029,63 @@ impl DatabaseItem for CombatSettings {
         "CombatSettings"
     }
 }
+impl VisitIds for CombatSettings {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#enemy_ai.visit_ids(visitor);
+        self.r#autopilot_ai.visit_ids(visitor);
+        self.r#clone_ai.visit_ids(visitor);
+        self.r#defensive_drone_ai.visit_ids(visitor);
+        self.r#offensive_drone_ai.visit_ids(visitor);
+        self.r#starbase_ai.visit_ids(visitor);
+        self.r#default_combat_rules.visit_ids(visitor);
+    }
+}
+impl VisitAssets for CombatSettings {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for CombatSettings {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "enemy_ai",
+            "autopilot_ai",
+            "clone_ai",
+            "defensive_drone_ai",
+            "offensive_drone_ai",
+            "starbase_ai",
+            "default_combat_rules",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "enemy_ai" => Some(FieldValue::of(&self.r#enemy_ai)),
+            "autopilot_ai" => Some(FieldValue::of(&self.r#autopilot_ai)),
+            "clone_ai" => Some(FieldValue::of(&self.r#clone_ai)),
+            "defensive_drone_ai" => Some(FieldValue::of(&self.r#defensive_drone_ai)),
+            "offensive_drone_ai" => Some(FieldValue::of(&self.r#offensive_drone_ai)),
+            "starbase_ai" => Some(FieldValue::of(&self.r#starbase_ai)),
+            "default_combat_rules" => Some(FieldValue::of(&self.r#default_combat_rules)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "enemy_ai" => value.write_to(&mut self.r#enemy_ai, "enemy_ai"),
+            "autopilot_ai" => value.write_to(&mut self.r#autopilot_ai, "autopilot_ai"),
+            "clone_ai" => value.write_to(&mut self.r#clone_ai, "clone_ai"),
+            "defensive_drone_ai" => {
+                value.write_to(&mut self.r#defensive_drone_ai, "defensive_drone_ai")
+            }
+            "offensive_drone_ai" => {
+                value.write_to(&mut self.r#offensive_drone_ai, "offensive_drone_ai")
+            }
+            "starbase_ai" => value.write_to(&mut self.r#starbase_ai, "starbase_ai"),
+            "default_combat_rules" => {
+                value.write_to(&mut self.r#default_combat_rules, "default_combat_rules")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for CombatSettings {
     fn default() -> Self {
         Self::new()
@@ -22747,9 +34096,13 @@ impl Default for CombatSettings {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct DatabaseSettings {
+    /// - Minimum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#database_version: i32,
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#database_version_minor: i32,
@@ -22757,9 +34110,11 @@ pub struct DatabaseSettings {
     pub r#mod_name: String,
     #[serde(default)]
     pub r#mod_id: String,
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#mod_version: i32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#unload_original_database: bool,
@@ -22855,6 +34210,51 @@ impl DatabaseItem for DatabaseSettings {
         "DatabaseSettings"
     }
 }
+impl VisitIds for DatabaseSettings {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for DatabaseSettings {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for DatabaseSettings {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "database_version",
+            "database_version_minor",
+            "mod_name",
+            "mod_id",
+            "mod_version",
+            "unload_original_database",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "database_version" => Some(FieldValue::of(&self.r#database_version)),
+            "database_version_minor" => Some(FieldValue::of(&self.r#database_version_minor)),
+            "mod_name" => Some(FieldValue::of(&self.r#mod_name)),
+            "mod_id" => Some(FieldValue::of(&self.r#mod_id)),
+            "mod_version" => Some(FieldValue::of(&self.r#mod_version)),
+            "unload_original_database" => Some(FieldValue::of(&self.r#unload_original_database)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "database_version" => value.write_to(&mut self.r#database_version, "database_version"),
+            "database_version_minor" => {
+                value.write_to(&mut self.r#database_version_minor, "database_version_minor")
+            }
+            "mod_name" => value.write_to(&mut self.r#mod_name, "mod_name"),
+            "mod_id" => value.write_to(&mut self.r#mod_id, "mod_id"),
+            "mod_version" => value.write_to(&mut self.r#mod_version, "mod_version"),
+            "unload_original_database" => value.write_to(
+                &mut self.r#unload_original_database,
+                "unload_original_database",
+            ),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for DatabaseSettings {
     fn default() -> Self {
         Self::new()
@@ -22867,6 +34267,7 @@ impl Default for DatabaseSettings {
 pub struct DebugSettings {
     #[serde(default)]
     pub r#codes: Vec<DebugCode>,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#enable_debug_console: bool,
@@ -22912,6 +34313,37 @@ impl DatabaseItem for DebugSettings {
         "DebugSettings"
     }
 }
+impl VisitIds for DebugSettings {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#codes.visit_ids(visitor);
+    }
+}
+impl VisitAssets for DebugSettings {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#codes.visit_assets(visitor);
+    }
+}
+impl DynamicFields for DebugSettings {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["codes", "enable_debug_console"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "codes" => Some(FieldValue::of(&self.r#codes)),
+            "enable_debug_console" => Some(FieldValue::of(&self.r#enable_debug_console)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "codes" => value.write_to(&mut self.r#codes, "codes"),
+            "enable_debug_console" => {
+                value.write_to(&mut self.r#enable_debug_console, "enable_debug_console")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for DebugSettings {
     fn default() -> Self {
         Self::new()
@@ -22934,11 +34366,12 @@ pub struct ExplorationSettings {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#hive_ship_build: Option<ShipBuildId>,
+    /// - Default: `"MIN(level*2,500)"`
     #[serde(default = "default_ඞquoteඞMINඞlparenඞlevelඞstarඞ2ඞcommaඞ500ඞrparenඞඞquoteඞ")]
     #[serde(
         skip_serializing_if = "skip_if_ඞquoteඞMINඞlparenඞlevelඞstarඞ2ඞcommaඞ500ඞrparenඞඞquoteඞ"
     )]
-    pub r#gas_cloud_dps: String,
+    pub r#gas_cloud_dps: crate::expression::Expression,
 }
 impl ExplorationSettings {
     pub fn new() -> Self {
@@ -22958,6 +34391,10 @@ impl ExplorationSettings {
         self.r#outpost_ship = r#outpost_ship.into();
         self
     }
+    pub fn clear_outpost_ship(&mut self) -> &mut Self {
+        self.r#outpost_ship = None;
+        self
+    }
     pub fn with_turret_ship(mut self, r#turret_ship: impl Into<Option<ShipId>>) -> Self {
         self.r#turret_ship = r#turret_ship.into();
         self
@@ -22966,6 +34403,10 @@ impl ExplorationSettings {
         self.r#turret_ship = r#turret_ship.into();
         self
     }
+    pub fn clear_turret_ship(&mut self) -> &mut Self {
+        self.r#turret_ship = None;
+        self
+    }
     pub fn with_infected_planet_faction(
         mut self,
         r#infected_planet_faction: impl Into<Option<FactionId>>,
@@ -22980,6 +34421,10 @@ impl ExplorationSettings {
         self.r#infected_planet_faction = r#infected_planet_faction.into();
         self
     }
+    pub fn clear_infected_planet_faction(&mut self) -> &mut Self {
+        self.r#infected_planet_faction = None;
+        self
+    }
     pub fn with_hive_ship_build(
         mut self,
         r#hive_ship_build: impl Into<Option<ShipBuildId>>,
@@ -22994,11 +34439,21 @@ impl ExplorationSettings {
         self.r#hive_ship_build = r#hive_ship_build.into();
         self
     }
-    pub fn with_gas_cloud_dps(mut self, r#gas_cloud_dps: impl Into<String>) -> Self {
+    pub fn clear_hive_ship_build(&mut self) -> &mut Self {
+        self.r#hive_ship_build = None;
+        self
+    }
+    pub fn with_gas_cloud_dps(
+        mut self,
+        r#gas_cloud_dps: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#gas_cloud_dps = r#gas_cloud_dps.into();
         self
     }
-    pub fn set_gas_cloud_dps(&mut self, r#gas_cloud_dps: impl Into<String>) -> &mut Self {
+    pub fn set_gas_cloud_dps(
+        &mut self,
+        r#gas_cloud_dps: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#gas_cloud_dps = r#gas_cloud_dps.into();
         self
     }
@@ -23009,6 +34464,51 @@ impl DatabaseItem for ExplorationSettings {
         "ExplorationSettings"
     }
 }
+impl VisitIds for ExplorationSettings {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#outpost_ship.visit_ids(visitor);
+        self.r#turret_ship.visit_ids(visitor);
+        self.r#infected_planet_faction.visit_ids(visitor);
+        self.r#hive_ship_build.visit_ids(visitor);
+    }
+}
+impl VisitAssets for ExplorationSettings {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for ExplorationSettings {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "outpost_ship",
+            "turret_ship",
+            "infected_planet_faction",
+            "hive_ship_build",
+            "gas_cloud_dps",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "outpost_ship" => Some(FieldValue::of(&self.r#outpost_ship)),
+            "turret_ship" => Some(FieldValue::of(&self.r#turret_ship)),
+            "infected_planet_faction" => Some(FieldValue::of(&self.r#infected_planet_faction)),
+            "hive_ship_build" => Some(FieldValue::of(&self.r#hive_ship_build)),
+            "gas_cloud_dps" => Some(FieldValue::of(&self.r#gas_cloud_dps)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "outpost_ship" => value.write_to(&mut self.r#outpost_ship, "outpost_ship"),
+            "turret_ship" => value.write_to(&mut self.r#turret_ship, "turret_ship"),
+            "infected_planet_faction" => value.write_to(
+                &mut self.r#infected_planet_faction,
+                "infected_planet_faction",
+            ),
+            "hive_ship_build" => value.write_to(&mut self.r#hive_ship_build, "hive_ship_build"),
+            "gas_cloud_dps" => value.write_to(&mut self.r#gas_cloud_dps, "gas_cloud_dps"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for ExplorationSettings {
     fn default() -> Self {
         Self::new()
@@ -23019,16 +34519,21 @@ impl Default for ExplorationSettings {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct FactionsSettings {
+    /// - Default: `"MIN(1000, 300 + 5*distance)"`
     #[serde(
         default = "default_ඞquoteඞMINඞlparenඞ1000ඞcommaඞඞspaceඞ300ඞspaceඞඞplusඞඞspaceඞ5ඞstarඞdistanceඞrparenඞඞquoteඞ"
     )]
     #[serde(
         skip_serializing_if = "skip_if_ඞquoteඞMINඞlparenඞ1000ඞcommaඞඞspaceඞ300ඞspaceඞඞplusඞඞspaceඞ5ඞstarඞdistanceඞrparenඞඞquoteඞ"
     )]
-    pub r#starbase_initial_defense: String,
+    pub r#starbase_initial_defense: crate::expression::Expression,
+    /// - Minimum: `1`
+    /// - Default: `50`
     #[serde(default = "default_50i32")]
     #[serde(skip_serializing_if = "skip_if_50i32")]
     pub r#starbase_min_defense: i32,
+    /// - Minimum: `0`
+    /// - Default: `10`
     #[serde(default = "default_10i32")]
     #[serde(skip_serializing_if = "skip_if_10i32")]
     pub r#defense_loss_per_enemy_defeated: i32,
@@ -23043,14 +34548,14 @@ impl FactionsSettings {
     }
     pub fn with_starbase_initial_defense(
         mut self,
-        r#starbase_initial_defense: impl Into<String>,
+        r#starbase_initial_defense: impl Into<crate::expression::Expression>,
     ) -> Self {
         self.r#starbase_initial_defense = r#starbase_initial_defense.into();
         self
     }
     pub fn set_starbase_initial_defense(
         &mut self,
-        r#starbase_initial_defense: impl Into<String>,
+        r#starbase_initial_defense: impl Into<crate::expression::Expression>,
     ) -> &mut Self {
         self.r#starbase_initial_defense = r#starbase_initial_defense.into();
         self
@@ -23103,6 +34608,47 @@ impl DatabaseItem for FactionsSettings {
         "FactionsSettings"
     }
 }
+impl VisitIds for FactionsSettings {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for FactionsSettings {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for FactionsSettings {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "starbase_initial_defense",
+            "starbase_min_defense",
+            "defense_loss_per_enemy_defeated",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "starbase_initial_defense" => Some(FieldValue::of(&self.r#starbase_initial_defense)),
+            "starbase_min_defense" => Some(FieldValue::of(&self.r#starbase_min_defense)),
+            "defense_loss_per_enemy_defeated" => {
+                Some(FieldValue::of(&self.r#defense_loss_per_enemy_defeated))
+            }
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "starbase_initial_defense" => value.write_to(
+                &mut self.r#starbase_initial_defense,
+                "starbase_initial_defense",
+            ),
+            "starbase_min_defense" => {
+                value.write_to(&mut self.r#starbase_min_defense, "starbase_min_defense")
+            }
+            "defense_loss_per_enemy_defeated" => value.write_to(
+                &mut self.r#defense_loss_per_enemy_defeated,
+                "defense_loss_per_enemy_defeated",
+            ),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for FactionsSettings {
     fn default() -> Self {
         Self::new()
@@ -23127,26 +34673,32 @@ pub struct GalaxySettings {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#default_starbase_build: Option<ShipBuildId>,
+    /// - Minimum: `0`
+    /// - Maximum: `500`
+    /// - Default: `300`
     #[serde(default = "default_300i32")]
     #[serde(skip_serializing_if = "skip_if_300i32")]
     pub r#max_enemy_ships_level: i32,
+    /// - Default: `"MIN(3*distance/5 - 5, MaxEnemyShipsLevel)"`
     #[serde(
         default = "default_ඞquoteඞMINඞlparenඞ3ඞstarඞdistanceඞslashඞ5ඞspaceඞඞdashඞඞspaceඞ5ඞcommaඞඞspaceඞMaxEnemyShipsLevelඞrparenඞඞquoteඞ"
     )]
     #[serde(
         skip_serializing_if = "skip_if_ඞquoteඞMINඞlparenඞ3ඞstarඞdistanceඞslashඞ5ඞspaceඞඞdashඞඞspaceඞ5ඞcommaඞඞspaceඞMaxEnemyShipsLevelඞrparenඞඞquoteඞ"
     )]
-    pub r#enemy_level: String,
+    pub r#enemy_level: crate::expression::Expression,
+    /// - Default: `"IF(size == Destroyer, 5, size == Cruiser, 15, size == Battleship, 50, size == Titan, 100, 0)"`
     #[serde(
         default = "default_ඞquoteඞIFඞlparenඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞDestroyerඞcommaඞඞspaceඞ5ඞcommaඞඞspaceඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞCruiserඞcommaඞඞspaceඞ15ඞcommaඞඞspaceඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞBattleshipඞcommaඞඞspaceඞ50ඞcommaඞඞspaceඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞTitanඞcommaඞඞspaceඞ100ඞcommaඞඞspaceඞ0ඞrparenඞඞquoteඞ"
     )]
     #[serde(
         skip_serializing_if = "skip_if_ඞquoteඞIFඞlparenඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞDestroyerඞcommaඞඞspaceඞ5ඞcommaඞඞspaceඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞCruiserඞcommaඞඞspaceඞ15ඞcommaඞඞspaceඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞBattleshipඞcommaඞඞspaceඞ50ඞcommaඞඞspaceඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞTitanඞcommaඞඞspaceඞ100ඞcommaඞඞspaceඞ0ඞrparenඞඞquoteඞ"
     )]
-    pub r#ship_min_spawn_distance: String,
+    pub r#ship_min_spawn_distance: crate::expression::Expression,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#capture_starbase_quest: Option<QuestId>,
+    /// - Options: `obsolete`
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#starting_invenory: Option<LootId>,
@@ -23205,6 +34757,10 @@ impl GalaxySettings {
         self.r#abandoned_starbase_faction = r#abandoned_starbase_faction.into();
         self
     }
+    pub fn clear_abandoned_starbase_faction(&mut self) -> &mut Self {
+        self.r#abandoned_starbase_faction = None;
+        self
+    }
     pub fn with_starting_ship_builds(
         mut self,
         r#starting_ship_builds: impl Into<Vec<ShipBuildId>>,
@@ -23233,6 +34789,10 @@ impl GalaxySettings {
         self.r#starting_inventory = r#starting_inventory.into();
         self
     }
+    pub fn clear_starting_inventory(&mut self) -> &mut Self {
+        self.r#starting_inventory = None;
+        self
+    }
     pub fn with_supporter_pack_ship(
         mut self,
         r#supporter_pack_ship: impl Into<Option<ShipBuildId>>,
@@ -23247,6 +34807,10 @@ impl GalaxySettings {
         self.r#supporter_pack_ship = r#supporter_pack_ship.into();
         self
     }
+    pub fn clear_supporter_pack_ship(&mut self) -> &mut Self {
+        self.r#supporter_pack_ship = None;
+        self
+    }
     pub fn with_default_starbase_build(
         mut self,
         r#default_starbase_build: impl Into<Option<ShipBuildId>>,
@@ -23261,6 +34825,10 @@ impl GalaxySettings {
         self.r#default_starbase_build = r#default_starbase_build.into();
         self
     }
+    pub fn clear_default_starbase_build(&mut self) -> &mut Self {
+        self.r#default_starbase_build = None;
+        self
+    }
     pub fn with_max_enemy_ships_level(mut self, r#max_enemy_ships_level: impl Into<i32>) -> Self {
         self.r#max_enemy_ships_level = r#max_enemy_ships_level.into();
         self
@@ -23272,24 +34840,30 @@ impl GalaxySettings {
         self.r#max_enemy_ships_level = r#max_enemy_ships_level.into();
         self
     }
-    pub fn with_enemy_level(mut self, r#enemy_level: impl Into<String>) -> Self {
+    pub fn with_enemy_level(
+        mut self,
+        r#enemy_level: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#enemy_level = r#enemy_level.into();
         self
     }
-    pub fn set_enemy_level(&mut self, r#enemy_level: impl Into<String>) -> &mut Self {
+    pub fn set_enemy_level(
+        &mut self,
+        r#enemy_level: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#enemy_level = r#enemy_level.into();
         self
     }
     pub fn with_ship_min_spawn_distance(
         mut self,
-        r#ship_min_spawn_distance: impl Into<String>,
+        r#ship_min_spawn_distance: impl Into<crate::expression::Expression>,
     ) -> Self {
         self.r#ship_min_spawn_distance = r#ship_min_spawn_distance.into();
         self
     }
     pub fn set_ship_min_spawn_distance(
         &mut self,
-        r#ship_min_spawn_distance: impl Into<String>,
+        r#ship_min_spawn_distance: impl Into<crate::expression::Expression>,
     ) -> &mut Self {
         self.r#ship_min_spawn_distance = r#ship_min_spawn_distance.into();
         self
@@ -23308,6 +34882,10 @@ impl GalaxySettings {
         self.r#capture_starbase_quest = r#capture_starbase_quest.into();
         self
     }
+    pub fn clear_capture_starbase_quest(&mut self) -> &mut Self {
+        self.r#capture_starbase_quest = None;
+        self
+    }
     pub fn with_starting_invenory(
         mut self,
         r#starting_invenory: impl Into<Option<LootId>>,
@@ -23322,6 +34900,10 @@ impl GalaxySettings {
         self.r#starting_invenory = r#starting_invenory.into();
         self
     }
+    pub fn clear_starting_invenory(&mut self) -> &mut Self {
+        self.r#starting_invenory = None;
+        self
+    }
     pub fn with_survival_combat_rules(
         mut self,
         r#survival_combat_rules: impl Into<Option<CombatRulesId>>,
@@ -23336,6 +34918,10 @@ impl GalaxySettings {
         self.r#survival_combat_rules = r#survival_combat_rules.into();
         self
     }
+    pub fn clear_survival_combat_rules(&mut self) -> &mut Self {
+        self.r#survival_combat_rules = None;
+        self
+    }
     pub fn with_starbase_combat_rules(
         mut self,
         r#starbase_combat_rules: impl Into<Option<CombatRulesId>>,
@@ -23350,6 +34936,10 @@ impl GalaxySettings {
         self.r#starbase_combat_rules = r#starbase_combat_rules.into();
         self
     }
+    pub fn clear_starbase_combat_rules(&mut self) -> &mut Self {
+        self.r#starbase_combat_rules = None;
+        self
+    }
     pub fn with_flagship_combat_rules(
         mut self,
         r#flagship_combat_rules: impl Into<Option<CombatRulesId>>,
@@ -23364,6 +34954,10 @@ impl GalaxySettings {
         self.r#flagship_combat_rules = r#flagship_combat_rules.into();
         self
     }
+    pub fn clear_flagship_combat_rules(&mut self) -> &mut Self {
+        self.r#flagship_combat_rules = None;
+        self
+    }
     pub fn with_arena_combat_rules(
         mut self,
         r#arena_combat_rules: impl Into<Option<CombatRulesId>>,
@@ -23378,6 +34972,10 @@ impl GalaxySettings {
         self.r#arena_combat_rules = r#arena_combat_rules.into();
         self
     }
+    pub fn clear_arena_combat_rules(&mut self) -> &mut Self {
+        self.r#arena_combat_rules = None;
+        self
+    }
     pub fn with_challenge_combat_rules(
         mut self,
         r#challenge_combat_rules: impl Into<Option<CombatRulesId>>,
@@ -23392,6 +34990,10 @@ impl GalaxySettings {
         self.r#challenge_combat_rules = r#challenge_combat_rules.into();
         self
     }
+    pub fn clear_challenge_combat_rules(&mut self) -> &mut Self {
+        self.r#challenge_combat_rules = None;
+        self
+    }
     pub fn with_quick_combat_rules(
         mut self,
         r#quick_combat_rules: impl Into<Option<CombatRulesId>>,
@@ -23406,6 +35008,10 @@ impl GalaxySettings {
         self.r#quick_combat_rules = r#quick_combat_rules.into();
         self
     }
+    pub fn clear_quick_combat_rules(&mut self) -> &mut Self {
+        self.r#quick_combat_rules = None;
+        self
+    }
 }
 impl DatabaseItem for GalaxySettings {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
@@ -23436,6 +35042,124 @@ impl DatabaseItem for GalaxySettings {
         "GalaxySettings"
     }
 }
+impl VisitIds for GalaxySettings {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#abandoned_starbase_faction.visit_ids(visitor);
+        self.r#starting_ship_builds.visit_ids(visitor);
+        self.r#starting_inventory.visit_ids(visitor);
+        self.r#supporter_pack_ship.visit_ids(visitor);
+        self.r#default_starbase_build.visit_ids(visitor);
+        self.r#capture_starbase_quest.visit_ids(visitor);
+        self.r#starting_invenory.visit_ids(visitor);
+        self.r#survival_combat_rules.visit_ids(visitor);
+        self.r#starbase_combat_rules.visit_ids(visitor);
+        self.r#flagship_combat_rules.visit_ids(visitor);
+        self.r#arena_combat_rules.visit_ids(visitor);
+        self.r#challenge_combat_rules.visit_ids(visitor);
+        self.r#quick_combat_rules.visit_ids(visitor);
+    }
+}
+impl VisitAssets for GalaxySettings {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for GalaxySettings {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "abandoned_starbase_faction",
+            "starting_ship_builds",
+            "starting_inventory",
+            "supporter_pack_ship",
+            "default_starbase_build",
+            "max_enemy_ships_level",
+            "enemy_level",
+            "ship_min_spawn_distance",
+            "capture_starbase_quest",
+            "starting_invenory",
+            "survival_combat_rules",
+            "starbase_combat_rules",
+            "flagship_combat_rules",
+            "arena_combat_rules",
+            "challenge_combat_rules",
+            "quick_combat_rules",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "abandoned_starbase_faction" => {
+                Some(FieldValue::of(&self.r#abandoned_starbase_faction))
+            }
+            "starting_ship_builds" => Some(FieldValue::of(&self.r#starting_ship_builds)),
+            "starting_inventory" => Some(FieldValue::of(&self.r#starting_inventory)),
+            "supporter_pack_ship" => Some(FieldValue::of(&self.r#supporter_pack_ship)),
+            "default_starbase_build" => Some(FieldValue::of(&self.r#default_starbase_build)),
+            "max_enemy_ships_level" => Some(FieldValue::of(&self.r#max_enemy_ships_level)),
+            "enemy_level" => Some(FieldValue::of(&self.r#enemy_level)),
+            "ship_min_spawn_distance" => Some(FieldValue::of(&self.r#ship_min_spawn_distance)),
+            "capture_starbase_quest" => Some(FieldValue::of(&self.r#capture_starbase_quest)),
+            "starting_invenory" => Some(FieldValue::of(&self.r#starting_invenory)),
+            "survival_combat_rules" => Some(FieldValue::of(&self.r#survival_combat_rules)),
+            "starbase_combat_rules" => Some(FieldValue::of(&self.r#starbase_combat_rules)),
+            "flagship_combat_rules" => Some(FieldValue::of(&self.r#flagship_combat_rules)),
+            "arena_combat_rules" => Some(FieldValue::of(&self.r#arena_combat_rules)),
+            "challenge_combat_rules" => Some(FieldValue::of(&self.r#challenge_combat_rules)),
+            "quick_combat_rules" => Some(FieldValue::of(&self.r#quick_combat_rules)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "abandoned_starbase_faction" => value.write_to(
+                &mut self.r#abandoned_starbase_faction,
+                "abandoned_starbase_faction",
+            ),
+            "starting_ship_builds" => {
+                value.write_to(&mut self.r#starting_ship_builds, "starting_ship_builds")
+            }
+            "starting_inventory" => {
+                value.write_to(&mut self.r#starting_inventory, "starting_inventory")
+            }
+            "supporter_pack_ship" => {
+                value.write_to(&mut self.r#supporter_pack_ship, "supporter_pack_ship")
+            }
+            "default_starbase_build" => {
+                value.write_to(&mut self.r#default_starbase_build, "default_starbase_build")
+            }
+            "max_enemy_ships_level" => {
+                value.write_to(&mut self.r#max_enemy_ships_level, "max_enemy_ships_level")
+            }
+            "enemy_level" => value.write_to(&mut self.r#enemy_level, "enemy_level"),
+            "ship_min_spawn_distance" => value.write_to(
+                &mut self.r#ship_min_spawn_distance,
+                "ship_min_spawn_distance",
+            ),
+            "capture_starbase_quest" => {
+                value.write_to(&mut self.r#capture_starbase_quest, "capture_starbase_quest")
+            }
+            "starting_invenory" => {
+                value.write_to(&mut self.r#starting_invenory, "starting_invenory")
+            }
+            "survival_combat_rules" => {
+                value.write_to(&mut self.r#survival_combat_rules, "survival_combat_rules")
+            }
+            "starbase_combat_rules" => {
+                value.write_to(&mut self.r#starbase_combat_rules, "starbase_combat_rules")
+            }
+            "flagship_combat_rules" => {
+                value.write_to(&mut self.r#flagship_combat_rules, "flagship_combat_rules")
+            }
+            "arena_combat_rules" => {
+                value.write_to(&mut self.r#arena_combat_rules, "arena_combat_rules")
+            }
+            "challenge_combat_rules" => {
+                value.write_to(&mut self.r#challenge_combat_rules, "challenge_combat_rules")
+            }
+            "quick_combat_rules" => {
+                value.write_to(&mut self.r#quick_combat_rules, "quick_combat_rules")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for GalaxySettings {
     fn default() -> Self {
         Self::new()
@@ -23446,9 +35170,11 @@ impl Default for GalaxySettings {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct LocalizationSettings {
+    /// - Default: `"$WeaponDamage"`
     #[serde(default = "default_ඞquoteඞඞdollarඞWeaponDamageඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞdollarඞWeaponDamageඞquoteඞ")]
     pub r#corrosive_damage_text: String,
+    /// - Default: `"$WeaponDPS"`
     #[serde(default = "default_ඞquoteඞඞdollarඞWeaponDPSඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞdollarඞWeaponDPSඞquoteඞ")]
     pub r#corrosive_dps_text: String,
@@ -23489,6 +35215,35 @@ impl DatabaseItem for LocalizationSettings {
         "LocalizationSettings"
     }
 }
+impl VisitIds for LocalizationSettings {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for LocalizationSettings {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for LocalizationSettings {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["corrosive_damage_text", "corrosive_dps_text"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "corrosive_damage_text" => Some(FieldValue::of(&self.r#corrosive_damage_text)),
+            "corrosive_dps_text" => Some(FieldValue::of(&self.r#corrosive_dps_text)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "corrosive_damage_text" => {
+                value.write_to(&mut self.r#corrosive_damage_text, "corrosive_damage_text")
+            }
+            "corrosive_dps_text" => {
+                value.write_to(&mut self.r#corrosive_dps_text, "corrosive_dps_text")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for LocalizationSettings {
     fn default() -> Self {
         Self::new()
@@ -23597,6 +35352,52 @@ impl DatabaseItem for MusicPlaylist {
         "MusicPlaylist"
     }
 }
+impl VisitIds for MusicPlaylist {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#main_menu_music.visit_ids(visitor);
+        self.r#galaxy_map_music.visit_ids(visitor);
+        self.r#combat_music.visit_ids(visitor);
+        self.r#exploration_music.visit_ids(visitor);
+    }
+}
+impl VisitAssets for MusicPlaylist {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#main_menu_music.visit_assets(visitor);
+        self.r#galaxy_map_music.visit_assets(visitor);
+        self.r#combat_music.visit_assets(visitor);
+        self.r#exploration_music.visit_assets(visitor);
+    }
+}
+impl DynamicFields for MusicPlaylist {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "main_menu_music",
+            "galaxy_map_music",
+            "combat_music",
+            "exploration_music",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "main_menu_music" => Some(FieldValue::of(&self.r#main_menu_music)),
+            "galaxy_map_music" => Some(FieldValue::of(&self.r#galaxy_map_music)),
+            "combat_music" => Some(FieldValue::of(&self.r#combat_music)),
+            "exploration_music" => Some(FieldValue::of(&self.r#exploration_music)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "main_menu_music" => value.write_to(&mut self.r#main_menu_music, "main_menu_music"),
+            "galaxy_map_music" => value.write_to(&mut self.r#galaxy_map_music, "galaxy_map_music"),
+            "combat_music" => value.write_to(&mut self.r#combat_music, "combat_music"),
+            "exploration_music" => {
+                value.write_to(&mut self.r#exploration_music, "exploration_music")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for MusicPlaylist {
     fn default() -> Self {
         Self::new()
@@ -23607,45 +35408,77 @@ impl Default for MusicPlaylist {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ShipModSettings {
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#remove_weapon_slot_mod: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#remove_unlimited_respawn_mod: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#remove_energy_recharge_cd_mod: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#remove_shield_recharge_cd_mod: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#remove_bigger_satellites_mod: bool,
+    /// - Minimum: `0`
+    /// - Maximum: `10`
+    /// - Default: `0.5`
     #[serde(default = "default_0ඞdotඞ5f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ5f32")]
     pub r#heat_defense_value: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `10`
+    /// - Default: `0.5`
     #[serde(default = "default_0ඞdotඞ5f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ5f32")]
     pub r#kinetic_defense_value: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `10`
+    /// - Default: `0.5`
     #[serde(default = "default_0ඞdotඞ5f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ5f32")]
     pub r#energy_defense_value: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.01`
     #[serde(default = "default_0ඞdotඞ01f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ01f32")]
     pub r#regeneration_value: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.85`
     #[serde(default = "default_0ඞdotඞ85f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ85f32")]
     pub r#regeneration_armor: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.8`
     #[serde(default = "default_0ඞdotඞ8f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ8f32")]
     pub r#weight_reduction: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.2`
     #[serde(default = "default_0ඞdotඞ2f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ2f32")]
     pub r#attack_reduction: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.5`
     #[serde(default = "default_0ඞdotඞ5f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ5f32")]
     pub r#energy_reduction: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.5`
     #[serde(default = "default_0ඞdotඞ5f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ5f32")]
     pub r#shield_reduction: f32,
@@ -23915,6 +35748,102 @@ impl DatabaseItem for ShipModSettings {
         "ShipModSettings"
     }
 }
+impl VisitIds for ShipModSettings {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for ShipModSettings {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for ShipModSettings {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "remove_weapon_slot_mod",
+            "remove_unlimited_respawn_mod",
+            "remove_energy_recharge_cd_mod",
+            "remove_shield_recharge_cd_mod",
+            "remove_bigger_satellites_mod",
+            "heat_defense_value",
+            "kinetic_defense_value",
+            "energy_defense_value",
+            "regeneration_value",
+            "regeneration_armor",
+            "weight_reduction",
+            "attack_reduction",
+            "energy_reduction",
+            "shield_reduction",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "remove_weapon_slot_mod" => Some(FieldValue::of(&self.r#remove_weapon_slot_mod)),
+            "remove_unlimited_respawn_mod" => {
+                Some(FieldValue::of(&self.r#remove_unlimited_respawn_mod))
+            }
+            "remove_energy_recharge_cd_mod" => {
+                Some(FieldValue::of(&self.r#remove_energy_recharge_cd_mod))
+            }
+            "remove_shield_recharge_cd_mod" => {
+                Some(FieldValue::of(&self.r#remove_shield_recharge_cd_mod))
+            }
+            "remove_bigger_satellites_mod" => {
+                Some(FieldValue::of(&self.r#remove_bigger_satellites_mod))
+            }
+            "heat_defense_value" => Some(FieldValue::of(&self.r#heat_defense_value)),
+            "kinetic_defense_value" => Some(FieldValue::of(&self.r#kinetic_defense_value)),
+            "energy_defense_value" => Some(FieldValue::of(&self.r#energy_defense_value)),
+            "regeneration_value" => Some(FieldValue::of(&self.r#regeneration_value)),
+            "regeneration_armor" => Some(FieldValue::of(&self.r#regeneration_armor)),
+            "weight_reduction" => Some(FieldValue::of(&self.r#weight_reduction)),
+            "attack_reduction" => Some(FieldValue::of(&self.r#attack_reduction)),
+            "energy_reduction" => Some(FieldValue::of(&self.r#energy_reduction)),
+            "shield_reduction" => Some(FieldValue::of(&self.r#shield_reduction)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "remove_weapon_slot_mod" => {
+                value.write_to(&mut self.r#remove_weapon_slot_mod, "remove_weapon_slot_mod")
+            }
+            "remove_unlimited_respawn_mod" => value.write_to(
+                &mut self.r#remove_unlimited_respawn_mod,
+                "remove_unlimited_respawn_mod",
+            ),
+            "remove_energy_recharge_cd_mod" => value.write_to(
+                &mut self.r#remove_energy_recharge_cd_mod,
+                "remove_energy_recharge_cd_mod",
+            ),
+            "remove_shield_recharge_cd_mod" => value.write_to(
+                &mut self.r#remove_shield_recharge_cd_mod,
+                "remove_shield_recharge_cd_mod",
+            ),
+            "remove_bigger_satellites_mod" => value.write_to(
+                &mut self.r#remove_bigger_satellites_mod,
+                "remove_bigger_satellites_mod",
+            ),
+            "heat_defense_value" => {
+                value.write_to(&mut self.r#heat_defense_value, "heat_defense_value")
+            }
+            "kinetic_defense_value" => {
+                value.write_to(&mut self.r#kinetic_defense_value, "kinetic_defense_value")
+            }
+            "energy_defense_value" => {
+                value.write_to(&mut self.r#energy_defense_value, "energy_defense_value")
+            }
+            "regeneration_value" => {
+                value.write_to(&mut self.r#regeneration_value, "regeneration_value")
+            }
+            "regeneration_armor" => {
+                value.write_to(&mut self.r#regeneration_armor, "regeneration_armor")
+            }
+            "weight_reduction" => value.write_to(&mut self.r#weight_reduction, "weight_reduction"),
+            "attack_reduction" => value.write_to(&mut self.r#attack_reduction, "attack_reduction"),
+            "energy_reduction" => value.write_to(&mut self.r#energy_reduction, "energy_reduction"),
+            "shield_reduction" => value.write_to(&mut self.r#shield_reduction, "shield_reduction"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for ShipModSettings {}
 impl std::cmp::PartialEq for ShipModSettings {
     fn eq(&self, other: &Self) -> bool {
@@ -23971,54 +35900,103 @@ impl Default for ShipModSettings {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ShipSettings {
+    /// - Minimum: `1`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#default_weight_per_cell: f32,
+    /// - Minimum: `1`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#minimum_weight_per_cell: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#base_armor_points: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#armor_points_per_cell: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `60`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#armor_repair_cooldown: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#base_energy_points: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#base_energy_recharge_rate: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `60`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#energy_recharge_cooldown: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#base_shield_recharge_rate: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `60`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#shield_recharge_cooldown: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#base_drone_reconstruction_speed: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.9`
     #[serde(default = "default_0ඞdotඞ9f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ9f32")]
     pub r#shield_corrosive_resistance: f32,
+    /// - Minimum: `5`
+    /// - Maximum: `100`
+    /// - Default: `30`
     #[serde(default = "default_30f32")]
     #[serde(skip_serializing_if = "skip_if_30f32")]
     pub r#max_velocity: f32,
+    /// - Minimum: `5`
+    /// - Maximum: `100`
+    /// - Default: `30`
     #[serde(default = "default_30f32")]
     #[serde(skip_serializing_if = "skip_if_30f32")]
     pub r#max_angular_velocity: f32,
+    /// - Minimum: `5`
+    /// - Maximum: `1000`
+    /// - Default: `300`
     #[serde(default = "default_300f32")]
     #[serde(skip_serializing_if = "skip_if_300f32")]
     pub r#max_acceleration: f32,
+    /// - Minimum: `5`
+    /// - Maximum: `1000`
+    /// - Default: `300`
     #[serde(default = "default_300f32")]
     #[serde(skip_serializing_if = "skip_if_300f32")]
     pub r#max_angular_acceleration: f32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#disable_cells_expansions: bool,
@@ -24477,6 +36455,123 @@ impl DatabaseItem for ShipSettings {
         "ShipSettings"
     }
 }
+impl VisitIds for ShipSettings {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for ShipSettings {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for ShipSettings {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "default_weight_per_cell",
+            "minimum_weight_per_cell",
+            "base_armor_points",
+            "armor_points_per_cell",
+            "armor_repair_cooldown",
+            "base_energy_points",
+            "base_energy_recharge_rate",
+            "energy_recharge_cooldown",
+            "base_shield_recharge_rate",
+            "shield_recharge_cooldown",
+            "base_drone_reconstruction_speed",
+            "shield_corrosive_resistance",
+            "max_velocity",
+            "max_angular_velocity",
+            "max_acceleration",
+            "max_angular_acceleration",
+            "disable_cells_expansions",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "default_weight_per_cell" => Some(FieldValue::of(&self.r#default_weight_per_cell)),
+            "minimum_weight_per_cell" => Some(FieldValue::of(&self.r#minimum_weight_per_cell)),
+            "base_armor_points" => Some(FieldValue::of(&self.r#base_armor_points)),
+            "armor_points_per_cell" => Some(FieldValue::of(&self.r#armor_points_per_cell)),
+            "armor_repair_cooldown" => Some(FieldValue::of(&self.r#armor_repair_cooldown)),
+            "base_energy_points" => Some(FieldValue::of(&self.r#base_energy_points)),
+            "base_energy_recharge_rate" => Some(FieldValue::of(&self.r#base_energy_recharge_rate)),
+            "energy_recharge_cooldown" => Some(FieldValue::of(&self.r#energy_recharge_cooldown)),
+            "base_shield_recharge_rate" => Some(FieldValue::of(&self.r#base_shield_recharge_rate)),
+            "shield_recharge_cooldown" => Some(FieldValue::of(&self.r#shield_recharge_cooldown)),
+            "base_drone_reconstruction_speed" => {
+                Some(FieldValue::of(&self.r#base_drone_reconstruction_speed))
+            }
+            "shield_corrosive_resistance" => {
+                Some(FieldValue::of(&self.r#shield_corrosive_resistance))
+            }
+            "max_velocity" => Some(FieldValue::of(&self.r#max_velocity)),
+            "max_angular_velocity" => Some(FieldValue::of(&self.r#max_angular_velocity)),
+            "max_acceleration" => Some(FieldValue::of(&self.r#max_acceleration)),
+            "max_angular_acceleration" => Some(FieldValue::of(&self.r#max_angular_acceleration)),
+            "disable_cells_expansions" => Some(FieldValue::of(&self.r#disable_cells_expansions)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "default_weight_per_cell" => value.write_to(
+                &mut self.r#default_weight_per_cell,
+                "default_weight_per_cell",
+            ),
+            "minimum_weight_per_cell" => value.write_to(
+                &mut self.r#minimum_weight_per_cell,
+                "minimum_weight_per_cell",
+            ),
+            "base_armor_points" => {
+                value.write_to(&mut self.r#base_armor_points, "base_armor_points")
+            }
+            "armor_points_per_cell" => {
+                value.write_to(&mut self.r#armor_points_per_cell, "armor_points_per_cell")
+            }
+            "armor_repair_cooldown" => {
+                value.write_to(&mut self.r#armor_repair_cooldown, "armor_repair_cooldown")
+            }
+            "base_energy_points" => {
+                value.write_to(&mut self.r#base_energy_points, "base_energy_points")
+            }
+            "base_energy_recharge_rate" => value.write_to(
+                &mut self.r#base_energy_recharge_rate,
+                "base_energy_recharge_rate",
+            ),
+            "energy_recharge_cooldown" => value.write_to(
+                &mut self.r#energy_recharge_cooldown,
+                "energy_recharge_cooldown",
+            ),
+            "base_shield_recharge_rate" => value.write_to(
+                &mut self.r#base_shield_recharge_rate,
+                "base_shield_recharge_rate",
+            ),
+            "shield_recharge_cooldown" => value.write_to(
+                &mut self.r#shield_recharge_cooldown,
+                "shield_recharge_cooldown",
+            ),
+            "base_drone_reconstruction_speed" => value.write_to(
+                &mut self.r#base_drone_reconstruction_speed,
+                "base_drone_reconstruction_speed",
+            ),
+            "shield_corrosive_resistance" => value.write_to(
+                &mut self.r#shield_corrosive_resistance,
+                "shield_corrosive_resistance",
+            ),
+            "max_velocity" => value.write_to(&mut self.r#max_velocity, "max_velocity"),
+            "max_angular_velocity" => {
+                value.write_to(&mut self.r#max_angular_velocity, "max_angular_velocity")
+            }
+            "max_acceleration" => value.write_to(&mut self.r#max_acceleration, "max_acceleration"),
+            "max_angular_acceleration" => value.write_to(
+                &mut self.r#max_angular_acceleration,
+                "max_angular_acceleration",
+            ),
+            "disable_cells_expansions" => value.write_to(
+                &mut self.r#disable_cells_expansions,
+                "disable_cells_expansions",
+            ),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for ShipSettings {}
 impl std::cmp::PartialEq for ShipSettings {
     fn eq(&self, other: &Self) -> bool {
@@ -24548,80 +36643,108 @@ impl Default for ShipSettings {
 pub struct SkillSettings {
     #[serde(default)]
     pub r#beat_all_enemies_faction_list: Vec<FactionId>,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#disable_exceed_the_limits: bool,
+    /// - Default: `"BaseFuelCapacity + 50*level"`
     #[serde(default = "default_ඞquoteඞBaseFuelCapacityඞspaceඞඞplusඞඞspaceඞ50ඞstarඞlevelඞquoteඞ")]
     #[serde(
         skip_serializing_if = "skip_if_ඞquoteඞBaseFuelCapacityඞspaceඞඞplusඞඞspaceඞ50ඞstarඞlevelඞquoteඞ"
     )]
-    pub r#fuel_tank_capacity: String,
+    pub r#fuel_tank_capacity: crate::expression::Expression,
+    /// - Default: `"0.1*level"`
     #[serde(default = "default_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
-    pub r#attack_bonus: String,
+    pub r#attack_bonus: crate::expression::Expression,
+    /// - Default: `"0.1*level"`
     #[serde(default = "default_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
-    pub r#defense_bonus: String,
+    pub r#defense_bonus: crate::expression::Expression,
+    /// - Default: `"0.1*level"`
     #[serde(default = "default_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
-    pub r#shield_strength_bonus: String,
+    pub r#shield_strength_bonus: crate::expression::Expression,
+    /// - Default: `"0.1*level"`
     #[serde(default = "default_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
-    pub r#shield_recharge_bonus: String,
+    pub r#shield_recharge_bonus: crate::expression::Expression,
+    /// - Default: `"0.1*level"`
     #[serde(default = "default_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
-    pub r#experience_bonus: String,
+    pub r#experience_bonus: crate::expression::Expression,
+    /// - Default: `"BaseFlightSpeed + 0.4*level"`
     #[serde(
         default = "default_ඞquoteඞBaseFlightSpeedඞspaceඞඞplusඞඞspaceඞ0ඞdotඞ4ඞstarඞlevelඞquoteඞ"
     )]
     #[serde(
         skip_serializing_if = "skip_if_ඞquoteඞBaseFlightSpeedඞspaceඞඞplusඞඞspaceඞ0ඞdotඞ4ඞstarඞlevelඞquoteඞ"
     )]
-    pub r#flight_speed: String,
+    pub r#flight_speed: crate::expression::Expression,
+    /// - Default: `"BaseFlightRange + 0.09*level"`
     #[serde(
         default = "default_ඞquoteඞBaseFlightRangeඞspaceඞඞplusඞඞspaceඞ0ඞdotඞ09ඞstarඞlevelඞquoteඞ"
     )]
     #[serde(
         skip_serializing_if = "skip_if_ඞquoteඞBaseFlightRangeඞspaceඞඞplusඞඞspaceඞ0ඞdotඞ09ඞstarඞlevelඞquoteඞ"
     )]
-    pub r#flight_range: String,
+    pub r#flight_range: crate::expression::Expression,
+    /// - Default: `"0.1*level"`
     #[serde(default = "default_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
-    pub r#exploration_loot_bonus: String,
+    pub r#exploration_loot_bonus: crate::expression::Expression,
+    /// - Default: `"0.1*level"`
     #[serde(default = "default_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
-    pub r#heat_resistance: String,
+    pub r#heat_resistance: crate::expression::Expression,
+    /// - Default: `"0.1*level"`
     #[serde(default = "default_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
-    pub r#kinetic_resistance: String,
+    pub r#kinetic_resistance: crate::expression::Expression,
+    /// - Default: `"0.1*level"`
     #[serde(default = "default_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ")]
-    pub r#energy_resistance: String,
+    pub r#energy_resistance: crate::expression::Expression,
+    /// - Default: `"1 - 0.05*level"`
     #[serde(default = "default_ඞquoteඞ1ඞspaceඞඞdashඞඞspaceඞ0ඞdotඞ05ඞstarඞlevelඞquoteඞ")]
     #[serde(
         skip_serializing_if = "skip_if_ඞquoteඞ1ඞspaceඞඞdashඞඞspaceඞ0ඞdotඞ05ඞstarඞlevelඞquoteඞ"
     )]
-    pub r#merchant_price_factor: String,
+    pub r#merchant_price_factor: crate::expression::Expression,
+    /// - Default: `"1 - 0.05*level"`
     #[serde(default = "default_ඞquoteඞ1ඞspaceඞඞdashඞඞspaceඞ0ඞdotඞ05ඞstarඞlevelඞquoteඞ")]
     #[serde(
         skip_serializing_if = "skip_if_ඞquoteඞ1ඞspaceඞඞdashඞඞspaceඞ0ඞdotඞ05ඞstarඞlevelඞquoteඞ"
     )]
-    pub r#crafting_price_factor: String,
+    pub r#crafting_price_factor: crate::expression::Expression,
+    /// - Default: `"5*level"`
     #[serde(default = "default_ඞquoteඞ5ඞstarඞlevelඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ5ඞstarඞlevelඞquoteඞ")]
-    pub r#crafting_level_reduction: String,
+    pub r#crafting_level_reduction: crate::expression::Expression,
+    /// - Minimum: `0`
+    /// - Maximum: `500`
+    /// - Default: `100`
     #[serde(default = "default_100i32")]
     #[serde(skip_serializing_if = "skip_if_100i32")]
     pub r#max_player_ships_level: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `200`
     #[serde(default = "default_200i32")]
     #[serde(skip_serializing_if = "skip_if_200i32")]
     pub r#increased_level_limit: i32,
+    /// - Minimum: `10`
+    /// - Default: `100`
     #[serde(default = "default_100i32")]
     #[serde(skip_serializing_if = "skip_if_100i32")]
     pub r#base_fuel_capacity: i32,
+    /// - Minimum: `1.5`
+    /// - Default: `1.5`
     #[serde(default = "default_1ඞdotඞ5f32")]
     #[serde(skip_serializing_if = "skip_if_1ඞdotඞ5f32")]
     pub r#base_flight_range: f32,
+    /// - Minimum: `1`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#base_flight_speed: f32,
@@ -24681,158 +36804,212 @@ impl SkillSettings {
         self.r#disable_exceed_the_limits = r#disable_exceed_the_limits.into();
         self
     }
-    pub fn with_fuel_tank_capacity(mut self, r#fuel_tank_capacity: impl Into<String>) -> Self {
+    pub fn with_fuel_tank_capacity(
+        mut self,
+        r#fuel_tank_capacity: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#fuel_tank_capacity = r#fuel_tank_capacity.into();
         self
     }
-    pub fn set_fuel_tank_capacity(&mut self, r#fuel_tank_capacity: impl Into<String>) -> &mut Self {
+    pub fn set_fuel_tank_capacity(
+        &mut self,
+        r#fuel_tank_capacity: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#fuel_tank_capacity = r#fuel_tank_capacity.into();
         self
     }
-    pub fn with_attack_bonus(mut self, r#attack_bonus: impl Into<String>) -> Self {
+    pub fn with_attack_bonus(
+        mut self,
+        r#attack_bonus: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#attack_bonus = r#attack_bonus.into();
         self
     }
-    pub fn set_attack_bonus(&mut self, r#attack_bonus: impl Into<String>) -> &mut Self {
+    pub fn set_attack_bonus(
+        &mut self,
+        r#attack_bonus: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#attack_bonus = r#attack_bonus.into();
         self
     }
-    pub fn with_defense_bonus(mut self, r#defense_bonus: impl Into<String>) -> Self {
+    pub fn with_defense_bonus(
+        mut self,
+        r#defense_bonus: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#defense_bonus = r#defense_bonus.into();
         self
     }
-    pub fn set_defense_bonus(&mut self, r#defense_bonus: impl Into<String>) -> &mut Self {
+    pub fn set_defense_bonus(
+        &mut self,
+        r#defense_bonus: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#defense_bonus = r#defense_bonus.into();
         self
     }
     pub fn with_shield_strength_bonus(
         mut self,
-        r#shield_strength_bonus: impl Into<String>,
+        r#shield_strength_bonus: impl Into<crate::expression::Expression>,
     ) -> Self {
         self.r#shield_strength_bonus = r#shield_strength_bonus.into();
         self
     }
     pub fn set_shield_strength_bonus(
         &mut self,
-        r#shield_strength_bonus: impl Into<String>,
+        r#shield_strength_bonus: impl Into<crate::expression::Expression>,
     ) -> &mut Self {
         self.r#shield_strength_bonus = r#shield_strength_bonus.into();
         self
     }
     pub fn with_shield_recharge_bonus(
         mut self,
-        r#shield_recharge_bonus: impl Into<String>,
+        r#shield_recharge_bonus: impl Into<crate::expression::Expression>,
     ) -> Self {
         self.r#shield_recharge_bonus = r#shield_recharge_bonus.into();
         self
     }
     pub fn set_shield_recharge_bonus(
         &mut self,
-        r#shield_recharge_bonus: impl Into<String>,
+        r#shield_recharge_bonus: impl Into<crate::expression::Expression>,
     ) -> &mut Self {
         self.r#shield_recharge_bonus = r#shield_recharge_bonus.into();
         self
     }
-    pub fn with_experience_bonus(mut self, r#experience_bonus: impl Into<String>) -> Self {
+    pub fn with_experience_bonus(
+        mut self,
+        r#experience_bonus: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#experience_bonus = r#experience_bonus.into();
         self
     }
-    pub fn set_experience_bonus(&mut self, r#experience_bonus: impl Into<String>) -> &mut Self {
+    pub fn set_experience_bonus(
+        &mut self,
+        r#experience_bonus: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#experience_bonus = r#experience_bonus.into();
         self
     }
-    pub fn with_flight_speed(mut self, r#flight_speed: impl Into<String>) -> Self {
+    pub fn with_flight_speed(
+        mut self,
+        r#flight_speed: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#flight_speed = r#flight_speed.into();
         self
     }
-    pub fn set_flight_speed(&mut self, r#flight_speed: impl Into<String>) -> &mut Self {
+    pub fn set_flight_speed(
+        &mut self,
+        r#flight_speed: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#flight_speed = r#flight_speed.into();
         self
     }
-    pub fn with_flight_range(mut self, r#flight_range: impl Into<String>) -> Self {
+    pub fn with_flight_range(
+        mut self,
+        r#flight_range: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#flight_range = r#flight_range.into();
         self
     }
-    pub fn set_flight_range(&mut self, r#flight_range: impl Into<String>) -> &mut Self {
+    pub fn set_flight_range(
+        &mut self,
+        r#flight_range: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#flight_range = r#flight_range.into();
         self
     }
     pub fn with_exploration_loot_bonus(
         mut self,
-        r#exploration_loot_bonus: impl Into<String>,
+        r#exploration_loot_bonus: impl Into<crate::expression::Expression>,
     ) -> Self {
         self.r#exploration_loot_bonus = r#exploration_loot_bonus.into();
         self
     }
     pub fn set_exploration_loot_bonus(
         &mut self,
-        r#exploration_loot_bonus: impl Into<String>,
+        r#exploration_loot_bonus: impl Into<crate::expression::Expression>,
     ) -> &mut Self {
         self.r#exploration_loot_bonus = r#exploration_loot_bonus.into();
         self
     }
-    pub fn with_heat_resistance(mut self, r#heat_resistance: impl Into<String>) -> Self {
+    pub fn with_heat_resistance(
+        mut self,
+        r#heat_resistance: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#heat_resistance = r#heat_resistance.into();
         self
     }
-    pub fn set_heat_resistance(&mut self, r#heat_resistance: impl Into<String>) -> &mut Self {
+    pub fn set_heat_resistance(
+        &mut self,
+        r#heat_resistance: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#heat_resistance = r#heat_resistance.into();
         self
     }
-    pub fn with_kinetic_resistance(mut self, r#kinetic_resistance: impl Into<String>) -> Self {
+    pub fn with_kinetic_resistance(
+        mut self,
+        r#kinetic_resistance: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#kinetic_resistance = r#kinetic_resistance.into();
         self
     }
-    pub fn set_kinetic_resistance(&mut self, r#kinetic_resistance: impl Into<String>) -> &mut Self {
+    pub fn set_kinetic_resistance(
+        &mut self,
+        r#kinetic_resistance: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#kinetic_resistance = r#kinetic_resistance.into();
         self
     }
-    pub fn with_energy_resistance(mut self, r#energy_resistance: impl Into<String>) -> Self {
+    pub fn with_energy_resistance(
+        mut self,
+        r#energy_resistance: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#energy_resistance = r#energy_resistance.into();
         self
     }
-    pub fn set_energy_resistance(&mut self, r#energy_resistance: impl Into<String>) -> &mut Self {
+    pub fn set_energy_resistance(
+        &mut self,
+        r#energy_resistance: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#energy_resistance = r#energy_resistance.into();
         self
     }
     pub fn with_merchant_price_factor(
         mut self,
-        r#merchant_price_factor: impl Into<String>,
+        r#merchant_price_factor: impl Into<crate::expression::Expression>,
     ) -> Self {
         self.r#merchant_price_factor = r#merchant_price_factor.into();
         self
     }
     pub fn set_merchant_price_factor(
         &mut self,
-        r#merchant_price_factor: impl Into<String>,
+        r#merchant_price_factor: impl Into<crate::expression::Expression>,
     ) -> &mut Self {
         self.r#merchant_price_factor = r#merchant_price_factor.into();
         self
     }
     pub fn with_crafting_price_factor(
         mut self,
-        r#crafting_price_factor: impl Into<String>,
+        r#crafting_price_factor: impl Into<crate::expression::Expression>,
     ) -> Self {
         self.r#crafting_price_factor = r#crafting_price_factor.into();
         self
     }
     pub fn set_crafting_price_factor(
         &mut self,
-        r#crafting_price_factor: impl Into<String>,
+        r#crafting_price_factor: impl Into<crate::expression::Expression>,
     ) -> &mut Self {
         self.r#crafting_price_factor = r#crafting_price_factor.into();
         self
     }
     pub fn with_crafting_level_reduction(
         mut self,
-        r#crafting_level_reduction: impl Into<String>,
+        r#crafting_level_reduction: impl Into<crate::expression::Expression>,
     ) -> Self {
         self.r#crafting_level_reduction = r#crafting_level_reduction.into();
         self
     }
     pub fn set_crafting_level_reduction(
         &mut self,
-        r#crafting_level_reduction: impl Into<String>,
+        r#crafting_level_reduction: impl Into<crate::expression::Expression>,
     ) -> &mut Self {
         self.r#crafting_level_reduction = r#crafting_level_reduction.into();
         self
@@ -24939,6 +37116,133 @@ impl DatabaseItem for SkillSettings {
         "SkillSettings"
     }
 }
+impl VisitIds for SkillSettings {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#beat_all_enemies_faction_list.visit_ids(visitor);
+    }
+}
+impl VisitAssets for SkillSettings {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for SkillSettings {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "beat_all_enemies_faction_list",
+            "disable_exceed_the_limits",
+            "fuel_tank_capacity",
+            "attack_bonus",
+            "defense_bonus",
+            "shield_strength_bonus",
+            "shield_recharge_bonus",
+            "experience_bonus",
+            "flight_speed",
+            "flight_range",
+            "exploration_loot_bonus",
+            "heat_resistance",
+            "kinetic_resistance",
+            "energy_resistance",
+            "merchant_price_factor",
+            "crafting_price_factor",
+            "crafting_level_reduction",
+            "max_player_ships_level",
+            "increased_level_limit",
+            "base_fuel_capacity",
+            "base_flight_range",
+            "base_flight_speed",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "beat_all_enemies_faction_list" => {
+                Some(FieldValue::of(&self.r#beat_all_enemies_faction_list))
+            }
+            "disable_exceed_the_limits" => Some(FieldValue::of(&self.r#disable_exceed_the_limits)),
+            "fuel_tank_capacity" => Some(FieldValue::of(&self.r#fuel_tank_capacity)),
+            "attack_bonus" => Some(FieldValue::of(&self.r#attack_bonus)),
+            "defense_bonus" => Some(FieldValue::of(&self.r#defense_bonus)),
+            "shield_strength_bonus" => Some(FieldValue::of(&self.r#shield_strength_bonus)),
+            "shield_recharge_bonus" => Some(FieldValue::of(&self.r#shield_recharge_bonus)),
+            "experience_bonus" => Some(FieldValue::of(&self.r#experience_bonus)),
+            "flight_speed" => Some(FieldValue::of(&self.r#flight_speed)),
+            "flight_range" => Some(FieldValue::of(&self.r#flight_range)),
+            "exploration_loot_bonus" => Some(FieldValue::of(&self.r#exploration_loot_bonus)),
+            "heat_resistance" => Some(FieldValue::of(&self.r#heat_resistance)),
+            "kinetic_resistance" => Some(FieldValue::of(&self.r#kinetic_resistance)),
+            "energy_resistance" => Some(FieldValue::of(&self.r#energy_resistance)),
+            "merchant_price_factor" => Some(FieldValue::of(&self.r#merchant_price_factor)),
+            "crafting_price_factor" => Some(FieldValue::of(&self.r#crafting_price_factor)),
+            "crafting_level_reduction" => Some(FieldValue::of(&self.r#crafting_level_reduction)),
+            "max_player_ships_level" => Some(FieldValue::of(&self.r#max_player_ships_level)),
+            "increased_level_limit" => Some(FieldValue::of(&self.r#increased_level_limit)),
+            "base_fuel_capacity" => Some(FieldValue::of(&self.r#base_fuel_capacity)),
+            "base_flight_range" => Some(FieldValue::of(&self.r#base_flight_range)),
+            "base_flight_speed" => Some(FieldValue::of(&self.r#base_flight_speed)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "beat_all_enemies_faction_list" => value.write_to(
+                &mut self.r#beat_all_enemies_faction_list,
+                "beat_all_enemies_faction_list",
+            ),
+            "disable_exceed_the_limits" => value.write_to(
+                &mut self.r#disable_exceed_the_limits,
+                "disable_exceed_the_limits",
+            ),
+            "fuel_tank_capacity" => {
+                value.write_to(&mut self.r#fuel_tank_capacity, "fuel_tank_capacity")
+            }
+            "attack_bonus" => value.write_to(&mut self.r#attack_bonus, "attack_bonus"),
+            "defense_bonus" => value.write_to(&mut self.r#defense_bonus, "defense_bonus"),
+            "shield_strength_bonus" => {
+                value.write_to(&mut self.r#shield_strength_bonus, "shield_strength_bonus")
+            }
+            "shield_recharge_bonus" => {
+                value.write_to(&mut self.r#shield_recharge_bonus, "shield_recharge_bonus")
+            }
+            "experience_bonus" => value.write_to(&mut self.r#experience_bonus, "experience_bonus"),
+            "flight_speed" => value.write_to(&mut self.r#flight_speed, "flight_speed"),
+            "flight_range" => value.write_to(&mut self.r#flight_range, "flight_range"),
+            "exploration_loot_bonus" => {
+                value.write_to(&mut self.r#exploration_loot_bonus, "exploration_loot_bonus")
+            }
+            "heat_resistance" => value.write_to(&mut self.r#heat_resistance, "heat_resistance"),
+            "kinetic_resistance" => {
+                value.write_to(&mut self.r#kinetic_resistance, "kinetic_resistance")
+            }
+            "energy_resistance" => {
+                value.write_to(&mut self.r#energy_resistance, "energy_resistance")
+            }
+            "merchant_price_factor" => {
+                value.write_to(&mut self.r#merchant_price_factor, "merchant_price_factor")
+            }
+            "crafting_price_factor" => {
+                value.write_to(&mut self.r#crafting_price_factor, "crafting_price_factor")
+            }
+            "crafting_level_reduction" => value.write_to(
+                &mut self.r#crafting_level_reduction,
+                "crafting_level_reduction",
+            ),
+            "max_player_ships_level" => {
+                value.write_to(&mut self.r#max_player_ships_level, "max_player_ships_level")
+            }
+            "increased_level_limit" => {
+                value.write_to(&mut self.r#increased_level_limit, "increased_level_limit")
+            }
+            "base_fuel_capacity" => {
+                value.write_to(&mut self.r#base_fuel_capacity, "base_fuel_capacity")
+            }
+            "base_flight_range" => {
+                value.write_to(&mut self.r#base_flight_range, "base_flight_range")
+            }
+            "base_flight_speed" => {
+                value.write_to(&mut self.r#base_flight_speed, "base_flight_speed")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for SkillSettings {}
 impl std::cmp::PartialEq for SkillSettings {
     fn eq(&self, other: &Self) -> bool {
@@ -25004,12 +37308,19 @@ impl Default for SkillSettings {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct SpecialEventSettings {
+    /// - Default: `true`
     #[serde(default = "default_true")]
     #[serde(skip_serializing_if = "skip_if_true")]
     pub r#enable_xmas_event: bool,
+    /// - Minimum: `0`
+    /// - Maximum: `30`
+    /// - Default: `24`
     #[serde(default = "default_24i32")]
     #[serde(skip_serializing_if = "skip_if_24i32")]
     pub r#xmas_days_before: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `30`
+    /// - Default: `15`
     #[serde(default = "default_15i32")]
     #[serde(skip_serializing_if = "skip_if_15i32")]
     pub r#xmas_days_after: i32,
@@ -25019,27 +37330,42 @@ pub struct SpecialEventSettings {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#xmas_combat_rules: Option<CombatRulesId>,
+    /// - Default: `"1 + credits/500"`
     #[serde(default = "default_ඞquoteඞ1ඞspaceඞඞplusඞඞspaceඞcreditsඞslashඞ500ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ1ඞspaceඞඞplusඞඞspaceඞcreditsඞslashඞ500ඞquoteඞ")]
-    pub r#convert_credits_to_snowflakes: String,
+    pub r#convert_credits_to_snowflakes: crate::expression::Expression,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#enable_easter_event: bool,
+    /// - Minimum: `0`
+    /// - Maximum: `30`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#easter_days_before: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `30`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#easter_days_after: i32,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#easter_quest: Option<QuestId>,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#enable_halloween_event: bool,
+    /// - Minimum: `0`
+    /// - Maximum: `30`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#halloween_days_before: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `30`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#halloween_days_after: i32,
@@ -25098,6 +37424,10 @@ impl SpecialEventSettings {
         self.r#xmas_quest = r#xmas_quest.into();
         self
     }
+    pub fn clear_xmas_quest(&mut self) -> &mut Self {
+        self.r#xmas_quest = None;
+        self
+    }
     pub fn with_xmas_combat_rules(
         mut self,
         r#xmas_combat_rules: impl Into<Option<CombatRulesId>>,
@@ -25112,16 +37442,20 @@ impl SpecialEventSettings {
         self.r#xmas_combat_rules = r#xmas_combat_rules.into();
         self
     }
+    pub fn clear_xmas_combat_rules(&mut self) -> &mut Self {
+        self.r#xmas_combat_rules = None;
+        self
+    }
     pub fn with_convert_credits_to_snowflakes(
         mut self,
-        r#convert_credits_to_snowflakes: impl Into<String>,
+        r#convert_credits_to_snowflakes: impl Into<crate::expression::Expression>,
     ) -> Self {
         self.r#convert_credits_to_snowflakes = r#convert_credits_to_snowflakes.into();
         self
     }
     pub fn set_convert_credits_to_snowflakes(
         &mut self,
-        r#convert_credits_to_snowflakes: impl Into<String>,
+        r#convert_credits_to_snowflakes: impl Into<crate::expression::Expression>,
     ) -> &mut Self {
         self.r#convert_credits_to_snowflakes = r#convert_credits_to_snowflakes.into();
         self
@@ -25158,6 +37492,10 @@ impl SpecialEventSettings {
         self.r#easter_quest = r#easter_quest.into();
         self
     }
+    pub fn clear_easter_quest(&mut self) -> &mut Self {
+        self.r#easter_quest = None;
+        self
+    }
     pub fn with_enable_halloween_event(
         mut self,
         r#enable_halloween_event: impl Into<bool>,
@@ -25205,6 +37543,10 @@ impl SpecialEventSettings {
         self.r#halloween_quest = r#halloween_quest.into();
         self
     }
+    pub fn clear_halloween_quest(&mut self) -> &mut Self {
+        self.r#halloween_quest = None;
+        self
+    }
 }
 impl DatabaseItem for SpecialEventSettings {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
@@ -25276,6 +37618,96 @@ impl DatabaseItem for SpecialEventSettings {
         "SpecialEventSettings"
     }
 }
+impl VisitIds for SpecialEventSettings {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#xmas_quest.visit_ids(visitor);
+        self.r#xmas_combat_rules.visit_ids(visitor);
+        self.r#easter_quest.visit_ids(visitor);
+        self.r#halloween_quest.visit_ids(visitor);
+    }
+}
+impl VisitAssets for SpecialEventSettings {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for SpecialEventSettings {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "enable_xmas_event",
+            "xmas_days_before",
+            "xmas_days_after",
+            "xmas_quest",
+            "xmas_combat_rules",
+            "convert_credits_to_snowflakes",
+            "enable_easter_event",
+            "easter_days_before",
+            "easter_days_after",
+            "easter_quest",
+            "enable_halloween_event",
+            "halloween_days_before",
+            "halloween_days_after",
+            "halloween_quest",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "enable_xmas_event" => Some(FieldValue::of(&self.r#enable_xmas_event)),
+            "xmas_days_before" => Some(FieldValue::of(&self.r#xmas_days_before)),
+            "xmas_days_after" => Some(FieldValue::of(&self.r#xmas_days_after)),
+            "xmas_quest" => Some(FieldValue::of(&self.r#xmas_quest)),
+            "xmas_combat_rules" => Some(FieldValue::of(&self.r#xmas_combat_rules)),
+            "convert_credits_to_snowflakes" => {
+                Some(FieldValue::of(&self.r#convert_credits_to_snowflakes))
+            }
+            "enable_easter_event" => Some(FieldValue::of(&self.r#enable_easter_event)),
+            "easter_days_before" => Some(FieldValue::of(&self.r#easter_days_before)),
+            "easter_days_after" => Some(FieldValue::of(&self.r#easter_days_after)),
+            "easter_quest" => Some(FieldValue::of(&self.r#easter_quest)),
+            "enable_halloween_event" => Some(FieldValue::of(&self.r#enable_halloween_event)),
+            "halloween_days_before" => Some(FieldValue::of(&self.r#halloween_days_before)),
+            "halloween_days_after" => Some(FieldValue::of(&self.r#halloween_days_after)),
+            "halloween_quest" => Some(FieldValue::of(&self.r#halloween_quest)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "enable_xmas_event" => {
+                value.write_to(&mut self.r#enable_xmas_event, "enable_xmas_event")
+            }
+            "xmas_days_before" => value.write_to(&mut self.r#xmas_days_before, "xmas_days_before"),
+            "xmas_days_after" => value.write_to(&mut self.r#xmas_days_after, "xmas_days_after"),
+            "xmas_quest" => value.write_to(&mut self.r#xmas_quest, "xmas_quest"),
+            "xmas_combat_rules" => {
+                value.write_to(&mut self.r#xmas_combat_rules, "xmas_combat_rules")
+            }
+            "convert_credits_to_snowflakes" => value.write_to(
+                &mut self.r#convert_credits_to_snowflakes,
+                "convert_credits_to_snowflakes",
+            ),
+            "enable_easter_event" => {
+                value.write_to(&mut self.r#enable_easter_event, "enable_easter_event")
+            }
+            "easter_days_before" => {
+                value.write_to(&mut self.r#easter_days_before, "easter_days_before")
+            }
+            "easter_days_after" => {
+                value.write_to(&mut self.r#easter_days_after, "easter_days_after")
+            }
+            "easter_quest" => value.write_to(&mut self.r#easter_quest, "easter_quest"),
+            "enable_halloween_event" => {
+                value.write_to(&mut self.r#enable_halloween_event, "enable_halloween_event")
+            }
+            "halloween_days_before" => {
+                value.write_to(&mut self.r#halloween_days_before, "halloween_days_before")
+            }
+            "halloween_days_after" => {
+                value.write_to(&mut self.r#halloween_days_after, "halloween_days_after")
+            }
+            "halloween_quest" => value.write_to(&mut self.r#halloween_quest, "halloween_quest"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for SpecialEventSettings {
     fn default() -> Self {
         Self::new()
@@ -25286,116 +37718,153 @@ impl Default for SpecialEventSettings {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct UiSettings {
+    /// - Default: `"#50C0FF"`
     #[serde(default = "default_ඞquoteඞඞhashඞ50C0FFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ50C0FFඞquoteඞ")]
     pub r#window_color: String,
+    /// - Default: `"#C050C0FF"`
     #[serde(default = "default_ඞquoteඞඞhashඞC050C0FFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞC050C0FFඞquoteඞ")]
     pub r#scroll_bar_color: String,
+    /// - Default: `"#80FFFF"`
     #[serde(default = "default_ඞquoteඞඞhashඞ80FFFFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ80FFFFඞquoteඞ")]
     pub r#icon_color: String,
+    /// - Default: `"#80FFFF"`
     #[serde(default = "default_ඞquoteඞඞhashඞ80FFFFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ80FFFFඞquoteඞ")]
     pub r#selection_color: String,
+    /// - Default: `"#50C0FF"`
     #[serde(default = "default_ඞquoteඞඞhashඞ50C0FFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ50C0FFඞquoteඞ")]
     pub r#button_color: String,
+    /// - Default: `"#4050C0FF"`
     #[serde(default = "default_ඞquoteඞඞhashඞ4050C0FFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ4050C0FFඞquoteඞ")]
     pub r#button_focus_color: String,
+    /// - Default: `"#80FFFF"`
     #[serde(default = "default_ඞquoteඞඞhashඞ80FFFFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ80FFFFඞquoteඞ")]
     pub r#button_text_color: String,
+    /// - Default: `"#E080FFFF"`
     #[serde(default = "default_ඞquoteඞඞhashඞE080FFFFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞE080FFFFඞquoteඞ")]
     pub r#button_icon_color: String,
+    /// - Default: `"#FF8050"`
     #[serde(default = "default_ඞquoteඞඞhashඞFF8050ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞFF8050ඞquoteඞ")]
     pub r#warning_button_color: String,
+    /// - Default: `"#20FF8050"`
     #[serde(default = "default_ඞquoteඞඞhashඞ20FF8050ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ20FF8050ඞquoteඞ")]
     pub r#warning_button_focus_color: String,
+    /// - Default: `"#FFFFC0"`
     #[serde(default = "default_ඞquoteඞඞhashඞFFFFC0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞFFFFC0ඞquoteඞ")]
     pub r#warning_button_text_color: String,
+    /// - Default: `"#FFFFC0"`
     #[serde(default = "default_ඞquoteඞඞhashඞFFFFC0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞFFFFC0ඞquoteඞ")]
     pub r#warning_button_icon_color: String,
+    /// - Default: `"#FFFFC0"`
     #[serde(default = "default_ඞquoteඞඞhashඞFFFFC0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞFFFFC0ඞquoteඞ")]
     pub r#premium_button_color: String,
+    /// - Default: `"#40FFFFC0"`
     #[serde(default = "default_ඞquoteඞඞhashඞ40FFFFC0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ40FFFFC0ඞquoteඞ")]
     pub r#premium_button_focus_color: String,
+    /// - Default: `"#FFFFE0"`
     #[serde(default = "default_ඞquoteඞඞhashඞFFFFE0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞFFFFE0ඞquoteඞ")]
     pub r#premium_button_text_color: String,
+    /// - Default: `"#FFFFC0"`
     #[serde(default = "default_ඞquoteඞඞhashඞFFFFC0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞFFFFC0ඞquoteඞ")]
     pub r#premium_button_icon_color: String,
+    /// - Default: `"#80FFFF"`
     #[serde(default = "default_ඞquoteඞඞhashඞ80FFFFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ80FFFFඞquoteඞ")]
     pub r#text_color: String,
+    /// - Default: `"#FF4040"`
     #[serde(default = "default_ඞquoteඞඞhashඞFF4040ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞFF4040ඞquoteඞ")]
     pub r#error_text_color: String,
+    /// - Default: `"#FFFFC0"`
     #[serde(default = "default_ඞquoteඞඞhashඞFFFFC0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞFFFFC0ඞquoteඞ")]
     pub r#header_text_color: String,
+    /// - Default: `"#A0FFFFFF"`
     #[serde(default = "default_ඞquoteඞඞhashඞA0FFFFFFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞA0FFFFFFඞquoteඞ")]
     pub r#pale_text_color: String,
+    /// - Default: `"#FFFFFF"`
     #[serde(default = "default_ඞquoteඞඞhashඞFFFFFFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞFFFFFFඞquoteඞ")]
     pub r#bright_text_color: String,
+    /// - Default: `"#000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ000000ඞquoteඞ")]
     pub r#background_dark: String,
+    /// - Default: `"#C0C0C0"`
     #[serde(default = "default_ඞquoteඞඞhashඞC0C0C0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞC0C0C0ඞquoteඞ")]
     pub r#low_quality_item_color: String,
+    /// - Default: `"#80FFFF"`
     #[serde(default = "default_ඞquoteඞඞhashඞ80FFFFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ80FFFFඞquoteඞ")]
     pub r#common_quality_item_color: String,
+    /// - Default: `"#80FF80"`
     #[serde(default = "default_ඞquoteඞඞhashඞ80FF80ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ80FF80ඞquoteඞ")]
     pub r#medium_quality_item_color: String,
+    /// - Default: `"#F09FFF"`
     #[serde(default = "default_ඞquoteඞඞhashඞF09FFFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞF09FFFඞquoteඞ")]
     pub r#high_quality_item_color: String,
+    /// - Default: `"#FFDF51"`
     #[serde(default = "default_ඞquoteඞඞhashඞFFDF51ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞFFDF51ඞquoteඞ")]
     pub r#perfect_quality_item_color: String,
+    /// - Default: `"#FFFFC0"`
     #[serde(default = "default_ඞquoteඞඞhashඞFFFFC0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞFFFFC0ඞquoteඞ")]
     pub r#available_tech_color: String,
+    /// - Default: `"#808080"`
     #[serde(default = "default_ඞquoteඞඞhashඞ808080ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ808080ඞquoteඞ")]
     pub r#unavailable_tech_color: String,
+    /// - Default: `"#50C0FF"`
     #[serde(default = "default_ඞquoteඞඞhashඞ50C0FFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ50C0FFඞquoteඞ")]
     pub r#obtained_tech_color: String,
+    /// - Default: `"#8080FF"`
     #[serde(default = "default_ඞquoteඞඞhashඞ8080FFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ8080FFඞquoteඞ")]
     pub r#hidden_tech_color: String,
+    /// - Default: `"#00FF00"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00FF00ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00FF00ඞquoteඞ")]
     pub r#credits_color: String,
+    /// - Default: `"#FFF0A0"`
     #[serde(default = "default_ඞquoteඞඞhashඞFFF0A0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞFFF0A0ඞquoteඞ")]
     pub r#stars_color: String,
+    /// - Default: `"#FFF0A0"`
     #[serde(default = "default_ඞquoteඞඞhashඞFFF0A0ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞFFF0A0ඞquoteඞ")]
     pub r#money_color: String,
+    /// - Default: `"#00FFFF"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00FFFFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00FFFFඞquoteඞ")]
     pub r#fuel_color: String,
+    /// - Default: `"#8080FF"`
     #[serde(default = "default_ඞquoteඞඞhashඞ8080FFඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ8080FFඞquoteඞ")]
     pub r#tokens_color: String,
     #[serde(default)]
     pub r#main_menu_background_image: String,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#no_credits_text: bool,
@@ -25839,11 +38308,457 @@ impl UiSettings {
     }
 }
 impl DatabaseItem for UiSettings {
-    fn validate(&self, ctx: DiagnosticContextRef) {}
+    fn validate(&self, mut ctx: DiagnosticContextRef) {
+        {
+            let mut ctx = ctx.enter("window_color");
+            if !crate::color::Color::is_valid(&self.r#window_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#window_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("scroll_bar_color");
+            if !crate::color::Color::is_valid(&self.r#scroll_bar_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#scroll_bar_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("icon_color");
+            if !crate::color::Color::is_valid(&self.r#icon_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#icon_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("selection_color");
+            if !crate::color::Color::is_valid(&self.r#selection_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#selection_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("button_color");
+            if !crate::color::Color::is_valid(&self.r#button_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#button_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("button_focus_color");
+            if !crate::color::Color::is_valid(&self.r#button_focus_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#button_focus_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("button_text_color");
+            if !crate::color::Color::is_valid(&self.r#button_text_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#button_text_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("button_icon_color");
+            if !crate::color::Color::is_valid(&self.r#button_icon_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#button_icon_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("warning_button_color");
+            if !crate::color::Color::is_valid(&self.r#warning_button_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#warning_button_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("warning_button_focus_color");
+            if !crate::color::Color::is_valid(&self.r#warning_button_focus_color) {
+                ctx.emit(DiagnosticKind::invalid_color(
+                    &self.r#warning_button_focus_color,
+                ));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("warning_button_text_color");
+            if !crate::color::Color::is_valid(&self.r#warning_button_text_color) {
+                ctx.emit(DiagnosticKind::invalid_color(
+                    &self.r#warning_button_text_color,
+                ));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("warning_button_icon_color");
+            if !crate::color::Color::is_valid(&self.r#warning_button_icon_color) {
+                ctx.emit(DiagnosticKind::invalid_color(
+                    &self.r#warning_button_icon_color,
+                ));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("premium_button_color");
+            if !crate::color::Color::is_valid(&self.r#premium_button_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#premium_button_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("premium_button_focus_color");
+            if !crate::color::Color::is_valid(&self.r#premium_button_focus_color) {
+                ctx.emit(DiagnosticKind::invalid_color(
+                    &self.r#premium_button_focus_color,
+                ));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("premium_button_text_color");
+            if !crate::color::Color::is_valid(&self.r#premium_button_text_color) {
+                ctx.emit(DiagnosticKind::invalid_color(
+                    &self.r#premium_button_text_color,
+                ));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("premium_button_icon_color");
+            if !crate::color::Color::is_valid(&self.r#premium_button_icon_color) {
+                ctx.emit(DiagnosticKind::invalid_color(
+                    &self.r#premium_button_icon_color,
+                ));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("text_color");
+            if !crate::color::Color::is_valid(&self.r#text_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#text_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("error_text_color");
+            if !crate::color::Color::is_valid(&self.r#error_text_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#error_text_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("header_text_color");
+            if !crate::color::Color::is_valid(&self.r#header_text_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#header_text_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("pale_text_color");
+            if !crate::color::Color::is_valid(&self.r#pale_text_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#pale_text_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("bright_text_color");
+            if !crate::color::Color::is_valid(&self.r#bright_text_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#bright_text_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("background_dark");
+            if !crate::color::Color::is_valid(&self.r#background_dark) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#background_dark));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("low_quality_item_color");
+            if !crate::color::Color::is_valid(&self.r#low_quality_item_color) {
+                ctx.emit(DiagnosticKind::invalid_color(
+                    &self.r#low_quality_item_color,
+                ));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("common_quality_item_color");
+            if !crate::color::Color::is_valid(&self.r#common_quality_item_color) {
+                ctx.emit(DiagnosticKind::invalid_color(
+                    &self.r#common_quality_item_color,
+                ));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("medium_quality_item_color");
+            if !crate::color::Color::is_valid(&self.r#medium_quality_item_color) {
+                ctx.emit(DiagnosticKind::invalid_color(
+                    &self.r#medium_quality_item_color,
+                ));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("high_quality_item_color");
+            if !crate::color::Color::is_valid(&self.r#high_quality_item_color) {
+                ctx.emit(DiagnosticKind::invalid_color(
+                    &self.r#high_quality_item_color,
+                ));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("perfect_quality_item_color");
+            if !crate::color::Color::is_valid(&self.r#perfect_quality_item_color) {
+                ctx.emit(DiagnosticKind::invalid_color(
+                    &self.r#perfect_quality_item_color,
+                ));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("available_tech_color");
+            if !crate::color::Color::is_valid(&self.r#available_tech_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#available_tech_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("unavailable_tech_color");
+            if !crate::color::Color::is_valid(&self.r#unavailable_tech_color) {
+                ctx.emit(DiagnosticKind::invalid_color(
+                    &self.r#unavailable_tech_color,
+                ));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("obtained_tech_color");
+            if !crate::color::Color::is_valid(&self.r#obtained_tech_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#obtained_tech_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("hidden_tech_color");
+            if !crate::color::Color::is_valid(&self.r#hidden_tech_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#hidden_tech_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("credits_color");
+            if !crate::color::Color::is_valid(&self.r#credits_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#credits_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("stars_color");
+            if !crate::color::Color::is_valid(&self.r#stars_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#stars_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("money_color");
+            if !crate::color::Color::is_valid(&self.r#money_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#money_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("fuel_color");
+            if !crate::color::Color::is_valid(&self.r#fuel_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#fuel_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("tokens_color");
+            if !crate::color::Color::is_valid(&self.r#tokens_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#tokens_color));
+            }
+        }
+    }
     fn type_name() -> &'static str {
         "UiSettings"
     }
 }
+impl VisitIds for UiSettings {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for UiSettings {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#main_menu_background_image.is_empty() {
+            visitor(AssetKind::Image, &self.r#main_menu_background_image);
+        }
+    }
+}
+impl DynamicFields for UiSettings {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "window_color",
+            "scroll_bar_color",
+            "icon_color",
+            "selection_color",
+            "button_color",
+            "button_focus_color",
+            "button_text_color",
+            "button_icon_color",
+            "warning_button_color",
+            "warning_button_focus_color",
+            "warning_button_text_color",
+            "warning_button_icon_color",
+            "premium_button_color",
+            "premium_button_focus_color",
+            "premium_button_text_color",
+            "premium_button_icon_color",
+            "text_color",
+            "error_text_color",
+            "header_text_color",
+            "pale_text_color",
+            "bright_text_color",
+            "background_dark",
+            "low_quality_item_color",
+            "common_quality_item_color",
+            "medium_quality_item_color",
+            "high_quality_item_color",
+            "perfect_quality_item_color",
+            "available_tech_color",
+            "unavailable_tech_color",
+            "obtained_tech_color",
+            "hidden_tech_color",
+            "credits_color",
+            "stars_color",
+            "money_color",
+            "fuel_color",
+            "tokens_color",
+            "main_menu_background_image",
+            "no_credits_text",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "window_color" => Some(FieldValue::of(&self.r#window_color)),
+            "scroll_bar_color" => Some(FieldValue::of(&self.r#scroll_bar_color)),
+            "icon_color" => Some(FieldValue::of(&self.r#icon_color)),
+            "selection_color" => Some(FieldValue::of(&self.r#selection_color)),
+            "button_color" => Some(FieldValue::of(&self.r#button_color)),
+            "button_focus_color" => Some(FieldValue::of(&self.r#button_focus_color)),
+            "button_text_color" => Some(FieldValue::of(&self.r#button_text_color)),
+            "button_icon_color" => Some(FieldValue::of(&self.r#button_icon_color)),
+            "warning_button_color" => Some(FieldValue::of(&self.r#warning_button_color)),
+            "warning_button_focus_color" => {
+                Some(FieldValue::of(&self.r#warning_button_focus_color))
+            }
+            "warning_button_text_color" => Some(FieldValue::of(&self.r#warning_button_text_color)),
+            "warning_button_icon_color" => Some(FieldValue::of(&self.r#warning_button_icon_color)),
+            "premium_button_color" => Some(FieldValue::of(&self.r#premium_button_color)),
+            "premium_button_focus_color" => {
+                Some(FieldValue::of(&self.r#premium_button_focus_color))
+            }
+            "premium_button_text_color" => Some(FieldValue::of(&self.r#premium_button_text_color)),
+            "premium_button_icon_color" => Some(FieldValue::of(&self.r#premium_button_icon_color)),
+            "text_color" => Some(FieldValue::of(&self.r#text_color)),
+            "error_text_color" => Some(FieldValue::of(&self.r#error_text_color)),
+            "header_text_color" => Some(FieldValue::of(&self.r#header_text_color)),
+            "pale_text_color" => Some(FieldValue::of(&self.r#pale_text_color)),
+            "bright_text_color" => Some(FieldValue::of(&self.r#bright_text_color)),
+            "background_dark" => Some(FieldValue::of(&self.r#background_dark)),
+            "low_quality_item_color" => Some(FieldValue::of(&self.r#low_quality_item_color)),
+            "common_quality_item_color" => Some(FieldValue::of(&self.r#common_quality_item_color)),
+            "medium_quality_item_color" => Some(FieldValue::of(&self.r#medium_quality_item_color)),
+            "high_quality_item_color" => Some(FieldValue::of(&self.r#high_quality_item_color)),
+            "perfect_quality_item_color" => {
+                Some(FieldValue::of(&self.r#perfect_quality_item_color))
+            }
+            "available_tech_color" => Some(FieldValue::of(&self.r#available_tech_color)),
+            "unavailable_tech_color" => Some(FieldValue::of(&self.r#unavailable_tech_color)),
+            "obtained_tech_color" => Some(FieldValue::of(&self.r#obtained_tech_color)),
+            "hidden_tech_color" => Some(FieldValue::of(&self.r#hidden_tech_color)),
+            "credits_color" => Some(FieldValue::of(&self.r#credits_color)),
+            "stars_color" => Some(FieldValue::of(&self.r#stars_color)),
+            "money_color" => Some(FieldValue::of(&self.r#money_color)),
+            "fuel_color" => Some(FieldValue::of(&self.r#fuel_color)),
+            "tokens_color" => Some(FieldValue::of(&self.r#tokens_color)),
+            "main_menu_background_image" => {
+                Some(FieldValue::of(&self.r#main_menu_background_image))
+            }
+            "no_credits_text" => Some(FieldValue::of(&self.r#no_credits_text)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "window_color" => value.write_to(&mut self.r#window_color, "window_color"),
+            "scroll_bar_color" => value.write_to(&mut self.r#scroll_bar_color, "scroll_bar_color"),
+            "icon_color" => value.write_to(&mut self.r#icon_color, "icon_color"),
+            "selection_color" => value.write_to(&mut self.r#selection_color, "selection_color"),
+            "button_color" => value.write_to(&mut self.r#button_color, "button_color"),
+            "button_focus_color" => {
+                value.write_to(&mut self.r#button_focus_color, "button_focus_color")
+            }
+            "button_text_color" => {
+                value.write_to(&mut self.r#button_text_color, "button_text_color")
+            }
+            "button_icon_color" => {
+                value.write_to(&mut self.r#button_icon_color, "button_icon_color")
+            }
+            "warning_button_color" => {
+                value.write_to(&mut self.r#warning_button_color, "warning_button_color")
+            }
+            "warning_button_focus_color" => value.write_to(
+                &mut self.r#warning_button_focus_color,
+                "warning_button_focus_color",
+            ),
+            "warning_button_text_color" => value.write_to(
+                &mut self.r#warning_button_text_color,
+                "warning_button_text_color",
+            ),
+            "warning_button_icon_color" => value.write_to(
+                &mut self.r#warning_button_icon_color,
+                "warning_button_icon_color",
+            ),
+            "premium_button_color" => {
+                value.write_to(&mut self.r#premium_button_color, "premium_button_color")
+            }
+            "premium_button_focus_color" => value.write_to(
+                &mut self.r#premium_button_focus_color,
+                "premium_button_focus_color",
+            ),
+            "premium_button_text_color" => value.write_to(
+                &mut self.r#premium_button_text_color,
+                "premium_button_text_color",
+            ),
+            "premium_button_icon_color" => value.write_to(
+                &mut self.r#premium_button_icon_color,
+                "premium_button_icon_color",
+            ),
+            "text_color" => value.write_to(&mut self.r#text_color, "text_color"),
+            "error_text_color" => value.write_to(&mut self.r#error_text_color, "error_text_color"),
+            "header_text_color" => {
+                value.write_to(&mut self.r#header_text_color, "header_text_color")
+            }
+            "pale_text_color" => value.write_to(&mut self.r#pale_text_color, "pale_text_color"),
+            "bright_text_color" => {
+                value.write_to(&mut self.r#bright_text_color, "bright_text_color")
+            }
+            "background_dark" => value.write_to(&mut self.r#background_dark, "background_dark"),
+            "low_quality_item_color" => {
+                value.write_to(&mut self.r#low_quality_item_color, "low_quality_item_color")
+            }
+            "common_quality_item_color" => value.write_to(
+                &mut self.r#common_quality_item_color,
+                "common_quality_item_color",
+            ),
+            "medium_quality_item_color" => value.write_to(
+                &mut self.r#medium_quality_item_color,
+                "medium_quality_item_color",
+            ),
+            "high_quality_item_color" => value.write_to(
+                &mut self.r#high_quality_item_color,
+                "high_quality_item_color",
+            ),
+            "perfect_quality_item_color" => value.write_to(
+                &mut self.r#perfect_quality_item_color,
+                "perfect_quality_item_color",
+            ),
+            "available_tech_color" => {
+                value.write_to(&mut self.r#available_tech_color, "available_tech_color")
+            }
+            "unavailable_tech_color" => {
+                value.write_to(&mut self.r#unavailable_tech_color, "unavailable_tech_color")
+            }
+            "obtained_tech_color" => {
+                value.write_to(&mut self.r#obtained_tech_color, "obtained_tech_color")
+            }
+            "hidden_tech_color" => {
+                value.write_to(&mut self.r#hidden_tech_color, "hidden_tech_color")
+            }
+            "credits_color" => value.write_to(&mut self.r#credits_color, "credits_color"),
+            "stars_color" => value.write_to(&mut self.r#stars_color, "stars_color"),
+            "money_color" => value.write_to(&mut self.r#money_color, "money_color"),
+            "fuel_color" => value.write_to(&mut self.r#fuel_color, "fuel_color"),
+            "tokens_color" => value.write_to(&mut self.r#tokens_color, "tokens_color"),
+            "main_menu_background_image" => value.write_to(
+                &mut self.r#main_menu_background_image,
+                "main_menu_background_image",
+            ),
+            "no_credits_text" => value.write_to(&mut self.r#no_credits_text, "no_credits_text"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl Default for UiSettings {
     fn default() -> Self {
         Self::new()
@@ -25855,6 +38770,7 @@ pub type BehaviorTreeId = DatabaseItemId<BehaviorTree>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct BehaviorTree {
+    /// - Options: `notnull`
     pub r#id: BehaviorTreeId,
     #[serde(default)]
     pub r#root_node: BehaviorTreeNode,
@@ -25894,11 +38810,44 @@ impl DatabaseItem for BehaviorTree {
         "BehaviorTree"
     }
 }
+impl VisitIds for BehaviorTree {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#root_node.visit_ids(visitor);
+    }
+}
+impl VisitAssets for BehaviorTree {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#root_node.visit_assets(visitor);
+    }
+}
+impl DynamicFields for BehaviorTree {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "root_node"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "root_node" => Some(FieldValue::of(&self.r#root_node)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "root_node" => value.write_to(&mut self.r#root_node, "root_node"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for BehaviorTree {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/AmmunitionObsolete.xml
@@ -25906,50 +38855,83 @@ pub type AmmunitionObsoleteId = DatabaseItemId<AmmunitionObsolete>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct AmmunitionObsolete {
+    /// - Options: `notnull`
     pub r#id: AmmunitionObsoleteId,
     #[serde(default)]
     pub r#ammunition_class: AmmunitionClassObsolete,
     #[serde(default)]
     pub r#damage_type: DamageType,
+    /// - Minimum: `0`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#impulse: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#recoil: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#size: f32,
     #[serde(default)]
     #[serde(with = "crate::helpers::glam_ser")]
     pub r#initial_position: glam::f32::Vec2,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#area_of_effect: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#damage: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#range: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#velocity: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#life_time: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#hit_points: i32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#ignores_ship_velocity: bool,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#energy_cost: f32,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#coupled_ammunition_id: Option<AmmunitionObsoleteId>,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#color: String,
@@ -26134,6 +39116,10 @@ impl AmmunitionObsolete {
         self.r#coupled_ammunition_id = r#coupled_ammunition_id.into();
         self
     }
+    pub fn clear_coupled_ammunition_id(&mut self) -> &mut Self {
+        self.r#coupled_ammunition_id = None;
+        self
+    }
     pub fn with_color(mut self, r#color: impl Into<String>) -> Self {
         self.r#color = r#color.into();
         self
@@ -26267,11 +39253,123 @@ impl DatabaseItem for AmmunitionObsolete {
                 ctx.emit(DiagnosticKind::too_large(1000000000f32, self.r#energy_cost));
             }
         }
+        {
+            let mut ctx = ctx.enter("color");
+            if !crate::color::Color::is_valid(&self.r#color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#color));
+            }
+        }
     }
     fn type_name() -> &'static str {
         "AmmunitionObsolete"
     }
 }
+impl VisitIds for AmmunitionObsolete {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#coupled_ammunition_id.visit_ids(visitor);
+    }
+}
+impl VisitAssets for AmmunitionObsolete {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#fire_sound.is_empty() {
+            visitor(AssetKind::AudioClip, &self.r#fire_sound);
+        }
+        if !self.r#hit_sound.is_empty() {
+            visitor(AssetKind::AudioClip, &self.r#hit_sound);
+        }
+        if !self.r#hit_effect_prefab.is_empty() {
+            visitor(AssetKind::Prefab, &self.r#hit_effect_prefab);
+        }
+        if !self.r#bullet_prefab.is_empty() {
+            visitor(AssetKind::Prefab, &self.r#bullet_prefab);
+        }
+    }
+}
+impl DynamicFields for AmmunitionObsolete {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "ammunition_class",
+            "damage_type",
+            "impulse",
+            "recoil",
+            "size",
+            "initial_position",
+            "area_of_effect",
+            "damage",
+            "range",
+            "velocity",
+            "life_time",
+            "hit_points",
+            "ignores_ship_velocity",
+            "energy_cost",
+            "coupled_ammunition_id",
+            "color",
+            "fire_sound",
+            "hit_sound",
+            "hit_effect_prefab",
+            "bullet_prefab",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "ammunition_class" => Some(FieldValue::of(&self.r#ammunition_class)),
+            "damage_type" => Some(FieldValue::of(&self.r#damage_type)),
+            "impulse" => Some(FieldValue::of(&self.r#impulse)),
+            "recoil" => Some(FieldValue::of(&self.r#recoil)),
+            "size" => Some(FieldValue::of(&self.r#size)),
+            "initial_position" => Some(FieldValue::of(&self.r#initial_position)),
+            "area_of_effect" => Some(FieldValue::of(&self.r#area_of_effect)),
+            "damage" => Some(FieldValue::of(&self.r#damage)),
+            "range" => Some(FieldValue::of(&self.r#range)),
+            "velocity" => Some(FieldValue::of(&self.r#velocity)),
+            "life_time" => Some(FieldValue::of(&self.r#life_time)),
+            "hit_points" => Some(FieldValue::of(&self.r#hit_points)),
+            "ignores_ship_velocity" => Some(FieldValue::of(&self.r#ignores_ship_velocity)),
+            "energy_cost" => Some(FieldValue::of(&self.r#energy_cost)),
+            "coupled_ammunition_id" => Some(FieldValue::of(&self.r#coupled_ammunition_id)),
+            "color" => Some(FieldValue::of(&self.r#color)),
+            "fire_sound" => Some(FieldValue::of(&self.r#fire_sound)),
+            "hit_sound" => Some(FieldValue::of(&self.r#hit_sound)),
+            "hit_effect_prefab" => Some(FieldValue::of(&self.r#hit_effect_prefab)),
+            "bullet_prefab" => Some(FieldValue::of(&self.r#bullet_prefab)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "ammunition_class" => value.write_to(&mut self.r#ammunition_class, "ammunition_class"),
+            "damage_type" => value.write_to(&mut self.r#damage_type, "damage_type"),
+            "impulse" => value.write_to(&mut self.r#impulse, "impulse"),
+            "recoil" => value.write_to(&mut self.r#recoil, "recoil"),
+            "size" => value.write_to(&mut self.r#size, "size"),
+            "initial_position" => value.write_to(&mut self.r#initial_position, "initial_position"),
+            "area_of_effect" => value.write_to(&mut self.r#area_of_effect, "area_of_effect"),
+            "damage" => value.write_to(&mut self.r#damage, "damage"),
+            "range" => value.write_to(&mut self.r#range, "range"),
+            "velocity" => value.write_to(&mut self.r#velocity, "velocity"),
+            "life_time" => value.write_to(&mut self.r#life_time, "life_time"),
+            "hit_points" => value.write_to(&mut self.r#hit_points, "hit_points"),
+            "ignores_ship_velocity" => {
+                value.write_to(&mut self.r#ignores_ship_velocity, "ignores_ship_velocity")
+            }
+            "energy_cost" => value.write_to(&mut self.r#energy_cost, "energy_cost"),
+            "coupled_ammunition_id" => {
+                value.write_to(&mut self.r#coupled_ammunition_id, "coupled_ammunition_id")
+            }
+            "color" => value.write_to(&mut self.r#color, "color"),
+            "fire_sound" => value.write_to(&mut self.r#fire_sound, "fire_sound"),
+            "hit_sound" => value.write_to(&mut self.r#hit_sound, "hit_sound"),
+            "hit_effect_prefab" => {
+                value.write_to(&mut self.r#hit_effect_prefab, "hit_effect_prefab")
+            }
+            "bullet_prefab" => value.write_to(&mut self.r#bullet_prefab, "bullet_prefab"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for AmmunitionObsolete {}
 impl std::cmp::PartialEq for AmmunitionObsolete {
     fn eq(&self, other: &Self) -> bool {
@@ -26340,6 +39438,10 @@ impl DatabaseItemWithId for AmmunitionObsolete {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Component.xml
@@ -26347,6 +39449,7 @@ pub type ComponentId = DatabaseItemId<Component>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct Component {
+    /// - Options: `notnull`
     pub r#id: ComponentId,
     #[serde(default)]
     pub r#name: String,
@@ -26356,20 +39459,25 @@ pub struct Component {
     pub r#display_category: ComponentCategory,
     #[serde(default)]
     pub r#availability: Availability,
+    /// - Options: `notnull`
     pub r#component_stats_id: ComponentStatsId,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#faction: Option<FactionId>,
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#level: i32,
     #[serde(default)]
     pub r#icon: String,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#color: String,
     #[serde(default)]
-    pub r#layout: String,
+    pub r#layout: crate::layout::Layout,
+    /// - Options: `obsolete`
     #[serde(default)]
     pub r#cell_type: String,
     #[serde(default)]
@@ -26381,6 +39489,7 @@ pub struct Component {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#ammunition_id: Option<AmmunitionId>,
+    /// - Options: `obsolete`
     #[serde(default)]
     pub r#weapon_slot_type: String,
     #[serde(default)]
@@ -26487,6 +39596,10 @@ impl Component {
         self.r#faction = r#faction.into();
         self
     }
+    pub fn clear_faction(&mut self) -> &mut Self {
+        self.r#faction = None;
+        self
+    }
     pub fn with_level(mut self, r#level: impl Into<i32>) -> Self {
         self.r#level = r#level.into();
         self
@@ -26511,11 +39624,11 @@ impl Component {
         self.r#color = r#color.into();
         self
     }
-    pub fn with_layout(mut self, r#layout: impl Into<String>) -> Self {
+    pub fn with_layout(mut self, r#layout: impl Into<crate::layout::Layout>) -> Self {
         self.r#layout = r#layout.into();
         self
     }
-    pub fn set_layout(&mut self, r#layout: impl Into<String>) -> &mut Self {
+    pub fn set_layout(&mut self, r#layout: impl Into<crate::layout::Layout>) -> &mut Self {
         self.r#layout = r#layout.into();
         self
     }
@@ -26535,6 +39648,10 @@ impl Component {
         self.r#device_id = r#device_id.into();
         self
     }
+    pub fn clear_device_id(&mut self) -> &mut Self {
+        self.r#device_id = None;
+        self
+    }
     pub fn with_weapon_id(mut self, r#weapon_id: impl Into<Option<WeaponId>>) -> Self {
         self.r#weapon_id = r#weapon_id.into();
         self
@@ -26543,6 +39660,10 @@ impl Component {
         self.r#weapon_id = r#weapon_id.into();
         self
     }
+    pub fn clear_weapon_id(&mut self) -> &mut Self {
+        self.r#weapon_id = None;
+        self
+    }
     pub fn with_ammunition_id(mut self, r#ammunition_id: impl Into<Option<AmmunitionId>>) -> Self {
         self.r#ammunition_id = r#ammunition_id.into();
         self
@@ -26554,6 +39675,10 @@ impl Component {
         self.r#ammunition_id = r#ammunition_id.into();
         self
     }
+    pub fn clear_ammunition_id(&mut self) -> &mut Self {
+        self.r#ammunition_id = None;
+        self
+    }
     pub fn with_weapon_slot_type(mut self, r#weapon_slot_type: impl Into<String>) -> Self {
         self.r#weapon_slot_type = r#weapon_slot_type.into();
         self
@@ -26570,6 +39695,10 @@ impl Component {
         self.r#drone_bay_id = r#drone_bay_id.into();
         self
     }
+    pub fn clear_drone_bay_id(&mut self) -> &mut Self {
+        self.r#drone_bay_id = None;
+        self
+    }
     pub fn with_drone_id(mut self, r#drone_id: impl Into<Option<ShipBuildId>>) -> Self {
         self.r#drone_id = r#drone_id.into();
         self
@@ -26578,6 +39707,10 @@ impl Component {
         self.r#drone_id = r#drone_id.into();
         self
     }
+    pub fn clear_drone_id(&mut self) -> &mut Self {
+        self.r#drone_id = None;
+        self
+    }
     pub fn with_restrictions(mut self, r#restrictions: impl Into<ComponentRestrictions>) -> Self {
         self.r#restrictions = r#restrictions.into();
         self
@@ -26613,9 +39746,9 @@ impl DatabaseItem for Component {
             }
         }
         {
-            let mut ctx = ctx.enter("layout");
-            if (self.r#layout.len() as f32).sqrt().floor().powi(2) != (self.r#layout.len() as f32) {
-                ctx.emit(DiagnosticKind::layout_not_square(self.r#layout.len()));
+            let mut ctx = ctx.enter("color");
+            if !crate::color::Color::is_valid(&self.r#color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#color));
             }
         }
         {
@@ -26641,11 +39774,116 @@ impl DatabaseItem for Component {
         "Component"
     }
 }
+impl VisitIds for Component {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#component_stats_id.visit_ids(visitor);
+        self.r#faction.visit_ids(visitor);
+        self.r#device_id.visit_ids(visitor);
+        self.r#weapon_id.visit_ids(visitor);
+        self.r#ammunition_id.visit_ids(visitor);
+        self.r#drone_bay_id.visit_ids(visitor);
+        self.r#drone_id.visit_ids(visitor);
+        self.r#restrictions.visit_ids(visitor);
+        self.r#possible_modifications.visit_ids(visitor);
+    }
+}
+impl VisitAssets for Component {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#icon.is_empty() {
+            visitor(AssetKind::Image, &self.r#icon);
+        }
+        self.r#restrictions.visit_assets(visitor);
+    }
+}
+impl DynamicFields for Component {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "name",
+            "description",
+            "display_category",
+            "availability",
+            "component_stats_id",
+            "faction",
+            "level",
+            "icon",
+            "color",
+            "layout",
+            "cell_type",
+            "device_id",
+            "weapon_id",
+            "ammunition_id",
+            "weapon_slot_type",
+            "drone_bay_id",
+            "drone_id",
+            "restrictions",
+            "possible_modifications",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "name" => Some(FieldValue::of(&self.r#name)),
+            "description" => Some(FieldValue::of(&self.r#description)),
+            "display_category" => Some(FieldValue::of(&self.r#display_category)),
+            "availability" => Some(FieldValue::of(&self.r#availability)),
+            "component_stats_id" => Some(FieldValue::of(&self.r#component_stats_id)),
+            "faction" => Some(FieldValue::of(&self.r#faction)),
+            "level" => Some(FieldValue::of(&self.r#level)),
+            "icon" => Some(FieldValue::of(&self.r#icon)),
+            "color" => Some(FieldValue::of(&self.r#color)),
+            "layout" => Some(FieldValue::of(&self.r#layout)),
+            "cell_type" => Some(FieldValue::of(&self.r#cell_type)),
+            "device_id" => Some(FieldValue::of(&self.r#device_id)),
+            "weapon_id" => Some(FieldValue::of(&self.r#weapon_id)),
+            "ammunition_id" => Some(FieldValue::of(&self.r#ammunition_id)),
+            "weapon_slot_type" => Some(FieldValue::of(&self.r#weapon_slot_type)),
+            "drone_bay_id" => Some(FieldValue::of(&self.r#drone_bay_id)),
+            "drone_id" => Some(FieldValue::of(&self.r#drone_id)),
+            "restrictions" => Some(FieldValue::of(&self.r#restrictions)),
+            "possible_modifications" => Some(FieldValue::of(&self.r#possible_modifications)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "name" => value.write_to(&mut self.r#name, "name"),
+            "description" => value.write_to(&mut self.r#description, "description"),
+            "display_category" => value.write_to(&mut self.r#display_category, "display_category"),
+            "availability" => value.write_to(&mut self.r#availability, "availability"),
+            "component_stats_id" => {
+                value.write_to(&mut self.r#component_stats_id, "component_stats_id")
+            }
+            "faction" => value.write_to(&mut self.r#faction, "faction"),
+            "level" => value.write_to(&mut self.r#level, "level"),
+            "icon" => value.write_to(&mut self.r#icon, "icon"),
+            "color" => value.write_to(&mut self.r#color, "color"),
+            "layout" => value.write_to(&mut self.r#layout, "layout"),
+            "cell_type" => value.write_to(&mut self.r#cell_type, "cell_type"),
+            "device_id" => value.write_to(&mut self.r#device_id, "device_id"),
+            "weapon_id" => value.write_to(&mut self.r#weapon_id, "weapon_id"),
+            "ammunition_id" => value.write_to(&mut self.r#ammunition_id, "ammunition_id"),
+            "weapon_slot_type" => value.write_to(&mut self.r#weapon_slot_type, "weapon_slot_type"),
+            "drone_bay_id" => value.write_to(&mut self.r#drone_bay_id, "drone_bay_id"),
+            "drone_id" => value.write_to(&mut self.r#drone_id, "drone_id"),
+            "restrictions" => value.write_to(&mut self.r#restrictions, "restrictions"),
+            "possible_modifications" => {
+                value.write_to(&mut self.r#possible_modifications, "possible_modifications")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for Component {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/ComponentMod.xml
@@ -26653,6 +39891,7 @@ pub type ComponentModId = DatabaseItemId<ComponentMod>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct ComponentMod {
+    /// - Options: `notnull`
     pub r#id: ComponentModId,
     #[serde(default)]
     pub r#description: String,
@@ -26709,11 +39948,46 @@ impl DatabaseItem for ComponentMod {
         "ComponentMod"
     }
 }
+impl VisitIds for ComponentMod {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#modifications.visit_ids(visitor);
+    }
+}
+impl VisitAssets for ComponentMod {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#modifications.visit_assets(visitor);
+    }
+}
+impl DynamicFields for ComponentMod {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "description", "modifications"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "description" => Some(FieldValue::of(&self.r#description)),
+            "modifications" => Some(FieldValue::of(&self.r#modifications)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "description" => value.write_to(&mut self.r#description, "description"),
+            "modifications" => value.write_to(&mut self.r#modifications, "modifications"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for ComponentMod {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/ComponentStatUpgrade.xml
@@ -26721,6 +39995,7 @@ pub type ComponentStatUpgradeId = DatabaseItemId<ComponentStatUpgrade>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct ComponentStatUpgrade {
+    /// - Options: `notnull`
     pub r#id: ComponentStatUpgradeId,
 }
 impl ComponentStatUpgrade {
@@ -26742,11 +40017,38 @@ impl DatabaseItem for ComponentStatUpgrade {
         "ComponentStatUpgrade"
     }
 }
+impl VisitIds for ComponentStatUpgrade {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for ComponentStatUpgrade {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for ComponentStatUpgrade {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for ComponentStatUpgrade {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/ComponentStats.xml
@@ -26754,99 +40056,190 @@ pub type ComponentStatsId = DatabaseItemId<ComponentStats>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct ComponentStats {
+    /// - Options: `notnull`
     pub r#id: ComponentStatsId,
     #[serde(default)]
     pub r#type: ComponentStatsType,
+    /// - Minimum: `-1000000`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#armor_points: f32,
+    /// - Minimum: `-1000000`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#armor_repair_rate: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#armor_repair_cooldown_modifier: f32,
+    /// - Minimum: `-1000000`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#energy_points: f32,
+    /// - Minimum: `-1000000`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#energy_recharge_rate: f32,
+    /// - Minimum: `-5`
+    /// - Maximum: `5`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#energy_recharge_cooldown_modifier: f32,
+    /// - Minimum: `-1000000`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#shield_points: f32,
+    /// - Minimum: `-1000000`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#shield_recharge_rate: f32,
+    /// - Minimum: `-5`
+    /// - Maximum: `5`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#shield_recharge_cooldown_modifier: f32,
+    /// - Minimum: `-1000000`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#weight: f32,
+    /// - Minimum: `-1000000`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#ramming_damage: f32,
+    /// - Minimum: `-1000000`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#energy_absorption: f32,
+    /// - Minimum: `-1000000`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#kinetic_resistance: f32,
+    /// - Minimum: `-1000000`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#energy_resistance: f32,
+    /// - Minimum: `-1000000`
+    /// - Maximum: `1000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#thermal_resistance: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `2000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#engine_power: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `2000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#turn_rate: f32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#autopilot: bool,
+    /// - Minimum: `-50`
+    /// - Maximum: `50`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#drone_range_modifier: f32,
+    /// - Minimum: `-50`
+    /// - Maximum: `50`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#drone_damage_modifier: f32,
+    /// - Minimum: `-50`
+    /// - Maximum: `50`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#drone_defense_modifier: f32,
+    /// - Minimum: `-50`
+    /// - Maximum: `50`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#drone_speed_modifier: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#drones_built_per_second: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#drone_build_time_modifier: f32,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#weapon_fire_rate_modifier: f32,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#weapon_damage_modifier: f32,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#weapon_range_modifier: f32,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#weapon_energy_cost_modifier: f32,
+    /// - Default: `0`
+    /// - Options: `obsolete`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#alter_weapon_platform: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `360`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#auto_aiming_arc: f32,
+    /// - Minimum: `-1000`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#turret_turn_speed: f32,
@@ -27624,6 +41017,186 @@ impl DatabaseItem for ComponentStats {
         "ComponentStats"
     }
 }
+impl VisitIds for ComponentStats {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for ComponentStats {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for ComponentStats {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "type",
+            "armor_points",
+            "armor_repair_rate",
+            "armor_repair_cooldown_modifier",
+            "energy_points",
+            "energy_recharge_rate",
+            "energy_recharge_cooldown_modifier",
+            "shield_points",
+            "shield_recharge_rate",
+            "shield_recharge_cooldown_modifier",
+            "weight",
+            "ramming_damage",
+            "energy_absorption",
+            "kinetic_resistance",
+            "energy_resistance",
+            "thermal_resistance",
+            "engine_power",
+            "turn_rate",
+            "autopilot",
+            "drone_range_modifier",
+            "drone_damage_modifier",
+            "drone_defense_modifier",
+            "drone_speed_modifier",
+            "drones_built_per_second",
+            "drone_build_time_modifier",
+            "weapon_fire_rate_modifier",
+            "weapon_damage_modifier",
+            "weapon_range_modifier",
+            "weapon_energy_cost_modifier",
+            "alter_weapon_platform",
+            "auto_aiming_arc",
+            "turret_turn_speed",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "type" => Some(FieldValue::of(&self.r#type)),
+            "armor_points" => Some(FieldValue::of(&self.r#armor_points)),
+            "armor_repair_rate" => Some(FieldValue::of(&self.r#armor_repair_rate)),
+            "armor_repair_cooldown_modifier" => {
+                Some(FieldValue::of(&self.r#armor_repair_cooldown_modifier))
+            }
+            "energy_points" => Some(FieldValue::of(&self.r#energy_points)),
+            "energy_recharge_rate" => Some(FieldValue::of(&self.r#energy_recharge_rate)),
+            "energy_recharge_cooldown_modifier" => {
+                Some(FieldValue::of(&self.r#energy_recharge_cooldown_modifier))
+            }
+            "shield_points" => Some(FieldValue::of(&self.r#shield_points)),
+            "shield_recharge_rate" => Some(FieldValue::of(&self.r#shield_recharge_rate)),
+            "shield_recharge_cooldown_modifier" => {
+                Some(FieldValue::of(&self.r#shield_recharge_cooldown_modifier))
+            }
+            "weight" => Some(FieldValue::of(&self.r#weight)),
+            "ramming_damage" => Some(FieldValue::of(&self.r#ramming_damage)),
+            "energy_absorption" => Some(FieldValue::of(&self.r#energy_absorption)),
+            "kinetic_resistance" => Some(FieldValue::of(&self.r#kinetic_resistance)),
+            "energy_resistance" => Some(FieldValue::of(&self.r#energy_resistance)),
+            "thermal_resistance" => Some(FieldValue::of(&self.r#thermal_resistance)),
+            "engine_power" => Some(FieldValue::of(&self.r#engine_power)),
+            "turn_rate" => Some(FieldValue::of(&self.r#turn_rate)),
+            "autopilot" => Some(FieldValue::of(&self.r#autopilot)),
+            "drone_range_modifier" => Some(FieldValue::of(&self.r#drone_range_modifier)),
+            "drone_damage_modifier" => Some(FieldValue::of(&self.r#drone_damage_modifier)),
+            "drone_defense_modifier" => Some(FieldValue::of(&self.r#drone_defense_modifier)),
+            "drone_speed_modifier" => Some(FieldValue::of(&self.r#drone_speed_modifier)),
+            "drones_built_per_second" => Some(FieldValue::of(&self.r#drones_built_per_second)),
+            "drone_build_time_modifier" => Some(FieldValue::of(&self.r#drone_build_time_modifier)),
+            "weapon_fire_rate_modifier" => Some(FieldValue::of(&self.r#weapon_fire_rate_modifier)),
+            "weapon_damage_modifier" => Some(FieldValue::of(&self.r#weapon_damage_modifier)),
+            "weapon_range_modifier" => Some(FieldValue::of(&self.r#weapon_range_modifier)),
+            "weapon_energy_cost_modifier" => {
+                Some(FieldValue::of(&self.r#weapon_energy_cost_modifier))
+            }
+            "alter_weapon_platform" => Some(FieldValue::of(&self.r#alter_weapon_platform)),
+            "auto_aiming_arc" => Some(FieldValue::of(&self.r#auto_aiming_arc)),
+            "turret_turn_speed" => Some(FieldValue::of(&self.r#turret_turn_speed)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "type" => value.write_to(&mut self.r#type, "type"),
+            "armor_points" => value.write_to(&mut self.r#armor_points, "armor_points"),
+            "armor_repair_rate" => {
+                value.write_to(&mut self.r#armor_repair_rate, "armor_repair_rate")
+            }
+            "armor_repair_cooldown_modifier" => value.write_to(
+                &mut self.r#armor_repair_cooldown_modifier,
+                "armor_repair_cooldown_modifier",
+            ),
+            "energy_points" => value.write_to(&mut self.r#energy_points, "energy_points"),
+            "energy_recharge_rate" => {
+                value.write_to(&mut self.r#energy_recharge_rate, "energy_recharge_rate")
+            }
+            "energy_recharge_cooldown_modifier" => value.write_to(
+                &mut self.r#energy_recharge_cooldown_modifier,
+                "energy_recharge_cooldown_modifier",
+            ),
+            "shield_points" => value.write_to(&mut self.r#shield_points, "shield_points"),
+            "shield_recharge_rate" => {
+                value.write_to(&mut self.r#shield_recharge_rate, "shield_recharge_rate")
+            }
+            "shield_recharge_cooldown_modifier" => value.write_to(
+                &mut self.r#shield_recharge_cooldown_modifier,
+                "shield_recharge_cooldown_modifier",
+            ),
+            "weight" => value.write_to(&mut self.r#weight, "weight"),
+            "ramming_damage" => value.write_to(&mut self.r#ramming_damage, "ramming_damage"),
+            "energy_absorption" => {
+                value.write_to(&mut self.r#energy_absorption, "energy_absorption")
+            }
+            "kinetic_resistance" => {
+                value.write_to(&mut self.r#kinetic_resistance, "kinetic_resistance")
+            }
+            "energy_resistance" => {
+                value.write_to(&mut self.r#energy_resistance, "energy_resistance")
+            }
+            "thermal_resistance" => {
+                value.write_to(&mut self.r#thermal_resistance, "thermal_resistance")
+            }
+            "engine_power" => value.write_to(&mut self.r#engine_power, "engine_power"),
+            "turn_rate" => value.write_to(&mut self.r#turn_rate, "turn_rate"),
+            "autopilot" => value.write_to(&mut self.r#autopilot, "autopilot"),
+            "drone_range_modifier" => {
+                value.write_to(&mut self.r#drone_range_modifier, "drone_range_modifier")
+            }
+            "drone_damage_modifier" => {
+                value.write_to(&mut self.r#drone_damage_modifier, "drone_damage_modifier")
+            }
+            "drone_defense_modifier" => {
+                value.write_to(&mut self.r#drone_defense_modifier, "drone_defense_modifier")
+            }
+            "drone_speed_modifier" => {
+                value.write_to(&mut self.r#drone_speed_modifier, "drone_speed_modifier")
+            }
+            "drones_built_per_second" => value.write_to(
+                &mut self.r#drones_built_per_second,
+                "drones_built_per_second",
+            ),
+            "drone_build_time_modifier" => value.write_to(
+                &mut self.r#drone_build_time_modifier,
+                "drone_build_time_modifier",
+            ),
+            "weapon_fire_rate_modifier" => value.write_to(
+                &mut self.r#weapon_fire_rate_modifier,
+                "weapon_fire_rate_modifier",
+            ),
+            "weapon_damage_modifier" => {
+                value.write_to(&mut self.r#weapon_damage_modifier, "weapon_damage_modifier")
+            }
+            "weapon_range_modifier" => {
+                value.write_to(&mut self.r#weapon_range_modifier, "weapon_range_modifier")
+            }
+            "weapon_energy_cost_modifier" => value.write_to(
+                &mut self.r#weapon_energy_cost_modifier,
+                "weapon_energy_cost_modifier",
+            ),
+            "alter_weapon_platform" => {
+                value.write_to(&mut self.r#alter_weapon_platform, "alter_weapon_platform")
+            }
+            "auto_aiming_arc" => value.write_to(&mut self.r#auto_aiming_arc, "auto_aiming_arc"),
+            "turret_turn_speed" => {
+                value.write_to(&mut self.r#turret_turn_speed, "turret_turn_speed")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for ComponentStats {}
 impl std::cmp::PartialEq for ComponentStats {
     fn eq(&self, other: &Self) -> bool {
@@ -27733,6 +41306,10 @@ impl DatabaseItemWithId for ComponentStats {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Device.xml
@@ -27740,30 +41317,53 @@ pub type DeviceId = DatabaseItemId<Device>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Device {
+    /// - Options: `notnull`
     pub r#id: DeviceId,
     #[serde(default)]
     pub r#device_class: DeviceClass,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#energy_consumption: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#passive_energy_consumption: f32,
+    /// - Default: `true`
     #[serde(default = "default_true")]
     #[serde(skip_serializing_if = "skip_if_true")]
     pub r#scale_energy_with_ship_size: bool,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#power: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#range: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#size: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#cooldown: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#lifetime: f32,
@@ -27772,6 +41372,7 @@ pub struct Device {
     pub r#offset: glam::f32::Vec2,
     #[serde(default)]
     pub r#activation_type: ActivationType,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#color: String,
@@ -27963,6 +41564,10 @@ impl Device {
         self.r#visual_effect = r#visual_effect.into();
         self
     }
+    pub fn clear_visual_effect(&mut self) -> &mut Self {
+        self.r#visual_effect = None;
+        self
+    }
     pub fn with_object_prefab(mut self, r#object_prefab: impl Into<String>) -> Self {
         self.r#object_prefab = r#object_prefab.into();
         self
@@ -27979,6 +41584,10 @@ impl Device {
         self.r#prefab = r#prefab.into();
         self
     }
+    pub fn clear_prefab(&mut self) -> &mut Self {
+        self.r#prefab = None;
+        self
+    }
     pub fn with_control_button_icon(mut self, r#control_button_icon: impl Into<String>) -> Self {
         self.r#control_button_icon = r#control_button_icon.into();
         self
@@ -28065,11 +41674,126 @@ impl DatabaseItem for Device {
                 ctx.emit(DiagnosticKind::too_large(1000f32, self.r#lifetime));
             }
         }
+        {
+            let mut ctx = ctx.enter("color");
+            if !crate::color::Color::is_valid(&self.r#color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#color));
+            }
+        }
     }
     fn type_name() -> &'static str {
         "Device"
     }
 }
+impl VisitIds for Device {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#visual_effect.visit_ids(visitor);
+        self.r#prefab.visit_ids(visitor);
+    }
+}
+impl VisitAssets for Device {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#sound.is_empty() {
+            visitor(AssetKind::AudioClip, &self.r#sound);
+        }
+        if !self.r#effect_prefab.is_empty() {
+            visitor(AssetKind::Prefab, &self.r#effect_prefab);
+        }
+        if !self.r#object_prefab.is_empty() {
+            visitor(AssetKind::Prefab, &self.r#object_prefab);
+        }
+        if !self.r#control_button_icon.is_empty() {
+            visitor(AssetKind::Image, &self.r#control_button_icon);
+        }
+    }
+}
+impl DynamicFields for Device {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "device_class",
+            "energy_consumption",
+            "passive_energy_consumption",
+            "scale_energy_with_ship_size",
+            "power",
+            "range",
+            "size",
+            "cooldown",
+            "lifetime",
+            "offset",
+            "activation_type",
+            "color",
+            "sound",
+            "effect_prefab",
+            "visual_effect",
+            "object_prefab",
+            "prefab",
+            "control_button_icon",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "device_class" => Some(FieldValue::of(&self.r#device_class)),
+            "energy_consumption" => Some(FieldValue::of(&self.r#energy_consumption)),
+            "passive_energy_consumption" => {
+                Some(FieldValue::of(&self.r#passive_energy_consumption))
+            }
+            "scale_energy_with_ship_size" => {
+                Some(FieldValue::of(&self.r#scale_energy_with_ship_size))
+            }
+            "power" => Some(FieldValue::of(&self.r#power)),
+            "range" => Some(FieldValue::of(&self.r#range)),
+            "size" => Some(FieldValue::of(&self.r#size)),
+            "cooldown" => Some(FieldValue::of(&self.r#cooldown)),
+            "lifetime" => Some(FieldValue::of(&self.r#lifetime)),
+            "offset" => Some(FieldValue::of(&self.r#offset)),
+            "activation_type" => Some(FieldValue::of(&self.r#activation_type)),
+            "color" => Some(FieldValue::of(&self.r#color)),
+            "sound" => Some(FieldValue::of(&self.r#sound)),
+            "effect_prefab" => Some(FieldValue::of(&self.r#effect_prefab)),
+            "visual_effect" => Some(FieldValue::of(&self.r#visual_effect)),
+            "object_prefab" => Some(FieldValue::of(&self.r#object_prefab)),
+            "prefab" => Some(FieldValue::of(&self.r#prefab)),
+            "control_button_icon" => Some(FieldValue::of(&self.r#control_button_icon)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "device_class" => value.write_to(&mut self.r#device_class, "device_class"),
+            "energy_consumption" => {
+                value.write_to(&mut self.r#energy_consumption, "energy_consumption")
+            }
+            "passive_energy_consumption" => value.write_to(
+                &mut self.r#passive_energy_consumption,
+                "passive_energy_consumption",
+            ),
+            "scale_energy_with_ship_size" => value.write_to(
+                &mut self.r#scale_energy_with_ship_size,
+                "scale_energy_with_ship_size",
+            ),
+            "power" => value.write_to(&mut self.r#power, "power"),
+            "range" => value.write_to(&mut self.r#range, "range"),
+            "size" => value.write_to(&mut self.r#size, "size"),
+            "cooldown" => value.write_to(&mut self.r#cooldown, "cooldown"),
+            "lifetime" => value.write_to(&mut self.r#lifetime, "lifetime"),
+            "offset" => value.write_to(&mut self.r#offset, "offset"),
+            "activation_type" => value.write_to(&mut self.r#activation_type, "activation_type"),
+            "color" => value.write_to(&mut self.r#color, "color"),
+            "sound" => value.write_to(&mut self.r#sound, "sound"),
+            "effect_prefab" => value.write_to(&mut self.r#effect_prefab, "effect_prefab"),
+            "visual_effect" => value.write_to(&mut self.r#visual_effect, "visual_effect"),
+            "object_prefab" => value.write_to(&mut self.r#object_prefab, "object_prefab"),
+            "prefab" => value.write_to(&mut self.r#prefab, "prefab"),
+            "control_button_icon" => {
+                value.write_to(&mut self.r#control_button_icon, "control_button_icon")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for Device {}
 impl std::cmp::PartialEq for Device {
     fn eq(&self, other: &Self) -> bool {
@@ -28132,6 +41856,10 @@ impl DatabaseItemWithId for Device {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/DroneBay.xml
@@ -28139,31 +41867,58 @@ pub type DroneBayId = DatabaseItemId<DroneBay>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct DroneBay {
+    /// - Options: `notnull`
     pub r#id: DroneBayId,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#energy_consumption: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#passive_energy_consumption: f32,
+    /// - Minimum: `1`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#range: f32,
+    /// - Minimum: `0.01`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#damage_multiplier: f32,
+    /// - Minimum: `0.01`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#defense_multiplier: f32,
+    /// - Minimum: `0.01`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#speed_multiplier: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#build_extra_cycles: i32,
+    /// - Default: `false`
+    /// - Options: `obsolete`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#improved_ai: bool,
+    /// - Minimum: `1`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#capacity: i32,
@@ -28344,6 +42099,10 @@ impl DroneBay {
         self.r#defensive_drone_ai = r#defensive_drone_ai.into();
         self
     }
+    pub fn clear_defensive_drone_ai(&mut self) -> &mut Self {
+        self.r#defensive_drone_ai = None;
+        self
+    }
     pub fn with_offensive_drone_ai(
         mut self,
         r#offensive_drone_ai: impl Into<Option<BehaviorTreeId>>,
@@ -28358,6 +42117,10 @@ impl DroneBay {
         self.r#offensive_drone_ai = r#offensive_drone_ai.into();
         self
     }
+    pub fn clear_offensive_drone_ai(&mut self) -> &mut Self {
+        self.r#offensive_drone_ai = None;
+        self
+    }
 }
 impl DatabaseItem for DroneBay {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
@@ -28460,6 +42223,110 @@ impl DatabaseItem for DroneBay {
         "DroneBay"
     }
 }
+impl VisitIds for DroneBay {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#defensive_drone_ai.visit_ids(visitor);
+        self.r#offensive_drone_ai.visit_ids(visitor);
+    }
+}
+impl VisitAssets for DroneBay {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#launch_sound.is_empty() {
+            visitor(AssetKind::AudioClip, &self.r#launch_sound);
+        }
+        if !self.r#launch_effect_prefab.is_empty() {
+            visitor(AssetKind::Prefab, &self.r#launch_effect_prefab);
+        }
+        if !self.r#control_button_icon.is_empty() {
+            visitor(AssetKind::Image, &self.r#control_button_icon);
+        }
+    }
+}
+impl DynamicFields for DroneBay {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "energy_consumption",
+            "passive_energy_consumption",
+            "range",
+            "damage_multiplier",
+            "defense_multiplier",
+            "speed_multiplier",
+            "build_extra_cycles",
+            "improved_ai",
+            "capacity",
+            "activation_type",
+            "launch_sound",
+            "launch_effect_prefab",
+            "control_button_icon",
+            "defensive_drone_ai",
+            "offensive_drone_ai",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "energy_consumption" => Some(FieldValue::of(&self.r#energy_consumption)),
+            "passive_energy_consumption" => {
+                Some(FieldValue::of(&self.r#passive_energy_consumption))
+            }
+            "range" => Some(FieldValue::of(&self.r#range)),
+            "damage_multiplier" => Some(FieldValue::of(&self.r#damage_multiplier)),
+            "defense_multiplier" => Some(FieldValue::of(&self.r#defense_multiplier)),
+            "speed_multiplier" => Some(FieldValue::of(&self.r#speed_multiplier)),
+            "build_extra_cycles" => Some(FieldValue::of(&self.r#build_extra_cycles)),
+            "improved_ai" => Some(FieldValue::of(&self.r#improved_ai)),
+            "capacity" => Some(FieldValue::of(&self.r#capacity)),
+            "activation_type" => Some(FieldValue::of(&self.r#activation_type)),
+            "launch_sound" => Some(FieldValue::of(&self.r#launch_sound)),
+            "launch_effect_prefab" => Some(FieldValue::of(&self.r#launch_effect_prefab)),
+            "control_button_icon" => Some(FieldValue::of(&self.r#control_button_icon)),
+            "defensive_drone_ai" => Some(FieldValue::of(&self.r#defensive_drone_ai)),
+            "offensive_drone_ai" => Some(FieldValue::of(&self.r#offensive_drone_ai)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "energy_consumption" => {
+                value.write_to(&mut self.r#energy_consumption, "energy_consumption")
+            }
+            "passive_energy_consumption" => value.write_to(
+                &mut self.r#passive_energy_consumption,
+                "passive_energy_consumption",
+            ),
+            "range" => value.write_to(&mut self.r#range, "range"),
+            "damage_multiplier" => {
+                value.write_to(&mut self.r#damage_multiplier, "damage_multiplier")
+            }
+            "defense_multiplier" => {
+                value.write_to(&mut self.r#defense_multiplier, "defense_multiplier")
+            }
+            "speed_multiplier" => value.write_to(&mut self.r#speed_multiplier, "speed_multiplier"),
+            "build_extra_cycles" => {
+                value.write_to(&mut self.r#build_extra_cycles, "build_extra_cycles")
+            }
+            "improved_ai" => value.write_to(&mut self.r#improved_ai, "improved_ai"),
+            "capacity" => value.write_to(&mut self.r#capacity, "capacity"),
+            "activation_type" => value.write_to(&mut self.r#activation_type, "activation_type"),
+            "launch_sound" => value.write_to(&mut self.r#launch_sound, "launch_sound"),
+            "launch_effect_prefab" => {
+                value.write_to(&mut self.r#launch_effect_prefab, "launch_effect_prefab")
+            }
+            "control_button_icon" => {
+                value.write_to(&mut self.r#control_button_icon, "control_button_icon")
+            }
+            "defensive_drone_ai" => {
+                value.write_to(&mut self.r#defensive_drone_ai, "defensive_drone_ai")
+            }
+            "offensive_drone_ai" => {
+                value.write_to(&mut self.r#offensive_drone_ai, "offensive_drone_ai")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for DroneBay {}
 impl std::cmp::PartialEq for DroneBay {
     fn eq(&self, other: &Self) -> bool {
@@ -28512,6 +42379,10 @@ impl DatabaseItemWithId for DroneBay {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Faction.xml
@@ -28519,42 +42390,65 @@ pub type FactionId = DatabaseItemId<Faction>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct Faction {
+    /// - Options: `notnull`
     pub r#id: FactionId,
     #[serde(default)]
     pub r#name: String,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#color: String,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#no_territories: bool,
+    /// - Minimum: `0`
+    /// - Maximum: `5000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#home_star_distance: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `5000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#home_star_distance_max: i32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#no_wandering_ships: bool,
+    /// - Minimum: `0`
+    /// - Maximum: `5000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#wandering_ships_distance: i32,
+    /// - Minimum: `0`
+    /// - Maximum: `5000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#wandering_ships_distance_max: i32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#hide_from_merchants: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#hide_research_tree: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#no_missions: bool,
+    /// - Default: `false`
+    /// - Options: `obsolete`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#hidden: bool,
+    /// - Default: `false`
+    /// - Options: `obsolete`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#hostile: bool,
@@ -28708,6 +42602,12 @@ impl Faction {
 }
 impl DatabaseItem for Faction {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
+        {
+            let mut ctx = ctx.enter("color");
+            if !crate::color::Color::is_valid(&self.r#color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#color));
+            }
+        }
         {
             let mut ctx = ctx.enter("home_star_distance");
             if self.r#home_star_distance < (0f32 as i32) {
@@ -28784,11 +42684,97 @@ impl DatabaseItem for Faction {
         "Faction"
     }
 }
+impl VisitIds for Faction {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for Faction {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for Faction {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "name",
+            "color",
+            "no_territories",
+            "home_star_distance",
+            "home_star_distance_max",
+            "no_wandering_ships",
+            "wandering_ships_distance",
+            "wandering_ships_distance_max",
+            "hide_from_merchants",
+            "hide_research_tree",
+            "no_missions",
+            "hidden",
+            "hostile",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "name" => Some(FieldValue::of(&self.r#name)),
+            "color" => Some(FieldValue::of(&self.r#color)),
+            "no_territories" => Some(FieldValue::of(&self.r#no_territories)),
+            "home_star_distance" => Some(FieldValue::of(&self.r#home_star_distance)),
+            "home_star_distance_max" => Some(FieldValue::of(&self.r#home_star_distance_max)),
+            "no_wandering_ships" => Some(FieldValue::of(&self.r#no_wandering_ships)),
+            "wandering_ships_distance" => Some(FieldValue::of(&self.r#wandering_ships_distance)),
+            "wandering_ships_distance_max" => {
+                Some(FieldValue::of(&self.r#wandering_ships_distance_max))
+            }
+            "hide_from_merchants" => Some(FieldValue::of(&self.r#hide_from_merchants)),
+            "hide_research_tree" => Some(FieldValue::of(&self.r#hide_research_tree)),
+            "no_missions" => Some(FieldValue::of(&self.r#no_missions)),
+            "hidden" => Some(FieldValue::of(&self.r#hidden)),
+            "hostile" => Some(FieldValue::of(&self.r#hostile)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "name" => value.write_to(&mut self.r#name, "name"),
+            "color" => value.write_to(&mut self.r#color, "color"),
+            "no_territories" => value.write_to(&mut self.r#no_territories, "no_territories"),
+            "home_star_distance" => {
+                value.write_to(&mut self.r#home_star_distance, "home_star_distance")
+            }
+            "home_star_distance_max" => {
+                value.write_to(&mut self.r#home_star_distance_max, "home_star_distance_max")
+            }
+            "no_wandering_ships" => {
+                value.write_to(&mut self.r#no_wandering_ships, "no_wandering_ships")
+            }
+            "wandering_ships_distance" => value.write_to(
+                &mut self.r#wandering_ships_distance,
+                "wandering_ships_distance",
+            ),
+            "wandering_ships_distance_max" => value.write_to(
+                &mut self.r#wandering_ships_distance_max,
+                "wandering_ships_distance_max",
+            ),
+            "hide_from_merchants" => {
+                value.write_to(&mut self.r#hide_from_merchants, "hide_from_merchants")
+            }
+            "hide_research_tree" => {
+                value.write_to(&mut self.r#hide_research_tree, "hide_research_tree")
+            }
+            "no_missions" => value.write_to(&mut self.r#no_missions, "no_missions"),
+            "hidden" => value.write_to(&mut self.r#hidden, "hidden"),
+            "hostile" => value.write_to(&mut self.r#hostile, "hostile"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for Faction {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/GameObjectPrefab.xml
@@ -28803,6 +42789,7 @@ pub enum GameObjectPrefab {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct GameObjectPrefabUndefined {
+    /// - Options: `notnull`
     pub r#id: GameObjectPrefabId,
 }
 impl GameObjectPrefabUndefined {
@@ -28824,6 +42811,29 @@ impl DatabaseItem for GameObjectPrefabUndefined {
         "GameObjectPrefabUndefined"
     }
 }
+impl VisitIds for GameObjectPrefabUndefined {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for GameObjectPrefabUndefined {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for GameObjectPrefabUndefined {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl From<GameObjectPrefabUndefined> for GameObjectPrefab {
     fn from(item: GameObjectPrefabUndefined) -> Self {
         Self::Undefined(item)
@@ -28842,29 +42852,51 @@ impl GameObjectPrefab {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct GameObjectPrefabWormTailSegment {
+    /// - Options: `notnull`
     pub r#id: GameObjectPrefabId,
     #[serde(default)]
     pub r#image_1: String,
     #[serde(default)]
     pub r#image_2: String,
+    /// - Minimum: `0`
+    /// - Maximum: `10`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#image_scale: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#image_offset: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#length: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#offset_1: f32,
+    /// - Minimum: `-1`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#offset_2: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `180`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#angle_1: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `180`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#angle_2: f32,
@@ -29035,6 +43067,65 @@ impl DatabaseItem for GameObjectPrefabWormTailSegment {
         "GameObjectPrefabWormTailSegment"
     }
 }
+impl VisitIds for GameObjectPrefabWormTailSegment {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for GameObjectPrefabWormTailSegment {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#image_1.is_empty() {
+            visitor(AssetKind::Image, &self.r#image_1);
+        }
+        if !self.r#image_2.is_empty() {
+            visitor(AssetKind::Image, &self.r#image_2);
+        }
+    }
+}
+impl DynamicFields for GameObjectPrefabWormTailSegment {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "image_1",
+            "image_2",
+            "image_scale",
+            "image_offset",
+            "length",
+            "offset_1",
+            "offset_2",
+            "angle_1",
+            "angle_2",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "image_1" => Some(FieldValue::of(&self.r#image_1)),
+            "image_2" => Some(FieldValue::of(&self.r#image_2)),
+            "image_scale" => Some(FieldValue::of(&self.r#image_scale)),
+            "image_offset" => Some(FieldValue::of(&self.r#image_offset)),
+            "length" => Some(FieldValue::of(&self.r#length)),
+            "offset_1" => Some(FieldValue::of(&self.r#offset_1)),
+            "offset_2" => Some(FieldValue::of(&self.r#offset_2)),
+            "angle_1" => Some(FieldValue::of(&self.r#angle_1)),
+            "angle_2" => Some(FieldValue::of(&self.r#angle_2)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "image_1" => value.write_to(&mut self.r#image_1, "image_1"),
+            "image_2" => value.write_to(&mut self.r#image_2, "image_2"),
+            "image_scale" => value.write_to(&mut self.r#image_scale, "image_scale"),
+            "image_offset" => value.write_to(&mut self.r#image_offset, "image_offset"),
+            "length" => value.write_to(&mut self.r#length, "length"),
+            "offset_1" => value.write_to(&mut self.r#offset_1, "offset_1"),
+            "offset_2" => value.write_to(&mut self.r#offset_2, "offset_2"),
+            "angle_1" => value.write_to(&mut self.r#angle_1, "angle_1"),
+            "angle_2" => value.write_to(&mut self.r#angle_2, "angle_2"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for GameObjectPrefabWormTailSegment {}
 impl std::cmp::PartialEq for GameObjectPrefabWormTailSegment {
     fn eq(&self, other: &Self) -> bool {
@@ -29089,9 +43180,13 @@ impl GameObjectPrefab {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct GameObjectPrefabCircularSpriteObject {
+    /// - Options: `notnull`
     pub r#id: GameObjectPrefabId,
     #[serde(default)]
     pub r#image_1: String,
+    /// - Minimum: `0`
+    /// - Maximum: `10`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#image_scale: f32,
@@ -29145,6 +43240,37 @@ impl DatabaseItem for GameObjectPrefabCircularSpriteObject {
         "GameObjectPrefabCircularSpriteObject"
     }
 }
+impl VisitIds for GameObjectPrefabCircularSpriteObject {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for GameObjectPrefabCircularSpriteObject {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#image_1.is_empty() {
+            visitor(AssetKind::Image, &self.r#image_1);
+        }
+    }
+}
+impl DynamicFields for GameObjectPrefabCircularSpriteObject {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "image_1", "image_scale"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "image_1" => Some(FieldValue::of(&self.r#image_1)),
+            "image_scale" => Some(FieldValue::of(&self.r#image_scale)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "image_1" => value.write_to(&mut self.r#image_1, "image_1"),
+            "image_scale" => value.write_to(&mut self.r#image_scale, "image_scale"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for GameObjectPrefabCircularSpriteObject {}
 impl std::cmp::PartialEq for GameObjectPrefabCircularSpriteObject {
     fn eq(&self, other: &Self) -> bool {
@@ -29181,15 +43307,25 @@ impl GameObjectPrefab {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct GameObjectPrefabCircularOutlineObject {
+    /// - Options: `notnull`
     pub r#id: GameObjectPrefabId,
     #[serde(default)]
     pub r#image_1: String,
+    /// - Minimum: `0`
+    /// - Maximum: `10`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#image_scale: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.1`
     #[serde(default = "default_0ඞdotඞ1f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ1f32")]
     pub r#thickness: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `100`
+    /// - Default: `1`
     #[serde(default = "default_1f32")]
     #[serde(skip_serializing_if = "skip_if_1f32")]
     pub r#aspect_ratio: f32,
@@ -29279,6 +43415,41 @@ impl DatabaseItem for GameObjectPrefabCircularOutlineObject {
         "GameObjectPrefabCircularOutlineObject"
     }
 }
+impl VisitIds for GameObjectPrefabCircularOutlineObject {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for GameObjectPrefabCircularOutlineObject {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#image_1.is_empty() {
+            visitor(AssetKind::Image, &self.r#image_1);
+        }
+    }
+}
+impl DynamicFields for GameObjectPrefabCircularOutlineObject {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "image_1", "image_scale", "thickness", "aspect_ratio"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "image_1" => Some(FieldValue::of(&self.r#image_1)),
+            "image_scale" => Some(FieldValue::of(&self.r#image_scale)),
+            "thickness" => Some(FieldValue::of(&self.r#thickness)),
+            "aspect_ratio" => Some(FieldValue::of(&self.r#aspect_ratio)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "image_1" => value.write_to(&mut self.r#image_1, "image_1"),
+            "image_scale" => value.write_to(&mut self.r#image_scale, "image_scale"),
+            "thickness" => value.write_to(&mut self.r#thickness, "thickness"),
+            "aspect_ratio" => value.write_to(&mut self.r#aspect_ratio, "aspect_ratio"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for GameObjectPrefabCircularOutlineObject {}
 impl std::cmp::PartialEq for GameObjectPrefabCircularOutlineObject {
     fn eq(&self, other: &Self) -> bool {
@@ -29476,6 +43647,52 @@ impl DatabaseItem for GameObjectPrefab {
         "GameObjectPrefab"
     }
 }
+impl VisitIds for GameObjectPrefab {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        match self {
+            Self::Undefined(x) => x.visit_ids(visitor),
+            Self::WormTailSegment(x) => x.visit_ids(visitor),
+            Self::CircularSpriteObject(x) => x.visit_ids(visitor),
+            Self::CircularOutlineObject(x) => x.visit_ids(visitor),
+        }
+    }
+}
+impl VisitAssets for GameObjectPrefab {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        match self {
+            Self::Undefined(x) => x.visit_assets(visitor),
+            Self::WormTailSegment(x) => x.visit_assets(visitor),
+            Self::CircularSpriteObject(x) => x.visit_assets(visitor),
+            Self::CircularOutlineObject(x) => x.visit_assets(visitor),
+        }
+    }
+}
+impl DynamicFields for GameObjectPrefab {
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Undefined(x) => x.field_names(),
+            Self::WormTailSegment(x) => x.field_names(),
+            Self::CircularSpriteObject(x) => x.field_names(),
+            Self::CircularOutlineObject(x) => x.field_names(),
+        }
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match self {
+            Self::Undefined(x) => x.get_field(name),
+            Self::WormTailSegment(x) => x.get_field(name),
+            Self::CircularSpriteObject(x) => x.get_field(name),
+            Self::CircularOutlineObject(x) => x.get_field(name),
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match self {
+            Self::Undefined(x) => x.set_field(name, value),
+            Self::WormTailSegment(x) => x.set_field(name, value),
+            Self::CircularSpriteObject(x) => x.set_field(name, value),
+            Self::CircularOutlineObject(x) => x.set_field(name, value),
+        }
+    }
+}
 impl GameObjectPrefab {
     pub fn inner_type_name(&self) -> &'static str {
         match self {
@@ -29486,11 +43703,27 @@ impl GameObjectPrefab {
         }
     }
 }
+/// Runs the same code for every variant of [GameObjectPrefab], binding the inner item to the given pattern
+#[macro_export]
+macro_rules! match_game_object_prefab {
+    ($value:expr, |$inner:pat_param| $body:expr) => {
+        match $value {
+            $crate::schema::GameObjectPrefab::Undefined($inner) => $body,
+            $crate::schema::GameObjectPrefab::WormTailSegment($inner) => $body,
+            $crate::schema::GameObjectPrefab::CircularSpriteObject($inner) => $body,
+            $crate::schema::GameObjectPrefab::CircularOutlineObject($inner) => $body,
+        }
+    };
+}
 impl DatabaseItemWithId for GameObjectPrefab {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         *x.id()
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        x.id_mut()
+    }
 }
 
 // v1/Objects/Quests/Character.xml
@@ -29498,6 +43731,7 @@ pub type CharacterId = DatabaseItemId<Character>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct Character {
+    /// - Options: `notnull`
     pub r#id: CharacterId,
     #[serde(default)]
     pub r#name: String,
@@ -29512,9 +43746,13 @@ pub struct Character {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#fleet: Option<FleetId>,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#relations: i32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#is_unique: bool,
@@ -29564,6 +43802,10 @@ impl Character {
         self.r#faction = r#faction.into();
         self
     }
+    pub fn clear_faction(&mut self) -> &mut Self {
+        self.r#faction = None;
+        self
+    }
     pub fn with_inventory(mut self, r#inventory: impl Into<Option<LootId>>) -> Self {
         self.r#inventory = r#inventory.into();
         self
@@ -29572,6 +43814,10 @@ impl Character {
         self.r#inventory = r#inventory.into();
         self
     }
+    pub fn clear_inventory(&mut self) -> &mut Self {
+        self.r#inventory = None;
+        self
+    }
     pub fn with_fleet(mut self, r#fleet: impl Into<Option<FleetId>>) -> Self {
         self.r#fleet = r#fleet.into();
         self
@@ -29580,6 +43826,10 @@ impl Character {
         self.r#fleet = r#fleet.into();
         self
     }
+    pub fn clear_fleet(&mut self) -> &mut Self {
+        self.r#fleet = None;
+        self
+    }
     pub fn with_relations(mut self, r#relations: impl Into<i32>) -> Self {
         self.r#relations = r#relations.into();
         self
@@ -29613,11 +43863,69 @@ impl DatabaseItem for Character {
         "Character"
     }
 }
+impl VisitIds for Character {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#faction.visit_ids(visitor);
+        self.r#inventory.visit_ids(visitor);
+        self.r#fleet.visit_ids(visitor);
+    }
+}
+impl VisitAssets for Character {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#avatar_icon.is_empty() {
+            visitor(AssetKind::Image, &self.r#avatar_icon);
+        }
+    }
+}
+impl DynamicFields for Character {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "name",
+            "avatar_icon",
+            "faction",
+            "inventory",
+            "fleet",
+            "relations",
+            "is_unique",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "name" => Some(FieldValue::of(&self.r#name)),
+            "avatar_icon" => Some(FieldValue::of(&self.r#avatar_icon)),
+            "faction" => Some(FieldValue::of(&self.r#faction)),
+            "inventory" => Some(FieldValue::of(&self.r#inventory)),
+            "fleet" => Some(FieldValue::of(&self.r#fleet)),
+            "relations" => Some(FieldValue::of(&self.r#relations)),
+            "is_unique" => Some(FieldValue::of(&self.r#is_unique)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "name" => value.write_to(&mut self.r#name, "name"),
+            "avatar_icon" => value.write_to(&mut self.r#avatar_icon, "avatar_icon"),
+            "faction" => value.write_to(&mut self.r#faction, "faction"),
+            "inventory" => value.write_to(&mut self.r#inventory, "inventory"),
+            "fleet" => value.write_to(&mut self.r#fleet, "fleet"),
+            "relations" => value.write_to(&mut self.r#relations, "relations"),
+            "is_unique" => value.write_to(&mut self.r#is_unique, "is_unique"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for Character {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Quests/CombatRules.xml
@@ -29625,23 +43933,29 @@ pub type CombatRulesId = DatabaseItemId<CombatRules>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct CombatRules {
+    /// - Options: `notnull`
     pub r#id: CombatRulesId,
+    /// - Default: `"1"`
     #[serde(default = "default_ඞquoteඞ1ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ1ඞquoteඞ")]
-    pub r#initial_enemy_ships: String,
+    pub r#initial_enemy_ships: crate::expression::Expression,
+    /// - Default: `"12"`
     #[serde(default = "default_ඞquoteඞ12ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞ12ඞquoteඞ")]
-    pub r#max_enemy_ships: String,
+    pub r#max_enemy_ships: crate::expression::Expression,
+    /// - Minimum: `50`
+    /// - Default: `200`
     #[serde(default = "default_200i32")]
     #[serde(skip_serializing_if = "skip_if_200i32")]
     pub r#battle_map_size: i32,
+    /// - Default: `"MAX(40, 100 - level)"`
     #[serde(
         default = "default_ඞquoteඞMAXඞlparenඞ40ඞcommaඞඞspaceඞ100ඞspaceඞඞdashඞඞspaceඞlevelඞrparenඞඞquoteඞ"
     )]
     #[serde(
         skip_serializing_if = "skip_if_ඞquoteඞMAXඞlparenඞ40ඞcommaඞඞspaceඞ100ඞspaceඞඞdashඞඞspaceඞlevelඞrparenඞඞquoteඞ"
     )]
-    pub r#time_limit: String,
+    pub r#time_limit: crate::expression::Expression,
     #[serde(default)]
     pub r#time_out_mode: TimeOutMode,
     #[serde(default)]
@@ -29650,22 +43964,29 @@ pub struct CombatRules {
     pub r#exp_condition: RewardCondition,
     #[serde(default)]
     pub r#ship_selection: PlayerShipSelectionMode,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#disable_skill_bonuses: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#disable_random_loot: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#disable_asteroids: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#disable_planet: bool,
+    /// - Default: `true`
     #[serde(default = "default_true")]
     #[serde(skip_serializing_if = "skip_if_true")]
     pub r#next_enemy_button: bool,
     ///For debug purposes
+    ///
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#kill_them_all_button: bool,
@@ -29701,22 +44022,31 @@ impl CombatRules {
         self.r#id = r#id.into();
         self
     }
-    pub fn with_initial_enemy_ships(mut self, r#initial_enemy_ships: impl Into<String>) -> Self {
+    pub fn with_initial_enemy_ships(
+        mut self,
+        r#initial_enemy_ships: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#initial_enemy_ships = r#initial_enemy_ships.into();
         self
     }
     pub fn set_initial_enemy_ships(
         &mut self,
-        r#initial_enemy_ships: impl Into<String>,
+        r#initial_enemy_ships: impl Into<crate::expression::Expression>,
     ) -> &mut Self {
         self.r#initial_enemy_ships = r#initial_enemy_ships.into();
         self
     }
-    pub fn with_max_enemy_ships(mut self, r#max_enemy_ships: impl Into<String>) -> Self {
+    pub fn with_max_enemy_ships(
+        mut self,
+        r#max_enemy_ships: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#max_enemy_ships = r#max_enemy_ships.into();
         self
     }
-    pub fn set_max_enemy_ships(&mut self, r#max_enemy_ships: impl Into<String>) -> &mut Self {
+    pub fn set_max_enemy_ships(
+        &mut self,
+        r#max_enemy_ships: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#max_enemy_ships = r#max_enemy_ships.into();
         self
     }
@@ -29728,11 +44058,17 @@ impl CombatRules {
         self.r#battle_map_size = r#battle_map_size.into();
         self
     }
-    pub fn with_time_limit(mut self, r#time_limit: impl Into<String>) -> Self {
+    pub fn with_time_limit(
+        mut self,
+        r#time_limit: impl Into<crate::expression::Expression>,
+    ) -> Self {
         self.r#time_limit = r#time_limit.into();
         self
     }
-    pub fn set_time_limit(&mut self, r#time_limit: impl Into<String>) -> &mut Self {
+    pub fn set_time_limit(
+        &mut self,
+        r#time_limit: impl Into<crate::expression::Expression>,
+    ) -> &mut Self {
         self.r#time_limit = r#time_limit.into();
         self
     }
@@ -29866,11 +44202,103 @@ impl DatabaseItem for CombatRules {
         "CombatRules"
     }
 }
+impl VisitIds for CombatRules {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#custom_soundtrack.visit_ids(visitor);
+    }
+}
+impl VisitAssets for CombatRules {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#custom_soundtrack.visit_assets(visitor);
+    }
+}
+impl DynamicFields for CombatRules {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "initial_enemy_ships",
+            "max_enemy_ships",
+            "battle_map_size",
+            "time_limit",
+            "time_out_mode",
+            "loot_condition",
+            "exp_condition",
+            "ship_selection",
+            "disable_skill_bonuses",
+            "disable_random_loot",
+            "disable_asteroids",
+            "disable_planet",
+            "next_enemy_button",
+            "kill_them_all_button",
+            "custom_soundtrack",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "initial_enemy_ships" => Some(FieldValue::of(&self.r#initial_enemy_ships)),
+            "max_enemy_ships" => Some(FieldValue::of(&self.r#max_enemy_ships)),
+            "battle_map_size" => Some(FieldValue::of(&self.r#battle_map_size)),
+            "time_limit" => Some(FieldValue::of(&self.r#time_limit)),
+            "time_out_mode" => Some(FieldValue::of(&self.r#time_out_mode)),
+            "loot_condition" => Some(FieldValue::of(&self.r#loot_condition)),
+            "exp_condition" => Some(FieldValue::of(&self.r#exp_condition)),
+            "ship_selection" => Some(FieldValue::of(&self.r#ship_selection)),
+            "disable_skill_bonuses" => Some(FieldValue::of(&self.r#disable_skill_bonuses)),
+            "disable_random_loot" => Some(FieldValue::of(&self.r#disable_random_loot)),
+            "disable_asteroids" => Some(FieldValue::of(&self.r#disable_asteroids)),
+            "disable_planet" => Some(FieldValue::of(&self.r#disable_planet)),
+            "next_enemy_button" => Some(FieldValue::of(&self.r#next_enemy_button)),
+            "kill_them_all_button" => Some(FieldValue::of(&self.r#kill_them_all_button)),
+            "custom_soundtrack" => Some(FieldValue::of(&self.r#custom_soundtrack)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "initial_enemy_ships" => {
+                value.write_to(&mut self.r#initial_enemy_ships, "initial_enemy_ships")
+            }
+            "max_enemy_ships" => value.write_to(&mut self.r#max_enemy_ships, "max_enemy_ships"),
+            "battle_map_size" => value.write_to(&mut self.r#battle_map_size, "battle_map_size"),
+            "time_limit" => value.write_to(&mut self.r#time_limit, "time_limit"),
+            "time_out_mode" => value.write_to(&mut self.r#time_out_mode, "time_out_mode"),
+            "loot_condition" => value.write_to(&mut self.r#loot_condition, "loot_condition"),
+            "exp_condition" => value.write_to(&mut self.r#exp_condition, "exp_condition"),
+            "ship_selection" => value.write_to(&mut self.r#ship_selection, "ship_selection"),
+            "disable_skill_bonuses" => {
+                value.write_to(&mut self.r#disable_skill_bonuses, "disable_skill_bonuses")
+            }
+            "disable_random_loot" => {
+                value.write_to(&mut self.r#disable_random_loot, "disable_random_loot")
+            }
+            "disable_asteroids" => {
+                value.write_to(&mut self.r#disable_asteroids, "disable_asteroids")
+            }
+            "disable_planet" => value.write_to(&mut self.r#disable_planet, "disable_planet"),
+            "next_enemy_button" => {
+                value.write_to(&mut self.r#next_enemy_button, "next_enemy_button")
+            }
+            "kill_them_all_button" => {
+                value.write_to(&mut self.r#kill_them_all_button, "kill_them_all_button")
+            }
+            "custom_soundtrack" => {
+                value.write_to(&mut self.r#custom_soundtrack, "custom_soundtrack")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for CombatRules {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Quests/Fleet.xml
@@ -29878,27 +44306,42 @@ pub type FleetId = DatabaseItemId<Fleet>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct Fleet {
+    /// - Options: `notnull`
     pub r#id: FleetId,
     #[serde(default)]
     pub r#factions: FactionFilter,
+    /// - Minimum: `-10000`
+    /// - Maximum: `10000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#level_bonus: i32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#no_random_ships: bool,
+    /// - Minimum: `0`
+    /// - Maximum: `999`
+    /// - Default: `0`
+    /// - Options: `obsolete`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#combat_time_limit: i32,
+    /// - Options: `obsolete`
     #[serde(default)]
     pub r#loot_condition: RewardCondition,
+    /// - Options: `obsolete`
     #[serde(default)]
     pub r#exp_condition: RewardCondition,
     #[serde(default)]
     pub r#specific_ships: Vec<ShipBuildId>,
+    /// - Default: `false`
+    /// - Options: `obsolete`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#no_ship_changing: bool,
+    /// - Default: `false`
+    /// - Options: `obsolete`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#player_has_one_ship: bool,
@@ -30019,6 +44462,10 @@ impl Fleet {
         self.r#combat_rules = r#combat_rules.into();
         self
     }
+    pub fn clear_combat_rules(&mut self) -> &mut Self {
+        self.r#combat_rules = None;
+        self
+    }
 }
 impl DatabaseItem for Fleet {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
@@ -30081,11 +44528,80 @@ impl DatabaseItem for Fleet {
         "Fleet"
     }
 }
+impl VisitIds for Fleet {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#factions.visit_ids(visitor);
+        self.r#specific_ships.visit_ids(visitor);
+        self.r#combat_rules.visit_ids(visitor);
+    }
+}
+impl VisitAssets for Fleet {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#factions.visit_assets(visitor);
+    }
+}
+impl DynamicFields for Fleet {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "factions",
+            "level_bonus",
+            "no_random_ships",
+            "combat_time_limit",
+            "loot_condition",
+            "exp_condition",
+            "specific_ships",
+            "no_ship_changing",
+            "player_has_one_ship",
+            "combat_rules",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "factions" => Some(FieldValue::of(&self.r#factions)),
+            "level_bonus" => Some(FieldValue::of(&self.r#level_bonus)),
+            "no_random_ships" => Some(FieldValue::of(&self.r#no_random_ships)),
+            "combat_time_limit" => Some(FieldValue::of(&self.r#combat_time_limit)),
+            "loot_condition" => Some(FieldValue::of(&self.r#loot_condition)),
+            "exp_condition" => Some(FieldValue::of(&self.r#exp_condition)),
+            "specific_ships" => Some(FieldValue::of(&self.r#specific_ships)),
+            "no_ship_changing" => Some(FieldValue::of(&self.r#no_ship_changing)),
+            "player_has_one_ship" => Some(FieldValue::of(&self.r#player_has_one_ship)),
+            "combat_rules" => Some(FieldValue::of(&self.r#combat_rules)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "factions" => value.write_to(&mut self.r#factions, "factions"),
+            "level_bonus" => value.write_to(&mut self.r#level_bonus, "level_bonus"),
+            "no_random_ships" => value.write_to(&mut self.r#no_random_ships, "no_random_ships"),
+            "combat_time_limit" => {
+                value.write_to(&mut self.r#combat_time_limit, "combat_time_limit")
+            }
+            "loot_condition" => value.write_to(&mut self.r#loot_condition, "loot_condition"),
+            "exp_condition" => value.write_to(&mut self.r#exp_condition, "exp_condition"),
+            "specific_ships" => value.write_to(&mut self.r#specific_ships, "specific_ships"),
+            "no_ship_changing" => value.write_to(&mut self.r#no_ship_changing, "no_ship_changing"),
+            "player_has_one_ship" => {
+                value.write_to(&mut self.r#player_has_one_ship, "player_has_one_ship")
+            }
+            "combat_rules" => value.write_to(&mut self.r#combat_rules, "combat_rules"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for Fleet {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Quests/Loot.xml
@@ -30093,6 +44609,7 @@ pub type LootId = DatabaseItemId<Loot>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct Loot {
+    /// - Options: `notnull`
     pub r#id: LootId,
     #[serde(default)]
     pub r#loot: LootContent,
@@ -30132,11 +44649,44 @@ impl DatabaseItem for Loot {
         "Loot"
     }
 }
+impl VisitIds for Loot {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#loot.visit_ids(visitor);
+    }
+}
+impl VisitAssets for Loot {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#loot.visit_assets(visitor);
+    }
+}
+impl DynamicFields for Loot {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "loot"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "loot" => Some(FieldValue::of(&self.r#loot)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "loot" => value.write_to(&mut self.r#loot, "loot"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for Loot {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Quests/Quest.xml
@@ -30144,6 +44694,7 @@ pub type QuestId = DatabaseItemId<Quest>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Quest {
+    /// - Options: `notnull`
     pub r#id: QuestId,
     #[serde(default)]
     pub r#name: String,
@@ -30151,6 +44702,9 @@ pub struct Quest {
     pub r#quest_type: QuestType,
     #[serde(default)]
     pub r#start_condition: StartCondition,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#weight: f32,
@@ -30158,9 +44712,13 @@ pub struct Quest {
     pub r#origin: QuestOrigin,
     #[serde(default)]
     pub r#requirement: Requirement,
+    /// - Minimum: `0`
+    /// - Maximum: `1000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#level: i32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#use_random_seed: bool,
@@ -30306,6 +44864,66 @@ impl DatabaseItem for Quest {
         "Quest"
     }
 }
+impl VisitIds for Quest {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#origin.visit_ids(visitor);
+        self.r#requirement.visit_ids(visitor);
+        self.r#nodes.visit_ids(visitor);
+    }
+}
+impl VisitAssets for Quest {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#origin.visit_assets(visitor);
+        self.r#requirement.visit_assets(visitor);
+        self.r#nodes.visit_assets(visitor);
+    }
+}
+impl DynamicFields for Quest {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "name",
+            "quest_type",
+            "start_condition",
+            "weight",
+            "origin",
+            "requirement",
+            "level",
+            "use_random_seed",
+            "nodes",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "name" => Some(FieldValue::of(&self.r#name)),
+            "quest_type" => Some(FieldValue::of(&self.r#quest_type)),
+            "start_condition" => Some(FieldValue::of(&self.r#start_condition)),
+            "weight" => Some(FieldValue::of(&self.r#weight)),
+            "origin" => Some(FieldValue::of(&self.r#origin)),
+            "requirement" => Some(FieldValue::of(&self.r#requirement)),
+            "level" => Some(FieldValue::of(&self.r#level)),
+            "use_random_seed" => Some(FieldValue::of(&self.r#use_random_seed)),
+            "nodes" => Some(FieldValue::of(&self.r#nodes)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "name" => value.write_to(&mut self.r#name, "name"),
+            "quest_type" => value.write_to(&mut self.r#quest_type, "quest_type"),
+            "start_condition" => value.write_to(&mut self.r#start_condition, "start_condition"),
+            "weight" => value.write_to(&mut self.r#weight, "weight"),
+            "origin" => value.write_to(&mut self.r#origin, "origin"),
+            "requirement" => value.write_to(&mut self.r#requirement, "requirement"),
+            "level" => value.write_to(&mut self.r#level, "level"),
+            "use_random_seed" => value.write_to(&mut self.r#use_random_seed, "use_random_seed"),
+            "nodes" => value.write_to(&mut self.r#nodes, "nodes"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for Quest {}
 impl std::cmp::PartialEq for Quest {
     fn eq(&self, other: &Self) -> bool {
@@ -30341,6 +44959,10 @@ impl DatabaseItemWithId for Quest {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Quests/QuestItem.xml
@@ -30348,6 +44970,7 @@ pub type QuestItemId = DatabaseItemId<QuestItem>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct QuestItem {
+    /// - Options: `notnull`
     pub r#id: QuestItemId,
     #[serde(default)]
     pub r#name: String,
@@ -30355,9 +44978,13 @@ pub struct QuestItem {
     pub r#description: String,
     #[serde(default)]
     pub r#icon: String,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#color: String,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#price: i32,
@@ -30424,6 +45051,12 @@ impl QuestItem {
 }
 impl DatabaseItem for QuestItem {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
+        {
+            let mut ctx = ctx.enter("color");
+            if !crate::color::Color::is_valid(&self.r#color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#color));
+            }
+        }
         {
             let mut ctx = ctx.enter("price");
             if self.r#price < (0f32 as i32) {
@@ -30438,11 +45071,52 @@ impl DatabaseItem for QuestItem {
         "QuestItem"
     }
 }
+impl VisitIds for QuestItem {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for QuestItem {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#icon.is_empty() {
+            visitor(AssetKind::Image, &self.r#icon);
+        }
+    }
+}
+impl DynamicFields for QuestItem {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "name", "description", "icon", "color", "price"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "name" => Some(FieldValue::of(&self.r#name)),
+            "description" => Some(FieldValue::of(&self.r#description)),
+            "icon" => Some(FieldValue::of(&self.r#icon)),
+            "color" => Some(FieldValue::of(&self.r#color)),
+            "price" => Some(FieldValue::of(&self.r#price)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "name" => value.write_to(&mut self.r#name, "name"),
+            "description" => value.write_to(&mut self.r#description, "description"),
+            "icon" => value.write_to(&mut self.r#icon, "icon"),
+            "color" => value.write_to(&mut self.r#color, "color"),
+            "price" => value.write_to(&mut self.r#price, "price"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for QuestItem {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Satellite.xml
@@ -30450,18 +45124,22 @@ pub type SatelliteId = DatabaseItemId<Satellite>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Satellite {
+    /// - Options: `notnull`
     pub r#id: SatelliteId,
     #[serde(default)]
     pub r#name: String,
     #[serde(default)]
     pub r#model_image: String,
+    /// - Minimum: `0.1`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#model_scale: f32,
     #[serde(default)]
     pub r#size_class: SizeClass,
     #[serde(default)]
-    pub r#layout: String,
+    pub r#layout: crate::layout::Layout,
     #[serde(default)]
     pub r#barrels: Vec<Barrel>,
 }
@@ -30517,11 +45195,11 @@ impl Satellite {
         self.r#size_class = r#size_class.into();
         self
     }
-    pub fn with_layout(mut self, r#layout: impl Into<String>) -> Self {
+    pub fn with_layout(mut self, r#layout: impl Into<crate::layout::Layout>) -> Self {
         self.r#layout = r#layout.into();
         self
     }
-    pub fn set_layout(&mut self, r#layout: impl Into<String>) -> &mut Self {
+    pub fn set_layout(&mut self, r#layout: impl Into<crate::layout::Layout>) -> &mut Self {
         self.r#layout = r#layout.into();
         self
     }
@@ -30545,12 +45223,6 @@ impl DatabaseItem for Satellite {
                 ctx.emit(DiagnosticKind::too_large(100f32, self.r#model_scale));
             }
         }
-        {
-            let mut ctx = ctx.enter("layout");
-            if (self.r#layout.len() as f32).sqrt().floor().powi(2) != (self.r#layout.len() as f32) {
-                ctx.emit(DiagnosticKind::layout_not_square(self.r#layout.len()));
-            }
-        }
         {
             let mut ctx = ctx.enter("barrels");
             for (i, x) in self.r#barrels.iter().enumerate() {
@@ -30563,6 +45235,56 @@ impl DatabaseItem for Satellite {
         "Satellite"
     }
 }
+impl VisitIds for Satellite {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#barrels.visit_ids(visitor);
+    }
+}
+impl VisitAssets for Satellite {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#model_image.is_empty() {
+            visitor(AssetKind::Image, &self.r#model_image);
+        }
+        self.r#barrels.visit_assets(visitor);
+    }
+}
+impl DynamicFields for Satellite {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "name",
+            "model_image",
+            "model_scale",
+            "size_class",
+            "layout",
+            "barrels",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "name" => Some(FieldValue::of(&self.r#name)),
+            "model_image" => Some(FieldValue::of(&self.r#model_image)),
+            "model_scale" => Some(FieldValue::of(&self.r#model_scale)),
+            "size_class" => Some(FieldValue::of(&self.r#size_class)),
+            "layout" => Some(FieldValue::of(&self.r#layout)),
+            "barrels" => Some(FieldValue::of(&self.r#barrels)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "name" => value.write_to(&mut self.r#name, "name"),
+            "model_image" => value.write_to(&mut self.r#model_image, "model_image"),
+            "model_scale" => value.write_to(&mut self.r#model_scale, "model_scale"),
+            "size_class" => value.write_to(&mut self.r#size_class, "size_class"),
+            "layout" => value.write_to(&mut self.r#layout, "layout"),
+            "barrels" => value.write_to(&mut self.r#barrels, "barrels"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for Satellite {}
 impl std::cmp::PartialEq for Satellite {
     fn eq(&self, other: &Self) -> bool {
@@ -30592,6 +45314,10 @@ impl DatabaseItemWithId for Satellite {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/SatelliteBuild.xml
@@ -30599,8 +45325,11 @@ pub type SatelliteBuildId = DatabaseItemId<SatelliteBuild>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct SatelliteBuild {
+    /// - Options: `notnull`
     pub r#id: SatelliteBuildId,
+    /// - Options: `notnull`
     pub r#satellite_id: SatelliteId,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#not_available_in_game: bool,
@@ -30683,11 +45412,59 @@ impl DatabaseItem for SatelliteBuild {
         "SatelliteBuild"
     }
 }
+impl VisitIds for SatelliteBuild {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#satellite_id.visit_ids(visitor);
+        self.r#components.visit_ids(visitor);
+    }
+}
+impl VisitAssets for SatelliteBuild {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#components.visit_assets(visitor);
+    }
+}
+impl DynamicFields for SatelliteBuild {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "satellite_id",
+            "not_available_in_game",
+            "difficulty_class",
+            "components",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "satellite_id" => Some(FieldValue::of(&self.r#satellite_id)),
+            "not_available_in_game" => Some(FieldValue::of(&self.r#not_available_in_game)),
+            "difficulty_class" => Some(FieldValue::of(&self.r#difficulty_class)),
+            "components" => Some(FieldValue::of(&self.r#components)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "satellite_id" => value.write_to(&mut self.r#satellite_id, "satellite_id"),
+            "not_available_in_game" => {
+                value.write_to(&mut self.r#not_available_in_game, "not_available_in_game")
+            }
+            "difficulty_class" => value.write_to(&mut self.r#difficulty_class, "difficulty_class"),
+            "components" => value.write_to(&mut self.r#components, "components"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for SatelliteBuild {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Ship.xml
@@ -30695,6 +45472,7 @@ pub type ShipId = DatabaseItemId<Ship>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Ship {
+    /// - Options: `notnull`
     pub r#id: ShipId,
     #[serde(default)]
     pub r#ship_type: ShipType,
@@ -30711,53 +45489,79 @@ pub struct Ship {
     pub r#faction: Option<FactionId>,
     #[serde(default)]
     pub r#icon_image: String,
+    /// - Minimum: `0.1`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#icon_scale: f32,
     #[serde(default)]
     pub r#model_image: String,
+    /// - Minimum: `0.1`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#model_scale: f32,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#engine_color: String,
     #[serde(default)]
     pub r#engines: Vec<Engine>,
     #[serde(default)]
-    pub r#layout: String,
+    pub r#layout: crate::layout::Layout,
     #[serde(default)]
     pub r#barrels: Vec<Barrel>,
     #[serde(default)]
     pub r#features: ShipFeatures,
     #[serde(default)]
     pub r#cells_expansions: ToggleState,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0.02`
     #[serde(default = "default_0ඞdotඞ02f32")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ02f32")]
     pub r#collider_tolerance: f32,
+    /// - Options: `obsolete`
     #[serde(default)]
     #[serde(with = "crate::helpers::glam_ser")]
     pub r#engine_position: glam::f32::Vec2,
+    /// - Default: `0`
+    /// - Options: `obsolete`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#engine_size: f32,
+    /// - Default: `0`
+    /// - Options: `obsolete`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#ship_category: i32,
+    /// - Default: `0`
+    /// - Options: `obsolete`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#energy_resistance: f32,
+    /// - Default: `0`
+    /// - Options: `obsolete`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#kinetic_resistance: f32,
+    /// - Default: `0`
+    /// - Options: `obsolete`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#heat_resistance: f32,
+    /// - Default: `false`
+    /// - Options: `obsolete`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#regeneration: bool,
+    /// - Options: `obsolete`
     #[serde(default)]
     pub r#builtin_devices: Vec<DeviceId>,
+    /// - Default: `0`
+    /// - Options: `obsolete`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#base_weight_modifier: f32,
@@ -30850,6 +45654,10 @@ impl Ship {
         self.r#faction = r#faction.into();
         self
     }
+    pub fn clear_faction(&mut self) -> &mut Self {
+        self.r#faction = None;
+        self
+    }
     pub fn with_icon_image(mut self, r#icon_image: impl Into<String>) -> Self {
         self.r#icon_image = r#icon_image.into();
         self
@@ -30898,11 +45706,11 @@ impl Ship {
         self.r#engines = r#engines.into();
         self
     }
-    pub fn with_layout(mut self, r#layout: impl Into<String>) -> Self {
+    pub fn with_layout(mut self, r#layout: impl Into<crate::layout::Layout>) -> Self {
         self.r#layout = r#layout.into();
         self
     }
-    pub fn set_layout(&mut self, r#layout: impl Into<String>) -> &mut Self {
+    pub fn set_layout(&mut self, r#layout: impl Into<crate::layout::Layout>) -> &mut Self {
         self.r#layout = r#layout.into();
         self
     }
@@ -31043,6 +45851,12 @@ impl DatabaseItem for Ship {
                 ctx.emit(DiagnosticKind::too_large(100f32, self.r#model_scale));
             }
         }
+        {
+            let mut ctx = ctx.enter("engine_color");
+            if !crate::color::Color::is_valid(&self.r#engine_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#engine_color));
+            }
+        }
         {
             let mut ctx = ctx.enter("engines");
             for (i, x) in self.r#engines.iter().enumerate() {
@@ -31050,12 +45864,6 @@ impl DatabaseItem for Ship {
                 x.validate(ctx);
             }
         }
-        {
-            let mut ctx = ctx.enter("layout");
-            if (self.r#layout.len() as f32).sqrt().floor().powi(2) != (self.r#layout.len() as f32) {
-                ctx.emit(DiagnosticKind::layout_not_square(self.r#layout.len()));
-            }
-        }
         {
             let mut ctx = ctx.enter("barrels");
             for (i, x) in self.r#barrels.iter().enumerate() {
@@ -31144,6 +45952,133 @@ impl DatabaseItem for Ship {
         "Ship"
     }
 }
+impl VisitIds for Ship {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#faction.visit_ids(visitor);
+        self.r#engines.visit_ids(visitor);
+        self.r#barrels.visit_ids(visitor);
+        self.r#features.visit_ids(visitor);
+        self.r#builtin_devices.visit_ids(visitor);
+    }
+}
+impl VisitAssets for Ship {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#icon_image.is_empty() {
+            visitor(AssetKind::Image, &self.r#icon_image);
+        }
+        if !self.r#model_image.is_empty() {
+            visitor(AssetKind::Image, &self.r#model_image);
+        }
+        self.r#engines.visit_assets(visitor);
+        self.r#barrels.visit_assets(visitor);
+        self.r#features.visit_assets(visitor);
+    }
+}
+impl DynamicFields for Ship {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "ship_type",
+            "ship_rarity",
+            "size_class",
+            "name",
+            "description",
+            "faction",
+            "icon_image",
+            "icon_scale",
+            "model_image",
+            "model_scale",
+            "engine_color",
+            "engines",
+            "layout",
+            "barrels",
+            "features",
+            "cells_expansions",
+            "collider_tolerance",
+            "engine_position",
+            "engine_size",
+            "ship_category",
+            "energy_resistance",
+            "kinetic_resistance",
+            "heat_resistance",
+            "regeneration",
+            "builtin_devices",
+            "base_weight_modifier",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "ship_type" => Some(FieldValue::of(&self.r#ship_type)),
+            "ship_rarity" => Some(FieldValue::of(&self.r#ship_rarity)),
+            "size_class" => Some(FieldValue::of(&self.r#size_class)),
+            "name" => Some(FieldValue::of(&self.r#name)),
+            "description" => Some(FieldValue::of(&self.r#description)),
+            "faction" => Some(FieldValue::of(&self.r#faction)),
+            "icon_image" => Some(FieldValue::of(&self.r#icon_image)),
+            "icon_scale" => Some(FieldValue::of(&self.r#icon_scale)),
+            "model_image" => Some(FieldValue::of(&self.r#model_image)),
+            "model_scale" => Some(FieldValue::of(&self.r#model_scale)),
+            "engine_color" => Some(FieldValue::of(&self.r#engine_color)),
+            "engines" => Some(FieldValue::of(&self.r#engines)),
+            "layout" => Some(FieldValue::of(&self.r#layout)),
+            "barrels" => Some(FieldValue::of(&self.r#barrels)),
+            "features" => Some(FieldValue::of(&self.r#features)),
+            "cells_expansions" => Some(FieldValue::of(&self.r#cells_expansions)),
+            "collider_tolerance" => Some(FieldValue::of(&self.r#collider_tolerance)),
+            "engine_position" => Some(FieldValue::of(&self.r#engine_position)),
+            "engine_size" => Some(FieldValue::of(&self.r#engine_size)),
+            "ship_category" => Some(FieldValue::of(&self.r#ship_category)),
+            "energy_resistance" => Some(FieldValue::of(&self.r#energy_resistance)),
+            "kinetic_resistance" => Some(FieldValue::of(&self.r#kinetic_resistance)),
+            "heat_resistance" => Some(FieldValue::of(&self.r#heat_resistance)),
+            "regeneration" => Some(FieldValue::of(&self.r#regeneration)),
+            "builtin_devices" => Some(FieldValue::of(&self.r#builtin_devices)),
+            "base_weight_modifier" => Some(FieldValue::of(&self.r#base_weight_modifier)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "ship_type" => value.write_to(&mut self.r#ship_type, "ship_type"),
+            "ship_rarity" => value.write_to(&mut self.r#ship_rarity, "ship_rarity"),
+            "size_class" => value.write_to(&mut self.r#size_class, "size_class"),
+            "name" => value.write_to(&mut self.r#name, "name"),
+            "description" => value.write_to(&mut self.r#description, "description"),
+            "faction" => value.write_to(&mut self.r#faction, "faction"),
+            "icon_image" => value.write_to(&mut self.r#icon_image, "icon_image"),
+            "icon_scale" => value.write_to(&mut self.r#icon_scale, "icon_scale"),
+            "model_image" => value.write_to(&mut self.r#model_image, "model_image"),
+            "model_scale" => value.write_to(&mut self.r#model_scale, "model_scale"),
+            "engine_color" => value.write_to(&mut self.r#engine_color, "engine_color"),
+            "engines" => value.write_to(&mut self.r#engines, "engines"),
+            "layout" => value.write_to(&mut self.r#layout, "layout"),
+            "barrels" => value.write_to(&mut self.r#barrels, "barrels"),
+            "features" => value.write_to(&mut self.r#features, "features"),
+            "cells_expansions" => value.write_to(&mut self.r#cells_expansions, "cells_expansions"),
+            "collider_tolerance" => {
+                value.write_to(&mut self.r#collider_tolerance, "collider_tolerance")
+            }
+            "engine_position" => value.write_to(&mut self.r#engine_position, "engine_position"),
+            "engine_size" => value.write_to(&mut self.r#engine_size, "engine_size"),
+            "ship_category" => value.write_to(&mut self.r#ship_category, "ship_category"),
+            "energy_resistance" => {
+                value.write_to(&mut self.r#energy_resistance, "energy_resistance")
+            }
+            "kinetic_resistance" => {
+                value.write_to(&mut self.r#kinetic_resistance, "kinetic_resistance")
+            }
+            "heat_resistance" => value.write_to(&mut self.r#heat_resistance, "heat_resistance"),
+            "regeneration" => value.write_to(&mut self.r#regeneration, "regeneration"),
+            "builtin_devices" => value.write_to(&mut self.r#builtin_devices, "builtin_devices"),
+            "base_weight_modifier" => {
+                value.write_to(&mut self.r#base_weight_modifier, "base_weight_modifier")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for Ship {}
 impl std::cmp::PartialEq for Ship {
     fn eq(&self, other: &Self) -> bool {
@@ -31224,6 +46159,10 @@ impl DatabaseItemWithId for Ship {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/ShipBuild.xml
@@ -31231,11 +46170,15 @@ pub type ShipBuildId = DatabaseItemId<ShipBuild>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct ShipBuild {
+    /// - Options: `notnull`
     pub r#id: ShipBuildId,
+    /// - Options: `notnull`
     pub r#ship_id: ShipId,
+    /// - Default: `true`
     #[serde(default = "default_true")]
     #[serde(skip_serializing_if = "skip_if_true")]
     pub r#available_for_player: bool,
+    /// - Default: `true`
     #[serde(default = "default_true")]
     #[serde(skip_serializing_if = "skip_if_true")]
     pub r#available_for_enemy: bool,
@@ -31249,14 +46192,18 @@ pub struct ShipBuild {
     pub r#custom_ai: Option<BehaviorTreeId>,
     #[serde(default)]
     pub r#components: Vec<InstalledComponent>,
+    /// - Default: `false`
+    /// - Options: `obsolete`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#not_available_in_game: bool,
     #[serde(default)]
     pub r#perks: ShipBuildPerks,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#extended_layout: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#random_color: bool,
@@ -31343,6 +46290,10 @@ impl ShipBuild {
         self.r#build_faction = r#build_faction.into();
         self
     }
+    pub fn clear_build_faction(&mut self) -> &mut Self {
+        self.r#build_faction = None;
+        self
+    }
     pub fn with_custom_ai(mut self, r#custom_ai: impl Into<Option<BehaviorTreeId>>) -> Self {
         self.r#custom_ai = r#custom_ai.into();
         self
@@ -31351,6 +46302,10 @@ impl ShipBuild {
         self.r#custom_ai = r#custom_ai.into();
         self
     }
+    pub fn clear_custom_ai(&mut self) -> &mut Self {
+        self.r#custom_ai = None;
+        self
+    }
     pub fn with_components(mut self, r#components: impl Into<Vec<InstalledComponent>>) -> Self {
         self.r#components = r#components.into();
         self
@@ -31411,6 +46366,10 @@ impl ShipBuild {
         self.r#left_satellite_build = r#left_satellite_build.into();
         self
     }
+    pub fn clear_left_satellite_build(&mut self) -> &mut Self {
+        self.r#left_satellite_build = None;
+        self
+    }
     pub fn with_right_satellite_build(
         mut self,
         r#right_satellite_build: impl Into<Option<SatelliteBuildId>>,
@@ -31425,6 +46384,10 @@ impl ShipBuild {
         self.r#right_satellite_build = r#right_satellite_build.into();
         self
     }
+    pub fn clear_right_satellite_build(&mut self) -> &mut Self {
+        self.r#right_satellite_build = None;
+        self
+    }
 }
 impl DatabaseItem for ShipBuild {
     fn validate(&self, mut ctx: DiagnosticContextRef) {
@@ -31451,11 +46414,100 @@ impl DatabaseItem for ShipBuild {
         "ShipBuild"
     }
 }
+impl VisitIds for ShipBuild {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#ship_id.visit_ids(visitor);
+        self.r#build_faction.visit_ids(visitor);
+        self.r#custom_ai.visit_ids(visitor);
+        self.r#components.visit_ids(visitor);
+        self.r#perks.visit_ids(visitor);
+        self.r#left_satellite_build.visit_ids(visitor);
+        self.r#right_satellite_build.visit_ids(visitor);
+    }
+}
+impl VisitAssets for ShipBuild {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#components.visit_assets(visitor);
+        self.r#perks.visit_assets(visitor);
+    }
+}
+impl DynamicFields for ShipBuild {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "ship_id",
+            "available_for_player",
+            "available_for_enemy",
+            "difficulty_class",
+            "build_faction",
+            "custom_ai",
+            "components",
+            "not_available_in_game",
+            "perks",
+            "extended_layout",
+            "random_color",
+            "left_satellite_build",
+            "right_satellite_build",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "ship_id" => Some(FieldValue::of(&self.r#ship_id)),
+            "available_for_player" => Some(FieldValue::of(&self.r#available_for_player)),
+            "available_for_enemy" => Some(FieldValue::of(&self.r#available_for_enemy)),
+            "difficulty_class" => Some(FieldValue::of(&self.r#difficulty_class)),
+            "build_faction" => Some(FieldValue::of(&self.r#build_faction)),
+            "custom_ai" => Some(FieldValue::of(&self.r#custom_ai)),
+            "components" => Some(FieldValue::of(&self.r#components)),
+            "not_available_in_game" => Some(FieldValue::of(&self.r#not_available_in_game)),
+            "perks" => Some(FieldValue::of(&self.r#perks)),
+            "extended_layout" => Some(FieldValue::of(&self.r#extended_layout)),
+            "random_color" => Some(FieldValue::of(&self.r#random_color)),
+            "left_satellite_build" => Some(FieldValue::of(&self.r#left_satellite_build)),
+            "right_satellite_build" => Some(FieldValue::of(&self.r#right_satellite_build)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "ship_id" => value.write_to(&mut self.r#ship_id, "ship_id"),
+            "available_for_player" => {
+                value.write_to(&mut self.r#available_for_player, "available_for_player")
+            }
+            "available_for_enemy" => {
+                value.write_to(&mut self.r#available_for_enemy, "available_for_enemy")
+            }
+            "difficulty_class" => value.write_to(&mut self.r#difficulty_class, "difficulty_class"),
+            "build_faction" => value.write_to(&mut self.r#build_faction, "build_faction"),
+            "custom_ai" => value.write_to(&mut self.r#custom_ai, "custom_ai"),
+            "components" => value.write_to(&mut self.r#components, "components"),
+            "not_available_in_game" => {
+                value.write_to(&mut self.r#not_available_in_game, "not_available_in_game")
+            }
+            "perks" => value.write_to(&mut self.r#perks, "perks"),
+            "extended_layout" => value.write_to(&mut self.r#extended_layout, "extended_layout"),
+            "random_color" => value.write_to(&mut self.r#random_color, "random_color"),
+            "left_satellite_build" => {
+                value.write_to(&mut self.r#left_satellite_build, "left_satellite_build")
+            }
+            "right_satellite_build" => {
+                value.write_to(&mut self.r#right_satellite_build, "right_satellite_build")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for ShipBuild {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/StatUpgradeTemplate.xml
@@ -31463,7 +46515,10 @@ pub type StatUpgradeTemplateId = DatabaseItemId<StatUpgradeTemplate>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct StatUpgradeTemplate {
+    /// - Options: `notnull`
     pub r#id: StatUpgradeTemplateId,
+    /// - Minimum: `0`
+    /// - Default: `20`
     #[serde(default = "default_20i32")]
     #[serde(skip_serializing_if = "skip_if_20i32")]
     pub r#max_level: i32,
@@ -31538,11 +46593,46 @@ impl DatabaseItem for StatUpgradeTemplate {
         "StatUpgradeTemplate"
     }
 }
+impl VisitIds for StatUpgradeTemplate {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for StatUpgradeTemplate {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for StatUpgradeTemplate {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "max_level", "stars", "credits", "resources"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "max_level" => Some(FieldValue::of(&self.r#max_level)),
+            "stars" => Some(FieldValue::of(&self.r#stars)),
+            "credits" => Some(FieldValue::of(&self.r#credits)),
+            "resources" => Some(FieldValue::of(&self.r#resources)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "max_level" => value.write_to(&mut self.r#max_level, "max_level"),
+            "stars" => value.write_to(&mut self.r#stars, "stars"),
+            "credits" => value.write_to(&mut self.r#credits, "credits"),
+            "resources" => value.write_to(&mut self.r#resources, "resources"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for StatUpgradeTemplate {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Technology.xml
@@ -31556,22 +46646,31 @@ pub enum Technology {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct TechnologyComponent {
+    /// - Options: `notnull`
     pub r#id: TechnologyId,
+    /// - Options: `notnull`
     pub r#item_id: ComponentId,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#faction: Option<FactionId>,
+    /// - Minimum: `0`
+    /// - Maximum: `10000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#price: i32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#hidden: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#special: bool,
     #[serde(default)]
     pub r#dependencies: Vec<TechnologyId>,
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#custom_crafting_level: i32,
@@ -31613,6 +46712,10 @@ impl TechnologyComponent {
         self.r#faction = r#faction.into();
         self
     }
+    pub fn clear_faction(&mut self) -> &mut Self {
+        self.r#faction = None;
+        self
+    }
     pub fn with_price(mut self, r#price: impl Into<i32>) -> Self {
         self.r#price = r#price.into();
         self
@@ -31682,6 +46785,58 @@ impl DatabaseItem for TechnologyComponent {
         "TechnologyComponent"
     }
 }
+impl VisitIds for TechnologyComponent {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#item_id.visit_ids(visitor);
+        self.r#faction.visit_ids(visitor);
+        self.r#dependencies.visit_ids(visitor);
+    }
+}
+impl VisitAssets for TechnologyComponent {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for TechnologyComponent {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "item_id",
+            "faction",
+            "price",
+            "hidden",
+            "special",
+            "dependencies",
+            "custom_crafting_level",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            "faction" => Some(FieldValue::of(&self.r#faction)),
+            "price" => Some(FieldValue::of(&self.r#price)),
+            "hidden" => Some(FieldValue::of(&self.r#hidden)),
+            "special" => Some(FieldValue::of(&self.r#special)),
+            "dependencies" => Some(FieldValue::of(&self.r#dependencies)),
+            "custom_crafting_level" => Some(FieldValue::of(&self.r#custom_crafting_level)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            "faction" => value.write_to(&mut self.r#faction, "faction"),
+            "price" => value.write_to(&mut self.r#price, "price"),
+            "hidden" => value.write_to(&mut self.r#hidden, "hidden"),
+            "special" => value.write_to(&mut self.r#special, "special"),
+            "dependencies" => value.write_to(&mut self.r#dependencies, "dependencies"),
+            "custom_crafting_level" => {
+                value.write_to(&mut self.r#custom_crafting_level, "custom_crafting_level")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl From<TechnologyComponent> for Technology {
     fn from(item: TechnologyComponent) -> Self {
         Self::Component(item)
@@ -31700,19 +46855,28 @@ impl Technology {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct TechnologyShip {
+    /// - Options: `notnull`
     pub r#id: TechnologyId,
+    /// - Options: `notnull`
     pub r#item_id: ShipId,
+    /// - Minimum: `0`
+    /// - Maximum: `10000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#price: i32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#hidden: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#special: bool,
     #[serde(default)]
     pub r#dependencies: Vec<TechnologyId>,
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#custom_crafting_level: i32,
@@ -31814,6 +46978,54 @@ impl DatabaseItem for TechnologyShip {
         "TechnologyShip"
     }
 }
+impl VisitIds for TechnologyShip {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#item_id.visit_ids(visitor);
+        self.r#dependencies.visit_ids(visitor);
+    }
+}
+impl VisitAssets for TechnologyShip {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for TechnologyShip {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "item_id",
+            "price",
+            "hidden",
+            "special",
+            "dependencies",
+            "custom_crafting_level",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            "price" => Some(FieldValue::of(&self.r#price)),
+            "hidden" => Some(FieldValue::of(&self.r#hidden)),
+            "special" => Some(FieldValue::of(&self.r#special)),
+            "dependencies" => Some(FieldValue::of(&self.r#dependencies)),
+            "custom_crafting_level" => Some(FieldValue::of(&self.r#custom_crafting_level)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            "price" => value.write_to(&mut self.r#price, "price"),
+            "hidden" => value.write_to(&mut self.r#hidden, "hidden"),
+            "special" => value.write_to(&mut self.r#special, "special"),
+            "dependencies" => value.write_to(&mut self.r#dependencies, "dependencies"),
+            "custom_crafting_level" => {
+                value.write_to(&mut self.r#custom_crafting_level, "custom_crafting_level")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl From<TechnologyShip> for Technology {
     fn from(item: TechnologyShip) -> Self {
         Self::Ship(item)
@@ -31832,22 +47044,31 @@ impl Technology {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct TechnologySatellite {
+    /// - Options: `notnull`
     pub r#id: TechnologyId,
+    /// - Options: `notnull`
     pub r#item_id: SatelliteId,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#faction: Option<FactionId>,
+    /// - Minimum: `0`
+    /// - Maximum: `10000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#price: i32,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#hidden: bool,
+    /// - Default: `false`
     #[serde(default = "default_false")]
     #[serde(skip_serializing_if = "skip_if_false")]
     pub r#special: bool,
     #[serde(default)]
     pub r#dependencies: Vec<TechnologyId>,
+    /// - Minimum: `0`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#custom_crafting_level: i32,
@@ -31889,6 +47110,10 @@ impl TechnologySatellite {
         self.r#faction = r#faction.into();
         self
     }
+    pub fn clear_faction(&mut self) -> &mut Self {
+        self.r#faction = None;
+        self
+    }
     pub fn with_price(mut self, r#price: impl Into<i32>) -> Self {
         self.r#price = r#price.into();
         self
@@ -31958,6 +47183,58 @@ impl DatabaseItem for TechnologySatellite {
         "TechnologySatellite"
     }
 }
+impl VisitIds for TechnologySatellite {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#item_id.visit_ids(visitor);
+        self.r#faction.visit_ids(visitor);
+        self.r#dependencies.visit_ids(visitor);
+    }
+}
+impl VisitAssets for TechnologySatellite {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {}
+}
+impl DynamicFields for TechnologySatellite {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "item_id",
+            "faction",
+            "price",
+            "hidden",
+            "special",
+            "dependencies",
+            "custom_crafting_level",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "item_id" => Some(FieldValue::of(&self.r#item_id)),
+            "faction" => Some(FieldValue::of(&self.r#faction)),
+            "price" => Some(FieldValue::of(&self.r#price)),
+            "hidden" => Some(FieldValue::of(&self.r#hidden)),
+            "special" => Some(FieldValue::of(&self.r#special)),
+            "dependencies" => Some(FieldValue::of(&self.r#dependencies)),
+            "custom_crafting_level" => Some(FieldValue::of(&self.r#custom_crafting_level)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "item_id" => value.write_to(&mut self.r#item_id, "item_id"),
+            "faction" => value.write_to(&mut self.r#faction, "faction"),
+            "price" => value.write_to(&mut self.r#price, "price"),
+            "hidden" => value.write_to(&mut self.r#hidden, "hidden"),
+            "special" => value.write_to(&mut self.r#special, "special"),
+            "dependencies" => value.write_to(&mut self.r#dependencies, "dependencies"),
+            "custom_crafting_level" => {
+                value.write_to(&mut self.r#custom_crafting_level, "custom_crafting_level")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl From<TechnologySatellite> for Technology {
     fn from(item: TechnologySatellite) -> Self {
         Self::Satellite(item)
@@ -32272,6 +47549,47 @@ impl DatabaseItem for Technology {
         "Technology"
     }
 }
+impl VisitIds for Technology {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        match self {
+            Self::Component(x) => x.visit_ids(visitor),
+            Self::Ship(x) => x.visit_ids(visitor),
+            Self::Satellite(x) => x.visit_ids(visitor),
+        }
+    }
+}
+impl VisitAssets for Technology {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        match self {
+            Self::Component(x) => x.visit_assets(visitor),
+            Self::Ship(x) => x.visit_assets(visitor),
+            Self::Satellite(x) => x.visit_assets(visitor),
+        }
+    }
+}
+impl DynamicFields for Technology {
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Component(x) => x.field_names(),
+            Self::Ship(x) => x.field_names(),
+            Self::Satellite(x) => x.field_names(),
+        }
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match self {
+            Self::Component(x) => x.get_field(name),
+            Self::Ship(x) => x.get_field(name),
+            Self::Satellite(x) => x.get_field(name),
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match self {
+            Self::Component(x) => x.set_field(name, value),
+            Self::Ship(x) => x.set_field(name, value),
+            Self::Satellite(x) => x.set_field(name, value),
+        }
+    }
+}
 impl Technology {
     pub fn inner_type_name(&self) -> &'static str {
         match self {
@@ -32281,11 +47599,26 @@ impl Technology {
         }
     }
 }
+/// Runs the same code for every variant of [Technology], binding the inner item to the given pattern
+#[macro_export]
+macro_rules! match_technology {
+    ($value:expr, |$inner:pat_param| $body:expr) => {
+        match $value {
+            $crate::schema::Technology::Component($inner) => $body,
+            $crate::schema::Technology::Ship($inner) => $body,
+            $crate::schema::Technology::Satellite($inner) => $body,
+        }
+    };
+}
 impl DatabaseItemWithId for Technology {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         *x.id()
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        x.id_mut()
+    }
 }
 
 // v1/Objects/Weapon/Ammunition.xml
@@ -32293,6 +47626,7 @@ pub type AmmunitionId = DatabaseItemId<Ammunition>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct Ammunition {
+    /// - Options: `notnull`
     pub r#id: AmmunitionId,
     #[serde(default)]
     pub r#body: BulletBody,
@@ -32394,11 +47728,65 @@ impl DatabaseItem for Ammunition {
         "Ammunition"
     }
 }
+impl VisitIds for Ammunition {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#body.visit_ids(visitor);
+        self.r#controller.visit_ids(visitor);
+        self.r#triggers.visit_ids(visitor);
+        self.r#effects.visit_ids(visitor);
+    }
+}
+impl VisitAssets for Ammunition {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#body.visit_assets(visitor);
+        self.r#controller.visit_assets(visitor);
+        self.r#triggers.visit_assets(visitor);
+        self.r#effects.visit_assets(visitor);
+    }
+}
+impl DynamicFields for Ammunition {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "body",
+            "controller",
+            "triggers",
+            "impact_type",
+            "effects",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "body" => Some(FieldValue::of(&self.r#body)),
+            "controller" => Some(FieldValue::of(&self.r#controller)),
+            "triggers" => Some(FieldValue::of(&self.r#triggers)),
+            "impact_type" => Some(FieldValue::of(&self.r#impact_type)),
+            "effects" => Some(FieldValue::of(&self.r#effects)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "body" => value.write_to(&mut self.r#body, "body"),
+            "controller" => value.write_to(&mut self.r#controller, "controller"),
+            "triggers" => value.write_to(&mut self.r#triggers, "triggers"),
+            "impact_type" => value.write_to(&mut self.r#impact_type, "impact_type"),
+            "effects" => value.write_to(&mut self.r#effects, "effects"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for Ammunition {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Weapon/BulletPerfab.xml
@@ -32406,25 +47794,37 @@ pub type BulletPrefabId = DatabaseItemId<BulletPrefab>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct BulletPrefab {
+    /// - Options: `notnull`
     pub r#id: BulletPrefabId,
     #[serde(default)]
     pub r#shape: BulletShape,
     #[serde(default)]
     pub r#image: String,
+    /// - Minimum: `0.01`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#size: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#margins: f32,
+    /// - Minimum: `-100`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#deformation: f32,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#main_color: String,
     #[serde(default)]
     pub r#main_color_mode: ColorMode,
+    /// - Default: `"#00000000"`
     #[serde(default = "default_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     #[serde(skip_serializing_if = "skip_if_ඞquoteඞඞhashඞ00000000ඞquoteඞ")]
     pub r#second_color: String,
@@ -32559,11 +47959,81 @@ impl DatabaseItem for BulletPrefab {
                 ctx.emit(DiagnosticKind::too_large(100f32, self.r#deformation));
             }
         }
+        {
+            let mut ctx = ctx.enter("main_color");
+            if !crate::color::Color::is_valid(&self.r#main_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#main_color));
+            }
+        }
+        {
+            let mut ctx = ctx.enter("second_color");
+            if !crate::color::Color::is_valid(&self.r#second_color) {
+                ctx.emit(DiagnosticKind::invalid_color(&self.r#second_color));
+            }
+        }
     }
     fn type_name() -> &'static str {
         "BulletPrefab"
     }
 }
+impl VisitIds for BulletPrefab {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {}
+}
+impl VisitAssets for BulletPrefab {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#image.is_empty() {
+            visitor(AssetKind::Image, &self.r#image);
+        }
+    }
+}
+impl DynamicFields for BulletPrefab {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "shape",
+            "image",
+            "size",
+            "margins",
+            "deformation",
+            "main_color",
+            "main_color_mode",
+            "second_color",
+            "second_color_mode",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "shape" => Some(FieldValue::of(&self.r#shape)),
+            "image" => Some(FieldValue::of(&self.r#image)),
+            "size" => Some(FieldValue::of(&self.r#size)),
+            "margins" => Some(FieldValue::of(&self.r#margins)),
+            "deformation" => Some(FieldValue::of(&self.r#deformation)),
+            "main_color" => Some(FieldValue::of(&self.r#main_color)),
+            "main_color_mode" => Some(FieldValue::of(&self.r#main_color_mode)),
+            "second_color" => Some(FieldValue::of(&self.r#second_color)),
+            "second_color_mode" => Some(FieldValue::of(&self.r#second_color_mode)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "shape" => value.write_to(&mut self.r#shape, "shape"),
+            "image" => value.write_to(&mut self.r#image, "image"),
+            "size" => value.write_to(&mut self.r#size, "size"),
+            "margins" => value.write_to(&mut self.r#margins, "margins"),
+            "deformation" => value.write_to(&mut self.r#deformation, "deformation"),
+            "main_color" => value.write_to(&mut self.r#main_color, "main_color"),
+            "main_color_mode" => value.write_to(&mut self.r#main_color_mode, "main_color_mode"),
+            "second_color" => value.write_to(&mut self.r#second_color, "second_color"),
+            "second_color_mode" => {
+                value.write_to(&mut self.r#second_color_mode, "second_color_mode")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for BulletPrefab {}
 impl std::cmp::PartialEq for BulletPrefab {
     fn eq(&self, other: &Self) -> bool {
@@ -32600,6 +48070,10 @@ impl DatabaseItemWithId for BulletPrefab {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Weapon/VisualEffect.xml
@@ -32607,6 +48081,7 @@ pub type VisualEffectId = DatabaseItemId<VisualEffect>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub struct VisualEffect {
+    /// - Options: `notnull`
     pub r#id: VisualEffectId,
     #[serde(default)]
     pub r#elements: Vec<VisualEffectElement>,
@@ -32649,11 +48124,44 @@ impl DatabaseItem for VisualEffect {
         "VisualEffect"
     }
 }
+impl VisitIds for VisualEffect {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#elements.visit_ids(visitor);
+    }
+}
+impl VisitAssets for VisualEffect {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        self.r#elements.visit_assets(visitor);
+    }
+}
+impl DynamicFields for VisualEffect {
+    fn field_names(&self) -> &'static [&'static str] {
+        &["id", "elements"]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "elements" => Some(FieldValue::of(&self.r#elements)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "elements" => value.write_to(&mut self.r#elements, "elements"),
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl DatabaseItemWithId for VisualEffect {
     fn id(&self) -> DatabaseItemId<Self> {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // v1/Objects/Weapon/Weapon.xml
@@ -32661,15 +48169,25 @@ pub type WeaponId = DatabaseItemId<Weapon>;
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Weapon {
+    /// - Options: `notnull`
     pub r#id: WeaponId,
     #[serde(default)]
     pub r#weapon_class: WeaponClass,
+    /// - Minimum: `0`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#fire_rate: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `360`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#spread: f32,
+    /// - Minimum: `0`
+    /// - Maximum: `1000000000`
+    /// - Default: `0`
     #[serde(default = "default_0")]
     #[serde(skip_serializing_if = "skip_if_0")]
     pub r#magazine: i32,
@@ -32684,6 +48202,9 @@ pub struct Weapon {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub r#visual_effect: Option<VisualEffectId>,
+    /// - Minimum: `0`
+    /// - Maximum: `100`
+    /// - Default: `0`
     #[serde(default = "default_0ඞdotඞ0")]
     #[serde(skip_serializing_if = "skip_if_0ඞdotඞ0")]
     pub r#effect_size: f32,
@@ -32796,6 +48317,10 @@ impl Weapon {
         self.r#visual_effect = r#visual_effect.into();
         self
     }
+    pub fn clear_visual_effect(&mut self) -> &mut Self {
+        self.r#visual_effect = None;
+        self
+    }
     pub fn with_effect_size(mut self, r#effect_size: impl Into<f32>) -> Self {
         self.r#effect_size = r#effect_size.into();
         self
@@ -32859,6 +48384,83 @@ impl DatabaseItem for Weapon {
         "Weapon"
     }
 }
+impl VisitIds for Weapon {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        self.r#visual_effect.visit_ids(visitor);
+    }
+}
+impl VisitAssets for Weapon {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        if !self.r#shot_sound.is_empty() {
+            visitor(AssetKind::AudioClip, &self.r#shot_sound);
+        }
+        if !self.r#charge_sound.is_empty() {
+            visitor(AssetKind::AudioClip, &self.r#charge_sound);
+        }
+        if !self.r#shot_effect_prefab.is_empty() {
+            visitor(AssetKind::Prefab, &self.r#shot_effect_prefab);
+        }
+        if !self.r#control_button_icon.is_empty() {
+            visitor(AssetKind::Image, &self.r#control_button_icon);
+        }
+    }
+}
+impl DynamicFields for Weapon {
+    fn field_names(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "weapon_class",
+            "fire_rate",
+            "spread",
+            "magazine",
+            "activation_type",
+            "shot_sound",
+            "charge_sound",
+            "shot_effect_prefab",
+            "visual_effect",
+            "effect_size",
+            "control_button_icon",
+        ]
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match name {
+            "id" => Some(FieldValue::of(&self.r#id)),
+            "weapon_class" => Some(FieldValue::of(&self.r#weapon_class)),
+            "fire_rate" => Some(FieldValue::of(&self.r#fire_rate)),
+            "spread" => Some(FieldValue::of(&self.r#spread)),
+            "magazine" => Some(FieldValue::of(&self.r#magazine)),
+            "activation_type" => Some(FieldValue::of(&self.r#activation_type)),
+            "shot_sound" => Some(FieldValue::of(&self.r#shot_sound)),
+            "charge_sound" => Some(FieldValue::of(&self.r#charge_sound)),
+            "shot_effect_prefab" => Some(FieldValue::of(&self.r#shot_effect_prefab)),
+            "visual_effect" => Some(FieldValue::of(&self.r#visual_effect)),
+            "effect_size" => Some(FieldValue::of(&self.r#effect_size)),
+            "control_button_icon" => Some(FieldValue::of(&self.r#control_button_icon)),
+            _ => None,
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match name {
+            "id" => value.write_to(&mut self.r#id, "id"),
+            "weapon_class" => value.write_to(&mut self.r#weapon_class, "weapon_class"),
+            "fire_rate" => value.write_to(&mut self.r#fire_rate, "fire_rate"),
+            "spread" => value.write_to(&mut self.r#spread, "spread"),
+            "magazine" => value.write_to(&mut self.r#magazine, "magazine"),
+            "activation_type" => value.write_to(&mut self.r#activation_type, "activation_type"),
+            "shot_sound" => value.write_to(&mut self.r#shot_sound, "shot_sound"),
+            "charge_sound" => value.write_to(&mut self.r#charge_sound, "charge_sound"),
+            "shot_effect_prefab" => {
+                value.write_to(&mut self.r#shot_effect_prefab, "shot_effect_prefab")
+            }
+            "visual_effect" => value.write_to(&mut self.r#visual_effect, "visual_effect"),
+            "effect_size" => value.write_to(&mut self.r#effect_size, "effect_size"),
+            "control_button_icon" => {
+                value.write_to(&mut self.r#control_button_icon, "control_button_icon")
+            }
+            _ => Err(FieldError::UnknownField(name.to_string())),
+        }
+    }
+}
 impl std::cmp::Eq for Weapon {}
 impl std::cmp::PartialEq for Weapon {
     fn eq(&self, other: &Self) -> bool {
@@ -32900,6 +48502,10 @@ impl DatabaseItemWithId for Weapon {
         let x = self;
         x.id
     }
+    fn id_mut(&mut self) -> &mut DatabaseItemId<Self> {
+        let x = self;
+        &mut x.id
+    }
 }
 
 // Core Database Item
@@ -34167,6 +49773,227 @@ impl DatabaseItem for Item {
         "Item"
     }
 }
+impl VisitIds for Item {
+    fn visit_ids(&mut self, visitor: &mut dyn FnMut(&'static str, &mut i32)) {
+        match self {
+            Self::Component(x) => x.visit_ids(visitor),
+            Self::Device(x) => x.visit_ids(visitor),
+            Self::Weapon(x) => x.visit_ids(visitor),
+            Self::AmmunitionObsolete(x) => x.visit_ids(visitor),
+            Self::DroneBay(x) => x.visit_ids(visitor),
+            Self::Ship(x) => x.visit_ids(visitor),
+            Self::Satellite(x) => x.visit_ids(visitor),
+            Self::ShipBuild(x) => x.visit_ids(visitor),
+            Self::SatelliteBuild(x) => x.visit_ids(visitor),
+            Self::Technology(x) => x.visit_ids(visitor),
+            Self::ComponentStats(x) => x.visit_ids(visitor),
+            Self::ComponentMod(x) => x.visit_ids(visitor),
+            Self::Faction(x) => x.visit_ids(visitor),
+            Self::Quest(x) => x.visit_ids(visitor),
+            Self::Loot(x) => x.visit_ids(visitor),
+            Self::Fleet(x) => x.visit_ids(visitor),
+            Self::Character(x) => x.visit_ids(visitor),
+            Self::QuestItem(x) => x.visit_ids(visitor),
+            Self::Ammunition(x) => x.visit_ids(visitor),
+            Self::VisualEffect(x) => x.visit_ids(visitor),
+            Self::BulletPrefab(x) => x.visit_ids(visitor),
+            Self::BehaviorTree(x) => x.visit_ids(visitor),
+            Self::GameObjectPrefab(x) => x.visit_ids(visitor),
+            Self::CombatRules(x) => x.visit_ids(visitor),
+            Self::ComponentStatUpgrade(x) => x.visit_ids(visitor),
+            Self::StatUpgradeTemplate(x) => x.visit_ids(visitor),
+            Self::ShipSettings(x) => x.visit_ids(visitor),
+            Self::GalaxySettings(x) => x.visit_ids(visitor),
+            Self::DatabaseSettings(x) => x.visit_ids(visitor),
+            Self::ExplorationSettings(x) => x.visit_ids(visitor),
+            Self::ShipModSettings(x) => x.visit_ids(visitor),
+            Self::SpecialEventSettings(x) => x.visit_ids(visitor),
+            Self::SkillSettings(x) => x.visit_ids(visitor),
+            Self::DebugSettings(x) => x.visit_ids(visitor),
+            Self::CombatSettings(x) => x.visit_ids(visitor),
+            Self::UiSettings(x) => x.visit_ids(visitor),
+            Self::FactionsSettings(x) => x.visit_ids(visitor),
+            Self::MusicPlaylist(x) => x.visit_ids(visitor),
+            Self::LocalizationSettings(x) => x.visit_ids(visitor),
+        }
+    }
+}
+impl VisitAssets for Item {
+    fn visit_assets(&self, visitor: &mut dyn FnMut(AssetKind, &str)) {
+        match self {
+            Self::Component(x) => x.visit_assets(visitor),
+            Self::Device(x) => x.visit_assets(visitor),
+            Self::Weapon(x) => x.visit_assets(visitor),
+            Self::AmmunitionObsolete(x) => x.visit_assets(visitor),
+            Self::DroneBay(x) => x.visit_assets(visitor),
+            Self::Ship(x) => x.visit_assets(visitor),
+            Self::Satellite(x) => x.visit_assets(visitor),
+            Self::ShipBuild(x) => x.visit_assets(visitor),
+            Self::SatelliteBuild(x) => x.visit_assets(visitor),
+            Self::Technology(x) => x.visit_assets(visitor),
+            Self::ComponentStats(x) => x.visit_assets(visitor),
+            Self::ComponentMod(x) => x.visit_assets(visitor),
+            Self::Faction(x) => x.visit_assets(visitor),
+            Self::Quest(x) => x.visit_assets(visitor),
+            Self::Loot(x) => x.visit_assets(visitor),
+            Self::Fleet(x) => x.visit_assets(visitor),
+            Self::Character(x) => x.visit_assets(visitor),
+            Self::QuestItem(x) => x.visit_assets(visitor),
+            Self::Ammunition(x) => x.visit_assets(visitor),
+            Self::VisualEffect(x) => x.visit_assets(visitor),
+            Self::BulletPrefab(x) => x.visit_assets(visitor),
+            Self::BehaviorTree(x) => x.visit_assets(visitor),
+            Self::GameObjectPrefab(x) => x.visit_assets(visitor),
+            Self::CombatRules(x) => x.visit_assets(visitor),
+            Self::ComponentStatUpgrade(x) => x.visit_assets(visitor),
+            Self::StatUpgradeTemplate(x) => x.visit_assets(visitor),
+            Self::ShipSettings(x) => x.visit_assets(visitor),
+            Self::GalaxySettings(x) => x.visit_assets(visitor),
+            Self::DatabaseSettings(x) => x.visit_assets(visitor),
+            Self::ExplorationSettings(x) => x.visit_assets(visitor),
+            Self::ShipModSettings(x) => x.visit_assets(visitor),
+            Self::SpecialEventSettings(x) => x.visit_assets(visitor),
+            Self::SkillSettings(x) => x.visit_assets(visitor),
+            Self::DebugSettings(x) => x.visit_assets(visitor),
+            Self::CombatSettings(x) => x.visit_assets(visitor),
+            Self::UiSettings(x) => x.visit_assets(visitor),
+            Self::FactionsSettings(x) => x.visit_assets(visitor),
+            Self::MusicPlaylist(x) => x.visit_assets(visitor),
+            Self::LocalizationSettings(x) => x.visit_assets(visitor),
+        }
+    }
+}
+impl DynamicFields for Item {
+    fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Component(x) => x.field_names(),
+            Self::Device(x) => x.field_names(),
+            Self::Weapon(x) => x.field_names(),
+            Self::AmmunitionObsolete(x) => x.field_names(),
+            Self::DroneBay(x) => x.field_names(),
+            Self::Ship(x) => x.field_names(),
+            Self::Satellite(x) => x.field_names(),
+            Self::ShipBuild(x) => x.field_names(),
+            Self::SatelliteBuild(x) => x.field_names(),
+            Self::Technology(x) => x.field_names(),
+            Self::ComponentStats(x) => x.field_names(),
+            Self::ComponentMod(x) => x.field_names(),
+            Self::Faction(x) => x.field_names(),
+            Self::Quest(x) => x.field_names(),
+            Self::Loot(x) => x.field_names(),
+            Self::Fleet(x) => x.field_names(),
+            Self::Character(x) => x.field_names(),
+            Self::QuestItem(x) => x.field_names(),
+            Self::Ammunition(x) => x.field_names(),
+            Self::VisualEffect(x) => x.field_names(),
+            Self::BulletPrefab(x) => x.field_names(),
+            Self::BehaviorTree(x) => x.field_names(),
+            Self::GameObjectPrefab(x) => x.field_names(),
+            Self::CombatRules(x) => x.field_names(),
+            Self::ComponentStatUpgrade(x) => x.field_names(),
+            Self::StatUpgradeTemplate(x) => x.field_names(),
+            Self::ShipSettings(x) => x.field_names(),
+            Self::GalaxySettings(x) => x.field_names(),
+            Self::DatabaseSettings(x) => x.field_names(),
+            Self::ExplorationSettings(x) => x.field_names(),
+            Self::ShipModSettings(x) => x.field_names(),
+            Self::SpecialEventSettings(x) => x.field_names(),
+            Self::SkillSettings(x) => x.field_names(),
+            Self::DebugSettings(x) => x.field_names(),
+            Self::CombatSettings(x) => x.field_names(),
+            Self::UiSettings(x) => x.field_names(),
+            Self::FactionsSettings(x) => x.field_names(),
+            Self::MusicPlaylist(x) => x.field_names(),
+            Self::LocalizationSettings(x) => x.field_names(),
+        }
+    }
+    fn get_field(&self, name: &str) -> Option<FieldValue> {
+        match self {
+            Self::Component(x) => x.get_field(name),
+            Self::Device(x) => x.get_field(name),
+            Self::Weapon(x) => x.get_field(name),
+            Self::AmmunitionObsolete(x) => x.get_field(name),
+            Self::DroneBay(x) => x.get_field(name),
+            Self::Ship(x) => x.get_field(name),
+            Self::Satellite(x) => x.get_field(name),
+            Self::ShipBuild(x) => x.get_field(name),
+            Self::SatelliteBuild(x) => x.get_field(name),
+            Self::Technology(x) => x.get_field(name),
+            Self::ComponentStats(x) => x.get_field(name),
+            Self::ComponentMod(x) => x.get_field(name),
+            Self::Faction(x) => x.get_field(name),
+            Self::Quest(x) => x.get_field(name),
+            Self::Loot(x) => x.get_field(name),
+            Self::Fleet(x) => x.get_field(name),
+            Self::Character(x) => x.get_field(name),
+            Self::QuestItem(x) => x.get_field(name),
+            Self::Ammunition(x) => x.get_field(name),
+            Self::VisualEffect(x) => x.get_field(name),
+            Self::BulletPrefab(x) => x.get_field(name),
+            Self::BehaviorTree(x) => x.get_field(name),
+            Self::GameObjectPrefab(x) => x.get_field(name),
+            Self::CombatRules(x) => x.get_field(name),
+            Self::ComponentStatUpgrade(x) => x.get_field(name),
+            Self::StatUpgradeTemplate(x) => x.get_field(name),
+            Self::ShipSettings(x) => x.get_field(name),
+            Self::GalaxySettings(x) => x.get_field(name),
+            Self::DatabaseSettings(x) => x.get_field(name),
+            Self::ExplorationSettings(x) => x.get_field(name),
+            Self::ShipModSettings(x) => x.get_field(name),
+            Self::SpecialEventSettings(x) => x.get_field(name),
+            Self::SkillSettings(x) => x.get_field(name),
+            Self::DebugSettings(x) => x.get_field(name),
+            Self::CombatSettings(x) => x.get_field(name),
+            Self::UiSettings(x) => x.get_field(name),
+            Self::FactionsSettings(x) => x.get_field(name),
+            Self::MusicPlaylist(x) => x.get_field(name),
+            Self::LocalizationSettings(x) => x.get_field(name),
+        }
+    }
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), FieldError> {
+        match self {
+            Self::Component(x) => x.set_field(name, value),
+            Self::Device(x) => x.set_field(name, value),
+            Self::Weapon(x) => x.set_field(name, value),
+            Self::AmmunitionObsolete(x) => x.set_field(name, value),
+            Self::DroneBay(x) => x.set_field(name, value),
+            Self::Ship(x) => x.set_field(name, value),
+            Self::Satellite(x) => x.set_field(name, value),
+            Self::ShipBuild(x) => x.set_field(name, value),
+            Self::SatelliteBuild(x) => x.set_field(name, value),
+            Self::Technology(x) => x.set_field(name, value),
+            Self::ComponentStats(x) => x.set_field(name, value),
+            Self::ComponentMod(x) => x.set_field(name, value),
+            Self::Faction(x) => x.set_field(name, value),
+            Self::Quest(x) => x.set_field(name, value),
+            Self::Loot(x) => x.set_field(name, value),
+            Self::Fleet(x) => x.set_field(name, value),
+            Self::Character(x) => x.set_field(name, value),
+            Self::QuestItem(x) => x.set_field(name, value),
+            Self::Ammunition(x) => x.set_field(name, value),
+            Self::VisualEffect(x) => x.set_field(name, value),
+            Self::BulletPrefab(x) => x.set_field(name, value),
+            Self::BehaviorTree(x) => x.set_field(name, value),
+            Self::GameObjectPrefab(x) => x.set_field(name, value),
+            Self::CombatRules(x) => x.set_field(name, value),
+            Self::ComponentStatUpgrade(x) => x.set_field(name, value),
+            Self::StatUpgradeTemplate(x) => x.set_field(name, value),
+            Self::ShipSettings(x) => x.set_field(name, value),
+            Self::GalaxySettings(x) => x.set_field(name, value),
+            Self::DatabaseSettings(x) => x.set_field(name, value),
+            Self::ExplorationSettings(x) => x.set_field(name, value),
+            Self::ShipModSettings(x) => x.set_field(name, value),
+            Self::SpecialEventSettings(x) => x.set_field(name, value),
+            Self::SkillSettings(x) => x.set_field(name, value),
+            Self::DebugSettings(x) => x.set_field(name, value),
+            Self::CombatSettings(x) => x.set_field(name, value),
+            Self::UiSettings(x) => x.set_field(name, value),
+            Self::FactionsSettings(x) => x.set_field(name, value),
+            Self::MusicPlaylist(x) => x.set_field(name, value),
+            Self::LocalizationSettings(x) => x.set_field(name, value),
+        }
+    }
+}
 impl Item {
     pub fn inner_type_name(&self) -> &'static str {
         match self {
@@ -34212,6 +50039,53 @@ impl Item {
         }
     }
 }
+/// Runs the same code for every variant of [Item], binding the inner item to the given pattern
+#[macro_export]
+macro_rules! match_item {
+    ($value:expr, |$inner:pat_param| $body:expr) => {
+        match $value {
+            $crate::schema::Item::Component($inner) => $body,
+            $crate::schema::Item::Device($inner) => $body,
+            $crate::schema::Item::Weapon($inner) => $body,
+            $crate::schema::Item::AmmunitionObsolete($inner) => $body,
+            $crate::schema::Item::DroneBay($inner) => $body,
+            $crate::schema::Item::Ship($inner) => $body,
+            $crate::schema::Item::Satellite($inner) => $body,
+            $crate::schema::Item::ShipBuild($inner) => $body,
+            $crate::schema::Item::SatelliteBuild($inner) => $body,
+            $crate::schema::Item::Technology($inner) => $body,
+            $crate::schema::Item::ComponentStats($inner) => $body,
+            $crate::schema::Item::ComponentMod($inner) => $body,
+            $crate::schema::Item::Faction($inner) => $body,
+            $crate::schema::Item::Quest($inner) => $body,
+            $crate::schema::Item::Loot($inner) => $body,
+            $crate::schema::Item::Fleet($inner) => $body,
+            $crate::schema::Item::Character($inner) => $body,
+            $crate::schema::Item::QuestItem($inner) => $body,
+            $crate::schema::Item::Ammunition($inner) => $body,
+            $crate::schema::Item::VisualEffect($inner) => $body,
+            $crate::schema::Item::BulletPrefab($inner) => $body,
+            $crate::schema::Item::BehaviorTree($inner) => $body,
+            $crate::schema::Item::GameObjectPrefab($inner) => $body,
+            $crate::schema::Item::CombatRules($inner) => $body,
+            $crate::schema::Item::ComponentStatUpgrade($inner) => $body,
+            $crate::schema::Item::StatUpgradeTemplate($inner) => $body,
+            $crate::schema::Item::ShipSettings($inner) => $body,
+            $crate::schema::Item::GalaxySettings($inner) => $body,
+            $crate::schema::Item::DatabaseSettings($inner) => $body,
+            $crate::schema::Item::ExplorationSettings($inner) => $body,
+            $crate::schema::Item::ShipModSettings($inner) => $body,
+            $crate::schema::Item::SpecialEventSettings($inner) => $body,
+            $crate::schema::Item::SkillSettings($inner) => $body,
+            $crate::schema::Item::DebugSettings($inner) => $body,
+            $crate::schema::Item::CombatSettings($inner) => $body,
+            $crate::schema::Item::UiSettings($inner) => $body,
+            $crate::schema::Item::FactionsSettings($inner) => $body,
+            $crate::schema::Item::MusicPlaylist($inner) => $body,
+            $crate::schema::Item::LocalizationSettings($inner) => $body,
+        }
+    };
+}
 impl Item {
     /// Fetches untyped ID of the inner item, or None if content is a setting
     pub fn id(&self) -> Option<i32> {
@@ -34297,22 +50171,55 @@ macro_rules! apply_constructors {
 #[macro_export]
 macro_rules! apply_all_items {
     ($macro_name:ident) => {
-        $macro_name! { component : Component, device : Device, weapon : Weapon,
-        ammunition_obsolete : AmmunitionObsolete, drone_bay : DroneBay, ship : Ship,
-        satellite : Satellite, ship_build : ShipBuild, satellite_build : SatelliteBuild,
-        technology : Technology, component_stats : ComponentStats, component_mod :
-        ComponentMod, faction : Faction, quest : Quest, loot : Loot, fleet : Fleet,
-        character : Character, quest_item : QuestItem, ammunition : Ammunition,
-        visual_effect : VisualEffect, bullet_prefab : BulletPrefab, behavior_tree :
-        BehaviorTree, game_object_prefab : GameObjectPrefab, combat_rules : CombatRules,
-        component_stat_upgrade : ComponentStatUpgrade, stat_upgrade_template :
-        StatUpgradeTemplate, ship_settings : ShipSettings, galaxy_settings :
-        GalaxySettings, database_settings : DatabaseSettings, exploration_settings :
-        ExplorationSettings, ship_mod_settings : ShipModSettings, special_event_settings
-        : SpecialEventSettings, skill_settings : SkillSettings, debug_settings :
-        DebugSettings, combat_settings : CombatSettings, ui_settings : UiSettings,
-        factions_settings : FactionsSettings, music_playlist : MusicPlaylist,
-        localization_settings : LocalizationSettings }
+        $macro_name! { component : Component { has_id : true, is_setting : false,
+        type_name : "Component" }, device : Device { has_id : true, is_setting : false,
+        type_name : "Device" }, weapon : Weapon { has_id : true, is_setting : false,
+        type_name : "Weapon" }, ammunition_obsolete : AmmunitionObsolete { has_id : true,
+        is_setting : false, type_name : "AmmunitionObsolete" }, drone_bay : DroneBay {
+        has_id : true, is_setting : false, type_name : "DroneBay" }, ship : Ship { has_id
+        : true, is_setting : false, type_name : "Ship" }, satellite : Satellite { has_id
+        : true, is_setting : false, type_name : "Satellite" }, ship_build : ShipBuild {
+        has_id : true, is_setting : false, type_name : "ShipBuild" }, satellite_build :
+        SatelliteBuild { has_id : true, is_setting : false, type_name : "SatelliteBuild"
+        }, technology : Technology { has_id : true, is_setting : false, type_name :
+        "Technology" }, component_stats : ComponentStats { has_id : true, is_setting :
+        false, type_name : "ComponentStats" }, component_mod : ComponentMod { has_id :
+        true, is_setting : false, type_name : "ComponentMod" }, faction : Faction {
+        has_id : true, is_setting : false, type_name : "Faction" }, quest : Quest {
+        has_id : true, is_setting : false, type_name : "Quest" }, loot : Loot { has_id :
+        true, is_setting : false, type_name : "Loot" }, fleet : Fleet { has_id : true,
+        is_setting : false, type_name : "Fleet" }, character : Character { has_id : true,
+        is_setting : false, type_name : "Character" }, quest_item : QuestItem { has_id :
+        true, is_setting : false, type_name : "QuestItem" }, ammunition : Ammunition {
+        has_id : true, is_setting : false, type_name : "Ammunition" }, visual_effect :
+        VisualEffect { has_id : true, is_setting : false, type_name : "VisualEffect" },
+        bullet_prefab : BulletPrefab { has_id : true, is_setting : false, type_name :
+        "BulletPrefab" }, behavior_tree : BehaviorTree { has_id : true, is_setting :
+        false, type_name : "BehaviorTree" }, game_object_prefab : GameObjectPrefab {
+        has_id : true, is_setting : false, type_name : "GameObjectPrefab" }, combat_rules
+        : CombatRules { has_id : true, is_setting : false, type_name : "CombatRules" },
+        component_stat_upgrade : ComponentStatUpgrade { has_id : true, is_setting :
+        false, type_name : "ComponentStatUpgrade" }, stat_upgrade_template :
+        StatUpgradeTemplate { has_id : true, is_setting : false, type_name :
+        "StatUpgradeTemplate" }, ship_settings : ShipSettings { has_id : false,
+        is_setting : true, type_name : "ShipSettings" }, galaxy_settings : GalaxySettings
+        { has_id : false, is_setting : true, type_name : "GalaxySettings" },
+        database_settings : DatabaseSettings { has_id : false, is_setting : true,
+        type_name : "DatabaseSettings" }, exploration_settings : ExplorationSettings {
+        has_id : false, is_setting : true, type_name : "ExplorationSettings" },
+        ship_mod_settings : ShipModSettings { has_id : false, is_setting : true,
+        type_name : "ShipModSettings" }, special_event_settings : SpecialEventSettings {
+        has_id : false, is_setting : true, type_name : "SpecialEventSettings" },
+        skill_settings : SkillSettings { has_id : false, is_setting : true, type_name :
+        "SkillSettings" }, debug_settings : DebugSettings { has_id : false, is_setting :
+        true, type_name : "DebugSettings" }, combat_settings : CombatSettings { has_id :
+        false, is_setting : true, type_name : "CombatSettings" }, ui_settings :
+        UiSettings { has_id : false, is_setting : true, type_name : "UiSettings" },
+        factions_settings : FactionsSettings { has_id : false, is_setting : true,
+        type_name : "FactionsSettings" }, music_playlist : MusicPlaylist { has_id :
+        false, is_setting : true, type_name : "MusicPlaylist" }, localization_settings :
+        LocalizationSettings { has_id : false, is_setting : true, type_name :
+        "LocalizationSettings" } }
     };
 }
 #[macro_export]
@@ -34330,19 +50237,94 @@ macro_rules! apply_all_settings {
 #[macro_export]
 macro_rules! apply_all_collections {
     ($macro_name:ident) => {
-        $macro_name! { component : Component, device : Device, weapon : Weapon,
-        ammunition_obsolete : AmmunitionObsolete, drone_bay : DroneBay, ship : Ship,
-        satellite : Satellite, ship_build : ShipBuild, satellite_build : SatelliteBuild,
-        technology : Technology, component_stats : ComponentStats, component_mod :
-        ComponentMod, faction : Faction, quest : Quest, loot : Loot, fleet : Fleet,
-        character : Character, quest_item : QuestItem, ammunition : Ammunition,
-        visual_effect : VisualEffect, bullet_prefab : BulletPrefab, behavior_tree :
-        BehaviorTree, game_object_prefab : GameObjectPrefab, combat_rules : CombatRules,
-        component_stat_upgrade : ComponentStatUpgrade, stat_upgrade_template :
-        StatUpgradeTemplate }
+        $macro_name! { component : Component { has_id : true, is_setting : false,
+        type_name : "Component" }, device : Device { has_id : true, is_setting : false,
+        type_name : "Device" }, weapon : Weapon { has_id : true, is_setting : false,
+        type_name : "Weapon" }, ammunition_obsolete : AmmunitionObsolete { has_id : true,
+        is_setting : false, type_name : "AmmunitionObsolete" }, drone_bay : DroneBay {
+        has_id : true, is_setting : false, type_name : "DroneBay" }, ship : Ship { has_id
+        : true, is_setting : false, type_name : "Ship" }, satellite : Satellite { has_id
+        : true, is_setting : false, type_name : "Satellite" }, ship_build : ShipBuild {
+        has_id : true, is_setting : false, type_name : "ShipBuild" }, satellite_build :
+        SatelliteBuild { has_id : true, is_setting : false, type_name : "SatelliteBuild"
+        }, technology : Technology { has_id : true, is_setting : false, type_name :
+        "Technology" }, component_stats : ComponentStats { has_id : true, is_setting :
+        false, type_name : "ComponentStats" }, component_mod : ComponentMod { has_id :
+        true, is_setting : false, type_name : "ComponentMod" }, faction : Faction {
+        has_id : true, is_setting : false, type_name : "Faction" }, quest : Quest {
+        has_id : true, is_setting : false, type_name : "Quest" }, loot : Loot { has_id :
+        true, is_setting : false, type_name : "Loot" }, fleet : Fleet { has_id : true,
+        is_setting : false, type_name : "Fleet" }, character : Character { has_id : true,
+        is_setting : false, type_name : "Character" }, quest_item : QuestItem { has_id :
+        true, is_setting : false, type_name : "QuestItem" }, ammunition : Ammunition {
+        has_id : true, is_setting : false, type_name : "Ammunition" }, visual_effect :
+        VisualEffect { has_id : true, is_setting : false, type_name : "VisualEffect" },
+        bullet_prefab : BulletPrefab { has_id : true, is_setting : false, type_name :
+        "BulletPrefab" }, behavior_tree : BehaviorTree { has_id : true, is_setting :
+        false, type_name : "BehaviorTree" }, game_object_prefab : GameObjectPrefab {
+        has_id : true, is_setting : false, type_name : "GameObjectPrefab" }, combat_rules
+        : CombatRules { has_id : true, is_setting : false, type_name : "CombatRules" },
+        component_stat_upgrade : ComponentStatUpgrade { has_id : true, is_setting :
+        false, type_name : "ComponentStatUpgrade" }, stat_upgrade_template :
+        StatUpgradeTemplate { has_id : true, is_setting : false, type_name :
+        "StatUpgradeTemplate" } }
     };
 }
 
+// Expression types
+pub mod expressions {
+    use super::*;
+    pub const FLOAT_TO_FLOAT: ExpressionType = ExpressionType {
+        name: "FloatToFloat",
+        params: &[ExpressionParam {
+            name: "x",
+            ty: ExpressionParamType::Float,
+            description: None,
+        }],
+    };
+    pub const INT_TO_FLOAT: ExpressionType = ExpressionType {
+        name: "IntToFloat",
+        params: &[ExpressionParam {
+            name: "x",
+            ty: ExpressionParamType::Int,
+            description: None,
+        }],
+    };
+    pub const INT_TO_INT: ExpressionType = ExpressionType {
+        name: "IntToInt",
+        params: &[ExpressionParam {
+            name: "x",
+            ty: ExpressionParamType::Int,
+            description: None,
+        }],
+    };
+    pub const SIZE_CLASS_TO_INT: ExpressionType = ExpressionType {
+        name: "SizeClassToInt",
+        params: &[ExpressionParam {
+            name: "size",
+            ty: ExpressionParamType::Enum {
+                name: "SizeClass",
+                variants: &[
+                    "Undefined",
+                    "Frigate",
+                    "Destroyer",
+                    "Cruiser",
+                    "Battleship",
+                    "Titan",
+                    "Starbase",
+                ],
+            },
+            description: None,
+        }],
+    };
+    pub const ALL: &[ExpressionType] =
+        &[FLOAT_TO_FLOAT, INT_TO_FLOAT, INT_TO_INT, SIZE_CLASS_TO_INT];
+    /// Looks up the expression type by its schema name
+    pub fn get(name: &str) -> Option<&'static ExpressionType> {
+        ALL.iter().find(|ty| ty.name == name)
+    }
+}
+
 // Helper functions
 #[allow(non_snake_case)]
 pub fn default_0() -> i32 {
@@ -34457,78 +50439,84 @@ pub fn default_true() -> bool {
     true
 }
 #[allow(non_snake_case)]
-pub fn default_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ() -> String {
+pub fn default_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ() -> crate::expression::Expression {
     "0.1*level".into()
 }
 #[allow(non_snake_case)]
-pub fn default_ඞquoteඞ0ඞquoteඞ() -> String {
+pub fn default_ඞquoteඞ0ඞquoteඞ() -> crate::expression::Expression {
     "0".into()
 }
 #[allow(non_snake_case)]
-pub fn default_ඞquoteඞ12ඞquoteඞ() -> String {
+pub fn default_ඞquoteඞ12ඞquoteඞ() -> crate::expression::Expression {
     "12".into()
 }
 #[allow(non_snake_case)]
-pub fn default_ඞquoteඞ1ඞquoteඞ() -> String {
+pub fn default_ඞquoteඞ1ඞquoteඞ() -> crate::expression::Expression {
     "1".into()
 }
 #[allow(non_snake_case)]
-pub fn default_ඞquoteඞ1ඞspaceඞඞdashඞඞspaceඞ0ඞdotඞ05ඞstarඞlevelඞquoteඞ() -> String {
+pub fn default_ඞquoteඞ1ඞspaceඞඞdashඞඞspaceඞ0ඞdotඞ05ඞstarඞlevelඞquoteඞ(
+) -> crate::expression::Expression {
     "1 - 0.05*level".into()
 }
 #[allow(non_snake_case)]
-pub fn default_ඞquoteඞ1ඞspaceඞඞplusඞඞspaceඞcreditsඞslashඞ500ඞquoteඞ() -> String {
+pub fn default_ඞquoteඞ1ඞspaceඞඞplusඞඞspaceඞcreditsඞslashඞ500ඞquoteඞ(
+) -> crate::expression::Expression {
     "1 + credits/500".into()
 }
 #[allow(non_snake_case)]
-pub fn default_ඞquoteඞ5ඞstarඞlevelඞquoteඞ() -> String {
+pub fn default_ඞquoteඞ5ඞstarඞlevelඞquoteඞ() -> crate::expression::Expression {
     "5*level".into()
 }
 #[allow(non_snake_case)]
-pub fn default_ඞquoteඞBaseFlightRangeඞspaceඞඞplusඞඞspaceඞ0ඞdotඞ09ඞstarඞlevelඞquoteඞ() -> String {
+pub fn default_ඞquoteඞBaseFlightRangeඞspaceඞඞplusඞඞspaceඞ0ඞdotඞ09ඞstarඞlevelඞquoteඞ(
+) -> crate::expression::Expression {
     "BaseFlightRange + 0.09*level".into()
 }
 #[allow(non_snake_case)]
-pub fn default_ඞquoteඞBaseFlightSpeedඞspaceඞඞplusඞඞspaceඞ0ඞdotඞ4ඞstarඞlevelඞquoteඞ() -> String {
+pub fn default_ඞquoteඞBaseFlightSpeedඞspaceඞඞplusඞඞspaceඞ0ඞdotඞ4ඞstarඞlevelඞquoteඞ(
+) -> crate::expression::Expression {
     "BaseFlightSpeed + 0.4*level".into()
 }
 #[allow(non_snake_case)]
-pub fn default_ඞquoteඞBaseFuelCapacityඞspaceඞඞplusඞඞspaceඞ50ඞstarඞlevelඞquoteඞ() -> String {
+pub fn default_ඞquoteඞBaseFuelCapacityඞspaceඞඞplusඞඞspaceඞ50ඞstarඞlevelඞquoteඞ(
+) -> crate::expression::Expression {
     "BaseFuelCapacity + 50*level".into()
 }
 #[allow(non_snake_case)]
 pub fn default_ඞquoteඞIFඞlparenඞQuantityඞspaceඞඞltඞඞeqඞඞspaceඞ1ඞcommaඞඞspaceඞ0ඞcommaඞඞspaceඞRANDOMඞlparenඞ0ඞcommaඞඞspaceඞ360ඞrparenඞඞrparenඞඞquoteඞ(
-) -> String {
+) -> crate::expression::Expression {
     "IF(Quantity <= 1, 0, RANDOM(0, 360))".into()
 }
 #[allow(non_snake_case)]
 pub fn default_ඞquoteඞIFඞlparenඞQuantityඞspaceඞඞltඞඞeqඞඞspaceඞ1ඞcommaඞඞspaceඞ0ඞcommaඞඞspaceඞSizeඞspaceඞඞslashඞඞspaceඞ2ඞrparenඞඞquoteඞ(
-) -> String {
+) -> crate::expression::Expression {
     "IF(Quantity <= 1, 0, Size / 2)".into()
 }
 #[allow(non_snake_case)]
 pub fn default_ඞquoteඞIFඞlparenඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞDestroyerඞcommaඞඞspaceඞ5ඞcommaඞඞspaceඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞCruiserඞcommaඞඞspaceඞ15ඞcommaඞඞspaceඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞBattleshipඞcommaඞඞspaceඞ50ඞcommaඞඞspaceඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞTitanඞcommaඞඞspaceඞ100ඞcommaඞඞspaceඞ0ඞrparenඞඞquoteඞ(
-) -> String {
+) -> crate::expression::Expression {
     "IF(size == Destroyer, 5, size == Cruiser, 15, size == Battleship, 50, size == Titan, 100, 0)"
         .into()
 }
 #[allow(non_snake_case)]
 pub fn default_ඞquoteඞMAXඞlparenඞ40ඞcommaඞඞspaceඞ100ඞspaceඞඞdashඞඞspaceඞlevelඞrparenඞඞquoteඞ(
-) -> String {
+) -> crate::expression::Expression {
     "MAX(40, 100 - level)".into()
 }
 #[allow(non_snake_case)]
 pub fn default_ඞquoteඞMINඞlparenඞ1000ඞcommaඞඞspaceඞ300ඞspaceඞඞplusඞඞspaceඞ5ඞstarඞdistanceඞrparenඞඞquoteඞ(
-) -> String {
+) -> crate::expression::Expression {
     "MIN(1000, 300 + 5*distance)".into()
 }
 #[allow(non_snake_case)]
 pub fn default_ඞquoteඞMINඞlparenඞ3ඞstarඞdistanceඞslashඞ5ඞspaceඞඞdashඞඞspaceඞ5ඞcommaඞඞspaceඞMaxEnemyShipsLevelඞrparenඞඞquoteඞ(
-) -> String {
+) -> crate::expression::Expression {
     "MIN(3*distance/5 - 5, MaxEnemyShipsLevel)".into()
 }
 #[allow(non_snake_case)]
-pub fn default_ඞquoteඞMINඞlparenඞlevelඞstarඞ2ඞcommaඞ500ඞrparenඞඞquoteඞ() -> String {
+pub fn default_ඞquoteඞMINඞlparenඞlevelඞstarඞ2ඞcommaඞ500ඞrparenඞඞquoteඞ(
+) -> crate::expression::Expression {
     "MIN(level*2,500)".into()
 }
 #[allow(non_snake_case)]
@@ -34748,95 +50736,97 @@ pub fn skip_if_true(x: &bool) -> bool {
     *x
 }
 #[allow(non_snake_case)]
-pub fn skip_if_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ(x: &String) -> bool {
+pub fn skip_if_ඞquoteඞ0ඞdotඞ1ඞstarඞlevelඞquoteඞ(
+    x: &crate::expression::Expression,
+) -> bool {
     x == "0.1*level"
 }
 #[allow(non_snake_case)]
-pub fn skip_if_ඞquoteඞ0ඞquoteඞ(x: &String) -> bool {
+pub fn skip_if_ඞquoteඞ0ඞquoteඞ(x: &crate::expression::Expression) -> bool {
     x == "0"
 }
 #[allow(non_snake_case)]
-pub fn skip_if_ඞquoteඞ12ඞquoteඞ(x: &String) -> bool {
+pub fn skip_if_ඞquoteඞ12ඞquoteඞ(x: &crate::expression::Expression) -> bool {
     x == "12"
 }
 #[allow(non_snake_case)]
-pub fn skip_if_ඞquoteඞ1ඞquoteඞ(x: &String) -> bool {
+pub fn skip_if_ඞquoteඞ1ඞquoteඞ(x: &crate::expression::Expression) -> bool {
     x == "1"
 }
 #[allow(non_snake_case)]
 pub fn skip_if_ඞquoteඞ1ඞspaceඞඞdashඞඞspaceඞ0ඞdotඞ05ඞstarඞlevelඞquoteඞ(
-    x: &String,
+    x: &crate::expression::Expression,
 ) -> bool {
     x == "1 - 0.05*level"
 }
 #[allow(non_snake_case)]
 pub fn skip_if_ඞquoteඞ1ඞspaceඞඞplusඞඞspaceඞcreditsඞslashඞ500ඞquoteඞ(
-    x: &String,
+    x: &crate::expression::Expression,
 ) -> bool {
     x == "1 + credits/500"
 }
 #[allow(non_snake_case)]
-pub fn skip_if_ඞquoteඞ5ඞstarඞlevelඞquoteඞ(x: &String) -> bool {
+pub fn skip_if_ඞquoteඞ5ඞstarඞlevelඞquoteඞ(x: &crate::expression::Expression) -> bool {
     x == "5*level"
 }
 #[allow(non_snake_case)]
 pub fn skip_if_ඞquoteඞBaseFlightRangeඞspaceඞඞplusඞඞspaceඞ0ඞdotඞ09ඞstarඞlevelඞquoteඞ(
-    x: &String,
+    x: &crate::expression::Expression,
 ) -> bool {
     x == "BaseFlightRange + 0.09*level"
 }
 #[allow(non_snake_case)]
 pub fn skip_if_ඞquoteඞBaseFlightSpeedඞspaceඞඞplusඞඞspaceඞ0ඞdotඞ4ඞstarඞlevelඞquoteඞ(
-    x: &String,
+    x: &crate::expression::Expression,
 ) -> bool {
     x == "BaseFlightSpeed + 0.4*level"
 }
 #[allow(non_snake_case)]
 pub fn skip_if_ඞquoteඞBaseFuelCapacityඞspaceඞඞplusඞඞspaceඞ50ඞstarඞlevelඞquoteඞ(
-    x: &String,
+    x: &crate::expression::Expression,
 ) -> bool {
     x == "BaseFuelCapacity + 50*level"
 }
 #[allow(non_snake_case)]
 pub fn skip_if_ඞquoteඞIFඞlparenඞQuantityඞspaceඞඞltඞඞeqඞඞspaceඞ1ඞcommaඞඞspaceඞ0ඞcommaඞඞspaceඞRANDOMඞlparenඞ0ඞcommaඞඞspaceඞ360ඞrparenඞඞrparenඞඞquoteඞ(
-    x: &String,
+    x: &crate::expression::Expression,
 ) -> bool {
     x == "IF(Quantity <= 1, 0, RANDOM(0, 360))"
 }
 #[allow(non_snake_case)]
 pub fn skip_if_ඞquoteඞIFඞlparenඞQuantityඞspaceඞඞltඞඞeqඞඞspaceඞ1ඞcommaඞඞspaceඞ0ඞcommaඞඞspaceඞSizeඞspaceඞඞslashඞඞspaceඞ2ඞrparenඞඞquoteඞ(
-    x: &String,
+    x: &crate::expression::Expression,
 ) -> bool {
     x == "IF(Quantity <= 1, 0, Size / 2)"
 }
 #[allow(non_snake_case)]
 pub fn skip_if_ඞquoteඞIFඞlparenඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞDestroyerඞcommaඞඞspaceඞ5ඞcommaඞඞspaceඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞCruiserඞcommaඞඞspaceඞ15ඞcommaඞඞspaceඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞBattleshipඞcommaඞඞspaceඞ50ඞcommaඞඞspaceඞsizeඞspaceඞඞeqඞඞeqඞඞspaceඞTitanඞcommaඞඞspaceඞ100ඞcommaඞඞspaceඞ0ඞrparenඞඞquoteඞ(
-    x: &String,
+    x: &crate::expression::Expression,
 ) -> bool {
     x
         == "IF(size == Destroyer, 5, size == Cruiser, 15, size == Battleship, 50, size == Titan, 100, 0)"
 }
 #[allow(non_snake_case)]
 pub fn skip_if_ඞquoteඞMAXඞlparenඞ40ඞcommaඞඞspaceඞ100ඞspaceඞඞdashඞඞspaceඞlevelඞrparenඞඞquoteඞ(
-    x: &String,
+    x: &crate::expression::Expression,
 ) -> bool {
     x == "MAX(40, 100 - level)"
 }
 #[allow(non_snake_case)]
 pub fn skip_if_ඞquoteඞMINඞlparenඞ1000ඞcommaඞඞspaceඞ300ඞspaceඞඞplusඞඞspaceඞ5ඞstarඞdistanceඞrparenඞඞquoteඞ(
-    x: &String,
+    x: &crate::expression::Expression,
 ) -> bool {
     x == "MIN(1000, 300 + 5*distance)"
 }
 #[allow(non_snake_case)]
 pub fn skip_if_ඞquoteඞMINඞlparenඞ3ඞstarඞdistanceඞslashඞ5ඞspaceඞඞdashඞඞspaceඞ5ඞcommaඞඞspaceඞMaxEnemyShipsLevelඞrparenඞඞquoteඞ(
-    x: &String,
+    x: &crate::expression::Expression,
 ) -> bool {
     x == "MIN(3*distance/5 - 5, MaxEnemyShipsLevel)"
 }
 #[allow(non_snake_case)]
 pub fn skip_if_ඞquoteඞMINඞlparenඞlevelඞstarඞ2ඞcommaඞ500ඞrparenඞඞquoteඞ(
-    x: &String,
+    x: &crate::expression::Expression,
 ) -> bool {
     x == "MIN(level*2,500)"
 }
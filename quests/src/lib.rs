@@ -3,6 +3,8 @@ use eh_mod_dev::database::Database;
 use crate::quests::QuestContext;
 
 pub mod quests;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 pub fn xquest(
     db: &Database,
@@ -1,10 +1,13 @@
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 
 use parking_lot::RwLock;
+use thiserror::Error;
 
 use eh_mod_dev::database::Database;
+use eh_mod_dev::diagnostic::diagnostic::DiagnosticKind;
 use eh_mod_dev::mapping::{IdMapping, KindProvider};
 use eh_mod_dev::schema::schema::{
     Node, NodeCancelQuest, NodeCompleteQuest, NodeFailQuest, Quest, QuestId,
@@ -27,33 +30,102 @@ pub struct QuestContext {
     data: QuestContextData,
 }
 
+#[derive(Debug, Clone, Error)]
+pub enum QuestContextError {
+    #[error("Quest `{}` is already being edited by another QuestContext", .0)]
+    AlreadyOpen(String),
+    #[error("Quest `{}` was already built, use `QuestContext::resume` to continue editing it", .0)]
+    AlreadyBuilt(String),
+}
+
+/// Quests that have a context opened for them, or were already built
+#[derive(Debug, Default)]
+struct QuestOwnership {
+    open: BTreeSet<String>,
+    built: BTreeSet<String>,
+}
+
 impl QuestContext {
+    /// Opens a context for building a new quest
+    ///
+    /// # Panics
+    /// Will panic if the quest is already being edited by another context,
+    /// or was already built, see [QuestContext::try_new]
     pub fn new(
         db: &Database,
         id: impl Into<String>,
         starting_node_id: impl Into<String>,
     ) -> QuestContext {
-        let mappings = db.get_mappings::<NodeId>();
+        Self::try_new(db, id, starting_node_id).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Opens a context for building a new quest
+    ///
+    /// Only one context can be opened for each quest at a time, and a quest
+    /// can only be built once, since contexts share the node ID mappings
+    pub fn try_new(
+        db: &Database,
+        id: impl Into<String>,
+        starting_node_id: impl Into<String>,
+    ) -> Result<QuestContext, QuestContextError> {
         let string_id = id.into();
+        acquire(db, &string_id, false)?;
         let id = db.new_id(string_id.as_str());
-        let mut data = QuestContextData {
+        let mut ctx = Self::open(db, id, string_id, vec![]);
+        ctx.data.init_defaults();
+        ctx.data.set_start_id(starting_node_id);
+        Ok(ctx)
+    }
+
+    /// Opens a context for editing the quest that was already built, keeping
+    /// its node ID mappings
+    ///
+    /// Nodes of the quest are taken from the database item if it's present,
+    /// so the returned context continues where the previous one ended
+    pub fn resume(db: &Database, id: impl Into<String>) -> Result<QuestContext, QuestContextError> {
+        let string_id = id.into();
+        let was_built = acquire(db, &string_id, true)?;
+        let id = db.id::<Quest>(string_id.as_str());
+        let nodes = db
+            .get_item::<Quest>(id)
+            .map(|quest| quest.read().nodes.clone())
+            .unwrap_or_default();
+        let mut ctx = Self::open(db, id, string_id, nodes);
+        // Ranges only persist within a single run, quests built by previous
+        // runs only have their mappings
+        if !was_built {
+            ctx.data.init_defaults();
+        }
+        for node in &ctx.data.nodes {
+            match NodeId(*node.id()) {
+                START_ID => ctx.data.has_start = true,
+                COMPLETE_ID => ctx.data.has_complete = true,
+                FAIL_ID => ctx.data.has_fail = true,
+                CANCEL_ID => ctx.data.has_cancel = true,
+                _ => {}
+            }
+        }
+        Ok(ctx)
+    }
+
+    fn open(db: &Database, id: QuestId, string_id: String, nodes: Vec<Node>) -> QuestContext {
+        let mappings = db.get_mappings::<NodeId>();
+        let data = QuestContextData {
             id,
             db: db.clone(),
             string_id,
             mappings,
-            nodes: vec![],
+            nodes,
             has_cancel: false,
             has_complete: false,
             has_fail: false,
             has_start: false,
         };
-        data.init_defaults();
-        data.set_start_id(starting_node_id);
         Self { data }
     }
 
-    pub fn into_quest(self) -> Quest {
-        if !self.nodes.first().is_some_and(|n| *n.id() == 1) {
+    pub fn into_quest(mut self) -> Quest {
+        if self.nodes.first().is_none_or(|n| *n.id() != 1) {
             panic!("Quest {} is missing the starting node", self.string_id)
         }
         Quest {
@@ -66,11 +138,35 @@ impl QuestContext {
             requirement: Default::default(),
             level: 0,
             use_random_seed: false,
-            nodes: self.data.nodes,
+            nodes: std::mem::take(&mut self.data.nodes),
         }
     }
 }
 
+/// Marks the quest as open, returning whether it was built before
+fn acquire(db: &Database, string_id: &str, resume: bool) -> Result<bool, QuestContextError> {
+    let ownership = db.extra_or_init::<QuestOwnership>();
+    let mut ownership = ownership.write();
+    if ownership.open.contains(string_id) {
+        return Err(QuestContextError::AlreadyOpen(string_id.to_string()));
+    }
+    let built = ownership.built.contains(string_id);
+    if built && !resume {
+        return Err(QuestContextError::AlreadyBuilt(string_id.to_string()));
+    }
+    ownership.open.insert(string_id.to_string());
+    Ok(built)
+}
+
+impl Drop for QuestContext {
+    fn drop(&mut self) {
+        let ownership = self.data.db.extra_or_init::<QuestOwnership>();
+        let mut ownership = ownership.write();
+        ownership.open.remove(&self.data.string_id);
+        ownership.built.insert(self.data.string_id.clone());
+    }
+}
+
 impl Deref for QuestContext {
     type Target = QuestContextData;
 
@@ -161,21 +257,51 @@ impl QuestContextData {
         NodeId(id.into_id(&self.string_id, &m))
     }
     pub fn new_id(&mut self, id: impl IntoNodeId) -> NodeId {
-        let mut m = self.mappings.write();
-        NodeId(id.into_new_id(self.string_id.clone(), &mut m))
+        let m = self.mappings.read();
+        NodeId(id.into_new_id(self.string_id.clone(), &m))
     }
     pub fn raw_id(&mut self, id: impl Into<String>) -> NodeId {
-        let mut m = self.mappings.write();
+        let m = self.mappings.read();
         NodeId(m.get_id_raw(self.string_id.clone(), id))
     }
 
+    /// Resolves the ID of another quest, which doesn't have to be built yet
+    ///
+    /// Saving the database reports the reference if the quest was never
+    /// built, or if it doesn't start with the given node. Game always starts
+    /// quests from their starting node, so the node is only checked to
+    /// catch references to the wrong quest
+    pub fn quest_ref(&self, quest: impl Into<String>, node: impl Into<String>) -> QuestId {
+        let (quest, node) = (quest.into(), node.into());
+        let target = self.db.get_id_raw::<Quest>(quest.as_str());
+        let source = self.id;
+        self.db.add_save_check(move |db, ctx| {
+            let mut ctx = ctx.enter(db.item_file_name(source));
+            if db.get_item::<Quest>(target).is_none() {
+                ctx.emit(DiagnosticKind::unknown_quest(quest.as_str()));
+                return;
+            }
+            let node_id = db
+                .get_mappings::<NodeId>()
+                .read()
+                .assigned_id(quest.as_str(), &node);
+            if node_id != Some(START_ID.0) {
+                ctx.emit(DiagnosticKind::unknown_quest_node(
+                    quest.as_str(),
+                    node.as_str(),
+                ));
+            }
+        });
+        target
+    }
+
     pub fn set_id(&mut self, string_id: impl Into<String>, numeric_id: i32) {
         self.mappings
             .write()
             .set_id(self.string_id.clone(), string_id, numeric_id);
     }
 
-    pub fn branch(&mut self) -> BranchBuilder {
+    pub fn branch(&mut self) -> BranchBuilder<'_> {
         Contextual::new(self, BranchBuilderData::default())
     }
 
@@ -270,14 +396,14 @@ impl KindProvider for NodeId {
 
 pub trait IntoNodeId {
     fn into_id<'a>(self, quest_id: impl Into<Cow<'a, str>>, ids: &'a IdMapping) -> i32;
-    fn into_new_id(self, quest_id: impl Into<Cow<'static, str>>, ids: &mut IdMapping) -> i32;
+    fn into_new_id(self, quest_id: impl Into<Cow<'static, str>>, ids: &IdMapping) -> i32;
 }
 
 impl IntoNodeId for NodeId {
     fn into_id<'a>(self, _: impl Into<Cow<'a, str>>, _: &'a IdMapping) -> i32 {
         self.0
     }
-    fn into_new_id(self, _: impl Into<Cow<'static, str>>, _: &mut IdMapping) -> i32 {
+    fn into_new_id(self, _: impl Into<Cow<'static, str>>, _: &IdMapping) -> i32 {
         self.0
     }
 }
@@ -286,7 +412,7 @@ impl IntoNodeId for &str {
     fn into_id<'a>(self, quest_id: impl Into<Cow<'a, str>>, ids: &'a IdMapping) -> i32 {
         ids.existing_id(quest_id, self)
     }
-    fn into_new_id(self, quest_id: impl Into<Cow<'static, str>>, ids: &mut IdMapping) -> i32 {
+    fn into_new_id(self, quest_id: impl Into<Cow<'static, str>>, ids: &IdMapping) -> i32 {
         ids.new_id(quest_id, self)
     }
 }
@@ -295,7 +421,7 @@ impl IntoNodeId for String {
     fn into_id<'a>(self, quest_id: impl Into<Cow<'a, str>>, ids: &'a IdMapping) -> i32 {
         ids.existing_id(quest_id, &self)
     }
-    fn into_new_id(self, quest_id: impl Into<Cow<'static, str>>, ids: &mut IdMapping) -> i32 {
+    fn into_new_id(self, quest_id: impl Into<Cow<'static, str>>, ids: &IdMapping) -> i32 {
         ids.new_id(quest_id, self)
     }
 }
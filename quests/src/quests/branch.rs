@@ -6,6 +6,8 @@ use crate::quests::{Contextual, IntoNodeId, NodeId, QuestContextData};
 
 pub mod combat;
 pub mod dialog;
+pub mod market;
+pub mod relations;
 pub mod switch;
 
 pub mod nodes;
@@ -178,6 +180,23 @@ impl<'a> BranchBuilder<'a> {
         self.set_next(next);
         self.done()
     }
+
+    /// Closes the branch by starting another quest and cancelling this one,
+    /// see [BranchBuilder::start_quest_node]
+    ///
+    /// Branches going to the same quest share the transition nodes
+    pub fn goto_quest_node(self, quest: impl Into<String>, node: impl Into<String>) -> BranchDone {
+        let (quest, node) = (quest.into(), node.into());
+        self.goto(|ctx| {
+            let id = format!("goto_quest:{quest}:{node}");
+            ctx.cached(id.clone(), |ctx| {
+                ctx.branch()
+                    .start_quest_node(id, quest, node)
+                    .cancel_quest()
+                    .entrypoint()
+            })
+        })
+    }
 }
 
 impl TransitionalNode for BranchBuilderData {
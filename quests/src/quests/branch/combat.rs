@@ -26,7 +26,7 @@ impl<'a> BranchBuilder<'a> {
         let (win, fail, branch) = branch_out.win_lose_branch(self.ctx());
         let id = self.ctx().new_id(id).0;
         let enemy = self.ctx().db.id(enemy);
-        let loot = loot.into_opt().map(|id| self.ctx().db.id(id));
+        let loot = self.ctx().db.opt_id(loot);
         self.node(BakedCombat {
             node: NodeAttackFleet {
                 id,
@@ -52,7 +52,7 @@ impl<'a> BranchBuilder<'a> {
             default_transition: win(self.ctx()).0,
             failure_transition: fail(self.ctx()).0,
             enemy: Some(self.ctx().db.id(enemy)),
-            loot: loot.into_opt().map(|id| self.ctx().db.id(id)),
+            loot: self.ctx().db.opt_id(loot),
         };
         self.push_final(node)
     }
@@ -9,6 +9,13 @@ pub struct SmartDialog<'a, const HAS_NEXT: bool> {
     ctx: &'a mut QuestContextData,
     node: NodeShowDialog,
     next_transition: Option<usize>,
+    fallback: Option<Fallback>,
+}
+
+/// Action that is only shown when none of the gated actions are available
+struct Fallback {
+    action: NodeAction,
+    is_next: bool,
 }
 
 impl<const HAS_NEXT: bool> SmartDialog<'_, HAS_NEXT> {
@@ -48,9 +55,61 @@ impl<const HAS_NEXT: bool> SmartDialog<'_, HAS_NEXT> {
         self
     }
 
+    /// Adds a fallback action that branches out, shown only when none of
+    /// the other actions are available
+    ///
+    /// Use this for dialogs where all actions are gated behind requirements,
+    /// to avoid soft-locking the player. Replaces the previously set fallback
+    pub fn default_action(
+        mut self,
+        action: impl IntoDialogAction,
+        branch: impl FnOnce(&mut QuestContextData) -> NodeId,
+    ) -> Self {
+        let mut action = action.into_action();
+        action.target_node = branch(self.ctx).0;
+        self.set_fallback(action, false);
+        self
+    }
+
     pub fn ctx(&mut self) -> &mut QuestContextData {
         self.ctx
     }
+
+    fn set_fallback(&mut self, action: NodeAction, is_next: bool) {
+        if self.fallback.as_ref().is_some_and(|f| f.is_next) {
+            panic!("Dialog fallback can't replace the transition to the next node")
+        }
+        self.fallback = Some(Fallback { action, is_next });
+    }
+
+    /// Appends the fallback action, gating it behind the inverse of all the
+    /// other action requirements
+    fn finish(mut self) -> (NodeShowDialog, Option<usize>) {
+        let mut next_transition = self.next_transition;
+        if let Some(Fallback {
+            mut action,
+            is_next,
+        }) = self.fallback
+        {
+            let others = self
+                .node
+                .actions
+                .iter()
+                .map(|a| a.requirement.clone())
+                .reduce(|a, b| a | b);
+            if let Some(others) = others {
+                action.requirement = match action.requirement {
+                    Requirement::Empty(_) => !others,
+                    requirement => requirement & !others,
+                };
+            }
+            if is_next {
+                next_transition = Some(self.node.actions.len());
+            }
+            self.node.actions.push(action);
+        }
+        (self.node, next_transition)
+    }
 }
 
 impl<'a> SmartDialog<'a, false> {
@@ -62,12 +121,28 @@ impl<'a> SmartDialog<'a, false> {
             ctx: self.ctx,
             node: self.node,
             next_transition: self.next_transition,
+            fallback: self.fallback,
+        }
+    }
+
+    /// Adds a fallback action that continues onwards, shown only when none
+    /// of the other actions are available
+    ///
+    /// Dialog nodes have no timers, so the player still has to press the
+    /// button, but the branch is guaranteed to be able to continue
+    pub fn auto_continue(mut self, action: impl IntoDialogAction) -> SmartDialog<'a, true> {
+        self.set_fallback(action.into_action(), true);
+        SmartDialog {
+            ctx: self.ctx,
+            node: self.node,
+            next_transition: None,
+            fallback: self.fallback,
         }
     }
 
     /// Converts the dialog into a node. This is only possible before the next transition is set
     pub fn into_node(self) -> NodeShowDialog {
-        self.node
+        self.finish().0
     }
 
     pub fn new(
@@ -89,16 +164,17 @@ impl<'a> SmartDialog<'a, false> {
             ctx,
             node,
             next_transition: None,
+            fallback: None,
         }
     }
 }
 
 impl<'a> SmartDialog<'a, true> {
     pub fn bake(self) -> BakedDialog {
+        let (node, next_transition) = self.finish();
         BakedDialog {
-            node: self.node,
-            next_transition: self
-                .next_transition
+            node,
+            next_transition: next_transition
                 .expect("Next transition should always be present in a bake-able dialog"),
         }
     }
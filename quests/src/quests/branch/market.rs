@@ -0,0 +1,115 @@
+use eh_mod_dev::database::DatabaseIdLike;
+use eh_mod_dev::mapping::OptionalDatabaseIdLike;
+use eh_mod_dev::schema::schema::{
+    Faction, LootContent, LootId, NodeOpenShipyard, NodeOpenWorkshop, NodeTrade,
+};
+
+use crate::quests::branch::BranchBuilder;
+use crate::quests::{IntoNodeId, QuestContextData};
+
+/// Inventory of the market opened by the [BranchBuilder::open_market]
+#[derive(Debug, Clone)]
+pub struct Market {
+    loot: String,
+    content: Option<LootContent>,
+}
+
+impl Market {
+    /// Market selling the content of the existing loot item
+    pub fn existing(loot: impl Into<String>) -> Self {
+        Self {
+            loot: loot.into(),
+            content: None,
+        }
+    }
+
+    /// Market selling the given content, stored as a new loot item with the
+    /// given ID
+    pub fn new(loot: impl Into<String>, content: impl Into<LootContent>) -> Self {
+        Self {
+            loot: loot.into(),
+            content: Some(content.into()),
+        }
+    }
+
+    fn into_loot(self, ctx: &QuestContextData) -> LootId {
+        match self.content {
+            None => ctx.db.id(self.loot),
+            Some(content) => {
+                let mut loot = ctx.db.new_loot(self.loot);
+                loot.set_loot(content);
+                loot.id
+            }
+        }
+    }
+}
+
+impl From<&str> for Market {
+    fn from(value: &str) -> Self {
+        Self::existing(value)
+    }
+}
+
+impl From<String> for Market {
+    fn from(value: String) -> Self {
+        Self::existing(value)
+    }
+}
+
+impl<S: Into<String>, C: Into<LootContent>> From<(S, C)> for Market {
+    fn from((loot, content): (S, C)) -> Self {
+        Self::new(loot, content)
+    }
+}
+
+impl<'a> BranchBuilder<'a> {
+    /// Opens the trade screen with the given inventory
+    ///
+    /// Existing inventories must already be present in the database, new
+    /// ones are created alongside the node
+    pub fn open_market(mut self, id: impl IntoNodeId, market: impl Into<Market>) -> Self {
+        let loot = market.into().into_loot(self.ctx());
+        let id = self.ctx().new_id(id);
+        self.node(NodeTrade::new().with_id(id.0).with_loot(loot))
+    }
+
+    /// Opens the shipyard of the given faction, or of the current star
+    /// faction if none is given
+    ///
+    /// `level` determines the ships available for purchase
+    pub fn open_shipyard<ID: DatabaseIdLike<Faction>>(
+        mut self,
+        id: impl IntoNodeId,
+        faction: impl OptionalDatabaseIdLike<Faction, ID>,
+        level: i32,
+    ) -> Self {
+        let faction = self.ctx().db.opt_id(faction);
+        let id = self.ctx().new_id(id);
+        self.node(
+            NodeOpenShipyard::new()
+                .with_id(id.0)
+                .with_faction(faction)
+                .with_value(level),
+        )
+    }
+
+    /// Opens the workshop of the given faction, or of the current star
+    /// faction if none is given
+    ///
+    /// `level` determines the components available for purchase
+    pub fn open_workshop<ID: DatabaseIdLike<Faction>>(
+        mut self,
+        id: impl IntoNodeId,
+        faction: impl OptionalDatabaseIdLike<Faction, ID>,
+        level: i32,
+    ) -> Self {
+        let faction = self.ctx().db.opt_id(faction);
+        let id = self.ctx().new_id(id);
+        self.node(
+            NodeOpenWorkshop::new()
+                .with_id(id.0)
+                .with_faction(faction)
+                .with_value(level),
+        )
+    }
+}
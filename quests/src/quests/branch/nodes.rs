@@ -35,6 +35,22 @@ impl<'a> BranchBuilder<'a> {
         self.node(Node::start_quest().with_id(id.0).with_quest(quest_id))
     }
 
+    /// Same as [BranchBuilder::start_quest], but refers to the quest by its
+    /// string ID and starting node, so it can be built later, see
+    /// [QuestContextData::quest_ref]
+    ///
+    /// [QuestContextData::quest_ref]: crate::quests::QuestContextData::quest_ref
+    pub fn start_quest_node(
+        mut self,
+        id: impl IntoNodeId,
+        quest: impl Into<String>,
+        node: impl Into<String>,
+    ) -> BranchBuilder<'a> {
+        let quest_id = self.ctx().quest_ref(quest, node);
+        let id = self.ctx().new_id(id);
+        self.node(Node::start_quest().with_id(id.0).with_quest(quest_id))
+    }
+
     pub fn retreat(mut self, id: impl IntoNodeId) -> BranchBuilder<'a> {
         let id = self.ctx().new_id(id);
         self.node(Node::retreat().with_id(id.0))
@@ -0,0 +1,67 @@
+use eh_mod_dev::database::DatabaseIdLike;
+use eh_mod_dev::schema::schema::{
+    Character, NodeChangeCharacterRelations, NodeChangeFactionRelations, NodeSetCharacterRelations,
+    NodeSetFactionRelations,
+};
+
+use crate::quests::branch::BranchBuilder;
+use crate::quests::IntoNodeId;
+
+impl<'a> BranchBuilder<'a> {
+    /// Changes the player reputation by the given amount
+    ///
+    /// Faction relation nodes carry no faction, the game always applies
+    /// them to the faction owning the current star
+    pub fn reputation(mut self, id: impl IntoNodeId, delta: i32) -> Self {
+        let id = self.ctx().new_id(id);
+        self.node(
+            NodeChangeFactionRelations::new()
+                .with_id(id.0)
+                .with_value(delta),
+        )
+    }
+
+    /// Sets the player reputation with the faction owning the current star
+    pub fn set_reputation(mut self, id: impl IntoNodeId, value: i32) -> Self {
+        let id = self.ctx().new_id(id);
+        self.node(
+            NodeSetFactionRelations::new()
+                .with_id(id.0)
+                .with_value(value),
+        )
+    }
+
+    /// Changes the character attitude towards the player by the given amount
+    pub fn character_reputation(
+        mut self,
+        id: impl IntoNodeId,
+        character: impl DatabaseIdLike<Character>,
+        delta: i32,
+    ) -> Self {
+        let character = self.ctx().db.id(character);
+        let id = self.ctx().new_id(id);
+        self.node(
+            NodeChangeCharacterRelations::new()
+                .with_id(id.0)
+                .with_character(character)
+                .with_value(delta),
+        )
+    }
+
+    /// Sets the character attitude towards the player
+    pub fn set_character_reputation(
+        mut self,
+        id: impl IntoNodeId,
+        character: impl DatabaseIdLike<Character>,
+        value: i32,
+    ) -> Self {
+        let character = self.ctx().db.id(character);
+        let id = self.ctx().new_id(id);
+        self.node(
+            NodeSetCharacterRelations::new()
+                .with_id(id.0)
+                .with_character(character)
+                .with_value(value),
+        )
+    }
+}
@@ -26,7 +26,7 @@ enum NextTransition {
 pub fn new_smart_switch(
     ctx: &mut QuestContextData,
     id: impl IntoNodeId,
-) -> SmartSwitch<false, false> {
+) -> SmartSwitch<'_, false, false> {
     let id = ctx.new_id(id);
     Contextual::new(
         ctx,
@@ -0,0 +1,340 @@
+use eh_mod_dev::database::{Database, DatabaseHolder};
+use eh_mod_dev::schema::schema::{Node, NodeTransition, Quest};
+
+use crate::quests::QuestContext;
+use crate::xquest;
+
+/// String ID of the quest built by [build_quest]
+pub const TEST_QUEST_ID: &str = "test:quest";
+/// Node ID of the starting node of the quest built by [build_quest]
+pub const TEST_START_NODE: &str = "start";
+
+/// Creates a database with the minimal fixture loaded
+///
+/// Database is only meant to be used in memory, saving it will fail
+pub fn test_database() -> Database {
+    let db = DatabaseHolder::ephemeral();
+    db_minimal::load_minimal(&db);
+    db
+}
+
+/// Builds a quest in a fresh [test_database]
+///
+/// Starting node of the quest should use the [TEST_START_NODE] ID
+pub fn build_quest(build: impl FnOnce(&mut QuestContext)) -> Quest {
+    build_quest_in(&test_database(), build)
+}
+
+/// Same as [build_quest], but uses the given database
+pub fn build_quest_in(db: &Database, build: impl FnOnce(&mut QuestContext)) -> Quest {
+    let mut ctx = xquest(db, TEST_QUEST_ID, TEST_START_NODE);
+    build(&mut ctx);
+    ctx.into_quest()
+}
+
+/// Returns the kind of the node, like `ShowDialog` or `CompleteQuest`
+pub fn node_kind(node: &Node) -> &'static str {
+    let name = node.inner_type_name();
+    name.strip_prefix("Node").unwrap_or(name)
+}
+
+/// Returns IDs of all the nodes this node can transition to
+pub fn transitions(node: &Node) -> Vec<i32> {
+    match node {
+        Node::Undefined(_)
+        | Node::ComingSoon(_)
+        | Node::CompleteQuest(_)
+        | Node::FailQuest(_)
+        | Node::CancelQuest(_) => vec![],
+        Node::ShowDialog(n) => n.actions.iter().map(|a| a.target_node).collect(),
+        Node::Switch(n) => switch_transitions(n.default_transition, &n.transitions),
+        Node::Random(n) => switch_transitions(n.default_transition, &n.transitions),
+        Node::Condition(n) => n.transitions.iter().map(|t| t.target_node).collect(),
+        Node::AttackFleet(n) => vec![n.default_transition, n.failure_transition],
+        Node::AttackOccupants(n) => vec![n.default_transition, n.failure_transition],
+        Node::AttackStarbase(n) => vec![n.default_transition, n.failure_transition],
+        Node::OpenShipyard(n) => vec![n.default_transition],
+        Node::OpenWorkshop(n) => vec![n.default_transition],
+        Node::DestroyOccupants(n) => vec![n.default_transition],
+        Node::SuppressOccupants(n) => vec![n.default_transition],
+        Node::Retreat(n) => vec![n.default_transition],
+        Node::ReceiveItem(n) => vec![n.default_transition],
+        Node::RemoveItem(n) => vec![n.default_transition],
+        Node::Trade(n) => vec![n.default_transition],
+        Node::StartQuest(n) => vec![n.default_transition],
+        Node::SetCharacterRelations(n) => vec![n.default_transition],
+        Node::SetFactionRelations(n) => vec![n.default_transition],
+        Node::SetFactionStarbasePower(n) => vec![n.default_transition],
+        Node::ChangeCharacterRelations(n) => vec![n.default_transition],
+        Node::ChangeFactionRelations(n) => vec![n.default_transition],
+        Node::ChangeFactionStarbasePower(n) => vec![n.default_transition],
+        Node::CaptureStarBase(n) => vec![n.default_transition],
+        Node::LiberateStarBase(n) => vec![n.default_transition],
+        Node::ChangeFaction(n) => vec![n.default_transition],
+    }
+}
+
+fn switch_transitions(default: i32, transitions: &[NodeTransition]) -> Vec<i32> {
+    let mut out: Vec<_> = transitions.iter().map(|t| t.target_node).collect();
+    if default != 0 {
+        out.push(default);
+    }
+    out
+}
+
+/// Finds the node with the given numeric ID
+pub fn find_node(quest: &Quest, id: i32) -> Option<&Node> {
+    quest.nodes.iter().find(|n| *n.id() == id)
+}
+
+/// Asserts that the quest has a path starting at the starting node that
+/// passes through the nodes of the given kinds, in order
+///
+/// ```ignore
+/// assert_path(&quest, &["ShowDialog", "ReceiveItem", "CompleteQuest"]);
+/// ```
+#[track_caller]
+pub fn assert_path(quest: &Quest, path: &[&str]) {
+    fn matches(quest: &Quest, id: i32, path: &[&str]) -> bool {
+        let Some((kind, rest)) = path.split_first() else {
+            return true;
+        };
+        let Some(node) = find_node(quest, id) else {
+            return false;
+        };
+        node_kind(node) == *kind
+            && (rest.is_empty()
+                || transitions(node)
+                    .into_iter()
+                    .any(|next| matches(quest, next, rest)))
+    }
+
+    if !matches(quest, 1, path) {
+        panic!(
+            "Quest has no path `{}`, nodes are:\n{}",
+            path.join(" -> "),
+            describe_nodes(quest)
+        )
+    }
+}
+
+/// Asserts that all transitions of the quest nodes lead to existing nodes
+#[track_caller]
+pub fn assert_no_dangling_transitions(quest: &Quest) {
+    let dangling: Vec<_> = quest
+        .nodes
+        .iter()
+        .flat_map(|node| {
+            transitions(node)
+                .into_iter()
+                .filter(|next| find_node(quest, *next).is_none())
+                .map(move |next| format!("{} -> {next}", *node.id()))
+        })
+        .collect();
+    if !dangling.is_empty() {
+        panic!(
+            "Quest has transitions to missing nodes: {}, nodes are:\n{}",
+            dangling.join(", "),
+            describe_nodes(quest)
+        )
+    }
+}
+
+fn describe_nodes(quest: &Quest) -> String {
+    quest
+        .nodes
+        .iter()
+        .map(|node| {
+            format!(
+                "  {} {} -> {:?}",
+                *node.id(),
+                node_kind(node),
+                transitions(node)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use eh_mod_dev::database::Remember;
+    use eh_mod_dev::diagnostic::context::DiagnosticContext;
+    use eh_mod_dev::diagnostic::diagnostic::DiagnosticKind;
+    use eh_mod_dev::schema::schema::QuestItem;
+    use eh_mod_dev::validators::validate_save_checks;
+
+    use crate::quests::{QuestContextError, START_ID};
+    use crate::MSG_CONTINUE;
+
+    use super::*;
+
+    #[test]
+    fn dialog_chain() {
+        let quest = build_quest(|ctx| {
+            ctx.branch()
+                .dialog(TEST_START_NODE, "Hello", |d| d.next(MSG_CONTINUE))
+                .dialog("second", "Bye", |d| d.next(MSG_CONTINUE))
+                .complete_quest();
+        });
+
+        assert_path(&quest, &["ShowDialog", "ShowDialog", "CompleteQuest"]);
+        assert_no_dangling_transitions(&quest);
+    }
+
+    #[test]
+    fn dialog_branches() {
+        let quest = build_quest(|ctx| {
+            ctx.branch().dialog_end(TEST_START_NODE, "Choose", |d| {
+                d.action("Fight", |ctx| ctx.branch().fail_quest().entrypoint())
+                    .action("Leave", |ctx| ctx.branch().cancel_quest().entrypoint())
+            });
+        });
+
+        assert_path(&quest, &["ShowDialog", "FailQuest"]);
+        assert_path(&quest, &["ShowDialog", "CancelQuest"]);
+        assert_no_dangling_transitions(&quest);
+    }
+
+    #[test]
+    #[should_panic(expected = "Quest has no path")]
+    fn missing_path() {
+        let quest = build_quest(|ctx| {
+            ctx.branch()
+                .dialog(TEST_START_NODE, "Hello", |d| d.next(MSG_CONTINUE))
+                .complete_quest();
+        });
+
+        assert_path(&quest, &["ShowDialog", "FailQuest"]);
+    }
+
+    #[test]
+    fn dialog_fallback() {
+        let db = test_database();
+        let item = db.new_quest_item("test:item").id;
+        let quest = build_quest_in(&db, |ctx| {
+            ctx.branch()
+                .dialog(TEST_START_NODE, "Hello", |d| {
+                    d.action(("Use item", item.req_at_least(1)), |ctx| {
+                        ctx.branch().fail_quest().entrypoint()
+                    })
+                    .auto_continue(MSG_CONTINUE)
+                })
+                .complete_quest();
+        });
+
+        assert_path(&quest, &["ShowDialog", "CompleteQuest"]);
+        let Some(Node::ShowDialog(dialog)) = find_node(&quest, 1) else {
+            panic!("Starting node should be a dialog")
+        };
+        assert_eq!(dialog.actions.len(), 2);
+        assert_eq!(dialog.actions[1].requirement, !item.req_at_least(1));
+        assert!(db.get_item::<QuestItem>(item).is_some());
+    }
+
+    #[test]
+    fn wait_for_condition() {
+        let db = test_database();
+        let item = db.new_quest_item("test:item").id;
+        let loot = db
+            .new_loot("test:loot")
+            .with(|l| l.with_loot(item.as_loot(1)))
+            .id;
+        let quest = build_quest_in(&db, |ctx| {
+            ctx.branch()
+                .receive_item(TEST_START_NODE, loot)
+                .wait_for("wait", "Waiting", item.req_at_least(1))
+                .complete_quest();
+        });
+
+        assert_path(&quest, &["ReceiveItem", "Condition", "CompleteQuest"]);
+        assert_no_dangling_transitions(&quest);
+    }
+
+    #[test]
+    fn quest_opened_twice() {
+        let db = test_database();
+        let _ctx = QuestContext::new(&db, TEST_QUEST_ID, TEST_START_NODE);
+
+        let err = QuestContext::try_new(&db, TEST_QUEST_ID, TEST_START_NODE).unwrap_err();
+        assert!(matches!(err, QuestContextError::AlreadyOpen(_)));
+        assert!(matches!(
+            QuestContext::resume(&db, TEST_QUEST_ID),
+            Err(QuestContextError::AlreadyOpen(_))
+        ));
+    }
+
+    #[test]
+    fn resume_built_quest() {
+        let db = test_database();
+        let quest = build_quest_in(&db, |ctx| {
+            ctx.branch()
+                .dialog(TEST_START_NODE, "Hello", |d| d.next(MSG_CONTINUE))
+                .complete_quest();
+        });
+        quest.remember(&db);
+
+        assert!(matches!(
+            QuestContext::try_new(&db, TEST_QUEST_ID, TEST_START_NODE),
+            Err(QuestContextError::AlreadyBuilt(_))
+        ));
+
+        let mut ctx = QuestContext::resume(&db, TEST_QUEST_ID).unwrap();
+        let start = ctx.id(TEST_START_NODE);
+        assert_eq!(start, START_ID);
+        ctx.branch()
+            .dialog("extra", "Extra", |d| d.next(MSG_CONTINUE))
+            .fail_quest();
+        let quest = ctx.into_quest();
+
+        assert_path(&quest, &["ShowDialog", "CompleteQuest"]);
+        assert!(quest.nodes.iter().any(|n| node_kind(n) == "FailQuest"));
+        assert_no_dangling_transitions(&quest);
+    }
+
+    #[test]
+    fn goto_quest_node_checked_on_save() {
+        let db = test_database();
+        let quest = build_quest_in(&db, |ctx| {
+            ctx.branch().dialog_end(TEST_START_NODE, "Where to?", |d| {
+                d.action("Built", |ctx| {
+                    ctx.branch()
+                        .goto_quest_node("test:other", "other_start")
+                        .entrypoint()
+                })
+                .action("Missing", |ctx| {
+                    ctx.branch()
+                        .goto_quest_node("test:missing", "start")
+                        .entrypoint()
+                })
+                .action("Wrong node", |ctx| {
+                    ctx.branch()
+                        .goto_quest_node("test:other", "not_start")
+                        .entrypoint()
+                })
+            });
+        });
+        assert_path(&quest, &["ShowDialog", "StartQuest", "CancelQuest"]);
+        quest.remember(&db);
+
+        let mut other = QuestContext::new(&db, "test:other", "other_start");
+        other.branch().retreat("other_start").complete_quest();
+        other.into_quest().remember(&db);
+
+        let mut ctx = DiagnosticContext::default();
+        validate_save_checks(&db, &mut ctx);
+        let messages: Vec<_> = ctx
+            .diagnostics
+            .into_values()
+            .flatten()
+            .map(|d| d.kind.to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                DiagnosticKind::unknown_quest("test:missing").to_string(),
+                DiagnosticKind::unknown_quest_node("test:other", "not_start").to_string(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! See `bench.sh` in the repository root for comparing the runs
+
+use std::path::Path;
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+use smart_output::testing::{delete_options, test_dir};
+use smart_output::{FlushParallelism, OutputOptions, SmartOutput};
+
+const FILES: usize = 2000;
+const FILE_SIZE: usize = 4096;
+const SMALL_FILE_SIZE: usize = 256;
+
+fn output(root: &Path) -> SmartOutput {
+    output_with(root, FILE_SIZE, FlushParallelism::default())
+}
+
+fn output_with(root: &Path, file_size: usize, parallelism: FlushParallelism) -> SmartOutput {
+    let options = OutputOptions {
+        parallelism,
+        ..delete_options()
+    };
+    let mut output = SmartOutput::init_with(root.to_path_buf(), options).unwrap();
+    for i in 0..FILES {
+        let content = vec![(i % 256) as u8; file_size];
+        output
+            .add_file(root.join(format!("{}/{i}.json", i % 16)), content)
+            .unwrap();
+    }
+    output
+}
+
+/// Removes the output of the previous iteration
+fn clear(root: &Path) {
+    fs_err::remove_dir_all(root).unwrap();
+    fs_err::create_dir_all(root).unwrap();
+}
+
+fn flush(c: &mut Criterion) {
+    let (_dir, root) = test_dir("fresh");
+    c.bench_function("flush_fresh", |b| {
+        b.iter_batched(
+            || {
+                clear(&root);
+                output(&root)
+            },
+            |output| output.flush().unwrap(),
+            BatchSize::PerIteration,
+        )
+    });
+
+    let (_dir, root) = test_dir("unchanged");
+    output(&root).flush().unwrap();
+    c.bench_function("flush_unchanged", |b| {
+        b.iter_batched(
+            || output(&root),
+            |output| output.flush().unwrap(),
+            BatchSize::PerIteration,
+        )
+    });
+
+    for (name, batch_size) in [("flush_small_unbatched", 1), ("flush_small_batched", 32)] {
+        let (_dir, root) = test_dir(name);
+        let parallelism = FlushParallelism {
+            batch_size,
+            ..Default::default()
+        };
+        c.bench_function(name, |b| {
+            b.iter_batched(
+                || {
+                    clear(&root);
+                    output_with(&root, SMALL_FILE_SIZE, parallelism)
+                },
+                |output| output.flush().unwrap(),
+                BatchSize::PerIteration,
+            )
+        });
+    }
+}
+
+criterion_group!(benches, flush);
+criterion_main!(benches);
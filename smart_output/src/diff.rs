@@ -0,0 +1,195 @@
+//! Line-based unified diff of the rewritten text files, see
+//! [DiffPolicy](crate::DiffPolicy)
+
+use std::fmt::Write;
+
+/// Number of the unchanged lines shown around each change
+const CONTEXT: usize = 3;
+/// Number of the changed lines past which the diff is not computed, since
+/// the memory use of the search grows with it
+const MAX_EDITS: usize = 2048;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Checks whether the content looks like a text that is worth diffing
+pub(crate) fn as_text(data: &[u8]) -> Option<&str> {
+    std::str::from_utf8(data)
+        .ok()
+        .filter(|text| !text.contains('\0'))
+}
+
+/// Formats the unified diff of the two texts, with the path in the header
+///
+/// Returns `None` if there are too many changes
+pub(crate) fn unified_diff(path: &str, old: &str, new: &str) -> Option<String> {
+    let old = old.lines().collect::<Vec<_>>();
+    let new = new.lines().collect::<Vec<_>>();
+    let ops = diff_lines(&old, &new)?;
+
+    // Positions of each op in the old and the new text
+    let mut positions = Vec::with_capacity(ops.len() + 1);
+    let (mut old_pos, mut new_pos) = (0, 0);
+    for (op, _) in &ops {
+        positions.push((old_pos, new_pos));
+        match op {
+            Op::Equal => {
+                old_pos += 1;
+                new_pos += 1;
+            }
+            Op::Delete => old_pos += 1,
+            Op::Insert => new_pos += 1,
+        }
+    }
+    positions.push((old_pos, new_pos));
+
+    let mut hunks: Vec<(usize, usize)> = vec![];
+    for (i, _) in ops
+        .iter()
+        .enumerate()
+        .filter(|(_, (op, _))| *op != Op::Equal)
+    {
+        let start = i.saturating_sub(CONTEXT);
+        let end = (i + CONTEXT + 1).min(ops.len());
+        match hunks.last_mut() {
+            Some(last) if last.1 >= start => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut out = format!("--- {path}\n+++ {path}\n");
+    for (start, end) in hunks {
+        let (old_start, new_start) = positions[start];
+        let (old_end, new_end) = positions[end];
+        let line_start = |start: usize, len: usize| if len == 0 { start } else { start + 1 };
+        writeln!(
+            out,
+            "@@ -{},{} +{},{} @@",
+            line_start(old_start, old_end - old_start),
+            old_end - old_start,
+            line_start(new_start, new_end - new_start),
+            new_end - new_start
+        )
+        .unwrap();
+        for (op, line) in &ops[start..end] {
+            let prefix = match op {
+                Op::Equal => ' ',
+                Op::Delete => '-',
+                Op::Insert => '+',
+            };
+            writeln!(out, "{prefix}{line}").unwrap();
+        }
+    }
+    Some(out)
+}
+
+/// Finds the shortest edit script between the lines via the Myers
+/// algorithm, after trimming the common prefix and suffix
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Option<Vec<(Op, &'a str)>> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+
+    let n = a.len() as isize;
+    let m = b.len() as isize;
+    let max = (a.len() + b.len()).min(MAX_EDITS) as isize;
+    let offset = max + 1;
+    let mut v = vec![0isize; 2 * max as usize + 3];
+    let mut trace = vec![];
+    'search: {
+        for d in 0..=max {
+            trace.push(v.clone());
+            for k in (-d..=d).step_by(2) {
+                let idx = (k + offset) as usize;
+                let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                    v[idx + 1]
+                } else {
+                    v[idx - 1] + 1
+                };
+                let mut y = x - k;
+                while x < n && y < m && a[x as usize] == b[y as usize] {
+                    x += 1;
+                    y += 1;
+                }
+                v[idx] = x;
+                if x >= n && y >= m {
+                    break 'search;
+                }
+            }
+        }
+        return None;
+    }
+
+    let mut middle = vec![];
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let idx = (k + offset) as usize;
+        let prev_k = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[(prev_k + offset) as usize];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            middle.push((Op::Equal, a[x as usize - 1]));
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            if x == prev_x {
+                middle.push((Op::Insert, b[y as usize - 1]));
+            } else {
+                middle.push((Op::Delete, a[x as usize - 1]));
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    middle.reverse();
+
+    let mut ops = old[..prefix]
+        .iter()
+        .map(|line| (Op::Equal, *line))
+        .collect::<Vec<_>>();
+    ops.extend(middle);
+    ops.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|line| (Op::Equal, *line)),
+    );
+    Some(ops)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unified_diff;
+
+    #[test]
+    fn unified() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+        let new = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\n";
+        assert_eq!(
+            unified_diff("x.json", old, new).unwrap(),
+            "--- x.json\n+++ x.json\n\
+             @@ -1,5 +1,5 @@\n a\n-b\n+B\n c\n d\n e\n\
+             @@ -8,3 +8,4 @@\n h\n i\n j\n+k\n"
+        );
+        assert_eq!(
+            unified_diff("x.json", "", "a\n").unwrap(),
+            "--- x.json\n+++ x.json\n@@ -0,0 +1,1 @@\n+a\n"
+        );
+    }
+}
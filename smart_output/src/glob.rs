@@ -0,0 +1,91 @@
+use regex::Regex;
+
+/// Glob pattern matched against the paths relative to the output root
+///
+/// Supports `*` and `?` within a single path component, and `**` spanning
+/// any number of components. Patterns without `/` are matched against the
+/// file name only, so `*.png` matches images in all subdirectories
+#[derive(Debug, Clone)]
+pub struct Glob {
+    pattern: String,
+    regex: Regex,
+    name_only: bool,
+}
+
+impl Glob {
+    pub fn new(pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        let name_only = !pattern.contains('/');
+        let mut regex = String::from("^");
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '*' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    if chars.peek() == Some(&'/') {
+                        chars.next();
+                        regex += "(?:.*/)?";
+                    } else {
+                        regex += ".*";
+                    }
+                }
+                '*' => regex += "[^/]*",
+                '?' => regex += "[^/]",
+                c => regex += &regex::escape(&c.to_string()),
+            }
+        }
+        regex += "$";
+        let regex = Regex::new(&regex).expect("Escaped glob should be a valid regex");
+        Self {
+            pattern,
+            regex,
+            name_only,
+        }
+    }
+
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Checks the path relative to the output root, using either separator
+    pub fn matches(&self, relative: &str) -> bool {
+        let relative = relative.replace('\\', "/");
+        if self.name_only {
+            let name = relative.rsplit('/').next().unwrap_or(&relative);
+            self.regex.is_match(name)
+        } else {
+            self.regex.is_match(&relative)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Glob;
+
+    #[test]
+    fn file_name_patterns() {
+        let glob = Glob::new("*.png");
+        assert!(glob.matches("a.png"));
+        assert!(glob.matches("Images/deep/b.png"));
+        assert!(glob.matches("Images\\c.png"));
+        assert!(!glob.matches("a.png.json"));
+    }
+
+    #[test]
+    fn path_patterns() {
+        let glob = Glob::new("custom/**");
+        assert!(glob.matches("custom/a.json"));
+        assert!(glob.matches("custom/x/y.json"));
+        assert!(!glob.matches("other/custom/a.json"));
+
+        let glob = Glob::new("**/notes/?.txt");
+        assert!(glob.matches("notes/a.txt"));
+        assert!(glob.matches("x/y/notes/b.txt"));
+        assert!(!glob.matches("notes/ab.txt"));
+
+        let glob = Glob::new("data/*.json");
+        assert!(glob.matches("data/a.json"));
+        assert!(!glob.matches("data/x/a.json"));
+    }
+}
@@ -0,0 +1,57 @@
+use sha2::Digest;
+
+/// Hash function used to detect the changes of the managed files
+///
+/// Algorithm is recorded in the marker file, so switching it only causes
+/// the recorded hashes to be recomputed on the next init
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    #[default]
+    Sha256,
+    /// Much faster than sha256 on large files, such as images
+    #[cfg(feature = "blake3")]
+    Blake3,
+}
+
+impl HashAlgorithm {
+    pub fn hash(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            HashAlgorithm::Sha256 => {
+                let mut hasher = sha2::Sha256::new();
+                hasher.update(data);
+                hasher.finalize().to_vec()
+            }
+            #[cfg(feature = "blake3")]
+            HashAlgorithm::Blake3 => blake3::hash(data).as_bytes().to_vec(),
+        }
+    }
+
+    /// Name of the algorithm in the exported manifest
+    pub fn name(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "sha256",
+            #[cfg(feature = "blake3")]
+            HashAlgorithm::Blake3 => "blake3",
+        }
+    }
+
+    /// ID of the algorithm in the marker file
+    pub(crate) fn id(&self) -> u8 {
+        match self {
+            HashAlgorithm::Sha256 => 0,
+            #[cfg(feature = "blake3")]
+            HashAlgorithm::Blake3 => 1,
+        }
+    }
+
+    /// Algorithm with the given marker ID, or `None` if it's unknown or
+    /// disabled
+    pub(crate) fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(HashAlgorithm::Sha256),
+            #[cfg(feature = "blake3")]
+            1 => Some(HashAlgorithm::Blake3),
+            _ => None,
+        }
+    }
+}
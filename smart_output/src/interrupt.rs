@@ -0,0 +1,142 @@
+use std::sync::atomic::{AtomicI32, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Depth of the currently entered critical sections
+static DEPTH: AtomicUsize = AtomicUsize::new(0);
+/// Signal received while inside the critical section, or zero
+static PENDING: AtomicI32 = AtomicI32::new(0);
+/// Serializes installing and restoring the handlers by the outermost
+/// sections of the different threads
+static TRANSITION: Mutex<()> = Mutex::new(());
+
+/// Guard deferring SIGINT and SIGTERM until it's dropped
+///
+/// Used around steps that leave backup files behind when interrupted, so
+/// stopping the build either completes the step or doesn't start it.
+/// Handlers are only installed while the sections are entered. Once the
+/// outermost section is dropped, the previous signal actions are restored
+/// and the deferred signal is raised again, so it reaches the handler that
+/// was installed before. Receiving a second signal while deferring forwards
+/// it right away
+///
+/// Signals are only deferred on unix platforms
+#[must_use]
+#[derive(Debug)]
+pub struct CriticalSection(());
+
+impl CriticalSection {
+    pub fn enter() -> Self {
+        let _transition = TRANSITION.lock().unwrap_or_else(|e| e.into_inner());
+        if DEPTH.fetch_add(1, Ordering::SeqCst) == 0 {
+            install_handlers();
+        }
+        Self(())
+    }
+}
+
+/// Runs the closure inside the [CriticalSection]
+pub fn critical_section<T>(actions: impl FnOnce() -> T) -> T {
+    let _section = CriticalSection::enter();
+    actions()
+}
+
+/// Returns whether the termination signal was received and is waiting for
+/// the critical section to finish
+pub fn interrupt_pending() -> bool {
+    PENDING.load(Ordering::SeqCst) != 0
+}
+
+impl Drop for CriticalSection {
+    fn drop(&mut self) {
+        let _transition = TRANSITION.lock().unwrap_or_else(|e| e.into_inner());
+        if DEPTH.fetch_sub(1, Ordering::SeqCst) == 1 {
+            restore_handlers();
+            let signal = PENDING.swap(0, Ordering::SeqCst);
+            if signal != 0 {
+                tracing::warn!("Build was interrupted, stopping after the completed step");
+                forward(signal);
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+const SIGNALS: [libc::c_int; 2] = [libc::SIGINT, libc::SIGTERM];
+
+/// Actions of the [SIGNALS] that were installed before the handlers
+#[cfg(unix)]
+struct PreviousActions(std::cell::UnsafeCell<[std::mem::MaybeUninit<libc::sigaction>; 2]>);
+
+// SAFETY: actions are only written by the outermost section under the
+// TRANSITION lock before the handlers are installed, and are only read
+// while the handlers are installed
+#[cfg(unix)]
+unsafe impl Sync for PreviousActions {}
+
+#[cfg(unix)]
+static PREVIOUS: PreviousActions = PreviousActions(std::cell::UnsafeCell::new(
+    [std::mem::MaybeUninit::zeroed(); 2],
+));
+
+#[cfg(unix)]
+fn install_handlers() {
+    extern "C" fn handler(signal: libc::c_int) {
+        if DEPTH.load(Ordering::SeqCst) == 0 || PENDING.swap(signal, Ordering::SeqCst) != 0 {
+            forward(signal);
+        }
+    }
+
+    // SAFETY: handler only touches atomics and async-signal-safe libc
+    // functions. Previous actions are not read until the handlers are
+    // installed
+    unsafe {
+        let previous = &mut *PREVIOUS.0.get();
+        for (signal, previous) in SIGNALS.into_iter().zip(previous) {
+            let mut action: libc::sigaction = std::mem::zeroed();
+            action.sa_sigaction = handler as *const () as libc::sighandler_t;
+            action.sa_flags = libc::SA_RESTART;
+            libc::sigemptyset(&mut action.sa_mask);
+            libc::sigaction(signal, &action, previous.as_mut_ptr());
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn install_handlers() {}
+
+/// Restores the action of the signal that was installed before the
+/// handlers
+#[cfg(unix)]
+fn restore_handler(signal: libc::c_int) {
+    let Some(i) = SIGNALS.iter().position(|s| *s == signal) else {
+        return;
+    };
+    // SAFETY: previous action was written when the handlers were
+    // installed, and restoring it is async-signal-safe
+    unsafe {
+        let previous = &*PREVIOUS.0.get();
+        libc::sigaction(signal, previous[i].as_ptr(), std::ptr::null_mut());
+    }
+}
+
+#[cfg(unix)]
+fn restore_handlers() {
+    SIGNALS.into_iter().for_each(restore_handler);
+}
+
+#[cfg(not(unix))]
+fn restore_handlers() {}
+
+/// Raises the signal with the previous action restored, so it reaches the
+/// handler that was installed before, or terminates the process by default
+#[cfg(unix)]
+fn forward(signal: i32) {
+    restore_handler(signal);
+    // SAFETY: raising a signal is async-signal-safe
+    unsafe {
+        libc::raise(signal);
+    }
+}
+
+#[cfg(not(unix))]
+fn forward(_signal: i32) {}
@@ -1,18 +1,43 @@
-use crate::utils::{compress, decompress, sha256};
+use crate::diff::{as_text, unified_diff};
+use crate::glob::Glob;
+use crate::interrupt::CriticalSection;
+use crate::lock::{LockError, OutputLock};
+use crate::marker::{
+    decode_marker, encode_marker, FileStamp, ManagedFile, Marker, MarkerError, MARKER_VERSION,
+};
+use crate::metrics::MetricsSink;
+use crate::utils::{
+    clear_read_only, is_read_only, is_symlink, resolve_symlink, try_write_file,
+    write_preserving_permissions,
+};
 use ahash::AHashSet;
 use bytes::Bytes;
-use flate2::Compression;
 use miette::Diagnostic;
 use std::collections::btree_map::Entry;
 use std::collections::BTreeMap;
-use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::fmt::{Debug, Formatter};
+use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
+use std::time::Instant;
 use thiserror::Error;
-use tracing::debug;
+use tracing::{debug, info, warn};
 
+mod cancel;
+mod diff;
+pub mod glob;
+mod hash;
+pub mod interrupt;
+mod lock;
+mod marker;
+pub mod metrics;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 mod utils;
 
+pub use cancel::CancelToken;
+pub use hash::HashAlgorithm;
+pub use utils::TEMP_FILE_SUFFIX;
+
 #[derive(Debug, Error, Diagnostic)]
 pub enum Error {
     #[error("Failed to read marker file at `{}`: {}", .path.display(), .source)]
@@ -29,8 +54,14 @@ pub enum Error {
     },
     #[error("Failed to decode marker file")]
     ManagedFileDecodeError { source: bitcode::Error },
+    #[error("Marker file at `{}` is corrupted", .path.display())]
+    ManagedFileCorrupted { path: PathBuf },
+    #[error("Marker file at `{}` has version {}, which is newer than the supported version {}", .path.display(), .version, MARKER_VERSION)]
+    ManagedFileUnsupportedVersion { path: PathBuf, version: u32 },
     #[error("Managed file backup present at `{}`, refusing to overwrite", .path.display())]
     ManagedFileBackupPresent { path: PathBuf },
+    #[error("Managed file backup at `{}` can't be reconciled with the marker file: {}", .path.display(), .reason)]
+    ManagedFileBackupUnrecoverable { path: PathBuf, reason: &'static str },
     #[error("Failed to create marker file backup at `{}`: {}", .path.display(), .source)]
     ManagedFileBackupError {
         path: PathBuf,
@@ -61,6 +92,11 @@ pub enum Error {
     FileOutsideRoot { root: PathBuf, path: PathBuf },
     #[error("Output file is a duplicate: `{}`", .path.display())]
     DuplicateFile { path: PathBuf },
+    #[error("Output file belongs to the sub-root: sub_root=`{}`, path=`{}`", .sub_root.display(), .path.display()
+    )]
+    FileInSubRoot { sub_root: PathBuf, path: PathBuf },
+    #[error("Sub-root overlaps with the other sub-root or the added files: `{}`", .path.display())]
+    OverlappingSubRoot { path: PathBuf },
 
     #[error("Failed to create parent directory at `{}`: {}", .path.display(), .source)]
     ParentDirCreateError {
@@ -69,6 +105,12 @@ pub enum Error {
         source: std::io::Error,
     },
 
+    #[error("Failed to read file at `{}`: {}", .path.display(), .source)]
+    FileReadError {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
     #[error("Failed to write file at `{}`: {}", .path.display(), .source)]
     FileWriteError {
         path: PathBuf,
@@ -80,29 +122,309 @@ pub enum Error {
         #[source]
         source: trash::Error,
     },
+    #[error("Failed to delete file at `{}`: {}", .path.display(), .source)]
+    FileDeleteError {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
 
     #[error("Path `{}` contains non-UTF8 sequences", .path.display())]
     NonUtf8Path { path: PathBuf },
+
+    #[error("Output files were modified since the last build: {}", .paths.iter().map(|p| format!("`{}`", p.display())).collect::<Vec<_>>().join(", "))]
+    ManuallyEdited { paths: Vec<PathBuf> },
+    #[error("Output files are symlinks: {}", .paths.iter().map(|p| format!("`{}`", p.display())).collect::<Vec<_>>().join(", "))]
+    SymlinkedFiles { paths: Vec<PathBuf> },
+    #[error("Output files are read-only: {}", .paths.iter().map(|p| format!("`{}`", p.display())).collect::<Vec<_>>().join(", "))]
+    ReadOnlyFiles { paths: Vec<PathBuf> },
+    #[error("Output directory at `{}` is locked by another writer", .path.display())]
+    OutputLocked { path: PathBuf },
+    #[error("Failed to lock the output directory at `{}`: {}", .path.display(), .source)]
+    LockError {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Failed to create the flush thread pool: {}", .source)]
+    ThreadPoolError {
+        #[source]
+        source: rayon::ThreadPoolBuildError,
+    },
+    #[error("Flush was cancelled")]
+    Cancelled,
+}
+
+/// Handling of output files that were modified since the last flush
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EditPolicy {
+    /// Overwrite or remove the modified files, logging a warning
+    #[default]
+    Overwrite,
+    /// Leave the modified files untouched, logging a warning. Kept files are
+    /// reported again on every flush until the edit is reverted or the
+    /// file is removed
+    KeepAndWarn,
+    /// Fail the flush before any file is written
+    Error,
+}
+
+/// Handling of files that are no longer part of the output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CleanupPolicy {
+    /// Move the files to the trash, so they can be restored
+    #[default]
+    Trash,
+    /// Delete the files permanently. Useful on headless machines that lack
+    /// the trash
+    Delete,
+    /// Leave the files on disk, they are no longer managed by the output
+    Keep,
+}
+
+/// Handling of output files that are symlinks
+///
+/// Only the files themselves are affected, symlinked directories and
+/// Windows junctions inside the output directory are always followed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SymlinkPolicy {
+    /// Replace the link with a regular file when writing, leaving the link
+    /// target untouched
+    #[default]
+    Replace,
+    /// Write the content into the link target, keeping the link
+    Follow,
+    /// Fail the flush before any file is written
+    Error,
+}
+
+/// Handling of output files that are marked read-only
+///
+/// Permissions of the other rewritten files are always kept as they were
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReadOnlyPolicy {
+    /// Fail the flush before any file is written
+    #[default]
+    Error,
+    /// Make the files writable for the write or removal, and mark the
+    /// rewritten ones read-only again
+    Restore,
+    /// Make the files writable for good
+    Clear,
+}
+
+/// Recording of the changes of the rewritten text files in
+/// [SmartOutput::flush], as unified diffs against the previous content
+///
+/// Only files that were managed before and are still present on disk are
+/// diffed, binary files are skipped
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiffPolicy {
+    #[default]
+    Off,
+    /// Log the diffs at the info level
+    Log,
+    /// Return the diffs in the [FlushReport::diffs]
+    Report,
+}
+
+/// Parallelism of writing the files in [SmartOutput::flush]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlushParallelism {
+    /// Number of the threads writing the files, `None` uses the global rayon
+    /// pool
+    pub threads: Option<usize>,
+    /// Files of at most this many bytes are considered small, and are
+    /// written in batches
+    pub small_file_size: usize,
+    /// Maximum number of the small files written by a single task, `1`
+    /// disables the batching
+    ///
+    /// Writing many tiny files one task each is dominated by the scheduling
+    /// and syscall overhead, especially on Windows
+    pub batch_size: usize,
+}
+
+impl Default for FlushParallelism {
+    fn default() -> Self {
+        Self {
+            threads: None,
+            small_file_size: 16 * 1024,
+            batch_size: 32,
+        }
+    }
+}
+
+/// Configuration of the [SmartOutput]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutputOptions {
+    /// Handling of output files that were modified since the last flush
+    pub edit_policy: EditPolicy,
+    /// Refuse to start managing a directory that already contains files
+    ///
+    /// Without the marker file, all existing files are treated as unknown
+    /// and are left untouched, but files with the same names as the output
+    /// ones are overwritten
+    pub strict_adoption: bool,
+    pub cleanup_policy: CleanupPolicy,
+    pub hash_algorithm: HashAlgorithm,
+    /// Recover from the marker backup left over by the interrupted flush,
+    /// instead of failing the init
+    ///
+    /// Files recorded only by the backup are managed again if they are
+    /// unchanged, so they are cleaned up by the next flush. Fails if the
+    /// backup and the marker can't be reconciled
+    pub recover_backup: bool,
+    /// Handling of output files that are symlinks. Cleanup always removes
+    /// the link itself, unless the policy is [SymlinkPolicy::Error]
+    pub symlink_policy: SymlinkPolicy,
+    pub read_only_policy: ReadOnlyPolicy,
+    pub diff_policy: DiffPolicy,
+    pub parallelism: FlushParallelism,
 }
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
-const MANAGED_FILES_NAME: &str = ".managed_files";
-const MANAGED_FILES_BACKUP_NAME: &str = ".managed_files.bk";
+pub const MANAGED_FILES_NAME: &str = ".managed_files";
+/// Name of the managed files backup, which is only present while flushing
+pub const MANAGED_FILES_BACKUP_NAME: &str = ".managed_files.bk";
+/// Name of the lock file, which prevents simultaneous writers from racing
+/// on the same output directory
+pub const LOCK_FILE_NAME: &str = ".managed_files.lock";
+
+type ContentProvider = Box<dyn FnOnce() -> Bytes + Send>;
+
+/// Content of the added file
+enum FileContent {
+    Ready(Bytes),
+    /// Produced during the flush, see [SmartOutput::add_file_with]
+    Lazy(ContentProvider),
+}
+
+impl FileContent {
+    fn into_bytes(self) -> Bytes {
+        match self {
+            FileContent::Ready(data) => data,
+            FileContent::Lazy(provider) => provider(),
+        }
+    }
+}
+
+impl Debug for FileContent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileContent::Ready(data) => f.debug_tuple("Ready").field(&data.len()).finish(),
+            FileContent::Lazy(_) => f.write_str("Lazy"),
+        }
+    }
+}
+
+/// Progress of the [SmartOutput::flush], see [SmartOutput::on_progress]
+#[derive(Debug, Clone, Copy)]
+pub enum FlushEvent<'a> {
+    /// Flush started, with the number of the added files
+    Started {
+        files: usize,
+    },
+    /// Added file is unchanged, or is an edited file that was kept
+    Skipped(&'a Path),
+    Written(&'a Path),
+    /// Cleanup started, with the number of the files to remove
+    Cleanup {
+        files: usize,
+    },
+    Removed(&'a Path),
+    Finished,
+}
+
+/// Receiver of the [FlushEvent]s
+///
+/// Events are reported from the worker threads as the files are processed,
+/// so they may arrive out of order
+#[derive(Clone)]
+pub struct FlushProgress(Arc<dyn Fn(FlushEvent) + Send + Sync>);
+
+impl FlushProgress {
+    pub fn new(callback: impl Fn(FlushEvent) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    fn emit(progress: &Option<FlushProgress>, event: FlushEvent) {
+        if let Some(progress) = progress {
+            (progress.0)(event);
+        }
+    }
+}
+
+impl<F: Fn(FlushEvent) + Send + Sync + 'static> From<F> for FlushProgress {
+    fn from(callback: F) -> Self {
+        Self::new(callback)
+    }
+}
+
+impl Debug for FlushProgress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("FlushProgress")
+    }
+}
 
 #[must_use]
 #[derive(Debug)]
 pub struct SmartOutput {
-    files: BTreeMap<PathBuf, Bytes>,
+    files: BTreeMap<PathBuf, FileContent>,
     root: PathBuf,
     managed_files_path: PathBuf,
     managed_files_backup_path: PathBuf,
     parent_dirs: AHashSet<PathBuf>,
-    hashes: BTreeMap<String, Vec<u8>>,
+    hashes: BTreeMap<String, ManagedFile>,
+    edit_policy: EditPolicy,
+    cleanup_policy: CleanupPolicy,
+    strict_adoption: bool,
+    hash_algorithm: HashAlgorithm,
+    recover_backup: bool,
+    symlink_policy: SymlinkPolicy,
+    read_only_policy: ReadOnlyPolicy,
+    diff_policy: DiffPolicy,
+    parallelism: FlushParallelism,
+    preserve: Vec<Glob>,
+    sub_roots: Vec<PathBuf>,
+    progress: Option<FlushProgress>,
+    metrics: Option<MetricsSink>,
+    _lock: OutputLock,
 }
 
 impl SmartOutput {
     pub fn init(path: PathBuf) -> Result<Self> {
+        Self::init_with(path, OutputOptions::default())
+    }
+
+    pub fn init_with(path: PathBuf, options: OutputOptions) -> Result<Self> {
+        Self::init_adopting(path, options, BTreeMap::new())
+    }
+
+    /// Initializes the output, taking over the records of the files that
+    /// were managed by the parent output if the marker is missing
+    ///
+    /// Output directory stays locked until the output is flushed or
+    /// dropped, so other writers fail with [Error::OutputLocked] instead of
+    /// racing on the marker and each other's cleanup
+    fn init_adopting(
+        path: PathBuf,
+        options: OutputOptions,
+        adopted: BTreeMap<String, ManagedFile>,
+    ) -> Result<Self> {
+        fs_err::create_dir_all(&path).map_err(|e| Error::ParentDirCreateError {
+            path: path.clone(),
+            source: e,
+        })?;
+        let lock_path = path.join(LOCK_FILE_NAME);
+        let lock = OutputLock::acquire(&lock_path).map_err(|e| match e {
+            LockError::Locked => Error::OutputLocked { path: path.clone() },
+            LockError::Io(e) => Error::LockError {
+                path: lock_path,
+                source: e,
+            },
+        })?;
         let managed_files_path = path.join(MANAGED_FILES_NAME);
         let managed_files_backup_path = path.join(MANAGED_FILES_BACKUP_NAME);
         let mut out = Self {
@@ -112,58 +434,184 @@ impl SmartOutput {
             managed_files_path,
             managed_files_backup_path,
             parent_dirs: Default::default(),
+            edit_policy: options.edit_policy,
+            cleanup_policy: options.cleanup_policy,
+            strict_adoption: options.strict_adoption,
+            hash_algorithm: options.hash_algorithm,
+            recover_backup: options.recover_backup,
+            symlink_policy: options.symlink_policy,
+            read_only_policy: options.read_only_policy,
+            diff_policy: options.diff_policy,
+            parallelism: options.parallelism,
+            preserve: Default::default(),
+            sub_roots: Default::default(),
+            progress: None,
+            metrics: None,
+            _lock: lock,
         };
 
-        out.init_hashes()?;
+        out.init_hashes(options.strict_adoption, adopted)?;
 
         Ok(out)
     }
 
-    fn init_hashes(&mut self) -> Result<()> {
-        if self.managed_files_backup_path.exists() {
-            return Err(Error::ManagedFileBackupPresent {
-                path: self.managed_files_backup_path.to_path_buf(),
+    /// Sets the handling of files that were modified by hand since the last
+    /// flush
+    pub fn with_edit_policy(mut self, policy: EditPolicy) -> Self {
+        self.edit_policy = policy;
+        self
+    }
+
+    /// Reports the progress of the flush, so long flushes can be displayed
+    pub fn on_progress(mut self, progress: impl Into<FlushProgress>) -> Self {
+        self.progress = Some(progress.into());
+        self
+    }
+
+    /// Reports the metrics of the flush, see [metrics]
+    pub fn with_metrics(mut self, metrics: impl Into<MetricsSink>) -> Self {
+        self.metrics = Some(metrics.into());
+        self
+    }
+
+    /// Protects the files matching any of the globs from the cleanup, see
+    /// [Glob] for the syntax
+    ///
+    /// Matching files that are no longer produced are left on disk and stop
+    /// being managed, so hand-authored files can live in the output
+    /// directory. Produced files are written as usual
+    pub fn preserve_globs(mut self, globs: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.preserve.extend(globs.into_iter().map(Glob::new));
+        self
+    }
+
+    /// Registers the sub-directory that is managed by its own output, with
+    /// a separate marker file, so both can be flushed independently
+    ///
+    /// Files inside the sub-root can no longer be added to this output.
+    /// Records of such files are moved to the sub-root, so this output
+    /// neither cleans them up nor tracks their edits. Sub-root uses the
+    /// same options, progress and metrics receivers, but not the preserve
+    /// globs
+    pub fn sub_root(&mut self, relative: impl AsRef<Path>) -> Result<SmartOutput> {
+        let relative = relative.as_ref();
+        let path = self.root.join(relative);
+        if relative.as_os_str().is_empty()
+            || !relative
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)))
+        {
+            return Err(Error::FileOutsideRoot {
+                root: self.root.clone(),
+                path,
             });
+        }
+        if self
+            .sub_roots
+            .iter()
+            .any(|root| root.starts_with(&path) || path.starts_with(root))
+            || self.files.keys().any(|file| file.starts_with(&path))
+        {
+            return Err(Error::OverlappingSubRoot { path });
+        }
+
+        fs_err::create_dir_all(&path).map_err(|e| Error::ParentDirCreateError {
+            path: path.clone(),
+            source: e,
+        })?;
+
+        let mut adopted = BTreeMap::new();
+        self.hashes.retain(|key, record| {
+            let Some(rest) = Path::new(key)
+                .strip_prefix(relative)
+                .ok()
+                .and_then(|rest| rest.to_str())
+            else {
+                return true;
+            };
+            adopted.insert(rest.to_string(), record.clone());
+            false
+        });
+
+        let options = OutputOptions {
+            edit_policy: self.edit_policy,
+            strict_adoption: self.strict_adoption,
+            cleanup_policy: self.cleanup_policy,
+            hash_algorithm: self.hash_algorithm,
+            recover_backup: self.recover_backup,
+            symlink_policy: self.symlink_policy,
+            read_only_policy: self.read_only_policy,
+            diff_policy: self.diff_policy,
+            parallelism: self.parallelism,
+        };
+        let mut output = Self::init_adopting(path.clone(), options, adopted)?;
+        output.progress = self.progress.clone();
+        output.metrics = self.metrics.clone();
+        self.sub_roots.push(path);
+        Ok(output)
+    }
+
+    fn init_hashes(
+        &mut self,
+        strict_adoption: bool,
+        adopted: BTreeMap<String, ManagedFile>,
+    ) -> Result<()> {
+        if self.managed_files_backup_path.exists() {
+            if !self.recover_backup {
+                return Err(Error::ManagedFileBackupPresent {
+                    path: self.managed_files_backup_path.to_path_buf(),
+                });
+            }
+            self.recover_from_backup()?;
         };
 
         let hashes = if self.managed_files_path.exists() {
-            let data = fs_err::read(&self.managed_files_path).map_err(|e| {
-                Error::ManagedFileReadError {
-                    path: self.managed_files_path.to_path_buf(),
-                    source: e,
-                }
-            })?;
-            let data = decompress(&data);
-            bitcode::decode(&data).map_err(|e| Error::ManagedFileDecodeError { source: e })?
+            let marker = read_marker(&self.managed_files_path)?;
+            if marker.algorithm == Some(self.hash_algorithm) {
+                marker.files
+            } else {
+                use rayon::prelude::*;
+                debug!(
+                    from = ?marker.algorithm,
+                    to = ?self.hash_algorithm,
+                    "Re-hashing managed files"
+                );
+                marker
+                    .files
+                    .into_par_iter()
+                    .map(|(relative, record)| {
+                        let path = self.root.join(&relative);
+                        let record = record.rehash(&path, marker.algorithm, self.hash_algorithm);
+                        (relative, record)
+                    })
+                    .collect()
+            }
         } else {
-            // todo: re-enable this as a config option for projects that want to be ultra-safe?
-            // if self.root.exists()
-            //     && fs_err::read_dir(&self.root)
-            //         .map_err(|e| Error::ProjectDirReadError {
-            //             path: self.root.to_path_buf(),
-            //             source: e,
-            //         })?
-            //         .next()
-            //         .is_some()
-            // {
-            //     return Err(Error::NewProjectDirectoryNotEmpty {
-            //         path: self.root.to_path_buf(),
-            //     });
-            // }
+            if strict_adoption
+                && adopted.is_empty()
+                && self.root.exists()
+                && fs_err::read_dir(&self.root)
+                    .map_err(|e| Error::ProjectDirReadError {
+                        path: self.root.to_path_buf(),
+                        source: e,
+                    })?
+                    .any(|entry| entry.is_ok_and(|entry| entry.file_name() != LOCK_FILE_NAME))
+            {
+                return Err(Error::NewProjectDirectoryNotEmpty {
+                    path: self.root.to_path_buf(),
+                });
+            }
 
             fs_err::write(
                 &self.managed_files_path,
-                compress(
-                    &bitcode::encode(&BTreeMap::<String, Vec<u8>>::new()),
-                    Compression::best(),
-                ),
+                encode_marker(&adopted, self.hash_algorithm),
             )
             .map_err(|e| Error::ManagedFileWriteError {
                 path: self.managed_files_path.to_path_buf(),
                 source: e,
             })?;
 
-            BTreeMap::<String, Vec<u8>>::default()
+            adopted
         };
 
         self.hashes = hashes;
@@ -171,18 +619,115 @@ impl SmartOutput {
         Ok(())
     }
 
+    /// Reconciles the marker with the backup left over by the interrupted
+    /// flush, and removes the backup
+    fn recover_from_backup(&self) -> Result<()> {
+        use rayon::prelude::*;
+
+        let unrecoverable = |reason| Error::ManagedFileBackupUnrecoverable {
+            path: self.managed_files_backup_path.to_path_buf(),
+            reason,
+        };
+        let backup = read_marker(&self.managed_files_backup_path).ok();
+        let current = if self.managed_files_path.exists() {
+            read_marker(&self.managed_files_path).ok()
+        } else {
+            None
+        };
+
+        match (current, backup) {
+            // Flush was interrupted after the marker was written, so the
+            // files removed from it may still be on disk
+            (Some(current), Some(backup)) => {
+                if current.algorithm != backup.algorithm {
+                    return Err(unrecoverable("hash algorithms differ"));
+                }
+                let Some(algorithm) = current.algorithm else {
+                    return Err(unrecoverable("hash algorithm is not supported"));
+                };
+                let mut files = current.files;
+                let root = &self.root;
+                let restored = backup
+                    .files
+                    .into_par_iter()
+                    .filter(|(relative, _)| !files.contains_key(relative))
+                    .filter(|(relative, record)| {
+                        let path = root.join(relative);
+                        path.exists() && !record.is_edited(&path, algorithm)
+                    })
+                    .collect::<Vec<_>>();
+                if !restored.is_empty() {
+                    warn!(
+                        files = restored.len(),
+                        "Restoring records of the files left over by the interrupted flush"
+                    );
+                    files.extend(restored);
+                    try_write_file(&self.managed_files_path, &encode_marker(&files, algorithm))
+                        .map_err(|e| Error::ManagedFileWriteError {
+                            path: self.managed_files_path.to_path_buf(),
+                            source: e,
+                        })?;
+                }
+            }
+            // Marker is written atomically, so the backup is redundant
+            (Some(_), None) => {
+                warn!("Discarding the unreadable managed files backup");
+            }
+            (None, Some(_)) => {
+                warn!("Restoring the missing or unreadable marker file from the backup");
+                fs_err::copy(&self.managed_files_backup_path, &self.managed_files_path).map_err(
+                    |e| Error::ManagedFileWriteError {
+                        path: self.managed_files_path.to_path_buf(),
+                        source: e,
+                    },
+                )?;
+            }
+            (None, None) => {
+                return Err(unrecoverable(
+                    "neither the marker nor the backup can be read",
+                ));
+            }
+        }
+
+        remove_marker_backup(&self.managed_files_backup_path, self.cleanup_policy)
+    }
+
     pub fn add_file(&mut self, path: PathBuf, content: impl Into<Bytes>) -> Result<()> {
+        self.add_file_content(path, FileContent::Ready(content.into()))
+    }
+
+    /// Adds the file whose content is produced during the flush
+    ///
+    /// Content is hashed right after it's produced, and is only kept in
+    /// memory if the file needs to be written, so large outputs that mostly
+    /// stay the same don't have to be held in memory at once. Providers are
+    /// called in parallel
+    pub fn add_file_with(
+        &mut self,
+        path: PathBuf,
+        content: impl FnOnce() -> Bytes + Send + 'static,
+    ) -> Result<()> {
+        self.add_file_content(path, FileContent::Lazy(Box::new(content)))
+    }
+
+    fn add_file_content(&mut self, path: PathBuf, content: FileContent) -> Result<()> {
         if !path.starts_with(&self.root) {
             return Err(Error::FileOutsideRoot {
                 root: self.root.clone(),
                 path,
             });
         }
+        if let Some(sub_root) = self.sub_roots.iter().find(|root| path.starts_with(root)) {
+            return Err(Error::FileInSubRoot {
+                sub_root: sub_root.clone(),
+                path,
+            });
+        }
 
         match self.files.entry(path.clone()) {
             Entry::Occupied(_) => return Err(Error::DuplicateFile { path }),
             Entry::Vacant(entry) => {
-                entry.insert(content.into());
+                entry.insert(content);
             }
         }
 
@@ -194,7 +739,38 @@ impl SmartOutput {
     }
 
     /// Flushes the output to the filesystem
-    pub fn flush(self) -> Result<()> {
+    ///
+    /// Termination signals received during the flush are deferred until it
+    /// completes, see [CriticalSection]
+    ///
+    /// Writes all changed files and removes the files that are no longer
+    /// part of the output, returning the changes that were made
+    ///
+    /// Output directory is unlocked once the flush returns, whether it
+    /// succeeded or not
+    pub fn flush(self) -> Result<FlushReport> {
+        self.flush_with(&CancelToken::new())
+    }
+
+    /// Same as [SmartOutput::flush], but stops with [Error::Cancelled] once
+    /// the token is cancelled
+    ///
+    /// Cancelled flush stops between the files and skips the cleanup. Files
+    /// that were already written are recorded in the marker alongside the
+    /// previous records, so the next flush picks up from the consistent
+    /// state
+    pub fn flush_with(self, cancel: &CancelToken) -> Result<FlushReport> {
+        let Some(threads) = self.parallelism.threads else {
+            return self.flush_in_pool(cancel);
+        };
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .map_err(|e| Error::ThreadPoolError { source: e })?;
+        pool.install(|| self.flush_in_pool(cancel))
+    }
+
+    fn flush_in_pool(self, cancel: &CancelToken) -> Result<FlushReport> {
         use rayon::prelude::*;
 
         let SmartOutput {
@@ -204,8 +780,133 @@ impl SmartOutput {
             managed_files_backup_path,
             parent_dirs,
             hashes,
+            edit_policy,
+            cleanup_policy,
+            strict_adoption: _,
+            hash_algorithm,
+            recover_backup: _,
+            symlink_policy,
+            read_only_policy,
+            diff_policy,
+            parallelism,
+            preserve,
+            sub_roots: _,
+            progress,
+            metrics,
+            _lock,
         } = self;
 
+        let started = Instant::now();
+        let metrics = metrics.unwrap_or_else(|| MetricsSink::new(|_| {}));
+        let total_to_write = files.len();
+        FlushProgress::emit(&progress, FlushEvent::Started { files: files.len() });
+
+        // Content of the files that stay the same is dropped right away
+        let files = files
+            .into_par_iter()
+            .map(|(path, content)| {
+                let relative = relative_path(&root, &path)?;
+                let data = content.into_bytes();
+                let hash_started = Instant::now();
+                let hash = hash_algorithm.hash(&data);
+                metrics.duration(metrics::HASH_SECONDS, hash_started.elapsed());
+                let data = hashes
+                    .get(&relative)
+                    .is_none_or(|old| old.hash != hash)
+                    .then_some(data);
+                if data.is_none() {
+                    FlushProgress::emit(&progress, FlushEvent::Skipped(&path));
+                }
+                Ok((path, relative, data, hash))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let written = files
+            .iter()
+            .map(|(_, relative, _, _)| relative.as_str())
+            .collect::<AHashSet<_>>();
+        let gone_files = hashes
+            .keys()
+            .filter(|k| !written.contains(k.as_str()))
+            .filter(|k| !preserve.iter().any(|glob| glob.matches(k)))
+            .map(|relative| (root.join(relative), relative))
+            .filter(|(p, _)| fs_err::symlink_metadata(p).is_ok())
+            .collect::<Vec<_>>();
+
+        // Only files that are about to be changed are checked, edited files
+        // that would be written with the same content are left as is anyway
+        let edited = files
+            .par_iter()
+            .filter(|(_, relative, _, hash)| {
+                hashes.get(relative).is_some_and(|old| old.hash != *hash)
+            })
+            .map(|(path, relative, _, _)| (path.as_path(), relative.as_str()))
+            .chain(
+                gone_files
+                    .par_iter()
+                    .map(|(path, relative)| (path.as_path(), relative.as_str())),
+            )
+            .filter(|(path, relative)| hashes[*relative].is_edited(path, hash_algorithm))
+            .map(|(path, _)| path.to_path_buf())
+            .collect::<ahash::HashSet<_>>();
+
+        if !edited.is_empty() {
+            let mut paths = edited.iter().cloned().collect::<Vec<_>>();
+            paths.sort();
+            match edit_policy {
+                EditPolicy::Overwrite => {
+                    warn!(
+                        ?paths,
+                        "Overwriting output files that were modified by hand"
+                    )
+                }
+                EditPolicy::KeepAndWarn => {
+                    warn!(?paths, "Keeping output files that were modified by hand")
+                }
+                EditPolicy::Error => return Err(Error::ManuallyEdited { paths }),
+            }
+        }
+        let keep_edited = edit_policy == EditPolicy::KeepAndWarn;
+
+        if symlink_policy == SymlinkPolicy::Error {
+            let mut paths = files
+                .iter()
+                .filter(|(_, _, data, _)| data.is_some())
+                .map(|(path, _, _, _)| path)
+                .chain(gone_files.iter().map(|(path, _)| path))
+                .filter(|path| is_symlink(path))
+                .cloned()
+                .collect::<Vec<_>>();
+            if !paths.is_empty() {
+                paths.sort();
+                return Err(Error::SymlinkedFiles { paths });
+            }
+        }
+
+        if read_only_policy == ReadOnlyPolicy::Error {
+            let removed = gone_files
+                .iter()
+                .filter(|_| cleanup_policy != CleanupPolicy::Keep)
+                .map(|(path, _)| path);
+            let mut paths = files
+                .iter()
+                .filter(|(_, _, data, _)| data.is_some())
+                .map(|(path, _, _, _)| path)
+                .chain(removed)
+                .filter(|path| is_read_only(path))
+                .cloned()
+                .collect::<Vec<_>>();
+            if !paths.is_empty() {
+                paths.sort();
+                return Err(Error::ReadOnlyFiles { paths });
+            }
+        }
+
+        if cancel.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+
+        let _section = CriticalSection::enter();
         fs_err::copy(&managed_files_path, &managed_files_backup_path).map_err(|e| {
             Error::ManagedFileBackupError {
                 path: managed_files_backup_path.to_path_buf(),
@@ -220,75 +921,159 @@ impl SmartOutput {
             })
         })?;
 
-        let updated_count = Arc::new(AtomicUsize::new(0));
-        let total_to_write = files.len();
-
-        fn try_write_file(
-            root: &Path,
-            path: &Path,
-            data: Bytes,
-            hashes: &BTreeMap<String, Vec<u8>>,
-            updated_count: &AtomicUsize,
-        ) -> Result<Option<(String, Vec<u8>)>> {
-            let relative = path
-                .strip_prefix(root)
-                .expect("All file paths are inside root");
-            let Some(relative) = relative.as_os_str().to_str() else {
-                return Err(Error::NonUtf8Path {
-                    path: path.to_path_buf(),
+        let batches = write_batches(files, parallelism, |(_, _, data, _)| {
+            data.as_ref().map_or(0, |data| data.len())
+        });
+        let new_hashes_list = batches
+            .into_par_iter()
+            .flat_map_iter(|batch| batch.into_iter())
+            .map(|(path, relative, data, hash)| {
+                if cancel.is_cancelled() {
+                    return Ok(None);
+                }
+                let old = hashes.get(&relative);
+                if keep_edited && edited.contains(&path) {
+                    FlushProgress::emit(&progress, FlushEvent::Skipped(&path));
+                    let old = old.expect("Edited files are managed").clone();
+                    return Ok(Some((relative, old, None, None)));
+                }
+                let mut diff = None;
+                let stamp = if let Some(data) = &data {
+                    let target = match symlink_policy {
+                        SymlinkPolicy::Follow => resolve_symlink(&path),
+                        SymlinkPolicy::Replace | SymlinkPolicy::Error => path.clone(),
+                    };
+                    if diff_policy != DiffPolicy::Off && old.is_some() {
+                        diff = file_diff(&target, &relative, data);
+                    }
+                    let keep_read_only = read_only_policy == ReadOnlyPolicy::Restore;
+                    write_preserving_permissions(&target, data, keep_read_only).map_err(|e| {
+                        Error::FileWriteError {
+                            path: path.to_path_buf(),
+                            source: e,
+                        }
+                    })?;
+                    FlushProgress::emit(&progress, FlushEvent::Written(&path));
+                    metrics.counter(metrics::FILES_WRITTEN, 1);
+                    metrics.counter(metrics::BYTES_WRITTEN, data.len() as u64);
+                    FileStamp::of(&path)
+                } else {
+                    old.and_then(|old| old.stamp)
+                };
+                let diff = diff.map(|diff| FileDiff {
+                    path: path.clone(),
+                    diff,
                 });
-            };
-            let hash = sha256(&data);
-
-            let old_hash = hashes.get(relative).cloned();
+                let record = ManagedFile { hash, stamp };
+                Ok(Some((
+                    relative,
+                    record,
+                    data.is_some().then_some(path),
+                    diff,
+                )))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
 
-            if !old_hash.is_some_and(|old_hash| old_hash == hash) {
-                fs_err::write(path, data).map_err(|e| Error::FileWriteError {
-                    path: path.to_path_buf(),
-                    source: e,
-                })?;
+        if new_hashes_list.iter().any(Option::is_none) {
+            let mut partial_hashes = hashes;
+            partial_hashes.extend(
+                new_hashes_list
+                    .into_iter()
+                    .flatten()
+                    .map(|(relative, record, _, _)| (relative, record)),
+            );
+            try_write_file(
+                &managed_files_path,
+                &encode_marker(&partial_hashes, hash_algorithm),
+            )
+            .map_err(|e| Error::ManagedFileWriteError {
+                path: managed_files_path,
+                source: e,
+            })?;
+            remove_marker_backup(&managed_files_backup_path, cleanup_policy)?;
+            debug!("Output flush was cancelled");
+            return Err(Error::Cancelled);
+        }
 
-                updated_count.fetch_add(1, Ordering::Release);
+        let mut updated = vec![];
+        let mut diffs = vec![];
+        let mut new_hashes = BTreeMap::default();
+        for (relative, record, path, diff) in new_hashes_list.into_iter().flatten() {
+            updated.extend(path);
+            diffs.extend(diff);
+            new_hashes.insert(relative, record);
+        }
+        updated.sort();
+        diffs.sort_by(|a: &FileDiff, b| a.path.cmp(&b.path));
+        if diff_policy == DiffPolicy::Log {
+            for FileDiff { path, diff } in std::mem::take(&mut diffs) {
+                info!(path = %path.display(), "Output file changed\n{diff}");
             }
-
-            Ok(Some((relative.to_string(), hash)))
         }
 
-        let new_hashes = files
-            .into_par_iter()
-            .filter_map(|(path, data)| {
-                try_write_file(&root, &path, data, &hashes, &updated_count).transpose()
+        let gone_files = gone_files
+            .into_iter()
+            .filter(|(path, relative)| {
+                let keep = keep_edited && edited.contains(path);
+                if keep {
+                    new_hashes.insert(relative.to_string(), hashes[*relative].clone());
+                }
+                !keep
             })
-            .collect::<Result<ahash::HashMap<String, Vec<u8>>, Error>>()?;
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
 
-        fs_err::write(
+        try_write_file(
             &managed_files_path,
-            compress(&bitcode::encode(&new_hashes), Compression::best()),
+            &encode_marker(&new_hashes, hash_algorithm),
         )
         .map_err(|e| Error::ManagedFileWriteError {
             path: managed_files_path,
             source: e,
         })?;
 
-        let updated_count = updated_count.load(Ordering::Acquire);
-
-        let gone_files = hashes
-            .keys()
-            .filter(|k| !new_hashes.contains_key(&**k))
-            .map(|path| root.join(path))
-            .filter(|p| p.exists())
-            .collect::<Vec<_>>();
+        let updated_count = updated.len();
 
         let cleaned_count = gone_files.len();
 
-        trash::delete_all(gone_files).map_err(|e| Error::CleanupError { source: e })?;
-
-        trash::delete(&managed_files_backup_path).map_err(|e| {
-            Error::ManagedFileBackupDeleteError {
-                path: managed_files_backup_path,
-                source: e,
+        if cleanup_policy != CleanupPolicy::Keep {
+            FlushProgress::emit(
+                &progress,
+                FlushEvent::Cleanup {
+                    files: gone_files.len(),
+                },
+            );
+        }
+        let gone_files = match cleanup_policy {
+            CleanupPolicy::Trash => {
+                trash::delete_all(&gone_files).map_err(|e| Error::CleanupError { source: e })?;
+                for path in &gone_files {
+                    FlushProgress::emit(&progress, FlushEvent::Removed(path));
+                }
+                gone_files
             }
-        })?;
+            CleanupPolicy::Delete => {
+                gone_files.par_iter().try_for_each(|path| {
+                    clear_read_only(path)
+                        .and_then(|_| fs_err::remove_file(path))
+                        .map_err(|e| Error::FileDeleteError {
+                            path: path.to_path_buf(),
+                            source: e,
+                        })?;
+                    FlushProgress::emit(&progress, FlushEvent::Removed(path));
+                    Ok(())
+                })?;
+                gone_files
+            }
+            CleanupPolicy::Keep => {
+                if !gone_files.is_empty() {
+                    debug!(paths=?gone_files, "Keeping files that are no longer part of the output");
+                }
+                vec![]
+            }
+        };
+
+        remove_marker_backup(&managed_files_backup_path, cleanup_policy)?;
 
         debug!(
             updated_files = updated_count,
@@ -296,6 +1081,722 @@ impl SmartOutput {
             cleaned_files = cleaned_count,
             "Output flushed successfully"
         );
-        Ok(())
+        metrics.counter(
+            metrics::FILES_SKIPPED,
+            (total_to_write - updated_count) as u64,
+        );
+        metrics.counter(metrics::FILES_REMOVED, gone_files.len() as u64);
+        metrics.duration(metrics::FLUSH_SECONDS, started.elapsed());
+        FlushProgress::emit(&progress, FlushEvent::Finished);
+        Ok(FlushReport {
+            updated,
+            removed: gone_files,
+            diffs,
+        })
+    }
+
+    /// Writes the JSON listing of the managed files and their hashes, for
+    /// the external tooling like packaging scripts
+    ///
+    /// Lists the files recorded by the last flush, files added since are not
+    /// included. Paths are relative to the output root, and hashes are
+    /// lowercase hex strings
+    pub fn export_manifest(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let manifest = Manifest {
+            algorithm: self.hash_algorithm.name(),
+            files: self
+                .hashes
+                .iter()
+                .map(|(relative, record)| (relative.as_str(), to_hex(&record.hash)))
+                .collect(),
+        };
+        let data = serde_json::to_vec_pretty(&manifest).expect("Manifest should be serializable");
+        try_write_file(path, &data).map_err(|e| Error::FileWriteError {
+            path: path.to_path_buf(),
+            source: e,
+        })
+    }
+
+    /// Compares the added files with the ones on disk without changing
+    /// anything, returning the files that a [SmartOutput::flush] would
+    /// change
+    ///
+    /// Useful for checking that the committed output is up to date with the
+    /// generator. Files inside sub-roots are not checked
+    pub fn verify(self) -> Result<VerifyReport> {
+        use rayon::prelude::*;
+
+        enum Drift {
+            Changed,
+            Missing,
+        }
+
+        let SmartOutput {
+            files,
+            root,
+            hashes,
+            hash_algorithm,
+            preserve,
+            ..
+        } = self;
+
+        let checked = files
+            .into_par_iter()
+            .map(|(path, content)| {
+                let relative = relative_path(&root, &path)?;
+                let hash = hash_algorithm.hash(&content.into_bytes());
+                // Matching record of the unchanged file saves reading it
+                let recorded = hashes
+                    .get(&relative)
+                    .is_some_and(|old| old.hash == hash && !old.is_edited(&path, hash_algorithm));
+                let state = if recorded {
+                    None
+                } else {
+                    match fs_err::read(&path) {
+                        Ok(data) => (hash_algorithm.hash(&data) != hash).then_some(Drift::Changed),
+                        Err(_) if !path.exists() => Some(Drift::Missing),
+                        Err(e) => {
+                            return Err(Error::FileReadError {
+                                path: path.clone(),
+                                source: e,
+                            })
+                        }
+                    }
+                };
+                Ok((path, relative, state))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut report = VerifyReport::default();
+        let mut produced = AHashSet::with_capacity(checked.len());
+        for (path, relative, state) in checked {
+            match state {
+                Some(Drift::Changed) => report.drifted.push(path),
+                Some(Drift::Missing) => report.deleted.push(path),
+                None => {}
+            }
+            produced.insert(relative);
+        }
+        report.extra = hashes
+            .keys()
+            .filter(|k| !produced.contains(k.as_str()))
+            .filter(|k| !preserve.iter().any(|glob| glob.matches(k)))
+            .map(|relative| root.join(relative))
+            .filter(|path| path.exists())
+            .collect();
+
+        Ok(report)
+    }
+}
+
+/// Path of the file relative to the output root, as recorded in the marker
+fn relative_path(root: &Path, path: &Path) -> Result<String> {
+    let relative = path
+        .strip_prefix(root)
+        .expect("All file paths are inside root");
+    let Some(relative) = relative.as_os_str().to_str() else {
+        return Err(Error::NonUtf8Path {
+            path: path.to_path_buf(),
+        });
+    };
+    Ok(relative.to_string())
+}
+
+/// Groups the files into the write tasks. Consecutive small files share a
+/// task, while larger ones get a task each
+fn write_batches<T>(
+    files: Vec<T>,
+    parallelism: FlushParallelism,
+    size: impl Fn(&T) -> usize,
+) -> Vec<Vec<T>> {
+    let mut batches = vec![];
+    let mut batch = vec![];
+    for file in files {
+        if size(&file) > parallelism.small_file_size {
+            batches.push(vec![file]);
+            continue;
+        }
+        batch.push(file);
+        if batch.len() >= parallelism.batch_size {
+            batches.push(std::mem::take(&mut batch));
+        }
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    batches
+}
+
+fn read_marker(path: &Path) -> Result<Marker> {
+    let data = fs_err::read(path).map_err(|e| Error::ManagedFileReadError {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    decode_marker(&data).map_err(|e| match e {
+        MarkerError::Corrupted => Error::ManagedFileCorrupted {
+            path: path.to_path_buf(),
+        },
+        MarkerError::Decode(source) => Error::ManagedFileDecodeError { source },
+        MarkerError::UnsupportedVersion(version) => Error::ManagedFileUnsupportedVersion {
+            path: path.to_path_buf(),
+            version,
+        },
+    })
+}
+
+fn remove_marker_backup(path: &Path, cleanup_policy: CleanupPolicy) -> Result<()> {
+    if cleanup_policy == CleanupPolicy::Trash {
+        trash::delete(path).map_err(|e| Error::ManagedFileBackupDeleteError {
+            path: path.to_path_buf(),
+            source: e,
+        })
+    } else {
+        fs_err::remove_file(path).map_err(|e| Error::FileDeleteError {
+            path: path.to_path_buf(),
+            source: e,
+        })
+    }
+}
+
+/// Differences between the added files and the ones on disk, found by
+/// [SmartOutput::verify]. All lists are sorted
+#[derive(Debug, Clone, Default)]
+pub struct VerifyReport {
+    /// Files whose content on disk differs from the added one
+    pub drifted: Vec<PathBuf>,
+    /// Added files that are missing on disk
+    pub deleted: Vec<PathBuf>,
+    /// Managed files that are no longer added, and would be cleaned up
+    pub extra: Vec<PathBuf>,
+}
+
+impl VerifyReport {
+    pub fn is_empty(&self) -> bool {
+        self.drifted.is_empty() && self.deleted.is_empty() && self.extra.is_empty()
+    }
+}
+
+/// Listing of the managed files, see [SmartOutput::export_manifest]
+#[derive(serde::Serialize)]
+struct Manifest<'a> {
+    algorithm: &'static str,
+    files: BTreeMap<&'a str, String>,
+}
+
+fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Files changed by [SmartOutput::flush]
+#[derive(Debug, Clone, Default)]
+pub struct FlushReport {
+    /// Files that were created or whose content changed, sorted
+    pub updated: Vec<PathBuf>,
+    /// Files that were removed, since they are no longer part of the output
+    pub removed: Vec<PathBuf>,
+    /// Changes of the rewritten text files, sorted by path, only filled
+    /// with [DiffPolicy::Report]
+    pub diffs: Vec<FileDiff>,
+}
+
+/// Unified diff of the file rewritten by [SmartOutput::flush]
+#[derive(Debug, Clone)]
+pub struct FileDiff {
+    pub path: PathBuf,
+    pub diff: String,
+}
+
+/// Diffs the file on disk against the new content, if both are text
+fn file_diff(path: &Path, relative: &str, data: &[u8]) -> Option<String> {
+    let old = fs_err::read(path).ok()?;
+    let diff = unified_diff(relative, as_text(&old)?, as_text(data)?);
+    if diff.is_none() {
+        debug!(path = %path.display(), "Output file has too many changes to diff");
+    }
+    diff
+}
+
+impl FlushReport {
+    pub fn is_empty(&self) -> bool {
+        self.updated.is_empty() && self.removed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+    use std::sync::{Arc, Mutex};
+
+    use super::{
+        write_batches, CancelToken, DiffPolicy, Error, FlushEvent, FlushParallelism, OutputOptions,
+        ReadOnlyPolicy, SmartOutput, SymlinkPolicy, MANAGED_FILES_BACKUP_NAME, MANAGED_FILES_NAME,
+    };
+    use crate::metrics::{self, Metric};
+    use crate::testing::{delete_options, test_dir};
+
+    #[test]
+    fn sub_root_flushes_independently() {
+        let (_dir, root) = test_dir("sub_root");
+        let options = delete_options();
+
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        output.add_file(root.join("a.json"), "a").unwrap();
+        output.add_file(root.join("images/b.png"), "b").unwrap();
+        output.flush().unwrap();
+
+        // Records of the files inside are moved to the sub-root, so the
+        // parent doesn't clean them up
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        drop(output.sub_root("images").unwrap());
+        assert!(matches!(
+            output.add_file(root.join("images/c.png"), "c"),
+            Err(Error::FileInSubRoot { .. })
+        ));
+        output.add_file(root.join("a.json"), "a").unwrap();
+        assert!(output.flush().unwrap().is_empty());
+        assert!(root.join("images/b.png").exists());
+        assert!(root.join("images/.managed_files").exists());
+
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        let report = output.sub_root("images").unwrap().flush().unwrap();
+        assert_eq!(report.removed, vec![root.join("images/b.png")]);
+        output.add_file(root.join("a.json"), "a").unwrap();
+        assert!(output.flush().unwrap().is_empty());
+    }
+
+    #[test]
+    fn lazy_content() {
+        let (_dir, root) = test_dir("lazy_content");
+        let options = delete_options();
+
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        output
+            .add_file_with(root.join("a.json"), || "a".into())
+            .unwrap();
+        let report = output.flush().unwrap();
+        assert_eq!(report.updated, vec![root.join("a.json")]);
+        assert_eq!(std::fs::read(root.join("a.json")).unwrap(), b"a");
+
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        output
+            .add_file_with(root.join("a.json"), || "a".into())
+            .unwrap();
+        assert!(output.flush().unwrap().is_empty());
+    }
+
+    #[test]
+    fn recover_backup() {
+        let (_dir, root) = test_dir("recover_backup");
+        let options = delete_options();
+
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        output.add_file(root.join("a.json"), "a").unwrap();
+        output.add_file(root.join("b.json"), "b").unwrap();
+        output.flush().unwrap();
+        let marker = root.join(MANAGED_FILES_NAME);
+        let backup = root.join(MANAGED_FILES_BACKUP_NAME);
+        let old_marker = std::fs::read(&marker).unwrap();
+
+        // Simulates the flush interrupted after writing the new marker, but
+        // before removing the stale file
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        output.add_file(root.join("a.json"), "a").unwrap();
+        output.flush().unwrap();
+        std::fs::write(&backup, old_marker).unwrap();
+        std::fs::write(root.join("b.json"), "b").unwrap();
+        assert!(matches!(
+            SmartOutput::init_with(root.clone(), options),
+            Err(Error::ManagedFileBackupPresent { .. })
+        ));
+
+        let recover = OutputOptions {
+            recover_backup: true,
+            ..options
+        };
+        let mut output = SmartOutput::init_with(root.clone(), recover).unwrap();
+        assert!(!backup.exists());
+        output.add_file(root.join("a.json"), "a").unwrap();
+        let report = output.flush().unwrap();
+        assert_eq!(report.removed, vec![root.join("b.json")]);
+
+        std::fs::copy(&marker, &backup).unwrap();
+        std::fs::write(&marker, "garbage").unwrap();
+        drop(SmartOutput::init_with(root.clone(), recover).unwrap());
+        assert!(!backup.exists());
+
+        std::fs::write(&marker, "garbage").unwrap();
+        std::fs::write(&backup, "garbage").unwrap();
+        assert!(matches!(
+            SmartOutput::init_with(root.clone(), recover),
+            Err(Error::ManagedFileBackupUnrecoverable { .. })
+        ));
+    }
+
+    #[test]
+    fn verify_reports_drift() {
+        let (_dir, root) = test_dir("verify");
+        let options = delete_options();
+
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        for name in ["a.json", "b.json", "c.json", "d.json"] {
+            output.add_file(root.join(name), name).unwrap();
+        }
+        output.flush().unwrap();
+
+        let verify = |files: &[(&str, &str)]| {
+            let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+            for (name, content) in files {
+                output
+                    .add_file(root.join(name), content.to_string())
+                    .unwrap();
+            }
+            output.verify().unwrap()
+        };
+        let unchanged = [
+            ("a.json", "a.json"),
+            ("b.json", "b.json"),
+            ("c.json", "c.json"),
+            ("d.json", "d.json"),
+        ];
+        assert!(verify(&unchanged).is_empty());
+
+        std::fs::write(root.join("b.json"), "edited").unwrap();
+        std::fs::remove_file(root.join("c.json")).unwrap();
+        let report = verify(&[
+            ("a.json", "changed"),
+            ("b.json", "b.json"),
+            ("c.json", "c.json"),
+        ]);
+        assert_eq!(
+            report.drifted,
+            vec![root.join("a.json"), root.join("b.json")]
+        );
+        assert_eq!(report.deleted, vec![root.join("c.json")]);
+        assert_eq!(report.extra, vec![root.join("d.json")]);
+        // Verification doesn't touch the files
+        assert_eq!(std::fs::read(root.join("a.json")).unwrap(), b"a.json");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_policy() {
+        let (_dir, root) = test_dir("symlink_policy");
+        let (_target_dir, target) = test_dir("symlink_policy_target");
+        let target = target.join("target.json");
+        std::fs::write(&target, "target").unwrap();
+        let link = root.join("a.json");
+        let write = |policy| {
+            let options = OutputOptions {
+                symlink_policy: policy,
+                ..delete_options()
+            };
+            let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+            output
+                .add_file(link.clone(), format!("{policy:?}"))
+                .unwrap();
+            output.flush()
+        };
+
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+        assert!(matches!(
+            write(SymlinkPolicy::Error),
+            Err(Error::SymlinkedFiles { .. })
+        ));
+        write(SymlinkPolicy::Follow).unwrap();
+        assert!(link.is_symlink());
+        assert_eq!(std::fs::read(&target).unwrap(), b"Follow");
+
+        write(SymlinkPolicy::Replace).unwrap();
+        assert!(!link.is_symlink());
+        assert_eq!(std::fs::read(&link).unwrap(), b"Replace");
+        assert_eq!(std::fs::read(&target).unwrap(), b"Follow");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn read_only_policy() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let (_dir, root) = test_dir("read_only");
+        let path = root.join("a.json");
+        let write = |policy, content: &str| {
+            let options = OutputOptions {
+                read_only_policy: policy,
+                ..Default::default()
+            };
+            let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+            output.add_file(path.clone(), content.to_string()).unwrap();
+            output.flush()
+        };
+        let mode = |path: &PathBuf| std::fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        let set_mode =
+            |mode| std::fs::set_permissions(&path, std::fs::Permissions::from_mode(mode)).unwrap();
+
+        write(ReadOnlyPolicy::Error, "a").unwrap();
+        set_mode(0o640);
+        write(ReadOnlyPolicy::Error, "b").unwrap();
+        assert_eq!(mode(&path), 0o640);
+
+        set_mode(0o440);
+        assert!(matches!(
+            write(ReadOnlyPolicy::Error, "c"),
+            Err(Error::ReadOnlyFiles { .. })
+        ));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "b");
+
+        write(ReadOnlyPolicy::Restore, "c").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "c");
+        assert_eq!(mode(&path), 0o440);
+
+        write(ReadOnlyPolicy::Clear, "d").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "d");
+        assert_eq!(mode(&path), 0o640);
+    }
+
+    #[test]
+    fn progress_events() {
+        let (_dir, root) = test_dir("progress");
+        let options = delete_options();
+        let flush = |files: &[&str]| {
+            let events = Arc::new(Mutex::new(vec![]));
+            let sink = events.clone();
+            let mut output = SmartOutput::init_with(root.clone(), options)
+                .unwrap()
+                .on_progress(move |event: FlushEvent| {
+                    let event = match event {
+                        FlushEvent::Started { files } => format!("started {files}"),
+                        FlushEvent::Skipped(path) => format!("skipped {}", path.display()),
+                        FlushEvent::Written(path) => format!("written {}", path.display()),
+                        FlushEvent::Cleanup { files } => format!("cleanup {files}"),
+                        FlushEvent::Removed(path) => format!("removed {}", path.display()),
+                        FlushEvent::Finished => "finished".to_string(),
+                    };
+                    sink.lock().unwrap().push(event);
+                });
+            for name in files {
+                output.add_file(root.join(name), name.to_string()).unwrap();
+            }
+            output.flush().unwrap();
+            let mut events = events.lock().unwrap().clone();
+            events.sort();
+            events
+        };
+        let a = root.join("a.json");
+        let b = root.join("b.json");
+
+        assert_eq!(
+            flush(&["a.json", "b.json"]),
+            vec![
+                "cleanup 0".to_string(),
+                "finished".to_string(),
+                "started 2".to_string(),
+                format!("written {}", a.display()),
+                format!("written {}", b.display()),
+            ]
+        );
+        assert_eq!(
+            flush(&["a.json"]),
+            vec![
+                "cleanup 1".to_string(),
+                "finished".to_string(),
+                format!("removed {}", b.display()),
+                format!("skipped {}", a.display()),
+                "started 1".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn batched_flush() {
+        let parallelism = FlushParallelism {
+            threads: Some(2),
+            small_file_size: 4,
+            batch_size: 2,
+        };
+        let batches = write_batches(vec![1, 2, 10, 3, 4, 5], parallelism, |size| *size);
+        assert_eq!(batches, vec![vec![1, 2], vec![10], vec![3, 4], vec![5]]);
+
+        let (_dir, root) = test_dir("batched");
+        let options = OutputOptions {
+            parallelism,
+            ..delete_options()
+        };
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        for i in 0..5 {
+            output
+                .add_file(root.join(format!("{i}.json")), "x".repeat(i * 2))
+                .unwrap();
+        }
+        let report = output.flush().unwrap();
+        assert_eq!(report.updated.len(), 5);
+        for i in 0..5 {
+            let data = std::fs::read_to_string(root.join(format!("{i}.json"))).unwrap();
+            assert_eq!(data, "x".repeat(i * 2));
+        }
+    }
+
+    #[test]
+    fn export_manifest() {
+        let (_dir, root) = test_dir("manifest");
+        let mut output = SmartOutput::init(root.clone()).unwrap();
+        output.add_file(root.join("a/b.json"), "data").unwrap();
+        output.flush().unwrap();
+
+        let manifest = root.join("manifest.json");
+        let output = SmartOutput::init(root.clone()).unwrap();
+        output.export_manifest(&manifest).unwrap();
+        let manifest: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(manifest).unwrap()).unwrap();
+        assert_eq!(manifest["algorithm"], "sha256");
+        let path = Path::new("a").join("b.json");
+        assert_eq!(
+            manifest["files"][path.to_str().unwrap()],
+            "3a6eb0790f39ac87c94f3856b2dd2c5d110e6811602261a9a923d3bb23adc8b7"
+        );
+    }
+
+    #[test]
+    fn output_lock() {
+        let (_dir, root) = test_dir("lock");
+        let output = SmartOutput::init(root.clone()).unwrap();
+        assert!(matches!(
+            SmartOutput::init(root.clone()),
+            Err(Error::OutputLocked { .. })
+        ));
+        output.flush().unwrap();
+
+        // Lock file left after the flush doesn't make the directory look
+        // hand-authored to the strict adoption
+        std::fs::remove_file(root.join(MANAGED_FILES_NAME)).unwrap();
+        let options = OutputOptions {
+            strict_adoption: true,
+            ..Default::default()
+        };
+        let output = SmartOutput::init_with(root.clone(), options).unwrap();
+        drop(output);
+        SmartOutput::init(root.clone()).unwrap().flush().unwrap();
+    }
+
+    #[test]
+    fn diff_report() {
+        let (_dir, root) = test_dir("diff_report");
+        let options = OutputOptions {
+            diff_policy: DiffPolicy::Report,
+            ..Default::default()
+        };
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        output
+            .add_file(root.join("a.json"), "{\n  \"a\": 1\n}\n")
+            .unwrap();
+        output.add_file(root.join("b.png"), vec![0u8, 1]).unwrap();
+        assert!(output.flush().unwrap().diffs.is_empty());
+
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        output
+            .add_file(root.join("a.json"), "{\n  \"a\": 2\n}\n")
+            .unwrap();
+        output.add_file(root.join("b.png"), vec![0u8, 2]).unwrap();
+        let report = output.flush().unwrap();
+        assert_eq!(report.diffs.len(), 1);
+        assert_eq!(report.diffs[0].path, root.join("a.json"));
+        assert_eq!(
+            report.diffs[0].diff,
+            "--- a.json\n+++ a.json\n@@ -1,3 +1,3 @@\n {\n-  \"a\": 1\n+  \"a\": 2\n }\n"
+        );
+    }
+
+    #[test]
+    fn cancelled_flush() {
+        let (_dir, root) = test_dir("cancelled_flush");
+        let options = OutputOptions {
+            parallelism: FlushParallelism {
+                threads: Some(1),
+                batch_size: 1,
+                ..Default::default()
+            },
+            ..delete_options()
+        };
+        let names = ["a.json", "b.json", "c.json"];
+
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        for name in names.iter().chain(&["d.json"]) {
+            output.add_file(root.join(name), "1").unwrap();
+        }
+        output.flush().unwrap();
+
+        // Single worker writes the files in order, so only the first one is
+        // written before the cancellation is noticed
+        let cancel = CancelToken::new();
+        let token = cancel.clone();
+        let mut output = SmartOutput::init_with(root.clone(), options)
+            .unwrap()
+            .on_progress(move |event: FlushEvent| {
+                if let FlushEvent::Written(_) = event {
+                    token.cancel();
+                }
+            });
+        for name in names {
+            output.add_file(root.join(name), "2").unwrap();
+        }
+        assert!(matches!(output.flush_with(&cancel), Err(Error::Cancelled)));
+        assert_eq!(std::fs::read(root.join("a.json")).unwrap(), b"2");
+        assert_eq!(std::fs::read(root.join("b.json")).unwrap(), b"1");
+        assert!(root.join("d.json").exists());
+        assert!(!root.join(MANAGED_FILES_BACKUP_NAME).exists());
+
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        for name in names {
+            output.add_file(root.join(name), "2").unwrap();
+        }
+        let report = output.flush().unwrap();
+        assert_eq!(
+            report.updated,
+            vec![root.join("b.json"), root.join("c.json")]
+        );
+        assert_eq!(report.removed, vec![root.join("d.json")]);
+    }
+
+    #[test]
+    fn flush_metrics() {
+        let (_dir, root) = test_dir("flush_metrics");
+        let options = delete_options();
+
+        let mut output = SmartOutput::init_with(root.clone(), options).unwrap();
+        output.add_file(root.join("a.json"), "1").unwrap();
+        output.add_file(root.join("b.json"), "1").unwrap();
+        output.flush().unwrap();
+
+        let recorded = Arc::new(Mutex::new(vec![]));
+        let sink = recorded.clone();
+        let mut output = SmartOutput::init_with(root.clone(), options)
+            .unwrap()
+            .with_metrics(move |metric: Metric| sink.lock().unwrap().push(metric));
+        output.add_file(root.join("a.json"), "1").unwrap();
+        output.add_file(root.join("c.json"), "123").unwrap();
+        output.flush().unwrap();
+
+        let recorded = recorded.lock().unwrap();
+        let counter = |name: &str| -> u64 {
+            recorded
+                .iter()
+                .filter_map(|metric| match metric {
+                    Metric::Counter { name: n, value } if *n == name => Some(*value),
+                    _ => None,
+                })
+                .sum()
+        };
+        let samples = |name: &str| {
+            recorded
+                .iter()
+                .filter(|metric| matches!(metric, Metric::Histogram { name: n, .. } if *n == name))
+                .count()
+        };
+        assert_eq!(counter(metrics::FILES_WRITTEN), 1);
+        assert_eq!(counter(metrics::BYTES_WRITTEN), 3);
+        assert_eq!(counter(metrics::FILES_SKIPPED), 1);
+        assert_eq!(counter(metrics::FILES_REMOVED), 1);
+        assert_eq!(samples(metrics::HASH_SECONDS), 2);
+        assert_eq!(samples(metrics::FLUSH_SECONDS), 1);
     }
 }
@@ -0,0 +1,36 @@
+use std::fs::{File, TryLockError};
+use std::path::Path;
+
+/// Exclusive lock of the output directory, held from the
+/// [SmartOutput](crate::SmartOutput) init until its flush or drop
+///
+/// Lock is taken on the lock file via the OS advisory lock, so it is released
+/// even if the process is killed. Lock file itself is left on disk, since
+/// removing it would let the waiting writer lock the unlinked file
+#[derive(Debug)]
+pub(crate) struct OutputLock {
+    _file: File,
+}
+
+pub(crate) enum LockError {
+    Locked,
+    Io(std::io::Error),
+}
+
+impl OutputLock {
+    pub(crate) fn acquire(path: &Path) -> Result<Self, LockError> {
+        let file = fs_err::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(path)
+            .map_err(LockError::Io)?
+            .into_parts()
+            .0;
+        match file.try_lock() {
+            Ok(()) => Ok(Self { _file: file }),
+            Err(TryLockError::WouldBlock) => Err(LockError::Locked),
+            Err(TryLockError::Error(e)) => Err(LockError::Io(e)),
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use flate2::Compression;
+
+use crate::hash::HashAlgorithm;
+use crate::utils::{compress, decompress};
+
+/// Prefix of the versioned marker, followed by the little-endian `u32`
+/// version and the payload of that version. Markers without it are the
+/// legacy ones, holding a plain map of the sha256 hashes
+const ENVELOPE_MAGIC: &[u8; 4] = b"SOMV";
+/// Version of the markers written by this build
+///
+/// Changing the payload requires bumping the version and migrating the
+/// payloads of the previous versions in [decode_marker]
+pub(crate) const MARKER_VERSION: u32 = 1;
+
+/// Record of the managed file
+#[derive(Debug, Clone, PartialEq, Eq, bitcode::Encode, bitcode::Decode)]
+pub(crate) struct ManagedFile {
+    pub hash: Vec<u8>,
+    /// Metadata of the file right after it was written, `None` for the
+    /// files recorded by the legacy marker format
+    pub stamp: Option<FileStamp>,
+}
+
+/// Size and modification time of the file, used to detect the changes
+/// without reading the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, bitcode::Encode, bitcode::Decode)]
+pub(crate) struct FileStamp {
+    size: u64,
+    modified_secs: u64,
+    modified_nanos: u32,
+}
+
+impl FileStamp {
+    /// Reads the stamp of the file, returning `None` if the file is missing
+    /// or the platform lacks the modification time
+    pub fn of(path: &Path) -> Option<Self> {
+        let metadata = fs_err::metadata(path).ok()?;
+        let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+        Some(Self {
+            size: metadata.len(),
+            modified_secs: modified.as_secs(),
+            modified_nanos: modified.subsec_nanos(),
+        })
+    }
+}
+
+impl ManagedFile {
+    /// Checks whether the file content differs from the recorded one
+    ///
+    /// Matching size and modification time are trusted, and the file is
+    /// only hashed when they changed
+    pub fn is_edited(&self, path: &Path, algorithm: HashAlgorithm) -> bool {
+        let stamp = FileStamp::of(path);
+        if stamp.is_some() && stamp == self.stamp {
+            return false;
+        }
+        if let (Some(stamp), Some(old)) = (stamp, self.stamp) {
+            if stamp.size != old.size {
+                return true;
+            }
+        }
+        fs_err::read(path).map_or(true, |data| algorithm.hash(&data) != self.hash)
+    }
+
+    /// Converts the record made with the `from` algorithm, which is `None`
+    /// if it's not available, to the `to` algorithm
+    ///
+    /// Hash is recomputed from the file on disk if it's unchanged. Changed
+    /// or missing files get an empty hash, which never matches, so they are
+    /// treated as edited
+    pub fn rehash(&self, path: &Path, from: Option<HashAlgorithm>, to: HashAlgorithm) -> Self {
+        let unchanged = match from {
+            Some(from) => !self.is_edited(path, from),
+            None => self.stamp.is_some() && FileStamp::of(path) == self.stamp,
+        };
+        let hash = unchanged
+            .then(|| fs_err::read(path).ok())
+            .flatten()
+            .map(|data| to.hash(&data))
+            .unwrap_or_default();
+        Self {
+            hash,
+            stamp: self.stamp,
+        }
+    }
+}
+
+/// Payload of the current marker version
+#[derive(bitcode::Encode, bitcode::Decode)]
+struct MarkerPayload {
+    algorithm: u8,
+    files: BTreeMap<String, ManagedFile>,
+}
+
+#[derive(Debug)]
+pub(crate) enum MarkerError {
+    /// Marker is not a valid zlib stream, or lacks the version
+    Corrupted,
+    Decode(bitcode::Error),
+    /// Marker was written by a newer build
+    UnsupportedVersion(u32),
+}
+
+impl From<bitcode::Error> for MarkerError {
+    fn from(err: bitcode::Error) -> Self {
+        MarkerError::Decode(err)
+    }
+}
+
+/// Managed files recorded by the marker file
+pub(crate) struct Marker {
+    /// Algorithm of the recorded hashes, `None` if it's unknown or disabled
+    pub algorithm: Option<HashAlgorithm>,
+    pub files: BTreeMap<String, ManagedFile>,
+}
+
+pub(crate) fn encode_marker(
+    files: &BTreeMap<String, ManagedFile>,
+    algorithm: HashAlgorithm,
+) -> Vec<u8> {
+    let payload = MarkerPayload {
+        algorithm: algorithm.id(),
+        files: files.clone(),
+    };
+    let mut data = ENVELOPE_MAGIC.to_vec();
+    data.extend(MARKER_VERSION.to_le_bytes());
+    data.extend(bitcode::encode(&payload));
+    compress(&data, Compression::best())
+}
+
+/// Decodes the marker of any version up to the current one, migrating it
+/// to the current representation
+pub(crate) fn decode_marker(data: &[u8]) -> Result<Marker, MarkerError> {
+    let data = decompress(data).map_err(|_| MarkerError::Corrupted)?;
+    if let Some(data) = data.strip_prefix(ENVELOPE_MAGIC) {
+        let Some((version, payload)) = data.split_first_chunk::<4>() else {
+            return Err(MarkerError::Corrupted);
+        };
+        let version = u32::from_le_bytes(*version);
+        if version != MARKER_VERSION {
+            return Err(MarkerError::UnsupportedVersion(version));
+        }
+        let payload: MarkerPayload = bitcode::decode(payload)?;
+        return Ok(Marker {
+            algorithm: HashAlgorithm::from_id(payload.algorithm),
+            files: payload.files,
+        });
+    }
+    let hashes: BTreeMap<String, Vec<u8>> = bitcode::decode(&data)?;
+    Ok(Marker {
+        algorithm: Some(HashAlgorithm::Sha256),
+        files: hashes
+            .into_iter()
+            .map(|(path, hash)| (path, ManagedFile { hash, stamp: None }))
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use flate2::Compression;
+
+    use super::{
+        decode_marker, encode_marker, FileStamp, ManagedFile, MarkerError, ENVELOPE_MAGIC,
+        MARKER_VERSION,
+    };
+    use crate::hash::HashAlgorithm;
+    use crate::testing::test_dir;
+    use crate::utils::compress;
+
+    #[test]
+    fn legacy_marker() {
+        let hashes = BTreeMap::from([("a.json".to_string(), vec![1u8, 2, 3])]);
+        let data = compress(&bitcode::encode(&hashes), Compression::best());
+        let marker = decode_marker(&data).unwrap();
+        assert_eq!(marker.algorithm, Some(HashAlgorithm::Sha256));
+        assert_eq!(
+            marker.files["a.json"],
+            ManagedFile {
+                hash: vec![1, 2, 3],
+                stamp: None
+            }
+        );
+    }
+
+    #[test]
+    fn marker_roundtrip() {
+        let files = BTreeMap::from([(
+            "a.json".to_string(),
+            ManagedFile {
+                hash: vec![4, 5],
+                stamp: Some(FileStamp {
+                    size: 10,
+                    modified_secs: 20,
+                    modified_nanos: 30,
+                }),
+            },
+        )]);
+        let marker = decode_marker(&encode_marker(&files, HashAlgorithm::Sha256)).unwrap();
+        assert_eq!(marker.algorithm, Some(HashAlgorithm::Sha256));
+        assert_eq!(marker.files, files);
+    }
+
+    #[test]
+    fn unsupported_marker() {
+        let mut data = ENVELOPE_MAGIC.to_vec();
+        data.extend((MARKER_VERSION + 1).to_le_bytes());
+        assert!(matches!(
+            decode_marker(&compress(&data, Compression::best())),
+            Err(MarkerError::UnsupportedVersion(version)) if version == MARKER_VERSION + 1
+        ));
+        assert!(matches!(
+            decode_marker(b"not a marker"),
+            Err(MarkerError::Corrupted)
+        ));
+    }
+
+    #[test]
+    fn rehash_unknown_algorithm() {
+        let (_dir, dir) = test_dir("rehash");
+        let path = dir.join("a.json");
+        std::fs::write(&path, b"data").unwrap();
+        let sha256 = HashAlgorithm::Sha256.hash(b"data");
+
+        // Matching stamp proves the file is unchanged without the old hash
+        let record = ManagedFile {
+            hash: vec![1, 2, 3],
+            stamp: FileStamp::of(&path),
+        };
+        assert_eq!(
+            record.rehash(&path, None, HashAlgorithm::Sha256).hash,
+            sha256
+        );
+
+        let record = ManagedFile {
+            hash: vec![1, 2, 3],
+            stamp: None,
+        };
+        let record = record.rehash(&path, None, HashAlgorithm::Sha256);
+        assert!(record.hash.is_empty());
+        assert!(record.is_edited(&path, HashAlgorithm::Sha256));
+    }
+}
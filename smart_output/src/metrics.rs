@@ -0,0 +1,71 @@
+//! Metrics of the output flushes, see [SmartOutput::with_metrics]
+//!
+//! Metrics are reported through the callback, so long-running build
+//! services can forward them into any metrics backend, like the `metrics`
+//! crate. Names of the metrics reported by the [SmartOutput::flush] are
+//! listed in this module
+//!
+//! [SmartOutput::with_metrics]: crate::SmartOutput::with_metrics
+//! [SmartOutput::flush]: crate::SmartOutput::flush
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Counter of the written files
+pub const FILES_WRITTEN: &str = "smart_output.files_written";
+/// Counter of the added files that were left as is
+pub const FILES_SKIPPED: &str = "smart_output.files_skipped";
+/// Counter of the files removed by the cleanup
+pub const FILES_REMOVED: &str = "smart_output.files_removed";
+/// Counter of the bytes written into the output files
+pub const BYTES_WRITTEN: &str = "smart_output.bytes_written";
+/// Histogram of the time spent hashing each added file, in seconds
+pub const HASH_SECONDS: &str = "smart_output.hash_seconds";
+/// Histogram of the successful flush durations, in seconds
+pub const FLUSH_SECONDS: &str = "smart_output.flush_seconds";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Metric {
+    /// Increment of the counter
+    Counter { name: &'static str, value: u64 },
+    /// Sample of the histogram, durations are reported in seconds
+    Histogram { name: &'static str, value: f64 },
+}
+
+/// Receiver of the [Metric]s
+///
+/// Like the [FlushProgress](crate::FlushProgress), metrics are reported
+/// from the worker threads
+#[derive(Clone)]
+pub struct MetricsSink(Arc<dyn Fn(Metric) + Send + Sync>);
+
+impl MetricsSink {
+    pub fn new(callback: impl Fn(Metric) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    pub fn counter(&self, name: &'static str, value: u64) {
+        (self.0)(Metric::Counter { name, value });
+    }
+
+    pub fn histogram(&self, name: &'static str, value: f64) {
+        (self.0)(Metric::Histogram { name, value });
+    }
+
+    pub fn duration(&self, name: &'static str, duration: Duration) {
+        self.histogram(name, duration.as_secs_f64());
+    }
+}
+
+impl<F: Fn(Metric) + Send + Sync + 'static> From<F> for MetricsSink {
+    fn from(callback: F) -> Self {
+        Self::new(callback)
+    }
+}
+
+impl Debug for MetricsSink {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MetricsSink")
+    }
+}
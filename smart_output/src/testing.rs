@@ -0,0 +1,30 @@
+//! Fixtures of the tests and benchmarks that write into real directories
+//!
+//! Enabled by the `testing` feature for the tests of the dependent crates
+
+use std::path::PathBuf;
+
+use tempdir::TempDir;
+
+use crate::{CleanupPolicy, OutputOptions};
+
+/// Creates an empty temporary directory, returning its guard alongside the
+/// path
+///
+/// Directory is removed once the guard is dropped, so it doesn't leak when
+/// the test fails. Keep the guard in a named binding, since `_` drops it
+/// right away
+pub fn test_dir(name: &str) -> (TempDir, PathBuf) {
+    let dir = TempDir::new(name).expect("Should be able to create a temporary directory");
+    let path = dir.path().to_path_buf();
+    (dir, path)
+}
+
+/// Output options that remove the stale files instead of moving them to
+/// the trash
+pub fn delete_options() -> OutputOptions {
+    OutputOptions {
+        cleanup_policy: CleanupPolicy::Delete,
+        ..Default::default()
+    }
+}
@@ -1,6 +1,9 @@
 use flate2::Compression;
-use sha2::Digest;
 use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Suffix of the temporary files, see [try_write_file]
+pub const TEMP_FILE_SUFFIX: &str = ".smart_tmp";
 
 pub(crate) fn compress(data: &[u8], compression: Compression) -> Vec<u8> {
     let mut flate2_data = vec![];
@@ -10,17 +13,165 @@ pub(crate) fn compress(data: &[u8], compression: Compression) -> Vec<u8> {
     flate2_data
 }
 
-pub(crate) fn decompress(data: &[u8]) -> Vec<u8> {
+pub(crate) fn decompress(data: &[u8]) -> std::io::Result<Vec<u8>> {
     let mut deflated = vec![];
     let mut writer = flate2::write::ZlibDecoder::new(&mut deflated);
-    writer.write_all(data).unwrap();
-    writer.flush().unwrap();
+    writer.write_all(data)?;
+    writer.flush()?;
     drop(writer);
-    deflated
+    Ok(deflated)
+}
+
+/// Writes the file by writing a temporary sibling and renaming it into
+/// place, so the file either has its old or its new content even if the
+/// process is killed mid-write
+///
+/// Temporary file is removed if the rename fails
+pub(crate) fn try_write_file(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let temp = temp_path(path);
+    fs_err::write(&temp, data)?;
+    fs_err::rename(&temp, path).inspect_err(|_| {
+        let _ = fs_err::remove_file(&temp);
+    })
+}
+
+/// Same as [try_write_file], but keeps the permissions of the replaced file
+///
+/// Read-only file is made writable for the write, since it can't be
+/// replaced on Windows otherwise. The flag is restored afterwards if
+/// `keep_read_only` is set, and is cleared for good otherwise
+pub(crate) fn write_preserving_permissions(
+    path: &Path,
+    data: &[u8],
+    keep_read_only: bool,
+) -> std::io::Result<()> {
+    let Ok(metadata) = fs_err::metadata(path) else {
+        return try_write_file(path, data);
+    };
+    let original = metadata.permissions();
+    let read_only = original.readonly();
+    let permissions = if read_only {
+        fs_err::set_permissions(path, writable(original.clone()))?;
+        if keep_read_only {
+            original.clone()
+        } else {
+            writable(original.clone())
+        }
+    } else {
+        original.clone()
+    };
+    let temp = temp_path(path);
+    let result = fs_err::write(&temp, data)
+        .and_then(|_| fs_err::set_permissions(&temp, permissions))
+        .and_then(|_| fs_err::rename(&temp, path));
+    if result.is_err() {
+        let _ = fs_err::remove_file(&temp);
+        if read_only {
+            let _ = fs_err::set_permissions(path, original);
+        }
+    }
+    result
+}
+
+pub(crate) fn is_read_only(path: &Path) -> bool {
+    fs_err::metadata(path).is_ok_and(|meta| meta.permissions().readonly())
+}
+
+/// Makes the read-only file writable, so it can be removed on Windows.
+/// Symlinks are left as is, without touching their target
+pub(crate) fn clear_read_only(path: &Path) -> std::io::Result<()> {
+    let metadata = fs_err::symlink_metadata(path)?;
+    if metadata.file_type().is_symlink() {
+        return Ok(());
+    }
+    let permissions = metadata.permissions();
+    if permissions.readonly() {
+        fs_err::set_permissions(path, writable(permissions))?;
+    }
+    Ok(())
+}
+
+/// Permissions with the write access for the owner
+#[cfg(unix)]
+fn writable(permissions: std::fs::Permissions) -> std::fs::Permissions {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::Permissions::from_mode(permissions.mode() | 0o200)
+}
+
+/// Permissions with the write access for the owner
+#[cfg(not(unix))]
+#[allow(clippy::permissions_set_readonly_false)]
+fn writable(mut permissions: std::fs::Permissions) -> std::fs::Permissions {
+    // Windows only has the read-only attribute, there are no other bits to
+    // make writable
+    permissions.set_readonly(false);
+    permissions
+}
+
+/// Symlink chains longer than this are treated as cyclic
+const MAX_SYMLINK_DEPTH: usize = 32;
+
+pub(crate) fn is_symlink(path: &Path) -> bool {
+    fs_err::symlink_metadata(path).is_ok_and(|meta| meta.file_type().is_symlink())
 }
 
-pub(crate) fn sha256(data: &[u8]) -> Vec<u8> {
-    let mut hasher = sha2::Sha256::new();
-    hasher.update(data);
-    hasher.finalize().to_vec()
+/// Follows the chain of symlinks, returning the final target, which may not
+/// exist
+pub(crate) fn resolve_symlink(path: &Path) -> PathBuf {
+    let mut path = path.to_path_buf();
+    for _ in 0..MAX_SYMLINK_DEPTH {
+        let Ok(target) = fs_err::read_link(&path) else {
+            break;
+        };
+        // Relative targets are relative to the directory of the link
+        path = match path.parent() {
+            Some(parent) => parent.join(target),
+            None => target,
+        };
+    }
+    path
+}
+
+pub(crate) fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    name.push(TEMP_FILE_SUFFIX);
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{temp_path, try_write_file};
+    use crate::testing::test_dir;
+
+    #[test]
+    fn interrupted_write_keeps_old_content() {
+        let (_dir, dir) = test_dir("interrupted");
+        let path = dir.join("item.json");
+        try_write_file(&path, b"old").unwrap();
+
+        // Process killed after the temporary file is written, but before the
+        // rename
+        std::fs::write(temp_path(&path), b"new, but cut").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"old");
+
+        // Stale temporary file is overwritten by the next write
+        try_write_file(&path, b"new").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"new");
+        assert!(!temp_path(&path).exists());
+    }
+
+    #[test]
+    fn failed_rename_removes_temp_file() {
+        let (_dir, dir) = test_dir("failed_rename");
+        let path = dir.join("item.json");
+        std::fs::create_dir(&path).unwrap();
+        std::fs::write(path.join("child"), b"child").unwrap();
+
+        assert!(try_write_file(&path, b"data").is_err());
+        assert!(!temp_path(&path).exists());
+        assert_eq!(std::fs::read(path.join("child")).unwrap(), b"child");
+    }
 }
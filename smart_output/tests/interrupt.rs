@@ -0,0 +1,78 @@
+//! Signal handling of the [CriticalSection]
+//!
+//! Kept in a separate test binary, since the signal actions are shared by
+//! the whole process and other tests enter the critical sections too
+
+#![cfg(unix)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use smart_output::interrupt::{interrupt_pending, CriticalSection};
+
+static RECEIVED: AtomicUsize = AtomicUsize::new(0);
+
+extern "C" fn count(_signal: libc::c_int) {
+    RECEIVED.fetch_add(1, Ordering::SeqCst);
+}
+
+fn current_action(signal: libc::c_int) -> libc::sighandler_t {
+    // SAFETY: only queries the current action
+    unsafe {
+        let mut action: libc::sigaction = std::mem::zeroed();
+        libc::sigaction(signal, std::ptr::null(), &mut action);
+        action.sa_sigaction
+    }
+}
+
+fn set_action(signal: libc::c_int, handler: libc::sighandler_t) {
+    // SAFETY: installed handler only touches an atomic
+    unsafe {
+        let mut action: libc::sigaction = std::mem::zeroed();
+        action.sa_sigaction = handler;
+        libc::sigemptyset(&mut action.sa_mask);
+        libc::sigaction(signal, &action, std::ptr::null_mut());
+    }
+}
+
+fn raise(signal: libc::c_int) {
+    // SAFETY: both signals have non-default actions installed by the test
+    unsafe {
+        libc::raise(signal);
+    }
+}
+
+#[test]
+fn deferred_signals_reach_previous_actions() {
+    let counter = count as *const () as libc::sighandler_t;
+    set_action(libc::SIGTERM, counter);
+    set_action(libc::SIGINT, libc::SIG_IGN);
+
+    let section = CriticalSection::enter();
+    assert_ne!(current_action(libc::SIGTERM), counter);
+    raise(libc::SIGTERM);
+    assert!(interrupt_pending());
+    drop(CriticalSection::enter());
+    assert_eq!(RECEIVED.load(Ordering::SeqCst), 0);
+    drop(section);
+
+    // Deferred signal is forwarded, and the previous action is back
+    assert!(!interrupt_pending());
+    assert_eq!(RECEIVED.load(Ordering::SeqCst), 1);
+    assert_eq!(current_action(libc::SIGTERM), counter);
+    raise(libc::SIGTERM);
+    assert_eq!(RECEIVED.load(Ordering::SeqCst), 2);
+
+    // Second signal is forwarded right away
+    let section = CriticalSection::enter();
+    raise(libc::SIGTERM);
+    raise(libc::SIGTERM);
+    assert_eq!(RECEIVED.load(Ordering::SeqCst), 3);
+    drop(section);
+    assert_eq!(RECEIVED.load(Ordering::SeqCst), 4);
+
+    // Ignored signal stays ignored
+    let section = CriticalSection::enter();
+    raise(libc::SIGINT);
+    drop(section);
+    assert_eq!(current_action(libc::SIGINT), libc::SIG_IGN);
+}